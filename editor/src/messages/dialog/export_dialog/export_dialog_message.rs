@@ -8,6 +8,7 @@ pub enum ExportDialogMessage {
 	ScaleFactor(f64),
 	TransparentBackground(bool),
 	ExportBounds(ExportBounds),
+	Channels(String),
 
 	Submit,
 }
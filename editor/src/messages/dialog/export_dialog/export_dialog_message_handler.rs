@@ -14,6 +14,8 @@ pub struct ExportDialogMessageHandler {
 	pub scale_factor: f64,
 	pub bounds: ExportBounds,
 	pub transparent_background: bool,
+	/// Comma-separated names of the print channels that are additionally exported as separate files.
+	pub channels: String,
 	pub artboards: HashMap<LayerNodeIdentifier, String>,
 	pub has_selection: bool,
 }
@@ -27,6 +29,7 @@ impl MessageHandler<ExportDialogMessage, ExportDialogMessageData<'_>> for Export
 			ExportDialogMessage::ScaleFactor(factor) => self.scale_factor = factor,
 			ExportDialogMessage::TransparentBackground(transparent_background) => self.transparent_background = transparent_background,
 			ExportDialogMessage::ExportBounds(export_area) => self.bounds = export_area,
+			ExportDialogMessage::Channels(channels) => self.channels = channels,
 
 			ExportDialogMessage::Submit => responses.add_front(PortfolioMessage::SubmitDocumentExport {
 				file_name: portfolio.active_document().map(|document| document.name.clone()).unwrap_or_default(),
@@ -34,6 +37,7 @@ impl MessageHandler<ExportDialogMessage, ExportDialogMessageData<'_>> for Export
 				scale_factor: self.scale_factor,
 				bounds: self.bounds,
 				transparent_background: self.file_type != FileType::Jpg && self.transparent_background,
+				channels: self.channels.split(',').map(str::trim).filter(|channel| !channel.is_empty()).map(String::from).collect(),
 			}),
 		}
 
@@ -67,10 +71,19 @@ impl DialogLayoutHolder for ExportDialogMessageHandler {
 
 impl LayoutHolder for ExportDialogMessageHandler {
 	fn layout(&self) -> Layout {
-		let entries = [(FileType::Png, "PNG"), (FileType::Jpg, "JPG"), (FileType::Svg, "SVG")]
-			.into_iter()
-			.map(|(val, name)| RadioEntryData::new(format!("{val:?}")).label(name).on_update(move |_| ExportDialogMessage::FileType(val).into()))
-			.collect();
+		let entries = [
+			(FileType::Png, "PNG"),
+			(FileType::Jpg, "JPG"),
+			(FileType::Svg, "SVG"),
+			(FileType::Eps, "EPS"),
+			(FileType::Emf, "EMF"),
+			(FileType::Pdf, "PDF"),
+			(FileType::Json, "JSON"),
+			(FileType::Ora, "ORA"),
+		]
+		.into_iter()
+		.map(|(val, name)| RadioEntryData::new(format!("{val:?}")).label(name).on_update(move |_| ExportDialogMessage::FileType(val).into()))
+		.collect();
 
 		let export_type = vec![
 			TextLabel::new("File Type").table_align(true).min_width(100).widget_holder(),
@@ -123,11 +136,22 @@ impl LayoutHolder for ExportDialogMessageHandler {
 				.widget_holder(),
 		];
 
+		let channels = vec![
+			TextLabel::new("Channels").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			TextInput::new(&self.channels)
+				.tooltip("Names of print channels (such as \"spot-uv, foil\") to export as additional files, each containing only the content tagged with that channel")
+				.on_update(|text_input: &TextInput| ExportDialogMessage::Channels(text_input.value.clone()).into())
+				.min_width(200)
+				.widget_holder(),
+		];
+
 		Layout::WidgetLayout(WidgetLayout::new(vec![
 			LayoutGroup::Row { widgets: export_type },
 			LayoutGroup::Row { widgets: resolution },
 			LayoutGroup::Row { widgets: export_area },
 			LayoutGroup::Row { widgets: transparent_background },
+			LayoutGroup::Row { widgets: channels },
 		]))
 	}
 }
@@ -1,4 +1,4 @@
-use super::utility_types::{FrontendDocumentDetails, MouseCursorIcon};
+use super::utility_types::{FrontendDocumentDetails, FrontendRasterLayer, MouseCursorIcon};
 use crate::messages::layout::utility_types::widget_prelude::*;
 use crate::messages::portfolio::document::node_graph::utility_types::{FrontendNode, FrontendNodeLink, FrontendNodeType};
 use crate::messages::portfolio::document::utility_types::nodes::{JsRawBuffer, LayerPanelEntry, RawBuffer};
@@ -46,6 +46,11 @@ pub enum FrontendMessage {
 		#[serde(rename = "blobUrl")]
 		blob_url: String,
 	},
+	TriggerDownloadBinaryFile {
+		data: Vec<u8>,
+		name: String,
+		mime: String,
+	},
 	TriggerDownloadBlobUrl {
 		#[serde(rename = "layerName")]
 		layer_name: String,
@@ -62,6 +67,14 @@ pub enum FrontendMessage {
 		document: String,
 		name: String,
 	},
+	TriggerDownloadOpenRaster {
+		/// The `stack.xml` file which describes the order, opacity, and blend mode of the layers.
+		stack: String,
+		/// The layers ordered from bottom to top.
+		layers: Vec<FrontendRasterLayer>,
+		name: String,
+		size: (f64, f64),
+	},
 	TriggerFetchAndOpenDocument {
 		name: String,
 		filename: String,
@@ -35,6 +35,16 @@ pub enum FileType {
 	Png,
 	Jpg,
 	Svg,
+	/// Encapsulated PostScript, for print shops and cutting plotters.
+	Eps,
+	/// Enhanced Metafile, for pasting vector artwork into office documents.
+	Emf,
+	/// Portable Document Format, for print workflows that require vector PDF files.
+	Pdf,
+	/// A JSON description of the scene, for external renderers and web viewers.
+	Json,
+	/// OpenRaster, a zip archive of PNG layers that is supported by most raster painting programs.
+	Ora,
 }
 
 impl FileType {
@@ -43,10 +53,27 @@ impl FileType {
 			FileType::Png => "image/png",
 			FileType::Jpg => "image/jpeg",
 			FileType::Svg => "image/svg+xml",
+			FileType::Eps => "application/postscript",
+			FileType::Emf => "image/emf",
+			FileType::Pdf => "application/pdf",
+			FileType::Json => "application/json",
+			FileType::Ora => "image/openraster",
 		}
 	}
 }
 
+/// A layer of a layered raster export, to be rasterized and packaged by the frontend.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct FrontendRasterLayer {
+	pub svg: String,
+	/// Path of the layer's PNG image within the exported archive.
+	pub source: String,
+	pub opacity: f32,
+	/// The canvas `globalCompositeOperation` used to merge this layer onto the layers beneath it when drawing the composite preview image.
+	#[serde(rename = "compositeOperation")]
+	pub composite_operation: String,
+}
+
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
 pub enum ExportBounds {
 	#[default]
@@ -1098,7 +1098,9 @@ impl DocumentMessageHandler {
 					..
 				} = input
 				{
-					fonts.insert(font.clone());
+					if !font.is_none() {
+						fonts.insert(font.clone());
+					}
 				}
 			}
 		}
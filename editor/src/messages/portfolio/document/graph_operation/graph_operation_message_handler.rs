@@ -9,7 +9,7 @@ use graph_craft::document::{generate_uuid, NodeId, NodeInput, NodeNetwork};
 use graphene_core::renderer::Quad;
 use graphene_core::text::Font;
 use graphene_core::uuid::ManipulatorGroupId;
-use graphene_core::vector::style::{Fill, Gradient, GradientType, LineCap, LineJoin, Stroke};
+use graphene_core::vector::style::{Fill, FillRule, Gradient, GradientType, LineCap, LineJoin, Stroke, StrokeAlignment};
 use graphene_core::Color;
 
 use glam::{DAffine2, DVec2, IVec2};
@@ -313,7 +313,13 @@ fn import_usvg_node(modify_inputs: &mut ModifyInputsContext, node: &usvg::Node,
 				bounds_transform,
 				transformed_bound_transform,
 			);
-			apply_usvg_stroke(&path.stroke, modify_inputs);
+			apply_usvg_stroke(
+				&path.stroke,
+				modify_inputs,
+				transform * usvg_transform(node.abs_transform()),
+				bounds_transform,
+				transformed_bound_transform,
+			);
 		}
 		usvg::Node::Image(_image) => {
 			warn!("Skip image")
@@ -326,91 +332,112 @@ fn import_usvg_node(modify_inputs: &mut ModifyInputsContext, node: &usvg::Node,
 	}
 }
 
-fn apply_usvg_stroke(stroke: &Option<usvg::Stroke>, modify_inputs: &mut ModifyInputsContext) {
+fn apply_usvg_stroke(stroke: &Option<usvg::Stroke>, modify_inputs: &mut ModifyInputsContext, transform: DAffine2, bounds_transform: DAffine2, transformed_bound_transform: DAffine2) {
 	if let Some(stroke) = stroke {
-		if let usvg::Paint::Color(color) = &stroke.paint {
-			modify_inputs.stroke_set(Stroke {
-				color: Some(usvg_color(*color, stroke.opacity.get())),
-				weight: stroke.width.get() as f64,
-				dash_lengths: stroke.dasharray.as_ref().map(|lengths| lengths.iter().map(|&length| length as f64).collect()).unwrap_or_default(),
-				dash_offset: stroke.dashoffset as f64,
-				line_cap: match stroke.linecap {
-					usvg::LineCap::Butt => LineCap::Butt,
-					usvg::LineCap::Round => LineCap::Round,
-					usvg::LineCap::Square => LineCap::Square,
-				},
-				line_join: match stroke.linejoin {
-					usvg::LineJoin::Miter => LineJoin::Miter,
-					usvg::LineJoin::MiterClip => LineJoin::Miter,
-					usvg::LineJoin::Round => LineJoin::Round,
-					usvg::LineJoin::Bevel => LineJoin::Bevel,
-				},
-				line_join_miter_limit: stroke.miterlimit.get() as f64,
-			})
-		} else {
-			warn!("Skip non-solid stroke")
-		}
+		let Some(paint) = usvg_paint(&stroke.paint, stroke.opacity.get(), transform, bounds_transform, transformed_bound_transform) else {
+			return;
+		};
+		modify_inputs.stroke_set(Stroke {
+			paint,
+			weight: stroke.width.get() as f64,
+			dash_lengths: stroke.dasharray.as_ref().map(|lengths| lengths.iter().map(|&length| length as f64).collect()).unwrap_or_default(),
+			dash_offset: stroke.dashoffset as f64,
+			line_cap: match stroke.linecap {
+				usvg::LineCap::Butt => LineCap::Butt,
+				usvg::LineCap::Round => LineCap::Round,
+				usvg::LineCap::Square => LineCap::Square,
+			},
+			line_join: match stroke.linejoin {
+				usvg::LineJoin::Miter => LineJoin::Miter,
+				usvg::LineJoin::MiterClip => LineJoin::Miter,
+				usvg::LineJoin::Round => LineJoin::Round,
+				usvg::LineJoin::Bevel => LineJoin::Bevel,
+			},
+			line_join_miter_limit: stroke.miterlimit.get() as f64,
+			gradient: None,
+			alignment: StrokeAlignment::Center,
+			width_profile: Vec::new(),
+			hairline: false,
+			dash_cap: None,
+			dashes_scale_with_weight: false,
+			dashes_align_to_corners: false,
+		})
 	}
 }
 
 fn apply_usvg_fill(fill: &Option<usvg::Fill>, modify_inputs: &mut ModifyInputsContext, transform: DAffine2, bounds_transform: DAffine2, transformed_bound_transform: DAffine2) {
 	if let Some(fill) = &fill {
-		modify_inputs.fill_set(match &fill.paint {
-			usvg::Paint::Color(color) => Fill::solid(usvg_color(*color, fill.opacity.get())),
-			usvg::Paint::LinearGradient(linear) => {
-				let local = [DVec2::new(linear.x1 as f64, linear.y1 as f64), DVec2::new(linear.x2 as f64, linear.y2 as f64)];
-
-				let to_doc_transform = if linear.base.units == usvg::Units::UserSpaceOnUse {
-					transform
-				} else {
-					transformed_bound_transform
-				};
-				let to_doc = to_doc_transform * usvg_transform(linear.transform);
-
-				let document = [to_doc.transform_point2(local[0]), to_doc.transform_point2(local[1])];
-				let layer = [transform.inverse().transform_point2(document[0]), transform.inverse().transform_point2(document[1])];
-
-				let [start, end] = [bounds_transform.inverse().transform_point2(layer[0]), bounds_transform.inverse().transform_point2(layer[1])];
-
-				Fill::Gradient(Gradient {
-					start,
-					end,
-					transform: DAffine2::IDENTITY,
-					gradient_type: GradientType::Linear,
-					positions: linear.stops.iter().map(|stop| (stop.offset.get() as f64, usvg_color(stop.color, stop.opacity.get()))).collect(),
-				})
-			}
-			usvg::Paint::RadialGradient(radial) => {
-				let local = [DVec2::new(radial.cx as f64, radial.cy as f64), DVec2::new(radial.fx as f64, radial.fy as f64)];
-
-				let to_doc_transform = if radial.base.units == usvg::Units::UserSpaceOnUse {
-					transform
-				} else {
-					transformed_bound_transform
-				};
-				let to_doc = to_doc_transform * usvg_transform(radial.transform);
-
-				let document = [to_doc.transform_point2(local[0]), to_doc.transform_point2(local[1])];
-				let layer = [transform.inverse().transform_point2(document[0]), transform.inverse().transform_point2(document[1])];
-
-				let [start, end] = [bounds_transform.inverse().transform_point2(layer[0]), bounds_transform.inverse().transform_point2(layer[1])];
-
-				Fill::Gradient(Gradient {
-					start,
-					end,
-					transform: DAffine2::IDENTITY,
-					gradient_type: GradientType::Radial,
-					positions: radial.stops.iter().map(|stop| (stop.offset.get() as f64, usvg_color(stop.color, stop.opacity.get()))).collect(),
-				})
-			}
-			usvg::Paint::Pattern(_) => {
-				warn!("Skip pattern");
-				return;
-			}
+		let Some(paint) = usvg_paint(&fill.paint, fill.opacity.get(), transform, bounds_transform, transformed_bound_transform) else {
+			return;
+		};
+		modify_inputs.fill_set(paint);
+		modify_inputs.fill_rule_set(match fill.rule {
+			usvg::FillRule::NonZero => FillRule::NonZero,
+			usvg::FillRule::EvenOdd => FillRule::EvenOdd,
 		});
 	}
 }
 
+/// Converts the paint of a fill or stroke, with its gradient points mapped from the space of the SVG to the bounds of the shape.
+fn usvg_paint(paint: &usvg::Paint, opacity: f32, transform: DAffine2, bounds_transform: DAffine2, transformed_bound_transform: DAffine2) -> Option<Fill> {
+	Some(match paint {
+		usvg::Paint::Color(color) => Fill::solid(usvg_color(*color, opacity)),
+		usvg::Paint::LinearGradient(linear) => {
+			let local = [DVec2::new(linear.x1 as f64, linear.y1 as f64), DVec2::new(linear.x2 as f64, linear.y2 as f64)];
+
+			let to_doc_transform = if linear.base.units == usvg::Units::UserSpaceOnUse {
+				transform
+			} else {
+				transformed_bound_transform
+			};
+			let to_doc = to_doc_transform * usvg_transform(linear.transform);
+
+			let document = [to_doc.transform_point2(local[0]), to_doc.transform_point2(local[1])];
+			let layer = [transform.inverse().transform_point2(document[0]), transform.inverse().transform_point2(document[1])];
+
+			let [start, end] = [bounds_transform.inverse().transform_point2(layer[0]), bounds_transform.inverse().transform_point2(layer[1])];
+
+			Fill::Gradient(Gradient {
+				start,
+				end,
+				transform: DAffine2::IDENTITY,
+				gradient_type: GradientType::Linear,
+				positions: linear.stops.iter().map(|stop| (stop.offset.get() as f64, usvg_color(stop.color, stop.opacity.get()))).collect(),
+				focal_point: None,
+				..Default::default()
+			})
+		}
+		usvg::Paint::RadialGradient(radial) => {
+			// The end point is where the circle is reached to the right of the center
+			let center = DVec2::new(radial.cx as f64, radial.cy as f64);
+			let local = [center, center + DVec2::X * radial.r.get() as f64, DVec2::new(radial.fx as f64, radial.fy as f64)];
+
+			let to_doc_transform = if radial.base.units == usvg::Units::UserSpaceOnUse {
+				transform
+			} else {
+				transformed_bound_transform
+			};
+			let to_doc = to_doc_transform * usvg_transform(radial.transform);
+
+			let [start, end, focal_point] = local.map(|point| bounds_transform.inverse().transform_point2(transform.inverse().transform_point2(to_doc.transform_point2(point))));
+
+			Fill::Gradient(Gradient {
+				start,
+				end,
+				transform: DAffine2::IDENTITY,
+				gradient_type: GradientType::Radial,
+				positions: radial.stops.iter().map(|stop| (stop.offset.get() as f64, usvg_color(stop.color, stop.opacity.get()))).collect(),
+				focal_point: (local[2] != local[0]).then_some(focal_point),
+				..Default::default()
+			})
+		}
+		usvg::Paint::Pattern(_) => {
+			warn!("Skip pattern");
+			return None;
+		}
+	})
+}
+
 fn convert_usvg_path(path: &usvg::Path) -> Vec<Subpath<ManipulatorGroupId>> {
 	let mut subpaths = Vec::new();
 	let mut groups = Vec::new();
@@ -10,7 +10,7 @@ use graphene_core::raster::{BlendMode, ImageFrame};
 use graphene_core::text::Font;
 use graphene_core::uuid::ManipulatorGroupId;
 use graphene_core::vector::brush_stroke::BrushStroke;
-use graphene_core::vector::style::{Fill, FillType, Stroke};
+use graphene_core::vector::style::{Fill, FillRule, FillType, Stroke};
 use graphene_core::{Artboard, Color};
 use graphene_std::vector::ManipulatorPointId;
 
@@ -227,12 +227,12 @@ impl<'a> ModifyInputsContext<'a> {
 	}
 
 	pub fn insert_text(&mut self, text: String, font: Font, size: f64, layer: NodeId) {
-		let text = resolve_document_node_type("Text").expect("Text node does not exist").to_document_node(
+		let text = resolve_document_node_type("Text").expect("Text node does not exist").to_document_node_default_inputs(
 			[
-				NodeInput::Network(graph_craft::concrete!(graphene_std::wasm_application_io::WasmEditorApi)),
-				NodeInput::value(TaggedValue::String(text), false),
-				NodeInput::value(TaggedValue::Font(font), false),
-				NodeInput::value(TaggedValue::F64(size), false),
+				Some(NodeInput::Network(graph_craft::concrete!(graphene_std::wasm_application_io::WasmEditorApi))),
+				Some(NodeInput::value(TaggedValue::String(text), false)),
+				Some(NodeInput::value(TaggedValue::Font(font), false)),
+				Some(NodeInput::value(TaggedValue::F64(size), false)),
 			],
 			Default::default(),
 		);
@@ -371,6 +371,8 @@ impl<'a> ModifyInputsContext<'a> {
 			let fill_type = match fill {
 				Fill::None | Fill::Solid(_) => FillType::Solid,
 				Fill::Gradient(_) => FillType::Gradient,
+				// Patterns and images come from the Pattern Fill and Image Fill nodes, since the inputs of the Fill node can't describe them
+				Fill::Pattern(_) | Fill::Image(_) => return,
 			};
 			inputs[1] = NodeInput::value(TaggedValue::FillType(fill_type), false);
 			if Fill::None == fill {
@@ -383,10 +385,17 @@ impl<'a> ModifyInputsContext<'a> {
 				inputs[5] = NodeInput::value(TaggedValue::DVec2(gradient.end), false);
 				inputs[6] = NodeInput::value(TaggedValue::DAffine2(gradient.transform), false);
 				inputs[7] = NodeInput::value(TaggedValue::GradientPositions(gradient.positions), false);
+				inputs[8] = NodeInput::value(TaggedValue::OptionalDVec2(gradient.focal_point), false);
 			}
 		});
 	}
 
+	pub fn fill_rule_set(&mut self, fill_rule: FillRule) {
+		self.modify_inputs("Fill", false, |inputs, _node_id, _metadata| {
+			inputs[9] = NodeInput::value(TaggedValue::FillRule(fill_rule), false);
+		});
+	}
+
 	pub fn opacity_set(&mut self, opacity: f64) {
 		self.modify_inputs("Opacity", false, |inputs, _node_id, _metadata| {
 			inputs[1] = NodeInput::value(TaggedValue::F64(opacity * 100.), false);
@@ -401,13 +410,25 @@ impl<'a> ModifyInputsContext<'a> {
 
 	pub fn stroke_set(&mut self, stroke: Stroke) {
 		self.modify_inputs("Stroke", false, |inputs, _node_id, _metadata| {
-			inputs[1] = NodeInput::value(TaggedValue::OptionalColor(stroke.color), false);
+			inputs[1] = NodeInput::value(TaggedValue::OptionalColor(stroke.color()), false);
 			inputs[2] = NodeInput::value(TaggedValue::F64(stroke.weight), false);
 			inputs[3] = NodeInput::value(TaggedValue::VecF64(stroke.dash_lengths), false);
 			inputs[4] = NodeInput::value(TaggedValue::F64(stroke.dash_offset), false);
 			inputs[5] = NodeInput::value(TaggedValue::LineCap(stroke.line_cap), false);
 			inputs[6] = NodeInput::value(TaggedValue::LineJoin(stroke.line_join), false);
 			inputs[7] = NodeInput::value(TaggedValue::F64(stroke.line_join_miter_limit), false);
+			inputs[8] = NodeInput::value(TaggedValue::StrokeAlignment(stroke.alignment), false);
+			if let Fill::Gradient(gradient) = stroke.paint {
+				inputs[9] = NodeInput::value(TaggedValue::FillType(FillType::Gradient), false);
+				inputs[10] = NodeInput::value(TaggedValue::GradientType(gradient.gradient_type), false);
+				inputs[11] = NodeInput::value(TaggedValue::DVec2(gradient.start), false);
+				inputs[12] = NodeInput::value(TaggedValue::DVec2(gradient.end), false);
+				inputs[13] = NodeInput::value(TaggedValue::DAffine2(gradient.transform), false);
+				inputs[14] = NodeInput::value(TaggedValue::GradientPositions(gradient.positions), false);
+				inputs[15] = NodeInput::value(TaggedValue::OptionalDVec2(gradient.focal_point), false);
+			} else {
+				inputs[9] = NodeInput::value(TaggedValue::FillType(FillType::Solid), false);
+			}
 		});
 	}
 
@@ -255,6 +255,321 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 			manual_composition: Some(concrete!(Footprint)),
 			..Default::default()
 		},
+		DocumentNodeDefinition {
+			name: "Impose",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::imposition::ImposeNode<_, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Artboard", TaggedValue::Artboard(Artboard::new(glam::IVec2::ZERO, glam::IVec2::new(1920, 1080))), true),
+				DocumentInputType::value("Sheet Dimensions", TaggedValue::IVec2(glam::IVec2::new(3508, 2480)), false),
+				DocumentInputType::value("Gutter", TaggedValue::DVec2(DVec2::splat(20.)), false),
+				DocumentInputType::value("Margin", TaggedValue::F64(50.), false),
+				DocumentInputType::value("Back Side", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Cut Marks", TaggedValue::Bool(true), false),
+				DocumentInputType::value("Cut Mark Length", TaggedValue::F64(20.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::Artboard)],
+			properties: node_properties::impose_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Channel",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::SetChannelNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Channel", TaggedValue::String("spot-uv".to_string()), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::channel_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Reference Image",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::ReferenceImageNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Opacity", TaggedValue::F64(50.), false),
+				DocumentInputType::value("Lock to Artboard", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::reference_image_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Export Set",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::export::ExportSetNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Name", TaggedValue::String("icon".to_string()), false),
+				DocumentInputType::value("Targets", TaggedValue::String("1x png, 2x png @2x, 3x png @3x, 1x svg".to_string()), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::export_set_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Clip",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::mask::ClipNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Mask", TaggedValue::VectorData(VectorData::empty()), true),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::node_no_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Export SVG",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::renderer::ExportSvgNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Precision", TaggedValue::U32(3), false),
+				DocumentInputType::value("View Box", TaggedValue::SvgViewBox(graphene_core::renderer::SvgViewBox::Content), false),
+				DocumentInputType::value("Flatten Transforms", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Deduplicate Defs", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![DocumentOutputType::new("SVG", FrontendGraphDataType::Text)],
+			properties: node_properties::export_svg_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Atlas Pack",
+			category: "General",
+			implementation: DocumentNodeImplementation::Network(NodeNetwork {
+				imports: vec![NodeId(0), NodeId(0), NodeId(0), NodeId(0), NodeId(0), NodeId(0)],
+				exports: vec![NodeOutput::new(NodeId(1), 0), NodeOutput::new(NodeId(2), 0)],
+				nodes: [
+					DocumentNode {
+						name: "Atlas Pack".to_string(),
+						inputs: vec![
+							NodeInput::Network(concrete!(GraphicGroup)),
+							NodeInput::Network(concrete!(String)),
+							NodeInput::Network(concrete!(f64)),
+							NodeInput::Network(concrete!(u32)),
+							NodeInput::Network(concrete!(u32)),
+							NodeInput::Network(concrete!(bool)),
+						],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::atlas::AtlasPackNode<_, _, _, _, _>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Atlas".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::FirstOfPairNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Metadata".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::SecondOfPairNode")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (NodeId(id as u64), node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Names", TaggedValue::String(String::new()), false),
+				DocumentInputType::value("Scale", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Padding", TaggedValue::U32(2), false),
+				DocumentInputType::value("Max Width", TaggedValue::U32(2048), false),
+				DocumentInputType::value("Power of Two", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![
+				DocumentOutputType::new("Atlas", FrontendGraphDataType::Raster),
+				DocumentOutputType::new("Metadata", FrontendGraphDataType::Text),
+			],
+			properties: node_properties::atlas_pack_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Nine-Patch",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_std::nine_patch::NinePatchNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Left", TaggedValue::U32(8), false),
+				DocumentInputType::value("Top", TaggedValue::U32(8), false),
+				DocumentInputType::value("Right", TaggedValue::U32(8), false),
+				DocumentInputType::value("Bottom", TaggedValue::U32(8), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::nine_patch_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Encode PNG",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_std::encode::EncodePngNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Best Compression", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::General)],
+			properties: node_properties::encode_png_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Encode JPEG",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_std::encode::EncodeJpegNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Quality", TaggedValue::U32(90), false),
+				DocumentInputType::value("Background", TaggedValue::Color(Color::WHITE), false),
+			],
+			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::General)],
+			properties: node_properties::encode_jpeg_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Encode WebP",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_std::encode::EncodeWebPNode"),
+			inputs: vec![DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::General)],
+			properties: node_properties::encode_webp_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Align and Distribute",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::layout::AlignDistributeNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Horizontal", TaggedValue::HorizontalAlignment(graphene_core::layout::HorizontalAlignment::None), false),
+				DocumentInputType::value("Vertical", TaggedValue::VerticalAlignment(graphene_core::layout::VerticalAlignment::Middle), false),
+				DocumentInputType::value("Distribute", TaggedValue::Distribution(graphene_core::layout::Distribution::Horizontal), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::align_distribute_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Visual Diff",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::diff::VisualDiffNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Before", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("After", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::node_no_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "For Each Row",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::variable_data::ForEachRowNode<_, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Template", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Table", TaggedValue::String("name\nAda Lovelace\nGrace Hopper".to_string()), false),
+				DocumentInputType::value("Columns", TaggedValue::U32(4), false),
+				DocumentInputType::value("Cell Size", TaggedValue::DVec2(DVec2::new(1920., 1080.)), false),
+				DocumentInputType::value("Artboards", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::for_each_row_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Row Field",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::variable_data::RowFieldNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Table", TaggedValue::String("name\nAda Lovelace\nGrace Hopper".to_string()), false),
+				DocumentInputType::value("Column", TaggedValue::String("name".to_string()), false),
+			],
+			outputs: vec![DocumentOutputType::new("Field", FrontendGraphDataType::Text)],
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::row_field_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Sequence Number",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::variable_data::SequenceNumberNode<_, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Start", TaggedValue::U32(1), false),
+				DocumentInputType::value("Step", TaggedValue::U32(1), false),
+				DocumentInputType::value("Digits", TaggedValue::U32(6), false),
+				DocumentInputType::value("Prefix", TaggedValue::String(String::new()), false),
+				DocumentInputType::value("Suffix", TaggedValue::String(String::new()), false),
+			],
+			outputs: vec![DocumentOutputType::new("Serial", FrontendGraphDataType::Text)],
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::sequence_number_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "UUID",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::variable_data::UuidNode<_>"),
+			inputs: vec![DocumentInputType::value("Seed", TaggedValue::U32(0), false)],
+			outputs: vec![DocumentOutputType::new("UUID", FrontendGraphDataType::Text)],
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::uuid_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Parameter Sweep",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::variable_data::ParameterSweepNode<_, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Template", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Parameter", TaggedValue::String("size".to_string()), false),
+				DocumentInputType::value("Values", TaggedValue::String("10..100/4".to_string()), false),
+				DocumentInputType::value("Columns", TaggedValue::U32(4), false),
+				DocumentInputType::value("Cell Size", TaggedValue::DVec2(DVec2::new(200., 200.)), false),
+				DocumentInputType::value("Labels", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::parameter_sweep_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Parameter",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_core::variable_data::ParameterNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Name", TaggedValue::String("size".to_string()), false),
+				DocumentInputType::value("Default", TaggedValue::F64(10.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Value", FrontendGraphDataType::Number)],
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::parameter_properties,
+			..Default::default()
+		},
+		#[cfg(feature = "native")]
+		DocumentNodeDefinition {
+			name: "Controller Parameter",
+			category: "General",
+			implementation: DocumentNodeImplementation::proto("graphene_std::controller_input::ControllerParameterNode<_, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Name", TaggedValue::String("size".to_string()), false),
+				DocumentInputType::value("Default", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Address", TaggedValue::String("/1/fader1".to_string()), false),
+				DocumentInputType::value("OSC Port", TaggedValue::U32(9000), false),
+				DocumentInputType::value("Min", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Max", TaggedValue::F64(100.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Value", FrontendGraphDataType::Number)],
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::controller_parameter_properties,
+			..Default::default()
+		},
 		// TODO: Does this need an internal Cull node to be added to its implementation?
 		DocumentNodeDefinition {
 			name: "Input Frame",
@@ -324,25 +639,63 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 			properties: node_properties::load_image_properties,
 			..Default::default()
 		},
+		#[cfg(feature = "native")]
 		DocumentNodeDefinition {
-			name: "Create Canvas",
+			name: "Watch Image",
 			category: "Structural",
 			implementation: DocumentNodeImplementation::Network(NodeNetwork {
-				imports: vec![NodeId(0)],
+				imports: vec![NodeId(0), NodeId(0)],
+				exports: vec![NodeOutput::new(NodeId(2), 0)],
+				nodes: [
+					DocumentNode {
+						name: "Watch File".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(())), NodeInput::Network(concrete!(String))],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::watch_file::WatchFileNode<_>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Decode Image".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::watch_file::DecodeFileImageNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Cull".to_string(),
+						inputs: vec![NodeInput::node(NodeId(1), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::transform::CullNode<_>")),
+						manual_composition: Some(concrete!(Footprint)),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (NodeId(id as u64), node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![DocumentInputType::none(), DocumentInputType::value("Path", TaggedValue::String(String::new()), false)],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::watch_file_properties,
+			..Default::default()
+		},
+		#[cfg(feature = "native")]
+		DocumentNodeDefinition {
+			name: "Watch Text File",
+			category: "Structural",
+			implementation: DocumentNodeImplementation::Network(NodeNetwork {
+				imports: vec![NodeId(0), NodeId(0)],
 				exports: vec![NodeOutput::new(NodeId(1), 0)],
 				nodes: [
 					DocumentNode {
-						name: "Create Canvas".to_string(),
-						inputs: vec![NodeInput::Network(concrete!(WasmEditorApi))],
-						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::wasm_application_io::CreateSurfaceNode")),
-						skip_deduplication: true,
+						name: "Watch File".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(())), NodeInput::Network(concrete!(String))],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::watch_file::WatchFileNode<_>")),
 						..Default::default()
 					},
 					DocumentNode {
-						name: "Cache".to_string(),
-						manual_composition: Some(concrete!(())),
+						name: "Decode Text".to_string(),
 						inputs: vec![NodeInput::node(NodeId(0), 0)],
-						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::memo::MemoNode<_, _>")),
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::watch_file::DecodeFileTextNode")),
 						..Default::default()
 					},
 				]
@@ -352,48 +705,48 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 				.collect(),
 				..Default::default()
 			}),
-			inputs: vec![DocumentInputType {
-				name: "In",
-				data_type: FrontendGraphDataType::General,
-				default: NodeInput::Network(concrete!(WasmEditorApi)),
-			}],
-			outputs: vec![DocumentOutputType {
-				name: "Canvas",
-				data_type: FrontendGraphDataType::General,
-			}],
+			inputs: vec![DocumentInputType::none(), DocumentInputType::value("Path", TaggedValue::String(String::new()), false)],
+			outputs: vec![DocumentOutputType::new("Text", FrontendGraphDataType::Text)],
+			properties: node_properties::watch_file_properties,
 			..Default::default()
 		},
+		#[cfg(feature = "fetch")]
 		DocumentNodeDefinition {
-			name: "Draw Canvas",
+			name: "Fetch Image",
 			category: "Structural",
 			implementation: DocumentNodeImplementation::Network(NodeNetwork {
-				imports: vec![NodeId(0), NodeId(2)],
-				exports: vec![NodeOutput::new(NodeId(3), 0)],
+				imports: vec![NodeId(0), NodeId(0), NodeId(0)],
+				exports: vec![NodeOutput::new(NodeId(4), 0), NodeOutput::new(NodeId(2), 0)],
 				nodes: [
 					DocumentNode {
-						name: "Convert Image Frame".to_string(),
-						inputs: vec![NodeInput::Network(concrete!(ImageFrame<Color>))],
-						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::IntoNode<_, ImageFrame<SRGBA8>>")),
+						name: "Fetch URL".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(())), NodeInput::Network(concrete!(String)), NodeInput::Network(concrete!(f64))],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::fetch::FetchUrlNode<_, _>")),
 						..Default::default()
 					},
 					DocumentNode {
-						name: "Create Canvas".to_string(),
-						inputs: vec![NodeInput::Network(concrete!(WasmEditorApi))],
-						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::wasm_application_io::CreateSurfaceNode")),
-						skip_deduplication: true,
+						name: "Data".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::FirstOfPairNode")),
 						..Default::default()
 					},
 					DocumentNode {
-						name: "Cache".to_string(),
-						manual_composition: Some(concrete!(())),
+						name: "Error".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::SecondOfPairNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Decode Image".to_string(),
 						inputs: vec![NodeInput::node(NodeId(1), 0)],
-						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::memo::MemoNode<_, _>")),
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::watch_file::DecodeFileImageNode")),
 						..Default::default()
 					},
 					DocumentNode {
-						name: "Draw Canvas".to_string(),
-						inputs: vec![NodeInput::node(NodeId(0), 0), NodeInput::node(NodeId(2), 0)],
-						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::wasm_application_io::DrawImageFrameNode<_>")),
+						name: "Cull".to_string(),
+						inputs: vec![NodeInput::node(NodeId(3), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::transform::CullNode<_>")),
+						manual_composition: Some(concrete!(Footprint)),
 						..Default::default()
 					},
 				]
@@ -404,9 +757,151 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 				..Default::default()
 			}),
 			inputs: vec![
-				DocumentInputType {
-					name: "In",
-					data_type: FrontendGraphDataType::Raster,
+				DocumentInputType::none(),
+				DocumentInputType::value("URL", TaggedValue::String(String::new()), false),
+				DocumentInputType::value("Refresh", TaggedValue::F64(0.), false),
+			],
+			outputs: vec![
+				DocumentOutputType::new("Image", FrontendGraphDataType::Raster),
+				DocumentOutputType::new("Error", FrontendGraphDataType::Text),
+			],
+			properties: node_properties::fetch_url_properties,
+			..Default::default()
+		},
+		#[cfg(feature = "fetch")]
+		DocumentNodeDefinition {
+			name: "Fetch Text",
+			category: "Structural",
+			implementation: DocumentNodeImplementation::Network(NodeNetwork {
+				imports: vec![NodeId(0), NodeId(0), NodeId(0)],
+				exports: vec![NodeOutput::new(NodeId(3), 0), NodeOutput::new(NodeId(2), 0)],
+				nodes: [
+					DocumentNode {
+						name: "Fetch URL".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(())), NodeInput::Network(concrete!(String)), NodeInput::Network(concrete!(f64))],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::fetch::FetchUrlNode<_, _>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Data".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::FirstOfPairNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Error".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::SecondOfPairNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Decode Text".to_string(),
+						inputs: vec![NodeInput::node(NodeId(1), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::watch_file::DecodeFileTextNode")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (NodeId(id as u64), node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType::none(),
+				DocumentInputType::value("URL", TaggedValue::String(String::new()), false),
+				DocumentInputType::value("Refresh", TaggedValue::F64(0.), false),
+			],
+			outputs: vec![
+				DocumentOutputType::new("Text", FrontendGraphDataType::Text),
+				DocumentOutputType::new("Error", FrontendGraphDataType::Text),
+			],
+			properties: node_properties::fetch_url_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Create Canvas",
+			category: "Structural",
+			implementation: DocumentNodeImplementation::Network(NodeNetwork {
+				imports: vec![NodeId(0)],
+				exports: vec![NodeOutput::new(NodeId(1), 0)],
+				nodes: [
+					DocumentNode {
+						name: "Create Canvas".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(WasmEditorApi))],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::wasm_application_io::CreateSurfaceNode")),
+						skip_deduplication: true,
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Cache".to_string(),
+						manual_composition: Some(concrete!(())),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::memo::MemoNode<_, _>")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (NodeId(id as u64), node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![DocumentInputType {
+				name: "In",
+				data_type: FrontendGraphDataType::General,
+				default: NodeInput::Network(concrete!(WasmEditorApi)),
+			}],
+			outputs: vec![DocumentOutputType {
+				name: "Canvas",
+				data_type: FrontendGraphDataType::General,
+			}],
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Draw Canvas",
+			category: "Structural",
+			implementation: DocumentNodeImplementation::Network(NodeNetwork {
+				imports: vec![NodeId(0), NodeId(2)],
+				exports: vec![NodeOutput::new(NodeId(3), 0)],
+				nodes: [
+					DocumentNode {
+						name: "Convert Image Frame".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(ImageFrame<Color>))],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::IntoNode<_, ImageFrame<SRGBA8>>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Create Canvas".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(WasmEditorApi))],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::wasm_application_io::CreateSurfaceNode")),
+						skip_deduplication: true,
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Cache".to_string(),
+						manual_composition: Some(concrete!(())),
+						inputs: vec![NodeInput::node(NodeId(1), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::memo::MemoNode<_, _>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Draw Canvas".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0), NodeInput::node(NodeId(2), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::wasm_application_io::DrawImageFrameNode<_>")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (NodeId(id as u64), node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType {
+					name: "In",
+					data_type: FrontendGraphDataType::Raster,
 					default: NodeInput::value(TaggedValue::ImageFrame(ImageFrame::empty()), true),
 				},
 				DocumentInputType {
@@ -996,6 +1491,25 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 			}],
 			..Default::default()
 		},
+		DocumentNodeDefinition {
+			name: "Brush Along Path",
+			category: "Brush",
+			implementation: DocumentNodeImplementation::proto("graphene_std::brush::BrushAlongPathNode<_, _, _, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(VectorData::empty()), true),
+				DocumentInputType::value("Tip", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Size", TaggedValue::F64(20.), false),
+				DocumentInputType::value("Spacing", TaggedValue::F64(25.), false),
+				DocumentInputType::value("Scatter", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Rotation Jitter", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Seed", TaggedValue::U32(0), false),
+				DocumentInputType::value("Pressure Attribute", TaggedValue::String("pressure".to_string()), false),
+				DocumentInputType::value("Rasterize", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::brush_along_path_properties,
+			..Default::default()
+		},
 		DocumentNodeDefinition {
 			name: "Extract Vector Points",
 			category: "Brush",
@@ -2372,6 +2886,52 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 			properties: node_properties::line_properties,
 			..Default::default()
 		},
+		DocumentNodeDefinition {
+			name: "Grid",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::Network(NodeNetwork {
+				imports: vec![NodeId(0), NodeId(0), NodeId(0), NodeId(0), NodeId(0), NodeId(0)],
+				exports: vec![NodeOutput::new(NodeId(1), 0)],
+				nodes: vec![
+					DocumentNode {
+						name: "Grid Generator".to_string(),
+						inputs: vec![
+							NodeInput::Network(concrete!(())),
+							NodeInput::Network(concrete!(graphene_core::vector::generator_nodes::GridType)),
+							NodeInput::Network(concrete!(f64)),
+							NodeInput::Network(concrete!(u32)),
+							NodeInput::Network(concrete!(u32)),
+							NodeInput::Network(concrete!(bool)),
+						],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::vector::generator_nodes::GridNode<_, _, _, _, _>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Cull".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::transform::CullNode<_>")),
+						manual_composition: Some(concrete!(Footprint)),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (NodeId(id as u64), node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType::none(),
+				DocumentInputType::value("Grid Type", TaggedValue::GridType(graphene_core::vector::generator_nodes::GridType::Square), false),
+				DocumentInputType::value("Spacing", TaggedValue::F64(20.), false),
+				DocumentInputType::value("Columns", TaggedValue::U32(10), false),
+				DocumentInputType::value("Rows", TaggedValue::U32(10), false),
+				DocumentInputType::value("Points", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::grid_properties,
+			..Default::default()
+		},
 		DocumentNodeDefinition {
 			name: "Spline",
 			category: "Vector",
@@ -2454,46 +3014,1051 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Mandelbrot",
-			category: "Generators",
-			implementation: DocumentNodeImplementation::proto("graphene_std::raster::MandelbrotNode"),
-			manual_composition: Some(concrete!(Footprint)),
-			inputs: vec![],
-			outputs: vec![DocumentOutputType::new("Raster", FrontendGraphDataType::Raster)],
+			name: "Rasterize",
+			category: "Structural",
+			implementation: DocumentNodeImplementation::proto("graphene_std::rasterize::RasterizeNode<_>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true)],
+			outputs: vec![DocumentOutputType::new("Raster", FrontendGraphDataType::Raster)],
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Mandelbrot",
+			category: "Generators",
+			implementation: DocumentNodeImplementation::proto("graphene_std::raster::MandelbrotNode"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![],
+			outputs: vec![DocumentOutputType::new("Raster", FrontendGraphDataType::Raster)],
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Cull",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::transform::CullNode<_>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(VectorData::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Text",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::Network(NodeNetwork {
+				imports: vec![
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+					NodeId(0),
+				],
+				exports: vec![NodeOutput::new(NodeId(1), 0)],
+				nodes: vec![
+					DocumentNode {
+						name: "Text Generator".to_string(),
+						inputs: vec![
+							NodeInput::Network(concrete!(application_io::EditorApi<graphene_std::wasm_application_io::WasmApplicationIo>)),
+							NodeInput::Network(concrete!(String)),
+							NodeInput::Network(concrete!(graphene_core::text::Font)),
+							NodeInput::Network(concrete!(f64)),
+							NodeInput::Network(concrete!(graphene_core::text::Font)),
+							NodeInput::Network(concrete!(graphene_core::text::Font)),
+							NodeInput::Network(concrete!(graphene_core::text::MissingGlyph)),
+							NodeInput::Network(concrete!(bool)),
+							NodeInput::Network(concrete!(bool)),
+							NodeInput::Network(concrete!(bool)),
+							NodeInput::Network(concrete!(u32)),
+							NodeInput::Network(concrete!(f64)),
+							NodeInput::Network(concrete!(graphene_core::text::TextAlign)),
+							NodeInput::Network(concrete!(graphene_core::text::Hyphenation)),
+							NodeInput::Network(concrete!(f64)),
+							NodeInput::Network(concrete!(f64)),
+							NodeInput::Network(concrete!(VectorData)),
+							NodeInput::Network(concrete!(VectorData)),
+							NodeInput::Network(concrete!(f64)),
+							NodeInput::Network(concrete!(f64)),
+							NodeInput::Network(concrete!(u32)),
+						],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new(
+							"graphene_core::text::TextGeneratorNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>",
+						)),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Cull".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::transform::CullNode<_>")),
+						manual_composition: Some(concrete!(Footprint)),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (NodeId(id as u64), node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType::none(),
+				DocumentInputType::value("Text", TaggedValue::String("Lorem ipsum".to_string()), false),
+				DocumentInputType::value("Font", TaggedValue::Font(Font::new(DEFAULT_FONT_FAMILY.into(), DEFAULT_FONT_STYLE.into())), false),
+				DocumentInputType::value("Size", TaggedValue::F64(24.), false),
+				DocumentInputType::value("Fallback Font", TaggedValue::Font(Font::none()), false),
+				DocumentInputType::value("Second Fallback Font", TaggedValue::Font(Font::none()), false),
+				DocumentInputType::value("Missing Glyph", TaggedValue::MissingGlyph(graphene_core::text::MissingGlyph::Notdef), false),
+				DocumentInputType::value("Ligatures", TaggedValue::Bool(true), false),
+				DocumentInputType::value("Small Caps", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Tabular Numbers", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Stylistic Set", TaggedValue::U32(0), false),
+				DocumentInputType::value("Line Width", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Align", TaggedValue::TextAlign(graphene_core::text::TextAlign::Left), false),
+				DocumentInputType::value("Hyphenation", TaggedValue::Hyphenation(graphene_core::text::Hyphenation::Off), false),
+				DocumentInputType::value("Max Word Spacing", TaggedValue::F64(100.), false),
+				DocumentInputType::value("Max Letter Spacing", TaggedValue::F64(5.), false),
+				DocumentInputType::value("Frame", TaggedValue::VectorData(VectorData::empty()), true),
+				DocumentInputType::value("Exclusions", TaggedValue::VectorData(VectorData::empty()), true),
+				DocumentInputType::value("Frame Padding", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Exclusion Margin", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Drop Cap Lines", TaggedValue::U32(0), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::node_section_font,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Figma/Sketch Import",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::Network(NodeNetwork {
+				imports: vec![NodeId(0), NodeId(0)],
+				exports: vec![NodeOutput::new(NodeId(1), 0)],
+				nodes: vec![
+					DocumentNode {
+						name: "Design Import".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(())), NodeInput::Network(concrete!(String))],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::design_import::DesignImportNode<_>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Cull".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::transform::CullNode<_>")),
+						manual_composition: Some(concrete!(Footprint)),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (NodeId(id as u64), node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![DocumentInputType::none(), DocumentInputType::value("JSON", TaggedValue::String(String::new()), false)],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::design_import_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Transform",
+			category: "Transform",
+			implementation: DocumentNodeImplementation::Network(NodeNetwork {
+				imports: vec![NodeId(0), NodeId(1), NodeId(1), NodeId(1), NodeId(1), NodeId(1)],
+				exports: vec![NodeOutput::new(NodeId(1), 0)],
+				nodes: [
+					DocumentNode {
+						inputs: vec![NodeInput::Network(concrete!(VectorData))],
+						..monitor_node()
+					},
+					DocumentNode {
+						name: "Transform".to_string(),
+						inputs: vec![
+							NodeInput::node(NodeId(0), 0),
+							NodeInput::Network(concrete!(DVec2)),
+							NodeInput::Network(concrete!(f64)),
+							NodeInput::Network(concrete!(DVec2)),
+							NodeInput::Network(concrete!(DVec2)),
+							NodeInput::Network(concrete!(DVec2)),
+						],
+						manual_composition: Some(concrete!(Footprint)),
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::transform::TransformNode<_, _, _, _, _, _>")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (NodeId(id as u64), node))
+				.collect(),
+				..Default::default()
+			}),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(VectorData::empty()), true),
+				DocumentInputType::value("Translation", TaggedValue::DVec2(DVec2::ZERO), false),
+				DocumentInputType::value("Rotation", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Scale", TaggedValue::DVec2(DVec2::ONE), false),
+				DocumentInputType::value("Skew", TaggedValue::DVec2(DVec2::ZERO), false),
+				DocumentInputType::value("Pivot", TaggedValue::DVec2(DVec2::splat(0.5)), false),
+			],
+			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::Subpath)],
+			properties: node_properties::transform_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "SetTransform",
+			category: "Transform",
+			implementation: DocumentNodeImplementation::proto("graphene_core::transform::SetTransformNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), true),
+			],
+			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::Subpath)],
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Fill",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SetFillNode<_, _, _, _, _, _, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Fill Type", TaggedValue::FillType(vector::style::FillType::Solid), false),
+				DocumentInputType::value("Solid Color", TaggedValue::OptionalColor(None), false),
+				DocumentInputType::value("Gradient Type", TaggedValue::GradientType(vector::style::GradientType::Linear), false),
+				DocumentInputType::value("Start", TaggedValue::DVec2(DVec2::new(0., 0.5)), false),
+				DocumentInputType::value("End", TaggedValue::DVec2(DVec2::new(1., 0.5)), false),
+				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), false),
+				DocumentInputType::value("Positions", TaggedValue::GradientPositions(vec![(0., Color::BLACK), (1., Color::WHITE)]), false),
+				DocumentInputType::value("Focal Point", TaggedValue::OptionalDVec2(None), false),
+				DocumentInputType::value("Fill Rule", TaggedValue::FillRule(vector::style::FillRule::NonZero), false),
+				DocumentInputType::value("Blend Mode", TaggedValue::BlendMode(BlendMode::Normal), false),
+				DocumentInputType::value("Opacity", TaggedValue::F64(100.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::fill_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Stroke",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SetStrokeNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Color", TaggedValue::OptionalColor(Some(Color::BLACK)), false),
+				DocumentInputType::value("Weight", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Dash Lengths", TaggedValue::VecF64(Vec::new()), false),
+				DocumentInputType::value("Dash Offset", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Line Cap", TaggedValue::LineCap(graphene_core::vector::style::LineCap::Butt), false),
+				DocumentInputType::value("Line Join", TaggedValue::LineJoin(graphene_core::vector::style::LineJoin::Miter), false),
+				DocumentInputType::value("Miter Limit", TaggedValue::F64(4.), false),
+				DocumentInputType::value("Alignment", TaggedValue::StrokeAlignment(graphene_core::vector::style::StrokeAlignment::Center), false),
+				DocumentInputType::value("Paint Type", TaggedValue::FillType(vector::style::FillType::Solid), false),
+				DocumentInputType::value("Gradient Type", TaggedValue::GradientType(vector::style::GradientType::Linear), false),
+				DocumentInputType::value("Start", TaggedValue::DVec2(DVec2::new(0., 0.5)), false),
+				DocumentInputType::value("End", TaggedValue::DVec2(DVec2::new(1., 0.5)), false),
+				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), false),
+				DocumentInputType::value("Positions", TaggedValue::GradientPositions(vec![(0., Color::BLACK), (1., Color::WHITE)]), false),
+				DocumentInputType::value("Focal Point", TaggedValue::OptionalDVec2(None), false),
+				DocumentInputType::value("Blend Mode", TaggedValue::BlendMode(BlendMode::Normal), false),
+				DocumentInputType::value("Opacity", TaggedValue::F64(100.), false),
+				DocumentInputType::value("Hairline", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::stroke_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Stroke Gradient",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SetStrokeGradientNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Positions", TaggedValue::GradientPositions(vec![(0., Color::TRANSPARENT), (1., Color::BLACK)]), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::stroke_gradient_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Stroke Width Profile",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::StrokeWidthProfileNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Widths", TaggedValue::VecF64(vec![0., 1., 0.]), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::stroke_width_profile_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Stroke Dashes",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::StrokeDashesNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Dash Cap", TaggedValue::LineCap(graphene_core::vector::style::LineCap::Butt), false),
+				DocumentInputType::value("Scale With Weight", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Align to Corners", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::stroke_dashes_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Fill and Stroke Opacity",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::FillStrokeOpacityNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Fill Opacity", TaggedValue::F64(100.), false),
+				DocumentInputType::value("Stroke Opacity", TaggedValue::F64(100.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::fill_stroke_opacity_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Gradient Interpolation",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::GradientInterpolationNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Color Space", TaggedValue::GradientInterpolation(vector::style::GradientInterpolation::Oklab), false),
+				DocumentInputType::value("Hue", TaggedValue::HueInterpolation(vector::style::HueInterpolation::Shorter), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::gradient_interpolation_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Color Space",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::ColorSpaceNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Color Space", TaggedValue::ColorSpace(graphene_core::raster::color::ColorSpace::DisplayP3), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::color_space_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Adjust Fill Color",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::AdjustFillColorNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Hue Shift", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Saturation Shift", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Lightness Shift", TaggedValue::F64(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::adjust_hsl_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Copy Style",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::CopyStyleNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Source", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Define Style",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::DefineStyleNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Library", TaggedValue::StyleLibrary(Default::default()), true),
+				DocumentInputType::value("Name", TaggedValue::String("Style".to_string()), false),
+				DocumentInputType::value("Source", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+			],
+			outputs: vec![DocumentOutputType::new("Library", FrontendGraphDataType::General)],
+			properties: node_properties::define_style_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Apply Style",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::ApplyStyleNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Library", TaggedValue::StyleLibrary(Default::default()), true),
+				DocumentInputType::value("Name", TaggedValue::String("Style".to_string()), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::apply_style_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Add Fill Layer",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::AddFillLayerNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Color", TaggedValue::OptionalColor(Some(Color::BLACK)), false),
+				DocumentInputType::value("Blend Mode", TaggedValue::BlendMode(BlendMode::Normal), false),
+				DocumentInputType::value("Opacity", TaggedValue::F64(100.), false),
+				DocumentInputType::value("Index", TaggedValue::F64(-1.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::add_fill_layer_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Add Stroke Layer",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::AddStrokeLayerNode<_, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Color", TaggedValue::OptionalColor(Some(Color::BLACK)), false),
+				DocumentInputType::value("Weight", TaggedValue::F64(2.), false),
+				DocumentInputType::value("Blend Mode", TaggedValue::BlendMode(BlendMode::Normal), false),
+				DocumentInputType::value("Opacity", TaggedValue::F64(100.), false),
+				DocumentInputType::value("Index", TaggedValue::F64(-1.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::add_stroke_layer_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Remove Paint Layer",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::RemovePaintLayerNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Index", TaggedValue::F64(-1.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::remove_paint_layer_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Pattern Fill",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SetPatternFillNode<_, _, _, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Tile", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Spacing", TaggedValue::DVec2(DVec2::splat(20.)), false),
+				DocumentInputType::value("Rotation", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Scale", TaggedValue::DVec2(DVec2::ONE), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::pattern_fill_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Image Fill",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SetImageFillNode<_, _, _, _, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Mode", TaggedValue::ImageFillMode(graphene_core::vector::style::ImageFillMode::Fill), false),
+				DocumentInputType::value("Offset", TaggedValue::DVec2(DVec2::ZERO), false),
+				DocumentInputType::value("Rotation", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Scale", TaggedValue::DVec2(DVec2::ONE), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::image_fill_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Bounding Box",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::BoundingBoxNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Oriented", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Per Subpath", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Padding", TaggedValue::F64(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::bounding_box_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Solidify Stroke",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SolidifyStrokeNode"),
+			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::node_no_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Repeat",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::RepeatNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Instance", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Direction", TaggedValue::DVec2((100., 0.).into()), false),
+				DocumentInputType::value("Count", TaggedValue::U32(10), false),
+				DocumentInputType::value("Rotation", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Scale", TaggedValue::F64(1.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::repeat_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Circular Repeat",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::CircularRepeatNode<_, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Instance", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Angle Offset", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Radius", TaggedValue::F64(5.), false),
+				DocumentInputType::value("Count", TaggedValue::U32(10), false),
+				DocumentInputType::value("Pivot", TaggedValue::OptionalDVec2(None), false),
+				DocumentInputType::value("Keep Upright", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::circular_repeat_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Pattern Tile",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::Network(NodeNetwork {
+				imports: vec![NodeId(0), NodeId(0), NodeId(0), NodeId(0), NodeId(0)],
+				exports: vec![NodeOutput::new(NodeId(1), 0), NodeOutput::new(NodeId(2), 0)],
+				nodes: [
+					DocumentNode {
+						name: "Pattern Tile".to_string(),
+						inputs: vec![
+							NodeInput::Network(concrete!(VectorData)),
+							NodeInput::Network(concrete!(graphene_core::vector::WallpaperGroup)),
+							NodeInput::Network(concrete!(DVec2)),
+							NodeInput::Network(concrete!(u32)),
+							NodeInput::Network(concrete!(u32)),
+						],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::vector::PatternTileNode<_, _, _, _>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Tile".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::FirstOfPairNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Preview".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::SecondOfPairNode")),
+						..Default::default()
+					},
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (NodeId(id as u64), node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType::value("Motif", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Group", TaggedValue::WallpaperGroup(graphene_core::vector::WallpaperGroup::P4m), false),
+				DocumentInputType::value("Size", TaggedValue::DVec2(DVec2::splat(100.)), false),
+				DocumentInputType::value("Columns", TaggedValue::U32(4), false),
+				DocumentInputType::value("Rows", TaggedValue::U32(4), false),
+			],
+			outputs: vec![
+				DocumentOutputType::new("Tile", FrontendGraphDataType::Subpath),
+				DocumentOutputType::new("Preview", FrontendGraphDataType::Subpath),
+			],
+			properties: node_properties::pattern_tile_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Sticker Outline",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::StickerOutlineNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Distance", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Color", TaggedValue::Color(Color::WHITE), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::sticker_outline_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Technical Drawing",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::TechnicalDrawingNode<_, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Dimension Offset", TaggedValue::F64(20.), false),
+				DocumentInputType::value("Hatch Spacing", TaggedValue::F64(8.), false),
+				DocumentInputType::value("Hatch Angle", TaggedValue::F64(45.), false),
+				DocumentInputType::value("Title", TaggedValue::String("Untitled".to_string()), false),
+				DocumentInputType::value("Color", TaggedValue::Color(Color::BLACK), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::technical_drawing_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "To Instances",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::ToInstancesNode"),
+			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Instances", FrontendGraphDataType::Subpath)],
+			properties: node_properties::node_no_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Flatten Instances",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::FlattenInstancesNode"),
+			inputs: vec![DocumentInputType::value(
+				"Instances",
+				TaggedValue::VectorInstances(graphene_core::vector::VectorInstances::default()),
+				true,
+			)],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::node_no_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Copy to Points",
+			category: "Vector",
+			// TODO: Wrap this implementation with a document node that has a cache node so the output is cached?
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::CopyToPoints<_, _, _, _, _, _, _, _, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Points", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Instance", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Random Scale Min", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Random Scale Max", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Random Scale Bias", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Random Rotation Min", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Random Rotation Max", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Random Seed", TaggedValue::U32(0), false),
+				DocumentInputType::value("Align to Path", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Instance Variants", TaggedValue::VariantSelection(graphene_core::vector::VariantSelection::Whole), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::copy_to_points_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Sample Points",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::Network(NodeNetwork {
+				imports: vec![NodeId(0), NodeId(2), NodeId(2), NodeId(2), NodeId(2)], // First is given to Identity, the rest are given to Sample Points
+				exports: vec![NodeOutput::new(NodeId(2), 0)],                         // Taken from output 0 of Sample Points
+				nodes: [
+					DocumentNode {
+						name: "Identity".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(graphene_core::vector::VectorData))], // From the document node's parameters
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::IdentityNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Lengths of Segments of Subpaths".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)], // From output 0 of Identity
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::vector::LengthsOfSegmentsOfSubpaths")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Sample Points".to_string(),
+						inputs: vec![
+							NodeInput::node(NodeId(0), 0),       // From output 0 of Identity
+							NodeInput::Network(concrete!(f64)),  // From the document node's parameters
+							NodeInput::Network(concrete!(f64)),  // From the document node's parameters
+							NodeInput::Network(concrete!(f64)),  // From the document node's parameters
+							NodeInput::Network(concrete!(bool)), // From the document node's parameters
+							NodeInput::node(NodeId(1), 0),       // From output 0 of Lengths of Segments of Subpaths
+						],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::vector::SamplePoints<_, _, _, _, _, _>")),
+						manual_composition: Some(concrete!(Footprint)),
+						..Default::default()
+					},
+					// TODO: Add a cache node here?
+				]
+				.into_iter()
+				.enumerate()
+				.map(|(id, node)| (NodeId(id as u64), node))
+				.collect(),
+				..Default::default()
+			}),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Spacing", TaggedValue::F64(100.), false),
+				DocumentInputType::value("Start Offset", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Stop Offset", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Adaptive Spacing", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::sample_points_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Poisson-Disk Points",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::PoissonDiskPoints<_>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Separation Disk Diameter", TaggedValue::F64(10.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::poisson_disk_points_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Points in Bounds",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::PointsInBoundsNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Spacing", TaggedValue::DVec2(DVec2::splat(20.)), false),
+				DocumentInputType::value("Inset", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Stagger", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Inside Shape", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::points_in_bounds_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Hatch Fill",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::HatchFillNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Angle", TaggedValue::F64(45.), false),
+				DocumentInputType::value("Spacing", TaggedValue::F64(5.), false),
+				DocumentInputType::value("Cross Hatch", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::hatch_fill_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Hatch Shading",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::HatchShadingNode<_, _, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Light Direction", TaggedValue::F64(-135.), false),
+				DocumentInputType::value("Angle", TaggedValue::F64(45.), false),
+				DocumentInputType::value("Min Spacing", TaggedValue::F64(1.5), false),
+				DocumentInputType::value("Max Spacing", TaggedValue::F64(12.), false),
+				DocumentInputType::value("Cross Hatch", TaggedValue::F64(0.7), false),
+				DocumentInputType::value("Jitter", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Seed", TaggedValue::U32(0), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::hatch_shading_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Extrude",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::ExtrudeNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Direction", TaggedValue::DVec2(DVec2::new(20., -20.)), false),
+				DocumentInputType::value("Shading", TaggedValue::F64(0.5), false),
+				DocumentInputType::value("Side Color", TaggedValue::OptionalColor(None), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::extrude_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Mosaic",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::MosaicNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Graphic Group", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Cell Shape", TaggedValue::MosaicCell(graphene_core::vector::MosaicCell::Square), false),
+				DocumentInputType::value("Cell Size", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Gap", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Levels", TaggedValue::U32(0), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::mosaic_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Medial Axis",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::MedialAxisNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Spacing", TaggedValue::F64(2.), false),
+				DocumentInputType::value("Pruning", TaggedValue::F64(2.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::medial_axis_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Tangent and Normal Lines",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::TangentNormalLinesNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Spacing", TaggedValue::F64(20.), false),
+				DocumentInputType::value("Length", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Normal", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::tangent_normal_lines_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Pathfind",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::PathfindNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Obstacles", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Start", TaggedValue::DVec2(DVec2::ZERO), false),
+				DocumentInputType::value("End", TaggedValue::DVec2(DVec2::new(200., 0.)), false),
+				DocumentInputType::value("Clearance", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Corner Radius", TaggedValue::F64(10.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::pathfind_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Filter Subpaths",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::FilterSubpathsNode<_, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Measure", TaggedValue::SubpathMeasure(graphene_core::vector::SubpathMeasure::Area), false),
+				DocumentInputType::value("Minimum", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Maximum", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Invert", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Order", TaggedValue::SubpathOrder(graphene_core::vector::SubpathOrder::Original), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::filter_subpaths_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Get Subpath",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::GetSubpathNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Index", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Count", TaggedValue::U32(1), false),
+				DocumentInputType::value("Out of Range", TaggedValue::IndexOutOfRange(graphene_core::vector::IndexOutOfRange::Skip), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::get_subpath_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Style Subpaths",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::StyleSubpathsNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Style", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Index", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Count", TaggedValue::U32(1), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::style_subpaths_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Style Subpaths by Measure",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::StyleSubpathsByMeasureNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Style", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Measure", TaggedValue::SubpathMeasure(graphene_core::vector::SubpathMeasure::Area), false),
+				DocumentInputType::value("Minimum", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Maximum", TaggedValue::F64(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::style_subpaths_by_measure_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "To CSS Clip Path",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::ToCssClipPathNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Format", TaggedValue::ClipPathFormat(graphene_core::vector::ClipPathFormat::Path), false),
+				DocumentInputType::value("Precision", TaggedValue::U32(2), false),
+				DocumentInputType::value("Normalize", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("CSS", FrontendGraphDataType::Text)],
+			properties: node_properties::to_css_clip_path_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Generate Path Code",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::GeneratePathCodeNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Language", TaggedValue::PathCodeLanguage(graphene_core::vector::PathCodeLanguage::Canvas), false),
+				DocumentInputType::value("Precision", TaggedValue::U32(2), false),
+			],
+			outputs: vec![DocumentOutputType::new("Code", FrontendGraphDataType::Text)],
+			properties: node_properties::generate_path_code_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Weld Points",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::WeldPointsNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Distance", TaggedValue::F64(0.1), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::weld_points_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Snap to Grid",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SnapToGridNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Spacing", TaggedValue::DVec2(DVec2::ONE), false),
+				DocumentInputType::value("Origin", TaggedValue::DVec2(DVec2::ZERO), false),
+				DocumentInputType::value("Isometric", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Snap Handles", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::snap_to_grid_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Icon Keyline",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::IconKeylineNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Keyline", TaggedValue::IconKeyline(graphene_core::vector::IconKeyline::Auto), false),
+				DocumentInputType::value("Grid Size", TaggedValue::F64(24.), false),
+				DocumentInputType::value("Overshoot", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Snap to Pixels", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::icon_keyline_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Splines from Points",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SplinesFromPointsNode"),
+			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::node_no_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Freeze",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::FreezeNode"),
+			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::node_no_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Set Handle Mirroring",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SetHandleMirroringNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Colinear", TaggedValue::Bool(true), false),
+				DocumentInputType::value("Angle Tolerance", TaggedValue::F64(10.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::set_handle_mirroring_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Fit Curve",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::FitCurveNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Tolerance", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Closed", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::fit_curve_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Cull",
+			name: "Variable Offset",
 			category: "Vector",
-			implementation: DocumentNodeImplementation::proto("graphene_core::transform::CullNode<_>"),
-			manual_composition: Some(concrete!(Footprint)),
-			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(VectorData::empty()), true)],
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::VariableOffsetNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Distance", TaggedValue::F64(5.), false),
+				DocumentInputType::value("Attribute", TaggedValue::String("width".to_string()), false),
+				DocumentInputType::value("Taper", TaggedValue::Bool(true), false),
+				DocumentInputType::value("Envelope", TaggedValue::Curve(Default::default()), false),
+			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::variable_offset_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Text",
+			name: "Closest Point",
 			category: "Vector",
 			implementation: DocumentNodeImplementation::Network(NodeNetwork {
-				imports: vec![NodeId(0), NodeId(0), NodeId(0), NodeId(0)],
-				exports: vec![NodeOutput::new(NodeId(1), 0)],
-				nodes: vec![
+				imports: vec![NodeId(0), NodeId(0)],
+				exports: vec![NodeOutput::new(NodeId(1), 0), NodeOutput::new(NodeId(2), 0), NodeOutput::new(NodeId(3), 0)],
+				nodes: [
 					DocumentNode {
-						name: "Text Generator".to_string(),
-						inputs: vec![
-							NodeInput::Network(concrete!(application_io::EditorApi<graphene_std::wasm_application_io::WasmApplicationIo>)),
-							NodeInput::Network(concrete!(String)),
-							NodeInput::Network(concrete!(graphene_core::text::Font)),
-							NodeInput::Network(concrete!(f64)),
-						],
-						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::text::TextGeneratorNode<_, _, _>")),
+						name: "Closest Point".to_string(),
+						inputs: vec![NodeInput::Network(concrete!(VectorData)), NodeInput::Network(concrete!(DVec2))],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::vector::ClosestPointNode<_>")),
 						..Default::default()
 					},
 					DocumentNode {
-						name: "Cull".to_string(),
+						name: "Point".to_string(),
 						inputs: vec![NodeInput::node(NodeId(0), 0)],
-						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::transform::CullNode<_>")),
-						manual_composition: Some(concrete!(Footprint)),
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::FirstOfTripleNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "T-Value".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::SecondOfTripleNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Distance".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::ThirdOfTripleNode")),
 						..Default::default()
 					},
 				]
@@ -2504,38 +4069,50 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 				..Default::default()
 			}),
 			inputs: vec![
-				DocumentInputType::none(),
-				DocumentInputType::value("Text", TaggedValue::String("Lorem ipsum".to_string()), false),
-				DocumentInputType::value("Font", TaggedValue::Font(Font::new(DEFAULT_FONT_FAMILY.into(), DEFAULT_FONT_STYLE.into())), false),
-				DocumentInputType::value("Size", TaggedValue::F64(24.), false),
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Position", TaggedValue::DVec2(DVec2::ZERO), false),
 			],
-			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::node_section_font,
+			outputs: vec![
+				DocumentOutputType::new("Point", FrontendGraphDataType::Vector),
+				DocumentOutputType::new("T-Value", FrontendGraphDataType::Number),
+				DocumentOutputType::new("Distance", FrontendGraphDataType::Number),
+			],
+			properties: node_properties::closest_point_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Transform",
-			category: "Transform",
+			name: "Detect Symmetry",
+			category: "Vector",
 			implementation: DocumentNodeImplementation::Network(NodeNetwork {
-				imports: vec![NodeId(0), NodeId(1), NodeId(1), NodeId(1), NodeId(1), NodeId(1)],
-				exports: vec![NodeOutput::new(NodeId(1), 0)],
+				imports: vec![NodeId(0), NodeId(0), NodeId(0)],
+				exports: vec![NodeOutput::new(NodeId(1), 0), NodeOutput::new(NodeId(2), 0), NodeOutput::new(NodeId(3), 0)],
 				nodes: [
 					DocumentNode {
-						inputs: vec![NodeInput::Network(concrete!(VectorData))],
-						..monitor_node()
-					},
-					DocumentNode {
-						name: "Transform".to_string(),
+						name: "Detect Symmetry".to_string(),
 						inputs: vec![
-							NodeInput::node(NodeId(0), 0),
-							NodeInput::Network(concrete!(DVec2)),
-							NodeInput::Network(concrete!(f64)),
-							NodeInput::Network(concrete!(DVec2)),
-							NodeInput::Network(concrete!(DVec2)),
-							NodeInput::Network(concrete!(DVec2)),
+							NodeInput::Network(concrete!(VectorData)),
+							NodeInput::Network(concrete!(graphene_core::vector::Symmetry)),
+							NodeInput::Network(concrete!(u32)),
 						],
-						manual_composition: Some(concrete!(Footprint)),
-						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::transform::TransformNode<_, _, _, _, _, _>")),
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::vector::DetectSymmetryNode<_, _>")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Symmetrized".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::FirstOfTripleNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Center".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::SecondOfTripleNode")),
+						..Default::default()
+					},
+					DocumentNode {
+						name: "Angle".to_string(),
+						inputs: vec![NodeInput::node(NodeId(0), 0)],
+						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::ThirdOfTripleNode")),
 						..Default::default()
 					},
 				]
@@ -2545,215 +4122,201 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 				.collect(),
 				..Default::default()
 			}),
-			manual_composition: Some(concrete!(Footprint)),
 			inputs: vec![
-				DocumentInputType::value("Vector Data", TaggedValue::VectorData(VectorData::empty()), true),
-				DocumentInputType::value("Translation", TaggedValue::DVec2(DVec2::ZERO), false),
-				DocumentInputType::value("Rotation", TaggedValue::F64(0.), false),
-				DocumentInputType::value("Scale", TaggedValue::DVec2(DVec2::ONE), false),
-				DocumentInputType::value("Skew", TaggedValue::DVec2(DVec2::ZERO), false),
-				DocumentInputType::value("Pivot", TaggedValue::DVec2(DVec2::splat(0.5)), false),
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Symmetry", TaggedValue::Symmetry(graphene_core::vector::Symmetry::Reflection), false),
+				DocumentInputType::value("Max Order", TaggedValue::U32(8), false),
 			],
-			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::Subpath)],
-			properties: node_properties::transform_properties,
+			outputs: vec![
+				DocumentOutputType::new("Symmetrized", FrontendGraphDataType::Subpath),
+				DocumentOutputType::new("Center", FrontendGraphDataType::Vector),
+				DocumentOutputType::new("Angle", FrontendGraphDataType::Number),
+			],
+			properties: node_properties::detect_symmetry_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "SetTransform",
-			category: "Transform",
-			implementation: DocumentNodeImplementation::proto("graphene_core::transform::SetTransformNode<_>"),
+			name: "Intersection Points",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::IntersectionPointsNode<_, _>"),
 			inputs: vec![
-				DocumentInputType::value("Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), true),
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Other", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
 			],
-			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::Subpath)],
+			outputs: vec![DocumentOutputType::new("Points", FrontendGraphDataType::Subpath)],
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::node_no_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Fill",
+			name: "Shape Deviation",
 			category: "Vector",
-			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SetFillNode<_, _, _, _, _, _, _>"),
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::ShapeDeviationNode<_, _, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Fill Type", TaggedValue::FillType(vector::style::FillType::Solid), false),
-				DocumentInputType::value("Solid Color", TaggedValue::OptionalColor(None), false),
-				DocumentInputType::value("Gradient Type", TaggedValue::GradientType(vector::style::GradientType::Linear), false),
-				DocumentInputType::value("Start", TaggedValue::DVec2(DVec2::new(0., 0.5)), false),
-				DocumentInputType::value("End", TaggedValue::DVec2(DVec2::new(1., 0.5)), false),
-				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), false),
-				DocumentInputType::value("Positions", TaggedValue::GradientPositions(vec![(0., Color::BLACK), (1., Color::WHITE)]), false),
+				DocumentInputType::value("Reference", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Spacing", TaggedValue::F64(5.), false),
+				DocumentInputType::value("Tolerance", TaggedValue::F64(2.), false),
+				DocumentInputType::value("Weight", TaggedValue::F64(2.), false),
 			],
-			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::fill_properties,
+			outputs: vec![DocumentOutputType::new("Heat Map", FrontendGraphDataType::GraphicGroup)],
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::shape_deviation_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Stroke",
+			name: "Clip to Viewport",
 			category: "Vector",
-			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SetStrokeNode<_, _, _, _, _, _, _>"),
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::ClipToViewportNode<_, _>"),
 			inputs: vec![
 				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Color", TaggedValue::OptionalColor(Some(Color::BLACK)), false),
-				DocumentInputType::value("Weight", TaggedValue::F64(0.), false),
-				DocumentInputType::value("Dash Lengths", TaggedValue::VecF64(Vec::new()), false),
-				DocumentInputType::value("Dash Offset", TaggedValue::F64(0.), false),
-				DocumentInputType::value("Line Cap", TaggedValue::LineCap(graphene_core::vector::style::LineCap::Butt), false),
-				DocumentInputType::value("Line Join", TaggedValue::LineJoin(graphene_core::vector::style::LineJoin::Miter), false),
-				DocumentInputType::value("Miter Limit", TaggedValue::F64(4.), false),
+				DocumentInputType::value("Margin", TaggedValue::F64(10.), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::stroke_properties,
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::clip_to_viewport_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Bounding Box",
+			name: "Scale Preserving Corners",
 			category: "Vector",
-			implementation: DocumentNodeImplementation::proto("graphene_core::vector::BoundingBoxNode"),
-			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::ScalePreservingCornersNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Scale", TaggedValue::DVec2(DVec2::ONE), false),
+			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::node_no_properties,
+			properties: node_properties::scale_preserving_corners_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Solidify Stroke",
+			name: "Align to Reference",
 			category: "Vector",
-			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SolidifyStrokeNode"),
-			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::AlignToReferenceNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Reference", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Scale", TaggedValue::Bool(true), false),
+			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::node_no_properties,
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::align_to_reference_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Repeat",
+			name: "Morph",
 			category: "Vector",
-			implementation: DocumentNodeImplementation::proto("graphene_core::vector::RepeatNode<_, _>"),
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::MorphNode<_, _, _, _>"),
 			inputs: vec![
-				DocumentInputType::value("Instance", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Direction", TaggedValue::DVec2((100., 0.).into()), false),
-				DocumentInputType::value("Count", TaggedValue::U32(10), false),
+				DocumentInputType::value("Source", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Target", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Start Index", TaggedValue::U32(0), false),
+				DocumentInputType::value("Time", TaggedValue::F64(0.5), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::repeat_properties,
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::morph_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Circular Repeat",
+			name: "Lattice Deform",
 			category: "Vector",
-			implementation: DocumentNodeImplementation::proto("graphene_core::vector::CircularRepeatNode<_, _, _>"),
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::LatticeDeformNode<_, _, _, _, _>"),
 			inputs: vec![
-				DocumentInputType::value("Instance", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Angle Offset", TaggedValue::F64(0.), false),
-				DocumentInputType::value("Radius", TaggedValue::F64(5.), false),
-				DocumentInputType::value("Count", TaggedValue::U32(10), false),
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Lattice", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Columns", TaggedValue::U32(3), false),
+				DocumentInputType::value("Rows", TaggedValue::U32(3), false),
+				DocumentInputType::value("Subdivisions", TaggedValue::U32(4), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::circular_repeat_properties,
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::lattice_deform_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Copy to Points",
+			name: "Project Points Onto Path",
 			category: "Vector",
-			// TODO: Wrap this implementation with a document node that has a cache node so the output is cached?
-			implementation: DocumentNodeImplementation::proto("graphene_core::vector::CopyToPoints<_, _, _, _, _, _>"),
-			manual_composition: Some(concrete!(Footprint)),
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::ProjectPointsOntoPathNode<_, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Points", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Instance", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Random Scale Min", TaggedValue::F64(1.), false),
-				DocumentInputType::value("Random Scale Max", TaggedValue::F64(1.), false),
-				DocumentInputType::value("Random Scale Bias", TaggedValue::F64(1.), false),
-				DocumentInputType::value("Random Rotation", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Path", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("T-Value Attribute", TaggedValue::String(String::new()), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::copy_to_points_properties,
+			manual_composition: Some(concrete!(Footprint)),
+			properties: node_properties::project_points_onto_path_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Sample Points",
+			name: "Set Point Attribute",
 			category: "Vector",
-			implementation: DocumentNodeImplementation::Network(NodeNetwork {
-				imports: vec![NodeId(0), NodeId(2), NodeId(2), NodeId(2), NodeId(2)], // First is given to Identity, the rest are given to Sample Points
-				exports: vec![NodeOutput::new(NodeId(2), 0)],                         // Taken from output 0 of Sample Points
-				nodes: [
-					DocumentNode {
-						name: "Identity".to_string(),
-						inputs: vec![NodeInput::Network(concrete!(graphene_core::vector::VectorData))], // From the document node's parameters
-						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::IdentityNode")),
-						..Default::default()
-					},
-					DocumentNode {
-						name: "Lengths of Segments of Subpaths".to_string(),
-						inputs: vec![NodeInput::node(NodeId(0), 0)], // From output 0 of Identity
-						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::vector::LengthsOfSegmentsOfSubpaths")),
-						..Default::default()
-					},
-					DocumentNode {
-						name: "Sample Points".to_string(),
-						inputs: vec![
-							NodeInput::node(NodeId(0), 0),       // From output 0 of Identity
-							NodeInput::Network(concrete!(f64)),  // From the document node's parameters
-							NodeInput::Network(concrete!(f64)),  // From the document node's parameters
-							NodeInput::Network(concrete!(f64)),  // From the document node's parameters
-							NodeInput::Network(concrete!(bool)), // From the document node's parameters
-							NodeInput::node(NodeId(1), 0),       // From output 0 of Lengths of Segments of Subpaths
-						],
-						implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::vector::SamplePoints<_, _, _, _, _, _>")),
-						manual_composition: Some(concrete!(Footprint)),
-						..Default::default()
-					},
-					// TODO: Add a cache node here?
-				]
-				.into_iter()
-				.enumerate()
-				.map(|(id, node)| (NodeId(id as u64), node))
-				.collect(),
-				..Default::default()
-			}),
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SetPointAttributeNode<_, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Spacing", TaggedValue::F64(100.), false),
-				DocumentInputType::value("Start Offset", TaggedValue::F64(0.), false),
-				DocumentInputType::value("Stop Offset", TaggedValue::F64(0.), false),
-				DocumentInputType::value("Adaptive Spacing", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Name", TaggedValue::String(graphene_core::vector::SCALE_ATTRIBUTE.to_string()), false),
+				DocumentInputType::value("Start", TaggedValue::F64(1.), false),
+				DocumentInputType::value("End", TaggedValue::F64(1.), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::sample_points_properties,
+			properties: node_properties::set_point_attribute_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Poisson-Disk Points",
+			name: "Set Point Vector Attribute",
 			category: "Vector",
-			implementation: DocumentNodeImplementation::proto("graphene_core::vector::PoissonDiskPoints<_>"),
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SetPointVectorAttributeNode<_, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Separation Disk Diameter", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Name", TaggedValue::String(graphene_core::vector::OFFSET_ATTRIBUTE.to_string()), false),
+				DocumentInputType::value("Start", TaggedValue::DVec2(DVec2::ZERO), false),
+				DocumentInputType::value("End", TaggedValue::DVec2(DVec2::ZERO), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::poisson_disk_points_properties,
+			properties: node_properties::set_point_vector_attribute_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Splines from Points",
+			name: "Set Point Color Attribute",
 			category: "Vector",
-			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SplinesFromPointsNode"),
-			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::SetPointColorAttributeNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Name", TaggedValue::String("color".to_string()), false),
+				DocumentInputType::value("Start", TaggedValue::Color(Color::BLACK), false),
+				DocumentInputType::value("End", TaggedValue::Color(Color::WHITE), false),
+			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::node_no_properties,
+			properties: node_properties::set_point_color_attribute_properties,
 			..Default::default()
 		},
 		DocumentNodeDefinition {
-			name: "Morph",
+			name: "Read Point Attribute",
 			category: "Vector",
-			implementation: DocumentNodeImplementation::proto("graphene_core::vector::MorphNode<_, _, _, _>"),
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::ReadPointAttributeNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Name", TaggedValue::String(graphene_core::vector::SCALE_ATTRIBUTE.to_string()), false),
+				DocumentInputType::value("Index", TaggedValue::U32(0), false),
+			],
+			outputs: vec![DocumentOutputType::new("Value", FrontendGraphDataType::Number)],
+			properties: node_properties::read_point_attribute_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Transfer Point Attributes",
+			category: "Vector",
+			implementation: DocumentNodeImplementation::proto("graphene_core::vector::TransferPointAttributesNode<_, _, _, _, _>"),
 			inputs: vec![
+				DocumentInputType::value("Points", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
 				DocumentInputType::value("Source", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Target", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
-				DocumentInputType::value("Start Index", TaggedValue::U32(0), false),
-				DocumentInputType::value("Time", TaggedValue::F64(0.5), false),
+				DocumentInputType::value("Name", TaggedValue::String(String::new()), false),
+				DocumentInputType::value("Method", TaggedValue::TransferMethod(graphene_core::vector::TransferMethod::Nearest), false),
+				DocumentInputType::value("Radius", TaggedValue::F64(0.), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
 			manual_composition: Some(concrete!(Footprint)),
-			properties: node_properties::morph_properties,
+			properties: node_properties::transfer_point_attributes_properties,
 			..Default::default()
 		},
 		// TODO: This needs to work with resolution-aware (raster with footprint, post-Cull node) data.
@@ -2819,6 +4382,32 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 			properties: node_properties::image_color_palette,
 			..Default::default()
 		},
+		DocumentNodeDefinition {
+			name: "Color Harmony",
+			category: "Image Adjustments",
+			implementation: DocumentNodeImplementation::proto("graphene_core::raster::palette::ColorHarmonyNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Color", TaggedValue::Color(Color::from_rgb8_srgb(0xE7, 0x4C, 0x3C)), false),
+				DocumentInputType::value("Harmony", TaggedValue::ColorHarmony(graphene_core::raster::palette::ColorHarmony::Complementary), false),
+				DocumentInputType::value("Count", TaggedValue::U32(5), false),
+				DocumentInputType::value("Spread", TaggedValue::F64(30.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Colors", FrontendGraphDataType::Color)],
+			properties: node_properties::color_harmony_properties,
+			..Default::default()
+		},
+		DocumentNodeDefinition {
+			name: "Palette Color",
+			category: "Image Adjustments",
+			implementation: DocumentNodeImplementation::proto("graphene_core::raster::palette::PaletteColorNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Colors", TaggedValue::Palette(Vec::new()), true),
+				DocumentInputType::value("Index", TaggedValue::F64(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Color", FrontendGraphDataType::Color)],
+			properties: node_properties::palette_color_properties,
+			..Default::default()
+		},
 	]
 }
 
@@ -3049,12 +4638,12 @@ pub fn new_text_network(text: String, font: Font, size: f64) -> NodeNetwork {
 		imports: vec![NodeId(0)],
 		..Default::default()
 	};
-	network.push_node(text_generator.to_document_node(
+	network.push_node(text_generator.to_document_node_default_inputs(
 		[
-			NodeInput::Network(concrete!(WasmEditorApi)),
-			NodeInput::value(TaggedValue::String(text), false),
-			NodeInput::value(TaggedValue::Font(font), false),
-			NodeInput::value(TaggedValue::F64(size), false),
+			Some(NodeInput::Network(concrete!(WasmEditorApi))),
+			Some(NodeInput::value(TaggedValue::String(text), false)),
+			Some(NodeInput::value(TaggedValue::Font(font), false)),
+			Some(NodeInput::value(TaggedValue::F64(size), false)),
 		],
 		DocumentNodeMetadata::position((0, 4)),
 	));
@@ -8,12 +8,18 @@ use crate::messages::prelude::*;
 use graph_craft::document::value::TaggedValue;
 use graph_craft::document::{DocumentNode, NodeId, NodeInput};
 use graph_craft::imaginate_input::{ImaginateSamplingMethod, ImaginateServerStatus, ImaginateStatus};
+use graphene_core::layout::{Distribution, HorizontalAlignment, VerticalAlignment};
 use graphene_core::memo::IORecord;
+use graphene_core::raster::palette::ColorHarmony;
 use graphene_core::raster::{
-	BlendMode, CellularDistanceFunction, CellularReturnType, Color, DomainWarpType, FractalType, ImageFrame, LuminanceCalculation, NoiseType, RedGreenBlue, RelativeAbsolute, SelectiveColorChoice,
+	BlendMode, CellularDistanceFunction, CellularReturnType, Color, ColorSpace, DomainWarpType, FractalType, ImageFrame, LuminanceCalculation, NoiseType, RedGreenBlue, RelativeAbsolute,
+	SelectiveColorChoice,
 };
-use graphene_core::text::Font;
-use graphene_core::vector::style::{FillType, GradientType, LineCap, LineJoin};
+use graphene_core::renderer::SvgViewBox;
+use graphene_core::text::{Font, Hyphenation, MissingGlyph, TextAlign};
+use graphene_core::vector::generator_nodes::GridType;
+use graphene_core::vector::style::{FillRule, FillType, GradientInterpolation, GradientType, HueInterpolation, ImageFillMode, LineCap, LineJoin, StrokeAlignment};
+use graphene_core::vector::{ClipPathFormat, IconKeyline, IndexOutOfRange, MosaicCell, PathCodeLanguage, SubpathMeasure, SubpathOrder, Symmetry, TransferMethod, VariantSelection, WallpaperGroup};
 
 use glam::{DVec2, IVec2, UVec2};
 
@@ -318,6 +324,31 @@ fn font_inputs(document_node: &DocumentNode, node_id: NodeId, index: usize, name
 	(first_widgets, second_widgets)
 }
 
+/// The widgets of an optional font like [`font_inputs`], with a button to set it back to none and no style picker while it is none.
+fn optional_font_inputs(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> (Vec<WidgetHolder>, Option<Vec<WidgetHolder>>) {
+	let (mut widgets, style) = font_inputs(document_node, node_id, index, name, blank_assist);
+	let NodeInput::Value {
+		tagged_value: TaggedValue::Font(font),
+		exposed: false,
+	} = &document_node.inputs[index]
+	else {
+		return (widgets, style);
+	};
+	if font.is_none() {
+		return (widgets, None);
+	}
+
+	widgets.extend_from_slice(&[
+		Separator::new(SeparatorType::Related).widget_holder(),
+		IconButton::new("Remove", 16)
+			.tooltip("Set to no font")
+			.on_update(update_value(|_: &IconButton| TaggedValue::Font(Font::none()), node_id, index))
+			.on_commit(commit_value)
+			.widget_holder(),
+	]);
+	(widgets, style)
+}
+
 fn vector_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> Vec<WidgetHolder> {
 	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::Vector, blank_assist);
 
@@ -619,1110 +650,1889 @@ fn line_cap_widget(document_node: &DocumentNode, node_id: NodeId, index: usize,
 	LayoutGroup::Row { widgets }
 }
 
-fn line_join_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+fn stroke_alignment_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
 	if let &NodeInput::Value {
-		tagged_value: TaggedValue::LineJoin(line_join),
+		tagged_value: TaggedValue::StrokeAlignment(alignment),
 		exposed: false,
 	} = &document_node.inputs[index]
 	{
-		let entries = [("Miter", LineJoin::Miter), ("Bevel", LineJoin::Bevel), ("Round", LineJoin::Round)]
+		let entries = [("Center", StrokeAlignment::Center), ("Inside", StrokeAlignment::Inside), ("Outside", StrokeAlignment::Outside)]
 			.into_iter()
 			.map(|(name, val)| {
 				RadioEntryData::new(format!("{val:?}"))
 					.label(name)
-					.on_update(update_value(move |_| TaggedValue::LineJoin(val), node_id, index))
+					.on_update(update_value(move |_| TaggedValue::StrokeAlignment(val), node_id, index))
 					.on_commit(commit_value)
 			})
 			.collect();
 
 		widgets.extend_from_slice(&[
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
-			RadioInput::new(entries).selected_index(Some(line_join as u32)).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(alignment as u32)).widget_holder(),
 		]);
 	}
-	LayoutGroup::Row { widgets }
+	LayoutGroup::Row { widgets }.with_tooltip("Where the stroke is drawn relative to the path, which only applies when every subpath is closed")
 }
 
-fn fill_type_widget(document_node: &DocumentNode, node_id: NodeId, index: usize) -> LayoutGroup {
-	let mut widgets = start_widgets(document_node, node_id, index, "Fill Type", FrontendGraphDataType::General, true);
+fn fill_rule_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
 	if let &NodeInput::Value {
-		tagged_value: TaggedValue::FillType(fill_type),
+		tagged_value: TaggedValue::FillRule(fill_rule),
 		exposed: false,
 	} = &document_node.inputs[index]
 	{
-		let entries = vec![
-			RadioEntryData::new("solid")
-				.label("Solid")
-				.on_update(update_value(move |_| TaggedValue::FillType(FillType::Solid), node_id, index))
-				.on_commit(commit_value),
-			RadioEntryData::new("gradient")
-				.label("Gradient")
-				.on_update(update_value(move |_| TaggedValue::FillType(FillType::Gradient), node_id, index))
-				.on_commit(commit_value),
-		];
+		let entries = [("Nonzero", FillRule::NonZero), ("Even-Odd", FillRule::EvenOdd)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::FillRule(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
 		widgets.extend_from_slice(&[
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
-			RadioInput::new(entries)
-				.selected_index(match fill_type {
-					FillType::Solid => Some(0),
-					FillType::Gradient => Some(1),
-				})
-				.widget_holder(),
+			RadioInput::new(entries).selected_index(Some(fill_rule as u32)).widget_holder(),
 		]);
 	}
 	LayoutGroup::Row { widgets }
 }
 
-fn gradient_type_widget(document_node: &DocumentNode, node_id: NodeId, index: usize) -> LayoutGroup {
-	let mut widgets = start_widgets(document_node, node_id, index, "Gradient Type", FrontendGraphDataType::General, true);
+fn missing_glyph_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
 	if let &NodeInput::Value {
-		tagged_value: TaggedValue::GradientType(gradient_type),
+		tagged_value: TaggedValue::MissingGlyph(missing_glyph),
 		exposed: false,
 	} = &document_node.inputs[index]
 	{
-		let entries = vec![
-			RadioEntryData::new("linear")
-				.label("Linear")
-				.on_update(update_value(move |_| TaggedValue::GradientType(GradientType::Linear), node_id, index))
-				.on_commit(commit_value),
-			RadioEntryData::new("radial")
-				.label("Radial")
-				.on_update(update_value(move |_| TaggedValue::GradientType(GradientType::Radial), node_id, index))
-				.on_commit(commit_value),
-		];
+		let entries = [("Box", MissingGlyph::Notdef), ("Replacement", MissingGlyph::Replacement), ("Skip", MissingGlyph::Skip)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::MissingGlyph(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
 		widgets.extend_from_slice(&[
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
-			RadioInput::new(entries).selected_index(Some(gradient_type as u32)).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(missing_glyph as u32)).widget_holder(),
 		]);
 	}
-	LayoutGroup::Row { widgets }
+	LayoutGroup::Row { widgets }.with_tooltip("What to draw for characters that none of the fonts have: the main font's empty box, the \"�\" replacement character, or nothing")
 }
 
-fn gradient_row(row: &mut Vec<WidgetHolder>, positions: &Vec<(f64, Color)>, index: usize, node_id: NodeId, input_index: usize) {
-	let label = TextLabel::new(format!("Gradient: {:.0}%", positions[index].0 * 100.)).tooltip("Adjustable by dragging the gradient stops in the viewport with the Gradient tool active");
-	row.push(label.widget_holder());
-	let on_update = {
-		let positions = positions.clone();
-		move |color_button: &ColorButton| {
-			let mut new_positions = positions.clone();
-			new_positions[index].1 = color_button.value.unwrap();
-			TaggedValue::GradientPositions(new_positions)
-		}
-	};
-	let color = ColorButton::new(Some(positions[index].1))
-		.on_update(update_value(on_update, node_id, input_index))
-		.on_commit(commit_value)
-		.allow_none(false);
-	add_blank_assist(row);
-	row.push(Separator::new(SeparatorType::Unrelated).widget_holder());
-	row.push(color.widget_holder());
-
-	let mut skip_separator = false;
-	// Remove button
-	if positions.len() != index + 1 && index != 0 {
-		let on_update = {
-			let in_positions = positions.clone();
-			move |_: &IconButton| {
-				let mut new_positions = in_positions.clone();
-				new_positions.remove(index);
-				TaggedValue::GradientPositions(new_positions)
-			}
-		};
-
-		skip_separator = true;
-		row.push(Separator::new(SeparatorType::Related).widget_holder());
-		row.push(
-			IconButton::new("Remove", 16)
-				.tooltip("Remove this gradient stop")
-				.on_update(update_value(on_update, node_id, input_index))
-				.on_commit(commit_value)
-				.widget_holder(),
-		);
-	}
-	// Add button
-	if positions.len() != index + 1 {
-		let on_update = {
-			let positions = positions.clone();
-			move |_: &IconButton| {
-				let mut new_positions = positions.clone();
-
-				// Blend linearly between the two colors.
-				let get_color = |index: usize| match (new_positions[index].1, new_positions.get(index + 1).map(|x| x.1)) {
-					(a, Some(b)) => Color::from_rgbaf32_unchecked((a.r() + b.r()) / 2., (a.g() + b.g()) / 2., (a.b() + b.b()) / 2., ((a.a() + b.a()) / 2.).clamp(0., 1.)),
-					(_, None) => Color::WHITE,
-				};
-				let get_pos = |index: usize| (new_positions[index].0 + new_positions.get(index + 1).map(|v| v.0).unwrap_or(1.)) / 2.;
-
-				new_positions.push((get_pos(index), get_color(index)));
-
-				new_positions.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-
-				TaggedValue::GradientPositions(new_positions)
-			}
-		};
+fn variant_selection_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::VariantSelection(variant_selection),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Whole", VariantSelection::Whole), ("Round Robin", VariantSelection::RoundRobin), ("Random", VariantSelection::Random)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::VariantSelection(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
-		if !skip_separator {
-			row.push(Separator::new(SeparatorType::Related).widget_holder());
-		}
-		row.push(
-			IconButton::new("Add", 16)
-				.tooltip("Add a gradient stop after this")
-				.on_update(update_value(on_update, node_id, input_index))
-				.on_commit(commit_value)
-				.widget_holder(),
-		);
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(variant_selection as u32)).widget_holder(),
+		]);
 	}
+	LayoutGroup::Row { widgets }.with_tooltip("Place the whole instance at each point, or just one of its children, taking turns in order or chosen at random by the seed")
 }
 
-fn gradient_positions(rows: &mut Vec<LayoutGroup>, document_node: &DocumentNode, name: &str, node_id: NodeId, input_index: usize) {
-	let mut widgets = vec![expose_widget(node_id, input_index, FrontendGraphDataType::General, document_node.inputs[input_index].is_exposed())];
-	if let NodeInput::Value {
-		tagged_value: TaggedValue::GradientPositions(gradient_positions),
+fn subpath_measure_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::SubpathMeasure(subpath_measure),
 		exposed: false,
-	} = &document_node.inputs[input_index]
+	} = &document_node.inputs[index]
 	{
-		for index in 0..gradient_positions.len() {
-			gradient_row(&mut widgets, gradient_positions, index, node_id, input_index);
-
-			let widgets = std::mem::take(&mut widgets);
-			rows.push(LayoutGroup::Row { widgets });
-		}
-		let on_update = {
-			let gradient_positions = gradient_positions.clone();
-			move |_: &TextButton| {
-				let mut new_positions = gradient_positions.clone();
-				new_positions = new_positions.iter().map(|(distance, color)| (1. - distance, *color)).collect();
-				new_positions.reverse();
-				TaggedValue::GradientPositions(new_positions)
-			}
-		};
-		let invert = TextButton::new("Invert")
-			.icon(Some("Swap".into()))
-			.tooltip("Reverse the order of each color stop")
-			.on_update(update_value(on_update, node_id, input_index))
-			.on_commit(commit_value)
-			.widget_holder();
-
-		if widgets.is_empty() {
-			widgets.push(TextLabel::new("").widget_holder());
-			add_blank_assist(&mut widgets);
-		}
-		widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
-		widgets.push(invert);
+		let entries = [
+			("Area", SubpathMeasure::Area),
+			("Length", SubpathMeasure::Length),
+			("Point Count", SubpathMeasure::PointCount),
+			("Bounding Box Size", SubpathMeasure::BoundingBoxSize),
+		]
+		.into_iter()
+		.map(|(name, val)| {
+			MenuListEntry::new(format!("{val:?}"))
+				.label(name)
+				.on_update(update_value(move |_| TaggedValue::SubpathMeasure(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
 
-		rows.push(LayoutGroup::Row { widgets });
-	} else {
-		widgets.push(TextLabel::new(name).widget_holder());
-		rows.push(LayoutGroup::Row { widgets })
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries]).selected_index(Some(subpath_measure as u32)).widget_holder(),
+		]);
 	}
+	LayoutGroup::Row { widgets }.with_tooltip("Property of each subpath that is compared against the range and sorted by")
 }
 
-fn color_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, color_props: ColorButton, blank_assist: bool) -> LayoutGroup {
-	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::Color, blank_assist);
-
-	if let NodeInput::Value { tagged_value, exposed: false } = &document_node.inputs[index] {
-		if let &TaggedValue::Color(x) = tagged_value {
-			widgets.extend_from_slice(&[
-				Separator::new(SeparatorType::Unrelated).widget_holder(),
-				color_props
-					.value(Some(x as Color))
-					.on_update(update_value(|x: &ColorButton| TaggedValue::Color(x.value.unwrap()), node_id, index))
-					.on_commit(commit_value)
-					.widget_holder(),
-			])
-		} else if let &TaggedValue::OptionalColor(x) = tagged_value {
-			widgets.extend_from_slice(&[
-				Separator::new(SeparatorType::Unrelated).widget_holder(),
-				color_props
-					.value(x)
-					.on_update(update_value(|x: &ColorButton| TaggedValue::OptionalColor(x.value), node_id, index))
+fn subpath_order_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::SubpathOrder(subpath_order),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Original", SubpathOrder::Original), ("Ascending", SubpathOrder::Ascending), ("Descending", SubpathOrder::Descending)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::SubpathOrder(val), node_id, index))
 					.on_commit(commit_value)
-					.widget_holder(),
-			])
-		}
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(subpath_order as u32)).widget_holder(),
+		]);
 	}
-	LayoutGroup::Row { widgets }
+	LayoutGroup::Row { widgets }.with_tooltip("Keep the subpaths in their original order, or sort them by the measure, which also changes which are drawn on top")
 }
 
-fn curves_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+fn index_out_of_range_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
-
-	if let NodeInput::Value {
-		tagged_value: TaggedValue::Curve(curve),
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::IndexOutOfRange(out_of_range),
 		exposed: false,
 	} = &document_node.inputs[index]
 	{
+		let entries = [("Skip", IndexOutOfRange::Skip), ("Clamp", IndexOutOfRange::Clamp), ("Wrap", IndexOutOfRange::Wrap)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::IndexOutOfRange(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
 		widgets.extend_from_slice(&[
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
-			CurveInput::new(curve.clone())
-				.on_update(update_value(|x: &CurveInput| TaggedValue::Curve(x.value.clone()), node_id, index))
-				.on_commit(commit_value)
-				.widget_holder(),
-		])
+			RadioInput::new(entries).selected_index(Some(out_of_range as u32)).widget_holder(),
+		]);
 	}
-	LayoutGroup::Row { widgets }
+	LayoutGroup::Row { widgets }.with_tooltip("Leave out the subpaths past either end, move the range to the nearest subpath, or wrap around to the first subpath after the last")
 }
 
-pub fn levels_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let input_shadows = number_widget(document_node, node_id, 1, "Shadows", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
-	let input_midtones = number_widget(document_node, node_id, 2, "Midtones", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
-	let input_highlights = number_widget(document_node, node_id, 3, "Highlights", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
-	let output_minimums = number_widget(document_node, node_id, 4, "Output Minimums", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
-	let output_maximums = number_widget(document_node, node_id, 5, "Output Maximums", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
-
-	vec![
-		LayoutGroup::Row { widgets: input_shadows },
-		LayoutGroup::Row { widgets: input_midtones },
-		LayoutGroup::Row { widgets: input_highlights },
-		LayoutGroup::Row { widgets: output_minimums },
-		LayoutGroup::Row { widgets: output_maximums },
-	]
+fn transfer_method_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::TransferMethod(transfer_method),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Nearest", TransferMethod::Nearest), ("Barycentric", TransferMethod::Barycentric)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::TransferMethod(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(transfer_method as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Copy the values of the nearest source point, or blend those of the triangle of source points around each point")
 }
 
-pub fn black_and_white_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	const MIN: f64 = -200.;
-	const MAX: f64 = 300.;
-	// TODO: Add tint color (blended above using the "Color" blend mode)
-	let tint = color_widget(document_node, node_id, 1, "Tint", ColorButton::default(), true);
-	let r_weight = number_widget(document_node, node_id, 2, "Reds", NumberInput::default().mode_range().min(MIN).max(MAX).unit("%"), true);
-	let y_weight = number_widget(document_node, node_id, 3, "Yellows", NumberInput::default().mode_range().min(MIN).max(MAX).unit("%"), true);
-	let g_weight = number_widget(document_node, node_id, 4, "Greens", NumberInput::default().mode_range().min(MIN).max(MAX).unit("%"), true);
-	let c_weight = number_widget(document_node, node_id, 5, "Cyans", NumberInput::default().mode_range().min(MIN).max(MAX).unit("%"), true);
-	let b_weight = number_widget(document_node, node_id, 6, "Blues", NumberInput::default().mode_range().min(MIN).max(MAX).unit("%"), true);
-	let m_weight = number_widget(document_node, node_id, 7, "Magentas", NumberInput::default().mode_range().min(MIN).max(MAX).unit("%"), true);
+fn gradient_interpolation_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::GradientInterpolation(interpolation),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [
+			("sRGB", GradientInterpolation::Srgb),
+			("Linear", GradientInterpolation::LinearSrgb),
+			("OKLab", GradientInterpolation::Oklab),
+			("LCH", GradientInterpolation::Lch),
+		]
+		.into_iter()
+		.map(|(name, val)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.label(name)
+				.on_update(update_value(move |_| TaggedValue::GradientInterpolation(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
 
-	vec![
-		tint,
-		LayoutGroup::Row { widgets: r_weight },
-		LayoutGroup::Row { widgets: y_weight },
-		LayoutGroup::Row { widgets: g_weight },
-		LayoutGroup::Row { widgets: c_weight },
-		LayoutGroup::Row { widgets: b_weight },
-		LayoutGroup::Row { widgets: m_weight },
-	]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(interpolation as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("The color space the colors are blended in between stops, where OKLab and LCH avoid the muddy grays of sRGB")
 }
 
-pub fn blend_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let backdrop = color_widget(document_node, node_id, 1, "Backdrop", ColorButton::default(), true);
-	let blend_mode = blend_mode(document_node, node_id, 2, "Blend Mode", true);
-	let opacity = number_widget(document_node, node_id, 3, "Opacity", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+fn hue_interpolation_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::HueInterpolation(hue_interpolation),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [
+			("Shorter", HueInterpolation::Shorter),
+			("Longer", HueInterpolation::Longer),
+			("Increasing", HueInterpolation::Increasing),
+			("Decreasing", HueInterpolation::Decreasing),
+		]
+		.into_iter()
+		.map(|(name, val)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.label(name)
+				.on_update(update_value(move |_| TaggedValue::HueInterpolation(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
 
-	vec![backdrop, blend_mode, LayoutGroup::Row { widgets: opacity }]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(hue_interpolation as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Which way around the color wheel the hue turns between stops when blending in LCH")
 }
 
-pub fn number_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = number_widget(document_node, node_id, 0, "Number", NumberInput::default(), true);
+fn color_space_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::ColorSpace(color_space),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [
+			("sRGB", ColorSpace::Srgb),
+			("Linear sRGB", ColorSpace::LinearSrgb),
+			("Display P3", ColorSpace::DisplayP3),
+			("Linear Rec. 2020", ColorSpace::LinearRec2020),
+		]
+		.into_iter()
+		.map(|(name, val)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.label(name)
+				.on_update(update_value(move |_| TaggedValue::ColorSpace(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
 
-	vec![LayoutGroup::Row { widgets }]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(color_space as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("The color space the channels of the colors are read in, where Display P3 and Rec. 2020 reach more saturated colors than sRGB")
 }
 
-pub fn vector2_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let x = number_widget(document_node, node_id, 1, "X", NumberInput::default(), true);
-	let y = number_widget(document_node, node_id, 2, "Y", NumberInput::default(), true);
+fn color_harmony_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::ColorHarmony(color_harmony),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [
+			("Complementary", ColorHarmony::Complementary),
+			("Split", ColorHarmony::SplitComplementary),
+			("Analogous", ColorHarmony::Analogous),
+			("Triadic", ColorHarmony::Triadic),
+			("Tetradic", ColorHarmony::Tetradic),
+			("Mono", ColorHarmony::Monochromatic),
+		]
+		.into_iter()
+		.map(|(name, val)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.label(name)
+				.on_update(update_value(move |_| TaggedValue::ColorHarmony(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
 
-	vec![LayoutGroup::Row { widgets: x }, LayoutGroup::Row { widgets: y }]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(color_harmony as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("The rule from color theory used to pick hues around the color wheel that go with the base color")
 }
 
-pub fn boolean_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = bool_widget(document_node, node_id, 0, "Bool", true);
+fn clip_path_format_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::ClipPathFormat(clip_path_format),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("path()", ClipPathFormat::Path), ("polygon()", ClipPathFormat::Polygon)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::ClipPathFormat(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
-	vec![LayoutGroup::Row { widgets }]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(clip_path_format as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Write the exact curves with path(), or flatten them into straight edges with polygon(), which can be given in percentages")
 }
 
-pub fn color_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	vec![color_widget(document_node, node_id, 0, "Color", ColorButton::default(), true)]
+fn mosaic_cell_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::MosaicCell(mosaic_cell),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Square", MosaicCell::Square), ("Hexagon", MosaicCell::Hexagon), ("Circle", MosaicCell::Circle)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::MosaicCell(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(mosaic_cell as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Shape of the cells the artwork is rebuilt from")
 }
 
-pub fn load_image_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let url = text_widget(document_node, node_id, 1, "Url", true);
+fn icon_keyline_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::IconKeyline(keyline),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [
+			("Auto", IconKeyline::Auto),
+			("Square", IconKeyline::Square),
+			("Circle", IconKeyline::Circle),
+			("Portrait", IconKeyline::Portrait),
+			("Landscape", IconKeyline::Landscape),
+		]
+		.into_iter()
+		.map(|(name, val)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.label(name)
+				.on_update(update_value(move |_| TaggedValue::IconKeyline(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
 
-	vec![LayoutGroup::Row { widgets: url }]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(keyline as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Keyline shape the artwork is fitted to, or Auto to pick it from the artwork's proportions and roundness")
 }
 
-pub fn output_properties(_document_node: &DocumentNode, _node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let label = TextLabel::new("Graphics fed into the Output are drawn in the viewport").widget_holder();
+fn grid_type_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::GridType(grid_type),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [
+			("Square", GridType::Square),
+			("Hexagon", GridType::Hexagon),
+			("Triangle", GridType::Triangle),
+			("Radial", GridType::Radial),
+		]
+		.into_iter()
+		.map(|(name, val)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.label(name)
+				.on_update(update_value(move |_| TaggedValue::GridType(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
 
-	vec![LayoutGroup::Row { widgets: vec![label] }]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(grid_type as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Shape of the cells and how they fit together")
 }
 
-pub fn mask_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let mask = color_widget(document_node, node_id, 1, "Stencil", ColorButton::default(), true);
+fn path_code_language_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::PathCodeLanguage(path_code_language),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Canvas", PathCodeLanguage::Canvas), ("SwiftUI", PathCodeLanguage::SwiftUI), ("Flutter", PathCodeLanguage::Flutter)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::PathCodeLanguage(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
-	vec![mask]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(path_code_language as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Write JavaScript for an HTML canvas Path2D, Swift for a SwiftUI Path, or Dart for a Flutter Path")
 }
 
-pub fn color_channel_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	vec![color_channel(document_node, node_id, 0, "Channel", true)]
+fn image_fill_mode_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::ImageFillMode(image_fill_mode),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Fit", ImageFillMode::Fit), ("Fill", ImageFillMode::Fill), ("Tile", ImageFillMode::Tile)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::ImageFillMode(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(image_fill_mode as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Fit the whole image inside the shape's bounding box, fill the box with it and crop the rest, or tile it at its own size")
 }
 
-pub fn luminance_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let luminance_calc = luminance_calculation(document_node, node_id, 1, "Luminance Calc", true);
+fn symmetry_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::Symmetry(symmetry),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Reflection", Symmetry::Reflection), ("Rotation", Symmetry::Rotation)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::Symmetry(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
-	vec![luminance_calc]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(symmetry as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Look for a mirror line, or for rotated copies spaced evenly around a center")
 }
 
-pub fn insert_channel_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let color_channel = color_channel(document_node, node_id, 2, "Into", true);
+fn wallpaper_group_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::WallpaperGroup(group),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [
+			("p1", WallpaperGroup::P1),
+			("p2", WallpaperGroup::P2),
+			("pm", WallpaperGroup::Pm),
+			("pmm", WallpaperGroup::Pmm),
+			("p4", WallpaperGroup::P4),
+			("p4m", WallpaperGroup::P4m),
+			("p3", WallpaperGroup::P3),
+			("p6", WallpaperGroup::P6),
+			("p6m", WallpaperGroup::P6m),
+		]
+		.into_iter()
+		.map(|(name, val)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.label(name)
+				.on_update(update_value(move |_| TaggedValue::WallpaperGroup(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
 
-	vec![color_channel]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(group as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("The wallpaper group of turns and mirrors that copies of the motif are arranged by within the tile")
 }
 
-pub fn extract_channel_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let color_channel = color_channel(document_node, node_id, 1, "From", true);
+fn svg_view_box_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::SvgViewBox(view_box),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Content", SvgViewBox::Content), ("Origin", SvgViewBox::Origin), ("Omit", SvgViewBox::Omit)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::SvgViewBox(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
-	vec![color_channel]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(view_box as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Fit the view box around the content, place the content's corner at the origin, or leave the view box out")
 }
 
-// Noise Type is commented out for now as there is only one type of noise (White Noise).
-// As soon as there are more types of noise, this should be uncommented.
-pub fn noise_pattern_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	// Get the current values of the inputs of interest so they can set whether certain inputs are disabled based on various conditions.
-	let current_noise_type = match &document_node.inputs[4] {
-		NodeInput::Value {
-			tagged_value: TaggedValue::NoiseType(noise_type),
-			..
-		} => Some(*noise_type),
-		_ => None,
-	};
-	let current_domain_warp_type = match &document_node.inputs[5] {
-		NodeInput::Value {
-			tagged_value: TaggedValue::DomainWarpType(domain_warp_type),
-			..
-		} => Some(*domain_warp_type),
-		_ => None,
-	};
-	let current_fractal_type = match &document_node.inputs[7] {
-		NodeInput::Value {
-			tagged_value: TaggedValue::FractalType(fractal_type),
-			..
-		} => Some(*fractal_type),
-		_ => None,
-	};
-	let fractal_active = current_fractal_type != Some(FractalType::None);
-	let coherent_noise_active = current_noise_type != Some(NoiseType::WhiteNoise);
-	let cellular_noise_active = current_noise_type == Some(NoiseType::Cellular);
-	let ping_pong_active = current_fractal_type == Some(FractalType::PingPong);
-	let domain_warp_active = current_domain_warp_type != Some(DomainWarpType::None);
-	let domain_warp_only_fractal_type_wrongly_active =
-		!domain_warp_active && (current_fractal_type == Some(FractalType::DomainWarpIndependent) || current_fractal_type == Some(FractalType::DomainWarpProgressive));
-
-	// All
-	let dimensions = vec2_widget(document_node, node_id, 1, "Dimensions", "W", "H", "px", Some(1.), add_blank_assist);
-	let seed = number_widget(document_node, node_id, 2, "Seed", NumberInput::default().min(0.).is_integer(true), true);
-	let scale = number_widget(document_node, node_id, 3, "Scale", NumberInput::default().min(0.).disabled(!coherent_noise_active), true);
-	let noise_type_row = noise_type(document_node, node_id, 4, "Noise Type", true);
-
-	// Domain Warp
-	let domain_warp_type_row = domain_warp_type(document_node, node_id, 5, "Domain Warp Type", true, !coherent_noise_active);
-	let domain_warp_amplitude = number_widget(
-		document_node,
-		node_id,
-		6,
-		"Domain Warp Amplitude",
-		NumberInput::default().min(0.).disabled(!coherent_noise_active || !domain_warp_active),
-		true,
-	);
-
-	// Fractal
-	let fractal_type_row = fractal_type(document_node, node_id, 7, "Fractal Type", true, !coherent_noise_active);
-	let fractal_octaves = number_widget(
-		document_node,
-		node_id,
-		8,
-		"Fractal Octaves",
-		NumberInput::default()
-			.mode_range()
-			.min(1.)
-			.max(10.)
-			.range_max(Some(4.))
-			.is_integer(true)
-			.disabled(!coherent_noise_active || !fractal_active || domain_warp_only_fractal_type_wrongly_active),
-		true,
-	);
-	let fractal_lacunarity = number_widget(
-		document_node,
-		node_id,
-		9,
-		"Fractal Lacunarity",
-		NumberInput::default()
-			.mode_range()
-			.min(0.)
-			.range_max(Some(10.))
-			.disabled(!coherent_noise_active || !fractal_active || domain_warp_only_fractal_type_wrongly_active),
-		true,
-	);
-	let fractal_gain = number_widget(
-		document_node,
-		node_id,
-		10,
-		"Fractal Gain",
-		NumberInput::default()
-			.mode_range()
-			.min(0.)
-			.range_max(Some(10.))
-			.disabled(!coherent_noise_active || !fractal_active || domain_warp_only_fractal_type_wrongly_active),
-		true,
-	);
-	let fractal_weighted_strength = number_widget(
-		document_node,
-		node_id,
-		11,
-		"Fractal Weighted Strength",
-		NumberInput::default()
-			.mode_range()
-			.min(0.)
-			.max(1.) // Defined for the 0-1 range
-			.disabled(!coherent_noise_active || !fractal_active || domain_warp_only_fractal_type_wrongly_active),
-		true,
-	);
-	let fractal_ping_pong_strength = number_widget(
-		document_node,
-		node_id,
-		12,
-		"Fractal Ping Pong Strength",
-		NumberInput::default()
-			.mode_range()
-			.min(0.)
-			.range_max(Some(10.))
-			.disabled(!ping_pong_active || !coherent_noise_active || !fractal_active || domain_warp_only_fractal_type_wrongly_active),
-		true,
-	);
-
-	// Cellular
-	let cellular_distance_function_row = cellular_distance_function(document_node, node_id, 13, "Cellular Distance Function", true, !coherent_noise_active || !cellular_noise_active);
-	let cellular_return_type = cellular_return_type(document_node, node_id, 14, "Cellular Return Type", true, !coherent_noise_active || !cellular_noise_active);
-	let cellular_jitter = number_widget(
-		document_node,
-		node_id,
-		15,
-		"Cellular Jitter",
-		NumberInput::default()
-			.mode_range()
-			.range_min(Some(0.))
-			.range_max(Some(1.))
-			.disabled(!coherent_noise_active || !cellular_noise_active),
-		true,
-	);
-
-	vec![
-		// All
-		dimensions,
-		LayoutGroup::Row { widgets: seed },
-		LayoutGroup::Row { widgets: scale },
-		noise_type_row,
-		LayoutGroup::Row { widgets: Vec::new() },
-		// Domain Warp
-		domain_warp_type_row,
-		LayoutGroup::Row { widgets: domain_warp_amplitude },
-		LayoutGroup::Row { widgets: Vec::new() },
-		// Fractal
-		fractal_type_row,
-		LayoutGroup::Row { widgets: fractal_octaves },
-		LayoutGroup::Row { widgets: fractal_lacunarity },
-		LayoutGroup::Row { widgets: fractal_gain },
-		LayoutGroup::Row { widgets: fractal_weighted_strength },
-		LayoutGroup::Row { widgets: fractal_ping_pong_strength },
-		LayoutGroup::Row { widgets: Vec::new() },
-		// Cellular
-		cellular_distance_function_row,
-		cellular_return_type,
-		LayoutGroup::Row { widgets: cellular_jitter },
-	]
-}
-
-pub fn adjust_hsl_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let hue_shift = number_widget(document_node, node_id, 1, "Hue Shift", NumberInput::default().min(-180.).max(180.).unit("°"), true);
-	let saturation_shift = number_widget(document_node, node_id, 2, "Saturation Shift", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
-	let lightness_shift = number_widget(document_node, node_id, 3, "Lightness Shift", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+fn horizontal_alignment_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::HorizontalAlignment(alignment),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [HorizontalAlignment::None, HorizontalAlignment::Left, HorizontalAlignment::Center, HorizontalAlignment::Right]
+			.into_iter()
+			.map(|val| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(format!("{val:?}"))
+					.on_update(update_value(move |_| TaggedValue::HorizontalAlignment(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
-	vec![
-		LayoutGroup::Row { widgets: hue_shift },
-		LayoutGroup::Row { widgets: saturation_shift },
-		LayoutGroup::Row { widgets: lightness_shift },
-	]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(alignment as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Which side, or the center, of the elements to line up with that of their combined bounds")
 }
 
-pub fn brightness_contrast_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let brightness = number_widget(document_node, node_id, 1, "Brightness", NumberInput::default().min(-150.).max(150.), true);
-	let contrast = number_widget(document_node, node_id, 2, "Contrast", NumberInput::default().min(-100.).max(100.), true);
-	let use_legacy = bool_widget(document_node, node_id, 3, "Use Legacy", true);
+fn vertical_alignment_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::VerticalAlignment(alignment),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [VerticalAlignment::None, VerticalAlignment::Top, VerticalAlignment::Middle, VerticalAlignment::Bottom]
+			.into_iter()
+			.map(|val| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(format!("{val:?}"))
+					.on_update(update_value(move |_| TaggedValue::VerticalAlignment(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
-	vec![
-		LayoutGroup::Row { widgets: brightness },
-		LayoutGroup::Row { widgets: contrast },
-		LayoutGroup::Row { widgets: use_legacy },
-	]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(alignment as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Which side, or the middle, of the elements to line up with that of their combined bounds")
 }
 
-pub fn curves_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let curves = curves_widget(document_node, node_id, 1, "Curve", true);
+fn distribution_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::Distribution(distribution),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [Distribution::None, Distribution::Horizontal, Distribution::Vertical]
+			.into_iter()
+			.map(|val| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(format!("{val:?}"))
+					.on_update(update_value(move |_| TaggedValue::Distribution(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
-	vec![curves]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(distribution as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Direction to spread the elements out in so the gaps between them are even, which takes the place of aligning them in that direction")
 }
 
-pub fn _blur_image_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let radius = number_widget(document_node, node_id, 1, "Radius", NumberInput::default().min(0.).max(20.).int(), true);
-	let sigma = number_widget(document_node, node_id, 2, "Sigma", NumberInput::default().min(0.).max(10000.), true);
+fn text_align_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::TextAlign(align),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [TextAlign::Left, TextAlign::Center, TextAlign::Right, TextAlign::Justify]
+			.into_iter()
+			.map(|val| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(format!("{val:?}"))
+					.on_update(update_value(move |_| TaggedValue::TextAlign(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
-	vec![LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: sigma }]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(align as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("How each line is placed across the line width, or across the longest line if there's no line width")
 }
 
-pub fn adjust_threshold_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let thereshold_min = number_widget(document_node, node_id, 1, "Min Luminance", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
-	let thereshold_max = number_widget(document_node, node_id, 2, "Max Luminance", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
-	let luminance_calc = luminance_calculation(document_node, node_id, 3, "Luminance Calc", true);
+fn hyphenation_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::Hyphenation(hyphenation),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [Hyphenation::Off, Hyphenation::English, Hyphenation::German, Hyphenation::French, Hyphenation::Spanish]
+			.into_iter()
+			.map(|val| {
+				MenuListEntry::new(format!("{val:?}"))
+					.label(format!("{val:?}"))
+					.on_update(update_value(move |_| TaggedValue::Hyphenation(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
-	vec![LayoutGroup::Row { widgets: thereshold_min }, LayoutGroup::Row { widgets: thereshold_max }, luminance_calc]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries]).selected_index(Some(hyphenation as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Language whose syllables long words are hyphenated at when they don't fit on a line")
 }
 
-pub fn adjust_vibrance_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let vibrance = number_widget(document_node, node_id, 1, "Vibrance", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+fn line_join_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::LineJoin(line_join),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Miter", LineJoin::Miter), ("Bevel", LineJoin::Bevel), ("Round", LineJoin::Round)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::LineJoin(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
 
-	vec![LayoutGroup::Row { widgets: vibrance }]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(line_join as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
 }
 
-pub fn adjust_channel_mixer_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	// Monochrome
-	let monochrome_index = 1;
-	let monochrome = bool_widget(document_node, node_id, monochrome_index, "Monochrome", true);
-	let is_monochrome = if let &NodeInput::Value {
-		tagged_value: TaggedValue::Bool(monochrome_choice),
-		..
-	} = &document_node.inputs[monochrome_index]
-	{
-		monochrome_choice
-	} else {
-		false
-	};
-
-	// Output channel choice
-	let output_channel_index = 18;
-	let mut output_channel = vec![TextLabel::new("Output Channel").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
-	add_blank_assist(&mut output_channel);
+fn fill_type_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, true);
 	if let &NodeInput::Value {
-		tagged_value: TaggedValue::RedGreenBlue(choice),
+		tagged_value: TaggedValue::FillType(fill_type),
 		exposed: false,
-	} = &document_node.inputs[output_channel_index]
+	} = &document_node.inputs[index]
 	{
 		let entries = vec![
-			RadioEntryData::new(format!("{:?}", RedGreenBlue::Red))
-				.label(RedGreenBlue::Red.to_string())
-				.on_update(update_value(|_| TaggedValue::RedGreenBlue(RedGreenBlue::Red), node_id, output_channel_index))
-				.on_commit(commit_value),
-			RadioEntryData::new(format!("{:?}", RedGreenBlue::Green))
-				.label(RedGreenBlue::Green.to_string())
-				.on_update(update_value(|_| TaggedValue::RedGreenBlue(RedGreenBlue::Green), node_id, output_channel_index))
+			RadioEntryData::new("solid")
+				.label("Solid")
+				.on_update(update_value(move |_| TaggedValue::FillType(FillType::Solid), node_id, index))
 				.on_commit(commit_value),
-			RadioEntryData::new(format!("{:?}", RedGreenBlue::Blue))
-				.label(RedGreenBlue::Blue.to_string())
-				.on_update(update_value(|_| TaggedValue::RedGreenBlue(RedGreenBlue::Blue), node_id, output_channel_index))
+			RadioEntryData::new("gradient")
+				.label("Gradient")
+				.on_update(update_value(move |_| TaggedValue::FillType(FillType::Gradient), node_id, index))
 				.on_commit(commit_value),
 		];
-		output_channel.extend([RadioInput::new(entries).selected_index(Some(choice as u32)).widget_holder()]);
-	};
-	let is_output_channel = if let &NodeInput::Value {
-		tagged_value: TaggedValue::RedGreenBlue(choice),
-		..
-	} = &document_node.inputs[output_channel_index]
-	{
-		choice
-	} else {
-		warn!("Channel Mixer node properties panel could not be displayed.");
-		return vec![];
-	};
-
-	// Channel values
-	let (r, g, b, c) = match (is_monochrome, is_output_channel) {
-		(true, _) => ((2, "Red", 40.), (3, "Green", 40.), (4, "Blue", 20.), (5, "Constant", 0.)),
-		(false, RedGreenBlue::Red) => ((6, "(Red) Red", 100.), (7, "(Red) Green", 0.), (8, "(Red) Blue", 0.), (9, "(Red) Constant", 0.)),
-		(false, RedGreenBlue::Green) => ((10, "(Green) Red", 0.), (11, "(Green) Green", 100.), (12, "(Green) Blue", 0.), (13, "(Green) Constant", 0.)),
-		(false, RedGreenBlue::Blue) => ((14, "(Blue) Red", 0.), (15, "(Blue) Green", 0.), (16, "(Blue) Blue", 100.), (17, "(Blue) Constant", 0.)),
-	};
-	let red = number_widget(
-		document_node,
-		node_id,
-		r.0,
-		r.1,
-		NumberInput::default().mode_range().min(-200.).max(200.).value(Some(r.2)).unit("%"),
-		true,
-	);
-	let green = number_widget(
-		document_node,
-		node_id,
-		g.0,
-		g.1,
-		NumberInput::default().mode_range().min(-200.).max(200.).value(Some(g.2)).unit("%"),
-		true,
-	);
-	let blue = number_widget(
-		document_node,
-		node_id,
-		b.0,
-		b.1,
-		NumberInput::default().mode_range().min(-200.).max(200.).value(Some(b.2)).unit("%"),
-		true,
-	);
-	let constant = number_widget(
-		document_node,
-		node_id,
-		c.0,
-		c.1,
-		NumberInput::default().mode_range().min(-200.).max(200.).value(Some(c.2)).unit("%"),
-		true,
-	);
 
-	// Monochrome
-	let mut layout = vec![LayoutGroup::Row { widgets: monochrome }];
-	// Output channel choice
-	if !is_monochrome {
-		layout.push(LayoutGroup::Row { widgets: output_channel });
-	};
-	// Channel values
-	layout.extend([
-		LayoutGroup::Row { widgets: red },
-		LayoutGroup::Row { widgets: green },
-		LayoutGroup::Row { widgets: blue },
-		LayoutGroup::Row { widgets: constant },
-	]);
-	layout
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries)
+				.selected_index(match fill_type {
+					FillType::Solid => Some(0),
+					FillType::Gradient => Some(1),
+				})
+				.widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
 }
 
-pub fn adjust_selective_color_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	// Colors choice
-	let colors_index = 38;
-	let mut colors = vec![TextLabel::new("Colors").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
-	add_blank_assist(&mut colors);
-	if let &NodeInput::Value {
-		tagged_value: TaggedValue::SelectiveColorChoice(choice),
-		exposed: false,
-	} = &document_node.inputs[colors_index]
-	{
-		use SelectiveColorChoice::*;
-		let entries = [[Reds, Yellows, Greens, Cyans, Blues, Magentas].as_slice(), [Whites, Neutrals, Blacks].as_slice()]
-			.into_iter()
-			.map(|section| {
-				section
-					.iter()
-					.map(|choice| {
-						MenuListEntry::new(format!("{choice:?}"))
-							.label(choice.to_string())
-							.on_update(update_value(move |_| TaggedValue::SelectiveColorChoice(*choice), node_id, colors_index))
-							.on_commit(commit_value)
-					})
-					.collect()
-			})
-			.collect();
-		colors.extend([DropdownInput::new(entries).selected_index(Some(choice as u32)).widget_holder()]);
-	};
-	let colors_choice_index = if let &NodeInput::Value {
-		tagged_value: TaggedValue::SelectiveColorChoice(choice),
-		..
-	} = &document_node.inputs[colors_index]
-	{
-		choice
-	} else {
-		warn!("Selective Color node properties panel could not be displayed.");
-		return vec![];
-	};
-
-	// CMYK
-	let (c, m, y, k) = match colors_choice_index {
-		SelectiveColorChoice::Reds => ((2, "(Reds) Cyan"), (3, "(Reds) Magenta"), (4, "(Reds) Yellow"), (5, "(Reds) Black")),
-		SelectiveColorChoice::Yellows => ((6, "(Yellows) Cyan"), (7, "(Yellows) Magenta"), (8, "(Yellows) Yellow"), (9, "(Yellows) Black")),
-		SelectiveColorChoice::Greens => ((10, "(Greens) Cyan"), (11, "(Greens) Magenta"), (12, "(Greens) Yellow"), (13, "(Greens) Black")),
-		SelectiveColorChoice::Cyans => ((14, "(Cyans) Cyan"), (15, "(Cyans) Magenta"), (16, "(Cyans) Yellow"), (17, "(Cyans) Black")),
-		SelectiveColorChoice::Blues => ((18, "(Blues) Cyan"), (19, "(Blues) Magenta"), (20, "(Blues) Yellow"), (21, "(Blues) Black")),
-		SelectiveColorChoice::Magentas => ((22, "(Magentas) Cyan"), (23, "(Magentas) Magenta"), (24, "(Magentas) Yellow"), (25, "(Magentas) Black")),
-		SelectiveColorChoice::Whites => ((26, "(Whites) Cyan"), (27, "(Whites) Magenta"), (28, "(Whites) Yellow"), (29, "(Whites) Black")),
-		SelectiveColorChoice::Neutrals => ((30, "(Neutrals) Cyan"), (31, "(Neutrals) Magenta"), (32, "(Neutrals) Yellow"), (33, "(Neutrals) Black")),
-		SelectiveColorChoice::Blacks => ((34, "(Blacks) Cyan"), (35, "(Blacks) Magenta"), (36, "(Blacks) Yellow"), (37, "(Blacks) Black")),
-	};
-	let cyan = number_widget(document_node, node_id, c.0, c.1, NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
-	let magenta = number_widget(document_node, node_id, m.0, m.1, NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
-	let yellow = number_widget(document_node, node_id, y.0, y.1, NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
-	let black = number_widget(document_node, node_id, k.0, k.1, NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
-
-	// Mode
-	let mode_index = 1;
-	let mut mode = start_widgets(document_node, node_id, mode_index, "Mode", FrontendGraphDataType::General, true);
-	mode.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+fn gradient_type_widget(document_node: &DocumentNode, node_id: NodeId, index: usize) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, "Gradient Type", FrontendGraphDataType::General, true);
 	if let &NodeInput::Value {
-		tagged_value: TaggedValue::RelativeAbsolute(relative_or_absolute),
+		tagged_value: TaggedValue::GradientType(gradient_type),
 		exposed: false,
-	} = &document_node.inputs[mode_index]
+	} = &document_node.inputs[index]
 	{
 		let entries = vec![
-			RadioEntryData::new("relative")
-				.label("Relative")
-				.on_update(update_value(|_| TaggedValue::RelativeAbsolute(RelativeAbsolute::Relative), node_id, mode_index))
+			RadioEntryData::new("linear")
+				.label("Linear")
+				.on_update(update_value(move |_| TaggedValue::GradientType(GradientType::Linear), node_id, index))
 				.on_commit(commit_value),
-			RadioEntryData::new("absolute")
-				.label("Absolute")
-				.on_update(update_value(|_| TaggedValue::RelativeAbsolute(RelativeAbsolute::Absolute), node_id, mode_index))
+			RadioEntryData::new("radial")
+				.label("Radial")
+				.on_update(update_value(move |_| TaggedValue::GradientType(GradientType::Radial), node_id, index))
+				.on_commit(commit_value),
+			RadioEntryData::new("conic")
+				.label("Conic")
+				.on_update(update_value(move |_| TaggedValue::GradientType(GradientType::Conic), node_id, index))
 				.on_commit(commit_value),
 		];
-		mode.push(RadioInput::new(entries).selected_index(Some(relative_or_absolute as u32)).widget_holder());
-	};
-
-	vec![
-		// Colors choice
-		LayoutGroup::Row { widgets: colors },
-		// CMYK
-		LayoutGroup::Row { widgets: cyan },
-		LayoutGroup::Row { widgets: magenta },
-		LayoutGroup::Row { widgets: yellow },
-		LayoutGroup::Row { widgets: black },
-		// Mode
-		LayoutGroup::Row { widgets: mode },
-	]
-}
-
-#[cfg(feature = "gpu")]
-pub fn _gpu_map_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let map = text_widget(document_node, node_id, 1, "Map", true);
-
-	vec![LayoutGroup::Row { widgets: map }]
-}
 
-pub fn opacity_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let gamma = number_widget(document_node, node_id, 1, "Factor", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
-
-	vec![LayoutGroup::Row { widgets: gamma }]
-}
-
-pub fn blend_mode_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	vec![blend_mode(document_node, node_id, 1, "Blend Mode", true)]
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(gradient_type as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
 }
 
-pub fn blend_mode_value_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	vec![blend_mode(document_node, node_id, 0, "Blend Mode", true)]
-}
+fn gradient_row(row: &mut Vec<WidgetHolder>, positions: &[(f64, Color)], index: usize, node_id: NodeId, input_index: usize, in_degrees: bool) {
+	let position = if in_degrees {
+		format!("{:.0}°", positions[index].0 * 360.)
+	} else {
+		format!("{:.0}%", positions[index].0 * 100.)
+	};
+	let label = TextLabel::new(format!("Gradient: {position}")).tooltip("Adjustable by dragging the gradient stops in the viewport with the Gradient tool active");
+	row.push(label.widget_holder());
+	let on_update = {
+		let positions = positions.to_vec();
+		move |color_button: &ColorButton| {
+			let mut new_positions = positions.clone();
+			new_positions[index].1 = color_button.value.unwrap();
+			TaggedValue::GradientPositions(new_positions)
+		}
+	};
+	let color = ColorButton::new(Some(positions[index].1))
+		.on_update(update_value(on_update, node_id, input_index))
+		.on_commit(commit_value)
+		.allow_none(false);
+	add_blank_assist(row);
+	row.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+	row.push(color.widget_holder());
 
-pub fn posterize_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let value = number_widget(document_node, node_id, 1, "Levels", NumberInput::default().min(2.).max(255.).int(), true);
+	let mut skip_separator = false;
+	// Remove button
+	if positions.len() != index + 1 && index != 0 {
+		let on_update = {
+			let in_positions = positions.to_vec();
+			move |_: &IconButton| {
+				let mut new_positions = in_positions.clone();
+				new_positions.remove(index);
+				TaggedValue::GradientPositions(new_positions)
+			}
+		};
 
-	vec![LayoutGroup::Row { widgets: value }]
-}
+		skip_separator = true;
+		row.push(Separator::new(SeparatorType::Related).widget_holder());
+		row.push(
+			IconButton::new("Remove", 16)
+				.tooltip("Remove this gradient stop")
+				.on_update(update_value(on_update, node_id, input_index))
+				.on_commit(commit_value)
+				.widget_holder(),
+		);
+	}
+	// Add button
+	if positions.len() != index + 1 {
+		let on_update = {
+			let positions = positions.to_vec();
+			move |_: &IconButton| {
+				let mut new_positions = positions.clone();
 
-#[cfg(feature = "quantization")]
-pub fn quantize_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let value = number_widget(document_node, node_id, 1, "Levels", NumberInput::default().min(1.).max(1000.).int(), true);
-	let index = number_widget(document_node, node_id, 1, "Fit Fn Index", NumberInput::default().min(0.).max(2.).int(), true);
+				// Blend linearly between the two colors.
+				let get_color = |index: usize| match (new_positions[index].1, new_positions.get(index + 1).map(|x| x.1)) {
+					(a, Some(b)) => Color::from_rgbaf32_unchecked((a.r() + b.r()) / 2., (a.g() + b.g()) / 2., (a.b() + b.b()) / 2., ((a.a() + b.a()) / 2.).clamp(0., 1.)),
+					(_, None) => Color::WHITE,
+				};
+				let get_pos = |index: usize| (new_positions[index].0 + new_positions.get(index + 1).map(|v| v.0).unwrap_or(1.)) / 2.;
 
-	vec![LayoutGroup::Row { widgets: value }, LayoutGroup::Row { widgets: index }]
-}
-pub fn exposure_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let exposure = number_widget(document_node, node_id, 1, "Exposure", NumberInput::default().min(-20.).max(20.), true);
-	let offset = number_widget(document_node, node_id, 2, "Offset", NumberInput::default().min(-0.5).max(0.5), true);
-	let gamma_input = NumberInput::default().min(0.01).max(9.99).increment_step(0.1);
-	let gamma_correction = number_widget(document_node, node_id, 3, "Gamma Correction", gamma_input, true);
+				new_positions.push((get_pos(index), get_color(index)));
 
-	vec![
-		LayoutGroup::Row { widgets: exposure },
-		LayoutGroup::Row { widgets: offset },
-		LayoutGroup::Row { widgets: gamma_correction },
-	]
-}
+				new_positions.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
 
-pub fn add_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = number_widget(document_node, node_id, 1, "Addend", NumberInput::default(), true);
+				TaggedValue::GradientPositions(new_positions)
+			}
+		};
 
-	vec![LayoutGroup::Row { widgets }]
+		if !skip_separator {
+			row.push(Separator::new(SeparatorType::Related).widget_holder());
+		}
+		row.push(
+			IconButton::new("Add", 16)
+				.tooltip("Add a gradient stop after this")
+				.on_update(update_value(on_update, node_id, input_index))
+				.on_commit(commit_value)
+				.widget_holder(),
+		);
+	}
 }
 
-pub fn subtract_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = number_widget(document_node, node_id, 1, "Subtrahend", NumberInput::default(), true);
+/// Lists the stops of the gradient, with their positions given in degrees around the sweep of a conic gradient rather than as percentages.
+fn gradient_positions(rows: &mut Vec<LayoutGroup>, document_node: &DocumentNode, name: &str, node_id: NodeId, input_index: usize, in_degrees: bool) {
+	let mut widgets = vec![expose_widget(node_id, input_index, FrontendGraphDataType::General, document_node.inputs[input_index].is_exposed())];
+	if let NodeInput::Value {
+		tagged_value: TaggedValue::GradientPositions(gradient_positions),
+		exposed: false,
+	} = &document_node.inputs[input_index]
+	{
+		for index in 0..gradient_positions.len() {
+			gradient_row(&mut widgets, gradient_positions, index, node_id, input_index, in_degrees);
 
-	vec![LayoutGroup::Row { widgets }]
+			let widgets = std::mem::take(&mut widgets);
+			rows.push(LayoutGroup::Row { widgets });
+		}
+		let on_update = {
+			let gradient_positions = gradient_positions.clone();
+			move |_: &TextButton| {
+				let mut new_positions = gradient_positions.clone();
+				new_positions = new_positions.iter().map(|(distance, color)| (1. - distance, *color)).collect();
+				new_positions.reverse();
+				TaggedValue::GradientPositions(new_positions)
+			}
+		};
+		let invert = TextButton::new("Invert")
+			.icon(Some("Swap".into()))
+			.tooltip("Reverse the order of each color stop")
+			.on_update(update_value(on_update, node_id, input_index))
+			.on_commit(commit_value)
+			.widget_holder();
+
+		if widgets.is_empty() {
+			widgets.push(TextLabel::new("").widget_holder());
+			add_blank_assist(&mut widgets);
+		}
+		widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+		widgets.push(invert);
+
+		rows.push(LayoutGroup::Row { widgets });
+	} else {
+		widgets.push(TextLabel::new(name).widget_holder());
+		rows.push(LayoutGroup::Row { widgets })
+	}
 }
 
-pub fn divide_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = number_widget(document_node, node_id, 1, "Divisor", NumberInput::new(Some(1.)), true);
+fn color_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, color_props: ColorButton, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::Color, blank_assist);
 
-	vec![LayoutGroup::Row { widgets }]
+	if let NodeInput::Value { tagged_value, exposed: false } = &document_node.inputs[index] {
+		if let &TaggedValue::Color(x) = tagged_value {
+			widgets.extend_from_slice(&[
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				color_props
+					.value(Some(x as Color))
+					.on_update(update_value(|x: &ColorButton| TaggedValue::Color(x.value.unwrap()), node_id, index))
+					.on_commit(commit_value)
+					.widget_holder(),
+			])
+		} else if let &TaggedValue::OptionalColor(x) = tagged_value {
+			widgets.extend_from_slice(&[
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				color_props
+					.value(x)
+					.on_update(update_value(|x: &ColorButton| TaggedValue::OptionalColor(x.value), node_id, index))
+					.on_commit(commit_value)
+					.widget_holder(),
+			])
+		}
+	}
+	LayoutGroup::Row { widgets }
 }
 
-pub fn multiply_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = number_widget(document_node, node_id, 1, "Multiplicand", NumberInput::new(Some(1.)), true);
+fn curves_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
 
-	vec![LayoutGroup::Row { widgets }]
+	if let NodeInput::Value {
+		tagged_value: TaggedValue::Curve(curve),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			CurveInput::new(curve.clone())
+				.on_update(update_value(|x: &CurveInput| TaggedValue::Curve(x.value.clone()), node_id, index))
+				.on_commit(commit_value)
+				.widget_holder(),
+		])
+	}
+	LayoutGroup::Row { widgets }
 }
 
-pub fn exponent_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = number_widget(document_node, node_id, 1, "Power", NumberInput::new(Some(2.)), true);
+pub fn levels_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let input_shadows = number_widget(document_node, node_id, 1, "Shadows", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+	let input_midtones = number_widget(document_node, node_id, 2, "Midtones", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+	let input_highlights = number_widget(document_node, node_id, 3, "Highlights", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+	let output_minimums = number_widget(document_node, node_id, 4, "Output Minimums", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+	let output_maximums = number_widget(document_node, node_id, 5, "Output Maximums", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
 
-	vec![LayoutGroup::Row { widgets }]
+	vec![
+		LayoutGroup::Row { widgets: input_shadows },
+		LayoutGroup::Row { widgets: input_midtones },
+		LayoutGroup::Row { widgets: input_highlights },
+		LayoutGroup::Row { widgets: output_minimums },
+		LayoutGroup::Row { widgets: output_maximums },
+	]
 }
 
-pub fn log_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = number_widget(document_node, node_id, 1, "Base", NumberInput::default(), true);
+pub fn black_and_white_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	const MIN: f64 = -200.;
+	const MAX: f64 = 300.;
+	// TODO: Add tint color (blended above using the "Color" blend mode)
+	let tint = color_widget(document_node, node_id, 1, "Tint", ColorButton::default(), true);
+	let r_weight = number_widget(document_node, node_id, 2, "Reds", NumberInput::default().mode_range().min(MIN).max(MAX).unit("%"), true);
+	let y_weight = number_widget(document_node, node_id, 3, "Yellows", NumberInput::default().mode_range().min(MIN).max(MAX).unit("%"), true);
+	let g_weight = number_widget(document_node, node_id, 4, "Greens", NumberInput::default().mode_range().min(MIN).max(MAX).unit("%"), true);
+	let c_weight = number_widget(document_node, node_id, 5, "Cyans", NumberInput::default().mode_range().min(MIN).max(MAX).unit("%"), true);
+	let b_weight = number_widget(document_node, node_id, 6, "Blues", NumberInput::default().mode_range().min(MIN).max(MAX).unit("%"), true);
+	let m_weight = number_widget(document_node, node_id, 7, "Magentas", NumberInput::default().mode_range().min(MIN).max(MAX).unit("%"), true);
 
-	vec![LayoutGroup::Row { widgets }]
+	vec![
+		tint,
+		LayoutGroup::Row { widgets: r_weight },
+		LayoutGroup::Row { widgets: y_weight },
+		LayoutGroup::Row { widgets: g_weight },
+		LayoutGroup::Row { widgets: c_weight },
+		LayoutGroup::Row { widgets: b_weight },
+		LayoutGroup::Row { widgets: m_weight },
+	]
 }
 
-pub fn max_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = number_widget(document_node, node_id, 1, "Maximum", NumberInput::default(), true);
+pub fn blend_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let backdrop = color_widget(document_node, node_id, 1, "Backdrop", ColorButton::default(), true);
+	let blend_mode = blend_mode(document_node, node_id, 2, "Blend Mode", true);
+	let opacity = number_widget(document_node, node_id, 3, "Opacity", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
 
-	vec![LayoutGroup::Row { widgets }]
+	vec![backdrop, blend_mode, LayoutGroup::Row { widgets: opacity }]
 }
 
-pub fn min_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = number_widget(document_node, node_id, 1, "Minimum", NumberInput::default(), true);
+pub fn number_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = number_widget(document_node, node_id, 0, "Number", NumberInput::default(), true);
 
 	vec![LayoutGroup::Row { widgets }]
 }
 
-pub fn eq_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = number_widget(document_node, node_id, 1, "Equals", NumberInput::default(), true);
+pub fn vector2_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let x = number_widget(document_node, node_id, 1, "X", NumberInput::default(), true);
+	let y = number_widget(document_node, node_id, 2, "Y", NumberInput::default(), true);
 
-	vec![LayoutGroup::Row { widgets }]
+	vec![LayoutGroup::Row { widgets: x }, LayoutGroup::Row { widgets: y }]
 }
 
-pub fn modulo_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = number_widget(document_node, node_id, 1, "Modulo", NumberInput::default(), true);
+pub fn boolean_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = bool_widget(document_node, node_id, 0, "Bool", true);
 
 	vec![LayoutGroup::Row { widgets }]
 }
 
-pub fn circle_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	vec![LayoutGroup::Row {
-		widgets: number_widget(document_node, node_id, 1, "Radius", NumberInput::default(), true),
-	}]
+pub fn color_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	vec![color_widget(document_node, node_id, 0, "Color", ColorButton::default(), true)]
 }
 
-pub fn ellipse_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let operand = |name: &str, index| {
-		let widgets = number_widget(document_node, node_id, index, name, NumberInput::default(), true);
+pub fn load_image_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let url = text_widget(document_node, node_id, 1, "Url", true);
 
-		LayoutGroup::Row { widgets }
-	};
-	vec![operand("Radius X", 1), operand("Radius Y", 2)]
+	vec![LayoutGroup::Row { widgets: url }]
 }
 
-pub fn rectangle_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let operand = |name: &str, index| {
-		let widgets = number_widget(document_node, node_id, index, name, NumberInput::default(), true);
+pub fn watch_file_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let path = text_widget(document_node, node_id, 1, "Path", true);
 
-		LayoutGroup::Row { widgets }
-	};
-	vec![operand("Size X", 1), operand("Size Y", 2)]
+	vec![LayoutGroup::Row { widgets: path }.with_tooltip("The file on disk, which is read again whenever it's saved by another program")]
 }
 
-pub fn regular_polygon_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let points = number_widget(document_node, node_id, 1, "Points", NumberInput::default().min(3.), true);
-	let radius = number_widget(document_node, node_id, 2, "Radius", NumberInput::default(), true);
+pub fn fetch_url_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let url = text_widget(document_node, node_id, 1, "URL", true);
+	let refresh = number_widget(document_node, node_id, 2, "Refresh", NumberInput::default().min(0.).unit(" s"), true);
 
-	vec![LayoutGroup::Row { widgets: points }, LayoutGroup::Row { widgets: radius }]
+	vec![
+		LayoutGroup::Row { widgets: url }.with_tooltip("The address to download from, which is downloaded once and then kept until the URL is changed or the download is refreshed"),
+		LayoutGroup::Row { widgets: refresh }.with_tooltip("Seconds after which the download is fetched again the next time the graph runs, or 0 to keep it until the URL is changed"),
+	]
 }
 
-pub fn star_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let points = number_widget(document_node, node_id, 1, "Points", NumberInput::default().min(2.), true);
-	let radius = number_widget(document_node, node_id, 2, "Radius", NumberInput::default(), true);
-	let inner_radius = number_widget(document_node, node_id, 3, "Inner Radius", NumberInput::default(), true);
+pub fn output_properties(_document_node: &DocumentNode, _node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let label = TextLabel::new("Graphics fed into the Output are drawn in the viewport").widget_holder();
 
-	vec![LayoutGroup::Row { widgets: points }, LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: inner_radius }]
+	vec![LayoutGroup::Row { widgets: vec![label] }]
 }
 
-pub fn line_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let operand = |name: &str, index| vec2_widget(document_node, node_id, index, name, "X", "Y", "px", None, add_blank_assist);
-	vec![operand("Start", 1), operand("End", 2)]
-}
-pub fn spline_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	vec![LayoutGroup::Row {
-		widgets: vec_dvec2_input(document_node, node_id, 1, "Points", TextInput::default().centered(true), true),
-	}]
-}
+pub fn mask_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let mask = color_widget(document_node, node_id, 1, "Stencil", ColorButton::default(), true);
 
-pub fn logic_operator_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let widgets = bool_widget(document_node, node_id, 0, "Operand B", true);
-	vec![LayoutGroup::Row { widgets }]
+	vec![mask]
 }
 
-pub fn transform_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let translation_assist = |widgets: &mut Vec<WidgetHolder>| {
-		let pivot_index = 5;
-		if let NodeInput::Value {
-			tagged_value: TaggedValue::DVec2(pivot),
-			exposed: false,
-		} = document_node.inputs[pivot_index]
-		{
-			widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
-			widgets.push(
-				PivotInput::new(pivot.into())
-					.on_update(update_value(|pivot: &PivotInput| TaggedValue::DVec2(Into::<Option<DVec2>>::into(pivot.position).unwrap()), node_id, 5))
-					.on_commit(commit_value)
-					.widget_holder(),
-			);
-		} else {
-			add_blank_assist(widgets);
-		}
-	};
-	let translation = vec2_widget(document_node, node_id, 1, "Translation", "X", "Y", " px", None, translation_assist);
-
-	let rotation = {
-		let index = 2;
-
-		let mut widgets = start_widgets(document_node, node_id, index, "Rotation", FrontendGraphDataType::Number, true);
-
-		if let NodeInput::Value {
-			tagged_value: TaggedValue::F64(val),
-			exposed: false,
-		} = document_node.inputs[index]
-		{
-			widgets.extend_from_slice(&[
-				Separator::new(SeparatorType::Unrelated).widget_holder(),
-				NumberInput::new(Some(val.to_degrees()))
-					.unit("°")
-					.mode(NumberInputMode::Range)
-					.range_min(Some(-180.))
-					.range_max(Some(180.))
-					.on_update(update_value(|number_input: &NumberInput| TaggedValue::F64(number_input.value.unwrap().to_radians()), node_id, index))
-					.on_commit(commit_value)
-					.widget_holder(),
-			]);
-		}
-
-		LayoutGroup::Row { widgets }
-	};
+pub fn color_channel_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	vec![color_channel(document_node, node_id, 0, "Channel", true)]
+}
 
-	let scale = vec2_widget(document_node, node_id, 3, "Scale", "W", "H", "x", None, add_blank_assist);
+pub fn luminance_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let luminance_calc = luminance_calculation(document_node, node_id, 1, "Luminance Calc", true);
 
-	vec![translation, rotation, scale]
+	vec![luminance_calc]
 }
 
-pub fn node_section_font(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let text = text_area_widget(document_node, node_id, 1, "Text", true);
-	let (font, style) = font_inputs(document_node, node_id, 2, "Font", true);
-	let size = number_widget(document_node, node_id, 3, "Size", NumberInput::default().unit(" px").min(1.), true);
+pub fn insert_channel_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let color_channel = color_channel(document_node, node_id, 2, "Into", true);
 
-	let mut result = vec![LayoutGroup::Row { widgets: text }, LayoutGroup::Row { widgets: font }];
-	if let Some(style) = style {
-		result.push(LayoutGroup::Row { widgets: style });
-	}
-	result.push(LayoutGroup::Row { widgets: size });
-	result
+	vec![color_channel]
 }
 
-pub fn imaginate_properties(document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let imaginate_node = [context.nested_path, &[node_id]].concat();
-
-	let resolve_input = |name: &str| IMAGINATE_NODE.inputs.iter().position(|input| input.name == name).unwrap_or_else(|| panic!("Input {name} not found"));
-	let seed_index = resolve_input("Seed");
-	let resolution_index = resolve_input("Resolution");
-	let samples_index = resolve_input("Samples");
-	let sampling_method_index = resolve_input("Sampling Method");
-	let text_guidance_index = resolve_input("Prompt Guidance");
-	let text_index = resolve_input("Prompt");
-	let neg_index = resolve_input("Negative Prompt");
-	let base_img_index = resolve_input("Adapt Input Image");
-	let img_creativity_index = resolve_input("Image Creativity");
-	// let mask_index = resolve_input("Masking Layer");
-	// let inpaint_index = resolve_input("Inpaint");
-	// let mask_blur_index = resolve_input("Mask Blur");
-	// let mask_fill_index = resolve_input("Mask Starting Fill");
-	let faces_index = resolve_input("Improve Faces");
-	let tiling_index = resolve_input("Tiling");
+pub fn extract_channel_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let color_channel = color_channel(document_node, node_id, 1, "From", true);
 
-	let controller = &document_node.inputs[resolve_input("Controller")];
+	vec![color_channel]
+}
 
-	let server_status = {
-		let server_status = context.persistent_data.imaginate.server_status();
-		let status_text = server_status.to_text();
-		let mut widgets = vec![
-			TextLabel::new("Server").widget_holder(),
-			Separator::new(SeparatorType::Unrelated).widget_holder(),
-			IconButton::new("Settings", 24)
-				.tooltip("Preferences: Imaginate")
-				.on_update(|_| DialogMessage::RequestPreferencesDialog.into())
-				.widget_holder(),
-			Separator::new(SeparatorType::Unrelated).widget_holder(),
-			TextLabel::new(status_text).bold(true).widget_holder(),
-			Separator::new(SeparatorType::Related).widget_holder(),
-			IconButton::new("Reload", 24)
-				.tooltip("Refresh connection status")
-				.on_update(|_| PortfolioMessage::ImaginateCheckServerStatus.into())
-				.widget_holder(),
-		];
-		if let ImaginateServerStatus::Unavailable | ImaginateServerStatus::Failed(_) = server_status {
-			widgets.extend([
-				Separator::new(SeparatorType::Unrelated).widget_holder(),
-				TextButton::new("Server Help")
-					.tooltip("Learn how to connect Imaginate to an image generation server")
-					.on_update(|_| {
-						FrontendMessage::TriggerVisitLink {
-							url: "https://github.com/GraphiteEditor/Graphite/discussions/1089".to_string(),
-						}
-						.into()
-					})
-					.widget_holder(),
-			]);
-		}
-		LayoutGroup::Row { widgets }.with_tooltip("Connection status to the server that computes generated images")
+// Noise Type is commented out for now as there is only one type of noise (White Noise).
+// As soon as there are more types of noise, this should be uncommented.
+pub fn noise_pattern_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	// Get the current values of the inputs of interest so they can set whether certain inputs are disabled based on various conditions.
+	let current_noise_type = match &document_node.inputs[4] {
+		NodeInput::Value {
+			tagged_value: TaggedValue::NoiseType(noise_type),
+			..
+		} => Some(*noise_type),
+		_ => None,
 	};
-
-	let &NodeInput::Value {
-		tagged_value: TaggedValue::ImaginateController(ref controller),
-		..
-	} = controller
-	else {
-		panic!("Invalid output status input")
+	let current_domain_warp_type = match &document_node.inputs[5] {
+		NodeInput::Value {
+			tagged_value: TaggedValue::DomainWarpType(domain_warp_type),
+			..
+		} => Some(*domain_warp_type),
+		_ => None,
 	};
-	let imaginate_status = controller.get_status();
-
-	let use_base_image = if let &NodeInput::Value {
-		tagged_value: TaggedValue::Bool(use_base_image),
-		..
-	} = &document_node.inputs[base_img_index]
-	{
-		use_base_image
-	} else {
-		true
+	let current_fractal_type = match &document_node.inputs[7] {
+		NodeInput::Value {
+			tagged_value: TaggedValue::FractalType(fractal_type),
+			..
+		} => Some(*fractal_type),
+		_ => None,
 	};
+	let fractal_active = current_fractal_type != Some(FractalType::None);
+	let coherent_noise_active = current_noise_type != Some(NoiseType::WhiteNoise);
+	let cellular_noise_active = current_noise_type == Some(NoiseType::Cellular);
+	let ping_pong_active = current_fractal_type == Some(FractalType::PingPong);
+	let domain_warp_active = current_domain_warp_type != Some(DomainWarpType::None);
+	let domain_warp_only_fractal_type_wrongly_active =
+		!domain_warp_active && (current_fractal_type == Some(FractalType::DomainWarpIndependent) || current_fractal_type == Some(FractalType::DomainWarpProgressive));
 
-	let transform_not_connected = false;
-
-	let progress = {
-		let mut widgets = vec![TextLabel::new("Progress").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
-		add_blank_assist(&mut widgets);
-		let status = imaginate_status.to_text();
-		widgets.push(TextLabel::new(status.as_ref()).bold(true).widget_holder());
-		LayoutGroup::Row { widgets }.with_tooltip(match imaginate_status {
-			ImaginateStatus::Failed(_) => status.as_ref(),
-			_ => "When generating, the percentage represents how many sampling steps have so far been processed out of the target number",
-		})
-	};
+	// All
+	let dimensions = vec2_widget(document_node, node_id, 1, "Dimensions", "W", "H", "px", Some(1.), add_blank_assist);
+	let seed = number_widget(document_node, node_id, 2, "Seed", NumberInput::default().min(0.).is_integer(true), true);
+	let scale = number_widget(document_node, node_id, 3, "Scale", NumberInput::default().min(0.).disabled(!coherent_noise_active), true);
+	let noise_type_row = noise_type(document_node, node_id, 4, "Noise Type", true);
 
-	let image_controls = {
-		let mut widgets = vec![TextLabel::new("Image").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
+	// Domain Warp
+	let domain_warp_type_row = domain_warp_type(document_node, node_id, 5, "Domain Warp Type", true, !coherent_noise_active);
+	let domain_warp_amplitude = number_widget(
+		document_node,
+		node_id,
+		6,
+		"Domain Warp Amplitude",
+		NumberInput::default().min(0.).disabled(!coherent_noise_active || !domain_warp_active),
+		true,
+	);
 
-		match &imaginate_status {
-			ImaginateStatus::Beginning | ImaginateStatus::Uploading => {
-				add_blank_assist(&mut widgets);
-				widgets.push(TextButton::new("Beginning...").tooltip("Sending image generation request to the server").disabled(true).widget_holder());
-			}
-			ImaginateStatus::Generating(_) => {
-				add_blank_assist(&mut widgets);
-				widgets.push(
-					TextButton::new("Terminate")
-						.tooltip("Cancel the in-progress image generation and keep the latest progress")
-						.on_update({
-							let controller = controller.clone();
-							move |_| {
-								controller.request_termination();
-								Message::NoOp
-							}
-						})
-						.widget_holder(),
-				);
-			}
-			ImaginateStatus::Terminating => {
-				add_blank_assist(&mut widgets);
-				widgets.push(
-					TextButton::new("Terminating...")
-						.tooltip("Waiting on the final image generated after termination")
+	// Fractal
+	let fractal_type_row = fractal_type(document_node, node_id, 7, "Fractal Type", true, !coherent_noise_active);
+	let fractal_octaves = number_widget(
+		document_node,
+		node_id,
+		8,
+		"Fractal Octaves",
+		NumberInput::default()
+			.mode_range()
+			.min(1.)
+			.max(10.)
+			.range_max(Some(4.))
+			.is_integer(true)
+			.disabled(!coherent_noise_active || !fractal_active || domain_warp_only_fractal_type_wrongly_active),
+		true,
+	);
+	let fractal_lacunarity = number_widget(
+		document_node,
+		node_id,
+		9,
+		"Fractal Lacunarity",
+		NumberInput::default()
+			.mode_range()
+			.min(0.)
+			.range_max(Some(10.))
+			.disabled(!coherent_noise_active || !fractal_active || domain_warp_only_fractal_type_wrongly_active),
+		true,
+	);
+	let fractal_gain = number_widget(
+		document_node,
+		node_id,
+		10,
+		"Fractal Gain",
+		NumberInput::default()
+			.mode_range()
+			.min(0.)
+			.range_max(Some(10.))
+			.disabled(!coherent_noise_active || !fractal_active || domain_warp_only_fractal_type_wrongly_active),
+		true,
+	);
+	let fractal_weighted_strength = number_widget(
+		document_node,
+		node_id,
+		11,
+		"Fractal Weighted Strength",
+		NumberInput::default()
+			.mode_range()
+			.min(0.)
+			.max(1.) // Defined for the 0-1 range
+			.disabled(!coherent_noise_active || !fractal_active || domain_warp_only_fractal_type_wrongly_active),
+		true,
+	);
+	let fractal_ping_pong_strength = number_widget(
+		document_node,
+		node_id,
+		12,
+		"Fractal Ping Pong Strength",
+		NumberInput::default()
+			.mode_range()
+			.min(0.)
+			.range_max(Some(10.))
+			.disabled(!ping_pong_active || !coherent_noise_active || !fractal_active || domain_warp_only_fractal_type_wrongly_active),
+		true,
+	);
+
+	// Cellular
+	let cellular_distance_function_row = cellular_distance_function(document_node, node_id, 13, "Cellular Distance Function", true, !coherent_noise_active || !cellular_noise_active);
+	let cellular_return_type = cellular_return_type(document_node, node_id, 14, "Cellular Return Type", true, !coherent_noise_active || !cellular_noise_active);
+	let cellular_jitter = number_widget(
+		document_node,
+		node_id,
+		15,
+		"Cellular Jitter",
+		NumberInput::default()
+			.mode_range()
+			.range_min(Some(0.))
+			.range_max(Some(1.))
+			.disabled(!coherent_noise_active || !cellular_noise_active),
+		true,
+	);
+
+	vec![
+		// All
+		dimensions,
+		LayoutGroup::Row { widgets: seed },
+		LayoutGroup::Row { widgets: scale },
+		noise_type_row,
+		LayoutGroup::Row { widgets: Vec::new() },
+		// Domain Warp
+		domain_warp_type_row,
+		LayoutGroup::Row { widgets: domain_warp_amplitude },
+		LayoutGroup::Row { widgets: Vec::new() },
+		// Fractal
+		fractal_type_row,
+		LayoutGroup::Row { widgets: fractal_octaves },
+		LayoutGroup::Row { widgets: fractal_lacunarity },
+		LayoutGroup::Row { widgets: fractal_gain },
+		LayoutGroup::Row { widgets: fractal_weighted_strength },
+		LayoutGroup::Row { widgets: fractal_ping_pong_strength },
+		LayoutGroup::Row { widgets: Vec::new() },
+		// Cellular
+		cellular_distance_function_row,
+		cellular_return_type,
+		LayoutGroup::Row { widgets: cellular_jitter },
+	]
+}
+
+pub fn adjust_hsl_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let hue_shift = number_widget(document_node, node_id, 1, "Hue Shift", NumberInput::default().min(-180.).max(180.).unit("°"), true);
+	let saturation_shift = number_widget(document_node, node_id, 2, "Saturation Shift", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+	let lightness_shift = number_widget(document_node, node_id, 3, "Lightness Shift", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+
+	vec![
+		LayoutGroup::Row { widgets: hue_shift },
+		LayoutGroup::Row { widgets: saturation_shift },
+		LayoutGroup::Row { widgets: lightness_shift },
+	]
+}
+
+pub fn brightness_contrast_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let brightness = number_widget(document_node, node_id, 1, "Brightness", NumberInput::default().min(-150.).max(150.), true);
+	let contrast = number_widget(document_node, node_id, 2, "Contrast", NumberInput::default().min(-100.).max(100.), true);
+	let use_legacy = bool_widget(document_node, node_id, 3, "Use Legacy", true);
+
+	vec![
+		LayoutGroup::Row { widgets: brightness },
+		LayoutGroup::Row { widgets: contrast },
+		LayoutGroup::Row { widgets: use_legacy },
+	]
+}
+
+pub fn curves_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let curves = curves_widget(document_node, node_id, 1, "Curve", true);
+
+	vec![curves]
+}
+
+pub fn _blur_image_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let radius = number_widget(document_node, node_id, 1, "Radius", NumberInput::default().min(0.).max(20.).int(), true);
+	let sigma = number_widget(document_node, node_id, 2, "Sigma", NumberInput::default().min(0.).max(10000.), true);
+
+	vec![LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: sigma }]
+}
+
+pub fn adjust_threshold_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let thereshold_min = number_widget(document_node, node_id, 1, "Min Luminance", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+	let thereshold_max = number_widget(document_node, node_id, 2, "Max Luminance", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+	let luminance_calc = luminance_calculation(document_node, node_id, 3, "Luminance Calc", true);
+
+	vec![LayoutGroup::Row { widgets: thereshold_min }, LayoutGroup::Row { widgets: thereshold_max }, luminance_calc]
+}
+
+pub fn adjust_vibrance_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let vibrance = number_widget(document_node, node_id, 1, "Vibrance", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+
+	vec![LayoutGroup::Row { widgets: vibrance }]
+}
+
+pub fn adjust_channel_mixer_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	// Monochrome
+	let monochrome_index = 1;
+	let monochrome = bool_widget(document_node, node_id, monochrome_index, "Monochrome", true);
+	let is_monochrome = if let &NodeInput::Value {
+		tagged_value: TaggedValue::Bool(monochrome_choice),
+		..
+	} = &document_node.inputs[monochrome_index]
+	{
+		monochrome_choice
+	} else {
+		false
+	};
+
+	// Output channel choice
+	let output_channel_index = 18;
+	let mut output_channel = vec![TextLabel::new("Output Channel").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
+	add_blank_assist(&mut output_channel);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::RedGreenBlue(choice),
+		exposed: false,
+	} = &document_node.inputs[output_channel_index]
+	{
+		let entries = vec![
+			RadioEntryData::new(format!("{:?}", RedGreenBlue::Red))
+				.label(RedGreenBlue::Red.to_string())
+				.on_update(update_value(|_| TaggedValue::RedGreenBlue(RedGreenBlue::Red), node_id, output_channel_index))
+				.on_commit(commit_value),
+			RadioEntryData::new(format!("{:?}", RedGreenBlue::Green))
+				.label(RedGreenBlue::Green.to_string())
+				.on_update(update_value(|_| TaggedValue::RedGreenBlue(RedGreenBlue::Green), node_id, output_channel_index))
+				.on_commit(commit_value),
+			RadioEntryData::new(format!("{:?}", RedGreenBlue::Blue))
+				.label(RedGreenBlue::Blue.to_string())
+				.on_update(update_value(|_| TaggedValue::RedGreenBlue(RedGreenBlue::Blue), node_id, output_channel_index))
+				.on_commit(commit_value),
+		];
+		output_channel.extend([RadioInput::new(entries).selected_index(Some(choice as u32)).widget_holder()]);
+	};
+	let is_output_channel = if let &NodeInput::Value {
+		tagged_value: TaggedValue::RedGreenBlue(choice),
+		..
+	} = &document_node.inputs[output_channel_index]
+	{
+		choice
+	} else {
+		warn!("Channel Mixer node properties panel could not be displayed.");
+		return vec![];
+	};
+
+	// Channel values
+	let (r, g, b, c) = match (is_monochrome, is_output_channel) {
+		(true, _) => ((2, "Red", 40.), (3, "Green", 40.), (4, "Blue", 20.), (5, "Constant", 0.)),
+		(false, RedGreenBlue::Red) => ((6, "(Red) Red", 100.), (7, "(Red) Green", 0.), (8, "(Red) Blue", 0.), (9, "(Red) Constant", 0.)),
+		(false, RedGreenBlue::Green) => ((10, "(Green) Red", 0.), (11, "(Green) Green", 100.), (12, "(Green) Blue", 0.), (13, "(Green) Constant", 0.)),
+		(false, RedGreenBlue::Blue) => ((14, "(Blue) Red", 0.), (15, "(Blue) Green", 0.), (16, "(Blue) Blue", 100.), (17, "(Blue) Constant", 0.)),
+	};
+	let red = number_widget(
+		document_node,
+		node_id,
+		r.0,
+		r.1,
+		NumberInput::default().mode_range().min(-200.).max(200.).value(Some(r.2)).unit("%"),
+		true,
+	);
+	let green = number_widget(
+		document_node,
+		node_id,
+		g.0,
+		g.1,
+		NumberInput::default().mode_range().min(-200.).max(200.).value(Some(g.2)).unit("%"),
+		true,
+	);
+	let blue = number_widget(
+		document_node,
+		node_id,
+		b.0,
+		b.1,
+		NumberInput::default().mode_range().min(-200.).max(200.).value(Some(b.2)).unit("%"),
+		true,
+	);
+	let constant = number_widget(
+		document_node,
+		node_id,
+		c.0,
+		c.1,
+		NumberInput::default().mode_range().min(-200.).max(200.).value(Some(c.2)).unit("%"),
+		true,
+	);
+
+	// Monochrome
+	let mut layout = vec![LayoutGroup::Row { widgets: monochrome }];
+	// Output channel choice
+	if !is_monochrome {
+		layout.push(LayoutGroup::Row { widgets: output_channel });
+	};
+	// Channel values
+	layout.extend([
+		LayoutGroup::Row { widgets: red },
+		LayoutGroup::Row { widgets: green },
+		LayoutGroup::Row { widgets: blue },
+		LayoutGroup::Row { widgets: constant },
+	]);
+	layout
+}
+
+pub fn adjust_selective_color_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	// Colors choice
+	let colors_index = 38;
+	let mut colors = vec![TextLabel::new("Colors").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
+	add_blank_assist(&mut colors);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::SelectiveColorChoice(choice),
+		exposed: false,
+	} = &document_node.inputs[colors_index]
+	{
+		use SelectiveColorChoice::*;
+		let entries = [[Reds, Yellows, Greens, Cyans, Blues, Magentas].as_slice(), [Whites, Neutrals, Blacks].as_slice()]
+			.into_iter()
+			.map(|section| {
+				section
+					.iter()
+					.map(|choice| {
+						MenuListEntry::new(format!("{choice:?}"))
+							.label(choice.to_string())
+							.on_update(update_value(move |_| TaggedValue::SelectiveColorChoice(*choice), node_id, colors_index))
+							.on_commit(commit_value)
+					})
+					.collect()
+			})
+			.collect();
+		colors.extend([DropdownInput::new(entries).selected_index(Some(choice as u32)).widget_holder()]);
+	};
+	let colors_choice_index = if let &NodeInput::Value {
+		tagged_value: TaggedValue::SelectiveColorChoice(choice),
+		..
+	} = &document_node.inputs[colors_index]
+	{
+		choice
+	} else {
+		warn!("Selective Color node properties panel could not be displayed.");
+		return vec![];
+	};
+
+	// CMYK
+	let (c, m, y, k) = match colors_choice_index {
+		SelectiveColorChoice::Reds => ((2, "(Reds) Cyan"), (3, "(Reds) Magenta"), (4, "(Reds) Yellow"), (5, "(Reds) Black")),
+		SelectiveColorChoice::Yellows => ((6, "(Yellows) Cyan"), (7, "(Yellows) Magenta"), (8, "(Yellows) Yellow"), (9, "(Yellows) Black")),
+		SelectiveColorChoice::Greens => ((10, "(Greens) Cyan"), (11, "(Greens) Magenta"), (12, "(Greens) Yellow"), (13, "(Greens) Black")),
+		SelectiveColorChoice::Cyans => ((14, "(Cyans) Cyan"), (15, "(Cyans) Magenta"), (16, "(Cyans) Yellow"), (17, "(Cyans) Black")),
+		SelectiveColorChoice::Blues => ((18, "(Blues) Cyan"), (19, "(Blues) Magenta"), (20, "(Blues) Yellow"), (21, "(Blues) Black")),
+		SelectiveColorChoice::Magentas => ((22, "(Magentas) Cyan"), (23, "(Magentas) Magenta"), (24, "(Magentas) Yellow"), (25, "(Magentas) Black")),
+		SelectiveColorChoice::Whites => ((26, "(Whites) Cyan"), (27, "(Whites) Magenta"), (28, "(Whites) Yellow"), (29, "(Whites) Black")),
+		SelectiveColorChoice::Neutrals => ((30, "(Neutrals) Cyan"), (31, "(Neutrals) Magenta"), (32, "(Neutrals) Yellow"), (33, "(Neutrals) Black")),
+		SelectiveColorChoice::Blacks => ((34, "(Blacks) Cyan"), (35, "(Blacks) Magenta"), (36, "(Blacks) Yellow"), (37, "(Blacks) Black")),
+	};
+	let cyan = number_widget(document_node, node_id, c.0, c.1, NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+	let magenta = number_widget(document_node, node_id, m.0, m.1, NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+	let yellow = number_widget(document_node, node_id, y.0, y.1, NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+	let black = number_widget(document_node, node_id, k.0, k.1, NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+
+	// Mode
+	let mode_index = 1;
+	let mut mode = start_widgets(document_node, node_id, mode_index, "Mode", FrontendGraphDataType::General, true);
+	mode.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::RelativeAbsolute(relative_or_absolute),
+		exposed: false,
+	} = &document_node.inputs[mode_index]
+	{
+		let entries = vec![
+			RadioEntryData::new("relative")
+				.label("Relative")
+				.on_update(update_value(|_| TaggedValue::RelativeAbsolute(RelativeAbsolute::Relative), node_id, mode_index))
+				.on_commit(commit_value),
+			RadioEntryData::new("absolute")
+				.label("Absolute")
+				.on_update(update_value(|_| TaggedValue::RelativeAbsolute(RelativeAbsolute::Absolute), node_id, mode_index))
+				.on_commit(commit_value),
+		];
+		mode.push(RadioInput::new(entries).selected_index(Some(relative_or_absolute as u32)).widget_holder());
+	};
+
+	vec![
+		// Colors choice
+		LayoutGroup::Row { widgets: colors },
+		// CMYK
+		LayoutGroup::Row { widgets: cyan },
+		LayoutGroup::Row { widgets: magenta },
+		LayoutGroup::Row { widgets: yellow },
+		LayoutGroup::Row { widgets: black },
+		// Mode
+		LayoutGroup::Row { widgets: mode },
+	]
+}
+
+#[cfg(feature = "gpu")]
+pub fn _gpu_map_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let map = text_widget(document_node, node_id, 1, "Map", true);
+
+	vec![LayoutGroup::Row { widgets: map }]
+}
+
+pub fn opacity_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let gamma = number_widget(document_node, node_id, 1, "Factor", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+
+	vec![LayoutGroup::Row { widgets: gamma }]
+}
+
+pub fn blend_mode_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	vec![blend_mode(document_node, node_id, 1, "Blend Mode", true)]
+}
+
+pub fn blend_mode_value_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	vec![blend_mode(document_node, node_id, 0, "Blend Mode", true)]
+}
+
+pub fn posterize_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let value = number_widget(document_node, node_id, 1, "Levels", NumberInput::default().min(2.).max(255.).int(), true);
+
+	vec![LayoutGroup::Row { widgets: value }]
+}
+
+#[cfg(feature = "quantization")]
+pub fn quantize_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let value = number_widget(document_node, node_id, 1, "Levels", NumberInput::default().min(1.).max(1000.).int(), true);
+	let index = number_widget(document_node, node_id, 1, "Fit Fn Index", NumberInput::default().min(0.).max(2.).int(), true);
+
+	vec![LayoutGroup::Row { widgets: value }, LayoutGroup::Row { widgets: index }]
+}
+pub fn exposure_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let exposure = number_widget(document_node, node_id, 1, "Exposure", NumberInput::default().min(-20.).max(20.), true);
+	let offset = number_widget(document_node, node_id, 2, "Offset", NumberInput::default().min(-0.5).max(0.5), true);
+	let gamma_input = NumberInput::default().min(0.01).max(9.99).increment_step(0.1);
+	let gamma_correction = number_widget(document_node, node_id, 3, "Gamma Correction", gamma_input, true);
+
+	vec![
+		LayoutGroup::Row { widgets: exposure },
+		LayoutGroup::Row { widgets: offset },
+		LayoutGroup::Row { widgets: gamma_correction },
+	]
+}
+
+pub fn add_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = number_widget(document_node, node_id, 1, "Addend", NumberInput::default(), true);
+
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub fn subtract_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = number_widget(document_node, node_id, 1, "Subtrahend", NumberInput::default(), true);
+
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub fn divide_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = number_widget(document_node, node_id, 1, "Divisor", NumberInput::new(Some(1.)), true);
+
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub fn multiply_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = number_widget(document_node, node_id, 1, "Multiplicand", NumberInput::new(Some(1.)), true);
+
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub fn exponent_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = number_widget(document_node, node_id, 1, "Power", NumberInput::new(Some(2.)), true);
+
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub fn log_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = number_widget(document_node, node_id, 1, "Base", NumberInput::default(), true);
+
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub fn max_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = number_widget(document_node, node_id, 1, "Maximum", NumberInput::default(), true);
+
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub fn min_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = number_widget(document_node, node_id, 1, "Minimum", NumberInput::default(), true);
+
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub fn eq_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = number_widget(document_node, node_id, 1, "Equals", NumberInput::default(), true);
+
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub fn modulo_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = number_widget(document_node, node_id, 1, "Modulo", NumberInput::default(), true);
+
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub fn circle_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	vec![LayoutGroup::Row {
+		widgets: number_widget(document_node, node_id, 1, "Radius", NumberInput::default(), true),
+	}]
+}
+
+pub fn ellipse_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let operand = |name: &str, index| {
+		let widgets = number_widget(document_node, node_id, index, name, NumberInput::default(), true);
+
+		LayoutGroup::Row { widgets }
+	};
+	vec![operand("Radius X", 1), operand("Radius Y", 2)]
+}
+
+pub fn rectangle_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let operand = |name: &str, index| {
+		let widgets = number_widget(document_node, node_id, index, name, NumberInput::default(), true);
+
+		LayoutGroup::Row { widgets }
+	};
+	vec![operand("Size X", 1), operand("Size Y", 2)]
+}
+
+pub fn regular_polygon_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let points = number_widget(document_node, node_id, 1, "Points", NumberInput::default().min(3.), true);
+	let radius = number_widget(document_node, node_id, 2, "Radius", NumberInput::default(), true);
+
+	vec![LayoutGroup::Row { widgets: points }, LayoutGroup::Row { widgets: radius }]
+}
+
+pub fn star_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let points = number_widget(document_node, node_id, 1, "Points", NumberInput::default().min(2.), true);
+	let radius = number_widget(document_node, node_id, 2, "Radius", NumberInput::default(), true);
+	let inner_radius = number_widget(document_node, node_id, 3, "Inner Radius", NumberInput::default(), true);
+
+	vec![LayoutGroup::Row { widgets: points }, LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: inner_radius }]
+}
+
+pub fn line_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let operand = |name: &str, index| vec2_widget(document_node, node_id, index, name, "X", "Y", "px", None, add_blank_assist);
+	vec![operand("Start", 1), operand("End", 2)]
+}
+pub fn grid_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let grid_type = grid_type_widget(document_node, node_id, 1, "Grid Type", true);
+	let spacing = number_widget(document_node, node_id, 2, "Spacing", NumberInput::default().unit(" px").min(0.), true);
+	let columns = number_widget(document_node, node_id, 3, "Columns", NumberInput::default().int().min(1.), true);
+	let rows = number_widget(document_node, node_id, 4, "Rows", NumberInput::default().int().min(1.), true);
+	let points = bool_widget(document_node, node_id, 5, "Points", true);
+
+	vec![
+		grid_type,
+		LayoutGroup::Row { widgets: spacing }.with_tooltip("Distance between the centers of neighboring cells in a row, or the width of each ring of a radial grid"),
+		LayoutGroup::Row { widgets: columns }.with_tooltip("Number of cells in each row, or of slices in each ring of a radial grid"),
+		LayoutGroup::Row { widgets: rows }.with_tooltip("Number of rows, or of rings in a radial grid"),
+		LayoutGroup::Row { widgets: points }.with_tooltip("Give the center of each cell as a point, rather than the outline of each cell"),
+	]
+}
+
+pub fn spline_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	vec![LayoutGroup::Row {
+		widgets: vec_dvec2_input(document_node, node_id, 1, "Points", TextInput::default().centered(true), true),
+	}]
+}
+
+pub fn logic_operator_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widgets = bool_widget(document_node, node_id, 0, "Operand B", true);
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub fn transform_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let translation_assist = |widgets: &mut Vec<WidgetHolder>| {
+		let pivot_index = 5;
+		if let NodeInput::Value {
+			tagged_value: TaggedValue::DVec2(pivot),
+			exposed: false,
+		} = document_node.inputs[pivot_index]
+		{
+			widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+			widgets.push(
+				PivotInput::new(pivot.into())
+					.on_update(update_value(|pivot: &PivotInput| TaggedValue::DVec2(Into::<Option<DVec2>>::into(pivot.position).unwrap()), node_id, 5))
+					.on_commit(commit_value)
+					.widget_holder(),
+			);
+		} else {
+			add_blank_assist(widgets);
+		}
+	};
+	let translation = vec2_widget(document_node, node_id, 1, "Translation", "X", "Y", " px", None, translation_assist);
+
+	let rotation = {
+		let index = 2;
+
+		let mut widgets = start_widgets(document_node, node_id, index, "Rotation", FrontendGraphDataType::Number, true);
+
+		if let NodeInput::Value {
+			tagged_value: TaggedValue::F64(val),
+			exposed: false,
+		} = document_node.inputs[index]
+		{
+			widgets.extend_from_slice(&[
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				NumberInput::new(Some(val.to_degrees()))
+					.unit("°")
+					.mode(NumberInputMode::Range)
+					.range_min(Some(-180.))
+					.range_max(Some(180.))
+					.on_update(update_value(|number_input: &NumberInput| TaggedValue::F64(number_input.value.unwrap().to_radians()), node_id, index))
+					.on_commit(commit_value)
+					.widget_holder(),
+			]);
+		}
+
+		LayoutGroup::Row { widgets }
+	};
+
+	let scale = vec2_widget(document_node, node_id, 3, "Scale", "W", "H", "x", None, add_blank_assist);
+
+	vec![translation, rotation, scale]
+}
+
+pub fn node_section_font(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let text = text_area_widget(document_node, node_id, 1, "Text", true);
+	let (font, style) = font_inputs(document_node, node_id, 2, "Font", true);
+	let size = number_widget(document_node, node_id, 3, "Size", NumberInput::default().unit(" px").min(1.), true);
+	let (fallback_font, fallback_style) = optional_font_inputs(document_node, node_id, 4, "Fallback Font", true);
+	let (second_fallback_font, second_fallback_style) = optional_font_inputs(document_node, node_id, 5, "Second Fallback Font", true);
+	let missing_glyph = missing_glyph_widget(document_node, node_id, 6, "Missing Glyph", true);
+	let ligatures = bool_widget(document_node, node_id, 7, "Ligatures", true);
+	let small_caps = bool_widget(document_node, node_id, 8, "Small Caps", true);
+	let tabular_numbers = bool_widget(document_node, node_id, 9, "Tabular Numbers", true);
+	let stylistic_set = number_widget(document_node, node_id, 10, "Stylistic Set", NumberInput::default().min(0.).max(20.).is_integer(true), true);
+	let line_width = number_widget(document_node, node_id, 11, "Line Width", NumberInput::default().unit(" px").min(0.), true);
+	let align = text_align_widget(document_node, node_id, 12, "Align", true);
+	let hyphenation = hyphenation_widget(document_node, node_id, 13, "Hyphenation", true);
+	let max_word_spacing = number_widget(document_node, node_id, 14, "Max Word Spacing", NumberInput::default().unit("%").min(0.), true);
+	let max_letter_spacing = number_widget(document_node, node_id, 15, "Max Letter Spacing", NumberInput::default().unit("%").min(0.), true);
+	let frame_padding = number_widget(document_node, node_id, 18, "Frame Padding", NumberInput::default().unit(" px").min(0.), true);
+	let exclusion_margin = number_widget(document_node, node_id, 19, "Exclusion Margin", NumberInput::default().unit(" px").min(0.), true);
+	let drop_cap_lines = number_widget(document_node, node_id, 20, "Drop Cap Lines", NumberInput::default().min(0.).is_integer(true), true);
+
+	let mut result = vec![LayoutGroup::Row { widgets: text }, LayoutGroup::Row { widgets: font }];
+	if let Some(style) = style {
+		result.push(LayoutGroup::Row { widgets: style });
+	}
+	result.push(LayoutGroup::Row { widgets: size });
+	result.push(LayoutGroup::Row { widgets: fallback_font }.with_tooltip("Font to draw any characters missing from the main font with, if any"));
+	if let Some(style) = fallback_style {
+		result.push(LayoutGroup::Row { widgets: style });
+	}
+	result.push(LayoutGroup::Row { widgets: second_fallback_font }.with_tooltip("Font to draw any characters missing from both the main and fallback fonts with, if any"));
+	if let Some(style) = second_fallback_style {
+		result.push(LayoutGroup::Row { widgets: style });
+	}
+	result.push(missing_glyph);
+	result.push(LayoutGroup::Row { widgets: ligatures }.with_tooltip("Join letter pairs such as \"fi\" into single glyphs, if the font has ligatures for them"));
+	result.push(LayoutGroup::Row { widgets: small_caps }.with_tooltip("Draw lowercase letters as small capitals, if the font has them"));
+	result.push(LayoutGroup::Row { widgets: tabular_numbers }.with_tooltip("Give every digit the same width so numbers line up in columns, if the font supports it"));
+	result.push(LayoutGroup::Row { widgets: stylistic_set }.with_tooltip("Alternate glyphs from one of the font's stylistic sets (1 to 20), or 0 for the regular glyphs"));
+	result.push(LayoutGroup::Row { widgets: line_width }.with_tooltip("Width that lines wrap at, or 0 to only start new lines at line breaks"));
+	result.push(align);
+	result.push(hyphenation);
+	result.push(LayoutGroup::Row { widgets: max_word_spacing }.with_tooltip("Most extra space that justifying may add between words, as a percentage of the width of a space"));
+	result.push(
+		LayoutGroup::Row { widgets: max_letter_spacing }
+			.with_tooltip("Most extra space that justifying may add between letters once the gaps between words are at their limit, as a percentage of the font size"),
+	);
+	result.push(LayoutGroup::Row { widgets: frame_padding }.with_tooltip("Distance kept between the text and the edges of the frame shape wired into the node"));
+	result.push(LayoutGroup::Row { widgets: exclusion_margin }.with_tooltip("Distance kept between the text and the edges of the exclusion shapes wired into the node that it wraps around"));
+	result.push(LayoutGroup::Row { widgets: drop_cap_lines }.with_tooltip("How many lines the first character is enlarged to drop through, or 0 for no drop cap"));
+	result
+}
+
+pub fn imaginate_properties(document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let imaginate_node = [context.nested_path, &[node_id]].concat();
+
+	let resolve_input = |name: &str| IMAGINATE_NODE.inputs.iter().position(|input| input.name == name).unwrap_or_else(|| panic!("Input {name} not found"));
+	let seed_index = resolve_input("Seed");
+	let resolution_index = resolve_input("Resolution");
+	let samples_index = resolve_input("Samples");
+	let sampling_method_index = resolve_input("Sampling Method");
+	let text_guidance_index = resolve_input("Prompt Guidance");
+	let text_index = resolve_input("Prompt");
+	let neg_index = resolve_input("Negative Prompt");
+	let base_img_index = resolve_input("Adapt Input Image");
+	let img_creativity_index = resolve_input("Image Creativity");
+	// let mask_index = resolve_input("Masking Layer");
+	// let inpaint_index = resolve_input("Inpaint");
+	// let mask_blur_index = resolve_input("Mask Blur");
+	// let mask_fill_index = resolve_input("Mask Starting Fill");
+	let faces_index = resolve_input("Improve Faces");
+	let tiling_index = resolve_input("Tiling");
+
+	let controller = &document_node.inputs[resolve_input("Controller")];
+
+	let server_status = {
+		let server_status = context.persistent_data.imaginate.server_status();
+		let status_text = server_status.to_text();
+		let mut widgets = vec![
+			TextLabel::new("Server").widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			IconButton::new("Settings", 24)
+				.tooltip("Preferences: Imaginate")
+				.on_update(|_| DialogMessage::RequestPreferencesDialog.into())
+				.widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			TextLabel::new(status_text).bold(true).widget_holder(),
+			Separator::new(SeparatorType::Related).widget_holder(),
+			IconButton::new("Reload", 24)
+				.tooltip("Refresh connection status")
+				.on_update(|_| PortfolioMessage::ImaginateCheckServerStatus.into())
+				.widget_holder(),
+		];
+		if let ImaginateServerStatus::Unavailable | ImaginateServerStatus::Failed(_) = server_status {
+			widgets.extend([
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				TextButton::new("Server Help")
+					.tooltip("Learn how to connect Imaginate to an image generation server")
+					.on_update(|_| {
+						FrontendMessage::TriggerVisitLink {
+							url: "https://github.com/GraphiteEditor/Graphite/discussions/1089".to_string(),
+						}
+						.into()
+					})
+					.widget_holder(),
+			]);
+		}
+		LayoutGroup::Row { widgets }.with_tooltip("Connection status to the server that computes generated images")
+	};
+
+	let &NodeInput::Value {
+		tagged_value: TaggedValue::ImaginateController(ref controller),
+		..
+	} = controller
+	else {
+		panic!("Invalid output status input")
+	};
+	let imaginate_status = controller.get_status();
+
+	let use_base_image = if let &NodeInput::Value {
+		tagged_value: TaggedValue::Bool(use_base_image),
+		..
+	} = &document_node.inputs[base_img_index]
+	{
+		use_base_image
+	} else {
+		true
+	};
+
+	let transform_not_connected = false;
+
+	let progress = {
+		let mut widgets = vec![TextLabel::new("Progress").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
+		add_blank_assist(&mut widgets);
+		let status = imaginate_status.to_text();
+		widgets.push(TextLabel::new(status.as_ref()).bold(true).widget_holder());
+		LayoutGroup::Row { widgets }.with_tooltip(match imaginate_status {
+			ImaginateStatus::Failed(_) => status.as_ref(),
+			_ => "When generating, the percentage represents how many sampling steps have so far been processed out of the target number",
+		})
+	};
+
+	let image_controls = {
+		let mut widgets = vec![TextLabel::new("Image").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
+
+		match &imaginate_status {
+			ImaginateStatus::Beginning | ImaginateStatus::Uploading => {
+				add_blank_assist(&mut widgets);
+				widgets.push(TextButton::new("Beginning...").tooltip("Sending image generation request to the server").disabled(true).widget_holder());
+			}
+			ImaginateStatus::Generating(_) => {
+				add_blank_assist(&mut widgets);
+				widgets.push(
+					TextButton::new("Terminate")
+						.tooltip("Cancel the in-progress image generation and keep the latest progress")
+						.on_update({
+							let controller = controller.clone();
+							move |_| {
+								controller.request_termination();
+								Message::NoOp
+							}
+						})
+						.widget_holder(),
+				);
+			}
+			ImaginateStatus::Terminating => {
+				add_blank_assist(&mut widgets);
+				widgets.push(
+					TextButton::new("Terminating...")
+						.tooltip("Waiting on the final image generated after termination")
 						.disabled(true)
 						.widget_holder(),
 				);
@@ -1744,477 +2554,1119 @@ pub fn imaginate_properties(document_node: &DocumentNode, node_id: NodeId, conte
 					})
 					.widget_holder(),
 				Separator::new(SeparatorType::Unrelated).widget_holder(),
-				TextButton::new("Generate")
-					.tooltip("Fill layer frame by generating a new image")
-					.on_update({
-						let controller = controller.clone();
-						move |_| {
-							controller.trigger_regenerate();
-							DocumentMessage::ImaginateGenerate.into()
-						}
-					})
-					.widget_holder(),
-				Separator::new(SeparatorType::Related).widget_holder(),
-				TextButton::new("Clear")
-					.tooltip("Remove generated image from the layer frame")
-					.disabled(!matches!(imaginate_status, ImaginateStatus::ReadyDone))
-					.on_update({
-						let controller = controller.clone();
-						move |_| {
-							controller.set_status(ImaginateStatus::Ready);
-							DocumentMessage::ImaginateGenerate.into()
-						}
-					})
-					.widget_holder(),
-			]),
+				TextButton::new("Generate")
+					.tooltip("Fill layer frame by generating a new image")
+					.on_update({
+						let controller = controller.clone();
+						move |_| {
+							controller.trigger_regenerate();
+							DocumentMessage::ImaginateGenerate.into()
+						}
+					})
+					.widget_holder(),
+				Separator::new(SeparatorType::Related).widget_holder(),
+				TextButton::new("Clear")
+					.tooltip("Remove generated image from the layer frame")
+					.disabled(!matches!(imaginate_status, ImaginateStatus::ReadyDone))
+					.on_update({
+						let controller = controller.clone();
+						move |_| {
+							controller.set_status(ImaginateStatus::Ready);
+							DocumentMessage::ImaginateGenerate.into()
+						}
+					})
+					.widget_holder(),
+			]),
+		}
+		LayoutGroup::Row { widgets }.with_tooltip("Buttons that control the image generation process")
+	};
+
+	// Requires custom layout for the regenerate button
+	let seed = {
+		let mut widgets = start_widgets(document_node, node_id, seed_index, "Seed", FrontendGraphDataType::Number, false);
+
+		if let &NodeInput::Value {
+			tagged_value: TaggedValue::F64(seed),
+			exposed: false,
+		} = &document_node.inputs[seed_index]
+		{
+			widgets.extend_from_slice(&[
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				IconButton::new("Regenerate", 24)
+					.tooltip("Set a new random seed")
+					.on_update({
+						let imaginate_node = imaginate_node.clone();
+						move |_| {
+							DocumentMessage::ImaginateRandom {
+								imaginate_node: imaginate_node.clone(),
+								then_generate: false,
+							}
+							.into()
+						}
+					})
+					.widget_holder(),
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				NumberInput::new(Some(seed))
+					.int()
+					.min(-((1_u64 << f64::MANTISSA_DIGITS) as f64))
+					.max((1_u64 << f64::MANTISSA_DIGITS) as f64)
+					.on_update(update_value(move |input: &NumberInput| TaggedValue::F64(input.value.unwrap()), node_id, seed_index))
+					.on_commit(commit_value)
+					.mode(NumberInputMode::Increment)
+					.widget_holder(),
+			])
+		}
+		// Note: Limited by f64. You cannot even have all the possible u64 values :)
+		LayoutGroup::Row { widgets }.with_tooltip("Seed determines the random outcome, enabling limitless unique variations")
+	};
+
+	// let transform = context
+	// 	.executor
+	// 	.introspect_node_in_network(context.network, &imaginate_node, |network| network.inputs.first().copied(), |frame: &ImageFrame<Color>| frame.transform)
+	// 	.unwrap_or_default();
+	let image_size = context
+		.executor
+		.introspect_node_in_network(
+			context.network,
+			&imaginate_node,
+			|network| network.imports.first().copied(),
+			|frame: &IORecord<(), ImageFrame<Color>>| (frame.output.image.width, frame.output.image.height),
+		)
+		.unwrap_or_default();
+
+	let resolution = {
+		use graphene_std::imaginate::pick_safe_imaginate_resolution;
+
+		let mut widgets = start_widgets(document_node, node_id, resolution_index, "Resolution", FrontendGraphDataType::Vector, false);
+
+		let round = |x: DVec2| {
+			let (x, y) = pick_safe_imaginate_resolution(x.into());
+			DVec2::new(x as f64, y as f64)
+		};
+
+		if let &NodeInput::Value {
+			tagged_value: TaggedValue::OptionalDVec2(vec2),
+			exposed: false,
+		} = &document_node.inputs[resolution_index]
+		{
+			let dimensions_is_auto = vec2.is_none();
+			let vec2 = vec2.unwrap_or_else(|| round((image_size.0 as f64, image_size.1 as f64).into()));
+
+			widgets.extend_from_slice(&[
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				IconButton::new("Rescale", 24)
+					.tooltip("Set the layer dimensions to this resolution")
+					.on_update(move |_| DialogMessage::RequestComingSoonDialog { issue: None }.into())
+					.widget_holder(),
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				CheckboxInput::new(!dimensions_is_auto || transform_not_connected)
+					.icon("Edit12px")
+					.tooltip({
+						let message = "Set a custom resolution instead of using the input's dimensions (rounded to the nearest 64)";
+						let manual_message = "Set a custom resolution instead of using the input's dimensions (rounded to the nearest 64).\n\
+							\n\
+							(Resolution must be set manually while the 'Transform' input is disconnected.)";
+
+						if transform_not_connected {
+							manual_message
+						} else {
+							message
+						}
+					})
+					.disabled(transform_not_connected)
+					.on_update(update_value(
+						move |checkbox_input: &CheckboxInput| TaggedValue::OptionalDVec2(if checkbox_input.checked { Some(vec2) } else { None }),
+						node_id,
+						resolution_index,
+					))
+					.on_commit(commit_value)
+					.widget_holder(),
+				Separator::new(SeparatorType::Related).widget_holder(),
+				NumberInput::new(Some(vec2.x))
+					.label("W")
+					.min(64.)
+					.step(64.)
+					.unit(" px")
+					.disabled(dimensions_is_auto && !transform_not_connected)
+					.on_update(update_value(
+						move |number_input: &NumberInput| TaggedValue::OptionalDVec2(Some(round(DVec2::new(number_input.value.unwrap(), vec2.y)))),
+						node_id,
+						resolution_index,
+					))
+					.on_commit(commit_value)
+					.widget_holder(),
+				Separator::new(SeparatorType::Related).widget_holder(),
+				NumberInput::new(Some(vec2.y))
+					.label("H")
+					.min(64.)
+					.step(64.)
+					.unit(" px")
+					.disabled(dimensions_is_auto && !transform_not_connected)
+					.on_update(update_value(
+						move |number_input: &NumberInput| TaggedValue::OptionalDVec2(Some(round(DVec2::new(vec2.x, number_input.value.unwrap())))),
+						node_id,
+						resolution_index,
+					))
+					.on_commit(commit_value)
+					.widget_holder(),
+			])
+		}
+		LayoutGroup::Row { widgets }.with_tooltip(
+			"Width and height of the image that will be generated. Larger resolutions take longer to compute.\n\
+			\n\
+			512x512 yields optimal results because the AI is trained to understand that scale best. Larger sizes may tend to integrate the prompt's subject more than once. Small sizes are often incoherent.\n\
+			\n\
+			Dimensions must be a multiple of 64, so these are set by rounding the layer dimensions. A resolution exceeding 1 megapixel is reduced below that limit because larger sizes may exceed available GPU memory on the server.")
+	};
+
+	let sampling_steps = {
+		let widgets = number_widget(document_node, node_id, samples_index, "Sampling Steps", NumberInput::default().min(0.).max(150.).int(), true);
+		LayoutGroup::Row { widgets }.with_tooltip("Number of iterations to improve the image generation quality, with diminishing returns around 40 when using the Euler A sampling method")
+	};
+
+	let sampling_method = {
+		let mut widgets = start_widgets(document_node, node_id, sampling_method_index, "Sampling Method", FrontendGraphDataType::General, true);
+
+		if let &NodeInput::Value {
+			tagged_value: TaggedValue::ImaginateSamplingMethod(sampling_method),
+			exposed: false,
+		} = &document_node.inputs[sampling_method_index]
+		{
+			let sampling_methods = ImaginateSamplingMethod::list();
+			let mut entries = Vec::with_capacity(sampling_methods.len());
+			for method in sampling_methods {
+				entries.push(
+					MenuListEntry::new(format!("{method:?}"))
+						.label(method.to_string())
+						.on_update(update_value(move |_| TaggedValue::ImaginateSamplingMethod(method), node_id, sampling_method_index))
+						.on_commit(commit_value),
+				);
+			}
+			let entries = vec![entries];
+
+			widgets.extend_from_slice(&[
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				DropdownInput::new(entries).selected_index(Some(sampling_method as u32)).widget_holder(),
+			]);
 		}
-		LayoutGroup::Row { widgets }.with_tooltip("Buttons that control the image generation process")
+		LayoutGroup::Row { widgets }.with_tooltip("Algorithm used to generate the image during each sampling step")
+	};
+
+	let text_guidance = {
+		let widgets = number_widget(document_node, node_id, text_guidance_index, "Prompt Guidance", NumberInput::default().min(0.).max(30.), true);
+		LayoutGroup::Row { widgets }.with_tooltip(
+			"Amplification of the text prompt's influence over the outcome. At 0, the prompt is entirely ignored.\n\
+			\n\
+			Lower values are more creative and exploratory. Higher values are more literal and uninspired.\n\
+			\n\
+			This parameter is otherwise known as CFG (classifier-free guidance).",
+		)
+	};
+
+	let text_prompt = {
+		let widgets = text_area_widget(document_node, node_id, text_index, "Prompt", true);
+		LayoutGroup::Row { widgets }.with_tooltip(
+			"Description of the desired image subject and style.\n\
+			\n\
+			Include an artist name like \"Rembrandt\" or art medium like \"watercolor\" or \"photography\" to influence the look. List multiple to meld styles.\n\
+			\n\
+			To boost (or lessen) the importance of a word or phrase, wrap it in parentheses ending with a colon and a multiplier, for example:\n\
+			\"Colorless green ideas (sleep:1.3) furiously\"",
+		)
+	};
+	let negative_prompt = {
+		let widgets = text_area_widget(document_node, node_id, neg_index, "Negative Prompt", true);
+		LayoutGroup::Row { widgets }.with_tooltip("A negative text prompt can be used to list things like objects or colors to avoid")
+	};
+	let base_image = {
+		let widgets = bool_widget(document_node, node_id, base_img_index, "Adapt Input Image", true);
+		LayoutGroup::Row { widgets }.with_tooltip("Generate an image based upon the bitmap data plugged into this node")
+	};
+	let image_creativity = {
+		let props = NumberInput::default().percentage().disabled(!use_base_image);
+		let widgets = number_widget(document_node, node_id, img_creativity_index, "Image Creativity", props, true);
+		LayoutGroup::Row { widgets }.with_tooltip(
+			"Strength of the artistic liberties allowing changes from the input image. The image is unchanged at 0% and completely different at 100%.\n\
+			\n\
+			This parameter is otherwise known as denoising strength.",
+		)
+	};
+
+	let mut layout = vec![
+		server_status,
+		progress,
+		image_controls,
+		seed,
+		resolution,
+		sampling_steps,
+		sampling_method,
+		text_guidance,
+		text_prompt,
+		negative_prompt,
+		base_image,
+		image_creativity,
+		// layer_mask,
+	];
+
+	// if use_base_image && layer_reference_input_layer_is_some {
+	// 	let in_paint = {
+	// 		let mut widgets = start_widgets(document_node, node_id, inpaint_index, "Inpaint", FrontendGraphDataType::Boolean, true);
+
+	// 		if let &NodeInput::Value {
+	// 			tagged_value: TaggedValue::Bool(in_paint),
+	// 			exposed: false,
+	// 		} = &document_node.inputs[inpaint_index]
+	// 		{
+	// 			widgets.extend_from_slice(&[
+	// 				Separator::new(SeparatorType::Unrelated).widget_holder(),
+	// 				RadioInput::new(
+	// 					[(true, "Inpaint"), (false, "Outpaint")]
+	// 						.into_iter()
+	// 						.map(|(paint, name)| RadioEntryData::new(name).label(name).on_update(update_value(move |_| TaggedValue::Bool(paint), node_id, inpaint_index)))
+	// 						.collect(),
+	// 				)
+	// 				.selected_index(Some(1 - in_paint as u32))
+	// 				.widget_holder(),
+	// 			]);
+	// 		}
+	// 		LayoutGroup::Row { widgets }.with_tooltip(
+	// 			"Constrain image generation to the interior (inpaint) or exterior (outpaint) of the mask, while referencing the other unchanged parts as context imagery.\n\
+	// 			\n\
+	// 			An unwanted part of an image can be replaced by drawing around it with a black shape and inpainting with that mask layer.\n\
+	// 			\n\
+	// 			An image can be uncropped by resizing the Imaginate layer to the target bounds and outpainting with a black rectangle mask matching the original image bounds.",
+	// 		)
+	// 	};
+
+	// 	let blur_radius = {
+	// 		let number_props = NumberInput::default().unit(" px").min(0.).max(25.).int();
+	// 		let widgets = number_widget(document_node, node_id, mask_blur_index, "Mask Blur", number_props, true);
+	// 		LayoutGroup::Row { widgets }.with_tooltip("Blur radius for the mask. Useful for softening sharp edges to blend the masked area with the rest of the image.")
+	// 	};
+
+	// 	let mask_starting_fill = {
+	// 		let mut widgets = start_widgets(document_node, node_id, mask_fill_index, "Mask Starting Fill", FrontendGraphDataType::General, true);
+
+	// 		if let &NodeInput::Value {
+	// 			tagged_value: TaggedValue::ImaginateMaskStartingFill(starting_fill),
+	// 			exposed: false,
+	// 		} = &document_node.inputs[mask_fill_index]
+	// 		{
+	// 			let mask_fill_content_modes = ImaginateMaskStartingFill::list();
+	// 			let mut entries = Vec::with_capacity(mask_fill_content_modes.len());
+	// 			for mode in mask_fill_content_modes {
+	// 				entries.push(MenuListEntry::new(format!("{mode:?}")).label(mode.to_string()).on_update(update_value(move |_| TaggedValue::ImaginateMaskStartingFill(mode), node_id, mask_fill_index)));
+	// 			}
+	// 			let entries = vec![entries];
+
+	// 			widgets.extend_from_slice(&[
+	// 				Separator::new(SeparatorType::Unrelated).widget_holder(),
+	// 				DropdownInput::new(entries).selected_index(Some(starting_fill as u32)).widget_holder(),
+	// 			]);
+	// 		}
+	// 		LayoutGroup::Row { widgets }.with_tooltip(
+	// 			"Begin in/outpainting the masked areas using this fill content as the starting input image.\n\
+	// 			\n\
+	// 			Each option can be visualized by generating with 'Sampling Steps' set to 0.",
+	// 		)
+	// 	};
+	// 	layout.extend_from_slice(&[in_paint, blur_radius, mask_starting_fill]);
+	// }
+
+	let improve_faces = {
+		let widgets = bool_widget(document_node, node_id, faces_index, "Improve Faces", true);
+		LayoutGroup::Row { widgets }.with_tooltip(
+			"Postprocess human (or human-like) faces to look subtly less distorted.\n\
+			\n\
+			This filter can be used on its own by enabling 'Adapt Input Image' and setting 'Sampling Steps' to 0.",
+		)
+	};
+	let tiling = {
+		let widgets = bool_widget(document_node, node_id, tiling_index, "Tiling", true);
+		LayoutGroup::Row { widgets }.with_tooltip("Generate the image so its edges loop seamlessly to make repeatable patterns or textures")
+	};
+	layout.extend_from_slice(&[improve_faces, tiling]);
+
+	layout
+}
+
+fn unknown_node_properties(document_node: &DocumentNode) -> Vec<LayoutGroup> {
+	string_properties(format!("Node '{}' cannot be found in library", document_node.name))
+}
+
+pub fn node_no_properties(_document_node: &DocumentNode, _node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	string_properties("Node has no properties")
+}
+
+pub fn layer_no_properties(_document_node: &DocumentNode, _node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	string_properties("Layer has no properties")
+}
+
+pub fn index_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let index = number_widget(document_node, node_id, 1, "Index", NumberInput::default().min(0.), true);
+
+	vec![LayoutGroup::Row { widgets: index }]
+}
+
+pub fn generate_node_properties(document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> LayoutGroup {
+	let name = document_node.name.clone();
+	let layout = match super::document_node_types::resolve_document_node_type(&name) {
+		Some(document_node_type) => (document_node_type.properties)(document_node, node_id, context),
+		None => unknown_node_properties(document_node),
+	};
+	LayoutGroup::Section { name, layout }
+}
+
+pub fn stroke_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let color_index = 1;
+	let weight_index = 2;
+	let dash_lengths_index = 3;
+	let dash_offset_index = 4;
+	let line_cap_index = 5;
+	let line_join_index = 6;
+	let miter_limit_index = 7;
+	let alignment_index = 8;
+	let paint_type_index = 9;
+	let gradient_type_index = 10;
+	let start_index = 11;
+	let positions_index = 14;
+	let focal_point_index = 15;
+	let blend_mode_index = 16;
+	let hairline_index = 18;
+
+	let paint_type = match document_node.inputs.get(paint_type_index).and_then(NodeInput::as_value) {
+		Some(&TaggedValue::FillType(paint_type)) => Some(paint_type),
+		_ => None,
 	};
+	// Strokes from documents made before the paint type was added are solid
+	let gradient = paint_type == Some(graphene_core::vector::style::FillType::Gradient);
+
+	let mut widgets = Vec::new();
+	if paint_type.is_some() {
+		widgets.push(fill_type_widget(document_node, node_id, paint_type_index, "Paint Type"));
+	}
+
+	if !gradient {
+		widgets.push(color_widget(document_node, node_id, color_index, "Color", ColorButton::default(), true));
+	} else {
+		let gradient_type = match document_node.inputs[gradient_type_index].as_value() {
+			Some(&TaggedValue::GradientType(gradient_type)) => Some(gradient_type),
+			_ => None,
+		};
+		widgets.push(gradient_type_widget(document_node, node_id, gradient_type_index));
+		let conic = gradient_type == Some(GradientType::Conic);
+		gradient_positions(&mut widgets, document_node, "Gradient Positions", node_id, positions_index, conic);
+
+		if gradient_type == Some(GradientType::Radial) {
+			let center = match document_node.inputs[start_index].as_value() {
+				Some(&TaggedValue::DVec2(center)) => center,
+				_ => DVec2::splat(0.5),
+			};
+			let focal_point = focal_point_widget(document_node, node_id, focal_point_index, center);
+			widgets.push(focal_point.with_tooltip("Where the first color of the radial gradient begins, relative to the bounds of the shape, instead of at its center"));
+		}
+	}
+
+	let weight = number_widget(document_node, node_id, weight_index, "Weight", NumberInput::default().unit("px").min(0.), true);
+	let dash_lengths = vec_f64_input(document_node, node_id, dash_lengths_index, "Dash Lengths", TextInput::default().centered(true), true);
+	let dash_offset = number_widget(document_node, node_id, dash_offset_index, "Dash Offset", NumberInput::default().unit("px").min(0.), true);
+	let line_cap = line_cap_widget(document_node, node_id, line_cap_index, "Line Cap", true);
+	let line_join = line_join_widget(document_node, node_id, line_join_index, "Line Join", true);
+	let miter_limit = number_widget(document_node, node_id, miter_limit_index, "Miter Limit", NumberInput::default().min(0.), true);
+	let alignment = stroke_alignment_widget(document_node, node_id, alignment_index, "Alignment", true);
+
+	widgets.extend([
+		LayoutGroup::Row { widgets: weight },
+		LayoutGroup::Row { widgets: dash_lengths },
+		LayoutGroup::Row { widgets: dash_offset },
+		line_cap,
+		line_join,
+		LayoutGroup::Row { widgets: miter_limit },
+		alignment,
+	]);
+	if document_node.inputs.len() > hairline_index {
+		let hairline = bool_widget(document_node, node_id, hairline_index, "Hairline", true);
+		widgets.push(LayoutGroup::Row { widgets: hairline }.with_tooltip("Keep the weight in pixels on screen at any zoom, ignoring the scale of the shape's transform"));
+	}
+	blending_widgets(&mut widgets, document_node, node_id, blend_mode_index);
+	widgets
+}
+
+/// Adds the blend mode and opacity inputs of a style node, which documents made before they were added don't have.
+fn blending_widgets(widgets: &mut Vec<LayoutGroup>, document_node: &DocumentNode, node_id: NodeId, blend_mode_index: usize) {
+	if document_node.inputs.len() <= blend_mode_index + 1 {
+		return;
+	}
+	let blend_mode = blend_mode(document_node, node_id, blend_mode_index, "Blend Mode", true);
+	let opacity = number_widget(
+		document_node,
+		node_id,
+		blend_mode_index + 1,
+		"Opacity",
+		NumberInput::default().mode_range().min(0.).max(100.).unit("%"),
+		true,
+	);
+
+	widgets.push(blend_mode.with_tooltip("How the whole shape is blended onto what's beneath it"));
+	widgets.push(LayoutGroup::Row { widgets: opacity }.with_tooltip("Opacity of the whole shape as a percentage"));
+}
+
+pub fn add_fill_layer_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let color = color_widget(document_node, node_id, 1, "Color", ColorButton::default(), true);
+	let blend_mode = blend_mode(document_node, node_id, 2, "Blend Mode", true);
+	let opacity = number_widget(document_node, node_id, 3, "Opacity", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+	let index = number_widget(document_node, node_id, 4, "Index", NumberInput::default().int(), true);
+
+	vec![
+		color,
+		blend_mode,
+		LayoutGroup::Row { widgets: opacity },
+		LayoutGroup::Row { widgets: index }.with_tooltip("Where the fill goes in the stack of paint, counting from the bottom from 0 or from the top from -1"),
+	]
+}
 
-	// Requires custom layout for the regenerate button
-	let seed = {
-		let mut widgets = start_widgets(document_node, node_id, seed_index, "Seed", FrontendGraphDataType::Number, false);
+pub fn add_stroke_layer_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let color = color_widget(document_node, node_id, 1, "Color", ColorButton::default(), true);
+	let weight = number_widget(document_node, node_id, 2, "Weight", NumberInput::default().unit("px").min(0.), true);
+	let blend_mode = blend_mode(document_node, node_id, 3, "Blend Mode", true);
+	let opacity = number_widget(document_node, node_id, 4, "Opacity", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+	let index = number_widget(document_node, node_id, 5, "Index", NumberInput::default().int(), true);
 
-		if let &NodeInput::Value {
-			tagged_value: TaggedValue::F64(seed),
-			exposed: false,
-		} = &document_node.inputs[seed_index]
-		{
-			widgets.extend_from_slice(&[
-				Separator::new(SeparatorType::Unrelated).widget_holder(),
-				IconButton::new("Regenerate", 24)
-					.tooltip("Set a new random seed")
-					.on_update({
-						let imaginate_node = imaginate_node.clone();
-						move |_| {
-							DocumentMessage::ImaginateRandom {
-								imaginate_node: imaginate_node.clone(),
-								then_generate: false,
-							}
-							.into()
-						}
-					})
-					.widget_holder(),
-				Separator::new(SeparatorType::Unrelated).widget_holder(),
-				NumberInput::new(Some(seed))
-					.int()
-					.min(-((1_u64 << f64::MANTISSA_DIGITS) as f64))
-					.max((1_u64 << f64::MANTISSA_DIGITS) as f64)
-					.on_update(update_value(move |input: &NumberInput| TaggedValue::F64(input.value.unwrap()), node_id, seed_index))
-					.on_commit(commit_value)
-					.mode(NumberInputMode::Increment)
-					.widget_holder(),
-			])
-		}
-		// Note: Limited by f64. You cannot even have all the possible u64 values :)
-		LayoutGroup::Row { widgets }.with_tooltip("Seed determines the random outcome, enabling limitless unique variations")
-	};
+	vec![
+		color,
+		LayoutGroup::Row { widgets: weight },
+		blend_mode,
+		LayoutGroup::Row { widgets: opacity },
+		LayoutGroup::Row { widgets: index }.with_tooltip("Where the stroke goes in the stack of paint, counting from the bottom from 0 or from the top from -1"),
+	]
+}
 
-	// let transform = context
-	// 	.executor
-	// 	.introspect_node_in_network(context.network, &imaginate_node, |network| network.inputs.first().copied(), |frame: &ImageFrame<Color>| frame.transform)
-	// 	.unwrap_or_default();
-	let image_size = context
-		.executor
-		.introspect_node_in_network(
-			context.network,
-			&imaginate_node,
-			|network| network.imports.first().copied(),
-			|frame: &IORecord<(), ImageFrame<Color>>| (frame.output.image.width, frame.output.image.height),
-		)
-		.unwrap_or_default();
+pub fn remove_paint_layer_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let index = number_widget(document_node, node_id, 1, "Index", NumberInput::default().int(), true);
 
-	let resolution = {
-		use graphene_std::imaginate::pick_safe_imaginate_resolution;
+	vec![LayoutGroup::Row { widgets: index }.with_tooltip("Which fill or stroke to remove from the stack of paint, counting from the bottom from 0 or from the top from -1")]
+}
 
-		let mut widgets = start_widgets(document_node, node_id, resolution_index, "Resolution", FrontendGraphDataType::Vector, false);
+pub fn stroke_gradient_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let mut rows = Vec::new();
+	gradient_positions(&mut rows, document_node, "Gradient Positions", node_id, 1, false);
+	rows
+}
 
-		let round = |x: DVec2| {
-			let (x, y) = pick_safe_imaginate_resolution(x.into());
-			DVec2::new(x as f64, y as f64)
-		};
+pub fn stroke_width_profile_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let widths = vec_f64_input(document_node, node_id, 1, "Widths", TextInput::default().centered(true), true);
 
-		if let &NodeInput::Value {
-			tagged_value: TaggedValue::OptionalDVec2(vec2),
-			exposed: false,
-		} = &document_node.inputs[resolution_index]
-		{
-			let dimensions_is_auto = vec2.is_none();
-			let vec2 = vec2.unwrap_or_else(|| round((image_size.0 as f64, image_size.1 as f64).into()));
+	vec![LayoutGroup::Row { widgets: widths }.with_tooltip("Factors of the stroke's weight, spread evenly from the start to the end of each subpath")]
+}
 
-			widgets.extend_from_slice(&[
-				Separator::new(SeparatorType::Unrelated).widget_holder(),
-				IconButton::new("Rescale", 24)
-					.tooltip("Set the layer dimensions to this resolution")
-					.on_update(move |_| DialogMessage::RequestComingSoonDialog { issue: None }.into())
-					.widget_holder(),
-				Separator::new(SeparatorType::Unrelated).widget_holder(),
-				CheckboxInput::new(!dimensions_is_auto || transform_not_connected)
-					.icon("Edit12px")
-					.tooltip({
-						let message = "Set a custom resolution instead of using the input's dimensions (rounded to the nearest 64)";
-						let manual_message = "Set a custom resolution instead of using the input's dimensions (rounded to the nearest 64).\n\
-							\n\
-							(Resolution must be set manually while the 'Transform' input is disconnected.)";
+pub fn stroke_dashes_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let dash_cap = line_cap_widget(document_node, node_id, 1, "Dash Cap", true);
+	let scale_with_weight = bool_widget(document_node, node_id, 2, "Scale With Weight", true);
+	let align_to_corners = bool_widget(document_node, node_id, 3, "Align to Corners", true);
+
+	vec![
+		dash_cap.with_tooltip("The cap drawn on both ends of every dash, in place of the stroke's line cap"),
+		LayoutGroup::Row { widgets: scale_with_weight }.with_tooltip("Treat the dash lengths and offset as multiples of the stroke's weight, so the pattern keeps its proportions"),
+		LayoutGroup::Row { widgets: align_to_corners }.with_tooltip("Stretch the dashes between corners so a dash is centered on every corner and the path's open ends"),
+	]
+}
+
+pub fn fill_stroke_opacity_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let fill_opacity = number_widget(document_node, node_id, 1, "Fill Opacity", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+	let stroke_opacity = number_widget(document_node, node_id, 2, "Stroke Opacity", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+
+	vec![
+		LayoutGroup::Row { widgets: fill_opacity }.with_tooltip("Opacity of the fill alone, on top of the alpha of its colors"),
+		LayoutGroup::Row { widgets: stroke_opacity }.with_tooltip("Opacity of the stroke alone, on top of the alpha of its colors"),
+	]
+}
+
+pub fn define_style_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 1, "Name", true);
+
+	vec![LayoutGroup::Row { widgets: name }.with_tooltip("The name the style is applied by, replacing any style of the library defined with the same name")]
+}
+
+pub fn apply_style_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 2, "Name", true);
+
+	vec![LayoutGroup::Row { widgets: name }.with_tooltip("The name the style was defined with in the library, which leaves the shape unchanged if there isn't one")]
+}
+
+pub fn gradient_interpolation_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let interpolation = gradient_interpolation_widget(document_node, node_id, 1, "Color Space", true);
+	let lch = matches!(document_node.inputs[1].as_value(), Some(&TaggedValue::GradientInterpolation(GradientInterpolation::Lch)));
+
+	let mut widgets = vec![interpolation];
+	if lch {
+		widgets.push(hue_interpolation_widget(document_node, node_id, 2, "Hue", true));
+	}
+	widgets
+}
+
+pub fn color_space_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let color_space = color_space_widget(document_node, node_id, 1, "Color Space", true);
+
+	vec![color_space]
+}
+
+pub fn color_harmony_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let color = color_widget(document_node, node_id, 0, "Color", ColorButton::default().allow_none(false), true);
+	let harmony = color_harmony_widget(document_node, node_id, 1, "Harmony", true);
+	let count = number_widget(document_node, node_id, 2, "Count", NumberInput::default().int().min(1.).max(16.), true);
+	let spread = number_widget(document_node, node_id, 3, "Spread", NumberInput::default().unit("°").min(0.).max(180.), true);
+
+	vec![
+		color,
+		harmony,
+		LayoutGroup::Row { widgets: count }.with_tooltip("How many colors the analogous and monochromatic harmonies generate"),
+		LayoutGroup::Row { widgets: spread }.with_tooltip("Angle between neighboring analogous hues, and between the two hues of a split complement"),
+	]
+}
+
+pub fn palette_color_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let index = number_widget(document_node, node_id, 1, "Index", NumberInput::default().int(), true);
+
+	vec![LayoutGroup::Row { widgets: index }.with_tooltip("Which color to pick, wrapping around past the end of the palette and counting back from the end when negative")]
+}
+
+pub fn pattern_fill_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let spacing = vec2_widget(document_node, node_id, 2, "Spacing", "W", "H", " px", Some(0.), add_blank_assist);
+	let rotation = number_widget(document_node, node_id, 3, "Rotation", NumberInput::default().unit("°"), true);
+	let scale = vec2_widget(document_node, node_id, 4, "Scale", "X", "Y", "x", None, add_blank_assist);
+
+	vec![
+		spacing.with_tooltip("Size of the cell that each copy of the tile is drawn in"),
+		LayoutGroup::Row { widgets: rotation }.with_tooltip("Angle the grid of tiles is turned by within the shape"),
+		scale.with_tooltip("Factor the grid of tiles is scaled by within the shape"),
+	]
+}
+
+pub fn image_fill_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let mode = image_fill_mode_widget(document_node, node_id, 2, "Mode", true);
+	let offset = vec2_widget(document_node, node_id, 3, "Offset", "X", "Y", " px", None, add_blank_assist);
+	let rotation = number_widget(document_node, node_id, 4, "Rotation", NumberInput::default().unit("°"), true);
+	let scale = vec2_widget(document_node, node_id, 5, "Scale", "X", "Y", "x", None, add_blank_assist);
+
+	vec![
+		mode,
+		offset.with_tooltip("Distance the image is moved by within the shape"),
+		LayoutGroup::Row { widgets: rotation }.with_tooltip("Angle the image is turned by within the shape"),
+		scale.with_tooltip("Factor the image is scaled by within the shape"),
+	]
+}
+
+pub fn repeat_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let direction = vec2_widget(document_node, node_id, 1, "Direction", "X", "Y", " px", None, add_blank_assist);
+	let count = number_widget(document_node, node_id, 2, "Count", NumberInput::default().min(1.), true);
+	let rotation = number_widget(document_node, node_id, 3, "Rotation", NumberInput::default().unit("°"), true);
+	let scale = number_widget(document_node, node_id, 4, "Scale", NumberInput::default().unit("x").min(0.), true);
+
+	vec![
+		direction,
+		LayoutGroup::Row { widgets: count },
+		LayoutGroup::Row { widgets: rotation }.with_tooltip("Angle each copy is turned by from the one before it"),
+		LayoutGroup::Row { widgets: scale }.with_tooltip("Factor each copy is scaled by from the one before it"),
+	]
+}
+
+pub fn circular_repeat_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let angle_offset = number_widget(document_node, node_id, 1, "Angle Offset", NumberInput::default().unit("°"), true);
+	let radius = number_widget(document_node, node_id, 2, "Radius", NumberInput::default(), true); // TODO: What units?
+	let count = number_widget(document_node, node_id, 3, "Count", NumberInput::default().min(1.), true);
+	let keep_upright = bool_widget(document_node, node_id, 5, "Keep Upright", true);
+
+	let pivot_index = 4;
+	let mut pivot = start_widgets(document_node, node_id, pivot_index, "Pivot", FrontendGraphDataType::Vector, true);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::OptionalDVec2(vec2),
+		exposed: false,
+	} = &document_node.inputs[pivot_index]
+	{
+		let pivot_is_auto = vec2.is_none();
+		let vec2 = vec2.unwrap_or_default();
+
+		pivot.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			CheckboxInput::new(!pivot_is_auto)
+				.icon("Edit12px")
+				.tooltip("Set a custom pivot instead of using the center of the instance")
+				.on_update(update_value(
+					move |checkbox_input: &CheckboxInput| TaggedValue::OptionalDVec2(if checkbox_input.checked { Some(vec2) } else { None }),
+					node_id,
+					pivot_index,
+				))
+				.on_commit(commit_value)
+				.widget_holder(),
+			Separator::new(SeparatorType::Related).widget_holder(),
+			NumberInput::new(Some(vec2.x))
+				.label("X")
+				.unit(" px")
+				.disabled(pivot_is_auto)
+				.on_update(update_value(
+					move |number_input: &NumberInput| TaggedValue::OptionalDVec2(Some(DVec2::new(number_input.value.unwrap(), vec2.y))),
+					node_id,
+					pivot_index,
+				))
+				.on_commit(commit_value)
+				.widget_holder(),
+			Separator::new(SeparatorType::Related).widget_holder(),
+			NumberInput::new(Some(vec2.y))
+				.label("Y")
+				.unit(" px")
+				.disabled(pivot_is_auto)
+				.on_update(update_value(
+					move |number_input: &NumberInput| TaggedValue::OptionalDVec2(Some(DVec2::new(vec2.x, number_input.value.unwrap()))),
+					node_id,
+					pivot_index,
+				))
+				.on_commit(commit_value)
+				.widget_holder(),
+		]);
+	}
+
+	vec![
+		LayoutGroup::Row { widgets: angle_offset },
+		LayoutGroup::Row { widgets: radius },
+		LayoutGroup::Row { widgets: count },
+		LayoutGroup::Row { widgets: pivot }.with_tooltip("Center of the circle that the copies are arranged around"),
+		LayoutGroup::Row { widgets: keep_upright }.with_tooltip("Move each copy around the circle without rotating it, as with the numbers on a clock face"),
+	]
+}
+
+pub fn sticker_outline_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let distance = number_widget(document_node, node_id, 1, "Distance", NumberInput::default().min(0.).unit(" px"), true);
+	let color = color_widget(document_node, node_id, 2, "Color", ColorButton::default().allow_none(false), true);
+
+	vec![
+		LayoutGroup::Row { widgets: distance }.with_tooltip("How far the border reaches out past the edges of the content"),
+		color,
+	]
+}
+
+pub fn technical_drawing_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let dimension_offset = number_widget(document_node, node_id, 1, "Dimension Offset", NumberInput::default().min(0.).unit(" px"), true);
+	let hatch_spacing = number_widget(document_node, node_id, 2, "Hatch Spacing", NumberInput::default().min(0.).unit(" px"), true);
+	let hatch_angle = number_widget(document_node, node_id, 3, "Hatch Angle", NumberInput::default().min(-180.).max(180.).unit("°"), true);
+	let title = text_widget(document_node, node_id, 4, "Title", true);
+	let color = color_widget(document_node, node_id, 5, "Color", ColorButton::default().allow_none(false), true);
+
+	vec![
+		LayoutGroup::Row { widgets: dimension_offset }.with_tooltip("How far the dimensions of the edges sit out from the shape, with the overall size twice as far"),
+		LayoutGroup::Row { widgets: hatch_spacing }.with_tooltip("Distance between the lines hatching the closed regions of the shape"),
+		LayoutGroup::Row { widgets: hatch_angle }.with_tooltip("Direction of the lines hatching the closed regions of the shape"),
+		LayoutGroup::Row { widgets: title }.with_tooltip("Written in the title block in the bottom right corner of the frame"),
+		color,
+	]
+}
+
+pub fn pattern_tile_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let group = wallpaper_group_widget(document_node, node_id, 1, "Group", true);
+	let size = vec2_widget(document_node, node_id, 2, "Size", "W", "H", " px", Some(0.), add_blank_assist);
+	let columns = number_widget(document_node, node_id, 3, "Columns", NumberInput::default().min(0.).int(), true);
+	let rows = number_widget(document_node, node_id, 4, "Rows", NumberInput::default().min(0.).int(), true);
+
+	vec![
+		group,
+		size.with_tooltip("Width and height of the tile, where groups with quarter turns only use the width and those with third or sixth turns set the height to fit their grid"),
+		LayoutGroup::Row { widgets: columns }.with_tooltip("How many times the tile is repeated across in the preview"),
+		LayoutGroup::Row { widgets: rows }.with_tooltip("How many times the tile is repeated down in the preview"),
+	]
+}
+
+pub fn copy_to_points_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let instance = vector_widget(document_node, node_id, 1, "Instance", true);
+
+	let random_scale_min = number_widget(
+		document_node,
+		node_id,
+		2,
+		"Random Scale Min",
+		NumberInput::default().min(0.).mode_range().range_min(Some(0.)).range_max(Some(2.)).unit("x"),
+		true,
+	);
+	let random_scale_max = number_widget(
+		document_node,
+		node_id,
+		3,
+		"Random Scale Max",
+		NumberInput::default().min(0.).mode_range().range_min(Some(0.)).range_max(Some(2.)).unit("x"),
+		true,
+	);
+	let random_scale_bias = number_widget(
+		document_node,
+		node_id,
+		4,
+		"Random Scale Bias",
+		NumberInput::default().mode_range().range_min(Some(-50.)).range_max(Some(50.)),
+		true,
+	);
+
+	let random_rotation_min = number_widget(
+		document_node,
+		node_id,
+		5,
+		"Random Rotation Min",
+		NumberInput::default().min(-360.).max(360.).mode_range().unit("°"),
+		true,
+	);
+	let random_rotation_max = number_widget(
+		document_node,
+		node_id,
+		6,
+		"Random Rotation Max",
+		NumberInput::default().min(-360.).max(360.).mode_range().unit("°"),
+		true,
+	);
+	let random_seed = number_widget(document_node, node_id, 7, "Random Seed", NumberInput::default().min(0.).is_integer(true), true);
+
+	let align_to_path = bool_widget(document_node, node_id, 8, "Align to Path", true);
+
+	let instance_variants = variant_selection_widget(document_node, node_id, 9, "Instance Variants", true);
+
+	vec![
+		LayoutGroup::Row { widgets: instance }.with_tooltip("Artwork to be copied and placed at each point"),
+		LayoutGroup::Row { widgets: random_scale_min }.with_tooltip("Minimum range of randomized sizes given to each instance"),
+		LayoutGroup::Row { widgets: random_scale_max }.with_tooltip("Maximum range of randomized sizes given to each instance"),
+		LayoutGroup::Row { widgets: random_scale_bias }
+			.with_tooltip("Bias for the probability distribution of randomized sizes (0 is uniform, negatives favor more of small sizes, positives favor more of large sizes)"),
+		LayoutGroup::Row { widgets: random_rotation_min }.with_tooltip("Minimum range of randomized angles given to each instance, in degrees"),
+		LayoutGroup::Row { widgets: random_rotation_max }.with_tooltip("Maximum range of randomized angles given to each instance, in degrees"),
+		LayoutGroup::Row { widgets: random_seed }.with_tooltip("Seed for the randomized sizes, angles, and variants, which stay the same from one render to the next until it changes"),
+		LayoutGroup::Row { widgets: align_to_path }.with_tooltip("Rotate each instance so it faces along the direction of the path at its point"),
+		instance_variants,
+	]
+}
+
+pub fn sample_points_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let spacing = number_widget(document_node, node_id, 1, "Spacing", NumberInput::default().min(1.).unit(" px"), true);
+	let start_offset = number_widget(document_node, node_id, 2, "Start Offset", NumberInput::default().min(0.).unit(" px"), true);
+	let stop_offset = number_widget(document_node, node_id, 3, "Stop Offset", NumberInput::default().min(0.).unit(" px"), true);
+	let adaptive_spacing = bool_widget(document_node, node_id, 4, "Adaptive Spacing", true);
+
+	vec![
+		LayoutGroup::Row { widgets: spacing }.with_tooltip("Distance between each instance (exact if 'Adaptive Spacing' is disabled, approximate if enabled)"),
+		LayoutGroup::Row { widgets: start_offset }.with_tooltip("Exclude some distance from the start of the path before the first instance"),
+		LayoutGroup::Row { widgets: stop_offset }.with_tooltip("Exclude some distance from the end of the path after the last instance"),
+		LayoutGroup::Row { widgets: adaptive_spacing }.with_tooltip("Round 'Spacing' to a nearby value that divides into the path length evenly"),
+	]
+}
+
+pub fn poisson_disk_points_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let spacing = number_widget(
+		document_node,
+		node_id,
+		1,
+		"Separation Disk Diameter",
+		NumberInput::default().min(0.01).mode_range().range_min(Some(1.)).range_max(Some(100.)),
+		true,
+	);
+
+	vec![LayoutGroup::Row { widgets: spacing }]
+}
 
-						if transform_not_connected {
-							manual_message
-						} else {
-							message
-						}
-					})
-					.disabled(transform_not_connected)
-					.on_update(update_value(
-						move |checkbox_input: &CheckboxInput| TaggedValue::OptionalDVec2(if checkbox_input.checked { Some(vec2) } else { None }),
-						node_id,
-						resolution_index,
-					))
-					.on_commit(commit_value)
-					.widget_holder(),
-				Separator::new(SeparatorType::Related).widget_holder(),
-				NumberInput::new(Some(vec2.x))
-					.label("W")
-					.min(64.)
-					.step(64.)
-					.unit(" px")
-					.disabled(dimensions_is_auto && !transform_not_connected)
-					.on_update(update_value(
-						move |number_input: &NumberInput| TaggedValue::OptionalDVec2(Some(round(DVec2::new(number_input.value.unwrap(), vec2.y)))),
-						node_id,
-						resolution_index,
-					))
-					.on_commit(commit_value)
-					.widget_holder(),
-				Separator::new(SeparatorType::Related).widget_holder(),
-				NumberInput::new(Some(vec2.y))
-					.label("H")
-					.min(64.)
-					.step(64.)
-					.unit(" px")
-					.disabled(dimensions_is_auto && !transform_not_connected)
-					.on_update(update_value(
-						move |number_input: &NumberInput| TaggedValue::OptionalDVec2(Some(round(DVec2::new(vec2.x, number_input.value.unwrap())))),
-						node_id,
-						resolution_index,
-					))
-					.on_commit(commit_value)
-					.widget_holder(),
-			])
-		}
-		LayoutGroup::Row { widgets }.with_tooltip(
-			"Width and height of the image that will be generated. Larger resolutions take longer to compute.\n\
-			\n\
-			512x512 yields optimal results because the AI is trained to understand that scale best. Larger sizes may tend to integrate the prompt's subject more than once. Small sizes are often incoherent.\n\
-			\n\
-			Dimensions must be a multiple of 64, so these are set by rounding the layer dimensions. A resolution exceeding 1 megapixel is reduced below that limit because larger sizes may exceed available GPU memory on the server.")
-	};
+pub fn points_in_bounds_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let spacing = vec2_widget(document_node, node_id, 1, "Spacing", "X", "Y", " px", Some(0.01), add_blank_assist);
+	let inset = number_widget(document_node, node_id, 2, "Inset", NumberInput::default().unit(" px"), true);
+	let stagger = bool_widget(document_node, node_id, 3, "Stagger", true);
+	let inside_shape = bool_widget(document_node, node_id, 4, "Inside Shape", true);
 
-	let sampling_steps = {
-		let widgets = number_widget(document_node, node_id, samples_index, "Sampling Steps", NumberInput::default().min(0.).max(150.).int(), true);
-		LayoutGroup::Row { widgets }.with_tooltip("Number of iterations to improve the image generation quality, with diminishing returns around 40 when using the Euler A sampling method")
-	};
+	vec![
+		spacing.with_tooltip("Distance between neighboring points of the lattice"),
+		LayoutGroup::Row { widgets: inset }.with_tooltip("Distance the bounding box is shrunk by on every side before it's filled"),
+		LayoutGroup::Row { widgets: stagger }.with_tooltip("Shift every other row over by half of the spacing"),
+		LayoutGroup::Row { widgets: inside_shape }.with_tooltip("Only keep the points inside the closed shape rather than its whole bounding box"),
+	]
+}
 
-	let sampling_method = {
-		let mut widgets = start_widgets(document_node, node_id, sampling_method_index, "Sampling Method", FrontendGraphDataType::General, true);
+pub fn hatch_fill_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let angle = number_widget(document_node, node_id, 1, "Angle", NumberInput::default().unit("°").min(-180.).max(180.), true);
+	let spacing = number_widget(document_node, node_id, 2, "Spacing", NumberInput::default().unit(" px").min(0.1), true);
+	let cross_hatch = bool_widget(document_node, node_id, 3, "Cross Hatch", true);
 
-		if let &NodeInput::Value {
-			tagged_value: TaggedValue::ImaginateSamplingMethod(sampling_method),
-			exposed: false,
-		} = &document_node.inputs[sampling_method_index]
-		{
-			let sampling_methods = ImaginateSamplingMethod::list();
-			let mut entries = Vec::with_capacity(sampling_methods.len());
-			for method in sampling_methods {
-				entries.push(
-					MenuListEntry::new(format!("{method:?}"))
-						.label(method.to_string())
-						.on_update(update_value(move |_| TaggedValue::ImaginateSamplingMethod(method), node_id, sampling_method_index))
-						.on_commit(commit_value),
-				);
-			}
-			let entries = vec![entries];
+	vec![
+		LayoutGroup::Row { widgets: angle }.with_tooltip("Direction of the hatch lines"),
+		LayoutGroup::Row { widgets: spacing }.with_tooltip("Distance between neighboring hatch lines"),
+		LayoutGroup::Row { widgets: cross_hatch }.with_tooltip("Add a second set of hatch lines perpendicular to the first"),
+	]
+}
 
-			widgets.extend_from_slice(&[
-				Separator::new(SeparatorType::Unrelated).widget_holder(),
-				DropdownInput::new(entries).selected_index(Some(sampling_method as u32)).widget_holder(),
-			]);
-		}
-		LayoutGroup::Row { widgets }.with_tooltip("Algorithm used to generate the image during each sampling step")
-	};
+pub fn hatch_shading_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let light_direction = number_widget(document_node, node_id, 1, "Light Direction", NumberInput::default().unit("°").min(-180.).max(180.), true);
+	let angle = number_widget(document_node, node_id, 2, "Angle", NumberInput::default().unit("°").min(-180.).max(180.), true);
+	let min_spacing = number_widget(document_node, node_id, 3, "Min Spacing", NumberInput::default().unit(" px").min(0.1), true);
+	let max_spacing = number_widget(document_node, node_id, 4, "Max Spacing", NumberInput::default().unit(" px").min(0.1), true);
+	let cross_hatch = number_widget(document_node, node_id, 5, "Cross Hatch", NumberInput::default().mode_range().min(0.).max(1.), true);
+	let jitter = number_widget(document_node, node_id, 6, "Jitter", NumberInput::default().unit("°").min(0.).max(90.), true);
+	let seed = number_widget(document_node, node_id, 7, "Seed", NumberInput::default().int().min(0.), true);
 
-	let text_guidance = {
-		let widgets = number_widget(document_node, node_id, text_guidance_index, "Prompt Guidance", NumberInput::default().min(0.).max(30.), true);
-		LayoutGroup::Row { widgets }.with_tooltip(
-			"Amplification of the text prompt's influence over the outcome. At 0, the prompt is entirely ignored.\n\
-			\n\
-			Lower values are more creative and exploratory. Higher values are more literal and uninspired.\n\
-			\n\
-			This parameter is otherwise known as CFG (classifier-free guidance).",
-		)
-	};
+	vec![
+		LayoutGroup::Row { widgets: light_direction }.with_tooltip("Direction the light shines from, where 0° is from the right"),
+		LayoutGroup::Row { widgets: angle }.with_tooltip("Direction of the hatch lines"),
+		LayoutGroup::Row { widgets: min_spacing }.with_tooltip("Distance between neighboring hatch lines in the darkest shade"),
+		LayoutGroup::Row { widgets: max_spacing }.with_tooltip("Distance between neighboring hatch lines in the lightest shade"),
+		LayoutGroup::Row { widgets: cross_hatch }.with_tooltip("Shade from 0 to 1 beyond which a second set of lines crosses the first, where 1 never cross hatches"),
+		LayoutGroup::Row { widgets: jitter }.with_tooltip("Most that the seed turns each set of hatch lines by"),
+		LayoutGroup::Row { widgets: seed }.with_tooltip("Picks the random turn of each set of hatch lines"),
+	]
+}
 
-	let text_prompt = {
-		let widgets = text_area_widget(document_node, node_id, text_index, "Prompt", true);
-		LayoutGroup::Row { widgets }.with_tooltip(
-			"Description of the desired image subject and style.\n\
-			\n\
-			Include an artist name like \"Rembrandt\" or art medium like \"watercolor\" or \"photography\" to influence the look. List multiple to meld styles.\n\
-			\n\
-			To boost (or lessen) the importance of a word or phrase, wrap it in parentheses ending with a colon and a multiplier, for example:\n\
-			\"Colorless green ideas (sleep:1.3) furiously\"",
-		)
-	};
-	let negative_prompt = {
-		let widgets = text_area_widget(document_node, node_id, neg_index, "Negative Prompt", true);
-		LayoutGroup::Row { widgets }.with_tooltip("A negative text prompt can be used to list things like objects or colors to avoid")
-	};
-	let base_image = {
-		let widgets = bool_widget(document_node, node_id, base_img_index, "Adapt Input Image", true);
-		LayoutGroup::Row { widgets }.with_tooltip("Generate an image based upon the bitmap data plugged into this node")
-	};
-	let image_creativity = {
-		let props = NumberInput::default().percentage().disabled(!use_base_image);
-		let widgets = number_widget(document_node, node_id, img_creativity_index, "Image Creativity", props, true);
-		LayoutGroup::Row { widgets }.with_tooltip(
-			"Strength of the artistic liberties allowing changes from the input image. The image is unchanged at 0% and completely different at 100%.\n\
-			\n\
-			This parameter is otherwise known as denoising strength.",
-		)
-	};
+pub fn extrude_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let direction = vec2_widget(document_node, node_id, 1, "Direction", "X", "Y", " px", None, add_blank_assist);
+	let shading = number_widget(document_node, node_id, 2, "Shading", NumberInput::default().mode_range().min(0.).max(1.), true);
+	let side_color = color_widget(document_node, node_id, 3, "Side Color", ColorButton::default(), true);
 
-	let mut layout = vec![
-		server_status,
-		progress,
-		image_controls,
-		seed,
-		resolution,
-		sampling_steps,
-		sampling_method,
-		text_guidance,
-		text_prompt,
-		negative_prompt,
-		base_image,
-		image_creativity,
-		// layer_mask,
-	];
+	vec![
+		direction.with_tooltip("How far, and which way, the shape is swept to form its sides"),
+		LayoutGroup::Row { widgets: shading }.with_tooltip("How much darker the sides facing away from the light are drawn"),
+		side_color.with_tooltip("Color of the sides, or the color of the shape itself when there's none"),
+	]
+}
 
-	// if use_base_image && layer_reference_input_layer_is_some {
-	// 	let in_paint = {
-	// 		let mut widgets = start_widgets(document_node, node_id, inpaint_index, "Inpaint", FrontendGraphDataType::Boolean, true);
+pub fn brush_along_path_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let size = number_widget(document_node, node_id, 2, "Size", NumberInput::default().unit(" px").min(0.1), true);
+	let spacing = number_widget(document_node, node_id, 3, "Spacing", NumberInput::default().unit("%").min(1.), true);
+	let scatter = number_widget(document_node, node_id, 4, "Scatter", NumberInput::default().unit("%").min(0.), true);
+	let rotation_jitter = number_widget(document_node, node_id, 5, "Rotation Jitter", NumberInput::default().unit("°").min(0.).max(180.), true);
+	let seed = number_widget(document_node, node_id, 6, "Seed", NumberInput::default().int().min(0.), true);
+	let pressure_attribute = text_widget(document_node, node_id, 7, "Pressure Attribute", true);
+	let rasterize = bool_widget(document_node, node_id, 8, "Rasterize", true);
 
-	// 		if let &NodeInput::Value {
-	// 			tagged_value: TaggedValue::Bool(in_paint),
-	// 			exposed: false,
-	// 		} = &document_node.inputs[inpaint_index]
-	// 		{
-	// 			widgets.extend_from_slice(&[
-	// 				Separator::new(SeparatorType::Unrelated).widget_holder(),
-	// 				RadioInput::new(
-	// 					[(true, "Inpaint"), (false, "Outpaint")]
-	// 						.into_iter()
-	// 						.map(|(paint, name)| RadioEntryData::new(name).label(name).on_update(update_value(move |_| TaggedValue::Bool(paint), node_id, inpaint_index)))
-	// 						.collect(),
-	// 				)
-	// 				.selected_index(Some(1 - in_paint as u32))
-	// 				.widget_holder(),
-	// 			]);
-	// 		}
-	// 		LayoutGroup::Row { widgets }.with_tooltip(
-	// 			"Constrain image generation to the interior (inpaint) or exterior (outpaint) of the mask, while referencing the other unchanged parts as context imagery.\n\
-	// 			\n\
-	// 			An unwanted part of an image can be replaced by drawing around it with a black shape and inpainting with that mask layer.\n\
-	// 			\n\
-	// 			An image can be uncropped by resizing the Imaginate layer to the target bounds and outpainting with a black rectangle mask matching the original image bounds.",
-	// 		)
-	// 	};
+	vec![
+		LayoutGroup::Row { widgets: size }.with_tooltip("Length of the longer side of each stamp of the brush tip"),
+		LayoutGroup::Row { widgets: spacing }.with_tooltip("Distance between neighboring stamps, as a percentage of their size"),
+		LayoutGroup::Row { widgets: scatter }.with_tooltip("Most that each stamp is pushed off to the side of the path, as a percentage of its size"),
+		LayoutGroup::Row { widgets: rotation_jitter }.with_tooltip("Most that each stamp is randomly turned by"),
+		LayoutGroup::Row { widgets: seed }.with_tooltip("Picks the random scatter and turn of each stamp"),
+		LayoutGroup::Row { widgets: pressure_attribute }.with_tooltip("Name of the per-point attribute read as the pen pressure, which scales the stamps"),
+		LayoutGroup::Row { widgets: rasterize }.with_tooltip("Composite the stamps into a single image, rather than keeping each as its own image"),
+	]
+}
 
-	// 	let blur_radius = {
-	// 		let number_props = NumberInput::default().unit(" px").min(0.).max(25.).int();
-	// 		let widgets = number_widget(document_node, node_id, mask_blur_index, "Mask Blur", number_props, true);
-	// 		LayoutGroup::Row { widgets }.with_tooltip("Blur radius for the mask. Useful for softening sharp edges to blend the masked area with the rest of the image.")
-	// 	};
+pub fn mosaic_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let cell_shape = mosaic_cell_widget(document_node, node_id, 1, "Cell Shape", true);
+	let cell_size = number_widget(document_node, node_id, 2, "Cell Size", NumberInput::default().unit(" px").min(0.1), true);
+	let gap = number_widget(document_node, node_id, 3, "Gap", NumberInput::default().unit(" px").min(0.), true);
+	let levels = number_widget(document_node, node_id, 4, "Levels", NumberInput::default().int().min(0.).max(256.), true);
 
-	// 	let mask_starting_fill = {
-	// 		let mut widgets = start_widgets(document_node, node_id, mask_fill_index, "Mask Starting Fill", FrontendGraphDataType::General, true);
+	vec![
+		cell_shape,
+		LayoutGroup::Row { widgets: cell_size }.with_tooltip("Distance between the centers of neighboring cells"),
+		LayoutGroup::Row { widgets: gap }.with_tooltip("Space left between neighboring cells"),
+		LayoutGroup::Row { widgets: levels }.with_tooltip("Number of levels each color channel is posterized to, limiting the palette. Below 2 keeps the colors as they are"),
+	]
+}
 
-	// 		if let &NodeInput::Value {
-	// 			tagged_value: TaggedValue::ImaginateMaskStartingFill(starting_fill),
-	// 			exposed: false,
-	// 		} = &document_node.inputs[mask_fill_index]
-	// 		{
-	// 			let mask_fill_content_modes = ImaginateMaskStartingFill::list();
-	// 			let mut entries = Vec::with_capacity(mask_fill_content_modes.len());
-	// 			for mode in mask_fill_content_modes {
-	// 				entries.push(MenuListEntry::new(format!("{mode:?}")).label(mode.to_string()).on_update(update_value(move |_| TaggedValue::ImaginateMaskStartingFill(mode), node_id, mask_fill_index)));
-	// 			}
-	// 			let entries = vec![entries];
+pub fn medial_axis_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let spacing = number_widget(document_node, node_id, 1, "Spacing", NumberInput::default().unit(" px").min(0.1), true);
+	let pruning = number_widget(document_node, node_id, 2, "Pruning", NumberInput::default().min(1.), true);
 
-	// 			widgets.extend_from_slice(&[
-	// 				Separator::new(SeparatorType::Unrelated).widget_holder(),
-	// 				DropdownInput::new(entries).selected_index(Some(starting_fill as u32)).widget_holder(),
-	// 			]);
-	// 		}
-	// 		LayoutGroup::Row { widgets }.with_tooltip(
-	// 			"Begin in/outpainting the masked areas using this fill content as the starting input image.\n\
-	// 			\n\
-	// 			Each option can be visualized by generating with 'Sampling Steps' set to 0.",
-	// 		)
-	// 	};
-	// 	layout.extend_from_slice(&[in_paint, blur_radius, mask_starting_fill]);
-	// }
+	vec![
+		LayoutGroup::Row { widgets: spacing }.with_tooltip("Distance between the samples taken around the outline. Smaller values give a more accurate skeleton"),
+		LayoutGroup::Row { widgets: pruning }.with_tooltip("How sharp a corner needs to be for the skeleton to branch out to it. Higher values keep only the main lines"),
+	]
+}
 
-	let improve_faces = {
-		let widgets = bool_widget(document_node, node_id, faces_index, "Improve Faces", true);
-		LayoutGroup::Row { widgets }.with_tooltip(
-			"Postprocess human (or human-like) faces to look subtly less distorted.\n\
-			\n\
-			This filter can be used on its own by enabling 'Adapt Input Image' and setting 'Sampling Steps' to 0.",
-		)
-	};
-	let tiling = {
-		let widgets = bool_widget(document_node, node_id, tiling_index, "Tiling", true);
-		LayoutGroup::Row { widgets }.with_tooltip("Generate the image so its edges loop seamlessly to make repeatable patterns or textures")
-	};
-	layout.extend_from_slice(&[improve_faces, tiling]);
+pub fn tangent_normal_lines_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let spacing = number_widget(document_node, node_id, 1, "Spacing", NumberInput::default().unit(" px").min(0.1), true);
+	let length = number_widget(document_node, node_id, 2, "Length", NumberInput::default().unit(" px"), true);
+	let normal = bool_widget(document_node, node_id, 3, "Normal", true);
 
-	layout
+	vec![
+		LayoutGroup::Row { widgets: spacing }.with_tooltip("Distance along the path between neighboring lines"),
+		LayoutGroup::Row { widgets: length }.with_tooltip("Length of each line, where negative values point the other way"),
+		LayoutGroup::Row { widgets: normal }.with_tooltip("Point the lines perpendicular to the path instead of along it"),
+	]
 }
 
-fn unknown_node_properties(document_node: &DocumentNode) -> Vec<LayoutGroup> {
-	string_properties(format!("Node '{}' cannot be found in library", document_node.name))
+pub fn pathfind_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let start = vec2_widget(document_node, node_id, 1, "Start", "X", "Y", " px", None, add_blank_assist);
+	let end = vec2_widget(document_node, node_id, 2, "End", "X", "Y", " px", None, add_blank_assist);
+	let clearance = number_widget(document_node, node_id, 3, "Clearance", NumberInput::default().unit(" px").min(0.), true);
+	let corner_radius = number_widget(document_node, node_id, 4, "Corner Radius", NumberInput::default().unit(" px").min(0.), true);
+
+	vec![
+		start.with_tooltip("Where the route begins, in document space"),
+		end.with_tooltip("Where the route finishes, in document space"),
+		LayoutGroup::Row { widgets: clearance }.with_tooltip("Closest the route may come to the obstacles"),
+		LayoutGroup::Row { widgets: corner_radius }.with_tooltip("How far from each turn of the route its rounding begins"),
+	]
 }
 
-pub fn node_no_properties(_document_node: &DocumentNode, _node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	string_properties("Node has no properties")
+pub fn filter_subpaths_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let measure = subpath_measure_widget(document_node, node_id, 1, "Measure", true);
+	let minimum = number_widget(document_node, node_id, 2, "Minimum", NumberInput::default().min(0.), true);
+	let maximum = number_widget(document_node, node_id, 3, "Maximum", NumberInput::default().min(0.), true);
+	let invert = bool_widget(document_node, node_id, 4, "Invert", true);
+	let order = subpath_order_widget(document_node, node_id, 5, "Order", true);
+
+	vec![
+		measure,
+		LayoutGroup::Row { widgets: minimum }.with_tooltip("Smallest measure of the subpaths that are kept"),
+		LayoutGroup::Row { widgets: maximum }.with_tooltip("Largest measure of the subpaths that are kept, where 0 means there's no upper limit"),
+		LayoutGroup::Row { widgets: invert }.with_tooltip("Keep the subpaths outside of the range instead"),
+		order,
+	]
+}
+
+pub fn get_subpath_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let index = number_widget(document_node, node_id, 1, "Index", NumberInput::default().int(), true);
+	let count = number_widget(document_node, node_id, 2, "Count", NumberInput::default().int().min(1.), true);
+	let out_of_range = index_out_of_range_widget(document_node, node_id, 3, "Out of Range", true);
+
+	vec![
+		LayoutGroup::Row { widgets: index }.with_tooltip("Position of the first subpath to pick, where negative numbers count back from the end with -1 being the last"),
+		LayoutGroup::Row { widgets: count }.with_tooltip("Number of subpaths to pick, starting from the index"),
+		out_of_range,
+	]
+}
+
+pub fn style_subpaths_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let index = number_widget(document_node, node_id, 2, "Index", NumberInput::default().int(), true);
+	let count = number_widget(document_node, node_id, 3, "Count", NumberInput::default().int().min(1.), true);
+
+	vec![
+		LayoutGroup::Row { widgets: index }.with_tooltip("Position of the first subpath to style, where negative numbers count back from the end with -1 being the last"),
+		LayoutGroup::Row { widgets: count }.with_tooltip("Number of subpaths to style, starting from the index"),
+	]
+}
+
+pub fn style_subpaths_by_measure_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let measure = subpath_measure_widget(document_node, node_id, 2, "Measure", true);
+	let minimum = number_widget(document_node, node_id, 3, "Minimum", NumberInput::default().min(0.), true);
+	let maximum = number_widget(document_node, node_id, 4, "Maximum", NumberInput::default().min(0.), true);
+
+	vec![
+		measure,
+		LayoutGroup::Row { widgets: minimum }.with_tooltip("Smallest measure of the subpaths that are styled"),
+		LayoutGroup::Row { widgets: maximum }.with_tooltip("Largest measure of the subpaths that are styled, where 0 means there's no upper limit"),
+	]
 }
 
-pub fn layer_no_properties(_document_node: &DocumentNode, _node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	string_properties("Layer has no properties")
+pub fn weld_points_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let distance = number_widget(document_node, node_id, 1, "Distance", NumberInput::default().unit(" px").min(0.), true);
+
+	vec![LayoutGroup::Row { widgets: distance }.with_tooltip("Points this close together, in document space, are merged into one")]
 }
 
-pub fn index_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let index = number_widget(document_node, node_id, 1, "Index", NumberInput::default().min(0.), true);
+pub fn bounding_box_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let oriented = bool_widget(document_node, node_id, 1, "Oriented", true);
+	let per_subpath = bool_widget(document_node, node_id, 2, "Per Subpath", true);
+	let padding = number_widget(document_node, node_id, 3, "Padding", NumberInput::default().unit(" px"), true);
 
-	vec![LayoutGroup::Row { widgets: index }]
+	vec![
+		LayoutGroup::Row { widgets: oriented }.with_tooltip("Turn the box to whichever angle fits the shape with the least area, instead of keeping it upright"),
+		LayoutGroup::Row { widgets: per_subpath }.with_tooltip("Give each subpath a box of its own"),
+		LayoutGroup::Row { widgets: padding }.with_tooltip("Distance to grow the box outwards by on every side, or to shrink it by if negative"),
+	]
 }
 
-pub fn generate_node_properties(document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> LayoutGroup {
-	let name = document_node.name.clone();
-	let layout = match super::document_node_types::resolve_document_node_type(&name) {
-		Some(document_node_type) => (document_node_type.properties)(document_node, node_id, context),
-		None => unknown_node_properties(document_node),
-	};
-	LayoutGroup::Section { name, layout }
+pub fn set_handle_mirroring_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let colinear = bool_widget(document_node, node_id, 1, "Colinear", true);
+	let angle_tolerance = number_widget(document_node, node_id, 2, "Angle Tolerance", NumberInput::default().unit("°").min(0.).max(180.), true);
+
+	vec![
+		LayoutGroup::Row { widgets: colinear }.with_tooltip("Lock the handles of smooth anchors at 180° from one another, or free the handles of every anchor if unchecked"),
+		LayoutGroup::Row { widgets: angle_tolerance }.with_tooltip("How far from opposite, in degrees, the handles of an anchor may be for it to count as smooth"),
+	]
 }
 
-pub fn stroke_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let color_index = 1;
-	let weight_index = 2;
-	let dash_lengths_index = 3;
-	let dash_offset_index = 4;
-	let line_cap_index = 5;
-	let line_join_index = 6;
-	let miter_limit_index = 7;
+pub fn fit_curve_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let tolerance = number_widget(document_node, node_id, 1, "Tolerance", NumberInput::default().unit(" px").min(0.), true);
+	let closed = bool_widget(document_node, node_id, 2, "Closed", true);
 
-	let color = color_widget(document_node, node_id, color_index, "Color", ColorButton::default(), true);
-	let weight = number_widget(document_node, node_id, weight_index, "Weight", NumberInput::default().unit("px").min(0.), true);
-	let dash_lengths = vec_f64_input(document_node, node_id, dash_lengths_index, "Dash Lengths", TextInput::default().centered(true), true);
-	let dash_offset = number_widget(document_node, node_id, dash_offset_index, "Dash Offset", NumberInput::default().unit("px").min(0.), true);
-	let line_cap = line_cap_widget(document_node, node_id, line_cap_index, "Line Cap", true);
-	let line_join = line_join_widget(document_node, node_id, line_join_index, "Line Join", true);
-	let miter_limit = number_widget(document_node, node_id, miter_limit_index, "Miter Limit", NumberInput::default().min(0.), true);
+	vec![
+		LayoutGroup::Row { widgets: tolerance }.with_tooltip("How far, in document space, the points may be from the curve. Larger values give fewer segments"),
+		LayoutGroup::Row { widgets: closed }.with_tooltip("Join the curve back around to the first point and fill it"),
+	]
+}
+
+pub fn variable_offset_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let distance = number_widget(document_node, node_id, 1, "Distance", NumberInput::default().unit(" px").min(0.), true);
+	let attribute = text_widget(document_node, node_id, 2, "Attribute", true);
+	let taper = bool_widget(document_node, node_id, 3, "Taper", true);
+	let envelope = curves_widget(document_node, node_id, 4, "Envelope", true);
 
 	vec![
-		color,
-		LayoutGroup::Row { widgets: weight },
-		LayoutGroup::Row { widgets: dash_lengths },
-		LayoutGroup::Row { widgets: dash_offset },
-		line_cap,
-		line_join,
-		LayoutGroup::Row { widgets: miter_limit },
+		LayoutGroup::Row { widgets: distance }.with_tooltip("How far the outline is offset to either side of the path, before scaling"),
+		LayoutGroup::Row { widgets: attribute }.with_tooltip("Name of the per-point attribute scaling the distance at each point, blending between the points"),
+		LayoutGroup::Row { widgets: taper }.with_tooltip("Scale the distance along open paths by the envelope"),
+		envelope.with_tooltip("Scale of the distance from the ends of the path (left) to halfway along it (right)"),
 	]
 }
 
-pub fn repeat_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let direction = vec2_widget(document_node, node_id, 1, "Direction", "X", "Y", " px", None, add_blank_assist);
-	let count = number_widget(document_node, node_id, 2, "Count", NumberInput::default().min(1.), true);
+pub fn icon_keyline_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let keyline = icon_keyline_widget(document_node, node_id, 1, "Keyline", true);
+	let grid_size = number_widget(document_node, node_id, 2, "Grid Size", NumberInput::default().unit(" px").min(1.), true);
+	let overshoot = number_widget(document_node, node_id, 3, "Overshoot", NumberInput::default().unit(" px").min(0.), true);
+	let snap_to_pixels = bool_widget(document_node, node_id, 4, "Snap to Pixels", true);
 
-	vec![direction, LayoutGroup::Row { widgets: count }]
+	vec![
+		keyline,
+		LayoutGroup::Row { widgets: grid_size }.with_tooltip("Width and height of the icon grid, whose keylines are given for 24 px and scale along with it"),
+		LayoutGroup::Row { widgets: overshoot }.with_tooltip("How far round artwork reaches past the square keyline on each side, on a 24 px grid, so it doesn't look smaller"),
+		LayoutGroup::Row { widgets: snap_to_pixels }.with_tooltip("Move the anchors onto the pixels of the grid, each a 24th of its size, to keep the edges crisp"),
+	]
 }
 
-pub fn circular_repeat_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let angle_offset = number_widget(document_node, node_id, 1, "Angle Offset", NumberInput::default().unit("°"), true);
-	let radius = number_widget(document_node, node_id, 2, "Radius", NumberInput::default(), true); // TODO: What units?
-	let count = number_widget(document_node, node_id, 3, "Count", NumberInput::default().min(1.), true);
+pub fn snap_to_grid_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let spacing = vec2_widget(document_node, node_id, 1, "Spacing", "X", "Y", " px", Some(0.), add_blank_assist);
+	let origin = vec2_widget(document_node, node_id, 2, "Origin", "X", "Y", " px", None, add_blank_assist);
+	let isometric = bool_widget(document_node, node_id, 3, "Isometric", true);
+	let snap_handles = bool_widget(document_node, node_id, 4, "Snap Handles", true);
 
-	vec![LayoutGroup::Row { widgets: angle_offset }, LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: count }]
+	vec![
+		spacing.with_tooltip("Distance between grid lines, in document space, where only Y is used by the isometric grid"),
+		origin.with_tooltip("Location, in document space, of a point on the grid"),
+		LayoutGroup::Row { widgets: isometric }.with_tooltip("Use a grid of vertical lines crossed by lines slanted 30° up and down, instead of a square grid"),
+		LayoutGroup::Row { widgets: snap_handles }.with_tooltip("Snap the handles to the grid too, rather than moving them along with their anchors"),
+	]
 }
 
-pub fn copy_to_points_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let instance = vector_widget(document_node, node_id, 1, "Instance", true);
+pub fn closest_point_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let position = vec2_widget(document_node, node_id, 1, "Position", "X", "Y", " px", None, add_blank_assist);
 
-	let random_scale_min = number_widget(
-		document_node,
-		node_id,
-		2,
-		"Random Scale Min",
-		NumberInput::default().min(0.).mode_range().range_min(Some(0.)).range_max(Some(2.)).unit("x"),
-		true,
-	);
-	let random_scale_max = number_widget(
-		document_node,
-		node_id,
-		3,
-		"Random Scale Max",
-		NumberInput::default().min(0.).mode_range().range_min(Some(0.)).range_max(Some(2.)).unit("x"),
-		true,
-	);
-	let random_scale_bias = number_widget(
-		document_node,
-		node_id,
-		4,
-		"Random Scale Bias",
-		NumberInput::default().mode_range().range_min(Some(-50.)).range_max(Some(50.)),
-		true,
-	);
+	vec![position.with_tooltip("Location, in document space, to find the nearest point on the path to")]
+}
 
-	let random_rotation = number_widget(document_node, node_id, 5, "Random Rotation", NumberInput::default().min(0.).max(360.).mode_range().unit("°"), true);
+pub fn detect_symmetry_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let symmetry = symmetry_widget(document_node, node_id, 1, "Symmetry", true);
+	let max_order = number_widget(document_node, node_id, 2, "Max Order", NumberInput::default().min(2.).max(64.).int(), true);
 
 	vec![
-		LayoutGroup::Row { widgets: instance }.with_tooltip("Artwork to be copied and placed at each point"),
-		LayoutGroup::Row { widgets: random_scale_min }.with_tooltip("Minimum range of randomized sizes given to each instance"),
-		LayoutGroup::Row { widgets: random_scale_max }.with_tooltip("Maximum range of randomized sizes given to each instance"),
-		LayoutGroup::Row { widgets: random_scale_bias }
-			.with_tooltip("Bias for the probability distribution of randomized sizes (0 is uniform, negatives favor more of small sizes, positives favor more of large sizes)"),
-		LayoutGroup::Row { widgets: random_rotation }.with_tooltip("Range of randomized angles given to each instance, in degrees ranging from furthest clockwise to counterclockwise"),
+		symmetry,
+		LayoutGroup::Row { widgets: max_order }.with_tooltip("Most rotated copies to look for, when looking for rotation"),
 	]
 }
 
-pub fn sample_points_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let spacing = number_widget(document_node, node_id, 1, "Spacing", NumberInput::default().min(1.).unit(" px"), true);
-	let start_offset = number_widget(document_node, node_id, 2, "Start Offset", NumberInput::default().min(0.).unit(" px"), true);
-	let stop_offset = number_widget(document_node, node_id, 3, "Stop Offset", NumberInput::default().min(0.).unit(" px"), true);
-	let adaptive_spacing = bool_widget(document_node, node_id, 4, "Adaptive Spacing", true);
+pub fn shape_deviation_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let spacing = number_widget(document_node, node_id, 2, "Spacing", NumberInput::default().unit(" px").min(0.1), true);
+	let tolerance = number_widget(document_node, node_id, 3, "Tolerance", NumberInput::default().unit(" px").min(0.), true);
+	let weight = number_widget(document_node, node_id, 4, "Weight", NumberInput::default().unit(" px").min(0.), true);
 
 	vec![
-		LayoutGroup::Row { widgets: spacing }.with_tooltip("Distance between each instance (exact if 'Adaptive Spacing' is disabled, approximate if enabled)"),
-		LayoutGroup::Row { widgets: start_offset }.with_tooltip("Exclude some distance from the start of the path before the first instance"),
-		LayoutGroup::Row { widgets: stop_offset }.with_tooltip("Exclude some distance from the end of the path after the last instance"),
-		LayoutGroup::Row { widgets: adaptive_spacing }.with_tooltip("Round 'Spacing' to a nearby value that divides into the path length evenly"),
+		LayoutGroup::Row { widgets: spacing }.with_tooltip("Length of the pieces the outline is split into, each colored by its own distance from the reference"),
+		LayoutGroup::Row { widgets: tolerance }.with_tooltip("Distance from the reference at which the outline is colored fully red"),
+		LayoutGroup::Row { widgets: weight }.with_tooltip("Stroke weight of the colored outline"),
 	]
 }
 
-pub fn poisson_disk_points_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let spacing = number_widget(
-		document_node,
-		node_id,
-		1,
-		"Separation Disk Diameter",
-		NumberInput::default().min(0.01).mode_range().range_min(Some(1.)).range_max(Some(100.)),
-		true,
-	);
+pub fn clip_to_viewport_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let margin = number_widget(document_node, node_id, 1, "Margin", NumberInput::default().unit(" px").min(0.), true);
 
-	vec![LayoutGroup::Row { widgets: spacing }]
+	vec![LayoutGroup::Row { widgets: margin }.with_tooltip("Room left around the rendered area, which should be wider than the stroke to keep the lines along the cut out of view")]
+}
+
+pub fn scale_preserving_corners_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let scale = vec2_widget(document_node, node_id, 1, "Scale", "X", "Y", "x", None, add_blank_assist);
+
+	vec![scale]
+}
+
+pub fn align_to_reference_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let scale = bool_widget(document_node, node_id, 2, "Scale", true);
+
+	vec![LayoutGroup::Row { widgets: scale }.with_tooltip("Resize the shape to match the reference, rather than only moving and turning it")]
 }
 
 pub fn morph_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
@@ -2227,12 +3679,89 @@ pub fn morph_properties(document_node: &DocumentNode, node_id: NodeId, _context:
 	]
 }
 
+pub fn project_points_onto_path_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let t_value_attribute = text_widget(document_node, node_id, 2, "T-Value Attribute", true);
+
+	vec![LayoutGroup::Row { widgets: t_value_attribute }.with_tooltip("Name of the per-point attribute that stores where along the path each point was projected to (leave blank to skip)")]
+}
+
+pub fn set_point_attribute_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 1, "Name", true);
+	let start = number_widget(document_node, node_id, 2, "Start", NumberInput::default(), true);
+	let end = number_widget(document_node, node_id, 3, "End", NumberInput::default(), true);
+
+	vec![
+		LayoutGroup::Row { widgets: name }.with_tooltip("Name of the per-point attribute to store the numbers in (\"scale\" and \"rotation\" are used by the Copy to Points node)"),
+		LayoutGroup::Row { widgets: start }.with_tooltip("Value given to the first point"),
+		LayoutGroup::Row { widgets: end }.with_tooltip("Value given to the last point, with the points in between blending from the start value"),
+	]
+}
+
+pub fn set_point_vector_attribute_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 1, "Name", true);
+	let start = vec2_widget(document_node, node_id, 2, "Start", "X", "Y", "", None, add_blank_assist);
+	let end = vec2_widget(document_node, node_id, 3, "End", "X", "Y", "", None, add_blank_assist);
+
+	vec![
+		LayoutGroup::Row { widgets: name }.with_tooltip("Name of the per-point attribute to store the vectors in (\"offset\" is used by the Copy to Points node)"),
+		start,
+		end,
+	]
+}
+
+pub fn set_point_color_attribute_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 1, "Name", true);
+	let start = color_widget(document_node, node_id, 2, "Start", ColorButton::default().allow_none(false), true);
+	let end = color_widget(document_node, node_id, 3, "End", ColorButton::default().allow_none(false), true);
+
+	vec![LayoutGroup::Row { widgets: name }.with_tooltip("Name of the per-point attribute to store the colors in"), start, end]
+}
+
+pub fn read_point_attribute_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 1, "Name", true);
+	let index = number_widget(document_node, node_id, 2, "Index", NumberInput::default().min(0.).is_integer(true), true);
+
+	vec![
+		LayoutGroup::Row { widgets: name }.with_tooltip("Name of the per-point attribute to read (vectors read as their length and colors as their luminance)"),
+		LayoutGroup::Row { widgets: index }.with_tooltip("Index of the point to read the attribute of"),
+	]
+}
+
+pub fn transfer_point_attributes_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 2, "Name", true);
+	let mut layout = vec![LayoutGroup::Row { widgets: name }.with_tooltip("Name of the per-point attribute to copy from the source points (leave blank to copy all of them)")];
+
+	// Nodes made before the method and radius inputs were added don't have them
+	if document_node.inputs.len() > 4 {
+		let radius = number_widget(document_node, node_id, 4, "Radius", NumberInput::default().unit(" px").min(0.), true);
+		layout.push(transfer_method_widget(document_node, node_id, 3, "Method", true));
+		layout.push(LayoutGroup::Row { widgets: radius }.with_tooltip("Distance beyond which points keep their own values, where 0 means there's no limit"));
+	}
+	layout
+}
+
+pub fn lattice_deform_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let columns = number_widget(document_node, node_id, 2, "Columns", NumberInput::default().int().min(2.), true);
+	let rows = number_widget(document_node, node_id, 3, "Rows", NumberInput::default().int().min(2.), true);
+	let subdivisions = number_widget(document_node, node_id, 4, "Subdivisions", NumberInput::default().int().min(1.), true);
+
+	vec![
+		LayoutGroup::Row { widgets: columns }.with_tooltip("Number of control points in each row of the lattice"),
+		LayoutGroup::Row { widgets: rows }.with_tooltip("Number of rows of control points in the lattice"),
+		LayoutGroup::Row { widgets: subdivisions }.with_tooltip("Number of pieces each curve is split into before deforming, for a more accurate result"),
+	]
+}
+
 /// Fill Node Widgets LayoutGroup
 pub fn fill_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let fill_type_index = 1;
 	let solid_color_index = 2;
 	let gradient_type_index = 3;
+	let start_index = 4;
 	let positions_index = 7;
+	let focal_point_index = 8;
+	let fill_rule_index = 9;
+	let blend_mode_index = 10;
 
 	let fill_type = if let &NodeInput::Value {
 		tagged_value: TaggedValue::FillType(fill_type),
@@ -2248,7 +3777,7 @@ pub fn fill_properties(document_node: &DocumentNode, node_id: NodeId, _context:
 	let gradient = fill_type == Some(graphene_core::vector::style::FillType::Gradient);
 	let solid = fill_type == Some(graphene_core::vector::style::FillType::Solid);
 
-	let fill_type_switch = fill_type_widget(document_node, node_id, fill_type_index);
+	let fill_type_switch = fill_type_widget(document_node, node_id, fill_type_index, "Fill Type");
 	widgets.push(fill_type_switch);
 
 	if fill_type.is_none() || solid {
@@ -2257,14 +3786,81 @@ pub fn fill_properties(document_node: &DocumentNode, node_id: NodeId, _context:
 	}
 
 	if fill_type.is_none() || gradient {
+		let gradient_type = match document_node.inputs[gradient_type_index].as_value() {
+			Some(&TaggedValue::GradientType(gradient_type)) => Some(gradient_type),
+			_ => None,
+		};
 		let gradient_type_switch = gradient_type_widget(document_node, node_id, gradient_type_index);
 		widgets.push(gradient_type_switch);
-		gradient_positions(&mut widgets, document_node, "Gradient Positions", node_id, positions_index);
+		let conic = gradient_type == Some(GradientType::Conic);
+		gradient_positions(&mut widgets, document_node, "Gradient Positions", node_id, positions_index, conic);
+
+		if gradient_type == Some(GradientType::Radial) {
+			let center = match document_node.inputs[start_index].as_value() {
+				Some(&TaggedValue::DVec2(center)) => center,
+				_ => DVec2::splat(0.5),
+			};
+			let focal_point = focal_point_widget(document_node, node_id, focal_point_index, center);
+			widgets.push(focal_point.with_tooltip("Where the first color of the radial gradient begins, relative to the bounds of the shape, instead of at its center"));
+		}
 	}
 
+	let fill_rule = fill_rule_widget(document_node, node_id, fill_rule_index, "Fill Rule", true);
+	widgets.push(fill_rule.with_tooltip("How overlapping and nested subpaths are filled, where even-odd cuts a hole wherever one subpath is inside another"));
+	blending_widgets(&mut widgets, document_node, node_id, blend_mode_index);
+
 	widgets
 }
 
+fn focal_point_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, center: DVec2) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, "Focal Point", FrontendGraphDataType::Vector, true);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::OptionalDVec2(vec2),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let focal_point_is_center = vec2.is_none();
+		let vec2 = vec2.unwrap_or(center);
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			CheckboxInput::new(!focal_point_is_center)
+				.icon("Edit12px")
+				.tooltip("Set a focal point instead of using the center of the gradient")
+				.on_update(update_value(
+					move |checkbox_input: &CheckboxInput| TaggedValue::OptionalDVec2(if checkbox_input.checked { Some(vec2) } else { None }),
+					node_id,
+					index,
+				))
+				.on_commit(commit_value)
+				.widget_holder(),
+			Separator::new(SeparatorType::Related).widget_holder(),
+			NumberInput::new(Some(vec2.x))
+				.label("X")
+				.disabled(focal_point_is_center)
+				.on_update(update_value(
+					move |number_input: &NumberInput| TaggedValue::OptionalDVec2(Some(DVec2::new(number_input.value.unwrap(), vec2.y))),
+					node_id,
+					index,
+				))
+				.on_commit(commit_value)
+				.widget_holder(),
+			Separator::new(SeparatorType::Related).widget_holder(),
+			NumberInput::new(Some(vec2.y))
+				.label("Y")
+				.disabled(focal_point_is_center)
+				.on_update(update_value(
+					move |number_input: &NumberInput| TaggedValue::OptionalDVec2(Some(DVec2::new(vec2.x, number_input.value.unwrap()))),
+					node_id,
+					index,
+				))
+				.on_commit(commit_value)
+				.widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
 pub fn artboard_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let location = vec2_widget(document_node, node_id, 1, "Location", "X", "Y", " px", None, add_blank_assist);
 	let dimensions = vec2_widget(document_node, node_id, 2, "Dimensions", "W", "H", " px", None, add_blank_assist);
@@ -2275,6 +3871,241 @@ pub fn artboard_properties(document_node: &DocumentNode, node_id: NodeId, _conte
 	vec![location, dimensions, background, clip]
 }
 
+pub fn channel_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let channel = text_widget(document_node, node_id, 1, "Channel", true);
+
+	vec![LayoutGroup::Row { widgets: channel }.with_tooltip("Name of the print channel (such as \"spot-uv\" or \"foil\") that this content is separated into when exporting")]
+}
+
+pub fn reference_image_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let opacity = number_widget(document_node, node_id, 2, "Opacity", NumberInput::default().mode_range().min(0.).max(100.).unit("%"), true);
+	let lock_to_artboard = bool_widget(document_node, node_id, 3, "Lock to Artboard", true);
+
+	vec![
+		LayoutGroup::Row { widgets: opacity }.with_tooltip("How strongly the image shows beneath the artwork while tracing over it"),
+		LayoutGroup::Row { widgets: lock_to_artboard }.with_tooltip("Place the image relative to the first artboard, beneath its artwork, so it moves with the artboard"),
+	]
+}
+
+pub fn export_set_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 1, "Name", true);
+	let targets = text_widget(document_node, node_id, 2, "Targets", true);
+
+	vec![
+		LayoutGroup::Row { widgets: name }.with_tooltip("Name of the exported files, which each target's suffix is appended to"),
+		LayoutGroup::Row { widgets: targets }.with_tooltip("Comma separated files to export, each written as its scale, format, and optional suffix, like \"2x png @2x\""),
+	]
+}
+
+pub fn export_svg_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let precision = number_widget(document_node, node_id, 1, "Precision", NumberInput::default().int().min(0.).max(12.), true);
+	let view_box = svg_view_box_widget(document_node, node_id, 2, "View Box", true);
+	let flatten_transforms = bool_widget(document_node, node_id, 3, "Flatten Transforms", true);
+	let deduplicate_defs = bool_widget(document_node, node_id, 4, "Deduplicate Defs", true);
+
+	vec![
+		LayoutGroup::Row { widgets: precision }.with_tooltip("Number of decimal places that numbers in the SVG are rounded to"),
+		view_box,
+		LayoutGroup::Row { widgets: flatten_transforms }.with_tooltip("Apply the transforms of groups and artboards to their contents instead of writing them as transform attributes"),
+		LayoutGroup::Row { widgets: deduplicate_defs }.with_tooltip("Write identical gradients and other definitions once, pointing every use at the same one"),
+	]
+}
+
+pub fn atlas_pack_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let names = text_widget(document_node, node_id, 1, "Names", true);
+	let scale = number_widget(document_node, node_id, 2, "Scale", NumberInput::default().min(0.).unit("x"), true);
+	let padding = number_widget(document_node, node_id, 3, "Padding", NumberInput::default().int().min(0.).unit(" px"), true);
+	let max_width = number_widget(document_node, node_id, 4, "Max Width", NumberInput::default().int().min(1.).unit(" px"), true);
+	let power_of_two = bool_widget(document_node, node_id, 5, "Power of Two", true);
+
+	vec![
+		LayoutGroup::Row { widgets: names }.with_tooltip("Comma separated names of the sprites, in order, with the rest named by their kind and number"),
+		LayoutGroup::Row { widgets: scale }.with_tooltip("Pixels per unit of the document that vector content is rasterized at, while images keep their own pixels"),
+		LayoutGroup::Row { widgets: padding }.with_tooltip("Empty space around each sprite, so sampling one doesn't bleed into its neighbors"),
+		LayoutGroup::Row { widgets: max_width }.with_tooltip("Width past which sprites are wrapped onto a new row"),
+		LayoutGroup::Row { widgets: power_of_two }.with_tooltip("Round the dimensions of the atlas up to powers of two, as some graphics hardware requires"),
+	]
+}
+
+pub fn nine_patch_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let insets = ["Left", "Top", "Right", "Bottom"]
+		.into_iter()
+		.enumerate()
+		.map(|(index, name)| number_widget(document_node, node_id, index + 1, name, NumberInput::default().int().min(0.).unit(" px"), true));
+
+	insets
+		.map(|widgets| LayoutGroup::Row { widgets }.with_tooltip("Distance in from this edge of the artwork, in pixels at 1x, where the region that stretches begins"))
+		.collect()
+}
+
+pub fn encode_png_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let best_compression = bool_widget(document_node, node_id, 1, "Best Compression", true);
+
+	vec![LayoutGroup::Row { widgets: best_compression }.with_tooltip("Compress the file as much as possible, which makes it smaller but takes longer")]
+}
+
+pub fn encode_jpeg_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let quality = number_widget(document_node, node_id, 1, "Quality", NumberInput::default().int().min(1.).max(100.), true);
+	let background = color_widget(document_node, node_id, 2, "Background", ColorButton::default().allow_none(false), true);
+
+	vec![
+		LayoutGroup::Row { widgets: quality }.with_tooltip("Lower qualities make smaller files with more visible compression artifacts"),
+		background.with_tooltip("Color shown through the transparent parts of the image, since JPEG files have no transparency"),
+	]
+}
+
+pub fn encode_webp_properties(_document_node: &DocumentNode, _node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let label = TextLabel::new("Lossless only, so there's no quality to set").widget_holder();
+
+	vec![LayoutGroup::Row { widgets: vec![label] }.with_tooltip("Only lossless WebP encoding is available, which keeps every pixel exactly but makes larger files than lossy encoding would")]
+}
+
+pub fn to_css_clip_path_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let format = clip_path_format_widget(document_node, node_id, 1, "Format", true);
+	let precision = number_widget(document_node, node_id, 2, "Precision", NumberInput::default().int().min(0.).max(10.), true);
+	let normalize = bool_widget(document_node, node_id, 3, "Normalize", true);
+
+	vec![
+		format,
+		LayoutGroup::Row { widgets: precision }.with_tooltip("Number of decimal places the coordinates are rounded to"),
+		LayoutGroup::Row { widgets: normalize }
+			.with_tooltip("Move a path so its bounding box starts at the top left of the element, or give a polygon in percentages of its bounding box so it fills the element"),
+	]
+}
+
+pub fn generate_path_code_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let language = path_code_language_widget(document_node, node_id, 1, "Language", true);
+	let precision = number_widget(document_node, node_id, 2, "Precision", NumberInput::default().int().min(0.).max(10.), true);
+
+	vec![
+		language,
+		LayoutGroup::Row { widgets: precision }.with_tooltip("Number of decimal places the coordinates are rounded to"),
+	]
+}
+
+pub fn align_distribute_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let horizontal = horizontal_alignment_widget(document_node, node_id, 1, "Horizontal", true);
+	let vertical = vertical_alignment_widget(document_node, node_id, 2, "Vertical", true);
+	let distribute = distribution_widget(document_node, node_id, 3, "Distribute", true);
+
+	vec![horizontal, vertical, distribute]
+}
+
+pub fn design_import_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let json = text_area_widget(document_node, node_id, 1, "JSON", true);
+
+	vec![LayoutGroup::Row { widgets: json }.with_tooltip("The JSON of a Figma file or node from its REST API (requested with geometry=paths), or of a page or layer from a Sketch file")]
+}
+
+pub fn for_each_row_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let table = text_area_widget(document_node, node_id, 1, "Table", true);
+	let columns = number_widget(document_node, node_id, 2, "Columns", NumberInput::default().int().min(1.), true);
+	let cell_size = vec2_widget(document_node, node_id, 3, "Cell Size", "W", "H", " px", Some(0.), add_blank_assist);
+	let artboards = bool_widget(document_node, node_id, 4, "Artboards", true);
+
+	vec![
+		LayoutGroup::Row { widgets: table }.with_tooltip("Comma-separated values with the column names on the first line, where each following line generates one copy of the template"),
+		LayoutGroup::Row { widgets: columns }.with_tooltip("Number of copies placed side by side before starting a new row of the grid"),
+		cell_size.with_tooltip("Spacing between copies, which is also the size of each artboard"),
+		LayoutGroup::Row { widgets: artboards }.with_tooltip("Place each copy in its own artboard so they can be exported individually"),
+	]
+}
+
+pub fn row_field_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let table = text_area_widget(document_node, node_id, 0, "Table", true);
+	let column = text_widget(document_node, node_id, 1, "Column", true);
+
+	vec![
+		LayoutGroup::Row { widgets: table }.with_tooltip("The same table given to the For Each Row node"),
+		LayoutGroup::Row { widgets: column }.with_tooltip("Name of the column to read from the row currently being generated"),
+	]
+}
+
+pub fn sequence_number_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let start = number_widget(document_node, node_id, 0, "Start", NumberInput::default().int().min(0.), true);
+	let step = number_widget(document_node, node_id, 1, "Step", NumberInput::default().int().min(0.), true);
+	let digits = number_widget(document_node, node_id, 2, "Digits", NumberInput::default().int().min(0.).max(20.), true);
+	let prefix = text_widget(document_node, node_id, 3, "Prefix", true);
+	let suffix = text_widget(document_node, node_id, 4, "Suffix", true);
+
+	vec![
+		LayoutGroup::Row { widgets: start }.with_tooltip("Serial number given to the first row of the data table"),
+		LayoutGroup::Row { widgets: step }.with_tooltip("Amount added to the serial number for each following row"),
+		LayoutGroup::Row { widgets: digits }.with_tooltip("Minimum number of digits, padding shorter numbers with leading zeros"),
+		LayoutGroup::Row { widgets: prefix }.with_tooltip("Text placed before the number"),
+		LayoutGroup::Row { widgets: suffix }.with_tooltip("Text placed after the number"),
+	]
+}
+
+pub fn uuid_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let seed = number_widget(document_node, node_id, 0, "Seed", NumberInput::default().int().min(0.), true);
+
+	vec![LayoutGroup::Row { widgets: seed }.with_tooltip("Seed determines the generated identifiers, so changing it produces a new batch")]
+}
+
+pub fn parameter_sweep_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let parameter = text_widget(document_node, node_id, 1, "Parameter", true);
+	let values = text_widget(document_node, node_id, 2, "Values", true);
+	let columns = number_widget(document_node, node_id, 3, "Columns", NumberInput::default().int().min(1.), true);
+	let cell_size = vec2_widget(document_node, node_id, 4, "Cell Size", "W", "H", " px", Some(0.), add_blank_assist);
+	let labels = bool_widget(document_node, node_id, 5, "Labels", true);
+
+	vec![
+		LayoutGroup::Row { widgets: parameter }.with_tooltip("Name of the Parameter node in the template that is given each of the values"),
+		LayoutGroup::Row { widgets: values }
+			.with_tooltip("Comma-separated numbers, where a range like 0..100/5 is that many evenly spaced numbers from its start to its end, or every whole number between them without the count"),
+		LayoutGroup::Row { widgets: columns }.with_tooltip("Number of copies placed side by side before starting a new row of the grid"),
+		cell_size.with_tooltip("Spacing between copies"),
+		LayoutGroup::Row { widgets: labels }.with_tooltip("Label each copy with the parameter and its value along the bottom of its cell"),
+	]
+}
+
+pub fn parameter_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 0, "Name", true);
+	let default = number_widget(document_node, node_id, 1, "Default", NumberInput::default(), true);
+
+	vec![
+		LayoutGroup::Row { widgets: name }.with_tooltip("Name that a Parameter Sweep node refers to this parameter by"),
+		LayoutGroup::Row { widgets: default }.with_tooltip("Value used when the parameter isn't being swept"),
+	]
+}
+
+pub fn controller_parameter_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 0, "Name", true);
+	let default = number_widget(document_node, node_id, 1, "Default", NumberInput::default(), true);
+	let address = text_widget(document_node, node_id, 2, "Address", true);
+	let port = number_widget(document_node, node_id, 3, "OSC Port", NumberInput::default().int().min(0.).max(u16::MAX as f64), true);
+	let min = number_widget(document_node, node_id, 4, "Min", NumberInput::default(), true);
+	let max = number_widget(document_node, node_id, 5, "Max", NumberInput::default(), true);
+
+	vec![
+		LayoutGroup::Row { widgets: name }.with_tooltip("Name that a Parameter Sweep node refers to this parameter by"),
+		LayoutGroup::Row { widgets: default }.with_tooltip("Value used until the controller sends one"),
+		LayoutGroup::Row { widgets: address }.with_tooltip("OSC address like /1/fader1, or MIDI control like midi/cc/1/7, midi/note/1/60, or midi/pitch/1 (for channel 1)"),
+		LayoutGroup::Row { widgets: port }.with_tooltip("UDP port that OSC messages are sent to"),
+		LayoutGroup::Row { widgets: min }.with_tooltip("Value when the controller is at its lowest"),
+		LayoutGroup::Row { widgets: max }.with_tooltip("Value when the controller is at its highest"),
+	]
+}
+
+pub fn impose_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let sheet_dimensions = vec2_widget(document_node, node_id, 1, "Sheet Dimensions", "W", "H", " px", Some(1.), add_blank_assist);
+	let gutter = vec2_widget(document_node, node_id, 2, "Gutter", "X", "Y", " px", Some(0.), add_blank_assist);
+	let margin = number_widget(document_node, node_id, 3, "Margin", NumberInput::default().min(0.).unit(" px"), true);
+	let back_side = bool_widget(document_node, node_id, 4, "Back Side", true);
+	let cut_marks = bool_widget(document_node, node_id, 5, "Cut Marks", true);
+	let cut_mark_length = number_widget(document_node, node_id, 6, "Cut Mark Length", NumberInput::default().min(0.).unit(" px"), true);
+
+	vec![
+		sheet_dimensions.with_tooltip("Size of the print sheet that the artboard is tiled onto"),
+		gutter.with_tooltip("Spacing between neighboring copies of the artboard"),
+		LayoutGroup::Row { widgets: margin }.with_tooltip("Unprintable border kept free around the edges of the sheet"),
+		LayoutGroup::Row { widgets: back_side }.with_tooltip("Mirror the layout so it lines up with the front side when printing duplex (flipped on the long edge)"),
+		LayoutGroup::Row { widgets: cut_marks }.with_tooltip("Draw crop marks outside the grid at each trim line"),
+		LayoutGroup::Row { widgets: cut_mark_length },
+	]
+}
+
 pub fn color_fill_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let color = color_widget(document_node, node_id, 1, "Color", ColorButton::default(), true);
 	vec![color]
@@ -3,4 +3,5 @@ pub mod document_metadata;
 pub mod error;
 pub mod misc;
 pub mod nodes;
+pub mod open_raster;
 pub mod transformation;
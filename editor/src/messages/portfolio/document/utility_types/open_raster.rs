@@ -0,0 +1,99 @@
+//! Helpers for exporting layered artwork as an [OpenRaster](https://www.openraster.org/) file.
+
+use crate::messages::frontend::utility_types::FrontendRasterLayer;
+
+use graphene_core::raster::BlendMode;
+use graphene_std::wasm_application_io::SvgLayer;
+
+use glam::UVec2;
+use std::fmt::Write;
+
+/// Builds the `stack.xml` file of an OpenRaster archive, given the layers ordered from bottom to top.
+pub fn stack_xml(size: UVec2, layers: &[SvgLayer]) -> String {
+	let mut xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<image version=\"0.0.5\" w=\"{}\" h=\"{}\">\n<stack>\n", size.x, size.y);
+
+	// OpenRaster lists layers from top to bottom
+	for (index, layer) in layers.iter().enumerate().rev() {
+		writeln!(
+			xml,
+			r#"<layer name="{}" src="{}" x="0" y="0" opacity="{}" composite-op="{}" visibility="visible"/>"#,
+			escape_xml(&layer.name),
+			layer_source(index),
+			layer.alpha_blending.opacity.clamp(0., 1.),
+			composite_op(layer.alpha_blending.blend_mode),
+		)
+		.unwrap();
+	}
+
+	xml.push_str("</stack>\n</image>\n");
+	xml
+}
+
+/// Converts the layers into the form sent to the frontend for rasterization, ordered from bottom to top.
+pub fn frontend_layers(layers: Vec<SvgLayer>) -> Vec<FrontendRasterLayer> {
+	layers
+		.into_iter()
+		.enumerate()
+		.map(|(index, layer)| FrontendRasterLayer {
+			svg: layer.svg,
+			source: layer_source(index),
+			opacity: layer.alpha_blending.opacity.clamp(0., 1.),
+			composite_operation: canvas_composite_operation(layer.alpha_blending.blend_mode).to_string(),
+		})
+		.collect()
+}
+
+fn layer_source(index: usize) -> String {
+	format!("data/layer{index}.png")
+}
+
+/// The OpenRaster compositing operator, falling back to normal blending for modes without an equivalent.
+fn composite_op(blend_mode: BlendMode) -> String {
+	match blend_mode {
+		BlendMode::LinearDodge => "svg:plus".to_string(),
+		blend_mode => match blend_mode.to_svg_style_name() {
+			Some(name) if blend_mode != BlendMode::Normal => format!("svg:{name}"),
+			_ => "svg:src-over".to_string(),
+		},
+	}
+}
+
+/// The HTML canvas compositing operation, falling back to normal blending for modes without an equivalent.
+fn canvas_composite_operation(blend_mode: BlendMode) -> &'static str {
+	match blend_mode {
+		BlendMode::LinearDodge => "lighter",
+		blend_mode => match blend_mode.to_svg_style_name() {
+			Some(name) if blend_mode != BlendMode::Normal => name,
+			_ => "source-over",
+		},
+	}
+}
+
+fn escape_xml(text: &str) -> String {
+	text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use graphene_core::AlphaBlending;
+
+	#[test]
+	fn stack_is_ordered_top_to_bottom() {
+		let layer = |name: &str, opacity, blend_mode| SvgLayer {
+			name: name.to_string(),
+			svg: String::new(),
+			alpha_blending: AlphaBlending { opacity, blend_mode },
+		};
+		let layers = [layer("Background", 1., BlendMode::Normal), layer("Shadows & <Light>", 0.5, BlendMode::Multiply)];
+
+		let xml = stack_xml(UVec2::new(64, 32), &layers);
+		assert!(xml.contains(r#"<image version="0.0.5" w="64" h="32">"#));
+		let top = xml.find("data/layer1.png").unwrap();
+		let bottom = xml.find("data/layer0.png").unwrap();
+		assert!(top < bottom);
+		assert!(xml.contains(r#"name="Shadows &amp; &lt;Light&gt;""#));
+		assert!(xml.contains(r#"opacity="0.5" composite-op="svg:multiply""#));
+		assert!(xml.contains(r#"opacity="1" composite-op="svg:src-over""#));
+	}
+}
@@ -96,6 +96,7 @@ pub enum PortfolioMessage {
 		scale_factor: f64,
 		bounds: ExportBounds,
 		transparent_background: bool,
+		channels: Vec<String>,
 	},
 	SubmitGraphRender {
 		document_id: DocumentId,
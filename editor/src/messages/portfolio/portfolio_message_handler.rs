@@ -474,6 +474,7 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 				scale_factor,
 				bounds,
 				transparent_background,
+				channels,
 			} => {
 				let document = self.active_document_id.and_then(|id| self.documents.get_mut(&id)).expect("Tried to render no existent Document");
 				let export_config = ExportConfig {
@@ -484,7 +485,15 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 					transparent_background,
 					..Default::default()
 				};
-				let result = self.executor.submit_document_export(document, export_config);
+
+				// Export the composite artwork followed by a separate file for each requested channel
+				let channel_configs = channels.into_iter().map(|channel| ExportConfig {
+					channel: Some(channel),
+					..export_config.clone()
+				});
+				let result = std::iter::once(export_config.clone())
+					.chain(channel_configs)
+					.try_for_each(|export_config| self.executor.submit_document_export(document, export_config));
 
 				if let Err(description) = result {
 					responses.add(DialogMessage::DisplayDialogError {
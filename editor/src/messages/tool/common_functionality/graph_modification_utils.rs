@@ -5,9 +5,10 @@ use crate::messages::prelude::*;
 use bezier_rs::{ManipulatorGroup, Subpath};
 use graph_craft::document::{value::TaggedValue, DocumentNode, NodeId, NodeInput, NodeNetwork};
 use graphene_core::raster::{BlendMode, ImageFrame};
-use graphene_core::text::Font;
+use graphene_core::text::{text_frame, Font, FontFeatures, Paragraph, TextLayout};
 use graphene_core::uuid::ManipulatorGroupId;
 use graphene_core::vector::style::{FillType, Gradient};
+use graphene_core::vector::VectorData;
 use graphene_core::Color;
 
 use glam::DVec2;
@@ -114,12 +115,17 @@ pub fn get_gradient(layer: LayerNodeIdentifier, document_network: &NodeNetwork)
 	let TaggedValue::GradientPositions(positions) = inputs.get(7)?.as_value()? else {
 		return None;
 	};
+	let TaggedValue::OptionalDVec2(focal_point) = inputs.get(8)?.as_value()? else {
+		return None;
+	};
 	Some(Gradient {
 		start: *start,
 		end: *end,
 		transform: *transform,
 		positions: positions.clone(),
 		gradient_type: *gradient_type,
+		focal_point: *focal_point,
+		..Default::default()
 	})
 }
 
@@ -194,6 +200,68 @@ pub fn get_text(layer: LayerNodeIdentifier, document_network: &NodeNetwork) -> O
 	Some((text, font, font_size))
 }
 
+/// Gets the options from the Text node that decide how its text is laid out, for inputs that are set to values.
+/// Frame and exclusion shapes are only known when they're values, not when they're fed from other nodes.
+pub fn get_text_layout(layer: LayerNodeIdentifier, document_network: &NodeNetwork) -> Option<TextLayout> {
+	let inputs = NodeGraphLayer::new(layer, document_network).find_node_inputs("Text")?;
+	let value = |index: usize| inputs.get(index).and_then(|input| input.as_value());
+	let font = |index: usize| match value(index) {
+		Some(TaggedValue::Font(font)) => Some(font.clone()),
+		_ => None,
+	};
+	let flag = |index: usize, default: bool| match value(index) {
+		Some(&TaggedValue::Bool(value)) => value,
+		_ => default,
+	};
+	let number = |index: usize, default: f64| match value(index) {
+		Some(&TaggedValue::F64(value)) => value,
+		Some(&TaggedValue::U32(value)) => value as f64,
+		_ => default,
+	};
+	let vector_data = |index: usize| match value(index) {
+		Some(TaggedValue::VectorData(vector_data)) => Some(vector_data),
+		_ => None,
+	};
+
+	let defaults = TextLayout::default();
+	let line_width = number(11, 0.);
+	let frame = match (vector_data(16), vector_data(17)) {
+		(None, None) => None,
+		(frame, exclusions) => {
+			let empty = VectorData::empty();
+			text_frame(frame.unwrap_or(&empty), exclusions.unwrap_or(&empty), number(18, 0.), number(19, 0.))
+		}
+	};
+	Some(TextLayout {
+		fallback_fonts: [font(4), font(5)].into_iter().flatten().collect(),
+		missing_glyph: match value(6) {
+			Some(&TaggedValue::MissingGlyph(missing_glyph)) => missing_glyph,
+			_ => defaults.missing_glyph,
+		},
+		features: FontFeatures {
+			ligatures: flag(7, defaults.features.ligatures),
+			small_caps: flag(8, defaults.features.small_caps),
+			tabular_numbers: flag(9, defaults.features.tabular_numbers),
+			stylistic_set: number(10, defaults.features.stylistic_set as f64) as u32,
+		},
+		line_width: (line_width > 0.).then_some(line_width),
+		paragraph: Paragraph {
+			align: match value(12) {
+				Some(&TaggedValue::TextAlign(align)) => align,
+				_ => defaults.paragraph.align,
+			},
+			hyphenation: match value(13) {
+				Some(&TaggedValue::Hyphenation(hyphenation)) => hyphenation,
+				_ => defaults.paragraph.hyphenation,
+			},
+			max_word_spacing: number(14, defaults.paragraph.max_word_spacing * 100.) / 100.,
+			max_letter_spacing: number(15, defaults.paragraph.max_letter_spacing * 100.) / 100.,
+			drop_cap_lines: number(20, defaults.paragraph.drop_cap_lines as f64) as u32,
+		},
+		frame,
+	})
+}
+
 pub fn get_stroke_width(layer: LayerNodeIdentifier, network: &NodeNetwork) -> Option<f64> {
 	let weight_node_input_index = 2;
 	if let TaggedValue::F64(width) = NodeGraphLayer::new(layer, network).find_input("Stroke", weight_node_input_index)? {
@@ -92,8 +92,12 @@ impl LayoutHolder for GradientTool {
 				.label("Radial")
 				.tooltip("Radial Gradient")
 				.on_update(move |_| GradientToolMessage::UpdateOptions(GradientOptionsUpdate::Type(GradientType::Radial)).into()),
+			RadioEntryData::new("conic")
+				.label("Conic")
+				.tooltip("Conic Gradient")
+				.on_update(move |_| GradientToolMessage::UpdateOptions(GradientOptionsUpdate::Type(GradientType::Conic)).into()),
 		])
-		.selected_index(Some((self.selected_gradient().unwrap_or(self.options.gradient_type) == GradientType::Radial) as u32))
+		.selected_index(Some(self.selected_gradient().unwrap_or(self.options.gradient_type) as u32))
 		.widget_holder();
 
 		Layout::WidgetLayout(WidgetLayout::new(vec![LayoutGroup::Row { widgets: vec![gradient_type] }]))
@@ -12,7 +12,7 @@ use crate::messages::tool::common_functionality::graph_modification_utils::{self
 use graph_craft::document::value::TaggedValue;
 use graph_craft::document::NodeId;
 use graphene_core::renderer::Quad;
-use graphene_core::text::{load_face, Font, FontCache};
+use graphene_core::text::{Font, FontCache};
 use graphene_core::vector::style::Fill;
 use graphene_core::Color;
 
@@ -221,7 +221,7 @@ impl TextToolData {
 		if let Some(editing_text) = self.editing_text.as_ref().filter(|_| editable) {
 			responses.add(FrontendMessage::DisplayEditableTextbox {
 				text: editing_text.text.clone(),
-				line_width: None,
+				line_width: graph_modification_utils::get_text_layout(self.layer, &document.network).and_then(|layout| layout.line_width),
 				font_size: editing_text.font_size,
 				color: editing_text.color.unwrap_or(Color::BLACK),
 				url: font_cache.get_preview_url(&editing_text.font).cloned().unwrap_or_default(),
@@ -310,20 +310,20 @@ impl TextToolData {
 		}
 	}
 
-	fn get_bounds(&self, text: &str, font_cache: &FontCache) -> Option<[DVec2; 2]> {
+	fn get_bounds(&self, text: &str, document: &DocumentMessageHandler, font_cache: &FontCache) -> Option<[DVec2; 2]> {
 		let editing_text = self.editing_text.as_ref()?;
-		let buzz_face = font_cache.get(&editing_text.font).map(|data| load_face(data));
-		let subpaths = graphene_core::text::to_path(text, buzz_face, editing_text.font_size, None);
+		let layout = graph_modification_utils::get_text_layout(self.layer, &document.network).unwrap_or_default();
+		let subpaths = layout.to_path(text, &editing_text.font, editing_text.font_size, font_cache);
 		let bounds = subpaths.iter().filter_map(|subpath| subpath.bounding_box());
 		let combined_bounds = bounds.reduce(|a, b| [a[0].min(b[0]), a[1].max(b[1])]).unwrap_or_default();
 		Some(combined_bounds)
 	}
 
-	fn fix_text_bounds(&self, new_text: &str, _document: &DocumentMessageHandler, font_cache: &FontCache, responses: &mut VecDeque<Message>) -> Option<()> {
+	fn fix_text_bounds(&self, new_text: &str, document: &DocumentMessageHandler, font_cache: &FontCache, responses: &mut VecDeque<Message>) -> Option<()> {
 		responses.add(GraphOperationMessage::UpdateBounds {
 			layer: self.layer,
-			old_bounds: self.get_bounds(&self.editing_text.as_ref()?.text, font_cache)?,
-			new_bounds: self.get_bounds(new_text, font_cache)?,
+			old_bounds: self.get_bounds(&self.editing_text.as_ref()?.text, document, font_cache)?,
+			new_bounds: self.get_bounds(new_text, document, font_cache)?,
 		});
 
 		Some(())
@@ -367,8 +367,8 @@ impl Fsm for TextToolFsmState {
 					transform: document.metadata().transform_to_viewport(tool_data.layer).to_cols_array(),
 				});
 				if let Some(editing_text) = tool_data.editing_text.as_ref() {
-					let buzz_face = font_cache.get(&editing_text.font).map(|data| load_face(data));
-					let far = graphene_core::text::bounding_box(&tool_data.new_text, buzz_face, editing_text.font_size, None);
+					let layout = graph_modification_utils::get_text_layout(tool_data.layer, &document.network).unwrap_or_default();
+					let far = layout.bounding_box(&tool_data.new_text, &editing_text.font, editing_text.font_size, font_cache);
 					if far.x != 0. && far.y != 0. {
 						let quad = Quad::from_box([DVec2::ZERO, far]);
 						let transformed_quad = document.metadata().transform_to_viewport(tool_data.layer) * quad;
@@ -383,8 +383,8 @@ impl Fsm for TextToolFsmState {
 					let Some((text, font, font_size)) = graph_modification_utils::get_text(layer, &document.network) else {
 						continue;
 					};
-					let buzz_face = font_cache.get(font).map(|data| load_face(data));
-					let far = graphene_core::text::bounding_box(text, buzz_face, font_size, None);
+					let layout = graph_modification_utils::get_text_layout(layer, &document.network).unwrap_or_default();
+					let far = layout.bounding_box(text, font, font_size, font_cache);
 					let quad = Quad::from_box([DVec2::ZERO, far]);
 					let multiplied = document.metadata().transform_to_viewport(layer) * quad;
 					overlay_context.quad(multiplied);
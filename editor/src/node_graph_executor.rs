@@ -2,6 +2,7 @@ use crate::consts::FILE_SAVE_SUFFIX;
 use crate::messages::frontend::utility_types::{ExportBounds, FileType};
 use crate::messages::portfolio::document::node_graph::document_node_types::wrap_network_in_scope;
 use crate::messages::portfolio::document::utility_types::document_metadata::LayerNodeIdentifier;
+use crate::messages::portfolio::document::utility_types::open_raster;
 use crate::messages::prelude::*;
 
 use graph_craft::concrete;
@@ -76,6 +77,8 @@ pub struct ExportConfig {
 	pub bounds: ExportBounds,
 	pub transparent_background: bool,
 	pub size: DVec2,
+	/// Only export the content tagged with this print channel, with everything else masked out.
+	pub channel: Option<String>,
 }
 
 pub(crate) struct ExecutionRequest {
@@ -114,6 +117,25 @@ impl NodeGraphUpdateSender for InternalNodeGraphUpdateSender {
 	}
 }
 
+/// Gives the Controller Parameter nodes a world state hash of the controller values' generation, so when a value changes, the graph hash changes and only those nodes
+/// and the ones downstream of them are rebuilt without their cached outputs, while the rest of the graph keeps its caches.
+#[cfg(feature = "native")]
+fn mark_controller_parameters(mut network: NodeNetwork, generation: u64) -> NodeNetwork {
+	fn mark(network: &mut NodeNetwork, generation: u64) {
+		for node in network.nodes.values_mut() {
+			match &mut node.implementation {
+				DocumentNodeImplementation::Network(nested) => mark(nested, generation),
+				implementation if *implementation == DocumentNodeImplementation::proto("graphene_std::controller_input::ControllerParameterNode<_, _, _, _, _, _>") => {
+					node.world_state_hash = generation
+				}
+				_ => {}
+			}
+		}
+	}
+	mark(&mut network, generation);
+	network
+}
+
 thread_local! {
 	pub(crate) static NODE_RUNTIME: Rc<RefCell<Option<NodeRuntime>>> = Rc::new(RefCell::new(None));
 }
@@ -141,6 +163,19 @@ impl NodeRuntime {
 	}
 
 	pub async fn run(&mut self) {
+		// Rebuild the graph from scratch when a watched file changes, so nothing downstream of it keeps a cached result from the old contents
+		#[cfg(feature = "native")]
+		if graphene_std::watch_file::poll_changes() {
+			self.executor = DynamicExecutor::default();
+			self.graph_hash = None;
+			self.sender.send(NodeGraphUpdateMessage::WatchedFileChanged);
+		}
+		// New values from a controller only need the graph to be evaluated again, which rebuilds the nodes reading them (see `mark_controller_parameters`)
+		#[cfg(feature = "native")]
+		if graphene_std::controller_input::poll_changes() {
+			self.sender.send(NodeGraphUpdateMessage::ControllerInputReceived);
+		}
+
 		let mut requests = self.receiver.try_iter().collect::<Vec<_>>();
 		// TODO: Currently we still render the document after we submit the node graph execution request.
 		// This should be avoided in the future.
@@ -181,6 +216,9 @@ impl NodeRuntime {
 			self.wasm_application_io = Some(WasmApplicationIo::new().await);
 		}
 
+		#[cfg(feature = "native")]
+		let graph = mark_controller_parameters(graph, graphene_std::controller_input::generation());
+
 		let editor_api = WasmEditorApi {
 			font_cache: &self.font_cache,
 			imaginate_preferences: &self.imaginate_preferences,
@@ -332,6 +370,19 @@ impl NodeRuntime {
 	}
 }
 
+/// The name of an exported file, which swaps the document's file extension for the export's, after the print channel it's the separation of, if any.
+fn export_file_name(file_name: String, file_type: FileType, channel: Option<&str>) -> String {
+	let file_suffix = &format!(".{file_type:?}").to_lowercase();
+	let file_suffix = &match channel {
+		Some(channel) => format!("-{channel}{file_suffix}"),
+		None => file_suffix.clone(),
+	};
+	match file_name.ends_with(FILE_SAVE_SUFFIX) {
+		true => file_name.replace(FILE_SAVE_SUFFIX, file_suffix),
+		false => file_name + file_suffix,
+	}
+}
+
 pub fn introspect_node(path: &[NodeId]) -> Option<Arc<dyn std::any::Any>> {
 	NODE_RUNTIME
 		.try_with(|runtime| {
@@ -457,6 +508,7 @@ impl NodeGraphExecutor {
 			view_mode: document.view_mode,
 			hide_artboards: false,
 			for_export: false,
+			channel: None,
 		};
 
 		// Execute the node graph
@@ -487,10 +539,18 @@ impl NodeGraphExecutor {
 				resolution: (size * export_config.scale_factor).as_uvec2(),
 				..Default::default()
 			},
-			export_format: graphene_core::application_io::ExportFormat::Svg,
+			export_format: match export_config.file_type {
+				FileType::Eps => graphene_core::application_io::ExportFormat::Eps,
+				FileType::Emf => graphene_core::application_io::ExportFormat::Emf,
+				FileType::Pdf => graphene_core::application_io::ExportFormat::Pdf,
+				FileType::Json => graphene_core::application_io::ExportFormat::Json,
+				FileType::Ora => graphene_core::application_io::ExportFormat::SvgLayers,
+				_ => graphene_core::application_io::ExportFormat::Svg,
+			},
 			view_mode: document.view_mode,
 			hide_artboards: export_config.transparent_background,
 			for_export: true,
+			channel: export_config.channel.clone(),
 		};
 		export_config.size = size;
 
@@ -503,30 +563,44 @@ impl NodeGraphExecutor {
 	}
 
 	fn export(&self, node_graph_output: TaggedValue, export_config: ExportConfig, responses: &mut VecDeque<Message>) -> Result<(), String> {
-		let TaggedValue::RenderOutput(graphene_std::wasm_application_io::RenderOutput::Svg(svg)) = node_graph_output else {
-			return Err("Incorrect render type for exportign (expected RenderOutput::Svg)".to_string());
-		};
-
 		let ExportConfig {
 			file_type,
 			file_name,
 			size,
 			scale_factor,
+			channel,
 			..
 		} = export_config;
 
-		let file_suffix = &format!(".{file_type:?}").to_lowercase();
-		let name = match file_name.ends_with(FILE_SAVE_SUFFIX) {
-			true => file_name.replace(FILE_SAVE_SUFFIX, file_suffix),
-			false => file_name + file_suffix,
-		};
+		let name = export_file_name(file_name, file_type, channel.as_deref());
 
-		if file_type == FileType::Svg {
-			responses.add(FrontendMessage::TriggerDownloadTextFile { document: svg, name });
-		} else {
-			let mime = file_type.to_mime().to_string();
-			let size = (size * scale_factor).into();
-			responses.add(FrontendMessage::TriggerDownloadImage { svg, name, mime, size });
+		let size = size * scale_factor;
+		match node_graph_output {
+			TaggedValue::RenderOutput(graphene_std::wasm_application_io::RenderOutput::Svg(svg)) if file_type == FileType::Svg => {
+				responses.add(FrontendMessage::TriggerDownloadTextFile { document: svg, name });
+			}
+			TaggedValue::RenderOutput(graphene_std::wasm_application_io::RenderOutput::Svg(svg)) => {
+				let mime = file_type.to_mime().to_string();
+				responses.add(FrontendMessage::TriggerDownloadImage { svg, name, mime, size: size.into() });
+			}
+			TaggedValue::RenderOutput(graphene_std::wasm_application_io::RenderOutput::Eps(document) | graphene_std::wasm_application_io::RenderOutput::Json(document)) => {
+				responses.add(FrontendMessage::TriggerDownloadTextFile { document, name });
+			}
+			TaggedValue::RenderOutput(graphene_std::wasm_application_io::RenderOutput::Emf(data) | graphene_std::wasm_application_io::RenderOutput::Pdf(data)) => {
+				let mime = file_type.to_mime().to_string();
+				responses.add(FrontendMessage::TriggerDownloadBinaryFile { data, name, mime });
+			}
+			TaggedValue::RenderOutput(graphene_std::wasm_application_io::RenderOutput::SvgLayers(layers)) => {
+				let stack = open_raster::stack_xml(size.as_uvec2(), &layers);
+				let layers = open_raster::frontend_layers(layers);
+				responses.add(FrontendMessage::TriggerDownloadOpenRaster {
+					stack,
+					layers,
+					name,
+					size: size.into(),
+				});
+			}
+			_ => return Err("Incorrect render type for exporting (expected RenderOutput::Svg, Eps, Emf, Pdf, Json, or SvgLayers)".to_string()),
 		}
 		Ok(())
 	}
@@ -573,6 +647,9 @@ impl NodeGraphExecutor {
 				NodeGraphUpdate::NodeGraphUpdateMessage(NodeGraphUpdateMessage::ImaginateStatusUpdate) => {
 					responses.add(DocumentMessage::PropertiesPanel(PropertiesPanelMessage::Refresh));
 				}
+				NodeGraphUpdate::NodeGraphUpdateMessage(NodeGraphUpdateMessage::WatchedFileChanged | NodeGraphUpdateMessage::ControllerInputReceived) => {
+					responses.add(NodeGraphMessage::RunDocumentGraph);
+				}
 			}
 		}
 		Ok(())
@@ -638,3 +715,17 @@ impl NodeGraphExecutor {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn export_file_names() {
+		assert_eq!(export_file_name("Poster.graphite".to_string(), FileType::Pdf, None), "Poster.pdf");
+		assert_eq!(export_file_name("Poster".to_string(), FileType::Png, None), "Poster.png");
+		// Each print channel is exported to its own file, named after the channel
+		assert_eq!(export_file_name("Poster.graphite".to_string(), FileType::Pdf, Some("Spot Gloss")), "Poster-Spot Gloss.pdf");
+		assert_eq!(export_file_name("Poster".to_string(), FileType::Svg, Some("Cut")), "Poster-Cut.svg");
+	}
+}
@@ -4,14 +4,17 @@ import { writable } from "svelte/store";
 
 import { copyToClipboardFileURL } from "@graphite/io-managers/clipboard";
 import { downloadFileText, downloadFileBlob, upload } from "@graphite/utility-functions/files";
-import { extractPixelData, imageToPNG, rasterizeSVG } from "@graphite/utility-functions/rasterization";
+import { extractPixelData, imageToPNG, rasterizeSVG, rasterizeSVGCanvas } from "@graphite/utility-functions/rasterization";
+import { createZip, type ZipEntry } from "@graphite/utility-functions/zip";
 import { type Editor } from "@graphite/wasm-communication/editor";
 import {
 	type FrontendDocumentDetails,
 	TriggerCopyToClipboardBlobUrl,
 	TriggerFetchAndOpenDocument,
+	TriggerDownloadBinaryFile,
 	TriggerDownloadBlobUrl,
 	TriggerDownloadImage,
+	TriggerDownloadOpenRaster,
 	TriggerDownloadTextFile,
 	TriggerImport,
 	TriggerOpenDocument,
@@ -79,6 +82,10 @@ export function createPortfolioState(editor: Editor) {
 	editor.subscriptions.subscribeJsMessage(TriggerDownloadTextFile, (triggerFileDownload) => {
 		downloadFileText(triggerFileDownload.name, triggerFileDownload.document);
 	});
+	editor.subscriptions.subscribeJsMessage(TriggerDownloadBinaryFile, (triggerFileDownload) => {
+		const { data, name, mime } = triggerFileDownload;
+		downloadFileBlob(name, new Blob([new Uint8Array(data)], { type: mime }));
+	});
 	editor.subscriptions.subscribeJsMessage(TriggerDownloadBlobUrl, async (triggerDownloadBlobUrl) => {
 		const data = await fetch(triggerDownloadBlobUrl.blobUrl);
 		const blob = await data.blob();
@@ -107,6 +114,56 @@ export function createPortfolioState(editor: Editor) {
 			// Fail silently if there's an error rasterizing the SVG, such as a zero-sized image
 		}
 	});
+	editor.subscriptions.subscribeJsMessage(TriggerDownloadOpenRaster, async (triggerDownloadOpenRaster) => {
+		const { stack, layers, name, size } = triggerDownloadOpenRaster;
+		const width = Math.round(size.x);
+		const height = Math.round(size.y);
+
+		const canvasToPNG = async (canvas: HTMLCanvasElement): Promise<Uint8Array> => {
+			const blob = await new Promise<Blob | null>((resolve) => canvas.toBlob(resolve, "image/png"));
+			if (!blob) throw new Error("Converting canvas to blob data failed");
+			return new Uint8Array(await blob.arrayBuffer());
+		};
+
+		try {
+			// The mimetype must be the first file of the archive, and stored uncompressed
+			const entries: ZipEntry[] = [
+				{ name: "mimetype", data: new TextEncoder().encode("image/openraster") },
+				{ name: "stack.xml", data: new TextEncoder().encode(stack) },
+			];
+
+			// Rasterize each layer to its own PNG file while also compositing them together for the merged image
+			const merged = document.createElement("canvas");
+			merged.width = width;
+			merged.height = height;
+			const mergedContext = merged.getContext("2d");
+			if (!mergedContext) throw new Error("Can't create 2D context from canvas during OpenRaster export");
+
+			const canvases = await Promise.all(layers.map((layer) => rasterizeSVGCanvas(layer.svg, width, height)));
+			const pngs = await Promise.all(canvases.map(canvasToPNG));
+			layers.forEach((layer, index) => {
+				entries.push({ name: layer.source, data: pngs[index] });
+
+				mergedContext.globalAlpha = layer.opacity;
+				mergedContext.globalCompositeOperation = layer.compositeOperation;
+				mergedContext.drawImage(canvases[index], 0, 0);
+			});
+			entries.push({ name: "mergedimage.png", data: await canvasToPNG(merged) });
+
+			// The thumbnail is limited to 256 pixels on its longest side
+			const scale = Math.min(1, 256 / Math.max(width, height));
+			const thumbnail = document.createElement("canvas");
+			thumbnail.width = Math.max(1, Math.round(width * scale));
+			thumbnail.height = Math.max(1, Math.round(height * scale));
+			thumbnail.getContext("2d")?.drawImage(merged, 0, 0, thumbnail.width, thumbnail.height);
+			entries.push({ name: "Thumbnails/thumbnail.png", data: await canvasToPNG(thumbnail) });
+
+			// Have the browser download the file to the user's disk
+			downloadFileBlob(name, createZip(entries, "image/openraster"));
+		} catch {
+			// Fail silently if there's an error rasterizing the SVG, such as a zero-sized image
+		}
+	});
 	editor.subscriptions.subscribeJsMessage(TriggerRevokeBlobUrl, async (triggerRevokeBlobUrl) => {
 		URL.revokeObjectURL(triggerRevokeBlobUrl.url);
 	});
@@ -0,0 +1,68 @@
+export type ZipEntry = { name: string; data: Uint8Array };
+
+const CRC_TABLE = new Uint32Array(256).map((_, index) => {
+	let crc = index;
+	for (let bit = 0; bit < 8; bit += 1) {
+		crc = crc & 1 ? 0xedb88320 ^ (crc >>> 1) : crc >>> 1;
+	}
+	return crc;
+});
+
+function crc32(data: Uint8Array): number {
+	let crc = 0xffffffff;
+	data.forEach((byte) => {
+		crc = CRC_TABLE[(crc ^ byte) & 0xff] ^ (crc >>> 8);
+	});
+	return (crc ^ 0xffffffff) >>> 0;
+}
+
+// Package the files, in the given order, into an uncompressed zip archive
+export function createZip(entries: ZipEntry[], mime = "application/zip"): Blob {
+	const encoder = new TextEncoder();
+	const localParts: Uint8Array[] = [];
+	const centralParts: Uint8Array[] = [];
+	let offset = 0;
+
+	entries.forEach(({ name, data }) => {
+		const fileName = encoder.encode(name);
+		const crc = crc32(data);
+
+		// Local file header
+		const local = new DataView(new ArrayBuffer(30));
+		local.setUint32(0, 0x04034b50, true);
+		local.setUint16(4, 20, true); // Version needed to extract
+		local.setUint16(8, 0, true); // Compression method: stored
+		local.setUint32(14, crc, true);
+		local.setUint32(18, data.length, true);
+		local.setUint32(22, data.length, true);
+		local.setUint16(26, fileName.length, true);
+		localParts.push(new Uint8Array(local.buffer), fileName, data);
+
+		// Central directory header
+		const central = new DataView(new ArrayBuffer(46));
+		central.setUint32(0, 0x02014b50, true);
+		central.setUint16(4, 20, true); // Version made by
+		central.setUint16(6, 20, true); // Version needed to extract
+		central.setUint16(10, 0, true); // Compression method: stored
+		central.setUint32(16, crc, true);
+		central.setUint32(20, data.length, true);
+		central.setUint32(24, data.length, true);
+		central.setUint16(28, fileName.length, true);
+		central.setUint32(42, offset, true);
+		centralParts.push(new Uint8Array(central.buffer), fileName);
+
+		offset += 30 + fileName.length + data.length;
+	});
+
+	const centralSize = centralParts.reduce((size, part) => size + part.length, 0);
+
+	// End of central directory record
+	const end = new DataView(new ArrayBuffer(22));
+	end.setUint32(0, 0x06054b50, true);
+	end.setUint16(8, entries.length, true);
+	end.setUint16(10, entries.length, true);
+	end.setUint32(12, centralSize, true);
+	end.setUint32(16, offset, true);
+
+	return new Blob([...localParts, ...centralParts, new Uint8Array(end.buffer)], { type: mime });
+}
@@ -535,6 +535,14 @@ export class TriggerCopyToClipboardBlobUrl extends JsMessage {
 	readonly blobUrl!: string;
 }
 
+export class TriggerDownloadBinaryFile extends JsMessage {
+	readonly data!: number[];
+
+	readonly name!: string;
+
+	readonly mime!: string;
+}
+
 export class TriggerDownloadBlobUrl extends JsMessage {
 	readonly layerName!: string;
 
@@ -558,6 +566,28 @@ export class TriggerDownloadTextFile extends JsMessage {
 	readonly name!: string;
 }
 
+export class FrontendRasterLayer {
+	readonly svg!: string;
+
+	readonly source!: string;
+
+	readonly opacity!: number;
+
+	readonly compositeOperation!: GlobalCompositeOperation;
+}
+
+export class TriggerDownloadOpenRaster extends JsMessage {
+	readonly stack!: string;
+
+	@Type(() => FrontendRasterLayer)
+	readonly layers!: FrontendRasterLayer[];
+
+	readonly name!: string;
+
+	@TupleToVec2
+	readonly size!: XY;
+}
+
 export class TriggerRefreshBoundsOfViewports extends JsMessage {}
 
 export class TriggerRevokeBlobUrl extends JsMessage {
@@ -1292,9 +1322,11 @@ export const messageMakers: Record<string, MessageMaker> = {
 	TriggerAboutGraphiteLocalizedCommitDate,
 	TriggerCopyToClipboardBlobUrl,
 	TriggerFetchAndOpenDocument,
+	TriggerDownloadBinaryFile,
 	TriggerDownloadBlobUrl,
 	TriggerDownloadImage,
 	TriggerDownloadTextFile,
+	TriggerDownloadOpenRaster,
 	TriggerFontLoad,
 	TriggerGraphViewOverlay,
 	TriggerImport,
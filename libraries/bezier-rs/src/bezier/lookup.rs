@@ -268,5 +268,10 @@ mod tests {
 
 		let bezier2 = Bezier::from_quadratic_coordinates(0., 0., 0., 100., 100., 100.);
 		assert_eq!(bezier2.project(DVec2::new(100., 0.)), 0.);
+
+		// Handles coinciding with the end points make the derivative vanish at both ends
+		let bezier3 = Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(100., 0.));
+		let projected = bezier3.evaluate(TValue::Parametric(bezier3.project(DVec2::new(10., -5.))));
+		assert!(projected.abs_diff_eq(DVec2::new(10., 0.), MAX_ABSOLUTE_DIFFERENCE));
 	}
 }
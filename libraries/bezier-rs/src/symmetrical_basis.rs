@@ -440,9 +440,12 @@ impl Bezier1d {
 		let bz = self;
 		let mut n_crossings = 0;
 
-		let mut old_sign = bz[0].signum();
+		// Unlike `f64::signum`, zero has a sign of zero here
+		let sign_of = |value: f64| if value == 0. { 0. } else { value.signum() };
+
+		let mut old_sign = sign_of(bz[0]);
 		for i in 1..bz.len() {
-			let sign = bz[i].signum();
+			let sign = sign_of(bz[i]);
 			if sign != 0. {
 				if sign != old_sign && old_sign != 0. {
 					n_crossings += 1;
@@ -451,7 +454,7 @@ impl Bezier1d {
 			}
 		}
 		// if last control point is zero, that counts as crossing too
-		if bz[bz.len() - 1].signum() == 0. {
+		if bz[bz.len() - 1] == 0. {
 			n_crossings += 1;
 		}
 		// no solutions
@@ -609,4 +612,15 @@ mod tests {
 			assert!(bz.value_at(t,).abs() < 1e-5, "roots should be roots {} {}", t, bz.value_at(t,));
 		}
 	}
+
+	#[test]
+	fn find_bernstein_root_at_end() {
+		// A control point of zero has no sign, so a curve ending at zero has a root there
+		let bz = Bezier1d(vec![1.0, 0.5, 0.0]);
+		let mut solutions = Vec::new();
+		bz.find_bernstein_roots(&mut solutions, 0, 0., 1.);
+
+		assert_eq!(solutions.len(), 1);
+		assert!((solutions[0] - 1.).abs() < 1e-5, "the root should be at the end {}", solutions[0]);
+	}
 }
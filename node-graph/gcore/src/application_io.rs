@@ -127,6 +127,10 @@ pub enum ApplicationError {
 #[derive(Debug, Clone)]
 pub enum NodeGraphUpdateMessage {
 	ImaginateStatusUpdate,
+	/// A file read by the graph was changed on disk, so the graph should be evaluated again.
+	WatchedFileChanged,
+	/// A hardware controller sent a new value for a parameter of the graph, so the graph should be evaluated again.
+	ControllerInputReceived,
 }
 
 pub trait NodeGraphUpdateSender {
@@ -146,15 +150,27 @@ pub enum ExportFormat {
 	},
 	Jpeg,
 	Canvas,
-}
-
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+	/// An Encapsulated PostScript document, for print workflows that require it.
+	Eps,
+	/// An Enhanced Metafile, for pasting vector artwork into office documents.
+	Emf,
+	/// A PDF document, for print workflows that require vector PDF files.
+	Pdf,
+	/// A JSON description of the scene, for external renderers and web viewers to draw the artwork themselves.
+	Json,
+	/// An SVG document for each layer of the artwork, to be assembled into a layered file format.
+	SvgLayers,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct RenderConfig {
 	pub viewport: Footprint,
 	pub export_format: ExportFormat,
 	pub view_mode: ViewMode,
 	pub hide_artboards: bool,
 	pub for_export: bool,
+	/// Only render the content tagged with this print channel, masking out everything else.
+	pub channel: Option<String>,
 }
 
 pub struct EditorApi<'a, Io> {
@@ -175,7 +191,7 @@ impl<'a, Io> Clone for EditorApi<'a, Io> {
 			application_io: self.application_io,
 			node_graph_message_sender: self.node_graph_message_sender,
 			imaginate_preferences: self.imaginate_preferences,
-			render_config: self.render_config,
+			render_config: self.render_config.clone(),
 		}
 	}
 }
@@ -1,6 +1,6 @@
 use crate::raster::{BlendMode, ImageFrame};
 use crate::transform::Footprint;
-use crate::vector::VectorData;
+use crate::vector::{VectorData, VectorInstances};
 use crate::{Color, Node};
 
 use bezier_rs::BezierHandles;
@@ -11,7 +11,13 @@ use core::future::Future;
 use core::ops::{Deref, DerefMut};
 use glam::{DAffine2, DVec2, IVec2, UVec2};
 
+pub mod diff;
+pub mod export;
+pub mod imposition;
+pub mod layout;
+pub mod mask;
 pub mod renderer;
+pub mod variable_data;
 
 #[derive(Copy, Clone, Debug, PartialEq, DynAny, specta::Type)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -46,6 +52,18 @@ pub struct GraphicGroup {
 	elements: Vec<GraphicElement>,
 	pub transform: DAffine2,
 	pub alpha_blending: AlphaBlending,
+	/// The name of the print channel (such as a "spot-uv" or "foil" plate) that the contents of this group are separated into when exporting.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub channel: Option<String>,
+	/// The files that the contents of this group are written to when exporting them headlessly.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub export_set: Option<export::ExportSet>,
+	/// Marks the group as a reference, such as an image to trace over, which is shown while working but left out of exports and can't be clicked on.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub reference: bool,
+	/// Limits what's shown of the group's elements to a clip path or a grayscale raster mask, which is placed in the same space as them.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub mask: Option<Box<mask::Mask>>,
 }
 
 impl core::hash::Hash for GraphicGroup {
@@ -53,11 +71,15 @@ impl core::hash::Hash for GraphicGroup {
 		self.transform.to_cols_array().iter().for_each(|element| element.to_bits().hash(state));
 		self.elements.hash(state);
 		self.alpha_blending.hash(state);
+		self.channel.hash(state);
+		self.export_set.hash(state);
+		self.reference.hash(state);
+		self.mask.hash(state);
 	}
 }
 
 /// The possible forms of graphical content held in a Vec by the `elements` field of [`GraphicElement`].
-/// Can be another recursively nested [`GraphicGroup`], [`VectorData`], an [`ImageFrame`], text (not yet implemented), or an [`Artboard`].
+/// Can be another recursively nested [`GraphicGroup`], [`VectorData`], [`VectorInstances`], an [`ImageFrame`], plain text, or an [`Artboard`].
 #[derive(Clone, Debug, Hash, PartialEq, DynAny)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GraphicElement {
@@ -65,11 +87,13 @@ pub enum GraphicElement {
 	GraphicGroup(GraphicGroup),
 	/// A vector shape, equivalent to the SVG <path> tag: https://developer.mozilla.org/en-US/docs/Web/SVG/Element/path
 	VectorData(Box<VectorData>),
+	/// Copies of a vector shape which share its geometry, each drawn like the SVG <use> tag: https://developer.mozilla.org/en-US/docs/Web/SVG/Element/use
+	VectorInstances(Box<VectorInstances>),
 	/// A bitmap image with a finite position and extent, equivalent to the SVG <image> tag: https://developer.mozilla.org/en-US/docs/Web/SVG/Element/image
 	ImageFrame(ImageFrame<Color>),
 	// TODO: Switch from `String` to a proper formatted typography type
 	/// Text, equivalent to the SVG <text> tag: https://developer.mozilla.org/en-US/docs/Web/SVG/Element/text
-	/// (Not yet implemented.)
+	/// (Only rendered as plain text in a default style, such as for labels, so far.)
 	Text(String),
 	/// The bounds for displaying a page of contained content
 	Artboard(Artboard),
@@ -123,6 +147,51 @@ async fn construct_layer<Data: Into<GraphicElement>, Fut1: Future<Output = Data>
 	stack
 }
 
+pub struct SetChannelNode<Channel> {
+	channel: Channel,
+}
+
+/// Tags the content with the named print channel so it can be exported on its own separation, with everything else masked out.
+#[node_fn(SetChannelNode)]
+fn set_channel<Data: Into<GraphicGroup>>(data: Data, channel: String) -> GraphicGroup {
+	let mut graphic_group = data.into();
+	graphic_group.channel = (!channel.is_empty()).then_some(channel);
+	graphic_group
+}
+
+pub struct ReferenceImageNode<Image, Opacity, LockToArtboard> {
+	image: Image,
+	opacity: Opacity,
+	lock_to_artboard: LockToArtboard,
+}
+
+/// Places the image beneath the content as a dimmed reference to trace over, which is left out when exporting. The opacity is a percentage.
+///
+/// When locked to the artboard, the image is placed relative to the first artboard in the content, beneath its artwork, so it moves with the artboard and is clipped by it.
+#[node_fn(ReferenceImageNode)]
+fn reference_image(mut graphic_group: GraphicGroup, image: ImageFrame<Color>, opacity: f64, lock_to_artboard: bool) -> GraphicGroup {
+	let mut reference = GraphicGroup::from(image);
+	reference.alpha_blending.opacity = (opacity / 100.).clamp(0., 1.) as f32;
+	reference.reference = true;
+
+	let artboard = graphic_group.iter_mut().find_map(|element| match element {
+		GraphicElement::Artboard(artboard) => Some(artboard),
+		_ => None,
+	});
+	match artboard.filter(|_| lock_to_artboard) {
+		Some(artboard) => {
+			// Undo the transform of the artboard's contents so the image is placed relative to the artboard itself
+			let contents_transform = artboard.graphic_group.transform;
+			if contents_transform.matrix2.determinant() != 0. {
+				reference.transform = contents_transform.inverse();
+			}
+			artboard.graphic_group.insert(0, reference.into());
+		}
+		None => graphic_group.insert(0, reference.into()),
+	}
+	graphic_group
+}
+
 pub struct ToGraphicElementNode {}
 
 #[node_fn(ToGraphicElementNode)]
@@ -180,6 +249,11 @@ impl From<VectorData> for GraphicElement {
 		GraphicElement::VectorData(Box::new(vector_data))
 	}
 }
+impl From<VectorInstances> for GraphicElement {
+	fn from(vector_instances: VectorInstances) -> Self {
+		GraphicElement::VectorInstances(Box::new(vector_instances))
+	}
+}
 impl From<GraphicGroup> for GraphicElement {
 	fn from(graphic_group: GraphicGroup) -> Self {
 		GraphicElement::GraphicGroup(graphic_group)
@@ -209,6 +283,7 @@ impl DerefMut for GraphicGroup {
 trait ToGraphicElement: Into<GraphicElement> {}
 
 impl ToGraphicElement for VectorData {}
+impl ToGraphicElement for VectorInstances {}
 impl ToGraphicElement for ImageFrame<Color> {}
 impl ToGraphicElement for Artboard {}
 
@@ -221,6 +296,10 @@ where
 			elements: (vec![value.into()]),
 			transform: DAffine2::IDENTITY,
 			alpha_blending: AlphaBlending::default(),
+			channel: None,
+			export_set: None,
+			reference: false,
+			mask: None,
 		}
 	}
 }
@@ -230,6 +309,10 @@ impl GraphicGroup {
 		elements: Vec::new(),
 		transform: DAffine2::IDENTITY,
 		alpha_blending: AlphaBlending::new(),
+		channel: None,
+		export_set: None,
+		reference: false,
+		mask: None,
 	};
 
 	pub fn to_usvg_tree(&self, resolution: UVec2, viewbox: [DVec2; 2]) -> usvg::Tree {
@@ -289,6 +372,7 @@ impl GraphicElement {
 				path.stroke = Some(usvg::Stroke::default());
 				usvg::Node::Path(Box::new(path))
 			}
+			GraphicElement::VectorInstances(vector_instances) => crate::renderer::GraphicElementRendered::to_usvg_node(&**vector_instances),
 			GraphicElement::ImageFrame(image_frame) => {
 				if image_frame.image.width * image_frame.image.height == 0 {
 					return usvg::Node::Group(Box::default());
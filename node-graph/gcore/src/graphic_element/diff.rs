@@ -0,0 +1,177 @@
+use crate::renderer::GraphicElementRendered;
+use crate::vector::style::{Fill, Stroke};
+use crate::vector::{PointId, VectorData};
+use crate::{Color, GraphicElement, GraphicGroup, Node};
+
+use bezier_rs::Subpath;
+use glam::DAffine2;
+use std::collections::HashSet;
+
+/// Color of the content only found in the later state.
+pub const ADDED_COLOR: Color = Color::GREEN;
+/// Color of the content only found in the earlier state.
+pub const REMOVED_COLOR: Color = Color::RED;
+/// Color of the outline around content found in both states but differing between them.
+pub const CHANGED_COLOR: Color = Color::BLUE;
+/// Opacity of the content which is the same in both states, kept faintly for context.
+const UNCHANGED_OPACITY: f32 = 0.25;
+/// Opacity of the fill given to added and removed shapes, so what's under them still shows.
+const HIGHLIGHT_FILL_OPACITY: f32 = 0.3;
+const HIGHLIGHT_STROKE_WEIGHT: f64 = 2.;
+
+/// Compares two evaluated states of a document, such as before and after an edit, for reviewing what changed between them.
+#[derive(Debug, Clone, Copy)]
+pub struct VisualDiffNode<After> {
+	after: After,
+}
+
+#[node_macro::node_fn(VisualDiffNode)]
+fn visual_diff_node(before: GraphicGroup, after: GraphicGroup) -> GraphicGroup {
+	visual_diff(&before, &after)
+}
+
+/// Builds a [`GraphicGroup`] showing the differences between the two states, with added content in green, removed content in red, and changed content outlined in blue over its new look.
+/// Content that's the same in both is kept faintly underneath for context.
+///
+/// Both states are flattened out of their groups and artboards so the content is compared by where it ends up. Pieces of content are paired up when they're identical,
+/// or for vector shapes when they share any point, since points keep their IDs as shapes are edited. Content other than vector shapes is highlighted by its bounding box.
+pub fn visual_diff(before: &GraphicGroup, after: &GraphicGroup) -> GraphicGroup {
+	let (mut before_leaves, mut after_leaves) = (Vec::new(), Vec::new());
+	collect_leaves(before, DAffine2::IDENTITY, &mut before_leaves);
+	collect_leaves(after, DAffine2::IDENTITY, &mut after_leaves);
+
+	// Identical content is paired first so that a shape sharing points with several others is paired with its unchanged counterpart if there is one
+	let mut paired = vec![false; after_leaves.len()];
+	let mut counterparts = vec![None; before_leaves.len()];
+	for (leaf, counterpart) in before_leaves.iter().zip(&mut counterparts) {
+		*counterpart = (0..after_leaves.len()).find(|&other| !paired[other] && after_leaves[other] == *leaf);
+		if let Some(other) = *counterpart {
+			paired[other] = true;
+		}
+	}
+	for ((_, element), counterpart) in before_leaves.iter().zip(&mut counterparts).filter(|(_, counterpart)| counterpart.is_none()) {
+		let GraphicElement::VectorData(vector_data) = element else { continue };
+		let points = vector_data.point_domain.ids().iter().copied().collect::<HashSet<PointId>>();
+		let shares_points = |element: &GraphicElement| matches!(element, GraphicElement::VectorData(other) if other.point_domain.ids().iter().any(|id| points.contains(id)));
+		*counterpart = (0..after_leaves.len()).find(|&other| !paired[other] && shares_points(after_leaves[other].1));
+		if let Some(other) = *counterpart {
+			paired[other] = true;
+		}
+	}
+
+	let mut unchanged = GraphicGroup::EMPTY;
+	unchanged.alpha_blending.opacity = UNCHANGED_OPACITY;
+	let mut differences = Vec::new();
+	for (leaf, counterpart) in before_leaves.iter().zip(counterparts) {
+		match counterpart.map(|other| after_leaves[other]) {
+			Some(after_leaf) if after_leaf == *leaf => unchanged.push(placed(after_leaf)),
+			Some(after_leaf) => {
+				differences.push(placed(after_leaf));
+				differences.extend(highlighted(after_leaf, CHANGED_COLOR, false));
+			}
+			None => differences.extend(highlighted(*leaf, REMOVED_COLOR, true)),
+		}
+	}
+	let added = after_leaves.iter().zip(&paired).filter(|(_, &paired)| !paired);
+	differences.extend(added.filter_map(|(&leaf, _)| highlighted(leaf, ADDED_COLOR, true)));
+
+	let mut diff = GraphicGroup::EMPTY;
+	if !unchanged.is_empty() {
+		diff.push(unchanged.into());
+	}
+	diff.extend(differences);
+	diff
+}
+
+/// Lists the content of the group, looking inside nested groups and artboards, along with the transform that places each piece in the space of the outermost group.
+fn collect_leaves<'a>(graphic_group: &'a GraphicGroup, transform: DAffine2, leaves: &mut Vec<(DAffine2, &'a GraphicElement)>) {
+	let transform = transform * graphic_group.transform;
+	for element in graphic_group.iter() {
+		match element {
+			GraphicElement::GraphicGroup(graphic_group) => collect_leaves(graphic_group, transform, leaves),
+			GraphicElement::Artboard(artboard) => collect_leaves(&artboard.graphic_group, transform * DAffine2::from_translation(artboard.location.as_dvec2()), leaves),
+			element => leaves.push((transform, element)),
+		}
+	}
+}
+
+/// Puts the content back where it was found, by wrapping it in a group with its transform.
+fn placed((transform, element): (DAffine2, &GraphicElement)) -> GraphicElement {
+	let mut graphic_group = GraphicGroup::EMPTY;
+	graphic_group.transform = transform;
+	graphic_group.push(element.clone());
+	graphic_group.into()
+}
+
+/// Draws the content in the color, as a copy of a vector shape or as the bounding box of anything else, filled faintly or just outlined.
+fn highlighted((transform, element): (DAffine2, &GraphicElement), color: Color, filled: bool) -> Option<GraphicElement> {
+	let mut highlight = match element {
+		GraphicElement::VectorData(vector_data) => {
+			let mut highlight = (**vector_data).clone();
+			highlight.transform = transform * highlight.transform;
+			highlight
+		}
+		element => {
+			let [min, max] = element.bounding_box(transform)?;
+			VectorData::from_subpath(Subpath::new_rect(min, max))
+		}
+	};
+
+	let has_fill = *highlight.style.fill() != Fill::None || !matches!(element, GraphicElement::VectorData(_));
+	highlight
+		.style
+		.set_fill(if filled && has_fill { Fill::Solid(color.with_alpha(HIGHLIGHT_FILL_OPACITY)) } else { Fill::None });
+	highlight.style.set_stroke(Stroke::new(Some(color), HIGHLIGHT_STROKE_WEIGHT));
+	Some(highlight.into())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	use glam::DVec2;
+
+	fn square(corner: DVec2) -> VectorData {
+		let mut square = VectorData::from_subpath(Subpath::new_rect(corner, corner + DVec2::splat(10.)));
+		square.style.set_fill(Fill::Solid(Color::BLACK));
+		square
+	}
+
+	#[test]
+	fn visual_diff_of_edit() {
+		let (kept, moved, removed) = (square(DVec2::ZERO), square(DVec2::new(20., 0.)), square(DVec2::new(40., 0.)));
+		let mut before = GraphicGroup::EMPTY;
+		before.extend([kept.clone().into(), moved.clone().into(), removed.into()]);
+
+		// The moved square keeps its point IDs, and the added square is nested in a group of its own
+		let mut after = GraphicGroup::EMPTY;
+		let mut edited = moved;
+		edited.transform = DAffine2::from_translation(DVec2::new(0., 20.));
+		let mut nested = GraphicGroup::EMPTY;
+		nested.push(square(DVec2::new(60., 0.)).into());
+		after.extend([kept.into(), edited.clone().into(), nested.into()]);
+
+		let diff = visual_diff(&before, &after);
+		let GraphicElement::GraphicGroup(unchanged) = &diff[0] else {
+			panic!("The unchanged content should come first")
+		};
+		assert_eq!(unchanged.len(), 1);
+		assert_eq!(unchanged.alpha_blending.opacity, UNCHANGED_OPACITY);
+
+		let colors = diff[1..]
+			.iter()
+			.filter_map(|element| match element {
+				GraphicElement::VectorData(vector_data) => vector_data.style.stroke().and_then(|stroke| stroke.color()),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(colors, [CHANGED_COLOR, REMOVED_COLOR, ADDED_COLOR]);
+		let GraphicElement::VectorData(changed) = &diff[2] else {
+			panic!("The changed square should be outlined")
+		};
+		assert_eq!(changed.transform, edited.transform);
+		assert_eq!(*changed.style.fill(), Fill::None);
+
+		assert_eq!(visual_diff(&before, &before).len(), 1);
+	}
+}
@@ -0,0 +1,204 @@
+use crate::{GraphicGroup, Node};
+
+use dyn_any::{DynAny, StaticType};
+
+/// The file formats that an [`ExportTarget`] can be written in.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum ExportFileFormat {
+	#[default]
+	Png,
+	Svg,
+	/// Encapsulated PostScript, for print shops and cutting plotters.
+	Eps,
+	/// Enhanced Metafile, for pasting vector artwork into office documents.
+	Emf,
+	/// Portable Document Format, for print workflows that require vector PDF files.
+	Pdf,
+	/// A JSON description of the scene, for external renderers and web viewers.
+	Json,
+}
+
+impl ExportFileFormat {
+	pub const ALL: [Self; 6] = [Self::Png, Self::Svg, Self::Eps, Self::Emf, Self::Pdf, Self::Json];
+
+	/// The file extension, without the leading dot.
+	pub fn extension(self) -> &'static str {
+		match self {
+			Self::Png => "png",
+			Self::Svg => "svg",
+			Self::Eps => "eps",
+			Self::Emf => "emf",
+			Self::Pdf => "pdf",
+			Self::Json => "json",
+		}
+	}
+}
+
+/// One of the files written by an [`ExportSet`], such as the 2x PNG of an icon.
+#[derive(Debug, Clone, PartialEq, DynAny, specta::Type)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExportTarget {
+	/// How many pixels of the file each unit of the document takes up.
+	pub scale: f64,
+	pub format: ExportFileFormat,
+	/// Appended to the name of the export set to name the file, such as "@2x".
+	pub suffix: String,
+}
+
+impl core::hash::Hash for ExportTarget {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.scale.to_bits().hash(state);
+		self.format.hash(state);
+		self.suffix.hash(state);
+	}
+}
+
+impl ExportTarget {
+	/// The name of the file written for this target of the named export set, including its extension.
+	pub fn file_name(&self, name: &str) -> String {
+		format!("{name}{}.{}", self.suffix, self.format.extension())
+	}
+
+	/// Reads a comma separated list of targets, each written as its scale followed by an "x", its format's extension, and optionally its suffix, like `1x png, 2x png @2x, 1x svg`.
+	pub fn parse_list(list: &str) -> Result<Vec<Self>, ExportTargetError> {
+		list.split(',').filter(|entry| !entry.trim().is_empty()).map(str::parse).collect()
+	}
+}
+
+impl core::fmt::Display for ExportTarget {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "{}x {}", self.scale, self.format.extension())?;
+		if !self.suffix.is_empty() {
+			write!(f, " {}", self.suffix)?;
+		}
+		Ok(())
+	}
+}
+
+impl core::str::FromStr for ExportTarget {
+	type Err = ExportTargetError;
+
+	fn from_str(entry: &str) -> Result<Self, Self::Err> {
+		let mut words = entry.split_whitespace();
+		let (Some(scale), Some(format)) = (words.next(), words.next()) else {
+			return Err(ExportTargetError::Incomplete(entry.trim().to_string()));
+		};
+
+		let scale = scale
+			.strip_suffix('x')
+			.unwrap_or(scale)
+			.parse::<f64>()
+			.ok()
+			.filter(|scale| scale.is_finite() && *scale > 0.)
+			.ok_or_else(|| ExportTargetError::InvalidScale(scale.to_string()))?;
+		let format = ExportFileFormat::ALL
+			.into_iter()
+			.find(|candidate| candidate.extension().eq_ignore_ascii_case(format))
+			.ok_or_else(|| ExportTargetError::UnknownFormat(format.to_string()))?;
+		let suffix = words.next().unwrap_or_default().to_string();
+		if let Some(extra) = words.next() {
+			return Err(ExportTargetError::Unexpected(extra.to_string()));
+		}
+
+		Ok(Self { scale, format, suffix })
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportTargetError {
+	/// The entry doesn't have both a scale and a format.
+	Incomplete(String),
+	/// The scale isn't a positive number.
+	InvalidScale(String),
+	UnknownFormat(String),
+	/// The entry continues past its suffix.
+	Unexpected(String),
+}
+
+impl core::fmt::Display for ExportTargetError {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::Incomplete(entry) => write!(f, "the export target \"{entry}\" needs both a scale and a format"),
+			Self::InvalidScale(scale) => write!(f, "\"{scale}\" isn't a valid export scale"),
+			Self::UnknownFormat(format) => write!(f, "\"{format}\" isn't a supported export format"),
+			Self::Unexpected(word) => write!(f, "unexpected \"{word}\" after the suffix of an export target"),
+		}
+	}
+}
+
+impl std::error::Error for ExportTargetError {}
+
+/// A named list of files that the content of a [`GraphicGroup`] is written to, all at once, when exporting it headlessly.
+#[derive(Debug, Clone, Default, PartialEq, Hash, DynAny, specta::Type)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExportSet {
+	/// The name that each target's suffix is appended to, to name its file.
+	pub name: String,
+	pub targets: Vec<ExportTarget>,
+	/// The region that stretches when the PNG targets are written as Android nine-patches, as `[left, top, right, bottom]` insets from the edges of the 1x artwork.
+	pub nine_patch: Option<[u32; 4]>,
+}
+
+impl ExportSet {
+	/// The name of the file written for the target, which ends in ".9.png" for PNG targets written as nine-patches, as Android requires.
+	pub fn file_name(&self, target: &ExportTarget) -> String {
+		match (self.nine_patch, target.format) {
+			(Some(_), ExportFileFormat::Png) => format!("{}{}.9.png", self.name, target.suffix),
+			_ => target.file_name(&self.name),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExportSetNode<Name, Targets> {
+	name: Name,
+	targets: Targets,
+}
+
+/// Declares the files that the content is exported to, such as the 1x, 2x, and 3x PNGs and the SVG of an icon, from a list of targets as read by [`ExportTarget::parse_list`].
+///
+/// Entries of the list which can't be read are left out, and an empty list removes any export set the content already had.
+/// The nine-patch region of an export set the content already had is kept.
+#[node_macro::node_fn(ExportSetNode)]
+fn export_set<Data: Into<GraphicGroup>>(data: Data, name: String, targets: String) -> GraphicGroup {
+	let mut graphic_group = data.into();
+	let targets = targets.split(',').filter_map(|entry| entry.parse().ok()).collect::<Vec<_>>();
+	let nine_patch = graphic_group.export_set.as_ref().and_then(|export_set| export_set.nine_patch);
+	graphic_group.export_set = (!targets.is_empty()).then_some(ExportSet { name, targets, nine_patch });
+	graphic_group
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+	use crate::vector::VectorData;
+
+	#[test]
+	fn export_target_list() {
+		let targets = ExportTarget::parse_list("1x png, 2x png @2x, 3 PNG @3x, 1x svg").unwrap();
+		assert_eq!(targets.len(), 4);
+		assert_eq!(targets[1].file_name("icon"), "icon@2x.png");
+		assert_eq!(targets[2].scale, 3.);
+		assert_eq!(targets[3].file_name("icon"), "icon.svg");
+		let written = targets.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+		assert_eq!(ExportTarget::parse_list(&written).unwrap(), targets);
+
+		assert_eq!(ExportTarget::parse_list("2x"), Err(ExportTargetError::Incomplete("2x".to_string())));
+		assert_eq!(ExportTarget::parse_list("0x png"), Err(ExportTargetError::InvalidScale("0x".to_string())));
+		assert_eq!(ExportTarget::parse_list("1x gif"), Err(ExportTargetError::UnknownFormat("gif".to_string())));
+
+		// The node leaves out the entries it can't read
+		let node = ExportSetNode {
+			name: ClonedNode("icon".to_string()),
+			targets: ClonedNode("1x png, 1x gif, 2x svg @2x".to_string()),
+		};
+		let export_set = node.eval(VectorData::empty()).export_set.unwrap();
+		assert_eq!(export_set.name, "icon");
+		assert_eq!(
+			export_set.targets.iter().map(|target| target.format).collect::<Vec<_>>(),
+			[ExportFileFormat::Png, ExportFileFormat::Svg]
+		);
+	}
+}
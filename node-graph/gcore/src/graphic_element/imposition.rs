@@ -0,0 +1,157 @@
+use crate::vector::style::Stroke;
+use crate::vector::VectorData;
+use crate::{Artboard, Color, GraphicGroup, Node};
+
+use bezier_rs::Subpath;
+use glam::{DAffine2, DVec2, IVec2};
+
+/// Distance between the trim line of the outermost cells and the start of each cut mark.
+const CUT_MARK_OFFSET: f64 = 3.;
+const CUT_MARK_WEIGHT: f64 = 0.5;
+
+/// Tiles the contents of an artboard n-up onto a larger print sheet, each copy clipped to its cell, separated by gutters and optionally surrounded by cut marks.
+#[derive(Debug, Clone, Copy)]
+pub struct ImposeNode<SheetDimensions, Gutter, Margin, BackSide, CutMarks, CutMarkLength> {
+	sheet_dimensions: SheetDimensions,
+	gutter: Gutter,
+	margin: Margin,
+	back_side: BackSide,
+	cut_marks: CutMarks,
+	cut_mark_length: CutMarkLength,
+}
+
+#[node_macro::node_fn(ImposeNode)]
+fn impose(artboard: Artboard, sheet_dimensions: IVec2, gutter: DVec2, margin: f64, back_side: bool, cut_marks: bool, cut_mark_length: f64) -> Artboard {
+	let mut sheet = Artboard::new(artboard.location, sheet_dimensions);
+	sheet.background = artboard.background;
+	sheet.clip = true;
+
+	let sheet_size = sheet.dimensions.as_dvec2();
+	let cell_size = artboard.dimensions.as_dvec2();
+	let cells = impose_cells(cell_size, sheet_size, gutter, margin, back_side);
+
+	// Each copy is clipped to its cell like the artboard clips its contents, so artwork spilling past the edges doesn't run into the neighboring cells
+	let cell_clip = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, cell_size));
+	for &cell in &cells {
+		let mut graphic_group = GraphicGroup::EMPTY;
+		graphic_group.push(artboard.graphic_group.clone().into());
+		graphic_group.transform = DAffine2::from_translation(cell);
+		graphic_group.mask = Some(Box::new(cell_clip.clone().into()));
+		sheet.graphic_group.push(graphic_group.into());
+	}
+
+	if cut_marks && !cells.is_empty() {
+		let marks = cut_mark_lines(&cells, artboard.dimensions.as_dvec2(), cut_mark_length);
+		let mut vector_data = VectorData::from_subpaths(marks.into_iter().map(|[start, end]| Subpath::new_line(start, end)));
+		vector_data.style.set_stroke(Stroke::new(Some(Color::BLACK), CUT_MARK_WEIGHT));
+		sheet.graphic_group.push(vector_data.into());
+	}
+
+	sheet
+}
+
+/// Computes the top left corner of every cell that fits onto the sheet, in reading order.
+///
+/// The back side of a duplex sheet is mirrored horizontally (and its cells listed right to left) so that each cell lines up with its front side counterpart when the sheet is flipped along its long edge.
+pub fn impose_cells(cell_size: DVec2, sheet_size: DVec2, gutter: DVec2, margin: f64, back_side: bool) -> Vec<DVec2> {
+	if cell_size.x <= 0. || cell_size.y <= 0. {
+		return Vec::new();
+	}
+
+	let gutter = gutter.max(DVec2::ZERO);
+	let available = sheet_size - DVec2::splat(2. * margin.max(0.));
+	let count = ((available + gutter) / (cell_size + gutter)).floor().max(DVec2::ZERO);
+	let (columns, rows) = (count.x as usize, count.y as usize);
+
+	let mut cells = Vec::with_capacity(columns * rows);
+	for row in 0..rows {
+		for column in 0..columns {
+			let mut position = DVec2::splat(margin.max(0.)) + DVec2::new(column as f64, row as f64) * (cell_size + gutter);
+			if back_side {
+				position.x = sheet_size.x - position.x - cell_size.x;
+			}
+			cells.push(position);
+		}
+	}
+
+	cells
+}
+
+/// Generates the line segments for crop marks outside of the grid of cells, one pair of marks per trim line.
+fn cut_mark_lines(cells: &[DVec2], cell_size: DVec2, length: f64) -> Vec<[DVec2; 2]> {
+	let mut xs = cells.iter().flat_map(|cell| [cell.x, cell.x + cell_size.x]).collect::<Vec<_>>();
+	let mut ys = cells.iter().flat_map(|cell| [cell.y, cell.y + cell_size.y]).collect::<Vec<_>>();
+	for values in [&mut xs, &mut ys] {
+		values.sort_by(f64::total_cmp);
+		values.dedup_by(|a, b| (*a - *b).abs() < 1e-6);
+	}
+
+	let (Some(&left), Some(&right), Some(&top), Some(&bottom)) = (xs.first(), xs.last(), ys.first(), ys.last()) else {
+		return Vec::new();
+	};
+
+	let mut lines = Vec::with_capacity(2 * (xs.len() + ys.len()));
+	for &x in &xs {
+		lines.push([DVec2::new(x, top - CUT_MARK_OFFSET - length), DVec2::new(x, top - CUT_MARK_OFFSET)]);
+		lines.push([DVec2::new(x, bottom + CUT_MARK_OFFSET), DVec2::new(x, bottom + CUT_MARK_OFFSET + length)]);
+	}
+	for &y in &ys {
+		lines.push([DVec2::new(left - CUT_MARK_OFFSET - length, y), DVec2::new(left - CUT_MARK_OFFSET, y)]);
+		lines.push([DVec2::new(right + CUT_MARK_OFFSET, y), DVec2::new(right + CUT_MARK_OFFSET + length, y)]);
+	}
+
+	lines
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+	use crate::GraphicElement;
+
+	#[test]
+	fn cells_fit_on_sheet() {
+		let cells = impose_cells(DVec2::new(100., 50.), DVec2::new(420., 300.), DVec2::splat(10.), 20., false);
+		// (380 + 10) / 110 = 3 columns, (260 + 10) / 60 = 4 rows
+		assert_eq!(cells.len(), 12);
+		assert_eq!(cells[0], DVec2::splat(20.));
+		assert_eq!(cells[1], DVec2::new(130., 20.));
+		assert_eq!(cells[3], DVec2::new(20., 80.));
+	}
+
+	#[test]
+	fn back_side_is_mirrored() {
+		let front = impose_cells(DVec2::new(100., 50.), DVec2::new(420., 300.), DVec2::splat(10.), 20., false);
+		let back = impose_cells(DVec2::new(100., 50.), DVec2::new(420., 300.), DVec2::splat(10.), 20., true);
+		assert_eq!(front.len(), back.len());
+		for (front, back) in front.iter().zip(&back) {
+			assert_eq!(front.y, back.y);
+			assert_eq!(back.x, 420. - front.x - 100.);
+		}
+	}
+
+	#[test]
+	fn impose_artboard() {
+		let mut artboard = Artboard::new(IVec2::ZERO, IVec2::new(100, 100));
+		artboard.graphic_group.push(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)).into());
+		let sheet = ImposeNode {
+			sheet_dimensions: ClonedNode(IVec2::new(200, 200)),
+			gutter: ClonedNode(DVec2::ZERO),
+			margin: ClonedNode(0.),
+			back_side: ClonedNode(false),
+			cut_marks: ClonedNode(true),
+			cut_mark_length: ClonedNode(5.),
+		}
+		.eval(artboard);
+		// Four copies plus the cut marks
+		assert_eq!(sheet.graphic_group.len(), 5);
+		assert_eq!(sheet.dimensions, IVec2::new(200, 200));
+
+		// Each copy is clipped to its own cell
+		for (element, cell) in sheet.graphic_group.iter().zip([DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(0., 100.), DVec2::splat(100.)]) {
+			let GraphicElement::GraphicGroup(copy) = element else { panic!("Each copy should be a group") };
+			let clip = copy.mask.as_ref().and_then(|mask| mask.bounding_box(copy.transform));
+			assert_eq!(clip, Some([cell, cell + DVec2::splat(100.)]));
+		}
+	}
+}
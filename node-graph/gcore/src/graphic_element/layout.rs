@@ -0,0 +1,178 @@
+use crate::renderer::GraphicElementRendered;
+use crate::transform::TransformMut;
+use crate::{GraphicElement, GraphicGroup, Node};
+
+use dyn_any::{DynAny, StaticType};
+use glam::{DAffine2, DVec2};
+
+/// Where [`AlignDistributeNode`] lines up the left and right sides of the elements.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum HorizontalAlignment {
+	/// The elements keep their horizontal positions
+	#[default]
+	None,
+	Left,
+	Center,
+	Right,
+}
+
+/// Where [`AlignDistributeNode`] lines up the tops and bottoms of the elements.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum VerticalAlignment {
+	/// The elements keep their vertical positions
+	#[default]
+	None,
+	Top,
+	Middle,
+	Bottom,
+}
+
+/// The direction in which [`AlignDistributeNode`] spreads out the elements with even gaps between them.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum Distribution {
+	/// The elements aren't spread out
+	#[default]
+	None,
+	Horizontal,
+	Vertical,
+}
+
+/// Lines up the elements of the group with the edges or center of their combined bounds, and spreads them out so the gaps between them are even.
+#[derive(Debug, Clone, Copy)]
+pub struct AlignDistributeNode<Horizontal, Vertical, Distribute> {
+	horizontal: Horizontal,
+	vertical: Vertical,
+	distribute: Distribute,
+}
+
+/// Each element is moved by the bounding box of its content, and elements without bounds (or which can't be moved, like text and artboards) are left where they are.
+///
+/// Distributing keeps the outermost elements in place and moves those between them, in order of their positions, to make the gaps equal.
+/// Distributing along an axis takes the place of aligning along it.
+#[node_macro::node_fn(AlignDistributeNode)]
+fn align_distribute(mut graphic_group: GraphicGroup, horizontal: HorizontalAlignment, vertical: VerticalAlignment, distribute: Distribution) -> GraphicGroup {
+	let bounds = graphic_group
+		.iter()
+		.map(|element| match element {
+			GraphicElement::Text(_) | GraphicElement::Artboard(_) => None,
+			element => element.bounding_box(DAffine2::IDENTITY),
+		})
+		.collect::<Vec<_>>();
+	let Some([min, max]) = bounds.iter().flatten().copied().reduce(|[a_min, a_max], [b_min, b_max]| [a_min.min(b_min), a_max.max(b_max)]) else {
+		return graphic_group;
+	};
+
+	// The fraction of the way across each element and across the combined bounds that are lined up, for each axis
+	let horizontal = match horizontal {
+		HorizontalAlignment::None => None,
+		HorizontalAlignment::Left => Some(0.),
+		HorizontalAlignment::Center => Some(0.5),
+		HorizontalAlignment::Right => Some(1.),
+	};
+	let vertical = match vertical {
+		VerticalAlignment::None => None,
+		VerticalAlignment::Top => Some(0.),
+		VerticalAlignment::Middle => Some(0.5),
+		VerticalAlignment::Bottom => Some(1.),
+	};
+	let alignment = [horizontal, vertical];
+
+	let mut offsets = vec![DVec2::ZERO; bounds.len()];
+	for axis in 0..2 {
+		let distributed = match distribute {
+			Distribution::None => false,
+			Distribution::Horizontal => axis == 0,
+			Distribution::Vertical => axis == 1,
+		};
+
+		if distributed {
+			for (index, offset) in distribution_offsets(&bounds, axis) {
+				offsets[index][axis] = offset;
+			}
+		} else if let Some(fraction) = alignment[axis] {
+			let target = min[axis] + (max[axis] - min[axis]) * fraction;
+			for (offset, &[element_min, element_max]) in offsets.iter_mut().zip(&bounds).filter_map(|(offset, bounds)| Some((offset, bounds.as_ref()?))) {
+				offset[axis] = target - (element_min[axis] + (element_max[axis] - element_min[axis]) * fraction);
+			}
+		}
+	}
+
+	// The bounds are in the space of the group, which is also the space that each element's transform places it in
+	for (element, offset) in graphic_group.iter_mut().zip(offsets) {
+		if offset != DVec2::ZERO {
+			element.translate(offset);
+		}
+	}
+
+	graphic_group
+}
+
+/// Finds how far each element with bounds needs to move along the axis so the gaps between them are all equal, keeping the first and last in place.
+fn distribution_offsets(bounds: &[Option<[DVec2; 2]>], axis: usize) -> Vec<(usize, f64)> {
+	let mut order = bounds.iter().enumerate().filter_map(|(index, bounds)| Some((index, (*bounds)?))).collect::<Vec<_>>();
+	if order.len() < 3 {
+		return Vec::new();
+	}
+	order.sort_by(|(_, [a_min, a_max]), (_, [b_min, b_max])| (a_min[axis] + a_max[axis]).total_cmp(&(b_min[axis] + b_max[axis])));
+
+	let start = order.iter().map(|(_, [min, _])| min[axis]).fold(f64::INFINITY, f64::min);
+	let end = order.iter().map(|(_, [_, max])| max[axis]).fold(f64::NEG_INFINITY, f64::max);
+	let total_size = order.iter().map(|(_, [min, max])| max[axis] - min[axis]).sum::<f64>();
+	let gap = (end - start - total_size) / (order.len() - 1) as f64;
+
+	let mut position = start;
+	order
+		.into_iter()
+		.map(|(index, [min, max])| {
+			let offset = position - min[axis];
+			position += max[axis] - min[axis] + gap;
+			(index, offset)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+	use crate::vector::VectorData;
+
+	use bezier_rs::Subpath;
+
+	fn boxes(graphic_group: &GraphicGroup) -> Vec<[DVec2; 2]> {
+		graphic_group.iter().filter_map(|element| element.bounding_box(DAffine2::IDENTITY)).collect()
+	}
+
+	#[test]
+	fn align_and_distribute() {
+		let mut graphic_group = GraphicGroup::EMPTY;
+		for (corner, size) in [
+			(DVec2::new(0., 10.), DVec2::new(10., 10.)),
+			(DVec2::new(50., 0.), DVec2::new(20., 40.)),
+			(DVec2::new(15., 5.), DVec2::new(5., 20.)),
+		] {
+			graphic_group.push(VectorData::from_subpath(Subpath::new_rect(corner, corner + size)).into());
+		}
+		let arrange = |horizontal, vertical, distribute| {
+			AlignDistributeNode {
+				horizontal: ClonedNode(horizontal),
+				vertical: ClonedNode(vertical),
+				distribute: ClonedNode(distribute),
+			}
+			.eval(graphic_group.clone())
+		};
+
+		let aligned = boxes(&arrange(HorizontalAlignment::Right, VerticalAlignment::Middle, Distribution::None));
+		assert!(aligned.iter().all(|[_, max]| max.x == 70.));
+		assert!(aligned.iter().all(|[min, max]| min.y + max.y == 40.));
+
+		// The left and right elements stay put, and the middle one is moved to leave 17.5 on either side
+		let distributed = boxes(&arrange(HorizontalAlignment::Left, VerticalAlignment::Top, Distribution::Horizontal));
+		assert_eq!(distributed[0], [DVec2::new(0., 0.), DVec2::new(10., 10.)]);
+		assert_eq!(distributed[1], [DVec2::new(50., 0.), DVec2::new(70., 40.)]);
+		assert_eq!(distributed[2], [DVec2::new(27.5, 0.), DVec2::new(32.5, 20.)]);
+	}
+}
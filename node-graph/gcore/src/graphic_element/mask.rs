@@ -0,0 +1,97 @@
+use crate::raster::{ImageFrame, Sample};
+use crate::renderer::Quad;
+use crate::vector::style::FillRule;
+use crate::vector::{PointId, VectorData};
+use crate::{Color, GraphicGroup, Node};
+
+use bezier_rs::Subpath;
+use dyn_any::{DynAny, StaticType};
+use glam::{DAffine2, DVec2};
+
+/// What a [`GraphicGroup`] is masked by, placed in the same space as the group's elements.
+#[derive(Clone, Debug, Hash, PartialEq, DynAny)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mask {
+	/// Keeps only what's inside the shape's path, by its fill rule, whether or not the shape is filled.
+	Clip(VectorData),
+	/// Keeps what's over the image in proportion to its luminance and alpha, so white shows the content, black hides it, and grays fade it.
+	/// Nothing outside of the image is kept.
+	Luminance(ImageFrame<Color>),
+}
+
+impl From<VectorData> for Mask {
+	fn from(vector_data: VectorData) -> Self {
+		Self::Clip(vector_data)
+	}
+}
+impl From<ImageFrame<Color>> for Mask {
+	fn from(image_frame: ImageFrame<Color>) -> Self {
+		Self::Luminance(image_frame)
+	}
+}
+
+impl Mask {
+	/// The area that renderers without soft masks clip to, as subpaths placed by the transform and filled by the fill rule.
+	/// That's the path of a shape, or the rectangle of an image, whose luminance can't be followed.
+	pub fn clip_area(&self) -> (Vec<Subpath<PointId>>, DAffine2, FillRule) {
+		match self {
+			Self::Clip(vector_data) => {
+				// Each subpath is taken once, as one taken twice would cancel itself out under the even-odd rule
+				(vector_data.stroke_bezier_paths().collect(), vector_data.transform, vector_data.style.fill_rule())
+			}
+			Self::Luminance(image_frame) if image_frame.image.data.is_empty() => (Vec::new(), image_frame.transform, FillRule::NonZero),
+			Self::Luminance(image_frame) => (vec![Subpath::new_rect(DVec2::ZERO, DVec2::ONE)], image_frame.transform, FillRule::NonZero),
+		}
+	}
+
+	/// The bounds of what the mask can keep, placed by the transform, or none if it keeps nothing.
+	pub fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]> {
+		let (subpaths, area_transform, _) = self.clip_area();
+		subpaths
+			.iter()
+			.filter_map(|subpath| subpath.bounding_box_with_transform(transform * area_transform))
+			.reduce(Quad::combine_bounds)
+	}
+
+	/// Whether any of the content at the point, in the space the mask is placed in, is kept.
+	pub fn keeps(&self, point: DVec2) -> bool {
+		match self {
+			Self::Clip(_) => {
+				let (subpaths, transform, fill_rule) = self.clip_area();
+				if transform.matrix2.determinant() == 0. {
+					return false;
+				}
+				let point = transform.inverse().transform_point2(point);
+				let winding = subpaths
+					.into_iter()
+					.map(|mut region| {
+						region.set_closed(true);
+						region.winding_order(point)
+					})
+					.sum::<i32>();
+				match fill_rule {
+					FillRule::NonZero => winding != 0,
+					FillRule::EvenOdd => winding % 2 != 0,
+				}
+			}
+			// The pixels are premultiplied, so their luminance is already scaled by their alpha
+			Self::Luminance(image_frame) => Sample::sample(image_frame, point, DVec2::ONE).is_some_and(|pixel: Color| pixel.luminance_srgb() > 0.),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClipNode<MaskInput> {
+	mask: MaskInput,
+}
+
+/// Masks the content so only what's within the mask is kept. A shape keeps what's inside its path, while an image is used as a grayscale mask, keeping what's over it in proportion to its luminance.
+///
+/// The mask is placed in the same space as the content, and any mask the content already has is kept as well.
+#[node_macro::node_fn(ClipNode)]
+fn clip<Data: Into<GraphicGroup>, MaskInput: Into<Mask>>(content: Data, mask: MaskInput) -> GraphicGroup {
+	let mut clipped = GraphicGroup::EMPTY;
+	clipped.push(content.into().into());
+	clipped.mask = Some(Box::new(mask.into()));
+	clipped
+}
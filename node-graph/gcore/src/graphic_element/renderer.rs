@@ -1,16 +1,35 @@
+mod emf;
+mod eps;
+mod hit_test;
+mod pdf;
 mod quad;
+mod scene;
+#[cfg(feature = "vello")]
+mod vello;
 
+#[cfg(feature = "vello")]
+pub use self::vello::VelloRender;
+use crate::mask::Mask;
 use crate::raster::{BlendMode, Image, ImageFrame};
 use crate::transform::Transform;
 use crate::uuid::generate_uuid;
+use crate::vector::style::{Fill, Paint, Stroke, StrokeAlignment, ViewMode};
 use crate::vector::PointId;
-use crate::{vector::VectorData, Artboard, Color, GraphicElement, GraphicGroup};
+use crate::{vector::VectorData, vector::VectorInstances, AlphaBlending, Artboard, Color, GraphicElement, GraphicGroup, Node};
+pub use emf::EmfRender;
+pub use eps::EpsRender;
+use hit_test::HitShape;
+pub use pdf::PdfRender;
 pub use quad::Quad;
+pub use scene::{Scene, SceneElement, SceneGradientStop, SceneMask, ScenePaint, SceneStroke, SCENE_SCHEMA_VERSION};
 
 use bezier_rs::Subpath;
 
 use base64::Engine;
+use dyn_any::{DynAny, StaticType};
 use glam::{DAffine2, DVec2};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 /// Represents a clickable target for the layer
 #[derive(Clone, Debug)]
@@ -65,6 +84,8 @@ pub struct SvgRender {
 	pub svg_defs: String,
 	pub transform: DAffine2,
 	pub image_data: Vec<(u64, Image<Color>)>,
+	/// Bake the transforms of groups and artboards into the coordinates of what's inside them, through `transform`, instead of writing them as `transform` attributes
+	pub flatten_transforms: bool,
 	indent: usize,
 }
 
@@ -75,6 +96,7 @@ impl SvgRender {
 			svg_defs: String::new(),
 			transform: DAffine2::IDENTITY,
 			image_data: Vec::new(),
+			flatten_transforms: false,
 			indent: 0,
 		}
 	}
@@ -154,11 +176,13 @@ impl Default for SvgRender {
 	}
 }
 
+#[derive(Clone, Copy, Debug)]
 pub enum ImageRenderMode {
 	Base64,
 }
 
 /// Static state used whilst rendering
+#[derive(Clone)]
 pub struct RenderParams {
 	pub view_mode: crate::vector::style::ViewMode,
 	pub image_render_mode: ImageRenderMode,
@@ -168,6 +192,12 @@ pub struct RenderParams {
 	pub hide_artboards: bool,
 	/// Are we exporting? Causes the text above an artboard to be hidden.
 	pub for_export: bool,
+	/// Only render the contents of groups tagged with this print channel, with everything else knocking out what it covers in white, the color of unprinted paper.
+	pub channel: Option<String>,
+	/// The size of a pixel on screen in the space of the culling bounds, which is how far a hairline stroke reaches past its path for each pixel of its weight.
+	pub culling_pixel_size: f64,
+	/// The extents of the shapes measured for culling so far in this render, shared with the parameters for the content placed within it.
+	painted_extents: PaintedExtentCache,
 }
 
 impl RenderParams {
@@ -179,8 +209,133 @@ impl RenderParams {
 			thumbnail,
 			hide_artboards,
 			for_export,
+			channel: None,
+			culling_pixel_size: 1.,
+			painted_extents: PaintedExtentCache::default(),
 		}
 	}
+
+	/// The parameters for rendering content placed by the transform, with the culling bounds brought into the content's own space.
+	/// Nothing is culled within content that the transform squashes flat.
+	pub fn placed_by(&self, transform: DAffine2) -> Self {
+		let culling_bounds = self
+			.culling_bounds
+			.filter(|_| transform.matrix2.determinant() != 0.)
+			.map(|bounds| (transform.inverse() * Quad::from_box(bounds)).bounding_box());
+		// A pixel is at most this long in the content's space, in whichever direction the transform squashes the most
+		let culling_pixel_size = match min_scale(transform) {
+			scale if scale > 0. => self.culling_pixel_size / scale,
+			_ => self.culling_pixel_size,
+		};
+		Self {
+			culling_bounds,
+			culling_pixel_size,
+			..self.clone()
+		}
+	}
+
+	/// The parameters for drawing the knockout of content outside of the channel being rendered, which is no longer split up by channel.
+	fn knockout(&self) -> Self {
+		Self { channel: None, ..self.clone() }
+	}
+
+	/// Whether the element, in the space it's placed in, paints nothing within the culling bounds, so rendering it can be skipped.
+	pub fn culls(&self, element: &GraphicElement) -> bool {
+		self.culling_bounds.is_some_and(|bounds| self.paints_outside(element, DAffine2::IDENTITY, bounds))
+	}
+
+	/// Whether everything the element paints, placed by the transform, lies outside of the bounds.
+	/// Artboards never do, since their labels are drawn above them, but the elements inside them are culled on their own.
+	fn paints_outside(&self, element: &GraphicElement, transform: DAffine2, bounds: [DVec2; 2]) -> bool {
+		let outside = |painted: Option<[DVec2; 2]>| painted.map_or(true, |[min, max]| min.cmpgt(bounds[1]).any() || max.cmplt(bounds[0]).any());
+		match element {
+			GraphicElement::VectorData(vector_data) => outside(self.painted_bounds(vector_data, transform)),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances
+				.instances
+				.iter()
+				.all(|&instance| outside(self.painted_bounds(&vector_instances.geometry, transform * vector_instances.transform * instance))),
+			GraphicElement::ImageFrame(image_frame) => outside(Some((transform * image_frame.transform * Quad::from_box([DVec2::ZERO, DVec2::ONE])).bounding_box())),
+			GraphicElement::Text(text) => outside(Some((transform * Quad::from_box(text_bounds(text))).bounding_box())),
+			GraphicElement::GraphicGroup(graphic_group) => {
+				let transform = transform * graphic_group.transform;
+				graphic_group.mask.as_ref().is_some_and(|mask| outside(mask.bounding_box(transform))) || graphic_group.iter().all(|element| self.paints_outside(element, transform, bounds))
+			}
+			GraphicElement::Artboard(_) => false,
+		}
+	}
+
+	/// The bounds of the shape placed by the transform, grown by as far as its strokes can reach past its path.
+	fn painted_bounds(&self, vector_data: &VectorData, transform: DAffine2) -> Option<[DVec2; 2]> {
+		let extent = self.painted_extents.get(vector_data)?;
+		let margin = DVec2::splat(extent.reach);
+		let [min, max] = (transform * vector_data.transform * Quad::from_box([extent.bounds[0] - margin, extent.bounds[1] + margin])).bounding_box();
+		// Hairlines keep their weight on screen, so they reach past the transformed path rather than being scaled along with it
+		let margin = DVec2::splat(extent.hairline_reach * self.culling_pixel_size);
+		Some([min - margin, max + margin])
+	}
+}
+
+/// How far a shape paints, in its own space before its transform.
+#[derive(Clone, Copy, Debug)]
+struct PaintedExtent {
+	/// The bounds of its path
+	bounds: [DVec2; 2],
+	/// How far its strokes can reach past its path, out to their miters
+	reach: f64,
+	/// How far its hairline strokes can reach past its path, in pixels on screen
+	hairline_reach: f64,
+}
+
+impl PaintedExtent {
+	/// Measures the shape, with the widest stroke of the style of any of its subpaths and of their paint layers.
+	fn new(vector_data: &VectorData) -> Option<Self> {
+		let bounds = vector_data.bounding_box()?;
+		let styles = core::iter::once(&vector_data.style).chain(vector_data.subpath_styles.iter().map(|(_, style)| style));
+		let strokes = styles.flat_map(|style| {
+			let layer_strokes = style.paint_layers().iter().filter_map(|layer| match &layer.paint {
+				Paint::Stroke(stroke) => Some(stroke.clone()),
+				Paint::Fill(_) => None,
+			});
+			style.stroke().into_iter().chain(layer_strokes)
+		});
+
+		let (mut reach, mut hairline_reach) = (0., 0.);
+		for stroke in strokes {
+			let weight = stroke.width_profile.iter().map(|&(_, width)| stroke.weight * width.max(0.)).reduce(f64::max).unwrap_or(stroke.weight);
+			let stroke_reach = weight * stroke.line_join_miter_limit.max(1.);
+			let widest = if stroke.hairline { &mut hairline_reach } else { &mut reach };
+			*widest = f64::max(*widest, stroke_reach);
+		}
+		Some(Self { bounds, reach, hairline_reach })
+	}
+}
+
+/// The extents of the shapes measured for culling, kept for the rest of the render so the shapes in nested groups and the geometry shared by instances are only measured once.
+///
+/// Shapes are told apart by their address, which stays the same while the content being rendered is borrowed, along with their number of points and first point,
+/// so a temporary shape made during the render isn't mistaken for an earlier one that was dropped from the same address.
+#[derive(Clone, Debug, Default)]
+struct PaintedExtentCache(Arc<Mutex<HashMap<(usize, usize, Option<PointId>), Option<PaintedExtent>>>>);
+
+impl PaintedExtentCache {
+	fn get(&self, vector_data: &VectorData) -> Option<PaintedExtent> {
+		let points = vector_data.point_domain.ids();
+		let key = (vector_data as *const VectorData as usize, points.len(), points.first().copied());
+		match self.0.lock() {
+			Ok(mut extents) => *extents.entry(key).or_insert_with(|| PaintedExtent::new(vector_data)),
+			Err(_) => PaintedExtent::new(vector_data),
+		}
+	}
+}
+
+/// The smallest factor that the transform scales a length by in any direction.
+pub fn min_scale(transform: DAffine2) -> f64 {
+	let [a, b, c, d] = transform.matrix2.to_cols_array();
+	let sum_of_squares = a * a + b * b + c * c + d * d;
+	let determinant = transform.matrix2.determinant();
+	// The smaller singular value of the matrix
+	let spread = (sum_of_squares * sum_of_squares - 4. * determinant * determinant).max(0.).sqrt();
+	((sum_of_squares - spread) / 2.).max(0.).sqrt()
 }
 
 pub fn format_transform_matrix(transform: DAffine2) -> String {
@@ -202,10 +357,52 @@ fn to_transform(transform: DAffine2) -> usvg::Transform {
 	usvg::Transform::from_row(cols[0] as f32, cols[1] as f32, cols[2] as f32, cols[3] as f32, cols[4] as f32, cols[5] as f32)
 }
 
+/// A separately composited layer of rendered content, as produced by [`GraphicElementRendered::render_svg_layers`].
+pub struct RenderedLayer {
+	pub name: String,
+	pub render: SvgRender,
+	/// The opacity and blend mode used to composite this layer onto the layers beneath it, which are not included in its render.
+	pub alpha_blending: AlphaBlending,
+}
+
 pub trait GraphicElementRendered {
 	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams);
+	/// Renders the content as a stack of layers, ordered from bottom to top, for export to layered file formats.
+	///
+	/// By default, everything is rendered into a single layer.
+	fn render_svg_layers(&self, render_params: &RenderParams) -> Vec<RenderedLayer> {
+		let mut render = SvgRender::new();
+		self.render_svg(&mut render, render_params);
+		vec![RenderedLayer {
+			name: "Layer 1".to_string(),
+			render,
+			alpha_blending: AlphaBlending::default(),
+		}]
+	}
+	/// Renders the content as Encapsulated PostScript. Content that has no PostScript equivalent renders nothing.
+	fn render_eps(&self, _render: &mut EpsRender, _render_params: &RenderParams) {}
+	/// Renders the content as an Enhanced Metafile. Content that has no metafile equivalent renders nothing.
+	fn render_emf(&self, _render: &mut EmfRender, _render_params: &RenderParams) {}
+	/// Renders the content as a page of a PDF document. Content that has no PDF equivalent renders nothing.
+	fn render_pdf(&self, _render: &mut PdfRender, _render_params: &RenderParams) {}
+	/// Describes the content as elements of a [`Scene`]. Content that has no scene equivalent describes nothing.
+	fn render_scene(&self, _render_params: &RenderParams) -> Vec<SceneElement> {
+		Vec::new()
+	}
+	/// Encodes the content into a vello scene for rendering on the GPU. Content that has no vello equivalent renders nothing.
+	#[cfg(feature = "vello")]
+	fn render_vello(&self, _render: &mut VelloRender, _render_params: &RenderParams) {}
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]>;
 	fn add_click_targets(&self, click_targets: &mut Vec<ClickTarget>);
+	/// Whether the point, in the space the content is placed in, lands on what's painted: inside a fill, or on a stroke within its width.
+	/// Content that can't be hit-tested is never hit.
+	fn contains_point(&self, _point: DVec2) -> bool {
+		false
+	}
+	/// Whether anything painted overlaps the quad, in the space the content is placed in, such as a selection box dragged across the document.
+	fn intersects_quad(&self, _quad: Quad) -> bool {
+		false
+	}
 	fn to_usvg_node(&self) -> usvg::Node {
 		let mut render = SvgRender::new();
 		let render_params = RenderParams::new(crate::vector::style::ViewMode::Normal, ImageRenderMode::Base64, None, false, false, false);
@@ -239,12 +436,68 @@ pub trait GraphicElementRendered {
 	}
 }
 
+impl GraphicGroup {
+	/// The parameters for rendering the group's elements. Everything inside a group tagged with the channel being rendered is part of that channel's separation.
+	fn content_render_params(&self, render_params: &RenderParams) -> RenderParams {
+		let mut content_params = render_params.placed_by(self.transform);
+		if render_params.channel.is_some() && self.channel == render_params.channel {
+			content_params.channel = None;
+		}
+		content_params
+	}
+}
+
+impl VectorData {
+	/// The shape painted in white, hiding what it covers in the channel being rendered without adding anything of its own.
+	fn knockout(&self) -> Self {
+		Self {
+			style: self.style.knockout(),
+			alpha_blending: AlphaBlending {
+				blend_mode: BlendMode::default(),
+				..self.alpha_blending
+			},
+			subpath_styles: self.subpath_styles.iter().map(|(index, style)| (*index, style.knockout())).collect(),
+			..self.clone()
+		}
+	}
+}
+
+impl ImageFrame<Color> {
+	/// The image painted in white wherever it isn't transparent, hiding what it covers in the channel being rendered without adding anything of its own.
+	fn knockout(&self) -> Self {
+		let image = Image {
+			width: self.image.width,
+			height: self.image.height,
+			// The pixels are premultiplied, so white is scaled by the alpha of each one
+			data: self.image.data.iter().map(|pixel| Color::from_unassociated_alpha(1., 1., 1., pixel.a())).collect(),
+			base64_string: None,
+		};
+		Self {
+			image,
+			transform: self.transform,
+			alpha_blending: AlphaBlending {
+				blend_mode: BlendMode::default(),
+				..self.alpha_blending
+			},
+		}
+	}
+}
+
 impl GraphicElementRendered for GraphicGroup {
 	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams) {
+		// References are only shown while working
+		if self.reference && render_params.for_export {
+			return;
+		}
+		let render_params = &self.content_render_params(render_params);
+
+		let flatten_transforms = render.flatten_transforms;
 		render.parent_tag(
 			"g",
 			|attributes| {
-				attributes.push("transform", format_transform_matrix(self.transform));
+				if !flatten_transforms {
+					attributes.push("transform", format_transform_matrix(self.transform));
+				}
 
 				if self.alpha_blending.opacity < 1. {
 					attributes.push("opacity", self.alpha_blending.opacity.to_string());
@@ -253,17 +506,183 @@ impl GraphicElementRendered for GraphicGroup {
 				if self.alpha_blending.blend_mode != BlendMode::default() {
 					attributes.push("style", self.alpha_blending.blend_mode.render());
 				}
+
+				if let Some(mask) = &self.mask {
+					// The mask is in the space of the group's elements, which is that of the group unless their transform is flattened into them
+					let mask_transform = if flatten_transforms { attributes.0.transform * self.transform } else { DAffine2::IDENTITY };
+					push_mask(attributes, mask, mask_transform);
+				}
 			},
 			|render| {
-				for element in self.iter() {
+				let outer_transform = render.transform;
+				if flatten_transforms {
+					render.transform = outer_transform * self.transform;
+				}
+				for element in self.iter().filter(|element| !render_params.culls(element)) {
 					element.render_svg(render, render_params);
 				}
+				render.transform = outer_transform;
 			},
 		);
 	}
 
+	fn render_eps(&self, render: &mut EpsRender, render_params: &RenderParams) {
+		if self.reference && render_params.for_export {
+			return;
+		}
+		let render_params = &self.content_render_params(render_params);
+
+		render.with_transform(self.transform, |render| {
+			render.with_mask(self.mask.as_deref(), |render| {
+				for element in self.iter().filter(|element| !render_params.culls(element)) {
+					element.render_eps(render, render_params);
+				}
+			});
+		});
+	}
+
+	fn render_emf(&self, render: &mut EmfRender, render_params: &RenderParams) {
+		if self.reference && render_params.for_export {
+			return;
+		}
+		let render_params = &self.content_render_params(render_params);
+
+		render.with_transform(self.transform, |render| {
+			render.with_mask(self.mask.as_deref(), |render| {
+				for element in self.iter().filter(|element| !render_params.culls(element)) {
+					element.render_emf(render, render_params);
+				}
+			});
+		});
+	}
+
+	fn render_pdf(&self, render: &mut PdfRender, render_params: &RenderParams) {
+		if self.reference && render_params.for_export {
+			return;
+		}
+		let render_params = &self.content_render_params(render_params);
+
+		render.with_transform(self.transform, |render| {
+			render.with_opacity(self.alpha_blending.opacity as f64, |render| {
+				render.with_mask(self.mask.as_deref(), |render| {
+					for element in self.iter().filter(|element| !render_params.culls(element)) {
+						element.render_pdf(render, render_params);
+					}
+				});
+			});
+		});
+	}
+
+	#[cfg(feature = "vello")]
+	fn render_vello(&self, render: &mut VelloRender, render_params: &RenderParams) {
+		if self.reference && render_params.for_export {
+			return;
+		}
+		let render_params = &self.content_render_params(render_params);
+
+		render.with_transform(self.transform, |render| {
+			render.with_alpha_blending(self.alpha_blending, |render| {
+				render.with_mask(self.mask.as_deref(), |render| {
+					for element in self.iter().filter(|element| !render_params.culls(element)) {
+						element.render_vello(render, render_params);
+					}
+				});
+			});
+		});
+	}
+
+	fn render_scene(&self, render_params: &RenderParams) -> Vec<SceneElement> {
+		if self.reference && render_params.for_export {
+			return Vec::new();
+		}
+		let render_params = &self.content_render_params(render_params);
+
+		vec![SceneElement::Group {
+			transform: scene::matrix(self.transform),
+			opacity: self.alpha_blending.opacity,
+			blend_mode: scene::blend_mode_name(self.alpha_blending.blend_mode),
+			mask: self.mask.as_deref().map(SceneMask::new),
+			children: self
+				.iter()
+				.filter(|element| !render_params.culls(element))
+				.flat_map(|element| element.render_scene(render_params))
+				.collect(),
+		}]
+	}
+
+	/// Renders each element to its own layer. Artboards are split into a layer for their background followed by a layer for each of their elements, clipped to the artboard.
+	fn render_svg_layers(&self, render_params: &RenderParams) -> Vec<RenderedLayer> {
+		let mut layers = Vec::new();
+		let mut push_layer = |kind: &str, element: GraphicElement, alpha_blending: AlphaBlending, render_params: &RenderParams| {
+			let group = GraphicGroup {
+				elements: vec![element],
+				transform: self.transform,
+				alpha_blending: AlphaBlending::default(),
+				channel: self.channel.clone(),
+				export_set: None,
+				reference: false,
+				mask: self.mask.clone(),
+			};
+			let mut render = SvgRender::new();
+			group.render_svg(&mut render, render_params);
+
+			let opacity = alpha_blending.opacity * self.alpha_blending.opacity;
+			layers.push(RenderedLayer {
+				name: format!("{kind} {}", layers.len() + 1),
+				render,
+				alpha_blending: AlphaBlending { opacity, ..alpha_blending },
+			});
+		};
+
+		let exported_reference = |element: &GraphicElement| render_params.for_export && matches!(element, GraphicElement::GraphicGroup(group) if group.reference);
+		for element in self.iter().filter(|element| !exported_reference(element)) {
+			let GraphicElement::Artboard(artboard) = element else {
+				let (element, alpha_blending) = separate_alpha_blending(element.clone());
+				push_layer(element_kind(&element), element, alpha_blending, render_params);
+				continue;
+			};
+
+			let artboard_with = |graphic_group| Artboard { graphic_group, ..*artboard };
+			if !render_params.hide_artboards && render_params.channel.is_none() {
+				push_layer("Artboard", GraphicElement::Artboard(artboard_with(GraphicGroup::EMPTY)), AlphaBlending::default(), render_params);
+			}
+
+			let contents_params = RenderParams {
+				hide_artboards: true,
+				..render_params.clone()
+			};
+			for content in artboard.graphic_group.iter().filter(|content| !exported_reference(content)) {
+				let (content, alpha_blending) = separate_alpha_blending(content.clone());
+				let kind = element_kind(&content);
+				let graphic_group = GraphicGroup {
+					elements: vec![content],
+					transform: artboard.graphic_group.transform,
+					alpha_blending: AlphaBlending::default(),
+					channel: artboard.graphic_group.channel.clone(),
+					export_set: None,
+					reference: false,
+					mask: artboard.graphic_group.mask.clone(),
+				};
+				let opacity = alpha_blending.opacity * artboard.graphic_group.alpha_blending.opacity;
+				push_layer(
+					kind,
+					GraphicElement::Artboard(artboard_with(graphic_group)),
+					AlphaBlending { opacity, ..alpha_blending },
+					&contents_params,
+				);
+			}
+		}
+
+		layers
+	}
+
+	/// The bounds of the elements, cut down to those of the mask.
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]> {
-		self.iter().filter_map(|element| element.bounding_box(transform * self.transform)).reduce(Quad::combine_bounds)
+		let [min, max] = self.iter().filter_map(|element| element.bounding_box(transform * self.transform)).reduce(Quad::combine_bounds)?;
+		let Some(mask) = &self.mask else { return Some([min, max]) };
+		let [mask_min, mask_max] = mask.bounding_box(transform * self.transform)?;
+		let [min, max] = [min.max(mask_min), max.min(mask_max)];
+		min.cmple(max).all().then_some([min, max])
 	}
 
 	fn add_click_targets(&self, click_targets: &mut Vec<ClickTarget>) {
@@ -277,6 +696,27 @@ impl GraphicElementRendered for GraphicGroup {
 		}
 	}
 
+	fn contains_point(&self, point: DVec2) -> bool {
+		if self.reference || self.transform.matrix2.determinant() == 0. {
+			return false;
+		}
+		let point = self.transform.inverse().transform_point2(point);
+		self.mask.as_ref().map_or(true, |mask| mask.keeps(point)) && self.iter().any(|element| element.contains_point(point))
+	}
+
+	/// Masks are only tested by their bounds, so a quad within them but outside of a clip path's shape can still hit the elements.
+	fn intersects_quad(&self, quad: Quad) -> bool {
+		if self.reference || self.transform.matrix2.determinant() == 0. {
+			return false;
+		}
+		let quad = self.transform.inverse() * quad;
+		let within_mask = self
+			.mask
+			.as_ref()
+			.map_or(true, |mask| mask.bounding_box(DAffine2::IDENTITY).is_some_and(|bounds| Quad::from_box(bounds).intersects(quad)));
+		within_mask && self.iter().any(|element| element.intersects_quad(quad))
+	}
+
 	fn to_usvg_node(&self) -> usvg::Node {
 		let mut root_node = usvg::Group::default();
 		for element in self.iter() {
@@ -290,30 +730,233 @@ impl GraphicElementRendered for GraphicGroup {
 	}
 }
 
+/// Defines the mask, placed by the transform in the space of the element it's applied to, and applies it to that element as a clip path or a luminance mask.
+fn push_mask(attributes: &mut SvgRenderAttrs, mask: &Mask, transform: DAffine2) {
+	use std::fmt::Write;
+	let id = format!("mask-{}", generate_uuid());
+	let defs = &mut attributes.0.svg_defs;
+	match mask {
+		Mask::Clip(_) => {
+			let (subpaths, area_transform, fill_rule) = mask.clip_area();
+			let mut path = String::new();
+			for subpath in &subpaths {
+				let _ = subpath.subpath_to_svg(&mut path, transform * area_transform);
+			}
+			write!(defs, r#"<clipPath id="{id}"><path d="{path}" clip-rule="{fill_rule}"/></clipPath>"#).unwrap();
+			attributes.push("clip-path", format!("url(#{id})"));
+		}
+		Mask::Luminance(image_frame) => {
+			// The mask only covers the image, so everything beyond it is hidden
+			let [min, max] = mask.bounding_box(transform).unwrap_or_default();
+			let (x, y, width, height) = (min.x, min.y, max.x - min.x, max.y - min.y);
+			write!(defs, r#"<mask id="{id}" maskUnits="userSpaceOnUse" x="{x}" y="{y}" width="{width}" height="{height}">"#).unwrap();
+			if !image_frame.image.data.is_empty() {
+				write!(
+					defs,
+					r#"<image width="1" height="1" preserveAspectRatio="none" transform="{}" href="{}"/>"#,
+					format_transform_matrix(transform * image_frame.transform),
+					image_data_url(&image_frame.image)
+				)
+				.unwrap();
+			}
+			defs.push_str("</mask>");
+			attributes.push("mask", format!("url(#{id})"));
+		}
+	}
+}
+
+/// Splits a shape with a stack of paint layers into a copy for its own fill and stroke followed by a copy for each layer, in the order they're painted.
+fn paint_layer_shapes(vector_data: &VectorData) -> Vec<VectorData> {
+	let mut shape = vector_data.clone();
+	shape.style.clear_paint_layers();
+	shape.alpha_blending = AlphaBlending::default();
+
+	let layers = vector_data.style.paint_layers().iter().map(|layer| VectorData {
+		style: layer.style(vector_data.style.fill_rule()),
+		alpha_blending: layer.alpha_blending,
+		..shape.clone()
+	});
+	let own = if has_separate_opacity(&shape) { separate_opacity_shapes(&shape) } else { vec![shape.clone()] };
+	own.into_iter().chain(layers).collect()
+}
+
+/// Splits a shape whose subpaths have styles of their own into a copy for each run of neighboring subpaths drawn with the same style, in order.
+fn subpath_style_shapes(vector_data: &VectorData) -> Vec<VectorData> {
+	let mut shapes: Vec<VectorData> = Vec::new();
+	for (index, subpath) in vector_data.stroke_bezier_paths().enumerate() {
+		let style = vector_data.subpath_style(index);
+		match shapes.last_mut() {
+			Some(shape) if shape.style == *style => shape.append_subpath(subpath),
+			_ => {
+				let mut shape = VectorData::empty();
+				shape.transform = vector_data.transform;
+				shape.style = style.clone();
+				shape.append_subpath(subpath);
+				shapes.push(shape);
+			}
+		}
+	}
+	shapes
+}
+
+/// Whether the shape's fill or stroke is given an opacity of its own.
+fn has_separate_opacity(vector_data: &VectorData) -> bool {
+	vector_data.style.fill_opacity() < 1. || (vector_data.style.stroke_opacity() < 1. && vector_data.style.stroke().is_some())
+}
+
+/// Splits a shape whose fill or stroke has an opacity of its own into a copy for its fill followed by a copy for its stroke, each drawn with that opacity.
+fn separate_opacity_shapes(vector_data: &VectorData) -> Vec<VectorData> {
+	let mut shape = vector_data.clone();
+	shape.style.set_fill_opacity(1.);
+	shape.style.set_stroke_opacity(1.);
+
+	let mut fill = shape.clone();
+	fill.style.clear_stroke();
+	fill.alpha_blending = AlphaBlending {
+		opacity: vector_data.style.fill_opacity() as f32,
+		blend_mode: BlendMode::Normal,
+	};
+	let mut stroke = shape;
+	stroke.style.set_fill(Fill::None);
+	stroke.alpha_blending = AlphaBlending {
+		opacity: vector_data.style.stroke_opacity() as f32,
+		blend_mode: BlendMode::Normal,
+	};
+	std::iter::once(fill).chain(vector_data.style.stroke().map(|_| stroke)).collect()
+}
+
+/// Splits a shape with a tapered stroke into a copy for its fill followed by the outline of the stroke filled with its paint, which renderers draw in place of the stroke.
+/// The outline is made with the shape's own transform applied, so like other strokes its width isn't scaled by the transform.
+fn tapered_stroke_shapes(vector_data: &VectorData) -> Vec<VectorData> {
+	let mut shape = vector_data.clone();
+	shape.style.clear_stroke();
+	shape.alpha_blending = AlphaBlending::default();
+	let Some(stroke) = vector_data.style.stroke() else { return vec![shape] };
+
+	let mut outline = VectorData::from_subpaths(vector_data.stroke_bezier_paths().flat_map(|subpath| stroke.tapered_outline(&subpath, vector_data.transform)));
+	outline.style.clear_stroke();
+	outline.style.set_fill(stroke.paint);
+	std::iter::once(shape).chain(std::iter::once(outline)).collect()
+}
+
+/// Splits a shape whose dashes are aligned to its corners into a copy for its fill followed by the dashes as subpaths of their own, stroked without a dash pattern.
+/// Like the outline of a tapered stroke, the dashes are made with the shape's own transform applied.
+fn corner_dashed_stroke_shapes(vector_data: &VectorData) -> Vec<VectorData> {
+	let mut shape = vector_data.clone();
+	shape.style.clear_stroke();
+	shape.alpha_blending = AlphaBlending::default();
+	let Some(stroke) = vector_data.style.stroke() else { return vec![shape] };
+
+	let mut dashes = VectorData::from_subpaths(vector_data.stroke_bezier_paths().flat_map(|subpath| stroke.corner_aligned_dashes(&subpath, vector_data.transform)));
+	dashes.style.set_fill(Fill::None);
+	dashes.style.set_stroke(Stroke {
+		dash_lengths: Vec::new(),
+		line_cap: stroke.drawn_line_cap(),
+		dashes_align_to_corners: false,
+		..stroke
+	});
+	std::iter::once(shape).chain(std::iter::once(dashes)).collect()
+}
+
+/// The copies of the shape that renderers draw in its place, one for each run of subpaths with their own style, for each of its paint layers,
+/// for its fill and stroke when they have their own opacity, or for the outline of its tapered stroke or dashes aligned to its corners, or none if it's drawn as it is.
+/// The outline view shows the path of a tapered or corner-aligned dashed stroke rather than its outline or dashes.
+fn component_shapes(vector_data: &VectorData, render_params: &RenderParams) -> Option<Vec<VectorData>> {
+	let tapered = vector_data.style.stroke().is_some_and(|stroke| !stroke.width_profile.is_empty() && stroke.weight > 0.);
+	let corner_dashed = vector_data
+		.style
+		.stroke()
+		.is_some_and(|stroke| stroke.dashes_align_to_corners && stroke.is_dashed() && stroke.weight > 0.);
+	if !vector_data.subpath_styles.is_empty() {
+		Some(subpath_style_shapes(vector_data))
+	} else if !vector_data.style.paint_layers().is_empty() {
+		Some(paint_layer_shapes(vector_data))
+	} else if has_separate_opacity(vector_data) {
+		Some(separate_opacity_shapes(vector_data))
+	} else if tapered && render_params.view_mode != ViewMode::Outline {
+		Some(tapered_stroke_shapes(vector_data))
+	} else if corner_dashed && render_params.view_mode != ViewMode::Outline {
+		Some(corner_dashed_stroke_shapes(vector_data))
+	} else {
+		None
+	}
+}
+
+/// The alignment the shape's stroke is drawn with, which is centered unless every subpath is closed, since only closed paths have an inside and outside.
+/// A tapered stroke is always centered.
+fn stroke_alignment(vector_data: &VectorData) -> StrokeAlignment {
+	let Some(stroke) = vector_data.style.stroke().filter(|stroke| stroke.weight > 0. && stroke.width_profile.is_empty()) else {
+		return StrokeAlignment::Center;
+	};
+	let mut subpaths = vector_data.stroke_bezier_paths().peekable();
+	if subpaths.peek().is_none() || !subpaths.all(|subpath| subpath.closed()) {
+		return StrokeAlignment::Center;
+	}
+	stroke.alignment
+}
+
+/// An inside or outside stroke is drawn centered on the path at twice its weight, and then clipped to that side of the shape so only the aligned half shows.
+fn aligned_stroke(stroke: &Stroke) -> Stroke {
+	Stroke {
+		weight: stroke.weight * 2.,
+		alignment: StrokeAlignment::Center,
+		..stroke.clone()
+	}
+}
+
+/// A rectangle on the page around the shape with room for its [`aligned_stroke`], which clips away the inside of the shape when paired with the path by the even-odd rule.
+fn outside_stroke_bounds(vector_data: &VectorData, stroke: &Stroke, transform: DAffine2) -> [DVec2; 2] {
+	let [min, max] = vector_data.bounding_box_with_transform(transform).unwrap_or_default();
+	// The stroke reaches out at most as far as its miters, whether or not its weight is scaled along with the shape
+	let scale = transform.matrix2.x_axis.length().max(transform.matrix2.y_axis.length()).max(1.);
+	let margin = DVec2::splat(stroke.weight * stroke.line_join_miter_limit.max(1.) * scale);
+	[min - margin, max + margin]
+}
+
 impl GraphicElementRendered for VectorData {
 	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams) {
+		if render_params.channel.is_some() {
+			self.knockout().render_svg(render, &render_params.knockout());
+			return;
+		}
+
+		if let Some(shapes) = component_shapes(self, render_params) {
+			// Each layer of paint, or the outline of a tapered stroke, is drawn as its own copy of the shape, grouped to blend with what's below as one
+			render.parent_tag(
+				"g",
+				|attributes| {
+					if self.alpha_blending.opacity < 1. {
+						attributes.push("opacity", self.alpha_blending.opacity.to_string());
+					}
+
+					if self.alpha_blending.blend_mode != BlendMode::default() {
+						attributes.push("style", self.alpha_blending.blend_mode.render());
+					}
+				},
+				|render| {
+					for shape in shapes {
+						shape.render_svg(render, render_params);
+					}
+				},
+			);
+			return;
+		}
+
 		let multiplied_transform = render.transform * self.transform;
 		let layer_bounds = self.bounding_box().unwrap_or_default();
 		let transformed_bounds = self.bounding_box_with_transform(multiplied_transform).unwrap_or_default();
 
+		let subpaths = self.stroke_bezier_paths().collect::<Vec<_>>();
 		let mut path = String::new();
-		for (_, subpath) in self.region_bezier_paths() {
-			let _ = subpath.subpath_to_svg(&mut path, multiplied_transform);
-		}
-		for subpath in self.stroke_bezier_paths() {
+		for subpath in &subpaths {
 			let _ = subpath.subpath_to_svg(&mut path, multiplied_transform);
 		}
 
-		render.leaf_tag("path", |attributes| {
-			attributes.push("class", "vector-data");
-
-			attributes.push("d", path);
-
-			let fill_and_stroke = self
-				.style
-				.render(render_params.view_mode, &mut attributes.0.svg_defs, multiplied_transform, layer_bounds, transformed_bounds);
-			attributes.push_val(fill_and_stroke);
-
+		let gradient_stroke = self
+			.style
+			.stroke()
+			.filter(|stroke| stroke.gradient.is_some() && stroke.weight > 0. && render_params.view_mode != ViewMode::Outline);
+		let blending = |attributes: &mut SvgRenderAttrs| {
 			if self.alpha_blending.opacity < 1. {
 				attributes.push("opacity", self.alpha_blending.opacity.to_string());
 			}
@@ -321,9 +964,177 @@ impl GraphicElementRendered for VectorData {
 			if self.alpha_blending.blend_mode != BlendMode::default() {
 				attributes.push("style", self.alpha_blending.blend_mode.render());
 			}
+		};
+
+		let alignment = stroke_alignment(self);
+		if let Some(stroke) = self.style.stroke().filter(|_| alignment != StrokeAlignment::Center && render_params.view_mode != ViewMode::Outline) {
+			let mut fill_shape = self.clone();
+			fill_shape.style.clear_stroke();
+			fill_shape.alpha_blending = AlphaBlending::default();
+			let mut stroke_shape = fill_shape.clone();
+			stroke_shape.style.set_fill(Fill::None);
+			stroke_shape.style.set_stroke(aligned_stroke(&stroke));
+
+			let mut outline = String::new();
+			for subpath in self.stroke_bezier_paths() {
+				let _ = subpath.subpath_to_svg(&mut outline, multiplied_transform);
+			}
+			let fill_rule = self.style.fill_rule();
+
+			// The stroke is drawn over the fill and clipped to the inside or outside of the shape, grouped to blend with what's below as one
+			render.parent_tag("g", blending, |render| {
+				fill_shape.render_svg(render, render_params);
+				render.parent_tag(
+					"g",
+					|attributes| {
+						use std::fmt::Write;
+						let id = format!("stroke-{}", generate_uuid());
+						if alignment == StrokeAlignment::Inside {
+							write!(&mut attributes.0.svg_defs, r#"<clipPath id="{id}"><path d="{outline}" clip-rule="{fill_rule}"/></clipPath>"#).unwrap();
+							attributes.push("clip-path", format!("url(#{id})"));
+						} else {
+							let [min, max] = outside_stroke_bounds(self, &stroke, multiplied_transform);
+							let size = max - min;
+							write!(
+								&mut attributes.0.svg_defs,
+								r#"<mask id="{id}" maskUnits="userSpaceOnUse" x="{}" y="{}" width="{}" height="{}"><rect x="{}" y="{}" width="{}" height="{}" fill="white"/><path d="{outline}" fill-rule="{fill_rule}"/></mask>"#,
+								min.x, min.y, size.x, size.y, min.x, min.y, size.x, size.y
+							)
+							.unwrap();
+							attributes.push("mask", format!("url(#{id})"));
+						}
+					},
+					|render| stroke_shape.render_svg(render, render_params),
+				);
+			});
+			return;
+		}
+
+		let fill_and_stroke = |render: &mut SvgRender, blended: bool| {
+			render.leaf_tag("path", |attributes| {
+				attributes.push("class", "vector-data");
+
+				attributes.push("d", path);
+
+				let fill_and_stroke = self
+					.style
+					.render(render_params.view_mode, &mut attributes.0.svg_defs, multiplied_transform, layer_bounds, transformed_bounds);
+				attributes.push_val(fill_and_stroke);
+
+				if blended {
+					blending(attributes);
+				}
+			});
+		};
+
+		let Some(stroke) = gradient_stroke else {
+			fill_and_stroke(render, true);
+			return;
+		};
+
+		// The gradient along the stroke is drawn as pieces over the fill, so they're grouped to blend with what's below as one
+		render.parent_tag("g", blending, |render| {
+			fill_and_stroke(render, false);
+			for (piece, piece_stroke) in subpaths.iter().flat_map(|subpath| stroke.gradient_pieces(subpath, multiplied_transform)) {
+				let mut path = String::new();
+				let _ = piece.subpath_to_svg(&mut path, DAffine2::IDENTITY);
+				render.leaf_tag("path", |attributes| {
+					attributes.push("d", path);
+					attributes.push("fill", "none");
+					let stroke_attributes = piece_stroke.render(&mut attributes.0.svg_defs, multiplied_transform, layer_bounds, transformed_bounds);
+					attributes.push_val(stroke_attributes);
+				});
+			}
 		});
 	}
 
+	fn render_eps(&self, render: &mut EpsRender, render_params: &RenderParams) {
+		if render_params.channel.is_some() {
+			self.knockout().render_eps(render, &render_params.knockout());
+			return;
+		}
+
+		match component_shapes(self, render_params) {
+			Some(shapes) => shapes.iter().for_each(|shape| render.vector_data(shape)),
+			None => render.vector_data(self),
+		}
+	}
+
+	fn render_emf(&self, render: &mut EmfRender, render_params: &RenderParams) {
+		if render_params.channel.is_some() {
+			self.knockout().render_emf(render, &render_params.knockout());
+			return;
+		}
+
+		match component_shapes(self, render_params) {
+			Some(shapes) => shapes.iter().for_each(|shape| render.vector_data(shape)),
+			None => render.vector_data(self),
+		}
+	}
+
+	fn render_pdf(&self, render: &mut PdfRender, render_params: &RenderParams) {
+		if render_params.channel.is_some() {
+			self.knockout().render_pdf(render, &render_params.knockout());
+			return;
+		}
+
+		match component_shapes(self, render_params) {
+			// The copies are drawn with the opacity of the shape they're made from, in addition to any of their own
+			Some(shapes) => render.with_opacity(self.alpha_blending.opacity as f64, |render| shapes.iter().for_each(|shape| render.vector_data(shape))),
+			None => render.vector_data(self),
+		}
+	}
+
+	#[cfg(feature = "vello")]
+	fn render_vello(&self, render: &mut VelloRender, render_params: &RenderParams) {
+		if render_params.channel.is_some() {
+			self.knockout().render_vello(render, &render_params.knockout());
+			return;
+		}
+
+		match component_shapes(self, render_params) {
+			// The copies are composited together with the opacity and blend mode of the shape they're made from, in addition to any of their own
+			Some(shapes) => render.with_alpha_blending(self.alpha_blending, |render| shapes.iter().for_each(|shape| render.vector_data(shape))),
+			None => render.vector_data(self),
+		}
+	}
+
+	fn render_scene(&self, render_params: &RenderParams) -> Vec<SceneElement> {
+		if render_params.channel.is_some() {
+			return self.knockout().render_scene(&render_params.knockout());
+		}
+
+		if let Some(shapes) = component_shapes(self, render_params) {
+			return vec![SceneElement::Group {
+				transform: scene::matrix(DAffine2::IDENTITY),
+				opacity: self.alpha_blending.opacity,
+				blend_mode: scene::blend_mode_name(self.alpha_blending.blend_mode),
+				mask: None,
+				children: shapes.iter().flat_map(|shape| shape.render_scene(render_params)).collect(),
+			}];
+		}
+
+		let mut path = String::new();
+		for subpath in self.stroke_bezier_paths() {
+			let _ = subpath.subpath_to_svg(&mut path, DAffine2::IDENTITY);
+		}
+		let [min, max] = self.bounding_box().unwrap_or_default();
+
+		vec![SceneElement::Path {
+			transform: scene::matrix(self.transform),
+			opacity: self.alpha_blending.opacity,
+			blend_mode: scene::blend_mode_name(self.alpha_blending.blend_mode),
+			path,
+			bounds: [min.x, min.y, max.x, max.y],
+			fill: ScenePaint::new(self.style.fill(), render_params),
+			fill_rule: self.style.fill_rule().to_string(),
+			stroke: self.style.stroke().as_ref().map(|stroke| SceneStroke {
+				alignment: stroke_alignment(self).to_string(),
+				..SceneStroke::new(stroke, render_params)
+			}),
+		}]
+	}
+
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]> {
 		self.bounding_box_with_transform(self.transform * transform)
 	}
@@ -334,47 +1145,142 @@ impl GraphicElementRendered for VectorData {
 		click_targets.extend(self.stroke_bezier_paths().map(|subpath| ClickTarget { stroke_width, subpath }));
 	}
 
-	fn to_usvg_node(&self) -> usvg::Node {
-		use bezier_rs::BezierHandles;
-		use usvg::tiny_skia_path::PathBuilder;
-		let mut builder = PathBuilder::new();
-		let vector_data = self;
-
-		let transform = to_transform(vector_data.transform);
-		for subpath in vector_data.stroke_bezier_paths() {
-			let start = vector_data.transform.transform_point2(subpath[0].anchor);
-			builder.move_to(start.x as f32, start.y as f32);
-			for bezier in subpath.iter() {
-				bezier.apply_transformation(|pos| vector_data.transform.transform_point2(pos));
-				let end = bezier.end;
-				match bezier.handles {
-					BezierHandles::Linear => builder.line_to(end.x as f32, end.y as f32),
-					BezierHandles::Quadratic { handle } => builder.quad_to(handle.x as f32, handle.y as f32, end.x as f32, end.y as f32),
-					BezierHandles::Cubic { handle_start, handle_end } => {
-						builder.cubic_to(handle_start.x as f32, handle_start.y as f32, handle_end.x as f32, handle_end.y as f32, end.x as f32, end.y as f32)
-					}
+	fn contains_point(&self, point: DVec2) -> bool {
+		HitShape::new(self).is_some_and(|shape| shape.contains_point(point))
+	}
+
+	fn intersects_quad(&self, quad: Quad) -> bool {
+		HitShape::new(self).is_some_and(|shape| shape.intersects_quad(quad))
+	}
+}
+
+impl GraphicElementRendered for VectorInstances {
+	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams) {
+		if self.instances.is_empty() {
+			return;
+		}
+
+		// The geometry is rendered just once, as a definition that each instance then refers to with a <use> tag
+		let mut geometry_render = SvgRender::new();
+		self.geometry.render_svg(&mut geometry_render, render_params);
+		let id = format!("instances-{}", generate_uuid());
+		use std::fmt::Write;
+		write!(&mut render.svg_defs, r#"{}<g id="{id}">{}</g>"#, geometry_render.svg_defs, geometry_render.svg.to_svg_string()).unwrap();
+
+		for &instance in &self.instances {
+			let transform = render.transform * self.transform * instance;
+			render.leaf_tag("use", |attributes| {
+				attributes.push("href", format!("#{id}"));
+				attributes.push("transform", format_transform_matrix(transform));
+			});
+		}
+	}
+
+	fn render_eps(&self, render: &mut EpsRender, render_params: &RenderParams) {
+		for &instance in &self.instances {
+			render.with_transform(self.transform * instance, |render| self.geometry.render_eps(render, render_params));
+		}
+	}
+
+	fn render_emf(&self, render: &mut EmfRender, render_params: &RenderParams) {
+		for &instance in &self.instances {
+			render.with_transform(self.transform * instance, |render| self.geometry.render_emf(render, render_params));
+		}
+	}
+
+	fn render_pdf(&self, render: &mut PdfRender, render_params: &RenderParams) {
+		for &instance in &self.instances {
+			render.with_transform(self.transform * instance, |render| self.geometry.render_pdf(render, render_params));
+		}
+	}
+
+	#[cfg(feature = "vello")]
+	fn render_vello(&self, render: &mut VelloRender, render_params: &RenderParams) {
+		for &instance in &self.instances {
+			render.with_transform(self.transform * instance, |render| self.geometry.render_vello(render, render_params));
+		}
+	}
+
+	fn render_scene(&self, render_params: &RenderParams) -> Vec<SceneElement> {
+		let geometry = self.geometry.render_scene(render_params);
+		if geometry.is_empty() {
+			return Vec::new();
+		}
+
+		self.instances
+			.iter()
+			.map(|&instance| SceneElement::Group {
+				transform: scene::matrix(self.transform * instance),
+				opacity: 1.,
+				blend_mode: scene::blend_mode_name(BlendMode::default()),
+				mask: None,
+				children: geometry.clone(),
+			})
+			.collect()
+	}
+
+	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]> {
+		self.instances
+			.iter()
+			.filter_map(|&instance| self.geometry.bounding_box_with_transform(transform * self.transform * instance * self.geometry.transform))
+			.reduce(Quad::combine_bounds)
+	}
+
+	fn add_click_targets(&self, click_targets: &mut Vec<ClickTarget>) {
+		let mut geometry_click_targets = Vec::new();
+		self.geometry.add_click_targets(&mut geometry_click_targets);
+		for &instance in &self.instances {
+			click_targets.extend(geometry_click_targets.iter().map(|click_target| {
+				let mut subpath = click_target.subpath.clone();
+				subpath.apply_transform(instance * self.geometry.transform);
+				ClickTarget {
+					subpath,
+					stroke_width: click_target.stroke_width,
 				}
-			}
-			if subpath.closed {
-				builder.close()
-			}
+			}));
 		}
-		let path = builder.finish().unwrap();
-		let mut path = usvg::Path::new(path.into());
-		path.abs_transform = transform;
-		// TODO: use proper style
-		path.fill = None;
-		path.stroke = Some(usvg::Stroke::default());
-		usvg::Node::Path(Box::new(path))
+	}
+
+	fn contains_point(&self, point: DVec2) -> bool {
+		let Some(shape) = HitShape::new(&self.geometry) else { return false };
+		let mut placements = self
+			.instances
+			.iter()
+			.map(|&instance| self.transform * instance)
+			.filter(|placement| placement.matrix2.determinant() != 0.);
+		placements.any(|placement| shape.contains_point(placement.inverse().transform_point2(point)))
+	}
+
+	fn intersects_quad(&self, quad: Quad) -> bool {
+		let Some(shape) = HitShape::new(&self.geometry) else { return false };
+		let mut placements = self
+			.instances
+			.iter()
+			.map(|&instance| self.transform * instance)
+			.filter(|placement| placement.matrix2.determinant() != 0.);
+		placements.any(|placement| shape.intersects_quad(placement.inverse() * quad))
+	}
+}
+
+impl Artboard {
+	/// The parameters for rendering the artboard's contents, which are placed at its location.
+	fn content_render_params(&self, render_params: &RenderParams) -> RenderParams {
+		render_params.placed_by(DAffine2::from_translation(self.location.as_dvec2()) * self.graphic_group.transform)
 	}
 }
 
 impl GraphicElementRendered for Artboard {
 	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams) {
-		if !render_params.hide_artboards {
+		let flatten_transforms = render.flatten_transforms;
+		let outer_transform = render.transform;
+		let content_params = self.content_render_params(render_params);
+		if !render_params.hide_artboards && render_params.channel.is_none() {
 			// Background
 			render.leaf_tag("rect", |attributes| {
 				attributes.push("class", "artboard-bg");
+				if outer_transform != DAffine2::IDENTITY {
+					attributes.push("transform", format_transform_matrix(outer_transform));
+				}
 				attributes.push("fill", format!("#{}", self.background.rgba_hex()));
 				attributes.push("x", self.location.x.min(self.location.x + self.dimensions.x).to_string());
 				attributes.push("y", self.location.y.min(self.location.y + self.dimensions.y).to_string());
@@ -408,12 +1314,20 @@ impl GraphicElementRendered for Artboard {
 			|attributes| {
 				attributes.push("class", "artboard");
 
-				attributes.push(
-					"transform",
-					format_transform_matrix(DAffine2::from_translation(self.location.as_dvec2()) * self.graphic_group.transform),
-				);
+				if !flatten_transforms {
+					attributes.push(
+						"transform",
+						format_transform_matrix(DAffine2::from_translation(self.location.as_dvec2()) * self.graphic_group.transform),
+					);
+				}
 
 				if self.clip {
+					// The clip is in the space of the group, which is that of the artboard's contents unless their transform is flattened into them
+					let clip_transform = if flatten_transforms {
+						outer_transform * DAffine2::from_translation(self.location.as_dvec2())
+					} else {
+						self.graphic_group.transform.inverse()
+					};
 					let id = format!("artboard-{}", generate_uuid());
 					let selector = format!("url(#{id})");
 					use std::fmt::Write;
@@ -422,7 +1336,7 @@ impl GraphicElementRendered for Artboard {
 						r##"<clipPath id="{id}"><rect x="0" y="0" width="{}" height="{}" transform="{}"/></clipPath>"##,
 						self.dimensions.x,
 						self.dimensions.y,
-						format_transform_matrix(self.graphic_group.transform.inverse())
+						format_transform_matrix(clip_transform)
 					)
 					.unwrap();
 					attributes.push("clip-path", selector);
@@ -430,13 +1344,76 @@ impl GraphicElementRendered for Artboard {
 			},
 			// Artboard contents
 			|render| {
-				for element in self.graphic_group.iter() {
-					element.render_svg(render, render_params);
+				if flatten_transforms {
+					render.transform = outer_transform * DAffine2::from_translation(self.location.as_dvec2()) * self.graphic_group.transform;
+				}
+				for element in self.graphic_group.iter().filter(|element| !content_params.culls(element)) {
+					element.render_svg(render, &content_params);
 				}
+				render.transform = outer_transform;
 			},
 		);
 	}
 
+	fn render_eps(&self, render: &mut EpsRender, render_params: &RenderParams) {
+		let show_background = !render_params.hide_artboards && render_params.channel.is_none();
+		let content_params = self.content_render_params(render_params);
+		render.artboard(self, show_background, |render| {
+			for element in self.graphic_group.iter().filter(|element| !content_params.culls(element)) {
+				element.render_eps(render, &content_params);
+			}
+		});
+	}
+
+	fn render_emf(&self, render: &mut EmfRender, render_params: &RenderParams) {
+		let show_background = !render_params.hide_artboards && render_params.channel.is_none();
+		let content_params = self.content_render_params(render_params);
+		render.artboard(self, show_background, |render| {
+			for element in self.graphic_group.iter().filter(|element| !content_params.culls(element)) {
+				element.render_emf(render, &content_params);
+			}
+		});
+	}
+
+	fn render_pdf(&self, render: &mut PdfRender, render_params: &RenderParams) {
+		let show_background = !render_params.hide_artboards && render_params.channel.is_none();
+		let content_params = self.content_render_params(render_params);
+		render.artboard(self, show_background, |render| {
+			for element in self.graphic_group.iter().filter(|element| !content_params.culls(element)) {
+				element.render_pdf(render, &content_params);
+			}
+		});
+	}
+
+	#[cfg(feature = "vello")]
+	fn render_vello(&self, render: &mut VelloRender, render_params: &RenderParams) {
+		let show_background = !render_params.hide_artboards && render_params.channel.is_none();
+		let content_params = self.content_render_params(render_params);
+		render.artboard(self, show_background, |render| {
+			for element in self.graphic_group.iter().filter(|element| !content_params.culls(element)) {
+				element.render_vello(render, &content_params);
+			}
+		});
+	}
+
+	fn render_scene(&self, render_params: &RenderParams) -> Vec<SceneElement> {
+		let show_background = !render_params.hide_artboards && render_params.channel.is_none();
+		let content_params = self.content_render_params(render_params);
+		vec![SceneElement::Artboard {
+			location: self.location.to_array(),
+			dimensions: self.dimensions.to_array(),
+			background: show_background.then(|| scene::color_hex(self.background)),
+			clip: self.clip,
+			transform: scene::matrix(DAffine2::from_translation(self.location.as_dvec2()) * self.graphic_group.transform),
+			children: self
+				.graphic_group
+				.iter()
+				.filter(|element| !content_params.culls(element))
+				.flat_map(|element| element.render_scene(&content_params))
+				.collect(),
+		}]
+	}
+
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]> {
 		let artboard_bounds = (transform * Quad::from_box([self.location.as_dvec2(), self.location.as_dvec2() + self.dimensions.as_dvec2()])).bounding_box();
 		if self.clip {
@@ -451,14 +1428,42 @@ impl GraphicElementRendered for Artboard {
 		click_targets.push(ClickTarget { stroke_width: 0., subpath });
 	}
 
+	/// The background covers the whole artboard, so anywhere on it is hit, along with the content spilling past its edges unless it's clipped.
+	fn contains_point(&self, point: DVec2) -> bool {
+		let background = Quad::from_box([self.location.as_dvec2(), self.location.as_dvec2() + self.dimensions.as_dvec2()]);
+		background.contains(point) || (!self.clip && self.graphic_group.contains_point(point))
+	}
+
+	fn intersects_quad(&self, quad: Quad) -> bool {
+		let background = Quad::from_box([self.location.as_dvec2(), self.location.as_dvec2() + self.dimensions.as_dvec2()]);
+		background.intersects(quad) || (!self.clip && self.graphic_group.intersects_quad(quad))
+	}
+
 	fn contains_artboard(&self) -> bool {
 		true
 	}
 }
 
+/// Encodes the image as a PNG in a base64 data URL, unless it already has one.
+pub fn image_data_url(image: &Image<Color>) -> String {
+	image.base64_string.clone().unwrap_or_else(|| {
+		let output = image.to_png();
+		let preamble = "data:image/png;base64,";
+		let mut base64_string = String::with_capacity(preamble.len() + output.len() * 4);
+		base64_string.push_str(preamble);
+		base64::engine::general_purpose::STANDARD.encode_string(output, &mut base64_string);
+		base64_string
+	})
+}
+
 impl GraphicElementRendered for ImageFrame<Color> {
 	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams) {
-		let transform: String = format_transform_matrix(self.transform * render.transform);
+		if render_params.channel.is_some() {
+			self.knockout().render_svg(render, &render_params.knockout());
+			return;
+		}
+
+		let transform: String = format_transform_matrix(render.transform * self.transform);
 
 		match render_params.image_render_mode {
 			ImageRenderMode::Base64 => {
@@ -467,14 +1472,7 @@ impl GraphicElementRendered for ImageFrame<Color> {
 					return;
 				}
 
-				let base64_string = image.base64_string.clone().unwrap_or_else(|| {
-					let output = image.to_png();
-					let preamble = "data:image/png;base64,";
-					let mut base64_string = String::with_capacity(preamble.len() + output.len() * 4);
-					base64_string.push_str(preamble);
-					base64::engine::general_purpose::STANDARD.encode_string(output, &mut base64_string);
-					base64_string
-				});
+				let base64_string = image_data_url(image);
 				render.leaf_tag("image", |attributes| {
 					attributes.push("width", 1.to_string());
 					attributes.push("height", 1.to_string());
@@ -489,6 +1487,53 @@ impl GraphicElementRendered for ImageFrame<Color> {
 		}
 	}
 
+	fn render_eps(&self, render: &mut EpsRender, render_params: &RenderParams) {
+		match render_params.channel {
+			Some(_) => render.image_frame(&self.knockout()),
+			None => render.image_frame(self),
+		}
+	}
+
+	fn render_emf(&self, render: &mut EmfRender, render_params: &RenderParams) {
+		match render_params.channel {
+			Some(_) => render.image_frame(&self.knockout()),
+			None => render.image_frame(self),
+		}
+	}
+
+	fn render_pdf(&self, render: &mut PdfRender, render_params: &RenderParams) {
+		match render_params.channel {
+			Some(_) => render.image_frame(&self.knockout()),
+			None => render.image_frame(self),
+		}
+	}
+
+	#[cfg(feature = "vello")]
+	fn render_vello(&self, render: &mut VelloRender, render_params: &RenderParams) {
+		match render_params.channel {
+			Some(_) => render.image_frame(&self.knockout()),
+			None => render.image_frame(self),
+		}
+	}
+
+	fn render_scene(&self, render_params: &RenderParams) -> Vec<SceneElement> {
+		if render_params.channel.is_some() {
+			return self.knockout().render_scene(&render_params.knockout());
+		}
+		if self.image.data.is_empty() {
+			return Vec::new();
+		}
+
+		vec![SceneElement::Image {
+			transform: scene::matrix(self.transform),
+			opacity: self.alpha_blending.opacity,
+			blend_mode: scene::blend_mode_name(self.alpha_blending.blend_mode),
+			width: self.image.width,
+			height: self.image.height,
+			href: image_data_url(&self.image),
+		}]
+	}
+
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]> {
 		let transform = self.transform * transform;
 		(transform.matrix2 != glam::DMat2::ZERO).then(|| (transform * Quad::from_box([DVec2::ZERO, DVec2::ONE])).bounding_box())
@@ -499,6 +1544,14 @@ impl GraphicElementRendered for ImageFrame<Color> {
 		click_targets.push(ClickTarget { subpath, stroke_width: 0. });
 	}
 
+	fn contains_point(&self, point: DVec2) -> bool {
+		(self.transform * Quad::from_box([DVec2::ZERO, DVec2::ONE])).contains(point)
+	}
+
+	fn intersects_quad(&self, quad: Quad) -> bool {
+		self.transform.matrix2.determinant() != 0. && (self.transform * Quad::from_box([DVec2::ZERO, DVec2::ONE])).intersects(quad)
+	}
+
 	fn to_usvg_node(&self) -> usvg::Node {
 		let image_frame = self;
 		if image_frame.image.width * image_frame.image.height == 0 {
@@ -524,18 +1577,80 @@ impl GraphicElementRendered for GraphicElement {
 	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams) {
 		match self {
 			GraphicElement::VectorData(vector_data) => vector_data.render_svg(render, render_params),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.render_svg(render, render_params),
 			GraphicElement::ImageFrame(image_frame) => image_frame.render_svg(render, render_params),
-			GraphicElement::Text(_) => todo!("Render a text GraphicElement"),
+			GraphicElement::Text(text) => render_text(text, render, render_params),
 			GraphicElement::GraphicGroup(graphic_group) => graphic_group.render_svg(render, render_params),
 			GraphicElement::Artboard(artboard) => artboard.render_svg(render, render_params),
 		}
 	}
 
+	fn render_eps(&self, render: &mut EpsRender, render_params: &RenderParams) {
+		match self {
+			GraphicElement::VectorData(vector_data) => vector_data.render_eps(render, render_params),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.render_eps(render, render_params),
+			GraphicElement::ImageFrame(image_frame) => image_frame.render_eps(render, render_params),
+			GraphicElement::Text(_) => {}
+			GraphicElement::GraphicGroup(graphic_group) => graphic_group.render_eps(render, render_params),
+			GraphicElement::Artboard(artboard) => artboard.render_eps(render, render_params),
+		}
+	}
+
+	fn render_emf(&self, render: &mut EmfRender, render_params: &RenderParams) {
+		match self {
+			GraphicElement::VectorData(vector_data) => vector_data.render_emf(render, render_params),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.render_emf(render, render_params),
+			GraphicElement::ImageFrame(image_frame) => image_frame.render_emf(render, render_params),
+			GraphicElement::Text(_) => {}
+			GraphicElement::GraphicGroup(graphic_group) => graphic_group.render_emf(render, render_params),
+			GraphicElement::Artboard(artboard) => artboard.render_emf(render, render_params),
+		}
+	}
+
+	fn render_pdf(&self, render: &mut PdfRender, render_params: &RenderParams) {
+		match self {
+			GraphicElement::VectorData(vector_data) => vector_data.render_pdf(render, render_params),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.render_pdf(render, render_params),
+			GraphicElement::ImageFrame(image_frame) => image_frame.render_pdf(render, render_params),
+			GraphicElement::Text(_) => {}
+			GraphicElement::GraphicGroup(graphic_group) => graphic_group.render_pdf(render, render_params),
+			GraphicElement::Artboard(artboard) => artboard.render_pdf(render, render_params),
+		}
+	}
+
+	#[cfg(feature = "vello")]
+	fn render_vello(&self, render: &mut VelloRender, render_params: &RenderParams) {
+		match self {
+			GraphicElement::VectorData(vector_data) => vector_data.render_vello(render, render_params),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.render_vello(render, render_params),
+			GraphicElement::ImageFrame(image_frame) => image_frame.render_vello(render, render_params),
+			GraphicElement::Text(_) => {}
+			GraphicElement::GraphicGroup(graphic_group) => graphic_group.render_vello(render, render_params),
+			GraphicElement::Artboard(artboard) => artboard.render_vello(render, render_params),
+		}
+	}
+
+	fn render_scene(&self, render_params: &RenderParams) -> Vec<SceneElement> {
+		match self {
+			GraphicElement::VectorData(vector_data) => vector_data.render_scene(render_params),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.render_scene(render_params),
+			GraphicElement::ImageFrame(image_frame) => image_frame.render_scene(render_params),
+			GraphicElement::Text(text) if render_params.channel.is_none() => vec![SceneElement::Text {
+				text: text.clone(),
+				font_size: TEXT_FONT_SIZE,
+			}],
+			GraphicElement::Text(_) => Vec::new(),
+			GraphicElement::GraphicGroup(graphic_group) => graphic_group.render_scene(render_params),
+			GraphicElement::Artboard(artboard) => artboard.render_scene(render_params),
+		}
+	}
+
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]> {
 		match self {
 			GraphicElement::VectorData(vector_data) => GraphicElementRendered::bounding_box(&**vector_data, transform),
+			GraphicElement::VectorInstances(vector_instances) => GraphicElementRendered::bounding_box(&**vector_instances, transform),
 			GraphicElement::ImageFrame(image_frame) => image_frame.bounding_box(transform),
-			GraphicElement::Text(_) => todo!("Bounds of a text GraphicElement"),
+			GraphicElement::Text(text) => Some((transform * Quad::from_box(text_bounds(text))).bounding_box()),
 			GraphicElement::GraphicGroup(graphic_group) => graphic_group.bounding_box(transform),
 			GraphicElement::Artboard(artboard) => artboard.bounding_box(transform),
 		}
@@ -544,16 +1659,46 @@ impl GraphicElementRendered for GraphicElement {
 	fn add_click_targets(&self, click_targets: &mut Vec<ClickTarget>) {
 		match self {
 			GraphicElement::VectorData(vector_data) => vector_data.add_click_targets(click_targets),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.add_click_targets(click_targets),
 			GraphicElement::ImageFrame(image_frame) => image_frame.add_click_targets(click_targets),
-			GraphicElement::Text(_) => todo!("click target for text GraphicElement"),
+			GraphicElement::Text(text) => {
+				let [min, max] = text_bounds(text);
+				click_targets.push(ClickTarget {
+					subpath: Subpath::new_rect(min, max),
+					stroke_width: 0.,
+				});
+			}
 			GraphicElement::GraphicGroup(graphic_group) => graphic_group.add_click_targets(click_targets),
 			GraphicElement::Artboard(artboard) => artboard.add_click_targets(click_targets),
 		}
 	}
 
+	fn contains_point(&self, point: DVec2) -> bool {
+		match self {
+			GraphicElement::VectorData(vector_data) => vector_data.contains_point(point),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.contains_point(point),
+			GraphicElement::ImageFrame(image_frame) => image_frame.contains_point(point),
+			GraphicElement::Text(text) => Quad::from_box(text_bounds(text)).contains(point),
+			GraphicElement::GraphicGroup(graphic_group) => graphic_group.contains_point(point),
+			GraphicElement::Artboard(artboard) => artboard.contains_point(point),
+		}
+	}
+
+	fn intersects_quad(&self, quad: Quad) -> bool {
+		match self {
+			GraphicElement::VectorData(vector_data) => vector_data.intersects_quad(quad),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.intersects_quad(quad),
+			GraphicElement::ImageFrame(image_frame) => image_frame.intersects_quad(quad),
+			GraphicElement::Text(text) => Quad::from_box(text_bounds(text)).intersects(quad),
+			GraphicElement::GraphicGroup(graphic_group) => graphic_group.intersects_quad(quad),
+			GraphicElement::Artboard(artboard) => artboard.intersects_quad(quad),
+		}
+	}
+
 	fn to_usvg_node(&self) -> usvg::Node {
 		match self {
 			GraphicElement::VectorData(vector_data) => vector_data.to_usvg_node(),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.to_usvg_node(),
 			GraphicElement::ImageFrame(image_frame) => image_frame.to_usvg_node(),
 			GraphicElement::Text(text) => text.to_usvg_node(),
 			GraphicElement::GraphicGroup(graphic_group) => graphic_group.to_usvg_node(),
@@ -564,6 +1709,7 @@ impl GraphicElementRendered for GraphicElement {
 	fn contains_artboard(&self) -> bool {
 		match self {
 			GraphicElement::VectorData(vector_data) => vector_data.contains_artboard(),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.contains_artboard(),
 			GraphicElement::ImageFrame(image_frame) => image_frame.contains_artboard(),
 			GraphicElement::Text(text) => text.contains_artboard(),
 			GraphicElement::GraphicGroup(graphic_group) => graphic_group.contains_artboard(),
@@ -572,6 +1718,55 @@ impl GraphicElementRendered for GraphicElement {
 	}
 }
 
+/// The size of the text rendered by [`render_text`].
+const TEXT_FONT_SIZE: f64 = 14.;
+
+/// Renders the plain text of a text element in a default style (since it doesn't yet carry any formatting), with its baseline starting at the origin.
+fn render_text(text: &str, render: &mut SvgRender, render_params: &RenderParams) {
+	let text = text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+	render.parent_tag(
+		"text",
+		|attributes| {
+			// Text outside of the channel being rendered knocks out what it covers in white
+			attributes.push("fill", if render_params.channel.is_some() { "white" } else { "black" });
+			attributes.push("font-family", "sans-serif");
+			attributes.push("font-size", format!("{TEXT_FONT_SIZE}px"));
+		},
+		|render| render.svg.push(text.into()),
+	);
+}
+
+/// Estimates the bounds of the text rendered by [`render_text`], since the font that the text ends up drawn with isn't known.
+fn text_bounds(text: &str) -> [DVec2; 2] {
+	let width = text.chars().count() as f64 * TEXT_FONT_SIZE * 0.6;
+	[DVec2::new(0., -TEXT_FONT_SIZE), DVec2::new(width, TEXT_FONT_SIZE * 0.25)]
+}
+
+/// Removes the opacity and blend mode from an element so it can be composited separately.
+fn separate_alpha_blending(mut element: GraphicElement) -> (GraphicElement, AlphaBlending) {
+	let alpha_blending = match &mut element {
+		GraphicElement::GraphicGroup(graphic_group) => Some(&mut graphic_group.alpha_blending),
+		GraphicElement::VectorData(vector_data) => Some(&mut vector_data.alpha_blending),
+		GraphicElement::ImageFrame(image_frame) => Some(&mut image_frame.alpha_blending),
+		// Copies that overlap would blend with each other differently if the opacity were applied to them all together
+		GraphicElement::VectorInstances(_) | GraphicElement::Text(_) | GraphicElement::Artboard(_) => None,
+	};
+	let alpha_blending = alpha_blending.map(core::mem::take).unwrap_or_default();
+	(element, alpha_blending)
+}
+
+/// The name of the kind of element, used along with its number to name layers and sprites.
+pub fn element_kind(element: &GraphicElement) -> &'static str {
+	match element {
+		GraphicElement::GraphicGroup(_) => "Group",
+		GraphicElement::VectorData(_) => "Vector",
+		GraphicElement::VectorInstances(_) => "Instances",
+		GraphicElement::ImageFrame(_) => "Image",
+		GraphicElement::Text(_) => "Text",
+		GraphicElement::Artboard(_) => "Artboard",
+	}
+}
+
 /// Used to stop rust complaining about upstream traits adding display implementations to `Option<Color>`. This would not be an issue as we control that crate.
 trait Primitive: core::fmt::Display {}
 impl Primitive for String {}
@@ -722,3 +1917,1004 @@ impl<'a> SvgRenderAttrs<'a> {
 		self.0.svg.push(value.into());
 	}
 }
+
+/// How the view box of the SVG document made by [`ExportSvgNode`] frames the artwork.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum SvgViewBox {
+	/// Fit snugly around the artwork where it is
+	#[default]
+	Content,
+	/// Fit snugly around the artwork, moved so the top left corner of its bounds is at the origin
+	Origin,
+	/// Left out, so the document has no view box or size
+	Omit,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExportSvgNode<Precision, ViewBox, FlattenTransforms, DeduplicateDefs> {
+	precision: Precision,
+	view_box: ViewBox,
+	flatten_transforms: FlattenTransforms,
+	deduplicate_defs: DeduplicateDefs,
+}
+
+/// Renders the artwork into a standalone SVG document, with the numbers in its tags rounded to the precision (a number of decimal places).
+///
+/// Flattening the transforms bakes those of groups and artboards into the coordinates of the shapes inside them rather than writing `transform` attributes,
+/// for tools that don't handle nested transforms. Deduplicating the definitions keeps only one of each identical gradient, clip path, or other definition,
+/// pointing everything that used the others at it.
+#[node_macro::node_fn(ExportSvgNode)]
+fn export_svg<Data: GraphicElementRendered>(data: Data, precision: u32, view_box: SvgViewBox, flatten_transforms: bool, deduplicate_defs: bool) -> String {
+	let render_params = RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, true, true);
+	let [min, max] = data.bounding_box(DAffine2::IDENTITY).unwrap_or_default();
+	let offset = DAffine2::from_translation(if view_box == SvgViewBox::Origin { -min } else { DVec2::ZERO });
+
+	let mut render = SvgRender::new();
+	render.flatten_transforms = flatten_transforms;
+	if flatten_transforms || offset == DAffine2::IDENTITY {
+		render.transform = offset;
+		data.render_svg(&mut render, &render_params);
+	} else {
+		render.parent_tag(
+			"g",
+			|attributes| attributes.push("transform", format_transform_matrix(offset)),
+			|render| data.render_svg(render, &render_params),
+		);
+	}
+
+	let (mut defs, mut body) = (render.svg_defs, render.svg.to_svg_string());
+	if deduplicate_defs {
+		(defs, body) = deduplicate_svg_defs(&defs, &body);
+	}
+
+	let size = max - min;
+	let view_box = match view_box {
+		SvgViewBox::Content => format!(r#" viewBox="{} {} {} {}" width="{}" height="{}""#, min.x, min.y, size.x, size.y, size.x, size.y),
+		SvgViewBox::Origin => format!(r#" viewBox="0 0 {} {}" width="{}" height="{}""#, size.x, size.y, size.x, size.y),
+		SvgViewBox::Omit => String::new(),
+	};
+	let svg = format!(r#"<svg xmlns="http://www.w3.org/2000/svg"{view_box}><defs>{defs}</defs>{body}</svg>"#);
+	round_svg_numbers(&svg, precision)
+}
+
+/// Splits a run of SVG elements, such as the contents of `<defs>`, into its outermost elements.
+fn outermost_elements(svg: &str) -> Vec<&str> {
+	let mut elements = Vec::new();
+	let (mut depth, mut start, mut index) = (0_usize, 0, 0);
+	while let Some(tag_start) = svg[index..].find('<').map(|offset| index + offset) {
+		let Some(tag_end) = svg[tag_start..].find('>').map(|offset| tag_start + offset + 1) else { break };
+		let tag = &svg[tag_start..tag_end];
+		if depth == 0 {
+			start = tag_start;
+		}
+		if tag.starts_with("</") {
+			depth = depth.saturating_sub(1);
+		} else if !tag.ends_with("/>") {
+			depth += 1;
+		}
+		if depth == 0 {
+			elements.push(&svg[start..tag_end]);
+		}
+		index = tag_end;
+	}
+	elements
+}
+
+/// Drops the definitions that are identical to an earlier one apart from their `id`, and points the references to them in the definitions and body at the one that's kept.
+fn deduplicate_svg_defs(defs: &str, body: &str) -> (String, String) {
+	let mut kept: Vec<(String, &str)> = Vec::new();
+	let mut renamed = Vec::new();
+	let mut deduplicated = String::new();
+
+	for element in outermost_elements(defs) {
+		let opening_tag = &element[..element.find('>').unwrap_or(element.len())];
+		let id = opening_tag.split_once(r#" id=""#).and_then(|(_, rest)| rest.split_once('"')).map(|(id, _)| id);
+		let Some(id) = id else {
+			deduplicated.push_str(element);
+			continue;
+		};
+
+		let contents = element.replacen(&format!(r#" id="{id}""#), "", 1);
+		match kept.iter().find(|(kept_contents, _)| *kept_contents == contents) {
+			Some(&(_, kept_id)) => renamed.push((id, kept_id)),
+			None => {
+				kept.push((contents, id));
+				deduplicated.push_str(element);
+			}
+		}
+	}
+
+	let mut body = body.to_string();
+	for (from, to) in renamed {
+		// References end in a closing parenthesis or quote, so an ID isn't mistaken for the start of a longer one
+		for end in [")", "'", "\""] {
+			let (from, to) = (format!("#{from}{end}"), format!("#{to}{end}"));
+			deduplicated = deduplicated.replace(&from, &to);
+			body = body.replace(&from, &to);
+		}
+	}
+	(deduplicated, body)
+}
+
+/// Rounds the decimal numbers inside the SVG's tags to the number of decimal places, dropping trailing zeros. Text between the tags is left as it is.
+fn round_svg_numbers(svg: &str, precision: u32) -> String {
+	let mut result = String::with_capacity(svg.len());
+	let mut in_tag = false;
+	let mut index = 0;
+	while let Some(character) = svg[index..].chars().next() {
+		match character {
+			'<' => in_tag = true,
+			'>' => in_tag = false,
+			_ => {}
+		}
+
+		// Only numbers with a decimal point are changed, so the digits in IDs, colors, and data URLs are left alone
+		if !in_tag || !character.is_ascii_digit() {
+			result.push(character);
+			index += character.len_utf8();
+			continue;
+		}
+
+		let length = svg[index..].find(|character: char| !character.is_ascii_digit() && character != '.').unwrap_or(svg.len() - index);
+		let number = &svg[index..index + length];
+		index += length;
+		match number.parse::<f64>() {
+			Ok(value) if number.contains('.') => {
+				let mut rounded = format!("{value:.*}", precision as usize);
+				if rounded.contains('.') {
+					rounded.truncate(rounded.trim_end_matches('0').trim_end_matches('.').len());
+				}
+				if rounded == "0" && result.ends_with('-') {
+					result.pop();
+				}
+				result.push_str(&rounded);
+			}
+			_ => result.push_str(number),
+		}
+	}
+	result
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::mask::ClipNode;
+	use crate::raster::ColorSpace;
+	use crate::value::ClonedNode;
+	use crate::vector::style::{FillRule, Gradient, GradientInterpolation, GradientType, HueInterpolation, LineCap, LineJoin, Paint, PaintLayer, PathStyle, Pattern};
+	use crate::ReferenceImageNode;
+
+	use glam::IVec2;
+
+	#[test]
+	fn hit_test_fills_and_strokes() {
+		// A filled square with a hole, by the even-odd rule, moved and scaled up
+		let mut square = VectorData::from_subpaths([Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)), Subpath::new_rect(DVec2::splat(4.), DVec2::splat(6.))]);
+		square.style.set_fill(Fill::Solid(Color::RED));
+		square.style.set_fill_rule(FillRule::EvenOdd);
+		square.transform = DAffine2::from_scale_angle_translation(DVec2::splat(2.), 0., DVec2::new(100., 0.));
+		assert!(square.contains_point(DVec2::new(105., 5.)));
+		assert!(!square.contains_point(DVec2::new(110., 10.)));
+		assert!(!square.contains_point(DVec2::new(99., 10.)));
+
+		// The stroke reaches half its width past the path, scaled by the transform
+		square.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.));
+		assert!(square.contains_point(DVec2::new(99., 10.)));
+		assert!(!square.contains_point(DVec2::new(97.5, 10.)));
+		assert!(square.intersects_quad(Quad::from_box([DVec2::new(90., 0.), DVec2::new(99.5, 5.)])));
+		assert!(!square.intersects_quad(Quad::from_box([DVec2::new(90., 0.), DVec2::new(97.5, 5.)])));
+
+		// An unfilled line is only hit along its stroke, even by a quad spanning where its fill would be
+		let mut line = VectorData::from_subpath(Subpath::new_line(DVec2::ZERO, DVec2::new(10., 10.)));
+		line.style.set_stroke(Stroke::new(Some(Color::BLACK), 1.));
+		assert!(line.contains_point(DVec2::new(5., 5.3)));
+		assert!(!line.contains_point(DVec2::new(5., 6.)));
+		assert!(line.intersects_quad(Quad::from_box([DVec2::new(4., 0.), DVec2::new(6., 10.)])));
+		assert!(!line.intersects_quad(Quad::from_box([DVec2::new(6., 0.), DVec2::new(10., 3.)])));
+
+		// Groups place their elements by their transform
+		let mut group = GraphicGroup::from(line);
+		group.transform = DAffine2::from_translation(DVec2::new(0., 100.));
+		assert!(group.contains_point(DVec2::new(5., 105.)));
+		assert!(!group.contains_point(DVec2::new(5., 5.)));
+		assert!(group.intersects_quad(Quad::from_box([DVec2::new(0., 100.), DVec2::new(2., 102.)])));
+	}
+
+	#[test]
+	fn reference_left_out_of_exports() {
+		let image = ImageFrame {
+			image: Image::new(2, 2, Color::RED),
+			transform: DAffine2::from_scale(DVec2::splat(10.)),
+			..Default::default()
+		};
+		let reference_image = |lock_to_artboard| ReferenceImageNode {
+			image: ClonedNode(image.clone()),
+			opacity: ClonedNode(50.),
+			lock_to_artboard: ClonedNode(lock_to_artboard),
+		};
+
+		let document = reference_image(false).eval(GraphicGroup::from(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE))));
+		let GraphicElement::GraphicGroup(reference) = &document[0] else {
+			panic!("The reference should be beneath the artwork")
+		};
+		assert!(reference.reference);
+		assert_eq!(reference.alpha_blending.opacity, 0.5);
+		assert!(!document.contains_point(DVec2::splat(5.)));
+
+		let render = |for_export| {
+			let mut render = SvgRender::new();
+			document.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, for_export));
+			render.svg.to_svg_string()
+		};
+		assert!(render(false).contains("<image"));
+		assert!(!render(true).contains("<image"));
+		let render_params = RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true);
+		assert_eq!(document.render_svg_layers(&render_params).len(), 1);
+
+		// Locked to an artboard, the reference goes beneath the artboard's artwork instead
+		let document = reference_image(true).eval(GraphicGroup::from(Artboard::new(IVec2::new(100, 0), IVec2::splat(50))));
+		let GraphicElement::Artboard(artboard) = &document[0] else {
+			panic!("The artboard should stay on top")
+		};
+		assert!(matches!(&artboard.graphic_group[0], GraphicElement::GraphicGroup(reference) if reference.reference));
+	}
+
+	#[test]
+	fn cull_elements_outside_viewport() {
+		let square = |x: f64| VectorData::from_subpath(Subpath::new_rect(DVec2::new(x, 0.), DVec2::new(x + 10., 10.)));
+		// Only within the viewport because of its stroke
+		let mut stroked = square(110.);
+		stroked.style.set_stroke(Stroke::new(Some(Color::BLACK), 40.));
+
+		let mut nested = GraphicGroup::EMPTY;
+		nested.extend([square(200.).into(), square(400.).into()]);
+		nested.transform = DAffine2::from_translation(DVec2::new(-200., 0.));
+		let mut artboard = Artboard::new(IVec2::ZERO, IVec2::splat(50));
+		artboard.graphic_group.push(square(300.).into());
+
+		let mut document = GraphicGroup::EMPTY;
+		document.extend([square(0.).into(), square(200.).into(), stroked.into(), nested.into(), artboard.into()]);
+
+		let render = |culling_bounds| {
+			let mut render = SvgRender::new();
+			document.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, culling_bounds, false, false, false));
+			render.svg.to_svg_string()
+		};
+		assert_eq!(render(None).matches("<path").count(), 6);
+		// The artboard is kept for its background and label, but not its content outside of the viewport
+		let culled = render(Some([DVec2::ZERO, DVec2::splat(100.)]));
+		assert_eq!(culled.matches("<path").count(), 3);
+		assert!(culled.contains("artboard-bg"));
+	}
+
+	#[test]
+	fn cull_by_subpath_and_hairline_strokes() {
+		let square = |min: f64, size: f64| VectorData::from_subpath(Subpath::new_rect(DVec2::new(min, 0.), DVec2::new(min + size, size)));
+		let renders = |element: GraphicElement, culling_pixel_size: f64| {
+			let mut render_params = RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, Some([DVec2::ZERO, DVec2::splat(100.)]), false, false, false);
+			render_params.culling_pixel_size = culling_pixel_size;
+			!render_params.culls(&element)
+		};
+
+		// Only within the viewport because of the stroke of its subpath's own style
+		let mut styled = square(110., 10.);
+		assert!(!renders(styled.clone().into(), 1.));
+		let mut subpath_style = styled.style.clone();
+		subpath_style.set_stroke(Stroke::new(Some(Color::BLACK), 40.));
+		styled.set_subpath_style(0, subpath_style);
+		assert!(renders(styled.into(), 1.));
+
+		// A hairline scaled up tenfold by its group still only reaches as far as its weight in pixels, out to its miters
+		let mut hairline = square(11., 1.);
+		hairline.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.).with_hairline(true));
+		let mut group = GraphicGroup::from(hairline);
+		group.transform = DAffine2::from_scale(DVec2::splat(10.));
+		assert!(!renders(group.clone().into(), 1.));
+		// Unless the viewport is zoomed out far enough for those pixels to span the gap
+		assert!(renders(group.into(), 10.));
+	}
+
+	#[test]
+	fn clip_and_mask_content() {
+		let mut square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		square.style.set_fill(Fill::Solid(Color::RED));
+		let mut content = GraphicGroup::from(square);
+		content.transform = DAffine2::from_translation(DVec2::new(100., 0.));
+		let render_params = RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, false);
+
+		// Clipped by a shape overlapping the bottom right corner of the square
+		let clip_shape = VectorData::from_subpath(Subpath::new_rect(DVec2::new(105., 5.), DVec2::new(120., 20.)));
+		let clipped = ClipNode { mask: ClonedNode(clip_shape) }.eval(content.clone());
+		assert_eq!(clipped.bounding_box(DAffine2::IDENTITY), Some([DVec2::new(105., 5.), DVec2::new(110., 10.)]));
+		assert!(clipped.contains_point(DVec2::new(107., 7.)));
+		assert!(!clipped.contains_point(DVec2::new(102., 2.)));
+		assert!(!clipped.intersects_quad(Quad::from_box([DVec2::new(100., 0.), DVec2::new(103., 3.)])));
+
+		let mut render = SvgRender::new();
+		clipped.render_svg(&mut render, &render_params);
+		assert!(render.svg_defs.starts_with("<clipPath id=\"mask-"));
+		assert!(render.svg.to_svg_string().contains("clip-path=\"url(#mask-"));
+		let mut eps = EpsRender::new(DAffine2::IDENTITY);
+		clipped.render_eps(&mut eps, &render_params);
+		assert!(eps.eps.starts_with("gsave\nnewpath\n") && eps.eps.contains("clip\n"));
+
+		// Clipped by the even-odd area of a shape with a hole in the middle of the square, which keeps the ring around the hole
+		let mut ring = VectorData::from_subpath(Subpath::new_rect(DVec2::new(100., 0.), DVec2::new(110., 10.)));
+		ring.append_subpath(Subpath::<PointId>::new_rect(DVec2::new(103., 3.), DVec2::new(107., 7.)));
+		ring.style.set_fill_rule(FillRule::EvenOdd);
+		let clipped = ClipNode { mask: ClonedNode(ring) }.eval(content.clone());
+		assert!(clipped.contains_point(DVec2::new(101., 1.)));
+		assert!(!clipped.contains_point(DVec2::new(105., 5.)));
+
+		let mut render = SvgRender::new();
+		clipped.render_svg(&mut render, &render_params);
+		assert!(render.svg_defs.contains(r#"clip-rule="evenodd""#));
+		assert_eq!(render.svg_defs.matches('M').count(), 2);
+		let mut eps = EpsRender::new(DAffine2::IDENTITY);
+		clipped.render_eps(&mut eps, &render_params);
+		let clip = eps.eps.split("eoclip\n").next().unwrap();
+		assert_eq!(clip.matches("moveto").count(), 2);
+		let mut pdf = PdfRender::new(DAffine2::IDENTITY);
+		clipped.render_pdf(&mut pdf, &render_params);
+		let pdf = String::from_utf8_lossy(&pdf.to_pdf_document(DVec2::splat(200.))).into_owned();
+		let clip = pdf.split("W* n\n").next().unwrap();
+		assert_eq!(clip.matches(" m\n").count(), 2);
+
+		// Masked by a grayscale image, black on its left half and white on its right
+		let mut image = Image::new(2, 1, Color::BLACK);
+		image.data[1] = Color::WHITE;
+		let image_frame = ImageFrame {
+			image,
+			transform: DAffine2::from_scale_angle_translation(DVec2::splat(10.), 0., DVec2::new(100., 0.)),
+			..Default::default()
+		};
+		let masked = ClipNode { mask: ClonedNode(image_frame) }.eval(content);
+		assert!(!masked.contains_point(DVec2::new(102., 5.)));
+		assert!(masked.contains_point(DVec2::new(107., 5.)));
+
+		let mut render = SvgRender::new();
+		masked.render_svg(&mut render, &render_params);
+		assert!(render.svg_defs.starts_with("<mask id=\"mask-") && render.svg_defs.contains("<image"));
+		assert!(render.svg.to_svg_string().contains("mask=\"url(#mask-"));
+		assert!(matches!(
+			masked.render_scene(&render_params)[..],
+			[SceneElement::Group {
+				mask: Some(SceneMask::Luminance { width: 2, height: 1, .. }),
+				..
+			}]
+		));
+	}
+
+	#[test]
+	fn render_print_channel() {
+		let square = |x: f64, color: Color| {
+			let mut square = VectorData::from_subpath(Subpath::new_rect(DVec2::new(x, 0.), DVec2::new(x + 10., 10.)));
+			square.style.set_fill(Fill::Solid(color));
+			square
+		};
+		let mut spot = GraphicGroup::from(square(0., Color::RED));
+		spot.channel = Some("Spot".to_string());
+		// Overlapping the spot color on top of it, but outside of its channel
+		let image = ImageFrame {
+			image: Image::new(1, 1, Color::BLUE),
+			transform: DAffine2::from_scale_angle_translation(DVec2::splat(10.), 0., DVec2::new(5., 0.)),
+			..Default::default()
+		};
+		let mut document = GraphicGroup::EMPTY;
+		document.extend([spot.into(), square(5., Color::BLUE).into(), image.into()]);
+
+		let render_params = |channel: Option<&str>| RenderParams {
+			channel: channel.map(str::to_string),
+			..RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true)
+		};
+		let render_svg = |channel| {
+			let mut render = SvgRender::new();
+			document.render_svg(&mut render, &render_params(channel));
+			render.svg.to_svg_string()
+		};
+		let composite = render_svg(None);
+		assert!(composite.contains(r##"fill="#FF0000""##) && composite.contains(r##"fill="#0000FF""##));
+
+		// The content inside of the channel is drawn as it is, while the content outside of it knocks out what it covers in white
+		let separation = render_svg(Some("Spot"));
+		assert!(separation.contains(r##"fill="#FF0000""##));
+		assert!(!separation.contains(r##"fill="#0000FF""##));
+		assert!(separation.find(r##"fill="#FF0000""##).unwrap() < separation.find(r##"fill="#FFFFFF""##).unwrap());
+		assert_eq!(separation.matches("<image").count(), 1);
+		assert_eq!(render_svg(Some("Other")).matches(r##"fill="#FFFFFF""##).count(), 2);
+
+		let mut eps = EpsRender::new(DAffine2::IDENTITY);
+		document.render_eps(&mut eps, &render_params(Some("Spot")));
+		assert!(eps.eps.contains("1 0 0 setrgbcolor fill") && eps.eps.contains("1 1 1 setrgbcolor fill"));
+		assert!(!eps.eps.contains("0 0 1 setrgbcolor"));
+
+		let scene = document.render_scene(&render_params(Some("Spot")));
+		let [SceneElement::Group { children, .. }] = scene.as_slice() else {
+			panic!("Expected the document's group, got {scene:?}");
+		};
+		let fills = children
+			.iter()
+			.flat_map(|child| match child {
+				SceneElement::Group { children, .. } => children.as_slice(),
+				child => std::slice::from_ref(child),
+			})
+			.filter_map(|child| match child {
+				SceneElement::Path { fill, .. } => Some(fill.clone()),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(fills, [ScenePaint::Solid { color: "#FF0000FF".to_string() }, ScenePaint::Solid { color: "#FFFFFFFF".to_string() }]);
+		assert!(matches!(children.last(), Some(SceneElement::Image { .. })));
+	}
+
+	#[test]
+	fn knockout_keeps_partial_alpha() {
+		// Image pixels are premultiplied while fill colors aren't, so each is knocked out in white in its own convention
+		let image = ImageFrame {
+			image: Image::new(1, 1, Color::from_unassociated_alpha(1., 0., 0., 0.5)),
+			..Default::default()
+		};
+		let knockout = image.knockout().image.data[0];
+		assert_eq!(knockout, Color::from_rgbaf32_unchecked(0.5, 0.5, 0.5, 0.5));
+		assert_eq!(knockout.to_unassociated_alpha(), Color::WHITE.with_alpha(0.5));
+
+		let mut square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		square.style.set_fill(Fill::Solid(Color::RED.with_alpha(0.5)));
+		assert_eq!(square.knockout().style.fill(), &Fill::Solid(Color::WHITE.with_alpha(0.5)));
+	}
+
+	#[test]
+	fn render_svg_layers() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		vector_data.alpha_blending = AlphaBlending {
+			opacity: 0.5,
+			blend_mode: BlendMode::Multiply,
+		};
+		let mut artboard = Artboard::new(IVec2::ZERO, IVec2::splat(10));
+		artboard.graphic_group.push(vector_data.clone().into());
+		artboard.graphic_group.push(VectorData::empty().into());
+
+		let mut graphic_group = GraphicGroup::EMPTY;
+		graphic_group.push(artboard.into());
+		graphic_group.push(vector_data.into());
+		graphic_group.alpha_blending.opacity = 0.5;
+
+		let render_params = RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true);
+		let layers = graphic_group.render_svg_layers(&render_params);
+		let names = layers.iter().map(|layer| layer.name.as_str()).collect::<Vec<_>>();
+		assert_eq!(names, ["Artboard 1", "Vector 2", "Vector 3", "Vector 4"]);
+
+		// The opacity of the element and its parent group is multiplied together, but not rendered
+		assert_eq!(layers[1].alpha_blending.opacity, 0.25);
+		assert_eq!(layers[1].alpha_blending.blend_mode, BlendMode::Multiply);
+		assert!(!layers[1].render.svg.to_svg_string().contains("opacity"));
+		// The background is only rendered into the first layer
+		assert!(layers[0].render.svg.to_svg_string().contains("artboard-bg"));
+		assert!(!layers[1].render.svg.to_svg_string().contains("artboard-bg"));
+	}
+
+	#[test]
+	fn render_instances_once() {
+		let geometry = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		let transforms = (0..100).map(|index| DAffine2::from_translation(DVec2::new(index as f64 * 2., 0.))).collect();
+		let instances = VectorInstances::from_transforms(geometry, transforms);
+
+		let mut render = SvgRender::new();
+		instances.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+		let svg = render.svg.to_svg_string();
+
+		// The geometry is only written out once, in the definitions
+		assert_eq!(render.svg_defs.matches("<path").count(), 1);
+		assert_eq!(svg.matches("<use").count(), 100);
+		assert!(!svg.contains("<path"));
+		assert_eq!(GraphicElementRendered::bounding_box(&instances, DAffine2::IDENTITY), Some([DVec2::ZERO, DVec2::new(199., 1.)]));
+	}
+
+	#[test]
+	fn render_fill_rule() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		vector_data.append_subpath(Subpath::<PointId>::new_rect(DVec2::splat(2.), DVec2::splat(8.)));
+		vector_data.style.set_fill(Fill::Solid(Color::BLACK));
+		vector_data.style.set_fill_rule(FillRule::EvenOdd);
+		let render_params = RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true);
+
+		// Each subpath is written once, since a closed one drawn twice would cancel itself out under the even-odd rule and leave the shape empty
+		let mut render = SvgRender::new();
+		vector_data.render_svg(&mut render, &render_params);
+		let svg = render.svg.to_svg_string();
+		assert!(svg.contains(r#"fill-rule="evenodd""#));
+		let d = svg.split(r#" d=""#).nth(1).unwrap().split('"').next().unwrap();
+		assert_eq!(d.matches('M').count(), 2);
+
+		let mut render = EpsRender::new(DAffine2::IDENTITY);
+		vector_data.render_eps(&mut render, &render_params);
+		assert_eq!((render.eps.matches("moveto").count(), render.eps.matches("eofill").count()), (2, 1));
+
+		let mut render = PdfRender::new(DAffine2::IDENTITY);
+		vector_data.render_pdf(&mut render, &render_params);
+		let pdf = String::from_utf8_lossy(&render.to_pdf_document(DVec2::splat(10.))).into_owned();
+		assert_eq!((pdf.matches(" m\n").count(), pdf.matches("f*\n").count()), (2, 1));
+
+		// Nonzero is the default in SVG, so it's left out
+		vector_data.style.set_fill_rule(FillRule::NonZero);
+		let mut render = SvgRender::new();
+		vector_data.render_svg(&mut render, &render_params);
+		assert!(!render.svg.to_svg_string().contains("fill-rule"));
+	}
+
+	#[test]
+	fn render_radial_gradient() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		let render = |vector_data: &VectorData| {
+			let mut render = SvgRender::new();
+			vector_data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+			render.svg_defs
+		};
+
+		let gradient = Gradient::new_radial(DVec2::splat(0.5), 0.5, None, Color::WHITE, Color::BLACK, DAffine2::IDENTITY);
+		assert_eq!((gradient.center(), gradient.radius(), gradient.focal_point()), (DVec2::splat(0.5), 0.5, DVec2::splat(0.5)));
+		vector_data.style.set_fill(Fill::Gradient(gradient.clone()));
+		let defs = render(&vector_data);
+		assert!(defs.contains(r#"cx="5" cy="5" r="5""#));
+		assert!(!defs.contains("fx="));
+
+		// The focal point is placed within the bounds just like the center
+		vector_data.style.set_fill(Fill::Gradient(Gradient {
+			focal_point: Some(DVec2::new(0.25, 0.5)),
+			..gradient
+		}));
+		assert!(render(&vector_data).contains(r#"cx="5" cy="5" r="5" fx="2.5" fy="5""#));
+	}
+
+	#[test]
+	fn render_conic_gradient() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		vector_data
+			.style
+			.set_fill(Fill::Gradient(Gradient::new_conic(DVec2::splat(0.5), 0., Color::RED, Color::BLUE, DAffine2::IDENTITY)));
+		let mut render = SvgRender::new();
+		vector_data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+
+		// The wedges are drawn into a pattern which the path is filled with
+		assert!(render.svg_defs.starts_with("<pattern"));
+		assert_eq!(render.svg_defs.matches("<path").count(), 180);
+		assert!(render.svg_defs.contains(r#"<path d="M5,5 L19.14213562373095,5"#));
+		assert!(render.svg.to_svg_string().contains("fill=\"url('#"));
+	}
+
+	#[test]
+	fn render_gradient_interpolation() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		let mut gradient = Gradient::new(DVec2::ZERO, Color::RED, DVec2::X, Color::BLUE, DAffine2::IDENTITY, GradientType::Linear);
+		let render = |vector_data: &VectorData| {
+			let mut render = SvgRender::new();
+			vector_data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+			render.svg_defs
+		};
+
+		vector_data.style.set_fill(Fill::Gradient(gradient.clone()));
+		assert_eq!(render(&vector_data).matches("<stop").count(), 2);
+
+		// Since SVG blends between stops in sRGB, other color spaces are drawn with extra stops in between
+		gradient.interpolation = GradientInterpolation::Oklab;
+		vector_data.style.set_fill(Fill::Gradient(gradient.clone()));
+		assert_eq!(render(&vector_data).matches("<stop").count(), 17);
+		assert_eq!(gradient.interpolated_stops()[8].1, gradient.evaluate(0.5));
+
+		// In LCH, red turns to blue through magenta the short way around the color wheel, and through green the long way
+		gradient.interpolation = GradientInterpolation::Lch;
+		let shorter = gradient.evaluate(0.5);
+		gradient.hue_interpolation = HueInterpolation::Longer;
+		let longer = gradient.evaluate(0.5);
+		assert!(shorter.r() > shorter.g() && shorter.b() > shorter.g());
+		assert!(longer.g() > shorter.g());
+
+		// A gray keeps the hue of the color it's blended with
+		let towards_gray = gradient.mix(Color::RED, Color::from_rgbf32_unchecked(0.5, 0.5, 0.5), 0.5);
+		assert!(towards_gray.r() > towards_gray.g() && towards_gray.r() > towards_gray.b());
+	}
+
+	#[test]
+	fn render_wide_gamut_color() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		let render = |vector_data: &VectorData| {
+			let mut render = SvgRender::new();
+			vector_data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+			render.svg.to_svg_string()
+		};
+
+		vector_data.style.set_fill(Fill::Solid(Color::RED));
+		assert!(render(&vector_data).contains(r##"fill="#FF0000""##));
+
+		// The red of Display P3 lies outside of sRGB, so it's kept as extended-range channels and drawn in Display P3
+		let p3_red = Color::from_color_space(ColorSpace::DisplayP3, 1., 0., 0., 1.).to_gamma_srgb();
+		assert!(p3_red.r() > 1. && p3_red.g() < 0. && p3_red.b() < 0.);
+		vector_data.style.set_fill(Fill::Solid(p3_red));
+		assert!(render(&vector_data).contains(r#"fill="color(display-p3 1.0000 "#));
+
+		// Beyond Display P3, the color is drawn in linear sRGB with its channels outside of 0 to 1
+		let rec2020_green = Color::from_color_space(ColorSpace::LinearRec2020, 0., 1., 0., 1.).to_gamma_srgb();
+		vector_data.style.set_fill(Fill::Solid(rec2020_green));
+		assert!(render(&vector_data).contains(r#"fill="color(srgb-linear -0.5876 1.1329 -0.1006)""#));
+	}
+
+	#[test]
+	fn render_stroke_gradient() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_line(DVec2::ZERO, DVec2::new(100., 0.)));
+		let gradient = Gradient {
+			positions: vec![(0., Color::TRANSPARENT), (1., Color::RED)],
+			..Default::default()
+		};
+		let stroke = Stroke::new(Some(Color::BLACK), 4.).with_dash_lengths("10").unwrap().with_gradient(Some(gradient));
+		vector_data.style.set_stroke(stroke.clone());
+
+		let subpath = vector_data.stroke_bezier_paths().next().unwrap();
+		let pieces = stroke.gradient_pieces(&subpath, DAffine2::from_scale(DVec2::splat(2.)));
+		assert_eq!(pieces.len(), 100);
+		// Each piece ends where the next begins, and continues the dashes from where the one before it ended
+		for (piece, next) in pieces.iter().zip(&pieces[1..]) {
+			assert!(piece.0.iter().last().unwrap().end.abs_diff_eq(next.0.iter().next().unwrap().start, 1e-6));
+		}
+		assert!(pieces[1].0.iter().next().unwrap().start.abs_diff_eq(DVec2::new(2., 0.), 1e-6));
+		assert!(pieces[1].0.iter().last().unwrap().end.abs_diff_eq(DVec2::new(4., 0.), 1e-6));
+		assert!((pieces[1].1.dash_offset - 2.).abs() < 1e-6);
+		assert!(pieces[99].0.iter().last().unwrap().end.abs_diff_eq(DVec2::new(200., 0.), 1e-6));
+		assert_eq!(pieces[99].1.color, Some(Color::TRANSPARENT.lerp(&Color::RED, 0.995)));
+
+		// The number of pieces follows the length of the path, up to a limit
+		assert_eq!(stroke.gradient_pieces(&subpath, DAffine2::from_scale(DVec2::splat(0.01))).len(), 1);
+		assert_eq!(stroke.gradient_pieces(&subpath, DAffine2::from_scale(DVec2::splat(5.))).len(), 250);
+		assert_eq!(stroke.gradient_pieces(&subpath, DAffine2::from_scale(DVec2::splat(1000.))).len(), 1000);
+
+		let mut render = SvgRender::new();
+		vector_data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+		let svg = render.svg.to_svg_string();
+		assert!(svg.trim_start().starts_with("<g>"));
+		assert_eq!(svg.matches("<path").count(), 101);
+		assert_eq!(svg.matches("stroke=").count(), 100);
+	}
+
+	#[test]
+	fn render_pattern_fill() {
+		let mut tile = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(5.)));
+		tile.style.set_fill(Fill::Solid(Color::RED));
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(100.)));
+		vector_data.style.set_fill(Fill::Pattern(Box::new(Pattern::new(tile, DVec2::splat(10.), DAffine2::from_angle(0.5)))));
+
+		let mut render = SvgRender::new();
+		render.transform = DAffine2::from_translation(DVec2::new(3., 0.));
+		vector_data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+
+		// The grid of tiles follows the shape as it's moved
+		let pattern_transform = (DAffine2::from_translation(DVec2::new(3., 0.)) * DAffine2::from_angle(0.5))
+			.to_cols_array()
+			.map(|entry| entry.to_string())
+			.join(",");
+		assert!(render.svg_defs.starts_with(r#"<pattern id=""#));
+		assert!(render
+			.svg_defs
+			.contains(&format!(r#"patternUnits="userSpaceOnUse" width="10" height="10" patternTransform="matrix({pattern_transform})">"#)));
+		assert!(render.svg_defs.contains(r##"fill="#FF0000""##));
+		assert!(render.svg.to_svg_string().contains("fill=\"url('#"));
+	}
+
+	#[test]
+	fn render_paint_layers() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		vector_data.style.set_fill(Fill::Solid(Color::RED));
+		let outer_stroke = AlphaBlending {
+			opacity: 0.5,
+			blend_mode: BlendMode::Multiply,
+		};
+		vector_data
+			.style
+			.insert_paint_layer(-1, PaintLayer::new(Paint::Stroke(Stroke::new(Some(Color::BLACK), 4.)), outer_stroke));
+		vector_data
+			.style
+			.insert_paint_layer(-1, PaintLayer::new(Paint::Stroke(Stroke::new(Some(Color::WHITE), 1.)), AlphaBlending::default()));
+
+		let mut render = SvgRender::new();
+		vector_data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+		let svg = render.svg.to_svg_string();
+
+		// The shape is drawn once with its own fill, and again for each layer in the order of the stack
+		assert!(svg.trim_start().starts_with("<g>"));
+		assert_eq!(svg.matches("<path").count(), 3);
+		let fill = svg.find(r##"fill="#FF0000""##).unwrap();
+		let black = svg.find(r##"stroke="#000000""##).unwrap();
+		let white = svg.find(r##"stroke="#FFFFFF""##).unwrap();
+		assert!(fill < black && black < white);
+		assert!(svg.contains(r#"opacity="0.5""#));
+	}
+
+	#[test]
+	fn render_subpath_styles() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		for offset in [20., 40.] {
+			vector_data.append_subpath(Subpath::<PointId>::new_rect(DVec2::splat(offset), DVec2::splat(offset + 10.)));
+		}
+		vector_data.style.set_fill(Fill::Solid(Color::BLUE));
+		vector_data.set_subpath_style(1, PathStyle::new(None, Fill::Solid(Color::RED)));
+
+		// Neighboring subpaths with the same style would be drawn together, but here the styled one sits between the others
+		let mut render = SvgRender::new();
+		vector_data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+		let svg = render.svg.to_svg_string();
+		assert_eq!(svg.matches("<path").count(), 3);
+		let red = svg.find(r##"fill="#FF0000""##).unwrap();
+		assert!(svg.find(r##"fill="#0000FF""##).unwrap() < red && red < svg.rfind(r##"fill="#0000FF""##).unwrap());
+	}
+
+	#[test]
+	fn render_separate_opacity() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		vector_data.style.set_fill(Fill::Solid(Color::RED));
+		vector_data.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.));
+		vector_data.style.set_fill_opacity(0.25);
+
+		let mut render = SvgRender::new();
+		vector_data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+		let svg = render.svg.to_svg_string();
+
+		// The fill is drawn with its own opacity under the opaque stroke, keeping the colors as they are
+		assert_eq!(svg.matches("<path").count(), 2);
+		assert_eq!(svg.matches("opacity=").count(), 1);
+		let fill = svg.find(r#"opacity="0.25""#).unwrap();
+		assert!(fill < svg.find(r##"stroke="#000000""##).unwrap());
+		assert!(svg.contains(r##"fill="#FF0000""##));
+	}
+
+	#[test]
+	fn render_stroke_alignment() {
+		let render_params = RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true);
+		let render_svg = |vector_data: &VectorData| {
+			let mut render = SvgRender::new();
+			vector_data.render_svg(&mut render, &render_params);
+			(render.svg.to_svg_string(), render.svg_defs)
+		};
+
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		vector_data.style.set_fill(Fill::Solid(Color::RED));
+		vector_data.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.).with_alignment(StrokeAlignment::Inside));
+
+		// The inside stroke is drawn over the fill at twice its weight, clipped to the shape
+		let (svg, defs) = render_svg(&vector_data);
+		assert_eq!(svg.matches("<path").count(), 2);
+		assert!(svg.find(r##"fill="#FF0000""##).unwrap() < svg.find(r#"stroke-width="4""#).unwrap());
+		assert!(svg.contains(r#"clip-path="url(#stroke-"#));
+		assert!(defs.starts_with("<clipPath"));
+
+		// The outside stroke is masked to leave out the shape, with room around it for the whole stroke
+		vector_data.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.).with_alignment(StrokeAlignment::Outside));
+		let (svg, defs) = render_svg(&vector_data);
+		assert!(svg.contains(r#"mask="url(#stroke-"#));
+		assert!(defs.contains(r#"x="-8" y="-8" width="26" height="26""#));
+
+		// Open paths have no inside or outside, so their stroke stays centered on the path
+		let mut line = VectorData::from_subpath(Subpath::from_anchors([DVec2::ZERO, DVec2::splat(10.)], false));
+		line.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.).with_alignment(StrokeAlignment::Inside));
+		let (svg, defs) = render_svg(&line);
+		assert_eq!(svg.matches("<path").count(), 1);
+		assert!(svg.contains(r#"stroke-width="2""#));
+		assert!(defs.is_empty());
+	}
+
+	#[test]
+	fn render_tapered_stroke() {
+		let mut line = VectorData::from_subpath(Subpath::from_anchors([DVec2::ZERO, DVec2::new(100., 0.)], false));
+		let stroke = Stroke::new(Some(Color::BLACK), 10.).with_width_profile(vec![(0., 0.), (1., 1.)]);
+		assert_eq!(stroke.weight_at(0.5), 5.);
+		line.style.set_stroke(stroke.clone());
+
+		// The stroke is drawn as its outline filled with its paint, widening from a point at the start to the whole weight at the end
+		let mut render = SvgRender::new();
+		line.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+		let svg = render.svg.to_svg_string();
+		assert!(svg.contains(r##"fill="#000000""##));
+		assert!(!svg.contains("stroke-width"));
+
+		let outline = stroke.tapered_outline(&line.stroke_bezier_paths().next().unwrap(), DAffine2::IDENTITY);
+		let [min, max] = outline[0].bounding_box().unwrap();
+		assert!(min.abs_diff_eq(DVec2::new(0., -5.), 1e-9) && max.abs_diff_eq(DVec2::new(100., 5.), 1e-9));
+
+		// A closed path is outlined by an outer and an inner ring
+		let square = Subpath::<PointId>::new_rect(DVec2::ZERO, DVec2::splat(100.));
+		assert_eq!(stroke.tapered_outline(&square, DAffine2::IDENTITY).len(), 2);
+	}
+
+	#[test]
+	fn render_corner_aligned_dashes() {
+		let square = Subpath::<PointId>::new_rect(DVec2::ZERO, DVec2::splat(100.));
+		let stroke = Stroke::new(Some(Color::BLACK), 2.).with_dash_lengths("10").unwrap().with_dashes_align_to_corners(true);
+
+		// Each side fits five periods of the pattern, with half a dash at either end joined to its neighbors' around the corners
+		let dashes = stroke.corner_aligned_dashes(&square, DAffine2::IDENTITY);
+		assert_eq!(dashes.len(), 20);
+		let corner = DVec2::new(100., 0.);
+		assert!(dashes.iter().any(|dash| dash.anchors().iter().any(|anchor| anchor.abs_diff_eq(corner, 1e-6))));
+
+		// Weight-relative dashes are multiples of the weight
+		let scaled = stroke.clone().with_dashes_scale_with_weight(true);
+		assert_eq!(scaled.scaled_dash_lengths(), vec![20.]);
+
+		// The dashes are drawn as subpaths of their own without a dash pattern
+		let mut vector_data = VectorData::from_subpath(square);
+		vector_data.style.set_stroke(stroke.with_dash_cap(Some(LineCap::Round)));
+		let mut render = SvgRender::new();
+		vector_data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+		let svg = render.svg.to_svg_string();
+		assert!(svg.contains(r#"stroke-dasharray="none""#));
+		assert!(svg.contains(r#"stroke-linecap="round""#));
+	}
+
+	#[test]
+	fn render_stroke_properties_alike() {
+		// An odd number of dash lengths, a negative dash offset, and a miter limit below 1 are drawn the same way by every renderer
+		let mut vector_data = VectorData::from_subpath(Subpath::new_line(DVec2::ZERO, DVec2::new(100., 0.)));
+		let stroke = Stroke::new(Some(Color::BLACK), 2.)
+			.with_dash_lengths("10, 5, 5")
+			.unwrap()
+			.with_dash_offset(-5.)
+			.with_line_cap(LineCap::Round)
+			.with_line_join(LineJoin::Bevel)
+			.with_line_join_miter_limit(0.5);
+		vector_data.style.set_stroke(stroke.clone());
+		assert_eq!(stroke.drawn_dash_lengths(), [10., 5., 5., 10., 5., 5.]);
+		assert_eq!(stroke.drawn_dash_offset(), 35.);
+		let render_params = RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true);
+
+		let mut render = SvgRender::new();
+		vector_data.render_svg(&mut render, &render_params);
+		let svg = render.svg.to_svg_string();
+		assert!(svg.contains(r#"stroke-dasharray="10, 5, 5, 10, 5, 5" stroke-dashoffset="35" stroke-linecap="round" stroke-linejoin="bevel" stroke-miterlimit="1""#));
+
+		let mut render = EpsRender::new(DAffine2::IDENTITY);
+		vector_data.render_eps(&mut render, &render_params);
+		assert!(render.eps.contains("1 setlinecap 2 setlinejoin 1 setmiterlimit\n[10 5 5 10 5 5] 35 setdash"));
+
+		let mut render = PdfRender::new(DAffine2::IDENTITY);
+		vector_data.render_pdf(&mut render, &render_params);
+		let pdf = String::from_utf8_lossy(&render.to_pdf_document(DVec2::splat(100.))).into_owned();
+		assert!(pdf.contains("2 w 1 J 2 j 1 M\n[10 5 5 10 5 5] 35 d"));
+
+		let [SceneElement::Path { stroke: Some(scene_stroke), .. }] = vector_data.render_scene(&render_params).as_slice() else {
+			panic!("The line should be a single stroked path")
+		};
+		assert_eq!((scene_stroke.dash_lengths.len(), scene_stroke.dash_offset, scene_stroke.miter_limit), (6, 35., 1.));
+
+		// Like in SVG, a dash pattern with a negative length is drawn solid, which formats that reject it are never given
+		vector_data.style.set_stroke(Stroke {
+			dash_lengths: vec![10., -5.],
+			..stroke
+		});
+		let mut render = EpsRender::new(DAffine2::IDENTITY);
+		vector_data.render_eps(&mut render, &render_params);
+		assert!(render.eps.contains("[] 0 setdash"));
+		let mut render = SvgRender::new();
+		vector_data.render_svg(&mut render, &render_params);
+		assert!(render.svg.to_svg_string().contains(r#"stroke-dasharray="none" stroke-dashoffset="0" stroke-linecap="round""#));
+	}
+
+	#[test]
+	fn export_svg_options() {
+		let gradient = Gradient::new(DVec2::new(0., 0.5), Color::RED, DVec2::new(1., 0.5), Color::BLUE, DAffine2::IDENTITY, GradientType::Linear);
+		let mut shape = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10. / 3.)));
+		shape.style.set_fill(Fill::Gradient(gradient));
+		let mut group = GraphicGroup::EMPTY;
+		group.push(shape.clone().into());
+		group.push(shape.into());
+		group.transform = DAffine2::from_translation(DVec2::new(5., 5.));
+
+		let export = |precision, view_box, flatten_transforms, deduplicate_defs| {
+			ExportSvgNode {
+				precision: ClonedNode(precision),
+				view_box: ClonedNode(view_box),
+				flatten_transforms: ClonedNode(flatten_transforms),
+				deduplicate_defs: ClonedNode(deduplicate_defs),
+			}
+			.eval(group.clone())
+		};
+
+		// The shared gradient is defined once, and the group's transform is baked into the path with its numbers rounded
+		let svg = export(2, SvgViewBox::Content, true, true);
+		assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="5 5 3.33 3.33" width="3.33" height="3.33">"#));
+		assert_eq!(svg.matches("<linearGradient").count(), 1);
+		assert!(!svg.contains("matrix(1, 0, 0, 1, 5, 5)"));
+		assert!(!svg.contains("3.333"));
+
+		let svg = export(6, SvgViewBox::Origin, false, false);
+		assert!(svg.contains(r#"viewBox="0 0 3.333333 3.333333""#));
+		assert_eq!(svg.matches("<linearGradient").count(), 2);
+		assert!(svg.contains(r#"transform="matrix(1, 0, 0, 1, -5, -5)""#));
+
+		assert!(!export(3, SvgViewBox::Omit, false, true).contains("viewBox"));
+	}
+
+	#[test]
+	fn round_svg_number_attributes() {
+		let svg = r##"<path id="stroke-12" d="M0.12345,-0.0001 L10.50000,3" fill="#1A2B3C"/><text>3.14159</text>"##;
+		assert_eq!(round_svg_numbers(svg, 2), r##"<path id="stroke-12" d="M0.12,0 L10.5,3" fill="#1A2B3C"/><text>3.14159</text>"##);
+	}
+
+	#[test]
+	fn render_hairline_stroke() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		vector_data.transform = DAffine2::from_scale(DVec2::splat(4.));
+		vector_data.style.set_stroke(Stroke::new(Some(Color::BLACK), 1.));
+		let render_params = RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true);
+		let render_svg = |vector_data: &VectorData| {
+			let mut render = SvgRender::new();
+			vector_data.render_svg(&mut render, &render_params);
+			render.svg.to_svg_string()
+		};
+		assert!(!render_svg(&vector_data).contains("vector-effect"));
+
+		// The weight is left unscaled by the transform, and by the zoom applied to the whole SVG
+		vector_data.style.set_stroke(Stroke::new(Some(Color::BLACK), 1.).with_hairline(true));
+		let svg = render_svg(&vector_data);
+		assert!(svg.contains(r#"stroke-width="1""#));
+		assert!(svg.contains(r#"vector-effect="non-scaling-stroke""#));
+
+		let scene = vector_data.render_scene(&render_params);
+		assert!(matches!(&scene[0], SceneElement::Path { stroke: Some(stroke), .. } if stroke.hairline));
+	}
+
+	#[test]
+	fn render_gradient_stroke() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		let gradient = Gradient::new(DVec2::new(0., 0.5), Color::RED, DVec2::new(1., 0.5), Color::BLUE, DAffine2::IDENTITY, GradientType::Linear);
+		vector_data.style.set_stroke(Stroke::new(None, 2.).with_paint(Fill::Gradient(gradient)));
+
+		let mut render = SvgRender::new();
+		vector_data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+
+		// The stroke references a gradient in the defs in the same way as a gradient fill
+		assert!(render.svg_defs.starts_with("<linearGradient"));
+		assert!(render.svg.to_svg_string().contains("stroke=\"url('#"));
+		assert_eq!(vector_data.style.stroke().unwrap().color(), Some(Color::RED));
+	}
+
+	#[test]
+	fn render_scene() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		vector_data.style.set_fill(Fill::Solid(Color::RED));
+		vector_data.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.));
+		vector_data.transform = DAffine2::from_translation(DVec2::new(5., 0.));
+		vector_data.alpha_blending.blend_mode = BlendMode::Multiply;
+		let mut artboard = Artboard::new(IVec2::new(10, 20), IVec2::splat(100));
+		artboard.graphic_group.push(vector_data.into());
+		artboard.graphic_group.push(GraphicElement::Text("Hello".to_string()));
+
+		let render_params = RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true);
+		let scene = Scene::new(&artboard, &render_params, DAffine2::IDENTITY, DVec2::splat(100.));
+		assert_eq!(scene.version, SCENE_SCHEMA_VERSION);
+
+		let [SceneElement::Artboard { transform, children, background, .. }] = scene.elements.as_slice() else {
+			panic!("Expected a single artboard, got {:?}", scene.elements);
+		};
+		assert_eq!(*transform, [1., 0., 0., 1., 10., 20.]);
+		assert_eq!(background.as_deref(), Some("#FFFFFFFF"));
+		let [SceneElement::Path {
+			transform,
+			blend_mode,
+			path,
+			bounds,
+			fill,
+			stroke,
+			..
+		}, SceneElement::Text { text, .. }] = children.as_slice()
+		else {
+			panic!("Expected a path and text, got {children:?}");
+		};
+		// The geometry stays in its own space, placed by the transform
+		assert_eq!(*transform, [1., 0., 0., 1., 5., 0.]);
+		assert_eq!(*bounds, [0., 0., 10., 10.]);
+		// The closed rectangle is written once rather than once as a region and again as a stroked subpath
+		assert_eq!(path.matches('M').count(), 1);
+		assert_eq!(blend_mode, "multiply");
+		assert_eq!(*fill, ScenePaint::Solid { color: "#FF0000FF".to_string() });
+		assert_eq!(stroke.as_ref().map(|stroke| stroke.width), Some(2.));
+		assert_eq!(text, "Hello");
+	}
+}
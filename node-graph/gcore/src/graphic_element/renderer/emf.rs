@@ -0,0 +1,529 @@
+use crate::mask::Mask;
+use crate::raster::ImageFrame;
+use crate::vector::style::{Fill, FillRule, LineCap, LineJoin, Stroke, StrokeAlignment};
+use crate::vector::{PointId, VectorData};
+use crate::{Artboard, Color};
+
+use bezier_rs::{BezierHandles, Subpath};
+use glam::{DAffine2, DVec2};
+
+const EMR_HEADER: u32 = 1;
+const EMR_POLYBEZIERTO: u32 = 5;
+const EMR_SETWINDOWEXTEX: u32 = 9;
+const EMR_SETVIEWPORTEXTEX: u32 = 11;
+const EMR_EOF: u32 = 14;
+const EMR_SETMAPMODE: u32 = 17;
+const EMR_SETPOLYFILLMODE: u32 = 19;
+const EMR_MOVETOEX: u32 = 27;
+const EMR_INTERSECTCLIPRECT: u32 = 30;
+const EMR_SAVEDC: u32 = 33;
+const EMR_RESTOREDC: u32 = 34;
+const EMR_SELECTOBJECT: u32 = 37;
+const EMR_CREATEBRUSHINDIRECT: u32 = 39;
+const EMR_DELETEOBJECT: u32 = 40;
+const EMR_LINETO: u32 = 54;
+const EMR_SETMITERLIMIT: u32 = 58;
+const EMR_BEGINPATH: u32 = 59;
+const EMR_ENDPATH: u32 = 60;
+const EMR_CLOSEFIGURE: u32 = 61;
+const EMR_FILLPATH: u32 = 62;
+const EMR_STROKEPATH: u32 = 64;
+const EMR_SELECTCLIPPATH: u32 = 67;
+const EMR_STRETCHDIBITS: u32 = 81;
+const EMR_EXTCREATEPEN: u32 = 95;
+
+const MM_ANISOTROPIC: u32 = 8;
+const ALTERNATE: u32 = 1;
+const RGN_AND: u32 = 1;
+const WINDING: u32 = 2;
+const NULL_BRUSH: u32 = 0x8000_0005;
+const NULL_PEN: u32 = 0x8000_0008;
+const PS_GEOMETRIC: u32 = 0x0001_0000;
+const PS_USERSTYLE: u32 = 0x0000_0007;
+const SRCCOPY: u32 = 0x00CC_0020;
+
+/// Index of the brush and pen in the object table, which are deleted right after each use so the indices can be reused.
+const BRUSH_INDEX: u32 = 1;
+const PEN_INDEX: u32 = 2;
+/// Logical coordinates are integers, so they are stored at a multiple of the pixel resolution to preserve subpixel precision.
+const SUBPIXELS: f64 = 16.;
+/// The reference device of the document is a 96 DPI display, so that a pixel in Graphite is a pixel when the file is placed at its original size.
+const REFERENCE_DEVICE_PIXELS: i32 = 960;
+const REFERENCE_DEVICE_MILLIMETERS: i32 = 254;
+
+/// Mutable state used whilst rendering to an Enhanced Metafile, which office suites accept as a scalable vector image.
+///
+/// Metafiles have no concept of transparency, so opacity, blend modes, and the alpha channel of colors are ignored, except for skipping fully transparent paint.
+/// Gradients are approximated by solid paint with the average color of their stops, and patterns and images are drawn copy by copy, clipped to the shape.
+pub struct EmfRender {
+	records: Vec<u8>,
+	record_count: u32,
+	/// The transform from the coordinate space of the content being rendered to the page, whose origin is the top left corner.
+	pub transform: DAffine2,
+}
+
+impl EmfRender {
+	pub fn new(transform: DAffine2) -> Self {
+		Self {
+			records: Vec::new(),
+			record_count: 0,
+			transform,
+		}
+	}
+
+	/// Wraps the rendered records into an EMF document with a page of the given size in pixels.
+	pub fn to_emf_document(&self, size: DVec2) -> Vec<u8> {
+		let [width, height] = [size.x, size.y].map(|length| length.ceil().max(1.) as i32);
+		let frame = |pixels: i32| (pixels as f64 * 2540. / 96.).round() as i32;
+
+		let mut setup = Self::new(DAffine2::IDENTITY);
+		setup.record(EMR_SETMAPMODE, &[MM_ANISOTROPIC]);
+		setup.record(EMR_SETWINDOWEXTEX, &[logical(width as f64) as u32, logical(height as f64) as u32]);
+		setup.record(EMR_SETVIEWPORTEXTEX, &[width as u32, height as u32]);
+		setup.record(EMR_SETPOLYFILLMODE, &[WINDING]);
+
+		const HEADER_SIZE: u32 = 108;
+		const EOF_SIZE: u32 = 20;
+		let bytes = HEADER_SIZE + setup.records.len() as u32 + self.records.len() as u32 + EOF_SIZE;
+		let records = 1 + setup.record_count + self.record_count + 1;
+
+		let mut document = Vec::with_capacity(bytes as usize);
+		let mut header = Vec::new();
+		// Bounds in device pixels, inclusive, followed by the frame in hundredths of a millimeter
+		push_i32s(&mut header, &[0, 0, width - 1, height - 1]);
+		push_i32s(&mut header, &[0, 0, frame(width), frame(height)]);
+		// Signature " EMF" and version
+		push_u32s(&mut header, &[0x464D_4520, 0x0001_0000, bytes, records]);
+		// Number of object table entries (the zeroth entry is reserved) and a reserved field, packed into one value
+		push_u32s(&mut header, &[PEN_INDEX + 1]);
+		// Description and palette, which are both empty
+		push_u32s(&mut header, &[0, 0, 0]);
+		push_i32s(
+			&mut header,
+			&[REFERENCE_DEVICE_PIXELS, REFERENCE_DEVICE_PIXELS, REFERENCE_DEVICE_MILLIMETERS, REFERENCE_DEVICE_MILLIMETERS],
+		);
+		// Pixel format and OpenGL, which are both unused
+		push_u32s(&mut header, &[0, 0, 0]);
+		push_i32s(&mut header, &[REFERENCE_DEVICE_MILLIMETERS * 1000, REFERENCE_DEVICE_MILLIMETERS * 1000]);
+		push_u32s(&mut document, &[EMR_HEADER, HEADER_SIZE]);
+		document.extend(header);
+
+		document.extend(&setup.records);
+		document.extend(&self.records);
+		push_u32s(&mut document, &[EMR_EOF, EOF_SIZE, 0, 16, EOF_SIZE]);
+		document
+	}
+
+	/// Fills the page, which spans from the origin to the given size, with a solid color.
+	pub fn fill_page(&mut self, size: DVec2, color: Color) {
+		self.fill(&[Subpath::<PointId>::new_rect(DVec2::ZERO, size)], DAffine2::IDENTITY, color);
+	}
+
+	/// Renders the content with an additional transform applied to it.
+	pub fn with_transform(&mut self, transform: DAffine2, content: impl FnOnce(&mut Self)) {
+		let parent_transform = self.transform;
+		self.transform = parent_transform * transform;
+		content(self);
+		self.transform = parent_transform;
+	}
+
+	/// Renders the content clipped to the area of the mask, if there is one. Raster masks are clipped to by their rectangle, since metafiles can't follow their luminance.
+	pub fn with_mask(&mut self, mask: Option<&Mask>, content: impl FnOnce(&mut Self)) {
+		let Some(mask) = mask else { return content(self) };
+		let (subpaths, transform, fill_rule) = mask.clip_area();
+
+		// Restoring the state afterwards also removes the clipping path and resets the fill mode
+		self.record(EMR_SAVEDC, &[]);
+		if fill_rule == FillRule::EvenOdd {
+			self.record(EMR_SETPOLYFILLMODE, &[ALTERNATE]);
+		}
+		// Nothing is kept by an empty mask
+		if self.path(&subpaths, self.transform * transform).is_some() {
+			self.record(EMR_SELECTCLIPPATH, &[RGN_AND]);
+			content(self);
+		}
+		self.record(EMR_RESTOREDC, &[-1_i32 as u32]);
+	}
+
+	pub fn vector_data(&mut self, vector_data: &VectorData) {
+		let transform = self.transform * vector_data.transform;
+
+		match vector_data.style.fill() {
+			Fill::Pattern(pattern) => {
+				let tiles = vector_data.bounding_box().map(|bounds| pattern.tile_transforms(bounds)).unwrap_or_default();
+				self.clip_to_regions(vector_data, tiles, |render| render.vector_data(&pattern.tile));
+			}
+			Fill::Image(image_fill) => {
+				let images = vector_data.bounding_box().map(|bounds| image_fill.image_transforms(bounds)).unwrap_or_default();
+				let image_frame = ImageFrame {
+					transform: DAffine2::IDENTITY,
+					..image_fill.image.clone()
+				};
+				self.clip_to_regions(vector_data, images, |render| render.image_frame(&image_frame));
+			}
+			_ => {}
+		}
+		if let Some(color) = solid_color(vector_data.style.fill()).filter(|color| color.a() > 0.) {
+			let regions = vector_data.region_bezier_paths().map(|(_, subpath)| subpath).collect::<Vec<_>>();
+			// Alternate is the even-odd rule, which is switched back to the nonzero winding rule the document is set up with once the path is filled
+			let even_odd = vector_data.style.fill_rule() == FillRule::EvenOdd;
+			if even_odd {
+				self.record(EMR_SETPOLYFILLMODE, &[ALTERNATE]);
+			}
+			self.fill(&regions, transform, color);
+			if even_odd {
+				self.record(EMR_SETPOLYFILLMODE, &[WINDING]);
+			}
+		}
+
+		let Some(mut stroke) = vector_data.style.stroke().filter(|stroke| stroke.weight > 0.) else {
+			return;
+		};
+		let subpaths = vector_data.stroke_bezier_paths().collect::<Vec<_>>();
+
+		let alignment = super::stroke_alignment(vector_data);
+		if alignment != StrokeAlignment::Center {
+			// The doubled stroke is clipped to the inside of the shape, or to the alternate area between a rectangle around it and the shape for the outside
+			let mut outline = subpaths.clone();
+			let even_odd = if alignment == StrokeAlignment::Inside {
+				vector_data.style.fill_rule() == FillRule::EvenOdd
+			} else {
+				let [min, max] = super::outside_stroke_bounds(vector_data, &stroke, transform);
+				let mut rectangle = Subpath::new_rect(min, max);
+				rectangle.apply_transform(transform.inverse());
+				outline.push(rectangle);
+				true
+			};
+
+			// Restoring the state after the stroke also removes the clipping path and resets the fill mode
+			self.record(EMR_SAVEDC, &[]);
+			if even_odd {
+				self.record(EMR_SETPOLYFILLMODE, &[ALTERNATE]);
+			}
+			if self.path(&outline, transform).is_some() {
+				self.record(EMR_SELECTCLIPPATH, &[RGN_AND]);
+			}
+			stroke = super::aligned_stroke(&stroke);
+		}
+
+		let strokes = if stroke.gradient.is_some() {
+			// Metafiles can't paint a gradient along a path, so the pieces approximating it are stroked one by one
+			let pieces = subpaths.iter().flat_map(|subpath| stroke.gradient_pieces(subpath, DAffine2::IDENTITY));
+			pieces.map(|(piece, piece_stroke)| (vec![piece], piece_stroke)).collect()
+		} else if solid_color(&stroke.paint).is_some_and(|color| color.a() > 0.) {
+			vec![(subpaths, stroke)]
+		} else {
+			Vec::new()
+		};
+
+		for (subpaths, stroke) in strokes {
+			if let Some(bounds) = self.path(&subpaths, transform) {
+				// Approximate the stroke weight under non-uniform scaling by the average scale factor, which a hairline isn't scaled by
+				let scale = if stroke.hairline { 1. } else { transform.matrix2.determinant().abs().sqrt() };
+				let weight = stroke.weight * scale;
+				self.record(EMR_SETMITERLIMIT, &[stroke.drawn_miter_limit().round() as u32]);
+				self.create_pen(&stroke, weight);
+				self.record(EMR_SELECTOBJECT, &[PEN_INDEX]);
+				self.record(EMR_STROKEPATH, &rectangle(bounds, weight / 2.));
+				self.record(EMR_SELECTOBJECT, &[NULL_PEN]);
+				self.record(EMR_DELETEOBJECT, &[PEN_INDEX]);
+			}
+		}
+
+		if alignment != StrokeAlignment::Center {
+			self.record(EMR_RESTOREDC, &[-1_i32 as u32]);
+		}
+	}
+
+	/// Draws the image stretched over the bounding box of its transformed unit square, since metafile images can't be rotated or skewed.
+	pub fn image_frame(&mut self, image_frame: &ImageFrame<Color>) {
+		let image = &image_frame.image;
+		if image.data.is_empty() {
+			return;
+		}
+
+		// The bitmap has no alpha channel, so composite the image over white, and store the rows from bottom to top in BGRX order
+		let (data, width, height) = image.to_flat_u8();
+		let mut bits = Vec::with_capacity(data.len());
+		for row in data.chunks(width as usize * 4).rev() {
+			for pixel in row.chunks(4) {
+				let alpha = pixel[3] as u32;
+				let [red, green, blue] = [pixel[0], pixel[1], pixel[2]].map(|channel| ((channel as u32 * alpha + 255 * (255 - alpha)) / 255) as u8);
+				bits.extend([blue, green, red, 0]);
+			}
+		}
+
+		let corners = [DVec2::ZERO, DVec2::X, DVec2::Y, DVec2::ONE].map(|corner| (self.transform * image_frame.transform).transform_point2(corner));
+		let bounds = [corners.into_iter().reduce(DVec2::min).unwrap(), corners.into_iter().reduce(DVec2::max).unwrap()];
+		let [start, end] = bounds.map(|point| point.to_array().map(logical));
+
+		const FIXED_SIZE: u32 = 80;
+		const BITMAP_INFO_SIZE: u32 = 40;
+		let mut payload = Vec::new();
+		push_i32s(&mut payload, &rectangle(bounds, 0.).map(|value| value as i32));
+		push_i32s(&mut payload, &[start[0], start[1], 0, 0, width as i32, height as i32]);
+		push_u32s(&mut payload, &[FIXED_SIZE, BITMAP_INFO_SIZE, FIXED_SIZE + BITMAP_INFO_SIZE, bits.len() as u32, 0, SRCCOPY]);
+		push_i32s(&mut payload, &[end[0] - start[0], end[1] - start[1]]);
+		// A 32 bits per pixel uncompressed bitmap with a single plane
+		push_u32s(&mut payload, &[BITMAP_INFO_SIZE, width, height, 1 | (32 << 16), 0, bits.len() as u32, 0, 0, 0, 0]);
+		payload.extend(bits);
+		self.raw_record(EMR_STRETCHDIBITS, &payload);
+	}
+
+	pub fn artboard(&mut self, artboard: &Artboard, show_background: bool, content: impl FnOnce(&mut Self)) {
+		let location = artboard.location.as_dvec2();
+		let rectangle = Subpath::<PointId>::new_rect(location, location + artboard.dimensions.as_dvec2());
+
+		if show_background && artboard.background.a() > 0. {
+			self.fill(std::slice::from_ref(&rectangle), self.transform, artboard.background);
+		}
+
+		self.record(EMR_SAVEDC, &[]);
+		if artboard.clip {
+			if let Some([start, end]) = rectangle.bounding_box_with_transform(self.transform) {
+				let [start, end] = [start, end].map(|point| point.to_array().map(logical));
+				self.record(EMR_INTERSECTCLIPRECT, &[start[0], start[1], end[0], end[1]].map(|value| value as u32));
+			}
+		}
+		self.with_transform(DAffine2::from_translation(location) * artboard.graphic_group.transform, content);
+		// Restore the state saved most recently
+		self.record(EMR_RESTOREDC, &[-1_i32 as u32]);
+	}
+
+	/// Draws the content once for each of the transforms, relative to the shape, clipped to the regions of the shape.
+	fn clip_to_regions(&mut self, vector_data: &VectorData, transforms: Vec<DAffine2>, mut content: impl FnMut(&mut Self)) {
+		if transforms.is_empty() {
+			return;
+		}
+		let regions = vector_data.region_bezier_paths().map(|(_, subpath)| subpath).collect::<Vec<_>>();
+
+		// Restoring the state afterwards also removes the clipping path and resets the fill mode
+		self.record(EMR_SAVEDC, &[]);
+		if vector_data.style.fill_rule() == FillRule::EvenOdd {
+			self.record(EMR_SETPOLYFILLMODE, &[ALTERNATE]);
+		}
+		if self.path(&regions, self.transform * vector_data.transform).is_some() {
+			self.record(EMR_SELECTCLIPPATH, &[RGN_AND]);
+			for transform in transforms {
+				self.with_transform(vector_data.transform * transform, &mut content);
+			}
+		}
+		self.record(EMR_RESTOREDC, &[-1_i32 as u32]);
+	}
+
+	fn fill<Id: bezier_rs::Identifier>(&mut self, subpaths: &[Subpath<Id>], transform: DAffine2, color: Color) {
+		let Some(bounds) = self.path(subpaths, transform) else { return };
+
+		self.record(EMR_CREATEBRUSHINDIRECT, &[BRUSH_INDEX, 0, color_ref(color), 0]);
+		self.record(EMR_SELECTOBJECT, &[BRUSH_INDEX]);
+		self.record(EMR_FILLPATH, &rectangle(bounds, 0.));
+		self.record(EMR_SELECTOBJECT, &[NULL_BRUSH]);
+		self.record(EMR_DELETEOBJECT, &[BRUSH_INDEX]);
+	}
+
+	/// Defines the current path from the subpaths, returning their bounds on the page if the path is not empty.
+	fn path<Id: bezier_rs::Identifier>(&mut self, subpaths: &[Subpath<Id>], transform: DAffine2) -> Option<[DVec2; 2]> {
+		let mut bounds: Option<[DVec2; 2]> = None;
+
+		for subpath in subpaths {
+			let mut beziers = subpath.iter().map(|bezier| bezier.apply_transformation(|point| transform.transform_point2(point))).peekable();
+			let Some(first) = beziers.peek() else { continue };
+			if bounds.is_none() {
+				self.record(EMR_BEGINPATH, &[]);
+			}
+			self.record(EMR_MOVETOEX, &point(first.start));
+
+			for bezier in beziers {
+				let [min, max] = bezier.bounding_box();
+				bounds = Some(bounds.map_or([min, max], |[old_min, old_max]| [old_min.min(min), old_max.max(max)]));
+
+				match bezier.handles {
+					// Curves with their handles on top of their anchors are straight lines
+					BezierHandles::Linear => self.record(EMR_LINETO, &point(bezier.end)),
+					BezierHandles::Cubic { handle_start, handle_end } if handle_start == bezier.start && handle_end == bezier.end => self.record(EMR_LINETO, &point(bezier.end)),
+					_ => {
+						let BezierHandles::Cubic { handle_start, handle_end } = bezier.to_cubic().handles else {
+							unreachable!()
+						};
+						let mut payload = rectangle([handle_start.min(handle_end).min(bezier.end), handle_start.max(handle_end).max(bezier.end)], 0.).to_vec();
+						payload.push(3);
+						payload.extend([handle_start, handle_end, bezier.end].into_iter().flat_map(point));
+						self.record(EMR_POLYBEZIERTO, &payload);
+					}
+				}
+			}
+
+			if subpath.closed() {
+				self.record(EMR_CLOSEFIGURE, &[]);
+			}
+		}
+
+		if bounds.is_some() {
+			self.record(EMR_ENDPATH, &[]);
+		}
+		bounds
+	}
+
+	fn create_pen(&mut self, stroke: &Stroke, weight: f64) {
+		let line_cap = match stroke.drawn_line_cap() {
+			LineCap::Round => 0,
+			LineCap::Square => 0x100,
+			LineCap::Butt => 0x200,
+		};
+		let line_join = match stroke.line_join {
+			LineJoin::Round => 0,
+			LineJoin::Bevel => 0x1000,
+			LineJoin::Miter => 0x2000,
+		};
+
+		// Pens have no dash offset, so the pattern always starts from its first dash
+		let dashes = stroke
+			.drawn_dash_lengths()
+			.iter()
+			.map(|&length| logical(length * weight / stroke.weight).max(1) as u32)
+			.collect::<Vec<_>>();
+		let style = if dashes.is_empty() { 0 } else { PS_USERSTYLE };
+
+		let mut payload = vec![PEN_INDEX, 0, 0, 0, 0];
+		payload.extend([
+			PS_GEOMETRIC | style | line_cap | line_join,
+			logical(weight).max(1) as u32,
+			0,
+			color_ref(solid_color(&stroke.paint).unwrap_or_default()),
+			0,
+			dashes.len() as u32,
+		]);
+		payload.extend(dashes);
+		self.record(EMR_EXTCREATEPEN, &payload);
+	}
+
+	fn record(&mut self, record_type: u32, values: &[u32]) {
+		let mut payload = Vec::with_capacity(values.len() * 4);
+		push_u32s(&mut payload, values);
+		self.raw_record(record_type, &payload);
+	}
+
+	fn raw_record(&mut self, record_type: u32, payload: &[u8]) {
+		// Records are aligned to four bytes
+		let padding = (4 - payload.len() % 4) % 4;
+		push_u32s(&mut self.records, &[record_type, (8 + payload.len() + padding) as u32]);
+		self.records.extend(payload);
+		self.records.resize(self.records.len() + padding, 0);
+		self.record_count += 1;
+	}
+}
+
+fn push_u32s(data: &mut Vec<u8>, values: &[u32]) {
+	data.extend(values.iter().flat_map(|value| value.to_le_bytes()));
+}
+
+fn push_i32s(data: &mut Vec<u8>, values: &[i32]) {
+	data.extend(values.iter().flat_map(|value| value.to_le_bytes()));
+}
+
+/// Converts a distance in pixels to logical units.
+fn logical(value: f64) -> i32 {
+	(value * SUBPIXELS).round() as i32
+}
+
+fn point(point: DVec2) -> [u32; 2] {
+	[logical(point.x) as u32, logical(point.y) as u32]
+}
+
+/// Converts bounds in pixels, grown by the given margin, to an inclusive rectangle in device units.
+fn rectangle([min, max]: [DVec2; 2], margin: f64) -> [u32; 4] {
+	let [min, max] = [(min - DVec2::splat(margin)).floor(), (max + DVec2::splat(margin)).ceil()];
+	[min.x, min.y, max.x, max.y].map(|value| value as i32 as u32)
+}
+
+/// The color that solid paint is drawn with, where gradients have the average color of their stops. Patterns and images have none, since they're drawn copy by copy.
+fn solid_color(paint: &Fill) -> Option<Color> {
+	match paint {
+		Fill::Solid(color) => Some(*color),
+		Fill::Gradient(gradient) if !gradient.positions.is_empty() => {
+			let sum = gradient.positions.iter().fold([0.; 3], |sum, (_, color)| [sum[0] + color.r(), sum[1] + color.g(), sum[2] + color.b()]);
+			let count = gradient.positions.len() as f32;
+			Some(Color::from_rgbf32_unchecked(sum[0] / count, sum[1] / count, sum[2] / count))
+		}
+		_ => None,
+	}
+}
+
+/// Converts a color to the `0x00BBGGRR` layout used by metafiles.
+fn color_ref(color: Color) -> u32 {
+	let [red, green, blue] = [color.r(), color.g(), color.b()].map(|channel| (channel * 255.).round() as u32);
+	red | (green << 8) | (blue << 16)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// Splits the document into the type and payload of each record.
+	fn records(document: &[u8]) -> Vec<(u32, &[u8])> {
+		let mut records = Vec::new();
+		let mut offset = 0;
+		while offset < document.len() {
+			let read = |offset: usize| u32::from_le_bytes(document[offset..offset + 4].try_into().unwrap());
+			let (record_type, size) = (read(offset), read(offset + 4) as usize);
+			records.push((record_type, &document[offset + 8..offset + size]));
+			offset += size;
+		}
+		records
+	}
+
+	#[test]
+	fn vector_data_records() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::new(10., 20.)));
+		vector_data.style.set_fill(Fill::Solid(Color::from_rgbf32_unchecked(1., 0., 0.)));
+		vector_data.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.));
+
+		let mut render = EmfRender::new(DAffine2::from_scale(DVec2::splat(2.)));
+		render.vector_data(&vector_data);
+		let document = render.to_emf_document(DVec2::new(20., 40.));
+		let records = records(&document);
+
+		let (header_type, header) = records[0];
+		assert_eq!(header_type, EMR_HEADER);
+		assert_eq!(&header[32..36], b" EMF");
+		assert_eq!(u32::from_le_bytes(header[40..44].try_into().unwrap()) as usize, document.len());
+		assert_eq!(u32::from_le_bytes(header[44..48].try_into().unwrap()) as usize, records.len());
+		assert_eq!(records.last().unwrap().0, EMR_EOF);
+
+		let types = records.iter().map(|(record_type, _)| *record_type).collect::<Vec<_>>();
+		assert_eq!(types.iter().filter(|&&record_type| record_type == EMR_BEGINPATH).count(), 2);
+		assert!(types.contains(&EMR_FILLPATH));
+		assert!(types.contains(&EMR_STROKEPATH));
+
+		// The corner of the rectangle is scaled along with the path
+		let corner = point(DVec2::new(20., 40.)).map(|value| value.to_le_bytes()).concat();
+		assert!(records.iter().any(|&(record_type, payload)| record_type == EMR_LINETO && payload == corner));
+		// The red brush
+		assert!(records
+			.iter()
+			.any(|&(record_type, payload)| record_type == EMR_CREATEBRUSHINDIRECT && payload[8..12] == 0xFF_u32.to_le_bytes()));
+		// The stroke weight is scaled along with the path
+		let pen = records.iter().find(|(record_type, _)| *record_type == EMR_EXTCREATEPEN).unwrap().1;
+		assert_eq!(u32::from_le_bytes(pen[24..28].try_into().unwrap()), logical(4.) as u32);
+	}
+
+	#[test]
+	fn image_records() {
+		let image = crate::raster::Image::new(2, 1, Color::from_rgbf32_unchecked(0., 0., 1.));
+		let image_frame = ImageFrame {
+			image,
+			transform: DAffine2::from_scale(DVec2::new(20., 10.)),
+			..Default::default()
+		};
+
+		let mut render = EmfRender::new(DAffine2::IDENTITY);
+		render.image_frame(&image_frame);
+		let document = render.to_emf_document(DVec2::new(20., 10.));
+		let (_, payload) = *records(&document).iter().find(|(record_type, _)| *record_type == EMR_STRETCHDIBITS).unwrap();
+
+		// Two blue pixels in BGRX order
+		assert_eq!(&payload[payload.len() - 8..], &[255, 0, 0, 0, 255, 0, 0, 0]);
+		// The destination size in logical units
+		assert_eq!(&payload[64..72], &[logical(20.).to_le_bytes(), logical(10.).to_le_bytes()].concat());
+	}
+}
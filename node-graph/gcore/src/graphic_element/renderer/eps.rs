@@ -0,0 +1,423 @@
+use crate::mask::Mask;
+use crate::raster::ImageFrame;
+use crate::vector::style::{Fill, FillRule, Gradient, GradientType, LineCap, LineJoin, Stroke, StrokeAlignment};
+use crate::vector::{PointId, VectorData};
+use crate::{Artboard, Color};
+
+use bezier_rs::{BezierHandles, Subpath};
+use glam::{DAffine2, DVec2};
+use std::fmt::Write;
+
+/// Mutable state used whilst rendering to Encapsulated PostScript, for print shops and cutting plotters that only accept EPS files.
+///
+/// PostScript has no concept of transparency, so opacity, blend modes, and the alpha channel of colors are ignored, except for skipping fully transparent paint.
+pub struct EpsRender {
+	pub eps: String,
+	/// The transform from the coordinate space of the content being rendered to the page, whose origin is the top left corner.
+	pub transform: DAffine2,
+}
+
+impl EpsRender {
+	pub fn new(transform: DAffine2) -> Self {
+		Self { eps: String::new(), transform }
+	}
+
+	/// Wraps the rendered content into an EPS document with a page of the given size.
+	pub fn to_eps_document(&self, size: DVec2) -> String {
+		let mut document = String::new();
+		writeln!(document, "%!PS-Adobe-3.0 EPSF-3.0").unwrap();
+		writeln!(document, "%%BoundingBox: 0 0 {} {}", size.x.ceil(), size.y.ceil()).unwrap();
+		writeln!(document, "%%HiResBoundingBox: 0 0 {} {}", number(size.x), number(size.y)).unwrap();
+		writeln!(document, "%%Creator: Graphite").unwrap();
+		writeln!(document, "%%LanguageLevel: 3").unwrap();
+		writeln!(document, "%%EndComments").unwrap();
+		// Flip the y-axis so the origin of the page is at the top left, like in the rest of Graphite
+		writeln!(document, "gsave\n[1 0 0 -1 0 {}] concat", number(size.y)).unwrap();
+		document.push_str(&self.eps);
+		writeln!(document, "grestore\nshowpage\n%%EOF").unwrap();
+		document
+	}
+
+	/// Fills the page, which spans from the origin to the given size, with a solid color.
+	pub fn fill_page(&mut self, size: DVec2, color: Color) {
+		writeln!(self.eps, "{} 0 0 {} {} rectfill", set_color(color), number(size.x), number(size.y)).unwrap();
+	}
+
+	/// Renders the content with an additional transform applied to it.
+	pub fn with_transform(&mut self, transform: DAffine2, content: impl FnOnce(&mut Self)) {
+		let parent_transform = self.transform;
+		self.transform = parent_transform * transform;
+		content(self);
+		self.transform = parent_transform;
+	}
+
+	/// Renders the content clipped to the area of the mask, if there is one. Raster masks are clipped to by their rectangle, since PostScript can't follow their luminance.
+	pub fn with_mask(&mut self, mask: Option<&Mask>, content: impl FnOnce(&mut Self)) {
+		let Some(mask) = mask else { return content(self) };
+		let (subpaths, transform, fill_rule) = mask.clip_area();
+		let mut path = String::new();
+		for subpath in &subpaths {
+			path_commands(&mut path, subpath, self.transform * transform);
+		}
+		// Nothing is kept by an empty mask
+		if path.is_empty() {
+			return;
+		}
+
+		let clip = match fill_rule {
+			FillRule::NonZero => "clip",
+			FillRule::EvenOdd => "eoclip",
+		};
+		writeln!(self.eps, "gsave\nnewpath\n{path}{clip}").unwrap();
+		content(self);
+		writeln!(self.eps, "grestore").unwrap();
+	}
+
+	pub fn vector_data(&mut self, vector_data: &VectorData) {
+		let transform = self.transform * vector_data.transform;
+		let mut path = String::new();
+		for subpath in vector_data.stroke_bezier_paths() {
+			path_commands(&mut path, &subpath, transform);
+		}
+		if path.is_empty() {
+			return;
+		}
+
+		let (fill, clip) = match vector_data.style.fill_rule() {
+			FillRule::NonZero => ("fill", "clip"),
+			FillRule::EvenOdd => ("eofill", "eoclip"),
+		};
+		match vector_data.style.fill() {
+			Fill::Solid(color) if color.a() > 0. => {
+				writeln!(self.eps, "newpath\n{path}{} {fill}", set_color(*color)).unwrap();
+			}
+			Fill::Gradient(gradient) if !gradient.positions.is_empty() => {
+				let paint = gradient_paint(gradient, vector_data, transform);
+				writeln!(self.eps, "gsave\nnewpath\n{path}{clip}\n{paint}\ngrestore").unwrap();
+			}
+			Fill::Pattern(pattern) => {
+				let tiles = vector_data.bounding_box().map(|bounds| pattern.tile_transforms(bounds)).unwrap_or_default();
+				if !tiles.is_empty() {
+					// A copy of the tile is drawn in each cell of the pattern's grid, clipped to the shape
+					writeln!(self.eps, "gsave\nnewpath\n{path}{clip}").unwrap();
+					for tile_transform in tiles {
+						self.with_transform(vector_data.transform * tile_transform, |render| render.vector_data(&pattern.tile));
+					}
+					writeln!(self.eps, "grestore").unwrap();
+				}
+			}
+			Fill::Image(image_fill) => {
+				let images = vector_data.bounding_box().map(|bounds| image_fill.image_transforms(bounds)).unwrap_or_default();
+				if !images.is_empty() {
+					// A copy of the image is drawn for each tile, or just once when not tiling, clipped to the shape
+					let image_frame = ImageFrame {
+						transform: DAffine2::IDENTITY,
+						..image_fill.image.clone()
+					};
+					writeln!(self.eps, "gsave\nnewpath\n{path}{clip}").unwrap();
+					for image_transform in images {
+						self.with_transform(vector_data.transform * image_transform, |render| render.image_frame(&image_frame));
+					}
+					writeln!(self.eps, "grestore").unwrap();
+				}
+			}
+			_ => {}
+		}
+
+		let Some(mut stroke) = vector_data.style.stroke().filter(|stroke| stroke.weight > 0.) else {
+			return;
+		};
+		// Approximate the stroke weight under non-uniform scaling by the average scale factor, which a hairline isn't scaled by
+		let scale = if stroke.hairline { 1. } else { transform.matrix2.determinant().abs().sqrt() };
+
+		let alignment = super::stroke_alignment(vector_data);
+		if alignment != StrokeAlignment::Center {
+			// The doubled stroke is clipped to the inside of the shape, or to the even-odd area between a rectangle around it and the shape for the outside
+			let mut outline = String::new();
+			let clip = if alignment == StrokeAlignment::Inside {
+				clip
+			} else {
+				let [min, max] = super::outside_stroke_bounds(vector_data, &stroke, transform);
+				writeln!(
+					outline,
+					"{} {} moveto {} {} lineto {} {} lineto {} {} lineto closepath",
+					number(min.x),
+					number(min.y),
+					number(max.x),
+					number(min.y),
+					number(max.x),
+					number(max.y),
+					number(min.x),
+					number(max.y)
+				)
+				.unwrap();
+				"eoclip"
+			};
+			for subpath in vector_data.stroke_bezier_paths() {
+				path_commands(&mut outline, &subpath, transform);
+			}
+			writeln!(self.eps, "gsave\nnewpath\n{outline}{clip}").unwrap();
+			stroke = super::aligned_stroke(&stroke);
+		}
+
+		if stroke.gradient.is_some() {
+			// PostScript can't paint a gradient along a path, so the pieces approximating it are stroked one by one
+			for (piece, piece_stroke) in vector_data.stroke_bezier_paths().flat_map(|subpath| stroke.gradient_pieces(&subpath, DAffine2::IDENTITY)) {
+				let mut path = String::new();
+				path_commands(&mut path, &piece, transform);
+				writeln!(self.eps, "newpath\n{path}{}stroke", stroke_state(&piece_stroke, scale)).unwrap();
+			}
+		} else if let Fill::Gradient(gradient) = &stroke.paint {
+			// The outline of the stroke is clipped to and painted with the gradient, the same as a shape filled with it
+			if !gradient.positions.is_empty() {
+				let paint = gradient_paint(gradient, vector_data, transform);
+				writeln!(self.eps, "gsave\nnewpath\n{path}{}strokepath clip\n{paint}\ngrestore", stroke_state(&stroke, scale)).unwrap();
+			}
+		} else if stroke.color().is_some_and(|color| color.a() > 0.) {
+			writeln!(self.eps, "newpath\n{path}{}stroke", stroke_state(&stroke, scale)).unwrap();
+		}
+
+		if alignment != StrokeAlignment::Center {
+			writeln!(self.eps, "grestore").unwrap();
+		}
+	}
+
+	pub fn image_frame(&mut self, image_frame: &ImageFrame<Color>) {
+		let image = &image_frame.image;
+		if image.data.is_empty() {
+			return;
+		}
+
+		// PostScript images have no alpha channel, so composite the image over white
+		let (data, width, height) = image.to_flat_u8();
+		let mut hex = String::with_capacity(data.len() / 4 * 6 + height as usize);
+		for row in data.chunks(width as usize * 4) {
+			for pixel in row.chunks(4) {
+				let alpha = pixel[3] as u32;
+				for &channel in &pixel[..3] {
+					write!(hex, "{:02x}", (channel as u32 * alpha + 255 * (255 - alpha)) / 255).unwrap();
+				}
+			}
+			hex.push('\n');
+		}
+
+		// The image occupies the unit square of its transform, with its first row at the top
+		let [a, b, c, d, e, f] = (self.transform * image_frame.transform).to_cols_array();
+		writeln!(self.eps, "gsave\n[{} {} {} {} {} {}] concat", number(a), number(b), number(c), number(d), number(e), number(f)).unwrap();
+		writeln!(self.eps, "/picstr {} string def", width * 3).unwrap();
+		writeln!(self.eps, "{width} {height} 8 [{width} 0 0 {height} 0 0] {{currentfile picstr readhexstring pop}} false 3 colorimage").unwrap();
+		self.eps.push_str(&hex);
+		writeln!(self.eps, "grestore").unwrap();
+	}
+
+	pub fn artboard(&mut self, artboard: &Artboard, show_background: bool, content: impl FnOnce(&mut Self)) {
+		let mut rectangle = String::new();
+		let location = artboard.location.as_dvec2();
+		path_commands(&mut rectangle, &Subpath::<PointId>::new_rect(location, location + artboard.dimensions.as_dvec2()), self.transform);
+
+		if show_background && artboard.background.a() > 0. {
+			writeln!(self.eps, "newpath\n{rectangle}{} fill", set_color(artboard.background)).unwrap();
+		}
+
+		writeln!(self.eps, "gsave").unwrap();
+		if artboard.clip {
+			writeln!(self.eps, "newpath\n{rectangle}clip").unwrap();
+		}
+		self.with_transform(DAffine2::from_translation(location) * artboard.graphic_group.transform, content);
+		writeln!(self.eps, "grestore").unwrap();
+	}
+}
+
+/// Formats a number without unnecessary trailing zeros.
+fn number(value: f64) -> String {
+	let formatted = format!("{value:.4}");
+	let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+	if formatted == "-0" {
+		"0".to_string()
+	} else {
+		formatted.to_string()
+	}
+}
+
+fn color_components(color: Color) -> String {
+	format!("{} {} {}", number(color.r() as f64), number(color.g() as f64), number(color.b() as f64))
+}
+
+fn set_color(color: Color) -> String {
+	format!("{} setrgbcolor", color_components(color))
+}
+
+fn path_commands<Id: bezier_rs::Identifier>(path: &mut String, subpath: &Subpath<Id>, transform: DAffine2) {
+	let mut beziers = subpath.iter().map(|bezier| bezier.apply_transformation(|point| transform.transform_point2(point))).peekable();
+	let Some(first) = beziers.peek() else { return };
+	writeln!(path, "{} {} moveto", number(first.start.x), number(first.start.y)).unwrap();
+
+	for bezier in beziers {
+		match bezier.handles {
+			BezierHandles::Linear => writeln!(path, "{} {} lineto", number(bezier.end.x), number(bezier.end.y)).unwrap(),
+			_ => {
+				let BezierHandles::Cubic { handle_start, handle_end } = bezier.to_cubic().handles else {
+					unreachable!()
+				};
+				let points = [handle_start, handle_end, bezier.end].map(|point| format!("{} {}", number(point.x), number(point.y)));
+				writeln!(path, "{} curveto", points.join(" ")).unwrap();
+			}
+		}
+	}
+
+	if subpath.closed() {
+		writeln!(path, "closepath").unwrap();
+	}
+}
+
+fn stroke_state(stroke: &Stroke, scale: f64) -> String {
+	let mut state = String::new();
+	writeln!(state, "{}", set_color(stroke.color().unwrap_or_default())).unwrap();
+	writeln!(state, "{} setlinewidth", number(stroke.weight * scale)).unwrap();
+	let line_cap = match stroke.drawn_line_cap() {
+		LineCap::Butt => 0,
+		LineCap::Round => 1,
+		LineCap::Square => 2,
+	};
+	let line_join = match stroke.line_join {
+		LineJoin::Miter => 0,
+		LineJoin::Round => 1,
+		LineJoin::Bevel => 2,
+	};
+	writeln!(state, "{line_cap} setlinecap {line_join} setlinejoin {} setmiterlimit", number(stroke.drawn_miter_limit())).unwrap();
+	let dashes = stroke.drawn_dash_lengths().iter().map(|length| number(length * scale)).collect::<Vec<_>>();
+	writeln!(state, "[{}] {} setdash", dashes.join(" "), number(stroke.drawn_dash_offset() * scale)).unwrap();
+	state
+}
+
+/// Paints the current clipping path with the gradient, whose points are relative to the bounding box of the shape that's mapped to the page by `transform`.
+fn gradient_paint(gradient: &Gradient, vector_data: &VectorData, transform: DAffine2) -> String {
+	let bounds = vector_data.bounding_box().unwrap_or_default();
+	let bound_transform = DAffine2::from_scale_angle_translation(bounds[1] - bounds[0], 0., bounds[0]);
+	if gradient.gradient_type == GradientType::Conic {
+		// PostScript has no conic shadings, so the wedges approximating it are filled one by one
+		conic_wedges(gradient, transform * bound_transform)
+	} else {
+		format!("{} shfill", shading(gradient, transform * bound_transform))
+	}
+}
+
+/// Fills the wedges of a conic gradient, whose center and start angle are given relative to the bounding box mapped by `transform`.
+fn conic_wedges(gradient: &Gradient, transform: DAffine2) -> String {
+	let mut commands = String::new();
+	for ([center, from, to], color) in gradient.conic_wedges(transform) {
+		let [center, from, to] = [center, from, to].map(|point| format!("{} {}", number(point.x), number(point.y)));
+		writeln!(commands, "newpath {center} moveto {from} lineto {to} lineto closepath {} fill", set_color(color)).unwrap();
+	}
+	commands.pop();
+	commands
+}
+
+/// Builds a PostScript Level 3 shading dictionary for the gradient, whose start and end points are given relative to the bounding box mapped by `transform`.
+fn shading(gradient: &Gradient, transform: DAffine2) -> String {
+	let mut stops = gradient.interpolated_stops();
+	stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+	// A single stop is a solid color
+	if stops.len() == 1 {
+		stops.push(stops[0]);
+	}
+
+	// Stitch together an interpolation between each pair of neighboring stops
+	let functions = stops
+		.windows(2)
+		.map(|pair| format!("<< /FunctionType 2 /Domain [0 1] /C0 [{}] /C1 [{}] /N 1 >>", color_components(pair[0].1), color_components(pair[1].1)))
+		.collect::<Vec<_>>();
+	let bounds = stops[1..stops.len() - 1].iter().map(|(position, _)| number(*position)).collect::<Vec<_>>();
+	let encode = vec!["0 1"; functions.len()];
+	let (domain_start, domain_end) = (stops[0].0, stops[stops.len() - 1].0);
+	let domain = if domain_end > domain_start { [domain_start, domain_end] } else { [0., 1.] };
+	let function = format!(
+		"<< /FunctionType 3 /Domain [{} {}] /Functions [{}] /Bounds [{}] /Encode [{}] >>",
+		number(domain[0]),
+		number(domain[1]),
+		functions.join(" "),
+		bounds.join(" "),
+		encode.join(" ")
+	);
+
+	let start = transform.transform_point2(gradient.start);
+	let end = transform.transform_point2(gradient.end);
+	let focal_point = transform.transform_point2(gradient.focal_point());
+	let (shading_type, coords) = match gradient.gradient_type {
+		GradientType::Linear => (2, [start.x, start.y, end.x, end.y].map(number).join(" ")),
+		// Conic gradients are filled by their wedges instead, but would fall back to radial shading around the same center
+		GradientType::Radial | GradientType::Conic => (3, [focal_point.x, focal_point.y, 0., start.x, start.y, start.distance(end)].map(number).join(" ")),
+	};
+
+	format!(
+		"<< /ShadingType {shading_type} /ColorSpace /DeviceRGB /Coords [{coords}] /Domain [{} {}] /Function {function} /Extend [true true] >>",
+		number(domain[0]),
+		number(domain[1])
+	)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn vector_data_paths() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::new(10., 20.)));
+		vector_data.style.set_fill(Fill::Solid(Color::from_rgbf32_unchecked(1., 0., 0.)));
+		vector_data.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.));
+
+		let mut render = EpsRender::new(DAffine2::from_scale(DVec2::splat(2.)));
+		render.vector_data(&vector_data);
+		assert!(render.eps.contains("0 0 moveto"));
+		assert!(render.eps.contains("closepath"));
+		assert!(render.eps.contains("1 0 0 setrgbcolor fill"));
+		// The stroke weight is scaled along with the path
+		assert!(render.eps.contains("4 setlinewidth"));
+
+		// Unless it's a hairline, which keeps its weight on the page
+		let mut hairline = vector_data.clone();
+		hairline.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.).with_hairline(true));
+		let mut render = EpsRender::new(DAffine2::from_scale(DVec2::splat(2.)));
+		render.vector_data(&hairline);
+		assert!(render.eps.contains("2 setlinewidth"));
+
+		vector_data.style.set_fill_rule(FillRule::EvenOdd);
+		let mut render = EpsRender::new(DAffine2::IDENTITY);
+		render.vector_data(&vector_data);
+		assert!(render.eps.contains("1 0 0 setrgbcolor eofill"));
+
+		let document = render.to_eps_document(DVec2::new(20., 40.));
+		assert!(document.starts_with("%!PS-Adobe-3.0 EPSF-3.0\n%%BoundingBox: 0 0 20 40\n"));
+		assert!(document.ends_with("%%EOF\n"));
+	}
+
+	#[test]
+	fn gradient_shading() {
+		let gradient = Gradient::new(DVec2::ZERO, Color::BLACK, DVec2::X, Color::WHITE, DAffine2::IDENTITY, GradientType::Linear);
+		let shading = shading(&gradient, DAffine2::from_scale(DVec2::splat(10.)));
+		assert!(shading.starts_with("<< /ShadingType 2 /ColorSpace /DeviceRGB /Coords [0 0 10 0]"));
+		assert!(shading.contains("/C0 [0 0 0] /C1 [1 1 1]"));
+	}
+
+	#[test]
+	fn conic_gradient_wedges() {
+		let gradient = Gradient::new_conic(DVec2::splat(0.5), 90., Color::BLACK, Color::WHITE, DAffine2::IDENTITY);
+		assert!((gradient.start_angle() - 90.).abs() < 1e-9);
+		assert_eq!(gradient.evaluate(0.5), Color::BLACK.lerp(&Color::WHITE, 0.5));
+
+		let wedges = gradient.conic_wedges(DAffine2::from_scale(DVec2::splat(10.)));
+		// The sweep starts straight down from the center and ends with the last color just before coming back around
+		let ([center, from, _], first) = wedges[0];
+		assert_eq!(center, DVec2::splat(5.));
+		assert!((from - center).normalize().abs_diff_eq(DVec2::Y, 1e-9));
+		assert!(from.distance(center) > DVec2::splat(5.).length());
+		assert!(first.r() < 0.01 && wedges[wedges.len() - 1].1.r() > 0.99);
+
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		vector_data.style.set_fill(Fill::Gradient(gradient));
+		let mut render = EpsRender::new(DAffine2::IDENTITY);
+		render.vector_data(&vector_data);
+		assert!(!render.eps.contains("shfill"));
+		assert_eq!(render.eps.matches("lineto closepath").count(), wedges.len());
+	}
+}
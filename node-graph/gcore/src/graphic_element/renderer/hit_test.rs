@@ -0,0 +1,138 @@
+use super::Quad;
+use crate::vector::style::{Fill, FillRule, Paint, Stroke, StrokeAlignment};
+use crate::vector::{PointId, VectorData};
+
+use bezier_rs::{Subpath, TValue};
+use glam::{DAffine2, DVec2};
+
+/// The area a shape paints, in the shape's own space, for testing whether points and quads land on it.
+/// Dashed strokes are tested as if they were solid.
+pub struct HitShape {
+	/// Maps from the space the shape is placed in to the shape's own space.
+	inverse_transform: DAffine2,
+	/// The subpaths as they're stroked.
+	outlines: Vec<Subpath<PointId>>,
+	/// The subpaths closed, as they're filled.
+	regions: Vec<Subpath<PointId>>,
+	fill_rule: FillRule,
+	filled: bool,
+	strokes: Vec<Stroke>,
+}
+
+impl HitShape {
+	/// Collects the visible fills and strokes of the shape, including those of its paint layers, or none if its transform squashes it flat.
+	pub fn new(vector_data: &VectorData) -> Option<Self> {
+		if vector_data.transform.matrix2.determinant() == 0. {
+			return None;
+		}
+
+		let style = &vector_data.style;
+		let layers = style.paint_layers().iter().map(|layer| &layer.paint);
+		let filled = *style.fill() != Fill::None || layers.clone().any(|paint| matches!(paint, Paint::Fill(fill) if *fill != Fill::None));
+		let layer_strokes = layers.filter_map(|paint| match paint {
+			Paint::Stroke(stroke) => Some(stroke.clone()),
+			Paint::Fill(_) => None,
+		});
+		let strokes = style
+			.stroke()
+			.into_iter()
+			.chain(layer_strokes)
+			.filter(|stroke| stroke.weight > 0. && (stroke.paint != Fill::None || stroke.gradient.is_some()))
+			.collect();
+
+		let outlines = vector_data.stroke_bezier_paths().collect::<Vec<_>>();
+		let regions = outlines
+			.iter()
+			.map(|subpath| {
+				let mut region = subpath.clone();
+				region.set_closed(true);
+				region
+			})
+			.collect();
+
+		Some(Self {
+			inverse_transform: vector_data.transform.inverse(),
+			outlines,
+			regions,
+			fill_rule: style.fill_rule(),
+			filled,
+			strokes,
+		})
+	}
+
+	/// Whether the point, in the space the shape is placed in, is inside a fill or on a stroke.
+	pub fn contains_point(&self, point: DVec2) -> bool {
+		self.contains_local_point(self.inverse_transform.transform_point2(point))
+	}
+
+	/// Whether anything painted overlaps the quad, in the space the shape is placed in.
+	pub fn intersects_quad(&self, quad: Quad) -> bool {
+		let quad = self.inverse_transform * quad;
+
+		// Covers the quad lying inside a fill or a corner of it lying on a stroke
+		if quad.0.iter().any(|&corner| self.contains_local_point(corner)) {
+			return true;
+		}
+		// A quad with no area can only be hit at its corners
+		let [a, b, c, d] = quad.0;
+		if (a - c).perp_dot(b - d).abs() <= f64::EPSILON {
+			return false;
+		}
+
+		// The paths either cross the edges of the quad or lie entirely inside it, where a stroke reaches as far outside of its path as its width
+		let reaches = |paths: &[Subpath<PointId>], quad: Quad| {
+			paths.iter().any(|subpath| {
+				let crosses = subpath.iter().any(|segment| quad.bezier_lines().any(|line| !segment.intersections(&line, None, None).is_empty()));
+				crosses || subpath.manipulator_groups().first().is_some_and(|group| quad.contains(group.anchor))
+			})
+		};
+		(self.filled && reaches(&self.regions, quad)) || self.strokes.iter().any(|stroke| reaches(&self.outlines, quad.inflate(self.stroke_reach(stroke))))
+	}
+
+	fn contains_local_point(&self, point: DVec2) -> bool {
+		(self.filled && self.fill_contains(point)) || self.strokes.iter().any(|stroke| self.stroke_contains(stroke, point))
+	}
+
+	fn fill_contains(&self, point: DVec2) -> bool {
+		let winding = self.regions.iter().map(|region| region.winding_order(point)).sum::<i32>();
+		match self.fill_rule {
+			FillRule::NonZero => winding != 0,
+			FillRule::EvenOdd => winding % 2 != 0,
+		}
+	}
+
+	fn stroke_contains(&self, stroke: &Stroke, point: DVec2) -> bool {
+		let alignment = self.stroke_alignment(stroke);
+		self.outlines.iter().any(|subpath| {
+			let Some((index, t)) = subpath.project(point) else { return false };
+			let nearest = subpath
+				.get_segment(index)
+				.map_or(subpath.manipulator_groups()[0].anchor, |segment| segment.evaluate(TValue::Parametric(t)));
+			let weight = stroke.weight_at((index as f64 + t) / subpath.len_segments().max(1) as f64);
+			let distance = nearest.distance(point);
+			match alignment {
+				StrokeAlignment::Center => distance <= weight / 2.,
+				StrokeAlignment::Inside => distance <= weight && self.fill_contains(point),
+				StrokeAlignment::Outside => distance <= weight && !self.fill_contains(point),
+			}
+		})
+	}
+
+	/// Strokes are only drawn inside or outside of shapes whose subpaths are all closed, and tapered strokes are always centered, as when rendering.
+	fn stroke_alignment(&self, stroke: &Stroke) -> StrokeAlignment {
+		if stroke.width_profile.is_empty() && !self.outlines.is_empty() && self.outlines.iter().all(Subpath::closed) {
+			stroke.alignment
+		} else {
+			StrokeAlignment::Center
+		}
+	}
+
+	/// How far the stroke reaches at most from its path.
+	fn stroke_reach(&self, stroke: &Stroke) -> f64 {
+		let weight = stroke.width_profile.iter().map(|&(_, width)| stroke.weight * width.max(0.)).reduce(f64::max).unwrap_or(stroke.weight);
+		match self.stroke_alignment(stroke) {
+			StrokeAlignment::Center => weight / 2.,
+			StrokeAlignment::Inside | StrokeAlignment::Outside => weight,
+		}
+	}
+}
@@ -0,0 +1,547 @@
+use crate::mask::Mask;
+use crate::raster::ImageFrame;
+use crate::vector::style::{Fill, FillRule, Gradient, GradientType, LineCap, LineJoin, Stroke, StrokeAlignment};
+use crate::vector::{PointId, VectorData};
+use crate::{Artboard, Color};
+
+use bezier_rs::{BezierHandles, Subpath};
+use glam::{DAffine2, DVec2};
+use std::fmt::Write;
+
+/// An image drawn by the content, which is written to the document as an image object, along with a soft mask of its alpha channel if it has any transparency.
+struct PdfImage {
+	width: u32,
+	height: u32,
+	rgb: Vec<u8>,
+	alpha: Option<Vec<u8>>,
+}
+
+/// Mutable state used whilst rendering to a single page PDF document, for print workflows that require vector PDF files.
+///
+/// Opacity and the alpha channel of colors and images are kept, but the opacity of a group is applied to each of its elements separately, so overlapping elements
+/// of a translucent group show through each other. Blend modes are ignored, as is the alpha channel of gradient stops.
+pub struct PdfRender {
+	content: String,
+	/// The shadings of the gradients painted by the content, with coordinates on the page, each used as the shading pattern `/P{index}`.
+	shadings: Vec<String>,
+	/// The images drawn by the content, each used as the image object `/Im{index}`.
+	images: Vec<PdfImage>,
+	/// The opacities that paint is drawn with, each set by the graphics state `/GS{index}`.
+	opacities: Vec<f64>,
+	/// The transform from the coordinate space of the content being rendered to the page, whose origin is the top left corner.
+	pub transform: DAffine2,
+	/// The opacity of the groups that the content being rendered is nested in.
+	pub opacity: f64,
+}
+
+impl PdfRender {
+	pub fn new(transform: DAffine2) -> Self {
+		Self {
+			content: String::new(),
+			shadings: Vec::new(),
+			images: Vec::new(),
+			opacities: Vec::new(),
+			transform,
+			opacity: 1.,
+		}
+	}
+
+	/// Wraps the rendered content into a PDF document with a single page of the given size, where each pixel is a point.
+	pub fn to_pdf_document(&self, size: DVec2) -> Vec<u8> {
+		// Flip the y-axis so the origin of the page is at the top left, like in the rest of Graphite
+		let flip = format!("1 0 0 -1 0 {}", number(size.y));
+		let content = format!("{flip} cm\n{}", self.content);
+
+		// The catalog, page tree, page, and content stream come first, followed by each image and its soft mask
+		let first_image = 5;
+		let mut image_objects = Vec::new();
+		let mut image_resources = String::new();
+		for (index, image) in self.images.iter().enumerate() {
+			let id = first_image + image_objects.len();
+			write!(image_resources, "/Im{index} {id} 0 R ").unwrap();
+			let soft_mask = image.alpha.as_ref().map(|_| format!(" /SMask {} 0 R", id + 1)).unwrap_or_default();
+			let dictionary = format!(
+				"/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB /BitsPerComponent 8{soft_mask}",
+				image.width, image.height
+			);
+			image_objects.push(stream_object(&dictionary, &image.rgb));
+			if let Some(alpha) = &image.alpha {
+				let dictionary = format!(
+					"/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceGray /BitsPerComponent 8",
+					image.width, image.height
+				);
+				image_objects.push(stream_object(&dictionary, alpha));
+			}
+		}
+
+		let mut resources = String::new();
+		if !self.opacities.is_empty() {
+			let states = self.opacities.iter().enumerate().map(|(index, opacity)| format!("/GS{index} << /ca {0} /CA {0} >>", number(*opacity)));
+			write!(resources, "/ExtGState << {} >> ", states.collect::<Vec<_>>().join(" ")).unwrap();
+		}
+		if !self.shadings.is_empty() {
+			// Shading patterns are positioned on the page's default coordinates rather than the flipped ones the content is drawn in
+			let patterns = self
+				.shadings
+				.iter()
+				.enumerate()
+				.map(|(index, shading)| format!("/P{index} << /PatternType 2 /Shading {shading} /Matrix [{flip}] >>"));
+			write!(resources, "/Pattern << {} >> ", patterns.collect::<Vec<_>>().join(" ")).unwrap();
+		}
+		if !image_resources.is_empty() {
+			write!(resources, "/XObject << {image_resources}>> ").unwrap();
+		}
+
+		let mut objects = vec![
+			b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+			b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+			format!(
+				"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << {resources}>> /Contents 4 0 R >>",
+				number(size.x),
+				number(size.y)
+			)
+			.into_bytes(),
+			stream_object("", content.as_bytes()),
+		];
+		objects.extend(image_objects);
+
+		// The header's comment of high bytes marks the file as binary for programs that transfer it
+		let mut document = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+		let mut offsets = Vec::with_capacity(objects.len());
+		for (index, object) in objects.iter().enumerate() {
+			offsets.push(document.len());
+			document.extend(format!("{} 0 obj\n", index + 1).into_bytes());
+			document.extend(object);
+			document.extend(b"\nendobj\n");
+		}
+
+		// Each entry of the cross-reference table is exactly 20 bytes long
+		let cross_reference = document.len();
+		let mut table = format!("xref\n0 {}\n0000000000 65535 f\r\n", objects.len() + 1);
+		for offset in offsets {
+			write!(table, "{offset:010} 00000 n\r\n").unwrap();
+		}
+		write!(table, "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{cross_reference}\n%%EOF\n", objects.len() + 1).unwrap();
+		document.extend(table.into_bytes());
+		document
+	}
+
+	/// Fills the page, which spans from the origin to the given size, with a solid color.
+	pub fn fill_page(&mut self, size: DVec2, color: Color) {
+		let graphics_state = self.graphics_state(color.a() as f64);
+		writeln!(self.content, "q\n{graphics_state}{} rg\n0 0 {} {} re f\nQ", color_components(color), number(size.x), number(size.y)).unwrap();
+	}
+
+	/// Renders the content with an additional transform applied to it.
+	pub fn with_transform(&mut self, transform: DAffine2, content: impl FnOnce(&mut Self)) {
+		let parent_transform = self.transform;
+		self.transform = parent_transform * transform;
+		content(self);
+		self.transform = parent_transform;
+	}
+
+	/// Renders the content with its opacity multiplied by that of a group it's nested in.
+	pub fn with_opacity(&mut self, opacity: f64, content: impl FnOnce(&mut Self)) {
+		let parent_opacity = self.opacity;
+		self.opacity = parent_opacity * opacity;
+		content(self);
+		self.opacity = parent_opacity;
+	}
+
+	/// Renders the content clipped to the area of the mask, if there is one. Raster masks are clipped to by their rectangle, without following their luminance.
+	pub fn with_mask(&mut self, mask: Option<&Mask>, content: impl FnOnce(&mut Self)) {
+		let Some(mask) = mask else { return content(self) };
+		let (subpaths, transform, fill_rule) = mask.clip_area();
+		let mut path = String::new();
+		for subpath in &subpaths {
+			path_commands(&mut path, subpath, self.transform * transform);
+		}
+		// Nothing is kept by an empty mask
+		if path.is_empty() {
+			return;
+		}
+
+		let clip = match fill_rule {
+			FillRule::NonZero => "W n",
+			FillRule::EvenOdd => "W* n",
+		};
+		writeln!(self.content, "q\n{path}{clip}").unwrap();
+		content(self);
+		writeln!(self.content, "Q").unwrap();
+	}
+
+	pub fn vector_data(&mut self, vector_data: &VectorData) {
+		let transform = self.transform * vector_data.transform;
+		let opacity = self.opacity * vector_data.alpha_blending.opacity as f64;
+		let mut path = String::new();
+		for subpath in vector_data.stroke_bezier_paths() {
+			path_commands(&mut path, &subpath, transform);
+		}
+		if path.is_empty() || opacity <= 0. {
+			return;
+		}
+
+		let (fill, clip) = match vector_data.style.fill_rule() {
+			FillRule::NonZero => ("f", "W n"),
+			FillRule::EvenOdd => ("f*", "W* n"),
+		};
+		match vector_data.style.fill() {
+			Fill::Solid(color) if color.a() > 0. => {
+				let graphics_state = self.graphics_state(opacity * color.a() as f64);
+				writeln!(self.content, "q\n{graphics_state}{} rg\n{path}{fill}\nQ", color_components(*color)).unwrap();
+			}
+			Fill::Gradient(gradient) if !gradient.positions.is_empty() => {
+				let graphics_state = self.graphics_state(opacity);
+				let bound_transform = bound_transform(vector_data);
+				if gradient.gradient_type == GradientType::Conic {
+					// PDF has no conic shadings, so the wedges approximating it are filled one by one, clipped to the shape
+					let wedges = conic_wedges(gradient, transform * bound_transform);
+					writeln!(self.content, "q\n{graphics_state}{path}{clip}\n{wedges}\nQ").unwrap();
+				} else {
+					let pattern = self.shading_pattern(gradient, transform * bound_transform);
+					writeln!(self.content, "q\n{graphics_state}/Pattern cs /{pattern} scn\n{path}{fill}\nQ").unwrap();
+				}
+			}
+			Fill::Pattern(pattern) => {
+				let tiles = vector_data.bounding_box().map(|bounds| pattern.tile_transforms(bounds)).unwrap_or_default();
+				if !tiles.is_empty() {
+					// A copy of the tile is drawn in each cell of the pattern's grid, clipped to the shape
+					writeln!(self.content, "q\n{path}{clip}").unwrap();
+					self.with_opacity(vector_data.alpha_blending.opacity as f64, |render| {
+						for tile_transform in tiles {
+							render.with_transform(vector_data.transform * tile_transform, |render| render.vector_data(&pattern.tile));
+						}
+					});
+					writeln!(self.content, "Q").unwrap();
+				}
+			}
+			Fill::Image(image_fill) => {
+				let images = vector_data.bounding_box().map(|bounds| image_fill.image_transforms(bounds)).unwrap_or_default();
+				if !images.is_empty() {
+					// A copy of the image is drawn for each tile, or just once when not tiling, clipped to the shape
+					let image_frame = ImageFrame {
+						transform: DAffine2::IDENTITY,
+						..image_fill.image.clone()
+					};
+					writeln!(self.content, "q\n{path}{clip}").unwrap();
+					self.with_opacity(vector_data.alpha_blending.opacity as f64, |render| {
+						for image_transform in images {
+							render.with_transform(vector_data.transform * image_transform, |render| render.image_frame(&image_frame));
+						}
+					});
+					writeln!(self.content, "Q").unwrap();
+				}
+			}
+			_ => {}
+		}
+
+		let Some(mut stroke) = vector_data.style.stroke().filter(|stroke| stroke.weight > 0.) else {
+			return;
+		};
+		// Approximate the stroke weight under non-uniform scaling by the average scale factor, which a hairline isn't scaled by
+		let scale = if stroke.hairline { 1. } else { transform.matrix2.determinant().abs().sqrt() };
+
+		let alignment = super::stroke_alignment(vector_data);
+		if alignment != StrokeAlignment::Center {
+			// The doubled stroke is clipped to the inside of the shape, or to the even-odd area between a rectangle around it and the shape for the outside
+			let mut outline = String::new();
+			let clip = if alignment == StrokeAlignment::Inside {
+				clip
+			} else {
+				let [min, max] = super::outside_stroke_bounds(vector_data, &stroke, transform);
+				writeln!(outline, "{} {} {} {} re", number(min.x), number(min.y), number(max.x - min.x), number(max.y - min.y)).unwrap();
+				"W* n"
+			};
+			for subpath in vector_data.stroke_bezier_paths() {
+				path_commands(&mut outline, &subpath, transform);
+			}
+			writeln!(self.content, "q\n{outline}{clip}").unwrap();
+			stroke = super::aligned_stroke(&stroke);
+		}
+
+		if stroke.gradient.is_some() {
+			// PDF can't paint a gradient along a path, so the pieces approximating it are stroked one by one
+			for (piece, piece_stroke) in vector_data.stroke_bezier_paths().flat_map(|subpath| stroke.gradient_pieces(&subpath, DAffine2::IDENTITY)) {
+				let mut path = String::new();
+				path_commands(&mut path, &piece, transform);
+				let color = piece_stroke.color().unwrap_or_default();
+				let graphics_state = self.graphics_state(opacity * color.a() as f64);
+				writeln!(self.content, "q\n{graphics_state}{} RG\n{}{path}S\nQ", color_components(color), stroke_state(&piece_stroke, scale)).unwrap();
+			}
+		} else if let Fill::Gradient(gradient) = &stroke.paint {
+			// Conic gradients fall back to radial shading around the same center, since the stroke can't be clipped to like a fill
+			if !gradient.positions.is_empty() {
+				let graphics_state = self.graphics_state(opacity);
+				let pattern = self.shading_pattern(gradient, transform * bound_transform(vector_data));
+				writeln!(self.content, "q\n{graphics_state}/Pattern CS /{pattern} SCN\n{}{path}S\nQ", stroke_state(&stroke, scale)).unwrap();
+			}
+		} else if let Some(color) = stroke.color().filter(|color| color.a() > 0.) {
+			let graphics_state = self.graphics_state(opacity * color.a() as f64);
+			writeln!(self.content, "q\n{graphics_state}{} RG\n{}{path}S\nQ", color_components(color), stroke_state(&stroke, scale)).unwrap();
+		}
+
+		if alignment != StrokeAlignment::Center {
+			writeln!(self.content, "Q").unwrap();
+		}
+	}
+
+	pub fn image_frame(&mut self, image_frame: &ImageFrame<Color>) {
+		let image = &image_frame.image;
+		let opacity = self.opacity * image_frame.alpha_blending.opacity as f64;
+		if image.data.is_empty() || opacity <= 0. {
+			return;
+		}
+
+		// The color and alpha channels are stored as separate images, leaving out the alpha channel when the image is opaque
+		let (data, width, height) = image.to_flat_u8();
+		let rgb = data.chunks(4).flat_map(|pixel| [pixel[0], pixel[1], pixel[2]]).collect();
+		let alpha = data.chunks(4).map(|pixel| pixel[3]).collect::<Vec<_>>();
+		let alpha = alpha.iter().any(|&alpha| alpha < 255).then_some(alpha);
+		let name = format!("Im{}", self.images.len());
+		self.images.push(PdfImage { width, height, rgb, alpha });
+
+		// The image occupies the unit square of its transform, but is drawn with its first row at the top of the unit square, which is flipped on the page
+		let flip = DAffine2::from_cols_array(&[1., 0., 0., -1., 0., 1.]);
+		let [a, b, c, d, e, f] = (self.transform * image_frame.transform * flip).to_cols_array().map(number);
+		let graphics_state = self.graphics_state(opacity);
+		writeln!(self.content, "q\n{graphics_state}{a} {b} {c} {d} {e} {f} cm\n/{name} Do\nQ").unwrap();
+	}
+
+	pub fn artboard(&mut self, artboard: &Artboard, show_background: bool, content: impl FnOnce(&mut Self)) {
+		let mut rectangle = String::new();
+		let location = artboard.location.as_dvec2();
+		path_commands(&mut rectangle, &Subpath::<PointId>::new_rect(location, location + artboard.dimensions.as_dvec2()), self.transform);
+
+		if show_background && artboard.background.a() > 0. {
+			let graphics_state = self.graphics_state(artboard.background.a() as f64);
+			writeln!(self.content, "q\n{graphics_state}{} rg\n{rectangle}f\nQ", color_components(artboard.background)).unwrap();
+		}
+
+		writeln!(self.content, "q").unwrap();
+		if artboard.clip {
+			writeln!(self.content, "{rectangle}W n").unwrap();
+		}
+		self.with_transform(DAffine2::from_translation(location) * artboard.graphic_group.transform, content);
+		writeln!(self.content, "Q").unwrap();
+	}
+
+	/// The operator setting the graphics state that draws paint with the given opacity, or nothing for opaque paint.
+	fn graphics_state(&mut self, opacity: f64) -> String {
+		let opacity = opacity.clamp(0., 1.);
+		if opacity >= 1. {
+			return String::new();
+		}
+		let index = self.opacities.iter().position(|&existing| existing == opacity).unwrap_or_else(|| {
+			self.opacities.push(opacity);
+			self.opacities.len() - 1
+		});
+		format!("/GS{index} gs\n")
+	}
+
+	/// Adds a shading pattern for the gradient, whose points are relative to the bounding box mapped to the page by `transform`, and returns its name.
+	fn shading_pattern(&mut self, gradient: &Gradient, transform: DAffine2) -> String {
+		self.shadings.push(shading(gradient, transform));
+		format!("P{}", self.shadings.len() - 1)
+	}
+}
+
+/// Formats a number without unnecessary trailing zeros, since PDF doesn't accept exponents.
+fn number(value: f64) -> String {
+	let formatted = format!("{value:.4}");
+	let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+	if formatted == "-0" {
+		"0".to_string()
+	} else {
+		formatted.to_string()
+	}
+}
+
+fn color_components(color: Color) -> String {
+	format!("{} {} {}", number(color.r() as f64), number(color.g() as f64), number(color.b() as f64))
+}
+
+fn stream_object(dictionary: &str, data: &[u8]) -> Vec<u8> {
+	let separator = if dictionary.is_empty() { "" } else { " " };
+	let mut object = format!("<< {dictionary}{separator}/Length {} >>\nstream\n", data.len()).into_bytes();
+	object.extend(data);
+	object.extend(b"\nendstream");
+	object
+}
+
+/// Maps the unit square to the bounding box of the shape, which the points of its gradients are relative to.
+fn bound_transform(vector_data: &VectorData) -> DAffine2 {
+	let bounds = vector_data.bounding_box().unwrap_or_default();
+	DAffine2::from_scale_angle_translation(bounds[1] - bounds[0], 0., bounds[0])
+}
+
+fn path_commands<Id: bezier_rs::Identifier>(path: &mut String, subpath: &Subpath<Id>, transform: DAffine2) {
+	let mut beziers = subpath.iter().map(|bezier| bezier.apply_transformation(|point| transform.transform_point2(point))).peekable();
+	let Some(first) = beziers.peek() else { return };
+	writeln!(path, "{} {} m", number(first.start.x), number(first.start.y)).unwrap();
+
+	for bezier in beziers {
+		match bezier.handles {
+			BezierHandles::Linear => writeln!(path, "{} {} l", number(bezier.end.x), number(bezier.end.y)).unwrap(),
+			_ => {
+				let BezierHandles::Cubic { handle_start, handle_end } = bezier.to_cubic().handles else {
+					unreachable!()
+				};
+				let points = [handle_start, handle_end, bezier.end].map(|point| format!("{} {}", number(point.x), number(point.y)));
+				writeln!(path, "{} c", points.join(" ")).unwrap();
+			}
+		}
+	}
+
+	if subpath.closed() {
+		writeln!(path, "h").unwrap();
+	}
+}
+
+/// Sets the weight, caps, joins, and dashes of the stroke, but not its paint.
+fn stroke_state(stroke: &Stroke, scale: f64) -> String {
+	let mut state = String::new();
+	let line_cap = match stroke.drawn_line_cap() {
+		LineCap::Butt => 0,
+		LineCap::Round => 1,
+		LineCap::Square => 2,
+	};
+	let line_join = match stroke.line_join {
+		LineJoin::Miter => 0,
+		LineJoin::Round => 1,
+		LineJoin::Bevel => 2,
+	};
+	writeln!(state, "{} w {line_cap} J {line_join} j {} M", number(stroke.weight * scale), number(stroke.drawn_miter_limit())).unwrap();
+	let dashes = stroke.drawn_dash_lengths().iter().map(|length| number(length * scale)).collect::<Vec<_>>();
+	writeln!(state, "[{}] {} d", dashes.join(" "), number(stroke.drawn_dash_offset() * scale)).unwrap();
+	state
+}
+
+/// Fills the wedges of a conic gradient, whose center and start angle are given relative to the bounding box mapped by `transform`.
+fn conic_wedges(gradient: &Gradient, transform: DAffine2) -> String {
+	let mut commands = String::new();
+	for ([center, from, to], color) in gradient.conic_wedges(transform) {
+		let [center, from, to] = [center, from, to].map(|point| format!("{} {}", number(point.x), number(point.y)));
+		writeln!(commands, "{} rg {center} m {from} l {to} l h f", color_components(color)).unwrap();
+	}
+	commands.pop();
+	commands
+}
+
+/// Builds a shading dictionary for the gradient, whose start and end points are given relative to the bounding box mapped by `transform`.
+fn shading(gradient: &Gradient, transform: DAffine2) -> String {
+	let mut stops = gradient.interpolated_stops();
+	stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+	// A single stop is a solid color
+	if stops.len() == 1 {
+		stops.push(stops[0]);
+	}
+
+	// Stitch together an interpolation between each pair of neighboring stops
+	let functions = stops
+		.windows(2)
+		.map(|pair| format!("<< /FunctionType 2 /Domain [0 1] /C0 [{}] /C1 [{}] /N 1 >>", color_components(pair[0].1), color_components(pair[1].1)))
+		.collect::<Vec<_>>();
+	let bounds = stops[1..stops.len() - 1].iter().map(|(position, _)| number(*position)).collect::<Vec<_>>();
+	let encode = vec!["0 1"; functions.len()];
+	let (domain_start, domain_end) = (stops[0].0, stops[stops.len() - 1].0);
+	let domain = if domain_end > domain_start { [domain_start, domain_end] } else { [0., 1.] };
+	let function = format!(
+		"<< /FunctionType 3 /Domain [{} {}] /Functions [{}] /Bounds [{}] /Encode [{}] >>",
+		number(domain[0]),
+		number(domain[1]),
+		functions.join(" "),
+		bounds.join(" "),
+		encode.join(" ")
+	);
+
+	let start = transform.transform_point2(gradient.start);
+	let end = transform.transform_point2(gradient.end);
+	let focal_point = transform.transform_point2(gradient.focal_point());
+	let (shading_type, coords) = match gradient.gradient_type {
+		GradientType::Linear => (2, [start.x, start.y, end.x, end.y].map(number).join(" ")),
+		GradientType::Radial | GradientType::Conic => (3, [focal_point.x, focal_point.y, 0., start.x, start.y, start.distance(end)].map(number).join(" ")),
+	};
+
+	format!(
+		"<< /ShadingType {shading_type} /ColorSpace /DeviceRGB /Coords [{coords}] /Domain [{} {}] /Function {function} /Extend [true true] >>",
+		number(domain[0]),
+		number(domain[1])
+	)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn document_text(render: &PdfRender, size: DVec2) -> String {
+		String::from_utf8_lossy(&render.to_pdf_document(size)).into_owned()
+	}
+
+	#[test]
+	fn vector_data_paths() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::new(10., 20.)));
+		vector_data.style.set_fill(Fill::Solid(Color::from_rgbf32_unchecked(1., 0., 0.)));
+		vector_data.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.));
+
+		let mut render = PdfRender::new(DAffine2::from_scale(DVec2::splat(2.)));
+		render.vector_data(&vector_data);
+		assert!(render.content.contains("0 0 m"));
+		assert!(render.content.contains("1 0 0 rg\n0 0 m"));
+		assert!(render.content.contains("h\nf\n"));
+		// The stroke weight is scaled along with the path
+		assert!(render.content.contains("0 0 0 RG\n4 w"));
+
+		// Translucent paint is drawn with a graphics state of its opacity
+		vector_data.style.set_fill(Fill::Solid(Color::from_rgbaf32_unchecked(1., 0., 0., 0.5)));
+		vector_data.style.set_fill_rule(FillRule::EvenOdd);
+		let mut render = PdfRender::new(DAffine2::IDENTITY);
+		render.vector_data(&vector_data);
+		assert!(render.content.contains("/GS0 gs\n1 0 0 rg"));
+		assert!(render.content.contains("h\nf*\n"));
+
+		let document = document_text(&render, DVec2::new(20., 40.));
+		assert!(document.starts_with("%PDF-1.4\n"));
+		assert!(document.contains("/MediaBox [0 0 20 40]"));
+		assert!(document.contains("/ExtGState << /GS0 << /ca 0.5 /CA 0.5 >> >>"));
+		assert!(document.contains("stream\n1 0 0 -1 0 40 cm\n"));
+		assert!(document.ends_with("%%EOF\n"));
+
+		// The cross-reference table points at the start of each object
+		let bytes = render.to_pdf_document(DVec2::new(20., 40.));
+		let start = bytes.windows(10).rposition(|window| window == b"startxref\n").unwrap() + 10;
+		let cross_reference = String::from_utf8_lossy(&bytes[start..]).lines().next().unwrap().parse::<usize>().unwrap();
+		assert!(bytes[cross_reference..].starts_with(b"xref\n0 5\n0000000000 65535 f\r\n"));
+		let first_entry = &bytes[cross_reference + "xref\n0 5\n".len() + 20..][..10];
+		let offset = String::from_utf8_lossy(first_entry).parse::<usize>().unwrap();
+		assert!(bytes[offset..].starts_with(b"1 0 obj\n<< /Type /Catalog"));
+	}
+
+	#[test]
+	fn gradients_and_clipping() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		let gradient = Gradient::new(DVec2::ZERO, Color::BLACK, DVec2::X, Color::WHITE, DAffine2::IDENTITY, GradientType::Linear);
+		vector_data.style.set_fill(Fill::Gradient(gradient.clone()));
+		let mut stroke = Stroke::new(None, 2.);
+		stroke.paint = Fill::Gradient(gradient);
+		vector_data.style.set_stroke(stroke);
+
+		// Both the fill and stroke are painted with a shading pattern, which is placed on the page's unflipped coordinates
+		let mut render = PdfRender::new(DAffine2::IDENTITY);
+		render.vector_data(&vector_data);
+		assert!(render.content.contains("/Pattern cs /P0 scn"));
+		assert!(render.content.contains("/Pattern CS /P1 SCN"));
+		let document = document_text(&render, DVec2::splat(10.));
+		assert!(document.contains("/P0 << /PatternType 2 /Shading << /ShadingType 2 /ColorSpace /DeviceRGB /Coords [0 0 10 0]"));
+		assert!(document.contains("/Matrix [1 0 0 -1 0 10]"));
+
+		// Artboards clip their contents to their bounds
+		let mut artboard = Artboard::new(glam::IVec2::new(5, 5), glam::IVec2::new(10, 10));
+		artboard.background = Color::WHITE;
+		let mut render = PdfRender::new(DAffine2::IDENTITY);
+		render.artboard(&artboard, true, |render| render.vector_data(&vector_data));
+		assert!(render.content.starts_with("q\n1 1 1 rg\n5 5 m"));
+		assert!(render.content.contains("h\nW n\n"));
+		// The content is placed relative to the artboard's corner
+		assert!(render.content.contains("/Pattern cs /P0 scn\n5 5 m"));
+	}
+}
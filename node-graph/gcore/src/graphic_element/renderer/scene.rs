@@ -0,0 +1,294 @@
+//! A structured description of rendered artwork, written as JSON so that external renderers and web viewers can draw Graphite's output themselves.
+//!
+//! The schema, at [`SCENE_SCHEMA_VERSION`], is made up of the types in this module serialized with serde:
+//! - A [`Scene`] is the root object, holding the size of the page and the elements drawn on it from bottom to top.
+//! - Every [`SceneElement`] and [`ScenePaint`] is an object with a `"type"` field naming its variant in snake case, such as `"group"` or `"solid"`, next to the fields of that variant.
+//! - Transforms are the six entries `[a, b, c, d, e, f]` of an affine matrix, in the same order as the SVG `matrix()` transform function, mapping the element's own space to its parent's.
+//! - Colors are hex strings with an alpha channel, like `"#FF000080"`.
+//! - Paths are SVG path data in the element's own space.
+//! - Blend modes are CSS `mix-blend-mode` values, like `"multiply"`.
+
+use super::{image_data_url, GraphicElementRendered, RenderParams};
+use crate::mask::Mask;
+use crate::raster::BlendMode;
+use crate::vector::style::{Fill, Gradient, GradientType, ImageFillMode, Stroke};
+use crate::Color;
+
+use glam::{DAffine2, DVec2};
+
+/// The version of the scene schema, increased whenever it changes in a way that readers of older versions can't handle.
+pub const SCENE_SCHEMA_VERSION: u32 = 3;
+
+/// The root of a scene.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct Scene {
+	/// The [`SCENE_SCHEMA_VERSION`] the scene was written with.
+	pub version: u32,
+	/// The size of the page in pixels, with the origin at its top left corner.
+	pub width: f64,
+	pub height: f64,
+	/// Maps the document, which the elements are placed in, to the page.
+	pub transform: [f64; 6],
+	/// The elements, from the bottom to the top.
+	pub elements: Vec<SceneElement>,
+}
+
+impl Scene {
+	/// Describes the content placed on a page of the given size by the transform.
+	pub fn new(content: &impl GraphicElementRendered, render_params: &RenderParams, transform: DAffine2, size: DVec2) -> Self {
+		Self {
+			version: SCENE_SCHEMA_VERSION,
+			width: size.x,
+			height: size.y,
+			transform: matrix(transform),
+			elements: content.render_scene(render_params),
+		}
+	}
+}
+
+/// An element of a [`Scene`], drawn over the elements before it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SceneElement {
+	/// Elements composited together, and then onto what's below, with the opacity and blend mode.
+	Group {
+		transform: [f64; 6],
+		opacity: f32,
+		blend_mode: String,
+		/// Limits what's shown of the children, placed in the same space as them.
+		#[serde(default)]
+		mask: Option<SceneMask>,
+		children: Vec<SceneElement>,
+	},
+	/// Vector geometry, filled and then stroked.
+	Path {
+		transform: [f64; 6],
+		opacity: f32,
+		blend_mode: String,
+		path: String,
+		/// The bounding box of the path as `[min x, min y, max x, max y]`, which the points of a gradient fill are relative to.
+		bounds: [f64; 4],
+		fill: ScenePaint,
+		/// Either `"nonzero"` or `"evenodd"`.
+		fill_rule: String,
+		stroke: Option<SceneStroke>,
+	},
+	/// A raster image stretched over the unit square of its transform.
+	Image {
+		transform: [f64; 6],
+		opacity: f32,
+		blend_mode: String,
+		/// The size of the image in pixels.
+		width: u32,
+		height: u32,
+		/// The image as a PNG data URL.
+		href: String,
+	},
+	/// A line of text in black sans-serif, with its baseline starting at the origin.
+	Text { text: String, font_size: f64 },
+	/// A rectangular area of the document, filled with its background and then its children, which are clipped to it if `clip` is set.
+	Artboard {
+		/// The top left corner and the size of the artboard.
+		location: [i32; 2],
+		dimensions: [i32; 2],
+		/// Left out when artboards are hidden, such as for a transparent export.
+		background: Option<String>,
+		clip: bool,
+		/// Places the children relative to the top left corner of the artboard.
+		transform: [f64; 6],
+		children: Vec<SceneElement>,
+	},
+}
+
+/// What a [`SceneElement::Group`] shows of its children.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SceneMask {
+	/// Keeps only what's inside the path, like the SVG `<clipPath>` element.
+	Clip {
+		transform: [f64; 6],
+		path: String,
+		/// Either `"nonzero"` or `"evenodd"`.
+		fill_rule: String,
+	},
+	/// Keeps what's over the image, stretched over the unit square of its transform, in proportion to its luminance and alpha, like the SVG `<mask>` element.
+	Luminance {
+		transform: [f64; 6],
+		/// The size of the image in pixels.
+		width: u32,
+		height: u32,
+		/// The image as a PNG data URL, or empty for an image without any pixels, which keeps nothing.
+		href: String,
+	},
+}
+
+impl SceneMask {
+	pub fn new(mask: &Mask) -> Self {
+		match mask {
+			Mask::Clip(_) => {
+				let (subpaths, transform, fill_rule) = mask.clip_area();
+				let mut path = String::new();
+				for subpath in &subpaths {
+					let _ = subpath.subpath_to_svg(&mut path, DAffine2::IDENTITY);
+				}
+				Self::Clip {
+					transform: matrix(transform),
+					path,
+					fill_rule: fill_rule.to_string(),
+				}
+			}
+			Mask::Luminance(image_frame) => Self::Luminance {
+				transform: matrix(image_frame.transform),
+				width: image_frame.image.width,
+				height: image_frame.image.height,
+				href: if image_frame.image.data.is_empty() { String::new() } else { image_data_url(&image_frame.image) },
+			},
+		}
+	}
+}
+
+/// How the inside of a [`SceneElement::Path`] is painted.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScenePaint {
+	None,
+	Solid {
+		color: String,
+	},
+	/// The start and end points are in the unit square of the path's bounds, from `[0, 0]` at its top left to `[1, 1]` at its bottom right.
+	Gradient {
+		/// One of `"linear"`, `"radial"`, or `"conic"`.
+		gradient_type: String,
+		start: [f64; 2],
+		end: [f64; 2],
+		/// Where the colors of a radial gradient radiate out from, if not its start.
+		focal_point: Option<[f64; 2]>,
+		/// Blended between in sRGB, with extra stops standing in for the blending of gradients in other color spaces.
+		stops: Vec<SceneGradientStop>,
+	},
+	/// The tile repeated in a grid of cells of the spacing, which the transform places in the path's own space.
+	Pattern {
+		tile: Vec<SceneElement>,
+		spacing: [f64; 2],
+		transform: [f64; 6],
+	},
+	/// A raster image sized to the path's bounds by the mode, one of `"fit"`, `"fill"`, or `"tile"`, and then placed by the transform.
+	Image {
+		href: String,
+		width: u32,
+		height: u32,
+		mode: String,
+		transform: [f64; 6],
+	},
+}
+
+/// A color at a position from 0 to 1 along a gradient.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct SceneGradientStop {
+	pub offset: f64,
+	pub color: String,
+}
+
+/// The outline drawn along a [`SceneElement::Path`], with the same meaning as the SVG stroke attributes.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct SceneStroke {
+	/// Painted across the bounds of the path in the same way as its fill.
+	pub paint: ScenePaint,
+	pub width: f64,
+	pub dash_lengths: Vec<f64>,
+	pub dash_offset: f64,
+	/// One of `"butt"`, `"round"`, or `"square"`.
+	pub line_cap: String,
+	/// One of `"miter"`, `"bevel"`, or `"round"`.
+	pub line_join: String,
+	pub miter_limit: f64,
+	/// Replaces the paint with these stops spread along the length of each subpath, when present.
+	pub gradient: Option<Vec<SceneGradientStop>>,
+	/// One of `"center"`, `"inside"`, or `"outside"`, where the last two draw the whole width of the stroke on that side of the path.
+	pub alignment: String,
+	/// Draws the stroke at its width in device pixels, ignoring the scale of every transform, like the SVG `vector-effect="non-scaling-stroke"` attribute.
+	#[serde(default)]
+	pub hairline: bool,
+}
+
+impl ScenePaint {
+	pub fn new(fill: &Fill, render_params: &RenderParams) -> Self {
+		match fill {
+			Fill::None => Self::None,
+			Fill::Solid(color) => Self::Solid { color: color_hex(*color) },
+			Fill::Gradient(gradient) => {
+				// Like the other renderers, the gradient's own transform is left out since its points already place it within the bounds
+				let gradient_type = match gradient.gradient_type {
+					GradientType::Linear => "linear",
+					GradientType::Radial => "radial",
+					GradientType::Conic => "conic",
+				};
+				Self::Gradient {
+					gradient_type: gradient_type.to_string(),
+					start: gradient.start.to_array(),
+					end: gradient.end.to_array(),
+					focal_point: gradient.focal_point.map(DVec2::to_array),
+					stops: gradient_stops(gradient),
+				}
+			}
+			Fill::Pattern(pattern) => Self::Pattern {
+				tile: pattern.tile.render_scene(render_params),
+				spacing: pattern.spacing.to_array(),
+				transform: matrix(pattern.transform),
+			},
+			Fill::Image(image_fill) => {
+				let mode = match image_fill.mode {
+					ImageFillMode::Fit => "fit",
+					ImageFillMode::Fill => "fill",
+					ImageFillMode::Tile => "tile",
+				};
+				Self::Image {
+					href: image_data_url(&image_fill.image.image),
+					width: image_fill.image.image.width,
+					height: image_fill.image.image.height,
+					mode: mode.to_string(),
+					transform: matrix(image_fill.transform),
+				}
+			}
+		}
+	}
+}
+
+impl SceneStroke {
+	pub fn new(stroke: &Stroke, render_params: &RenderParams) -> Self {
+		Self {
+			paint: ScenePaint::new(&stroke.paint, render_params),
+			width: stroke.weight,
+			dash_lengths: stroke.drawn_dash_lengths(),
+			dash_offset: stroke.drawn_dash_offset(),
+			line_cap: stroke.drawn_line_cap().to_string(),
+			line_join: stroke.line_join.to_string(),
+			miter_limit: stroke.drawn_miter_limit(),
+			gradient: stroke.gradient.as_ref().map(gradient_stops),
+			alignment: stroke.alignment.to_string(),
+			hairline: stroke.hairline,
+		}
+	}
+}
+
+/// The entries of the affine transform in the order of the SVG `matrix()` transform function.
+pub(super) fn matrix(transform: DAffine2) -> [f64; 6] {
+	transform.to_cols_array()
+}
+
+pub(super) fn color_hex(color: Color) -> String {
+	format!("#{}", color.rgba_hex())
+}
+
+/// The CSS name of the blend mode, falling back to normal for those CSS doesn't have.
+pub(super) fn blend_mode_name(blend_mode: BlendMode) -> String {
+	blend_mode.to_svg_style_name().unwrap_or("normal").to_string()
+}
+
+fn gradient_stops(gradient: &Gradient) -> Vec<SceneGradientStop> {
+	gradient
+		.interpolated_stops()
+		.into_iter()
+		.map(|(offset, color)| SceneGradientStop { offset, color: color_hex(color) })
+		.collect()
+}
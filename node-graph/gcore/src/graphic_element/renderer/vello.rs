@@ -0,0 +1,385 @@
+use crate::mask::Mask;
+use crate::raster::{BlendMode, ImageFrame};
+use crate::vector::style::{Fill, FillRule, Gradient, GradientType, LineCap, LineJoin, Stroke, StrokeAlignment};
+use crate::vector::VectorData;
+use crate::{AlphaBlending, Artboard, Color};
+
+use bezier_rs::{BezierHandles, Subpath};
+use glam::{DAffine2, DVec2};
+use std::sync::Arc;
+use vello::kurbo::{self, Affine, BezPath, Rect};
+use vello::peniko::{self, Brush, ColorStop, Compose, Mix};
+
+/// Mutable state used whilst encoding the content into a vello scene, which is rendered on the GPU through wgpu rather than rasterized on the CPU.
+///
+/// Paths are encoded with the transforms they're nested in already applied, so strokes are scaled like the other renderers do. Groups are composited as layers,
+/// so their opacity and blend mode apply to their elements together. Text isn't drawn.
+pub struct VelloRender {
+	scene: vello::Scene,
+	/// The area of the page, from its origin to its size, which layers are clipped to.
+	page: Rect,
+	/// The transform from the coordinate space of the content being rendered to the page, whose origin is the top left corner.
+	pub transform: DAffine2,
+}
+
+impl VelloRender {
+	pub fn new(transform: DAffine2, size: DVec2) -> Self {
+		Self {
+			scene: vello::Scene::new(),
+			page: Rect::new(0., 0., size.x, size.y),
+			transform,
+		}
+	}
+
+	/// The scene the content has been encoded into, ready to be rendered by a `vello::Renderer`.
+	pub fn scene(&self) -> &vello::Scene {
+		&self.scene
+	}
+
+	pub fn into_scene(self) -> vello::Scene {
+		self.scene
+	}
+
+	/// Fills the whole page with a solid color.
+	pub fn fill_page(&mut self, color: Color) {
+		self.scene.fill(peniko::Fill::NonZero, Affine::IDENTITY, vello_color(color), None, &self.page);
+	}
+
+	/// Renders the content with an additional transform applied to it.
+	pub fn with_transform(&mut self, transform: DAffine2, content: impl FnOnce(&mut Self)) {
+		let parent_transform = self.transform;
+		self.transform = parent_transform * transform;
+		content(self);
+		self.transform = parent_transform;
+	}
+
+	/// Renders the content in a layer clipped to the area of the mask, if there is one, whose fill rule clipping can't follow.
+	/// Raster masks are clipped to by their rectangle, without following their luminance.
+	pub fn with_mask(&mut self, mask: Option<&Mask>, content: impl FnOnce(&mut Self)) {
+		let Some(mask) = mask else { return content(self) };
+		let (subpaths, transform, _) = mask.clip_area();
+		let mut path = BezPath::new();
+		for subpath in &subpaths {
+			path_elements(&mut path, subpath, self.transform * transform);
+		}
+		// Nothing is kept by an empty mask
+		if path.elements().is_empty() {
+			return;
+		}
+
+		self.scene.push_layer(Mix::Clip, 1., Affine::IDENTITY, &path);
+		content(self);
+		self.scene.pop_layer();
+	}
+
+	/// Renders the content into a layer that's composited onto what's below with the opacity and blend mode. Opaque content with the normal blend mode is drawn directly.
+	pub fn with_alpha_blending(&mut self, alpha_blending: AlphaBlending, content: impl FnOnce(&mut Self)) {
+		if alpha_blending.opacity <= 0. {
+			return;
+		}
+		if alpha_blending.opacity >= 1. && alpha_blending.blend_mode == BlendMode::Normal {
+			content(self);
+			return;
+		}
+
+		self.scene.push_layer(blend_mode(alpha_blending.blend_mode), alpha_blending.opacity, Affine::IDENTITY, &self.page);
+		content(self);
+		self.scene.pop_layer();
+	}
+
+	pub fn vector_data(&mut self, vector_data: &VectorData) {
+		let transform = self.transform * vector_data.transform;
+		let mut path = BezPath::new();
+		for subpath in vector_data.stroke_bezier_paths() {
+			path_elements(&mut path, &subpath, transform);
+		}
+		if path.elements().is_empty() {
+			return;
+		}
+
+		self.with_alpha_blending(vector_data.alpha_blending, |render| {
+			render.fill(vector_data, &path, transform);
+			render.stroke(vector_data, &path, transform);
+		});
+	}
+
+	pub fn image_frame(&mut self, image_frame: &ImageFrame<Color>) {
+		let image = &image_frame.image;
+		if image.data.is_empty() {
+			return;
+		}
+
+		let (data, width, height) = image.to_flat_u8();
+		let image = peniko::Image::new(peniko::Blob::new(Arc::new(data)), peniko::Format::Rgba8, width, height);
+		// The image occupies the unit square of its transform, while vello draws it with a pixel per unit
+		let transform = self.transform * image_frame.transform * DAffine2::from_scale(DVec2::new(width as f64, height as f64).recip());
+		self.with_alpha_blending(image_frame.alpha_blending, |render| render.scene.draw_image(&image, affine(transform)));
+	}
+
+	pub fn artboard(&mut self, artboard: &Artboard, show_background: bool, content: impl FnOnce(&mut Self)) {
+		let location = artboard.location.as_dvec2();
+		let rectangle = Rect::from_points(point(location), point(location + artboard.dimensions.as_dvec2()));
+
+		if show_background && artboard.background.a() > 0. {
+			self.scene.fill(peniko::Fill::NonZero, affine(self.transform), vello_color(artboard.background), None, &rectangle);
+		}
+
+		if artboard.clip {
+			self.scene.push_layer(Mix::Clip, 1., affine(self.transform), &rectangle);
+		}
+		self.with_transform(DAffine2::from_translation(location) * artboard.graphic_group.transform, content);
+		if artboard.clip {
+			self.scene.pop_layer();
+		}
+	}
+
+	/// Fills the path, whose transform has already been applied, with the shape's fill.
+	fn fill(&mut self, vector_data: &VectorData, path: &BezPath, transform: DAffine2) {
+		let fill_rule = match vector_data.style.fill_rule() {
+			FillRule::NonZero => peniko::Fill::NonZero,
+			FillRule::EvenOdd => peniko::Fill::EvenOdd,
+		};
+
+		match vector_data.style.fill() {
+			Fill::Solid(color) if color.a() > 0. => self.scene.fill(fill_rule, Affine::IDENTITY, vello_color(*color), None, path),
+			Fill::Gradient(gradient) if !gradient.positions.is_empty() => {
+				let brush_transform = transform * bound_transform(vector_data);
+				self.scene.fill(fill_rule, Affine::IDENTITY, &gradient_brush(gradient), Some(affine(brush_transform)), path);
+			}
+			Fill::Pattern(pattern) => {
+				let tiles = vector_data.bounding_box().map(|bounds| pattern.tile_transforms(bounds)).unwrap_or_default();
+				if !tiles.is_empty() {
+					// A copy of the tile is drawn in each cell of the pattern's grid, clipped to the shape (whose fill rule clipping can't follow)
+					self.scene.push_layer(Mix::Clip, 1., Affine::IDENTITY, path);
+					for tile_transform in tiles {
+						self.with_transform(vector_data.transform * tile_transform, |render| render.vector_data(&pattern.tile));
+					}
+					self.scene.pop_layer();
+				}
+			}
+			Fill::Image(image_fill) => {
+				let images = vector_data.bounding_box().map(|bounds| image_fill.image_transforms(bounds)).unwrap_or_default();
+				if !images.is_empty() {
+					// A copy of the image is drawn for each tile, or just once when not tiling, clipped to the shape
+					let image_frame = ImageFrame {
+						transform: DAffine2::IDENTITY,
+						alpha_blending: AlphaBlending::default(),
+						..image_fill.image.clone()
+					};
+					self.scene.push_layer(Mix::Clip, 1., Affine::IDENTITY, path);
+					for image_transform in images {
+						self.with_transform(vector_data.transform * image_transform, |render| render.image_frame(&image_frame));
+					}
+					self.scene.pop_layer();
+				}
+			}
+			_ => {}
+		}
+	}
+
+	/// Strokes the path, whose transform has already been applied, with the shape's stroke.
+	fn stroke(&mut self, vector_data: &VectorData, path: &BezPath, transform: DAffine2) {
+		let Some(mut stroke) = vector_data.style.stroke().filter(|stroke| stroke.weight > 0.) else {
+			return;
+		};
+		// Approximate the stroke weight under non-uniform scaling by the average scale factor, which a hairline isn't scaled by
+		let scale = if stroke.hairline { 1. } else { transform.matrix2.determinant().abs().sqrt() };
+
+		// The doubled stroke is clipped to the inside of the shape, or drawn in a layer that the inside of the shape is then cut out of for the outside
+		let alignment = super::stroke_alignment(vector_data);
+		match alignment {
+			StrokeAlignment::Center => {}
+			StrokeAlignment::Inside => self.scene.push_layer(Mix::Clip, 1., Affine::IDENTITY, path),
+			StrokeAlignment::Outside => self.scene.push_layer(Mix::Normal, 1., Affine::IDENTITY, &self.page),
+		}
+		if alignment != StrokeAlignment::Center {
+			stroke = super::aligned_stroke(&stroke);
+		}
+
+		if stroke.gradient.is_some() {
+			// The pieces approximating the gradient along the path are stroked one by one
+			for (piece, piece_stroke) in vector_data.stroke_bezier_paths().flat_map(|subpath| stroke.gradient_pieces(&subpath, DAffine2::IDENTITY)) {
+				let mut piece_path = BezPath::new();
+				path_elements(&mut piece_path, &piece, transform);
+				let color = piece_stroke.color().unwrap_or_default();
+				self.scene.stroke(&stroke_style(&piece_stroke, scale), Affine::IDENTITY, vello_color(color), None, &piece_path);
+			}
+		} else if let Fill::Gradient(gradient) = &stroke.paint {
+			if !gradient.positions.is_empty() {
+				let brush_transform = transform * bound_transform(vector_data);
+				self.scene
+					.stroke(&stroke_style(&stroke, scale), Affine::IDENTITY, &gradient_brush(gradient), Some(affine(brush_transform)), path);
+			}
+		} else if let Some(color) = stroke.color().filter(|color| color.a() > 0.) {
+			self.scene.stroke(&stroke_style(&stroke, scale), Affine::IDENTITY, vello_color(color), None, path);
+		}
+
+		match alignment {
+			StrokeAlignment::Center => {}
+			StrokeAlignment::Inside => self.scene.pop_layer(),
+			StrokeAlignment::Outside => {
+				// Cut the inside of the shape out of the stroke's layer
+				let fill_rule = match vector_data.style.fill_rule() {
+					FillRule::NonZero => peniko::Fill::NonZero,
+					FillRule::EvenOdd => peniko::Fill::EvenOdd,
+				};
+				self.scene.push_layer(peniko::BlendMode::new(Mix::Normal, Compose::DestOut), 1., Affine::IDENTITY, &self.page);
+				self.scene.fill(fill_rule, Affine::IDENTITY, peniko::Color::BLACK, None, path);
+				self.scene.pop_layer();
+				self.scene.pop_layer();
+			}
+		}
+	}
+}
+
+fn affine(transform: DAffine2) -> Affine {
+	Affine::new(transform.to_cols_array())
+}
+
+fn point(point: DVec2) -> kurbo::Point {
+	kurbo::Point::new(point.x, point.y)
+}
+
+fn vello_color(color: Color) -> peniko::Color {
+	peniko::Color::rgba(color.r() as f64, color.g() as f64, color.b() as f64, color.a() as f64)
+}
+
+/// The blend mode that a layer is composited with, falling back to normal for those vello doesn't have.
+fn blend_mode(blend_mode: BlendMode) -> peniko::BlendMode {
+	let mix = match blend_mode {
+		BlendMode::Darken => Mix::Darken,
+		BlendMode::Multiply => Mix::Multiply,
+		BlendMode::ColorBurn => Mix::ColorBurn,
+		BlendMode::Lighten => Mix::Lighten,
+		BlendMode::Screen => Mix::Screen,
+		BlendMode::ColorDodge => Mix::ColorDodge,
+		BlendMode::Overlay => Mix::Overlay,
+		BlendMode::SoftLight => Mix::SoftLight,
+		BlendMode::HardLight => Mix::HardLight,
+		BlendMode::Difference => Mix::Difference,
+		BlendMode::Exclusion => Mix::Exclusion,
+		BlendMode::Hue => Mix::Hue,
+		BlendMode::Saturation => Mix::Saturation,
+		BlendMode::Color => Mix::Color,
+		BlendMode::Luminosity => Mix::Luminosity,
+		_ => Mix::Normal,
+	};
+	peniko::BlendMode::new(mix, Compose::SrcOver)
+}
+
+/// Maps the unit square to the bounding box of the shape, which the points of its gradients are relative to.
+fn bound_transform(vector_data: &VectorData) -> DAffine2 {
+	let bounds = vector_data.bounding_box().unwrap_or_default();
+	DAffine2::from_scale_angle_translation(bounds[1] - bounds[0], 0., bounds[0])
+}
+
+/// The gradient with its points in the unit square of the shape's bounds, blended between its interpolated stops.
+fn gradient_brush(gradient: &Gradient) -> Brush {
+	let mut stops = gradient.interpolated_stops();
+	stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+	let stops = stops
+		.into_iter()
+		.map(|(offset, stop_color)| ColorStop {
+			offset: offset as f32,
+			color: vello_color(stop_color),
+		})
+		.collect::<Vec<_>>();
+
+	let (start, end) = (point(gradient.start), point(gradient.end));
+	let brush = match gradient.gradient_type {
+		GradientType::Linear => peniko::Gradient::new_linear(start, end),
+		GradientType::Radial => peniko::Gradient::new_two_point_radial(point(gradient.focal_point()), 0., start, gradient.start.distance(gradient.end) as f32),
+		GradientType::Conic => {
+			let direction = gradient.end - gradient.start;
+			let angle = direction.y.atan2(direction.x) as f32;
+			peniko::Gradient::new_sweep(start, angle, angle + std::f32::consts::TAU)
+		}
+	};
+	Brush::Gradient(brush.with_stops(stops.as_slice()))
+}
+
+fn path_elements<Id: bezier_rs::Identifier>(path: &mut BezPath, subpath: &Subpath<Id>, transform: DAffine2) {
+	let mut beziers = subpath.iter().map(|bezier| bezier.apply_transformation(|point| transform.transform_point2(point))).peekable();
+	let Some(first) = beziers.peek() else { return };
+	path.move_to(point(first.start));
+
+	for bezier in beziers {
+		match bezier.handles {
+			BezierHandles::Linear => path.line_to(point(bezier.end)),
+			BezierHandles::Quadratic { handle } => path.quad_to(point(handle), point(bezier.end)),
+			BezierHandles::Cubic { handle_start, handle_end } => path.curve_to(point(handle_start), point(handle_end), point(bezier.end)),
+		}
+	}
+
+	if subpath.closed() {
+		path.close_path();
+	}
+}
+
+/// The weight, caps, joins, and dashes of the stroke, scaled along with the path.
+fn stroke_style(stroke: &Stroke, scale: f64) -> kurbo::Stroke {
+	let cap = match stroke.drawn_line_cap() {
+		LineCap::Butt => kurbo::Cap::Butt,
+		LineCap::Round => kurbo::Cap::Round,
+		LineCap::Square => kurbo::Cap::Square,
+	};
+	let join = match stroke.line_join {
+		LineJoin::Miter => kurbo::Join::Miter,
+		LineJoin::Round => kurbo::Join::Round,
+		LineJoin::Bevel => kurbo::Join::Bevel,
+	};
+	let dashes = stroke.drawn_dash_lengths().into_iter().map(|length| length * scale);
+
+	kurbo::Stroke::new(stroke.weight * scale)
+		.with_caps(cap)
+		.with_join(join)
+		.with_miter_limit(stroke.drawn_miter_limit())
+		.with_dashes(stroke.drawn_dash_offset() * scale, dashes)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::renderer::{GraphicElementRendered, ImageRenderMode, RenderParams};
+	use crate::vector::style::ViewMode;
+	use crate::GraphicGroup;
+
+	fn render_params() -> RenderParams {
+		RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, false)
+	}
+
+	#[test]
+	fn encode_vector_data_and_groups() {
+		let mut square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		square.style.set_fill(Fill::Solid(Color::BLACK));
+		square.style.set_stroke(Stroke::new(Some(Color::WHITE), 2.));
+
+		// The fill and stroke are each a path, drawn directly for an opaque group
+		let mut group = GraphicGroup::EMPTY;
+		group.push(square.clone().into());
+		let mut render = VelloRender::new(DAffine2::IDENTITY, DVec2::splat(100.));
+		group.render_vello(&mut render, &render_params());
+		let encoding = render.scene().encoding();
+		assert_eq!(encoding.n_paths, 2);
+		assert_eq!(encoding.n_clips, 0);
+
+		// A translucent group is composited as a layer
+		group.alpha_blending.opacity = 0.5;
+		let mut render = VelloRender::new(DAffine2::IDENTITY, DVec2::splat(100.));
+		group.render_vello(&mut render, &render_params());
+		let encoding = render.scene().encoding();
+		assert!(encoding.n_clips > 0);
+		assert_eq!(encoding.n_open_clips, 0);
+
+		// Nothing is drawn for an invisible shape, and an outside stroke's layer is closed
+		square.alpha_blending.opacity = 0.;
+		let mut render = VelloRender::new(DAffine2::IDENTITY, DVec2::splat(100.));
+		square.render_vello(&mut render, &render_params());
+		assert_eq!(render.scene().encoding().n_paths, 0);
+		square.alpha_blending.opacity = 1.;
+		square.style.set_stroke(Stroke::new(Some(Color::WHITE), 2.).with_alignment(StrokeAlignment::Outside));
+		let mut render = VelloRender::new(DAffine2::IDENTITY, DVec2::splat(100.));
+		square.render_vello(&mut render, &render_params());
+		assert_eq!(render.scene().encoding().n_open_clips, 0);
+	}
+}
@@ -0,0 +1,440 @@
+use crate::transform::Footprint;
+use crate::{Artboard, GraphicElement, GraphicGroup, Node};
+
+use core::fmt::Write;
+use core::future::Future;
+use dyn_any::{DynAny, StaticType};
+use glam::{DAffine2, DVec2};
+use rand::{Rng, SeedableRng};
+
+/// A table of text records parsed from comma-separated values, where the first line holds the column names.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DataTable {
+	pub columns: Vec<String>,
+	pub rows: Vec<Vec<String>>,
+}
+
+impl DataTable {
+	/// Parses comma-separated values, supporting double quoted fields which may contain commas, line breaks, and `""` escaped quotes.
+	pub fn from_csv(csv: &str) -> Self {
+		let mut records = Vec::new();
+		let mut record = Vec::new();
+		let mut field = String::new();
+		let mut quoted = false;
+		let mut chars = csv.chars().peekable();
+
+		while let Some(char) = chars.next() {
+			match (char, quoted) {
+				('"', true) if chars.peek() == Some(&'"') => {
+					chars.next();
+					field.push('"');
+				}
+				('"', true) => quoted = false,
+				('"', false) if field.is_empty() => quoted = true,
+				(',', false) => record.push(core::mem::take(&mut field)),
+				('\r', false) => {}
+				('\n', false) => {
+					record.push(core::mem::take(&mut field));
+					records.push(core::mem::take(&mut record));
+				}
+				(char, _) => field.push(char),
+			}
+		}
+		if !field.is_empty() || !record.is_empty() {
+			record.push(field);
+			records.push(record);
+		}
+
+		// Skip blank lines
+		let mut records = records.into_iter().filter(|record| !(record.len() == 1 && record[0].trim().is_empty()));
+		let columns = records.next().unwrap_or_default().into_iter().map(|column| column.trim().to_string()).collect();
+		Self { columns, rows: records.collect() }
+	}
+
+	/// Looks up the value in the specified row of the column with the given name.
+	pub fn field(&self, row: usize, column: &str) -> Option<&str> {
+		let column = self.columns.iter().position(|name| name == column.trim())?;
+		self.rows.get(row)?.get(column).map(String::as_str)
+	}
+}
+
+pub struct ForEachRowNode<Template, Table, Columns, CellSize, Artboards> {
+	template: Template,
+	table: Table,
+	columns: Columns,
+	cell_size: CellSize,
+	artboards: Artboards,
+}
+
+/// Evaluates the template once for every row of the data table, laying out the results in a grid.
+///
+/// The row being evaluated is passed to the template through [`Footprint::data_row`], where [`RowFieldNode`]s read its fields.
+#[node_macro::node_fn(ForEachRowNode)]
+async fn for_each_row<Fut: Future<Output = GraphicGroup>>(
+	footprint: Footprint,
+	template: impl Node<Footprint, Output = Fut>,
+	table: String,
+	columns: u32,
+	cell_size: DVec2,
+	artboards: bool,
+) -> GraphicGroup {
+	let table = DataTable::from_csv(&table);
+	let columns = columns.max(1) as usize;
+
+	let mut result = GraphicGroup::EMPTY;
+	for row in 0..table.rows.len() {
+		let offset = DVec2::new((row % columns) as f64, (row / columns) as f64) * cell_size;
+		let mut row_footprint = footprint;
+		row_footprint.transform *= DAffine2::from_translation(offset);
+		row_footprint.data_row = Some(row);
+
+		let mut graphic_group = self.template.eval(row_footprint).await;
+		if artboards {
+			let mut artboard = Artboard::new(offset.round().as_ivec2(), cell_size.round().as_ivec2());
+			artboard.clip = true;
+			artboard.graphic_group = graphic_group;
+			result.push(artboard.into());
+		} else {
+			graphic_group.transform = DAffine2::from_translation(offset) * graphic_group.transform;
+			result.push(graphic_group.into());
+		}
+	}
+
+	result
+}
+
+pub struct RowFieldNode<Table, Column> {
+	table: Table,
+	column: Column,
+}
+
+/// Reads a field of the data table row currently being evaluated by a [`ForEachRowNode`], previewing the first row elsewhere.
+#[node_macro::node_fn(RowFieldNode)]
+fn row_field(footprint: Footprint, table: String, column: String) -> String {
+	let row = footprint.data_row.unwrap_or_default();
+	DataTable::from_csv(&table).field(row, &column).unwrap_or_default().to_string()
+}
+
+pub struct SequenceNumberNode<Start, Step, Digits, Prefix, Suffix> {
+	start: Start,
+	step: Step,
+	digits: Digits,
+	prefix: Prefix,
+	suffix: Suffix,
+}
+
+/// Generates a serial number for the data table row currently being evaluated, such as `SN-00042` for the 42nd label of a batch.
+///
+/// The number is zero padded to the given count of digits, so that serial numbers sort correctly as text.
+#[node_macro::node_fn(SequenceNumberNode)]
+fn sequence_number(footprint: Footprint, start: u32, step: u32, digits: u32, prefix: String, suffix: String) -> String {
+	let row = footprint.data_row.unwrap_or_default() as u64;
+	let number = start as u64 + step as u64 * row;
+	format!("{prefix}{number:0width$}{suffix}", width = digits as usize)
+}
+
+pub struct UuidNode<Seed> {
+	seed: Seed,
+}
+
+/// Generates a random (version 4) UUID for the data table row currently being evaluated.
+///
+/// The UUID is derived from the seed and the row index, so the same batch always regenerates with the same identifiers. Change the seed to produce a new batch.
+#[node_macro::node_fn(UuidNode)]
+fn uuid(footprint: Footprint, seed: u32) -> String {
+	let row = footprint.data_row.unwrap_or_default() as u64;
+	let mut rng = rand::rngs::StdRng::seed_from_u64(((seed as u64) << 32) ^ row);
+	let mut bytes: [u8; 16] = rng.gen();
+	// Set the version (4) and variant (RFC 4122) bits
+	bytes[6] = (bytes[6] & 0x0f) | 0x40;
+	bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+	let mut uuid = String::with_capacity(36);
+	for (index, byte) in bytes.iter().enumerate() {
+		if matches!(index, 4 | 6 | 8 | 10) {
+			uuid.push('-');
+		}
+		let _ = write!(uuid, "{byte:02x}");
+	}
+	uuid
+}
+
+/// The most values that a [`ParameterSweepNode`] evaluates its template for, so a mistyped range can't generate an endless number of copies.
+const MAX_SWEEP_VALUES: usize = 1000;
+
+/// A value given to the [`ParameterNode`] of a particular name while a [`ParameterSweepNode`] evaluates its template.
+#[derive(Clone, Copy, Debug, PartialEq, DynAny)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParameterBinding {
+	/// A hash of the parameter's name, which keeps the binding small enough to be copied around along with the footprint
+	name: u64,
+	value: f64,
+}
+
+impl core::hash::Hash for ParameterBinding {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.name.hash(state);
+		self.value.to_bits().hash(state);
+	}
+}
+
+impl ParameterBinding {
+	pub fn new(name: &str, value: f64) -> Self {
+		Self { name: Self::name_hash(name), value }
+	}
+
+	/// The bound value, if the binding is for the parameter with the given name.
+	pub fn value_of(&self, name: &str) -> Option<f64> {
+		(self.name == Self::name_hash(name)).then_some(self.value)
+	}
+
+	/// Hashes the name with FNV-1a, which unlike the standard library's hasher is guaranteed to stay the same between versions.
+	fn name_hash(name: &str) -> u64 {
+		name.trim().bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+	}
+}
+
+/// Parses a comma-separated list of numbers, where a range like `0..100/5` stands for that many evenly spaced numbers from its start to its end (inclusive), or every whole step between them without the count.
+///
+/// Anything that isn't a number or range is skipped.
+pub fn parse_sweep_values(values: &str) -> Vec<f64> {
+	let mut result = Vec::new();
+	for entry in values.split(',').map(str::trim) {
+		let Some((start, end)) = entry.split_once("..") else {
+			result.extend(entry.parse::<f64>().ok().filter(|value| value.is_finite()));
+			continue;
+		};
+		let (end, count) = match end.split_once('/') {
+			Some((end, count)) => match count.trim().parse::<usize>() {
+				Ok(count) => (end, Some(count)),
+				Err(_) => continue,
+			},
+			None => (end, None),
+		};
+		let (Ok(start), Ok(end)) = (start.trim().parse::<f64>(), end.trim().parse::<f64>()) else {
+			continue;
+		};
+		if !start.is_finite() || !end.is_finite() {
+			continue;
+		}
+
+		match count {
+			Some(0) => {}
+			Some(1) => result.push(start),
+			Some(count) => result.extend((0..count.min(MAX_SWEEP_VALUES)).map(|index| start + (end - start) * index as f64 / (count - 1) as f64)),
+			None => {
+				let steps = ((end - start).abs() + 1e-9).floor().min(MAX_SWEEP_VALUES as f64) as usize;
+				result.extend((0..=steps).map(|step| start + (end - start).signum() * step as f64));
+			}
+		}
+	}
+	result.truncate(MAX_SWEEP_VALUES);
+	result
+}
+
+/// Writes out a swept value without the rounding errors picked up along the way, such as `0.3` rather than `0.30000000000000004`.
+fn format_sweep_value(value: f64) -> String {
+	// Adding zero turns a negative zero into a positive one
+	let rounded = (value * 1e6).round() / 1e6 + 0.;
+	format!("{rounded}")
+}
+
+pub struct ParameterSweepNode<Template, Parameter, Values, Columns, CellSize, Labels> {
+	template: Template,
+	parameter: Parameter,
+	values: Values,
+	columns: Columns,
+	cell_size: CellSize,
+	labels: Labels,
+}
+
+/// Evaluates the template once for each of the values (as parsed by [`parse_sweep_values`]), laying out the results in a grid to compare them side by side.
+///
+/// Each value is given to the template's [`ParameterNode`]s with the name of the swept parameter, through [`Footprint::parameter`].
+/// When labeled, each cell shows the parameter and its value along its bottom edge.
+#[node_macro::node_fn(ParameterSweepNode)]
+async fn parameter_sweep<Fut: Future<Output = GraphicGroup>>(
+	footprint: Footprint,
+	template: impl Node<Footprint, Output = Fut>,
+	parameter: String,
+	values: String,
+	columns: u32,
+	cell_size: DVec2,
+	labels: bool,
+) -> GraphicGroup {
+	let columns = columns.max(1) as usize;
+
+	let mut result = GraphicGroup::EMPTY;
+	for (index, value) in parse_sweep_values(&values).into_iter().enumerate() {
+		let offset = DVec2::new((index % columns) as f64, (index / columns) as f64) * cell_size;
+		let mut cell_footprint = footprint;
+		cell_footprint.transform *= DAffine2::from_translation(offset);
+		cell_footprint.parameter = Some(ParameterBinding::new(&parameter, value));
+
+		let mut graphic_group = self.template.eval(cell_footprint).await;
+		graphic_group.transform = DAffine2::from_translation(offset) * graphic_group.transform;
+		result.push(graphic_group.into());
+
+		if labels {
+			let mut label = GraphicGroup::EMPTY;
+			label.transform = DAffine2::from_translation(offset + DVec2::new(0., cell_size.y));
+			label.push(GraphicElement::Text(format!("{} = {}", parameter.trim(), format_sweep_value(value))));
+			result.push(label.into());
+		}
+	}
+
+	result
+}
+
+pub struct ParameterNode<Name, Default> {
+	name: Name,
+	default: Default,
+}
+
+/// A named number that a [`ParameterSweepNode`] varies while evaluating its template, which is otherwise the default.
+#[node_macro::node_fn(ParameterNode)]
+fn parameter(footprint: Footprint, name: String, default: f64) -> f64 {
+	footprint.parameter.and_then(|binding| binding.value_of(&name)).unwrap_or(default)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::transform::CullNode;
+	use crate::value::ClonedNode;
+	use crate::vector::{FutureWrapperNode, VectorData};
+
+	#[test]
+	fn parse_csv() {
+		let table = DataTable::from_csv("name, award\nAda,\"First, and \"\"only\"\"\"\r\n\nGrace,Second\n");
+		assert_eq!(table.columns, vec!["name", "award"]);
+		assert_eq!(table.rows.len(), 2);
+		assert_eq!(table.field(0, "award"), Some("First, and \"only\""));
+		assert_eq!(table.field(1, "name"), Some("Grace"));
+		assert_eq!(table.field(2, "name"), None);
+		assert_eq!(table.field(0, "missing"), None);
+	}
+
+	#[test]
+	fn row_field() {
+		let node = RowFieldNode {
+			table: ClonedNode("name\nAda\nGrace".to_string()),
+			column: ClonedNode("name".to_string()),
+		};
+		assert_eq!(node.eval(Footprint::default()), "Ada");
+		let footprint = Footprint {
+			data_row: Some(1),
+			..Default::default()
+		};
+		assert_eq!(node.eval(footprint), "Grace");
+	}
+
+	#[tokio::test]
+	async fn for_each_row() {
+		let result = ForEachRowNode {
+			template: CullNode::new(FutureWrapperNode(ClonedNode(GraphicGroup::from(VectorData::empty())))),
+			table: FutureWrapperNode(ClonedNode("name\nAda\nGrace\nKatherine".to_string())),
+			columns: FutureWrapperNode(ClonedNode(2)),
+			cell_size: FutureWrapperNode(ClonedNode(DVec2::new(100., 50.))),
+			artboards: FutureWrapperNode(ClonedNode(true)),
+		}
+		.eval(Footprint::default())
+		.await;
+		let locations = result
+			.iter()
+			.map(|element| match element {
+				crate::GraphicElement::Artboard(artboard) => artboard.location,
+				_ => panic!("Expected an artboard"),
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(locations, vec![glam::IVec2::ZERO, glam::IVec2::new(100, 0), glam::IVec2::new(0, 50)]);
+	}
+
+	#[test]
+	fn sequence_number() {
+		let node = SequenceNumberNode {
+			start: ClonedNode(7),
+			step: ClonedNode(5),
+			digits: ClonedNode(4),
+			prefix: ClonedNode("SN-".to_string()),
+			suffix: ClonedNode(String::new()),
+		};
+		assert_eq!(node.eval(Footprint::default()), "SN-0007");
+		let footprint = Footprint {
+			data_row: Some(3),
+			..Default::default()
+		};
+		assert_eq!(node.eval(footprint), "SN-0022");
+	}
+
+	#[test]
+	fn uuid() {
+		let node = UuidNode { seed: ClonedNode(1) };
+		let row = |row| Footprint {
+			data_row: Some(row),
+			..Default::default()
+		};
+		let first = node.eval(row(0));
+		assert_eq!(first.len(), 36);
+		assert_eq!(first.as_bytes()[14], b'4');
+		assert!(matches!(first.as_bytes()[19], b'8' | b'9' | b'a' | b'b'));
+		assert_eq!(first, node.eval(row(0)));
+		assert_ne!(first, node.eval(row(1)));
+		assert_ne!(first, UuidNode { seed: ClonedNode(2) }.eval(row(0)));
+	}
+
+	#[test]
+	fn sweep_values() {
+		assert_eq!(parse_sweep_values("1, 2.5,-4"), vec![1., 2.5, -4.]);
+		assert_eq!(parse_sweep_values("0..100/5"), vec![0., 25., 50., 75., 100.]);
+		assert_eq!(parse_sweep_values("3..0, 7..7/1"), vec![3., 2., 1., 0., 7.]);
+		assert_eq!(parse_sweep_values("x, 1..2/y, 4"), vec![4.]);
+		assert_eq!(parse_sweep_values("0..1e12").len(), MAX_SWEEP_VALUES);
+		assert_eq!(format_sweep_value(0.1 + 0.2), "0.3");
+	}
+
+	#[tokio::test]
+	async fn parameter_sweep() {
+		let parameter = |name: &str| ParameterNode {
+			name: ClonedNode(name.to_string()),
+			default: ClonedNode(1.),
+		};
+		assert_eq!(parameter("size").eval(Footprint::default()), 1.);
+
+		let result = ParameterSweepNode {
+			template: CullNode::new(FutureWrapperNode(ClonedNode(GraphicGroup::EMPTY))),
+			parameter: FutureWrapperNode(ClonedNode("size".to_string())),
+			values: FutureWrapperNode(ClonedNode("10..30/3".to_string())),
+			columns: FutureWrapperNode(ClonedNode(2)),
+			cell_size: FutureWrapperNode(ClonedNode(DVec2::new(100., 50.))),
+			labels: FutureWrapperNode(ClonedNode(true)),
+		}
+		.eval(Footprint::default())
+		.await;
+		let labels = result
+			.iter()
+			.filter_map(|element| match element {
+				GraphicElement::GraphicGroup(group) => match group.first() {
+					Some(GraphicElement::Text(text)) => Some((text.clone(), group.transform.translation)),
+					_ => None,
+				},
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(
+			labels,
+			vec![
+				("size = 10".to_string(), DVec2::new(0., 50.)),
+				("size = 20".to_string(), DVec2::new(100., 50.)),
+				("size = 30".to_string(), DVec2::new(0., 100.))
+			]
+		);
+
+		let footprint = Footprint {
+			parameter: Some(ParameterBinding::new("size", 20.)),
+			..Default::default()
+		};
+		assert_eq!(parameter("size").eval(footprint), 20.);
+		assert_eq!(parameter("weight").eval(footprint), 1.);
+	}
+}
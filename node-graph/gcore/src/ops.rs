@@ -265,6 +265,33 @@ impl SecondOfPairNode {
 	}
 }
 
+// First of Triple
+/// Return the first element of a 3-tuple
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct FirstOfTripleNode;
+#[node_macro::node_fn(FirstOfTripleNode)]
+fn first_of_triple<A, B, C>(input: (A, B, C)) -> A {
+	input.0
+}
+
+// Second of Triple
+/// Return the second element of a 3-tuple
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SecondOfTripleNode;
+#[node_macro::node_fn(SecondOfTripleNode)]
+fn second_of_triple<A, B, C>(input: (A, B, C)) -> B {
+	input.1
+}
+
+// Third of Triple
+/// Return the third element of a 3-tuple
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ThirdOfTripleNode;
+#[node_macro::node_fn(ThirdOfTripleNode)]
+fn third_of_triple<A, B, C>(input: (A, B, C)) -> C {
+	input.2
+}
+
 // Swap Pair
 /// Return a new 2-tuple with the elements reversed
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
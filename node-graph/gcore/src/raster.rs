@@ -5,7 +5,7 @@ use crate::Node;
 use bytemuck::{Pod, Zeroable};
 use glam::DVec2;
 
-pub use self::color::{Color, Luma, SRGBA8};
+pub use self::color::{Color, ColorSpace, Luma, SRGBA8};
 
 #[cfg(target_arch = "spirv")]
 use spirv_std::num_traits::float::Float;
@@ -20,6 +20,8 @@ pub mod color;
 #[cfg(not(target_arch = "spirv"))]
 pub mod curve;
 pub mod discrete_srgb;
+#[cfg(not(target_arch = "spirv"))]
+pub mod palette;
 pub use adjustments::*;
 
 pub trait Linear {
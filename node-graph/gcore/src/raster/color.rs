@@ -135,6 +135,37 @@ impl RGB for Luma {
 
 impl Pixel for Luma {}
 
+/// The color space that RGB channels given to or taken from a [Color] are expressed in, which tags wide-gamut values whose primaries reach beyond those of sRGB.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, DynAny)]
+pub enum ColorSpace {
+	/// The gamma-encoded channels of sRGB
+	#[default]
+	Srgb,
+	/// The channels of sRGB as amounts of light
+	LinearSrgb,
+	/// The gamma-encoded channels of Display P3, the wider gamut of modern screens
+	DisplayP3,
+	/// The channels of Rec. 2020 as amounts of light, the widest gamut of HDR video
+	LinearRec2020,
+}
+
+/// Converts linear sRGB channels to linear Display P3, whose primaries share the D65 white point.
+const SRGB_TO_DISPLAY_P3: [[f32; 3]; 3] = [[0.8224621, 0.177538, 0.], [0.0331941, 0.9668058, 0.], [0.0170827, 0.0723974, 0.9105199]];
+const DISPLAY_P3_TO_SRGB: [[f32; 3]; 3] = [[1.2249401, -0.2249404, 0.], [-0.0420569, 1.0420571, 0.], [-0.0196376, -0.0786361, 1.0982735]];
+/// Converts linear sRGB channels to linear Rec. 2020, whose primaries share the D65 white point.
+const SRGB_TO_REC2020: [[f32; 3]; 3] = [[0.6274039, 0.329283, 0.0433131], [0.0690973, 0.9195404, 0.0113623], [0.0163914, 0.0880133, 0.8955953]];
+const REC2020_TO_SRGB: [[f32; 3]; 3] = [[1.660491, -0.5876411, -0.0728499], [-0.1245505, 1.1328999, -0.0083494], [-0.0181508, -0.1005789, 1.1187297]];
+
+/// How far a channel may fall outside of `0.0` to `1.0` from rounding errors while still counting as within a gamut.
+const GAMUT_TOLERANCE: f32 = 1e-4;
+
+#[inline(always)]
+fn transform_channels(matrix: &[[f32; 3]; 3], [red, green, blue]: [f32; 3]) -> [f32; 3] {
+	matrix.map(|[r, g, b]| r * red + g * green + b * blue)
+}
+
 /// Structure that represents a color.
 /// Internally alpha is stored as `f32` that ranges from `0.0` (transparent) to `1.0` (opaque).
 /// The other components (RGB) are stored as `f32` that range from `0.0` up to `f32::MAX`,
@@ -293,6 +324,25 @@ impl Color {
 		Some(color)
 	}
 
+	/// Returns `Some(Color)` like [`Color::from_rgbaf32`], but also accepts negative RGB channels, which extended-range sRGB uses for colors outside of the sRGB gamut.
+	///
+	/// # Examples
+	/// ```
+	/// use graphene_core::raster::color::Color;
+	/// let color = Color::from_rgbaf32_extended(-0.2, 1.1, 0.15, 1.).unwrap();
+	/// assert!(color.components() == (-0.2, 1.1, 0.15, 1.));
+	///
+	/// let color = Color::from_rgbaf32_extended(f32::INFINITY, 1.0, 1.0, 1.0);
+	/// assert!(color == None);
+	/// ```
+	#[inline(always)]
+	pub fn from_rgbaf32_extended(red: f32, green: f32, blue: f32, alpha: f32) -> Option<Color> {
+		if !(0. ..=1.).contains(&alpha) || [red, green, blue].iter().any(|c| !c.is_finite()) {
+			return None;
+		}
+		Some(Color { red, green, blue, alpha })
+	}
+
 	/// Return an opaque `Color` from given `f32` RGB channels.
 	#[inline(always)]
 	pub const fn from_rgbf32_unchecked(red: f32, green: f32, blue: f32) -> Color {
@@ -888,24 +938,71 @@ impl Color {
 		}
 	}
 
+	/// Decodes a gamma-encoded sRGB channel, mirroring the curve for the negative channels of extended-range sRGB.
 	#[inline(always)]
 	pub fn srgb_to_linear(channel: f32) -> f32 {
-		if channel <= 0.04045 {
+		if channel.abs() <= 0.04045 {
 			channel / 12.92
 		} else {
-			((channel + 0.055) / 1.055).powf(2.4)
+			channel.signum() * ((channel.abs() + 0.055) / 1.055).powf(2.4)
 		}
 	}
 
+	/// Gamma-encodes a linear sRGB channel, mirroring the curve for the negative channels of extended-range sRGB.
 	#[inline(always)]
 	pub fn linear_to_srgb(channel: f32) -> f32 {
-		if channel <= 0.0031308 {
+		if channel.abs() <= 0.0031308 {
 			channel * 12.92
 		} else {
-			1.055 * channel.powf(1. / 2.4) - 0.055
+			channel.signum() * (1.055 * channel.abs().powf(1. / 2.4) - 0.055)
+		}
+	}
+
+	/// Creates a color with linear sRGB channels from channels given in the color space.
+	/// Colors outside of the sRGB gamut are kept as extended-range channels, which may be negative or above `1.0`.
+	///
+	/// # Examples
+	/// ```
+	/// use graphene_core::raster::color::{Color, ColorSpace};
+	/// let p3_red = Color::from_color_space(ColorSpace::DisplayP3, 1., 0., 0., 1.);
+	/// assert!(p3_red.r() > 1. && p3_red.g() < 0.);
+	/// assert!(!p3_red.is_in_gamut(ColorSpace::Srgb));
+	/// assert!(p3_red.is_in_gamut(ColorSpace::DisplayP3));
+	/// ```
+	pub fn from_color_space(space: ColorSpace, red: f32, green: f32, blue: f32, alpha: f32) -> Color {
+		let channels = [red, green, blue];
+		let [red, green, blue] = match space {
+			ColorSpace::Srgb => channels.map(Self::srgb_to_linear),
+			ColorSpace::LinearSrgb => channels,
+			ColorSpace::DisplayP3 => transform_channels(&DISPLAY_P3_TO_SRGB, channels.map(Self::srgb_to_linear)),
+			ColorSpace::LinearRec2020 => transform_channels(&REC2020_TO_SRGB, channels),
+		};
+		Color { red, green, blue, alpha }
+	}
+
+	/// Converts the RGB channels of the color, taken to be linear sRGB, into the color space.
+	///
+	/// # Examples
+	/// ```
+	/// use graphene_core::raster::color::{Color, ColorSpace};
+	/// let [red, green, blue] = Color::from_color_space(ColorSpace::LinearRec2020, 0.2, 0.6, 0.1, 1.).to_color_space(ColorSpace::LinearRec2020);
+	/// assert!((red - 0.2).abs() < 1e-4 && (green - 0.6).abs() < 1e-4 && (blue - 0.1).abs() < 1e-4);
+	/// ```
+	pub fn to_color_space(&self, space: ColorSpace) -> [f32; 3] {
+		let channels = [self.red, self.green, self.blue];
+		match space {
+			ColorSpace::Srgb => channels.map(Self::linear_to_srgb),
+			ColorSpace::LinearSrgb => channels,
+			ColorSpace::DisplayP3 => transform_channels(&SRGB_TO_DISPLAY_P3, channels).map(Self::linear_to_srgb),
+			ColorSpace::LinearRec2020 => transform_channels(&SRGB_TO_REC2020, channels),
 		}
 	}
 
+	/// Whether the color, taken to be linear sRGB, lies within the gamut of the color space, so it can be shown there without being clipped.
+	pub fn is_in_gamut(&self, space: ColorSpace) -> bool {
+		self.to_color_space(space).iter().all(|channel| (-GAMUT_TOLERANCE..=1. + GAMUT_TOLERANCE).contains(channel))
+	}
+
 	#[inline(always)]
 	pub fn map_rgba<F: Fn(f32) -> f32>(&self, f: F) -> Self {
 		Self::from_rgbaf32_unchecked(f(self.r()), f(self.g()), f(self.b()), f(self.a()))
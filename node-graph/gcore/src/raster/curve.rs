@@ -26,6 +26,39 @@ impl Default for Curve {
 	}
 }
 
+impl Curve {
+	/// Finds the height of the curve at the horizontal position, with the curve running from (0, 0) through its manipulator groups to (1, 1).
+	#[cfg(feature = "alloc")]
+	pub fn evaluate(&self, x: f64) -> f64 {
+		use bezier_rs::{Bezier, TValue};
+
+		let x = x.clamp(0., 1.);
+		let [mut pos, mut param]: [[f32; 2]; 2] = [[0.; 2], self.first_handle];
+		let end = CurveManipulatorGroup {
+			anchor: [1.; 2],
+			handles: [self.last_handle, [0.; 2]],
+		};
+		for sample in self.manipulator_groups.iter().chain(core::iter::once(&end)) {
+			let [x0, y0, x1, y1, x2, y2, x3, y3] = [pos[0], pos[1], param[0], param[1], sample.handles[0][0], sample.handles[0][1], sample.anchor[0], sample.anchor[1]].map(f64::from);
+			if x <= x0 {
+				return y0;
+			}
+			if x < x3 {
+				let bezier = Bezier::from_cubic_coordinates(x0, y0, x1, y1, x2, y2, x3, y3);
+				return bezier
+					.find_tvalues_for_x(x)
+					.next()
+					.map(|t| bezier.evaluate(TValue::Parametric(t.clamp(0., 1.))).y)
+					.unwrap_or_else(|| (x - x0) / (x3 - x0) * (y3 - y0) + y0);
+			}
+
+			pos = sample.anchor;
+			param = sample.handles[1];
+		}
+		1.
+	}
+}
+
 impl std::hash::Hash for Curve {
 	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
 		self.manipulator_groups.hash(state);
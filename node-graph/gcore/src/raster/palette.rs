@@ -0,0 +1,124 @@
+use super::Color;
+use crate::Node;
+
+use dyn_any::{DynAny, StaticType};
+
+/// A rule from color theory for picking hues around the color wheel which go well with a base color.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, DynAny)]
+pub enum ColorHarmony {
+	/// The base color and the hue opposite it
+	#[default]
+	Complementary,
+	/// The base color and the two hues either side of its complement
+	SplitComplementary,
+	/// Neighboring hues centered on the base color
+	Analogous,
+	/// Three hues evenly spaced around the wheel
+	Triadic,
+	/// Four hues evenly spaced around the wheel
+	Tetradic,
+	/// Shades of the base hue from dark to light
+	Monochromatic,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ColorHarmonyNode<Harmony, Count, Spread> {
+	harmony: Harmony,
+	count: Count,
+	spread: Spread,
+}
+
+/// Builds a palette from the base color by following a color harmony. The count sets how many colors the analogous and monochromatic harmonies generate,
+/// and the spread sets the angle in degrees between neighboring analogous hues and between the two halves of a split complement.
+#[node_macro::node_fn(ColorHarmonyNode)]
+fn color_harmony(color: Color, harmony: ColorHarmony, count: u32, spread: f64) -> Vec<Color> {
+	let [hue, saturation, lightness, alpha] = color.to_hsla();
+	let spread = spread as f32 / 360.;
+	let rotated = |turns: f32| {
+		if turns == 0. {
+			color
+		} else {
+			Color::from_hsla((hue + turns).rem_euclid(1.), saturation, lightness, alpha)
+		}
+	};
+	let count = count.max(1);
+
+	match harmony {
+		ColorHarmony::Complementary => vec![color, rotated(0.5)],
+		ColorHarmony::SplitComplementary => vec![color, rotated(0.5 - spread), rotated(0.5 + spread)],
+		ColorHarmony::Analogous => (0..count).map(|index| rotated((index as f32 - (count / 2) as f32) * spread)).collect(),
+		ColorHarmony::Triadic => (0..3).map(|index| rotated(index as f32 / 3.)).collect(),
+		ColorHarmony::Tetradic => (0..4).map(|index| rotated(index as f32 / 4.)).collect(),
+		ColorHarmony::Monochromatic => (0..count).map(|index| Color::from_hsla(hue, saturation, (index + 1) as f32 / (count + 1) as f32, alpha)).collect(),
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PaletteColorNode<Index> {
+	index: Index,
+}
+
+/// Picks one color out of a palette. The index wraps around past the end so a palette can be cycled through, and negative indices count back from the end.
+#[node_macro::node_fn(PaletteColorNode)]
+fn palette_color(palette: Vec<Color>, index: f64) -> Option<Color> {
+	if palette.is_empty() {
+		return None;
+	}
+	let index = (index.round() as i64).rem_euclid(palette.len() as i64) as usize;
+	Some(palette[index])
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+
+	fn hue(color: Color) -> f32 {
+		color.to_hsla()[0] * 360.
+	}
+
+	#[test]
+	fn color_harmony_hues() {
+		let base = Color::from_hsla(30. / 360., 0.8, 0.5, 1.);
+		let harmony = |harmony, count| {
+			ColorHarmonyNode {
+				harmony: ClonedNode(harmony),
+				count: ClonedNode(count),
+				spread: ClonedNode(30.),
+			}
+			.eval(base)
+		};
+
+		let complementary = harmony(ColorHarmony::Complementary, 1);
+		assert_eq!(complementary[0], base);
+		assert!((hue(complementary[1]) - 210.).abs() < 0.1);
+
+		let split = harmony(ColorHarmony::SplitComplementary, 1);
+		assert!((hue(split[1]) - 180.).abs() < 0.1);
+		assert!((hue(split[2]) - 240.).abs() < 0.1);
+
+		let analogous = harmony(ColorHarmony::Analogous, 5);
+		assert_eq!(analogous[2], base);
+		assert!((hue(analogous[0]) - 330.).abs() < 0.1);
+		assert!((hue(analogous[4]) - 90.).abs() < 0.1);
+
+		assert_eq!(harmony(ColorHarmony::Triadic, 1).len(), 3);
+		assert_eq!(harmony(ColorHarmony::Tetradic, 1).len(), 4);
+
+		let lightness = harmony(ColorHarmony::Monochromatic, 3).iter().map(|color| color.to_hsla()[2]).collect::<Vec<_>>();
+		assert!(lightness.windows(2).all(|pair| pair[0] < pair[1]));
+	}
+
+	#[test]
+	fn palette_color_wraps() {
+		let palette = vec![Color::RED, Color::GREEN, Color::BLUE];
+		let pick = |index| PaletteColorNode { index: ClonedNode(index) }.eval(palette.clone());
+
+		assert_eq!(pick(1.), Some(Color::GREEN));
+		assert_eq!(pick(4.), Some(Color::GREEN));
+		assert_eq!(pick(-1.), Some(Color::BLUE));
+		assert_eq!(PaletteColorNode { index: ClonedNode(0.) }.eval(Vec::new()), None);
+	}
+}
@@ -1,21 +1,194 @@
 mod font_cache;
+mod hyphenation;
+mod outline_cache;
 mod to_path;
 
 use crate::application_io::EditorApi;
 pub use font_cache::*;
+pub use hyphenation::*;
 use node_macro::node_fn;
+pub use outline_cache::*;
 pub use to_path::*;
 
+use crate::uuid::ManipulatorGroupId;
 use crate::Node;
 
-pub struct TextGeneratorNode<Text, FontName, Size> {
+use bezier_rs::Subpath;
+use glam::DVec2;
+
+pub struct TextGeneratorNode<
+	Text,
+	FontName,
+	Size,
+	FallbackFont,
+	SecondFallbackFont,
+	MissingGlyph,
+	Ligatures,
+	SmallCaps,
+	TabularNumbers,
+	StylisticSet,
+	LineWidth,
+	Align,
+	Hyphenation,
+	MaxWordSpacing,
+	MaxLetterSpacing,
+	Frame,
+	Exclusions,
+	FramePadding,
+	ExclusionMargin,
+	DropCapLines,
+> {
 	text: Text,
 	font_name: FontName,
 	font_size: Size,
+	fallback_font: FallbackFont,
+	second_fallback_font: SecondFallbackFont,
+	missing_glyph: MissingGlyph,
+	ligatures: Ligatures,
+	small_caps: SmallCaps,
+	tabular_numbers: TabularNumbers,
+	stylistic_set: StylisticSet,
+	line_width: LineWidth,
+	align: Align,
+	hyphenation: Hyphenation,
+	max_word_spacing: MaxWordSpacing,
+	max_letter_spacing: MaxLetterSpacing,
+	frame: Frame,
+	exclusions: Exclusions,
+	frame_padding: FramePadding,
+	exclusion_margin: ExclusionMargin,
+	drop_cap_lines: DropCapLines,
 }
 
+/// Converts the text to outlines. Characters missing from the font are drawn from the first fallback font that has them, and the missing glyph policy decides what to draw when none do.
+/// Fallback fonts that are set to none are left out.
+///
+/// Ligatures, small caps, tabular numbers, and a stylistic set (1 to 20, or 0 for none) are OpenType features that only take effect in fonts which provide them.
+///
+/// With a line width (0 for none), lines wrap to fit within it, hyphenating long words if a hyphenation language is chosen. Justified lines are spread out by widening the gaps between words and then letters,
+/// up to the maximum word spacing (as a percentage of the width of a space) and letter spacing (as a percentage of the font size).
+///
+/// Given a frame shape, the lines flow down through it instead, each as wide as the shape is at its height, and text that doesn't fit is left out. Lines also flow around any exclusion shapes,
+/// keeping the frame padding away from the edges of the frame and the exclusion margin away from the exclusions, which suits text wrapped inside a circle or around an image.
+///
+/// With a drop cap (a number of lines, or 0 for none), the first character is enlarged to drop through that many lines, which are indented to make room for it.
+///
+/// The outlines are cached, so evaluating the node again with the same text, fonts, and size doesn't regenerate them.
 #[node_fn(TextGeneratorNode)]
-fn generate_text<'a: 'input, T>(editor: EditorApi<'a, T>, text: String, font_name: Font, font_size: f64) -> crate::vector::VectorData {
-	let buzz_face = editor.font_cache.get(&font_name).map(|data| load_face(data));
-	crate::vector::VectorData::from_subpaths(to_path(&text, buzz_face, font_size, None))
+fn generate_text<'a: 'input, T>(
+	editor: EditorApi<'a, T>,
+	text: String,
+	font_name: Font,
+	font_size: f64,
+	fallback_font: Font,
+	second_fallback_font: Font,
+	missing_glyph: MissingGlyph,
+	ligatures: bool,
+	small_caps: bool,
+	tabular_numbers: bool,
+	stylistic_set: u32,
+	line_width: f64,
+	align: TextAlign,
+	hyphenation: Hyphenation,
+	max_word_spacing: f64,
+	max_letter_spacing: f64,
+	frame: crate::vector::VectorData,
+	exclusions: crate::vector::VectorData,
+	frame_padding: f64,
+	exclusion_margin: f64,
+	drop_cap_lines: u32,
+) -> crate::vector::VectorData {
+	let layout = TextLayout {
+		fallback_fonts: vec![fallback_font, second_fallback_font],
+		missing_glyph,
+		features: FontFeatures {
+			ligatures,
+			small_caps,
+			tabular_numbers,
+			stylistic_set,
+		},
+		line_width: (line_width > 0.).then_some(line_width),
+		paragraph: Paragraph {
+			align,
+			hyphenation,
+			max_word_spacing: max_word_spacing / 100.,
+			max_letter_spacing: max_letter_spacing / 100.,
+			drop_cap_lines,
+		},
+		frame: text_frame(&frame, &exclusions, frame_padding, exclusion_margin),
+	};
+
+	let key = OutlineKey {
+		fonts: layout.fonts(&font_name, editor.font_cache),
+		font_size: font_size.to_bits(),
+		line_width: layout.line_width.map(f64::to_bits),
+		frame: layout.frame.clone(),
+		paragraph: layout.paragraph,
+		text,
+		missing_glyph,
+		features: layout.features,
+	};
+	let subpaths = editor.font_cache.outlines(key.clone(), || layout.to_path(&key.text, &font_name, font_size, editor.font_cache));
+	crate::vector::VectorData::from_subpaths(subpaths)
+}
+
+/// The inputs of the Text node, other than the text and its main font and size, that decide where its characters are placed.
+/// Tools use it to measure text the same way the node lays it out.
+#[derive(Debug, Clone, Default)]
+pub struct TextLayout {
+	/// Fonts that characters missing from the main font are drawn from, in order, where any set to none are left out
+	pub fallback_fonts: Vec<Font>,
+	pub missing_glyph: MissingGlyph,
+	pub features: FontFeatures,
+	/// The width that lines wrap to fit within, if there is one
+	pub line_width: Option<f64>,
+	pub paragraph: Paragraph,
+	/// The shapes the lines are flowed through and around, if there are any
+	pub frame: Option<TextFrame>,
+}
+
+impl TextLayout {
+	/// The fonts in the fallback chain, starting with the main font, after any that haven't loaded are resolved to the default font.
+	pub fn fonts(&self, font: &Font, font_cache: &FontCache) -> Vec<Font> {
+		let mut fonts: Vec<Font> = Vec::new();
+		for font in core::iter::once(font).chain(&self.fallback_fonts).filter(|font| !font.is_none()) {
+			// Fonts that haven't loaded resolve to the default font, which only needs to be tried once
+			if let Some(font) = font_cache.resolve_font(font).filter(|&font| !fonts.contains(font)) {
+				fonts.push(font.clone());
+			}
+		}
+		fonts
+	}
+
+	/// Converts the text to outlines laid out with these options.
+	pub fn to_path(&self, text: &str, font: &Font, font_size: f64, font_cache: &FontCache) -> Vec<Subpath<ManipulatorGroupId>> {
+		let fonts = self.fonts(font, font_cache);
+		let buzz_faces = fonts.iter().filter_map(|font| font_cache.get(font)).map(|data| load_face(data)).collect::<Vec<_>>();
+		to_path_with_fallback(text, &buzz_faces, font_size, self.line_width, self.frame.as_ref(), &self.paragraph, self.missing_glyph, &self.features)
+	}
+
+	/// Measures the text laid out with these options, which is zero if none of its fonts have loaded.
+	pub fn bounding_box(&self, text: &str, font: &Font, font_size: f64, font_cache: &FontCache) -> DVec2 {
+		let fonts = self.fonts(font, font_cache);
+		let buzz_faces = fonts.iter().filter_map(|font| font_cache.get(font)).map(|data| load_face(data)).collect::<Vec<_>>();
+		bounding_box_with_fallback(text, &buzz_faces, font_size, self.line_width, self.frame.as_ref(), &self.paragraph, self.missing_glyph, &self.features)
+	}
+}
+
+/// Flattens the closed shapes of the frame and exclusions into the [`TextFrame`] that lines are flowed through and around, or none if neither has any.
+pub fn text_frame(frame: &crate::vector::VectorData, exclusions: &crate::vector::VectorData, padding: f64, margin: f64) -> Option<TextFrame> {
+	let polygons = |vector_data: &crate::vector::VectorData| {
+		vector_data
+			.stroke_bezier_paths()
+			.map(|subpath| TextFrame::polygon(subpath.iter().map(|bezier| bezier.apply_transformation(|point| vector_data.transform.transform_point2(point)))))
+			.filter(|polygon| polygon.len() > 2)
+			.collect::<Vec<_>>()
+	};
+	let frame = TextFrame {
+		region: polygons(frame),
+		exclusions: polygons(exclusions),
+		padding: padding.max(0.),
+		margin: margin.max(0.),
+	};
+	(!frame.region.is_empty() || !frame.exclusions.is_empty()).then_some(frame)
 }
@@ -1,6 +1,10 @@
-use dyn_any::{DynAny, StaticType};
+use super::{OutlineCache, OutlineKey};
+use crate::uuid::ManipulatorGroupId;
 
+use bezier_rs::Subpath;
+use dyn_any::{DynAny, StaticType};
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 /// A font type (storing font family and font style and an optional preview URL)
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Hash, PartialEq, Eq, DynAny, specta::Type)]
@@ -14,10 +18,20 @@ impl Font {
 	pub fn new(font_family: String, font_style: String) -> Self {
 		Self { font_family, font_style }
 	}
+
+	/// No font at all, for an optional font such as a fallback that isn't set. It's left out rather than resolving to the default font.
+	pub fn none() -> Self {
+		Self::new(String::new(), String::new())
+	}
+
+	/// Whether this is [`Font::none`].
+	pub fn is_none(&self) -> bool {
+		self.font_family.is_empty()
+	}
 }
 
 /// A cache of all loaded font data and preview urls along with the default font (send from `init_app` in `editor_api.rs`)
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default, PartialEq)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
 pub struct FontCache {
 	/// Actual font file data used for rendering a font with ttf_parser and rustybuzz
 	font_file_data: HashMap<Font, Vec<u8>>,
@@ -25,6 +39,9 @@ pub struct FontCache {
 	preview_urls: HashMap<Font, String>,
 	/// The default font (used as a fallback)
 	default_font: Option<Font>,
+	/// Outlines of text recently generated from these fonts, which copies of the font cache share and which isn't saved
+	#[serde(skip)]
+	outlines: Arc<Mutex<OutlineCache>>,
 }
 impl FontCache {
 	/// Returns the font family name if the font is cached, otherwise returns the default font family name if that is cached
@@ -53,6 +70,10 @@ impl FontCache {
 		}
 		self.font_file_data.insert(font.clone(), data);
 		self.preview_urls.insert(font, perview_url);
+		// Outlines made from the data of a font that's replaced would be out of date
+		if let Ok(mut outlines) = self.outlines.lock() {
+			outlines.clear();
+		}
 	}
 
 	/// Checks if the font cache has a default font
@@ -64,6 +85,19 @@ impl FontCache {
 	pub fn get_preview_url(&self, font: &Font) -> Option<&String> {
 		self.preview_urls.get(font)
 	}
+
+	/// Looks up the cached outlines for the key, generating and caching them if they aren't already.
+	pub fn outlines(&self, key: OutlineKey, generate: impl FnOnce() -> Vec<Subpath<ManipulatorGroupId>>) -> Vec<Subpath<ManipulatorGroupId>> {
+		let Ok(mut outlines) = self.outlines.lock() else { return generate() };
+		outlines.get_or_insert_with(key, generate)
+	}
+}
+
+// Compared by the fonts alone, since the cached outlines follow from them
+impl PartialEq for FontCache {
+	fn eq(&self, other: &Self) -> bool {
+		self.font_file_data == other.font_file_data && self.preview_urls == other.preview_urls && self.default_font == other.default_font
+	}
 }
 
 impl core::hash::Hash for FontCache {
@@ -0,0 +1,191 @@
+use dyn_any::{DynAny, StaticType};
+
+/// The language whose spelling rules decide where words may be broken with a hyphen when they don't fit on a line.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum Hyphenation {
+	/// Words are never hyphenated
+	#[default]
+	Off,
+	English,
+	German,
+	French,
+	Spanish,
+}
+
+/// The spelling rules used to find the syllables of a word in one language.
+struct HyphenationRules {
+	vowels: &'static str,
+	/// Groups of consonants that can start a syllable, which are kept together after a break
+	onsets: &'static [&'static str],
+	/// Groups of consonants that are never split, but end the syllable before a break rather than starting the one after it
+	codas: &'static [&'static str],
+	/// The fewest letters that may be left before a break
+	left_min: usize,
+	/// The fewest letters that may be carried over after a break
+	right_min: usize,
+	/// Whether a final "e" (even before an "s" or "d") is silent and so doesn't make a syllable
+	silent_final_e: bool,
+}
+
+const ENGLISH: HyphenationRules = HyphenationRules {
+	vowels: "aeiouy",
+	onsets: &[
+		"bl", "br", "ch", "cl", "cr", "dr", "fl", "fr", "gl", "gr", "ph", "pl", "pr", "sc", "sh", "sk", "sl", "sm", "sn", "sp", "st", "sw", "th", "tr", "tw", "wh", "wr", "qu", "chr", "sch", "scr",
+		"shr", "spl", "spr", "squ", "str", "thr",
+	],
+	codas: &["ck", "gh", "ng"],
+	left_min: 2,
+	right_min: 3,
+	silent_final_e: true,
+};
+
+const GERMAN: HyphenationRules = HyphenationRules {
+	vowels: "aeiouyäöü",
+	onsets: &[
+		"bl", "br", "ch", "ck", "dr", "fl", "fr", "gl", "gr", "kl", "kn", "kr", "pf", "ph", "pl", "pr", "qu", "tr", "zw", "sch", "schl", "schm", "schn", "schr", "schw",
+	],
+	codas: &[],
+	left_min: 2,
+	right_min: 2,
+	silent_final_e: false,
+};
+
+const FRENCH: HyphenationRules = HyphenationRules {
+	vowels: "aeiouyàâäéèêëîïôöùûüœæ",
+	onsets: &["bl", "br", "ch", "cl", "cr", "dr", "fl", "fr", "gl", "gn", "gr", "ph", "pl", "pr", "qu", "th", "tr", "vr"],
+	codas: &[],
+	left_min: 2,
+	right_min: 3,
+	silent_final_e: false,
+};
+
+const SPANISH: HyphenationRules = HyphenationRules {
+	vowels: "aeiouáéíóúü",
+	onsets: &["bl", "br", "ch", "cl", "cr", "dr", "fl", "fr", "gl", "gr", "ll", "pl", "pr", "qu", "rr", "tr"],
+	codas: &[],
+	left_min: 2,
+	right_min: 2,
+	silent_final_e: false,
+};
+
+impl Hyphenation {
+	fn rules(self) -> Option<&'static HyphenationRules> {
+		match self {
+			Self::Off => None,
+			Self::English => Some(&ENGLISH),
+			Self::German => Some(&GERMAN),
+			Self::French => Some(&FRENCH),
+			Self::Spanish => Some(&SPANISH),
+		}
+	}
+
+	/// Finds the byte offsets into the word, in ascending order, where it may be broken with a hyphen.
+	///
+	/// Breaks go between syllables, found from the word's vowels and the consonants that can start a syllable in the language.
+	/// That's right for most words, though unlike a dictionary it doesn't know about prefixes, compounds, or irregular spellings.
+	/// Any punctuation around the word is left alone, and words containing anything other than letters (such as numbers or hyphens) aren't broken.
+	pub fn break_points(self, word: &str) -> Vec<usize> {
+		let Some(rules) = self.rules() else { return Vec::new() };
+
+		let chars = word.char_indices().collect::<Vec<_>>();
+		let (Some(start), Some(end)) = (chars.iter().position(|(_, c)| c.is_alphabetic()), chars.iter().rposition(|(_, c)| c.is_alphabetic())) else {
+			return Vec::new();
+		};
+		let letters = chars[start..=end].iter().map(|&(_, c)| c.to_lowercase().next().unwrap_or(c)).collect::<Vec<_>>();
+		if letters.iter().any(|c| !c.is_alphabetic()) || letters.len() < rules.left_min + rules.right_min {
+			return Vec::new();
+		}
+
+		// The "u" in "qu" and a leading "y" act as consonants
+		let is_vowel = |index: usize| {
+			let letter = letters[index];
+			rules.vowels.contains(letter) && !(letter == 'u' && index > 0 && letters[index - 1] == 'q') && !(letter == 'y' && index == 0)
+		};
+
+		// Each run of vowels is the heart of one syllable
+		let mut nuclei: Vec<(usize, usize)> = Vec::new();
+		for index in 0..letters.len() {
+			if !is_vowel(index) {
+				continue;
+			}
+			match nuclei.last_mut() {
+				Some((_, end)) if *end == index => *end = index + 1,
+				_ => nuclei.push((index, index + 1)),
+			}
+		}
+		if rules.silent_final_e {
+			if let Some(&(start, end)) = nuclei.last() {
+				let ending = letters[end..].iter().collect::<String>();
+				if end - start == 1 && letters[start] == 'e' && nuclei.len() > 1 && matches!(ending.as_str(), "" | "s" | "d") {
+					nuclei.pop();
+				}
+			}
+		}
+
+		let is_group = |groups: &[&str], range: core::ops::Range<usize>| {
+			let group = letters[range].iter().collect::<String>();
+			groups.contains(&group.as_str())
+		};
+
+		nuclei
+			.windows(2)
+			.filter_map(|pair| {
+				let (cluster_start, cluster_end) = (pair[0].1, pair[1].0);
+				if cluster_start == cluster_end {
+					return None;
+				}
+
+				// The next syllable starts with as many of the consonants between the vowels as can begin a syllable together, but always at least one
+				let onset_length = (2..=cluster_end - cluster_start)
+					.rev()
+					.find(|&length| is_group(rules.onsets, cluster_end - length..cluster_end))
+					.unwrap_or(1);
+				let mut index = cluster_end - onset_length;
+
+				// Consonants that end a syllable together are kept in front of the break rather than split by it
+				let coda_end = (cluster_start..index)
+					.find_map(|group_start| (group_start + 2..=cluster_end.min(group_start + 3)).find(|&group_end| group_end > index && is_group(rules.codas, group_start..group_end)));
+				if let Some(coda_end) = coda_end {
+					index = coda_end;
+				}
+
+				(index >= rules.left_min && letters.len() - index >= rules.right_min).then(|| chars[start + index].0)
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn hyphenate(hyphenation: Hyphenation, word: &str) -> String {
+		let mut result = word.to_string();
+		for index in hyphenation.break_points(word).into_iter().rev() {
+			result.insert(index, '-');
+		}
+		result
+	}
+
+	#[test]
+	fn syllables() {
+		assert_eq!(hyphenate(Hyphenation::English, "hyphenation"), "hy-phe-na-tion");
+		assert_eq!(hyphenate(Hyphenation::English, "tickets"), "tick-ets");
+		assert_eq!(hyphenate(Hyphenation::English, "conquest"), "con-quest");
+		assert_eq!(hyphenate(Hyphenation::English, "complete"), "com-plete");
+		assert_eq!(hyphenate(Hyphenation::English, "Table,"), "Table,");
+		assert_eq!(hyphenate(Hyphenation::German, "Zucker"), "Zu-cker");
+		assert_eq!(hyphenate(Hyphenation::German, "Fenster"), "Fens-ter");
+		assert_eq!(hyphenate(Hyphenation::French, "république"), "ré-pu-bli-que");
+		assert_eq!(hyphenate(Hyphenation::Spanish, "caballero"), "ca-ba-lle-ro");
+	}
+
+	#[test]
+	fn leaves_unbreakable_words_alone() {
+		assert!(Hyphenation::Off.break_points("hyphenation").is_empty());
+		assert!(Hyphenation::English.break_points("well-known").is_empty());
+		assert!(Hyphenation::English.break_points("1234567").is_empty());
+		assert!(Hyphenation::English.break_points("cat").is_empty());
+	}
+}
@@ -0,0 +1,128 @@
+use super::{Font, FontFeatures, MissingGlyph, Paragraph, TextFrame};
+use crate::uuid::ManipulatorGroupId;
+
+use bezier_rs::Subpath;
+use std::collections::{HashMap, VecDeque};
+
+/// How many distinct pieces of text the [`OutlineCache`] holds the outlines of before dropping the least recently used.
+const OUTLINE_CACHE_CAPACITY: usize = 256;
+
+/// Everything that affects the outlines generated for a piece of text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OutlineKey {
+	/// The fonts in the fallback chain, after any that haven't loaded are resolved to the default font
+	pub fonts: Vec<Font>,
+	/// The bits of the font size, since floats can't be hashed
+	pub font_size: u64,
+	/// The bits of the line width, if there is one
+	pub line_width: Option<u64>,
+	/// The shapes the lines are flowed through and around, if there are any
+	pub frame: Option<TextFrame>,
+	pub paragraph: Paragraph,
+	pub text: String,
+	pub missing_glyph: MissingGlyph,
+	pub features: FontFeatures,
+}
+
+/// Outlines of recently generated text, so text that's evaluated again without changing (such as every frame of an animation) isn't reshaped and converted to paths each time.
+/// It's kept by the [`super::FontCache`] whose fonts the outlines are made from.
+#[derive(Debug, Default)]
+pub struct OutlineCache {
+	outlines: HashMap<OutlineKey, Vec<Subpath<ManipulatorGroupId>>>,
+	/// Keys in the order they were last used, least recent first
+	order: VecDeque<OutlineKey>,
+}
+
+impl OutlineCache {
+	/// Returns the cached outlines for the key, generating and caching them first if they aren't already.
+	pub fn get_or_insert_with(&mut self, key: OutlineKey, generate: impl FnOnce() -> Vec<Subpath<ManipulatorGroupId>>) -> Vec<Subpath<ManipulatorGroupId>> {
+		if let Some(outlines) = self.outlines.get(&key) {
+			if let Some(index) = self.order.iter().position(|used| *used == key) {
+				let used = self.order.remove(index);
+				self.order.extend(used);
+			}
+			return outlines.clone();
+		}
+
+		let outlines = generate();
+		if self.order.len() >= OUTLINE_CACHE_CAPACITY {
+			if let Some(oldest) = self.order.pop_front() {
+				self.outlines.remove(&oldest);
+			}
+		}
+		self.order.push_back(key.clone());
+		self.outlines.insert(key, outlines.clone());
+		outlines
+	}
+
+	pub fn clear(&mut self) {
+		self.outlines.clear();
+		self.order.clear();
+	}
+
+	pub fn len(&self) -> usize {
+		self.outlines.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.outlines.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	use glam::DVec2;
+
+	fn key(text: &str) -> OutlineKey {
+		OutlineKey {
+			fonts: vec![Font::new("Source Sans Pro".into(), "Regular (400)".into())],
+			font_size: 24_f64.to_bits(),
+			line_width: None,
+			frame: None,
+			paragraph: Paragraph::default(),
+			text: text.to_string(),
+			missing_glyph: MissingGlyph::Notdef,
+			features: FontFeatures::default(),
+		}
+	}
+
+	#[test]
+	fn generates_each_key_once() {
+		let mut cache = OutlineCache::default();
+		let mut generated = 0;
+		for text in ["a", "b", "a", "a", "b"] {
+			let outlines = cache.get_or_insert_with(key(text), || {
+				generated += 1;
+				vec![Subpath::new_rect(DVec2::ZERO, DVec2::ONE)]
+			});
+			assert_eq!(outlines.len(), 1);
+		}
+		assert_eq!(generated, 2);
+		assert_eq!(cache.len(), 2);
+	}
+
+	#[test]
+	fn drops_least_recently_used_when_full() {
+		let mut cache = OutlineCache::default();
+		for index in 0..OUTLINE_CACHE_CAPACITY {
+			cache.get_or_insert_with(key(&index.to_string()), Vec::new);
+		}
+		// Using the oldest text again keeps it, so the next oldest is dropped instead
+		cache.get_or_insert_with(key("0"), || panic!("The outlines should still be cached"));
+		cache.get_or_insert_with(key("new"), Vec::new);
+		assert_eq!(cache.len(), OUTLINE_CACHE_CAPACITY);
+
+		let regenerated = |cache: &mut OutlineCache, text: &str| {
+			let mut regenerated = false;
+			cache.get_or_insert_with(key(text), || {
+				regenerated = true;
+				Vec::new()
+			});
+			regenerated
+		};
+		assert!(!regenerated(&mut cache, "0"));
+		assert!(regenerated(&mut cache, "1"));
+	}
+}
@@ -1,10 +1,302 @@
+use super::Hyphenation;
 use crate::uuid::ManipulatorGroupId;
 
-use bezier_rs::{ManipulatorGroup, Subpath};
+use bezier_rs::{Bezier, BezierHandles, ManipulatorGroup, Subpath, TValue};
+use dyn_any::{DynAny, StaticType};
 
 use glam::DVec2;
 use rustybuzz::ttf_parser::{GlyphId, OutlineBuilder};
-use rustybuzz::{GlyphBuffer, UnicodeBuffer};
+use rustybuzz::{Face, Feature, Tag, UnicodeBuffer};
+
+/// What to draw in place of characters that neither the font nor any of its fallbacks have a glyph for.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum MissingGlyph {
+	/// The `.notdef` glyph of the primary font, usually an empty box
+	#[default]
+	Notdef,
+	/// The replacement character (U+FFFD) from the first font that has it
+	Replacement,
+	/// Nothing, closing up the gap the character would have left
+	Skip,
+}
+
+/// How each line of text is placed within the line width, or within the longest line if there's no line width.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum TextAlign {
+	#[default]
+	Left,
+	Center,
+	Right,
+	/// Spread out to fill the whole width, except for the last line of each paragraph which is left aligned
+	Justify,
+}
+
+/// How the lines of a paragraph are broken and aligned.
+#[derive(Debug, Clone, Copy)]
+pub struct Paragraph {
+	pub align: TextAlign,
+	pub hyphenation: Hyphenation,
+	/// The most extra space that justifying may add between two words, as a multiple of the width of a space
+	pub max_word_spacing: f64,
+	/// The most extra space that justifying may add between two letters, as a multiple of the font size
+	pub max_letter_spacing: f64,
+	/// How many lines the first character drops through as a drop cap, set into the start of those lines, or 0 for none
+	pub drop_cap_lines: u32,
+}
+
+impl Default for Paragraph {
+	fn default() -> Self {
+		Self {
+			align: TextAlign::Left,
+			hyphenation: Hyphenation::Off,
+			max_word_spacing: 1.,
+			max_letter_spacing: 0.05,
+			drop_cap_lines: 0,
+		}
+	}
+}
+
+// Compared and hashed by the bits of the spacing limits so paragraphs can be part of the key of the outline cache
+impl PartialEq for Paragraph {
+	fn eq(&self, other: &Self) -> bool {
+		self.align == other.align
+			&& self.hyphenation == other.hyphenation
+			&& self.max_word_spacing.to_bits() == other.max_word_spacing.to_bits()
+			&& self.max_letter_spacing.to_bits() == other.max_letter_spacing.to_bits()
+			&& self.drop_cap_lines == other.drop_cap_lines
+	}
+}
+
+impl Eq for Paragraph {}
+
+impl core::hash::Hash for Paragraph {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.align.hash(state);
+		self.hyphenation.hash(state);
+		self.max_word_spacing.to_bits().hash(state);
+		self.max_letter_spacing.to_bits().hash(state);
+		self.drop_cap_lines.hash(state);
+	}
+}
+
+/// How many straight pieces each curve of a frame's outlines is flattened into.
+const FRAME_FLATTEN_STEPS: usize = 16;
+
+/// Closed shapes that lines of text are flowed inside of and around, in the same coordinates as the laid out text.
+/// Each line is placed in the widest span at its height that's inside the region and clear of the exclusions, and heights with no room are skipped.
+#[derive(Debug, Clone, Default)]
+pub struct TextFrame {
+	/// Outlines flattened to polygons whose inside, by the even-odd rule, holds the lines, or none to flow the lines down the usual column from the top
+	pub region: Vec<Vec<DVec2>>,
+	/// Outlines flattened to polygons that the lines are kept out of
+	pub exclusions: Vec<Vec<DVec2>>,
+	/// Distance kept between the text and the edges of the region
+	pub padding: f64,
+	/// Distance kept between the text and the edges of the exclusions
+	pub margin: f64,
+}
+
+// Compared and hashed by the bits of the points so frames can be part of the key of the outline cache
+impl PartialEq for TextFrame {
+	fn eq(&self, other: &Self) -> bool {
+		let bits = |polygons: &Vec<Vec<DVec2>>| {
+			polygons
+				.iter()
+				.map(|polygon| polygon.iter().map(|point| point.to_array().map(f64::to_bits)).collect::<Vec<_>>())
+				.collect::<Vec<_>>()
+		};
+		bits(&self.region) == bits(&other.region)
+			&& bits(&self.exclusions) == bits(&other.exclusions)
+			&& self.padding.to_bits() == other.padding.to_bits()
+			&& self.margin.to_bits() == other.margin.to_bits()
+	}
+}
+
+impl Eq for TextFrame {}
+
+impl core::hash::Hash for TextFrame {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		for polygons in [&self.region, &self.exclusions] {
+			polygons.len().hash(state);
+			for polygon in polygons {
+				polygon.len().hash(state);
+				polygon.iter().for_each(|point| point.to_array().map(f64::to_bits).hash(state));
+			}
+		}
+		self.padding.to_bits().hash(state);
+		self.margin.to_bits().hash(state);
+	}
+}
+
+impl TextFrame {
+	/// Flattens the curves of a closed outline into the polygon that a frame is made of.
+	pub fn polygon(beziers: impl IntoIterator<Item = Bezier>) -> Vec<DVec2> {
+		let mut points = Vec::new();
+		for bezier in beziers {
+			match bezier.handles {
+				BezierHandles::Linear => points.push(bezier.start),
+				_ => points.extend((0..FRAME_FLATTEN_STEPS).map(|step| bezier.evaluate(TValue::Parametric(step as f64 / FRAME_FLATTEN_STEPS as f64)))),
+			}
+		}
+		points
+	}
+
+	/// The widest span across the band between the two heights that lies wholly inside the region (or the column, if there's no region) and clear of the exclusions, as its left edge and width.
+	fn widest_span(&self, top: f64, bottom: f64, column: (f64, f64)) -> Option<(f64, f64)> {
+		// The edges of the spans only change direction at the polygons' vertices, so the band (grown by the distance kept from the polygons) is at its narrowest
+		// at one of them or at its top or bottom, which are sampled just inside the band so that shapes which only touch its edge don't narrow it
+		let heights = |polygons: &[Vec<DVec2>], distance: f64| {
+			let (top, bottom) = (top - distance, bottom + distance);
+			let mut heights = vec![top + 1e-6, bottom - 1e-6];
+			heights.extend(polygons.iter().flatten().map(|point| point.y).filter(|&y| y > top && y < bottom));
+			heights
+		};
+
+		let mut spans = vec![column];
+		if !self.region.is_empty() {
+			for y in heights(&self.region, self.padding) {
+				let inside = inside_spans(&self.region, y)
+					.into_iter()
+					.map(|(start, end)| (start + self.padding, end - self.padding))
+					.collect::<Vec<_>>();
+				spans = intersect_spans(&spans, &inside);
+			}
+		}
+		for exclusion in &self.exclusions {
+			let exclusion = core::slice::from_ref(exclusion);
+			for y in heights(exclusion, self.margin) {
+				// Spans widened by the margin may overlap, leaving backwards gaps between them that the intersection drops
+				let covered = inside_spans(exclusion, y).into_iter().map(|(start, end)| (start - self.margin, end + self.margin)).collect::<Vec<_>>();
+				spans = intersect_spans(&spans, &complement_spans(&covered));
+			}
+		}
+
+		spans
+			.into_iter()
+			.map(|(start, end)| (start, end - start))
+			.filter(|&(_, width)| width > 0.)
+			.max_by(|a, b| a.1.total_cmp(&b.1))
+	}
+
+	/// The lowest point of the region, or of the exclusions if there's no region, below which a line that finds no room ends the flow.
+	fn bottom(&self) -> f64 {
+		let polygons = if self.region.is_empty() { &self.exclusions } else { &self.region };
+		polygons.iter().flatten().map(|point| point.y).fold(f64::NEG_INFINITY, f64::max)
+	}
+
+	/// The height that the first line is placed at.
+	fn top(&self) -> f64 {
+		if self.region.is_empty() {
+			0.
+		} else {
+			self.region.iter().flatten().map(|point| point.y).fold(f64::INFINITY, f64::min)
+		}
+	}
+}
+
+/// The spans along the horizontal line at the height that are inside the polygons by the even-odd rule, from left to right.
+fn inside_spans(polygons: &[Vec<DVec2>], y: f64) -> Vec<(f64, f64)> {
+	let mut crossings = Vec::new();
+	for polygon in polygons {
+		for (index, &start) in polygon.iter().enumerate() {
+			let end = polygon[(index + 1) % polygon.len()];
+			if (start.y <= y) != (end.y <= y) {
+				crossings.push(start.x + (y - start.y) / (end.y - start.y) * (end.x - start.x));
+			}
+		}
+	}
+	crossings.sort_by(f64::total_cmp);
+	crossings.chunks_exact(2).map(|pair| (pair[0], pair[1])).collect()
+}
+
+/// The parts of the line that none of the spans cover.
+fn complement_spans(spans: &[(f64, f64)]) -> Vec<(f64, f64)> {
+	let mut result = Vec::new();
+	let mut start = f64::NEG_INFINITY;
+	for &(span_start, span_end) in spans {
+		result.push((start, span_start));
+		start = span_end;
+	}
+	result.push((start, f64::INFINITY));
+	result
+}
+
+/// The parts of the line that are covered by both sets of spans.
+fn intersect_spans(a: &[(f64, f64)], b: &[(f64, f64)]) -> Vec<(f64, f64)> {
+	let mut result = Vec::new();
+	let (mut a_index, mut b_index) = (0, 0);
+	while a_index < a.len() && b_index < b.len() {
+		let (start, end) = (a[a_index].0.max(b[b_index].0), a[a_index].1.min(b[b_index].1));
+		if start < end {
+			result.push((start, end));
+		}
+		if a[a_index].1 < b[b_index].1 {
+			a_index += 1;
+		} else {
+			b_index += 1;
+		}
+	}
+	result
+}
+
+/// Optional OpenType features to shape the text with, for fonts that support them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FontFeatures {
+	/// Standard and contextual ligatures, such as joining "fi" into a single glyph
+	pub ligatures: bool,
+	/// Lowercase letters drawn as small capitals
+	pub small_caps: bool,
+	/// Digits of equal width, so numbers line up in columns
+	pub tabular_numbers: bool,
+	/// The stylistic set (1 to 20) whose alternate glyphs are used, or 0 for none
+	pub stylistic_set: u32,
+}
+
+impl Default for FontFeatures {
+	fn default() -> Self {
+		Self {
+			ligatures: true,
+			small_caps: false,
+			tabular_numbers: false,
+			stylistic_set: 0,
+		}
+	}
+}
+
+impl FontFeatures {
+	/// The features to pass to the shaper, overriding its defaults only where these differ from them.
+	fn shaping_features(&self) -> Vec<Feature> {
+		let mut features = Vec::new();
+		if !self.ligatures {
+			features.push(Feature::new(Tag::from_bytes(b"liga"), 0, ..));
+			features.push(Feature::new(Tag::from_bytes(b"clig"), 0, ..));
+		}
+		if self.small_caps {
+			features.push(Feature::new(Tag::from_bytes(b"smcp"), 1, ..));
+		}
+		if self.tabular_numbers {
+			features.push(Feature::new(Tag::from_bytes(b"tnum"), 1, ..));
+		}
+		if (1..=20).contains(&self.stylistic_set) {
+			let tag = format!("ss{:02}", self.stylistic_set);
+			features.push(Feature::new(Tag::from_bytes_lossy(tag.as_bytes()), 1, ..));
+		}
+		features
+	}
+}
+
+/// A glyph placed by shaping, with its advance and offset already scaled to the font size.
+struct ShapedGlyph {
+	/// Index of the font in the fallback chain that the glyph comes from
+	face: usize,
+	id: GlyphId,
+	/// The size the glyph is drawn at, which is larger than the rest of the text for a drop cap
+	font_size: f64,
+	advance: DVec2,
+	offset: DVec2,
+}
 
 struct Builder {
 	current_subpath: Subpath<ManipulatorGroupId>,
@@ -18,7 +310,7 @@ struct Builder {
 
 impl Builder {
 	fn point(&self, x: f32, y: f32) -> DVec2 {
-		self.pos + self.offset + DVec2::new(x as f64, self.ascender - y as f64) * self.scale
+		self.pos + self.offset + DVec2::new(x as f64 * self.scale, self.ascender - y as f64 * self.scale)
 	}
 }
 
@@ -53,125 +345,746 @@ impl OutlineBuilder for Builder {
 	}
 }
 
-fn font_properties(buzz_face: &rustybuzz::Face, font_size: f64) -> (f64, f64, UnicodeBuffer) {
-	let scale = (buzz_face.units_per_em() as f64).recip() * font_size;
-	let line_height = font_size;
-	let buffer = UnicodeBuffer::new();
-	(scale, line_height, buffer)
+fn font_scale(face: &Face, font_size: f64) -> f64 {
+	(face.units_per_em() as f64).recip() * font_size
 }
 
-fn push_str(buffer: &mut UnicodeBuffer, word: &str, trailing_space: bool) {
-	buffer.push_str(word);
+/// Shapes the text with the font at `face_index` in the fallback chain, handing any runs of characters it has no glyphs for on to the next font in the chain.
+fn shape(faces: &[Face], face_index: usize, text: &str, font_size: f64, missing_glyph: MissingGlyph, features: &[Feature], glyphs: &mut Vec<ShapedGlyph>) {
+	let face = &faces[face_index];
+	let scale = font_scale(face, font_size);
 
-	if trailing_space {
-		buffer.push_str(" ");
+	let mut buffer = UnicodeBuffer::new();
+	buffer.push_str(text);
+	let glyph_buffer = rustybuzz::shape(face, features, buffer);
+	let (infos, positions) = (glyph_buffer.glyph_infos(), glyph_buffer.glyph_positions());
+
+	// Byte offsets into the text where each cluster starts, used to find the characters behind a run of missing glyphs
+	let mut cluster_starts = infos.iter().map(|info| info.cluster as usize).collect::<Vec<_>>();
+	cluster_starts.sort_unstable();
+	cluster_starts.dedup();
+	let cluster_end = |cluster: usize| cluster_starts.iter().copied().find(|&start| start > cluster).unwrap_or(text.len());
+
+	// A cluster (such as a letter with a combining accent) is only drawn from this font if all of its glyphs are present, so fallback fonts always get whole characters
+	let cluster_missing = |cluster: u32| infos.iter().any(|info| info.cluster == cluster && info.glyph_id == 0);
+
+	let mut index = 0;
+	while index < infos.len() {
+		if !cluster_missing(infos[index].cluster) {
+			let position = positions[index];
+			glyphs.push(ShapedGlyph {
+				face: face_index,
+				id: GlyphId(infos[index].glyph_id as u16),
+				font_size,
+				advance: DVec2::new(position.x_advance as f64, position.y_advance as f64) * scale,
+				offset: DVec2::new(position.x_offset as f64, position.y_offset as f64) * scale,
+			});
+			index += 1;
+			continue;
+		}
+
+		let run_end = (index..infos.len()).find(|&index| !cluster_missing(infos[index].cluster)).unwrap_or(infos.len());
+		let clusters = infos[index..run_end].iter().map(|info| info.cluster as usize);
+		let (start, end) = (clusters.clone().min().unwrap_or_default(), cluster_end(clusters.max().unwrap_or_default()));
+		let missing = &text[start..end];
+
+		if face_index + 1 < faces.len() {
+			shape(faces, face_index + 1, missing, font_size, missing_glyph, features, glyphs);
+		} else {
+			match missing_glyph {
+				MissingGlyph::Notdef => {
+					let advance = faces[0].glyph_hor_advance(GlyphId(0)).unwrap_or_default() as f64 * font_scale(&faces[0], font_size);
+					glyphs.extend(missing.chars().map(|_| ShapedGlyph {
+						face: 0,
+						id: GlyphId(0),
+						font_size,
+						advance: DVec2::new(advance, 0.),
+						offset: DVec2::ZERO,
+					}));
+				}
+				MissingGlyph::Replacement => {
+					let replacement = char::REPLACEMENT_CHARACTER.to_string().repeat(missing.chars().count());
+					shape(faces, 0, &replacement, font_size, MissingGlyph::Notdef, features, glyphs);
+				}
+				MissingGlyph::Skip => {}
+			}
+		}
+
+		index = run_end;
 	}
 }
 
-fn wrap_word(line_width: Option<f64>, glyph_buffer: &GlyphBuffer, scale: f64, x_pos: f64) -> bool {
-	if let Some(line_width) = line_width {
-		let word_length: i32 = glyph_buffer.glyph_positions().iter().map(|pos| pos.x_advance).sum();
-		let scaled_word_length = word_length as f64 * scale;
+/// A line of words, each shaped into glyphs, before it's aligned.
+struct Line {
+	words: Vec<Vec<ShapedGlyph>>,
+	/// Whether the line ends its paragraph, which keeps it from being justified
+	last: bool,
+}
+
+fn advance_width(glyphs: &[ShapedGlyph]) -> f64 {
+	glyphs.iter().map(|glyph| glyph.advance.x).sum()
+}
+
+/// Glyphs that take up no space (such as combining accents) sit on the glyph before them, so letter spacing only goes before glyphs with an advance.
+fn letter_gaps(glyphs: &[ShapedGlyph]) -> usize {
+	glyphs.iter().skip(1).filter(|glyph| glyph.advance.x != 0.).count()
+}
+
+/// Where a line starts and how wide it may be, with no width meaning no limit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct LineSlot {
+	origin: DVec2,
+	width: Option<f64>,
+}
 
-		if scaled_word_length + x_pos > line_width {
-			return true;
+/// Finds the place of each line in turn as the text flows down its column, or through a frame.
+struct Flow<'a> {
+	frame: Option<&'a TextFrame>,
+	line_width: Option<f64>,
+	line_height: f64,
+	/// The top of the next band of the frame to look for room in
+	y: f64,
+	bottom: f64,
+	slots: Vec<LineSlot>,
+}
+
+impl<'a> Flow<'a> {
+	fn new(frame: Option<&'a TextFrame>, line_width: Option<f64>, line_height: f64) -> Self {
+		Self {
+			frame,
+			line_width,
+			line_height,
+			y: frame.map_or(0., TextFrame::top),
+			bottom: frame.map_or(0., TextFrame::bottom),
+			slots: Vec::new(),
 		}
 	}
-	false
+
+	/// The slot of the line at the index, or `None` once the lines have run out of room in the frame.
+	fn slot(&mut self, index: usize) -> Option<LineSlot> {
+		let Some(frame) = self.frame else {
+			return Some(LineSlot {
+				origin: DVec2::new(0., index as f64 * self.line_height),
+				width: self.line_width,
+			});
+		};
+
+		// The line width only bounds lines that run down the usual column rather than through a region
+		let column = if frame.region.is_empty() {
+			(0., self.line_width.unwrap_or(f64::INFINITY))
+		} else {
+			(f64::NEG_INFINITY, f64::INFINITY)
+		};
+		while self.slots.len() <= index {
+			match frame.widest_span(self.y, self.y + self.line_height, column) {
+				Some((x, width)) => self.slots.push(LineSlot {
+					origin: DVec2::new(x, self.y),
+					width: width.is_finite().then_some(width),
+				}),
+				None if self.y >= self.bottom => return None,
+				None => {}
+			}
+			self.y += self.line_height;
+		}
+		Some(self.slots[index])
+	}
 }
 
-pub fn to_path(str: &str, buzz_face: Option<rustybuzz::Face>, font_size: f64, line_width: Option<f64>) -> Vec<Subpath<ManipulatorGroupId>> {
-	let buzz_face = match buzz_face {
-		Some(face) => face,
-		// Show blank layer if font has not loaded
-		None => return vec![],
+/// Breaks the text into lines at its newlines and wherever the next word doesn't fit within the width of its line, given by its index, hyphenating words where the paragraph's hyphenation allows.
+fn break_lines(str: &str, mut line_width: impl FnMut(usize) -> Option<f64>, space_width: f64, hyphenation: Hyphenation, mut shape_text: impl FnMut(&str) -> Vec<ShapedGlyph>) -> Vec<Line> {
+	let mut lines = Vec::new();
+
+	for paragraph in str.split('\n') {
+		let mut line: Vec<Vec<ShapedGlyph>> = Vec::new();
+		let mut x = 0.;
+
+		for word in paragraph.split(' ') {
+			let mut text = word;
+			loop {
+				let glyphs = shape_text(text);
+				let gap = if line.is_empty() { 0. } else { space_width };
+				let width = line_width(lines.len());
+				let fits = |width_needed: f64| match width {
+					Some(width) => x + gap + width_needed <= width,
+					None => true,
+				};
+
+				if fits(advance_width(&glyphs)) {
+					x += gap + advance_width(&glyphs);
+					line.push(glyphs);
+					break;
+				}
+
+				// Break the word with a hyphen at the last point where its start still fits on the line
+				let hyphenated = hyphenation.break_points(text).into_iter().rev().find_map(|index| {
+					let head = shape_text(&format!("{}-", &text[..index]));
+					fits(advance_width(&head)).then_some((head, index))
+				});
+				if let Some((head, index)) = hyphenated {
+					line.push(head);
+					lines.push(Line {
+						words: core::mem::take(&mut line),
+						last: false,
+					});
+					x = 0.;
+					text = &text[index..];
+					continue;
+				}
+
+				if !line.is_empty() {
+					lines.push(Line {
+						words: core::mem::take(&mut line),
+						last: false,
+					});
+					x = 0.;
+					continue;
+				}
+
+				// The word is too long for a line of its own, so it's broken wherever it reaches the end of the line
+				let mut width = width.unwrap_or(f64::INFINITY);
+				let mut piece = Vec::new();
+				x = 0.;
+				for glyph in glyphs {
+					if !piece.is_empty() && x + glyph.advance.x >= width {
+						lines.push(Line {
+							words: vec![core::mem::take(&mut piece)],
+							last: false,
+						});
+						width = line_width(lines.len()).unwrap_or(f64::INFINITY);
+						x = 0.;
+					}
+					x += glyph.advance.x;
+					piece.push(glyph);
+				}
+				line.push(piece);
+				break;
+			}
+		}
+
+		lines.push(Line { words: line, last: true });
+	}
+
+	lines
+}
+
+/// Lays out the text in lines broken at the line width, or flowed through the frame, and aligned according to the paragraph, and calls `place` with each glyph and its position.
+/// Lines that overflow the frame are left out. Returns the size of the text's bounding box.
+#[allow(clippy::too_many_arguments)]
+fn layout(
+	str: &str,
+	faces: &[Face],
+	font_size: f64,
+	line_width: Option<f64>,
+	frame: Option<&TextFrame>,
+	paragraph: &Paragraph,
+	missing_glyph: MissingGlyph,
+	features: &FontFeatures,
+	mut place: impl FnMut(&ShapedGlyph, DVec2),
+) -> DVec2 {
+	let features = features.shaping_features();
+	let mut shape_text = |text: &str| {
+		let mut glyphs = Vec::new();
+		shape(faces, 0, text, font_size, missing_glyph, &features, &mut glyphs);
+		glyphs
+	};
+
+	let line_height = font_size;
+	let space_width = advance_width(&shape_text(" "));
+
+	// A drop cap is sized so the tops of its capitals line up with those of the first line and its baseline sits on that of the last line it drops through
+	let drop_cap_lines = paragraph.drop_cap_lines as usize;
+	let (drop_cap, str) = match str.chars().next() {
+		Some(first) if drop_cap_lines > 0 && !first.is_whitespace() => {
+			let cap_height = faces[0]
+				.capital_height()
+				.filter(|&height| height > 0)
+				.map_or(0.7, |height| height as f64 / faces[0].units_per_em() as f64);
+			let drop_cap_size = font_size + (drop_cap_lines - 1) as f64 * line_height / cap_height;
+			let mut glyphs = Vec::new();
+			shape(faces, 0, &str[..first.len_utf8()], drop_cap_size, missing_glyph, &features, &mut glyphs);
+			(glyphs, &str[first.len_utf8()..])
+		}
+		_ => (Vec::new(), str),
+	};
+	// The lines beside the drop cap are indented past it by the width of a space
+	let indent = |index: usize| {
+		if index < drop_cap_lines && !drop_cap.is_empty() {
+			advance_width(&drop_cap) + space_width
+		} else {
+			0.
+		}
 	};
 
-	let (scale, line_height, mut buffer) = font_properties(&buzz_face, font_size);
+	let mut flow = Flow::new(frame, line_width, line_height);
+	let lines = break_lines(
+		str,
+		|index| flow.slot(index).and_then(|slot| slot.width).map(|width| width - indent(index)),
+		space_width,
+		paragraph.hyphenation,
+		&mut shape_text,
+	);
+
+	let natural_width = |line: &Line| line.words.iter().map(|word| advance_width(word)).sum::<f64>() + line.words.len().saturating_sub(1) as f64 * space_width;
+	// Lines without a width are aligned within the longest of them
+	let longest_width = lines.iter().enumerate().map(|(index, line)| natural_width(line) + indent(index)).fold(0., f64::max);
+
+	let mut bounds = DVec2::ZERO;
+	if let Some(slot) = flow.slot(0).filter(|_| !drop_cap.is_empty()) {
+		let mut x = slot.origin.x;
+		let y = slot.origin.y + (drop_cap_lines - 1) as f64 * line_height;
+		for glyph in &drop_cap {
+			place(glyph, DVec2::new(x, y));
+			x += glyph.advance.x;
+		}
+		bounds = DVec2::new(x, y + line_height);
+	}
+	for (index, line) in lines.iter().enumerate() {
+		let Some(slot) = flow.slot(index) else { break };
+		let extra = (slot.width.unwrap_or(longest_width) - indent(index) - natural_width(line)).max(0.);
+		let (mut x, word_spacing, letter_spacing) = match paragraph.align {
+			TextAlign::Left => (0., 0., 0.),
+			TextAlign::Center => (extra / 2., 0., 0.),
+			TextAlign::Right => (extra, 0., 0.),
+			TextAlign::Justify if line.last => (0., 0., 0.),
+			TextAlign::Justify => {
+				// The space is made up by widening the gaps between words first, then those between letters, each only as far as its limit allows
+				let word_gaps = line.words.len().saturating_sub(1);
+				let word_spacing = if word_gaps > 0 {
+					(extra / word_gaps as f64).min(paragraph.max_word_spacing * space_width)
+				} else {
+					0.
+				};
+				let remaining = extra - word_spacing * word_gaps as f64;
+				let letter_gaps = line.words.iter().map(|word| letter_gaps(word)).sum::<usize>();
+				let letter_spacing = if letter_gaps > 0 {
+					(remaining / letter_gaps as f64).min(paragraph.max_letter_spacing * font_size)
+				} else {
+					0.
+				};
+				(0., word_spacing, letter_spacing)
+			}
+		};
+		x += slot.origin.x + indent(index);
+		let y = slot.origin.y;
+
+		for (word_index, word) in line.words.iter().enumerate() {
+			if word_index > 0 {
+				x += space_width + word_spacing;
+			}
+			for (glyph_index, glyph) in word.iter().enumerate() {
+				if glyph_index > 0 && glyph.advance.x != 0. {
+					x += letter_spacing;
+				}
+				place(glyph, DVec2::new(x, y));
+				x += glyph.advance.x;
+			}
+		}
+		bounds = bounds.max(DVec2::new(x, y + line_height));
+	}
+
+	bounds
+}
+
+pub fn to_path(str: &str, buzz_face: Option<Face>, font_size: f64, line_width: Option<f64>) -> Vec<Subpath<ManipulatorGroupId>> {
+	match buzz_face {
+		Some(face) => to_path_with_fallback(str, &[face], font_size, line_width, None, &Paragraph::default(), MissingGlyph::Notdef, &FontFeatures::default()),
+		// Show blank layer if font has not loaded
+		None => vec![],
+	}
+}
+
+/// Converts the text to outlines, drawing each character from the first of the fonts that has a glyph for it and falling back to the missing glyph policy when none do.
+/// The OpenType features apply to every font in the chain that supports them. Lines are broken at the line width, if there is one, or flowed through the frame, and aligned according to the paragraph.
+#[allow(clippy::too_many_arguments)]
+pub fn to_path_with_fallback(
+	str: &str,
+	faces: &[Face],
+	font_size: f64,
+	line_width: Option<f64>,
+	frame: Option<&TextFrame>,
+	paragraph: &Paragraph,
+	missing_glyph: MissingGlyph,
+	features: &FontFeatures,
+) -> Vec<Subpath<ManipulatorGroupId>> {
+	let Some(primary) = faces.first() else {
+		// Show blank layer if font has not loaded
+		return vec![];
+	};
 
 	let mut builder = Builder {
 		current_subpath: Subpath::new(Vec::new(), false),
 		other_subpaths: Vec::new(),
 		pos: DVec2::ZERO,
 		offset: DVec2::ZERO,
-		ascender: (buzz_face.ascender() as f64 / buzz_face.height() as f64) * font_size / scale,
-		scale,
+		ascender: (primary.ascender() as f64 / primary.height() as f64) * font_size,
+		scale: font_scale(primary, font_size),
 		id: ManipulatorGroupId::ZERO,
 	};
 
-	for line in str.split('\n') {
-		let length = line.split(' ').count();
-		for (index, word) in line.split(' ').enumerate() {
-			push_str(&mut buffer, word, index != length - 1);
-			let glyph_buffer = rustybuzz::shape(&buzz_face, &[], buffer);
+	layout(str, faces, font_size, line_width, frame, paragraph, missing_glyph, features, |glyph, pos| {
+		builder.pos = pos;
+		builder.offset = glyph.offset;
+		builder.scale = font_scale(&faces[glyph.face], glyph.font_size);
+		faces[glyph.face].outline_glyph(glyph.id, &mut builder);
+		if !builder.current_subpath.is_empty() {
+			builder.other_subpaths.push(core::mem::replace(&mut builder.current_subpath, Subpath::new(Vec::new(), false)));
+		}
+	});
 
-			if wrap_word(line_width, &glyph_buffer, scale, builder.pos.x) {
-				builder.pos = DVec2::new(0., builder.pos.y + line_height);
-			}
+	builder.other_subpaths
+}
 
-			for (glyph_position, glyph_info) in glyph_buffer.glyph_positions().iter().zip(glyph_buffer.glyph_infos()) {
-				if let Some(line_width) = line_width {
-					if builder.pos.x + (glyph_position.x_advance as f64 * builder.scale) >= line_width {
-						builder.pos = DVec2::new(0., builder.pos.y + line_height);
-					}
-				}
-				builder.offset = DVec2::new(glyph_position.x_offset as f64, glyph_position.y_offset as f64) * builder.scale;
-				buzz_face.outline_glyph(GlyphId(glyph_info.glyph_id as u16), &mut builder);
-				if !builder.current_subpath.is_empty() {
-					builder.other_subpaths.push(core::mem::replace(&mut builder.current_subpath, Subpath::new(Vec::new(), false)));
-				}
+pub fn bounding_box(str: &str, buzz_face: Option<Face>, font_size: f64, line_width: Option<f64>) -> DVec2 {
+	match buzz_face {
+		Some(face) => bounding_box_with_fallback(str, &[face], font_size, line_width, None, &Paragraph::default(), MissingGlyph::Notdef, &FontFeatures::default()),
+		// Show blank layer if font has not loaded
+		None => DVec2::ZERO,
+	}
+}
+
+/// Measures the text as laid out by [`to_path_with_fallback`].
+#[allow(clippy::too_many_arguments)]
+pub fn bounding_box_with_fallback(
+	str: &str,
+	faces: &[Face],
+	font_size: f64,
+	line_width: Option<f64>,
+	frame: Option<&TextFrame>,
+	paragraph: &Paragraph,
+	missing_glyph: MissingGlyph,
+	features: &FontFeatures,
+) -> DVec2 {
+	if faces.is_empty() {
+		return DVec2::ZERO;
+	}
+	layout(str, faces, font_size, line_width, frame, paragraph, missing_glyph, features, |_, _| {})
+}
+
+pub fn load_face(data: &[u8]) -> rustybuzz::Face {
+	rustybuzz::Face::from_slice(data, 0).expect("Loading font failed")
+}
 
-				builder.pos += DVec2::new(glyph_position.x_advance as f64, glyph_position.y_advance as f64) * builder.scale;
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// Shapes every character into a glyph 10 units wide, so line breaking can be tested without a font.
+	fn monospace(text: &str) -> Vec<ShapedGlyph> {
+		text.chars()
+			.map(|_| ShapedGlyph {
+				face: 0,
+				id: GlyphId(0),
+				font_size: 10.,
+				advance: DVec2::new(10., 0.),
+				offset: DVec2::ZERO,
+			})
+			.collect()
+	}
+
+	fn line_lengths(lines: &[Line]) -> Vec<Vec<usize>> {
+		lines.iter().map(|line| line.words.iter().map(Vec::len).collect()).collect()
+	}
+
+	/// Builds a font for shaping tests, 1000 units to the em, with a glyph for each of the characters in order from glyph 1 that's a square 500 units wide (or nothing, for a space).
+	/// Its "liga" feature joins "f" and "i" into a ligature 900 units wide, and its "smcp" feature swaps each lowercase letter for a small capital 400 units wide.
+	fn test_font(chars: &str) -> Vec<u8> {
+		let chars = chars.chars().collect::<Vec<_>>();
+		let glyph = |char: char| chars.iter().position(|&other| other == char).map(|index| index as i32 + 1);
+		let ligature = chars.len() as i32 + 1;
+		let lowercase = chars.iter().filter(|char| char.is_lowercase()).filter_map(|&char| glyph(char)).collect::<Vec<_>>();
+		let glyph_count = ligature + 1 + lowercase.len() as i32;
+		let advance = |id: i32| match id {
+			id if id == ligature => 900,
+			id if id > ligature => 400,
+			_ => 500,
+		};
+		let be16 = |values: &[i32]| values.iter().flat_map(|&value| (value as u16).to_be_bytes()).collect::<Vec<u8>>();
+		let be32 = |values: &[u32]| values.iter().flat_map(|value| value.to_be_bytes()).collect::<Vec<u8>>();
+
+		// Squares inset by 50 units from each side of the glyph, made of four on-curve points given as deltas
+		let (mut glyf, mut loca) = (Vec::new(), Vec::new());
+		for id in 0..glyph_count {
+			loca.push(glyf.len() as u32);
+			if id == 0 || glyph(' ') == Some(id) {
+				continue;
 			}
+			let right = advance(id) - 50;
+			glyf.extend(be16(&[1, 50, 0, right, 700, 3, 0]));
+			glyf.extend([1; 4]);
+			glyf.extend(be16(&[50, right - 50, 0, 50 - right, 0, 0, 700, 0]));
+		}
+		loca.push(glyf.len() as u32);
 
-			buffer = glyph_buffer.clear();
+		let mut mapping = chars.iter().filter_map(|&char| Some((char as u32, glyph(char)? as u32))).collect::<Vec<_>>();
+		mapping.sort_unstable();
+		mapping.dedup_by_key(|(char, _)| *char);
+		let mut cmap = [be16(&[0, 1, 3, 10]), be32(&[12]), be16(&[12, 0]), be32(&[16 + 12 * mapping.len() as u32, 0, mapping.len() as u32])].concat();
+		mapping.iter().for_each(|&(char, id)| cmap.extend(be32(&[char, char, id])));
+
+		let head = [be32(&[0x10000, 0x10000, 0, 0x5F0F3CF5]), be16(&[0, 1000]), vec![0; 16], be16(&[0, 0, 1000, 700, 0, 8, 2, 1, 0])].concat();
+		let hhea = [be32(&[0x10000]), be16(&[800, -200, 0, 900, 0, 0, 900, 1, 0, 0, 0, 0, 0, 0, 0, glyph_count])].concat();
+		let hmtx = (0..glyph_count).flat_map(|id| be16(&[advance(id), 0])).collect::<Vec<_>>();
+		let maxp = [be32(&[0x5000]), be16(&[glyph_count])].concat();
+
+		// One lookup for each feature, under the default script
+		let script_list = [be16(&[1]), b"DFLT".to_vec(), be16(&[8, 4, 0, 0, 0xFFFF, 2, 0, 1])].concat();
+		let feature_list = [be16(&[2]), b"liga".to_vec(), be16(&[14]), b"smcp".to_vec(), be16(&[20, 0, 1, 0, 0, 1, 1])].concat();
+		let ligature_subst = match (glyph('f'), glyph('i')) {
+			(Some(f), Some(i)) => be16(&[1, 8, 1, 14, 1, 1, f, 1, 4, ligature, 2, i]),
+			_ => be16(&[1, 6, 0, 1, 0]),
+		};
+		let count = lowercase.len() as i32;
+		let small_caps = (0..count).map(|index| ligature + 1 + index);
+		let single_subst = [be16(&[2, 6 + 2 * count, count]), be16(&small_caps.collect::<Vec<_>>()), be16(&[1, count]), be16(&lowercase)].concat();
+		let lookup_list = [be16(&[2, 6, 14, 4, 0, 1, 16, 1, 0, 1, 8 + ligature_subst.len() as i32]), ligature_subst, single_subst].concat();
+		let offsets = [10, 10 + script_list.len() as i32, 10 + (script_list.len() + feature_list.len()) as i32];
+		let gsub = [be16(&[1, 0]), be16(&offsets), script_list, feature_list, lookup_list].concat();
+
+		let tables = [
+			(b"GSUB", gsub),
+			(b"cmap", cmap),
+			(b"glyf", glyf),
+			(b"head", head),
+			(b"hhea", hhea),
+			(b"hmtx", hmtx),
+			(b"loca", be32(&loca)),
+			(b"maxp", maxp),
+		];
+		let mut font = [be32(&[0x10000]), be16(&[tables.len() as i32, 128, 3, 0])].concat();
+		let directory_end = font.len() + 16 * tables.len();
+		let mut data = Vec::new();
+		for (tag, table) in tables {
+			font.extend(tag);
+			font.extend(be32(&[0, (directory_end + data.len()) as u32, table.len() as u32]));
+			data.extend(table);
+			data.resize(data.len().next_multiple_of(4), 0);
 		}
-		builder.pos = DVec2::new(0., builder.pos.y + line_height);
+		font.extend(data);
+		font
 	}
-	builder.other_subpaths
-}
 
-pub fn bounding_box(str: &str, buzz_face: Option<rustybuzz::Face>, font_size: f64, line_width: Option<f64>) -> DVec2 {
-	let buzz_face = match buzz_face {
-		Some(face) => face,
-		// Show blank layer if font has not loaded
-		None => return DVec2::ZERO,
-	};
+	/// The font each glyph is drawn from, its ID, and where it's placed, for the text laid out at a size of 10 in a line without a width.
+	fn shaped(text: &str, faces: &[Face], missing_glyph: MissingGlyph, features: &FontFeatures) -> Vec<(usize, u16, DVec2)> {
+		let mut glyphs = Vec::new();
+		layout(text, faces, 10., None, None, &Paragraph::default(), missing_glyph, features, |glyph, position| {
+			glyphs.push((glyph.face, glyph.id.0, position))
+		});
+		glyphs
+	}
 
-	let (scale, line_height, mut buffer) = font_properties(&buzz_face, font_size);
+	#[test]
+	fn fall_back_to_other_fonts() {
+		let fonts = [test_font("ab "), test_font("b\u{2605}")];
+		let faces = fonts.iter().map(|font| load_face(font)).collect::<Vec<_>>();
+		let features = FontFeatures::default();
 
-	let mut pos = DVec2::ZERO;
-	let mut bounds = DVec2::ZERO;
+		// The star is drawn from the fallback font, while the "b" that both fonts have is still drawn from the primary font
+		let glyphs = shaped("a\u{2605}b", &faces, MissingGlyph::Notdef, &features);
+		assert_eq!(glyphs, vec![(0, 1, DVec2::ZERO), (1, 2, DVec2::new(5., 0.)), (0, 2, DVec2::new(10., 0.))]);
+		let outlines = to_path_with_fallback("a\u{2605}b", &faces, 10., None, None, &Paragraph::default(), MissingGlyph::Notdef, &features);
+		assert_eq!(outlines.len(), 3);
 
-	for line in str.split('\n') {
-		let length = line.split(' ').count();
-		for (index, word) in line.split(' ').enumerate() {
-			push_str(&mut buffer, word, index != length - 1);
+		// Without the fallback font, the star is missing
+		assert_eq!(shaped("a\u{2605}b", &faces[..1], MissingGlyph::Notdef, &features)[1], (0, 0, DVec2::new(5., 0.)));
+		assert_eq!(
+			to_path_with_fallback("a\u{2605}b", &faces[..1], 10., None, None, &Paragraph::default(), MissingGlyph::Notdef, &features).len(),
+			2
+		);
+	}
 
-			let glyph_buffer = rustybuzz::shape(&buzz_face, &[], buffer);
+	#[test]
+	fn missing_glyph_policies() {
+		let fonts = [test_font("ab"), test_font("\u{FFFD}")];
+		let faces = fonts.iter().map(|font| load_face(font)).collect::<Vec<_>>();
+		let features = FontFeatures::default();
 
-			if wrap_word(line_width, &glyph_buffer, scale, pos.x) {
-				pos = DVec2::new(0., pos.y + line_height);
-			}
+		// The ".notdef" glyph of the primary font takes the place of each missing character
+		let glyphs = shaped("a??b", &faces, MissingGlyph::Notdef, &features);
+		assert_eq!(glyphs, vec![(0, 1, DVec2::ZERO), (0, 0, DVec2::new(5., 0.)), (0, 0, DVec2::new(10., 0.)), (0, 2, DVec2::new(15., 0.))]);
 
-			for glyph_position in glyph_buffer.glyph_positions() {
-				if let Some(line_width) = line_width {
-					if pos.x + (glyph_position.x_advance as f64 * scale) >= line_width {
-						pos = DVec2::new(0., pos.y + line_height);
-					}
+		// The replacement character comes from the first font that has it
+		let glyphs = shaped("a??b", &faces, MissingGlyph::Replacement, &features);
+		assert_eq!(glyphs, vec![(0, 1, DVec2::ZERO), (1, 1, DVec2::new(5., 0.)), (1, 1, DVec2::new(10., 0.)), (0, 2, DVec2::new(15., 0.))]);
+
+		// Skipping missing characters closes up the gap they would have left
+		let glyphs = shaped("a??b", &faces, MissingGlyph::Skip, &features);
+		assert_eq!(glyphs, vec![(0, 1, DVec2::ZERO), (0, 2, DVec2::new(5., 0.))]);
+	}
+
+	#[test]
+	fn toggle_font_features() {
+		let font = test_font("fiab");
+		let faces = [load_face(&font)];
+		let ids = |text: &str, features: FontFeatures| shaped(text, &faces, MissingGlyph::Notdef, &features).into_iter().map(|(_, id, _)| id).collect::<Vec<_>>();
+		let defaults = FontFeatures::default();
+
+		// Ligatures are on by default, joining "fi" into a single glyph
+		assert_eq!(ids("fia", defaults), vec![5, 3]);
+		assert_eq!(ids("fia", FontFeatures { ligatures: false, ..defaults }), vec![1, 2, 3]);
+		// The ligature is wider than either of the letters it joins, but narrower than both
+		assert_eq!(shaped("fia", &faces, MissingGlyph::Notdef, &defaults)[1].2, DVec2::new(9., 0.));
+
+		// Small capitals replace the lowercase letters, narrower than the letters they replace
+		assert_eq!(ids("ab", defaults), vec![3, 4]);
+		assert_eq!(ids("ab", FontFeatures { small_caps: true, ..defaults }), vec![8, 9]);
+		assert_eq!(shaped("ab", &faces, MissingGlyph::Notdef, &FontFeatures { small_caps: true, ..defaults })[1].2, DVec2::new(4., 0.));
+
+		// Features that the font doesn't have leave the text as it is
+		assert_eq!(
+			ids(
+				"ab",
+				FontFeatures {
+					tabular_numbers: true,
+					stylistic_set: 3,
+					..defaults
 				}
-				pos += DVec2::new(glyph_position.x_advance as f64, glyph_position.y_advance as f64) * scale;
-			}
-			bounds = bounds.max(pos + DVec2::new(0., line_height));
+			),
+			vec![3, 4]
+		);
+	}
+
+	#[test]
+	fn break_lines_at_width() {
+		let lines = break_lines("one two three\nfour", |_| Some(90.), 10., Hyphenation::Off, monospace);
+		assert_eq!(line_lengths(&lines), vec![vec![3, 3], vec![5], vec![4]]);
+		assert_eq!(lines.iter().map(|line| line.last).collect::<Vec<_>>(), vec![false, true, true]);
 
-			buffer = glyph_buffer.clear();
+		// Without a line width, lines only break at newlines
+		let lines = break_lines("one two three\nfour", |_| None, 10., Hyphenation::Off, monospace);
+		assert_eq!(line_lengths(&lines), vec![vec![3, 3, 5], vec![4]]);
+
+		// A word too long for any line is broken wherever it reaches the end of the line
+		let lines = break_lines("abcdefghij", |_| Some(45.), 10., Hyphenation::Off, monospace);
+		assert_eq!(line_lengths(&lines), vec![vec![4], vec![4], vec![2]]);
+	}
+
+	fn rect(min: DVec2, max: DVec2) -> Vec<DVec2> {
+		vec![min, DVec2::new(max.x, min.y), max, DVec2::new(min.x, max.y)]
+	}
+
+	#[test]
+	fn frame_spans() {
+		let mut frame = TextFrame {
+			region: vec![rect(DVec2::ZERO, DVec2::splat(100.))],
+			exclusions: vec![rect(DVec2::new(0., 20.), DVec2::new(40., 60.))],
+			padding: 0.,
+			margin: 0.,
+		};
+		let unbounded = (f64::NEG_INFINITY, f64::INFINITY);
+		assert_eq!(frame.widest_span(0., 10., unbounded), Some((0., 100.)));
+		assert_eq!(frame.widest_span(30., 40., unbounded), Some((40., 60.)));
+		// A band that only just reaches the exclusion is still narrowed by it
+		assert_eq!(frame.widest_span(55., 65., unbounded), Some((40., 60.)));
+
+		// The margin only keeps the text away from the exclusions, and the padding only from the edges of the region
+		frame.margin = 5.;
+		assert_eq!(frame.widest_span(30., 40., unbounded), Some((45., 55.)));
+		assert_eq!(frame.widest_span(12., 18., unbounded), Some((45., 55.)));
+
+		frame.padding = 5.;
+		assert_eq!(frame.widest_span(30., 40., unbounded), Some((45., 50.)));
+		assert_eq!(frame.widest_span(5., 12., unbounded), Some((5., 90.)));
+
+		// A band is as narrow as the shape gets within it, and has no span outside of the shape
+		let triangle = TextFrame {
+			region: vec![vec![DVec2::ZERO, DVec2::new(100., 100.), DVec2::new(0., 100.)]],
+			..Default::default()
+		};
+		assert_eq!(triangle.widest_span(-20., -10., unbounded), None);
+		// The top of the band is sampled just inside it, so the span is a hair wider than the shape at that height
+		let (left, width) = triangle.widest_span(50., 60., unbounded).unwrap();
+		assert!(left == 0. && (width - 50.).abs() < 1e-3);
+	}
+
+	#[test]
+	fn flow_around_exclusions() {
+		// Without a region, lines run down the column and skip the heights that an exclusion fills the whole width of
+		let frame = TextFrame {
+			exclusions: vec![rect(DVec2::new(-10., 10.), DVec2::new(110., 30.))],
+			..Default::default()
+		};
+		let mut flow = Flow::new(Some(&frame), Some(100.), 10.);
+		let tops = (0..3).map(|index| flow.slot(index).unwrap().origin.y).collect::<Vec<_>>();
+		assert_eq!(tops, vec![0., 30., 40.]);
+
+		// Lines that overflow the region have no slot
+		let frame = TextFrame {
+			region: vec![rect(DVec2::ZERO, DVec2::new(50., 25.))],
+			..Default::default()
+		};
+		let mut flow = Flow::new(Some(&frame), None, 10.);
+		assert_eq!(flow.slot(1).map(|slot| slot.width), Some(Some(50.)));
+		assert_eq!(flow.slot(2), None);
+	}
+
+	/// The size that each glyph is drawn at and where it's placed, in order, for the text laid out at a size of 10.
+	fn placed(text: &str, faces: &[Face], line_width: Option<f64>, frame: Option<&TextFrame>, paragraph: &Paragraph) -> Vec<(f64, DVec2)> {
+		let mut glyphs = Vec::new();
+		layout(text, faces, 10., line_width, frame, paragraph, MissingGlyph::Notdef, &FontFeatures::default(), |glyph, position| {
+			glyphs.push((glyph.font_size, position))
+		});
+		glyphs
+	}
+
+	/// The height of each line of glyphs placed at a size of 10 from the [`test_font`], along with where its first glyph starts and its last glyph ends.
+	fn line_extents(glyphs: &[(f64, DVec2)]) -> Vec<(f64, f64, f64)> {
+		let mut lines: Vec<(f64, f64, f64)> = Vec::new();
+		for &(_, position) in glyphs {
+			match lines.iter_mut().find(|(y, _, _)| *y == position.y) {
+				Some(line) => line.2 = position.x + 5.,
+				None => lines.push((position.y, position.x, position.x + 5.)),
+			}
 		}
-		pos = DVec2::new(0., pos.y + line_height);
+		lines
 	}
 
-	bounds
-}
+	#[test]
+	fn drop_cap_spans_lines() {
+		let font = test_font("abc ");
+		let faces = [load_face(&font)];
+		let paragraph = Paragraph {
+			drop_cap_lines: 3,
+			..Default::default()
+		};
+		let glyphs = placed("abc abc abc abc abc abc abc abc abc abc", &faces, Some(60.), None, &paragraph);
 
-pub fn load_face(data: &[u8]) -> rustybuzz::Face {
-	rustybuzz::Face::from_slice(data, 0).expect("Loading font failed")
+		// Without a capital height in the font, capitals are taken to be 0.7 of the size, so the drop cap reaches from the top of the first line's capitals down to the third line
+		let (drop_cap_size, drop_cap_position) = glyphs[0];
+		assert!((drop_cap_size * 0.7 - (10. * 0.7 + 2. * 10.)).abs() < 1e-9);
+		assert_eq!(drop_cap_position, DVec2::new(0., 20.));
+		assert!(glyphs[1..].iter().all(|&(size, _)| size == 10.));
+
+		// The lines beside it are indented past it by the width of a space and fit their words into the room left, while the lines below it aren't indented
+		let indent = drop_cap_size * 0.5 + 5.;
+		let lines = line_extents(&glyphs[1..]);
+		assert_eq!(
+			lines.iter().map(|&(y, start, _)| (y, start)).collect::<Vec<_>>(),
+			vec![(0., indent), (10., indent), (20., indent), (30., 0.), (40., 0.)]
+		);
+		assert!(lines[..3].iter().all(|&(_, _, end)| end <= 60.));
+		assert_eq!(lines[3].2, 55.);
+	}
+
+	#[test]
+	fn wrap_around_exclusion_margin() {
+		let font = test_font("abc ");
+		let faces = [load_face(&font)];
+		let frame = TextFrame {
+			exclusions: vec![rect(DVec2::new(20., 0.), DVec2::new(40., 15.))],
+			margin: 5.,
+			..Default::default()
+		};
+		let glyphs = placed("abc abc abc abc abc abc abc abc abc abc abc abc", &faces, Some(100.), Some(&frame), &Paragraph::default());
+
+		// The lines beside the exclusion are placed in the widest room past it and its margin, while the lines clear of it span the whole width
+		assert_eq!(line_extents(&glyphs), vec![(0., 45., 100.), (10., 45., 100.), (20., 0., 95.), (30., 0., 15.)]);
+	}
+
+	#[test]
+	fn break_lines_with_hyphens() {
+		// "hy-phe-na-tion" is broken after "hyphe", which fits on the first line along with its hyphen
+		let lines = break_lines("a hyphenation", |_| Some(80.), 10., Hyphenation::English, monospace);
+		assert_eq!(line_lengths(&lines), vec![vec![1, 6], vec![6]]);
+		assert_eq!(lines[0].words[1].len(), "hyphe-".len());
+	}
 }
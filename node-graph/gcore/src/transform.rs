@@ -8,7 +8,7 @@ use glam::DVec2;
 use crate::raster::bbox::AxisAlignedBbox;
 use crate::raster::ImageFrame;
 use crate::raster::Pixel;
-use crate::vector::VectorData;
+use crate::vector::{VectorData, VectorInstances};
 use crate::Artboard;
 use crate::GraphicElement;
 use crate::GraphicGroup;
@@ -74,8 +74,9 @@ impl Transform for GraphicElement {
 	fn transform(&self) -> DAffine2 {
 		match self {
 			GraphicElement::VectorData(vector_shape) => vector_shape.transform(),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.transform(),
 			GraphicElement::ImageFrame(image_frame) => image_frame.transform(),
-			GraphicElement::Text(_) => todo!("Transform of text"),
+			GraphicElement::Text(_) => DAffine2::IDENTITY,
 			GraphicElement::GraphicGroup(graphic_group) => graphic_group.transform(),
 			GraphicElement::Artboard(artboard) => artboard.transform(),
 		}
@@ -83,8 +84,9 @@ impl Transform for GraphicElement {
 	fn local_pivot(&self, pivot: DVec2) -> DVec2 {
 		match self {
 			GraphicElement::VectorData(vector_shape) => vector_shape.local_pivot(pivot),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.local_pivot(pivot),
 			GraphicElement::ImageFrame(image_frame) => image_frame.local_pivot(pivot),
-			GraphicElement::Text(_) => todo!("Transform of text"),
+			GraphicElement::Text(_) => pivot,
 			GraphicElement::GraphicGroup(graphic_group) => graphic_group.local_pivot(pivot),
 			GraphicElement::Artboard(artboard) => artboard.local_pivot(pivot),
 		}
@@ -92,8 +94,9 @@ impl Transform for GraphicElement {
 	fn decompose_scale(&self) -> DVec2 {
 		match self {
 			GraphicElement::VectorData(vector_shape) => vector_shape.decompose_scale(),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.decompose_scale(),
 			GraphicElement::ImageFrame(image_frame) => image_frame.decompose_scale(),
-			GraphicElement::Text(_) => todo!("Transform of text"),
+			GraphicElement::Text(_) => DVec2::ONE,
 			GraphicElement::GraphicGroup(graphic_group) => graphic_group.decompose_scale(),
 			GraphicElement::Artboard(artboard) => artboard.decompose_scale(),
 		}
@@ -103,6 +106,7 @@ impl TransformMut for GraphicElement {
 	fn transform_mut(&mut self) -> &mut DAffine2 {
 		match self {
 			GraphicElement::VectorData(vector_shape) => vector_shape.transform_mut(),
+			GraphicElement::VectorInstances(vector_instances) => vector_instances.transform_mut(),
 			GraphicElement::ImageFrame(image_frame) => image_frame.transform_mut(),
 			GraphicElement::Text(_) => todo!("Transform of text"),
 			GraphicElement::GraphicGroup(graphic_group) => graphic_group.transform_mut(),
@@ -125,6 +129,20 @@ impl TransformMut for VectorData {
 	}
 }
 
+impl Transform for VectorInstances {
+	fn transform(&self) -> DAffine2 {
+		self.transform
+	}
+	fn local_pivot(&self, pivot: DVec2) -> DVec2 {
+		self.local_pivot(pivot)
+	}
+}
+impl TransformMut for VectorInstances {
+	fn transform_mut(&mut self) -> &mut DAffine2 {
+		&mut self.transform
+	}
+}
+
 impl Transform for Artboard {
 	fn transform(&self) -> DAffine2 {
 		DAffine2::from_translation(self.location.as_dvec2())
@@ -180,6 +198,12 @@ pub struct Footprint {
 	pub quality: RenderQuality,
 	/// When the transform is set downstream, all upsream modifications have to be ignored
 	pub ignore_modifications: bool,
+	/// The row of a data table that is being evaluated when generating variable data output, such as one certificate per name
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub data_row: Option<usize>,
+	/// The value of a named parameter that is being varied when evaluating a design for each value of a parameter sweep
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub parameter: Option<crate::variable_data::ParameterBinding>,
 }
 
 impl Default for Footprint {
@@ -189,6 +213,8 @@ impl Default for Footprint {
 			resolution: glam::UVec2::new(1920, 1080),
 			quality: RenderQuality::Full,
 			ignore_modifications: false,
+			data_row: None,
+			parameter: None,
 		}
 	}
 }
@@ -216,7 +242,9 @@ fn cull_vector_data<T>(footprint: Footprint, vector_data: T) -> T {
 impl core::hash::Hash for Footprint {
 	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
 		self.transform.to_cols_array().iter().for_each(|x| x.to_le_bytes().hash(state));
-		self.resolution.hash(state)
+		self.resolution.hash(state);
+		self.data_row.hash(state);
+		self.parameter.hash(state)
 	}
 }
 
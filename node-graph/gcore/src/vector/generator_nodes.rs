@@ -1,8 +1,9 @@
 use crate::uuid::ManipulatorGroupId;
-use crate::vector::VectorData;
+use crate::vector::{AttributeValues, PointId, VectorData};
 use crate::Node;
 
-use bezier_rs::Subpath;
+use bezier_rs::{Bezier, Subpath};
+use dyn_any::{DynAny, StaticType};
 
 use glam::DVec2;
 
@@ -106,9 +107,175 @@ pub struct PathGenerator<ColinearManipulators> {
 fn generate_path(path_data: Vec<Subpath<ManipulatorGroupId>>, colinear_manipulators: Vec<ManipulatorGroupId>) -> super::VectorData {
 	let mut vector_data = super::VectorData::from_subpaths(path_data);
 	vector_data.colinear_manipulators = colinear_manipulators;
+	// The path is drawn and edited by hand with the Pen and Path tools
+	vector_data.set_manual(true);
 	vector_data
 }
 
+/// Name of the per-point attribute, written by [`GridNode`], holding the row of the cell each point belongs to.
+pub const ROW_ATTRIBUTE: &str = "row";
+/// Name of the per-point attribute, written by [`GridNode`], holding the column of the cell each point belongs to, or its place around the ring of a radial grid.
+pub const COLUMN_ATTRIBUTE: &str = "column";
+/// Name of the per-point attribute, written by [`GridNode`] for radial grids, holding the ring of the cell each point belongs to, counting outwards from 0 at the middle.
+pub const RING_ATTRIBUTE: &str = "ring";
+
+/// The arrangement of the cells made by [`GridNode`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum GridType {
+	/// Squares in rows and columns
+	#[default]
+	Square,
+	/// Hexagons with a corner at the top, in rows with every other one shifted over by half a cell
+	Hexagon,
+	/// Triangles in rows, alternately pointing up and down
+	Triangle,
+	/// Rings around the middle, each cut into the same number of slices
+	Radial,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GridNode<GridType, Spacing, Columns, Rows, Points> {
+	grid_type: GridType,
+	spacing: Spacing,
+	columns: Columns,
+	rows: Rows,
+	points: Points,
+}
+
+/// Generates a grid of cells centered on the origin, as the outline of each cell or as the point at the center of each cell, for building tilings and layouts.
+///
+/// The spacing is the distance between the centers of neighboring cells in a row, which is the length of the sides of triangles, and the width of each ring
+/// of a radial grid. A radial grid has a ring for each row, cut into a slice for each column.
+///
+/// Every point is given the [`ROW_ATTRIBUTE`] and [`COLUMN_ATTRIBUTE`] of its cell, and the points of a radial grid also get the [`RING_ATTRIBUTE`] so that
+/// the rings can be picked out by the same name whatever the grid is built from.
+#[node_macro::node_fn(GridNode)]
+fn grid(_input: (), grid_type: GridType, spacing: f64, columns: u32, rows: u32, points: bool) -> VectorData {
+	// Most cells in the grid, which keeps huge counts from generating an endless amount of geometry
+	const MAX_CELLS: u32 = 100_000;
+
+	let columns = columns.min(MAX_CELLS);
+	let rows = rows.min(MAX_CELLS / columns.max(1));
+	let row_height = match grid_type {
+		GridType::Hexagon | GridType::Triangle => spacing * 3_f64.sqrt() / 2.,
+		GridType::Square | GridType::Radial => spacing,
+	};
+	// Moves the middle of the grid to the origin
+	let offset = match grid_type {
+		GridType::Square => -DVec2::new(columns.saturating_sub(1) as f64 * spacing, rows.saturating_sub(1) as f64 * row_height) / 2.,
+		GridType::Hexagon => {
+			let shifted_rows = if rows > 1 { spacing / 2. } else { 0. };
+			-DVec2::new(columns.saturating_sub(1) as f64 * spacing + shifted_rows, rows.saturating_sub(1) as f64 * row_height) / 2.
+		}
+		GridType::Triangle => -DVec2::new(columns.saturating_sub(1) as f64 * spacing / 2., rows as f64 * row_height) / 2.,
+		GridType::Radial => DVec2::ZERO,
+	};
+
+	let mut vector_data = VectorData::empty();
+	let mut cell_rows = Vec::new();
+	let mut cell_columns = Vec::new();
+	for row in 0..rows {
+		for column in 0..columns {
+			let (center, outlines) = match grid_type {
+				GridType::Square => {
+					let center = offset + DVec2::new(column as f64, row as f64) * spacing;
+					(center, vec![Subpath::new_rect(center - spacing / 2., center + spacing / 2.)])
+				}
+				GridType::Hexagon => {
+					let shift = if row % 2 == 1 { spacing / 2. } else { 0. };
+					let center = offset + DVec2::new(column as f64 * spacing + shift, row as f64 * row_height);
+					let corner_radius = spacing / 3_f64.sqrt();
+					let corners = (0..6).map(|corner| center + DVec2::from_angle((corner as f64 * 60. - 90.).to_radians()) * corner_radius);
+					(center, vec![Subpath::from_anchors(corners, true)])
+				}
+				GridType::Triangle => {
+					let up = (row + column) % 2 == 0;
+					let x = offset.x + column as f64 * spacing / 2.;
+					let [top, bottom] = [row as f64, row as f64 + 1.].map(|row| offset.y + row * row_height);
+					let corners = if up {
+						[DVec2::new(x, top), DVec2::new(x + spacing / 2., bottom), DVec2::new(x - spacing / 2., bottom)]
+					} else {
+						[DVec2::new(x, bottom), DVec2::new(x - spacing / 2., top), DVec2::new(x + spacing / 2., top)]
+					};
+					(corners.iter().sum::<DVec2>() / 3., vec![Subpath::from_anchors(corners, true)])
+				}
+				GridType::Radial => {
+					let [inner, outer] = [row as f64 * spacing, (row + 1) as f64 * spacing];
+					let slice = core::f64::consts::TAU / columns as f64;
+					let [start, end] = [column as f64 * slice, (column + 1) as f64 * slice];
+					let center = if row == 0 && columns == 1 {
+						DVec2::ZERO
+					} else {
+						DVec2::from_angle(start + slice / 2.) * (inner + outer) / 2.
+					};
+					(center, ring_slice(inner, outer, start, end, columns == 1))
+				}
+			};
+
+			let cell_points = if points {
+				vector_data.point_domain.push(PointId::generate(), center);
+				1
+			} else {
+				let count = outlines.iter().map(|outline| outline.len()).sum();
+				outlines.into_iter().for_each(|outline| vector_data.append_subpath(outline));
+				count
+			};
+			cell_rows.extend(core::iter::repeat(row as f64).take(cell_points));
+			cell_columns.extend(core::iter::repeat(column as f64).take(cell_points));
+		}
+	}
+
+	if grid_type == GridType::Radial {
+		vector_data.point_domain.set_attribute(RING_ATTRIBUTE, AttributeValues::F64(cell_rows.clone()));
+	}
+	vector_data.point_domain.set_attribute(ROW_ATTRIBUTE, AttributeValues::F64(cell_rows));
+	vector_data.point_domain.set_attribute(COLUMN_ATTRIBUTE, AttributeValues::F64(cell_columns));
+	vector_data
+}
+
+/// The outlines of the slice of the ring between the radii and the angles, which is a wedge when the inner radius is 0 and the whole ring when it's full.
+/// A whole ring with a hole is given as an outer circle and an inner one running the other way, so the hole is left unfilled.
+fn ring_slice(inner: f64, outer: f64, start: f64, end: f64, full: bool) -> Vec<Subpath<ManipulatorGroupId>> {
+	if full {
+		let mut outlines = vec![Subpath::from_beziers(&arc(outer, start, end), true)];
+		if inner > 0. {
+			outlines.push(Subpath::from_beziers(&arc(inner, end, start), true));
+		}
+		return outlines;
+	}
+
+	let mut beziers = arc(outer, start, end);
+	let [outer_start, outer_end] = [start, end].map(|angle| DVec2::from_angle(angle) * outer);
+	if inner > 0. {
+		let [inner_start, inner_end] = [start, end].map(|angle| DVec2::from_angle(angle) * inner);
+		beziers.push(Bezier::from_linear_dvec2(outer_end, inner_end));
+		beziers.extend(arc(inner, end, start));
+		beziers.push(Bezier::from_linear_dvec2(inner_start, outer_start));
+	} else {
+		beziers.push(Bezier::from_linear_dvec2(outer_end, DVec2::ZERO));
+		beziers.push(Bezier::from_linear_dvec2(DVec2::ZERO, outer_start));
+	}
+	vec![Subpath::from_beziers(&beziers, true)]
+}
+
+/// Approximates the circular arc around the origin between the angles with cubic curves, using one for each quarter turn or less.
+fn arc(radius: f64, start: f64, end: f64) -> Vec<Bezier> {
+	let pieces = ((end - start).abs() / core::f64::consts::FRAC_PI_2).ceil().max(1.) as usize;
+	let sweep = (end - start) / pieces as f64;
+	// Length of the handles that best fit a circle, relative to the radius
+	let handle_length = 4. / 3. * (sweep / 4.).tan() * radius;
+
+	(0..pieces)
+		.map(|piece| {
+			let [from, to] = [piece, piece + 1].map(|piece| start + sweep * piece as f64);
+			let [from_point, to_point] = [from, to].map(|angle| DVec2::from_angle(angle) * radius);
+			let [from_tangent, to_tangent] = [from, to].map(|angle| DVec2::from_angle(angle).perp());
+			Bezier::from_cubic_dvec2(from_point, from_point + from_tangent * handle_length, to_point - to_tangent * handle_length, to_point)
+		})
+		.collect()
+}
+
 // #[derive(Debug, Clone, Copy)]
 // pub struct BlitSubpath<P> {
 // 	path_data: P,
@@ -132,3 +299,56 @@ fn generate_path(path_data: Vec<Subpath<ManipulatorGroupId>>, colinear_manipulat
 
 // 	base_image
 // }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+
+	fn grid(grid_type: GridType, columns: u32, rows: u32, points: bool) -> VectorData {
+		GridNode {
+			grid_type: ClonedNode(grid_type),
+			spacing: ClonedNode(10.),
+			columns: ClonedNode(columns),
+			rows: ClonedNode(rows),
+			points: ClonedNode(points),
+		}
+		.eval(())
+	}
+
+	#[test]
+	fn grid_cells() {
+		// Each cell is its own closed outline, centered around the origin
+		let squares = grid(GridType::Square, 3, 2, false);
+		assert_eq!(squares.region_bezier_paths().count(), 6);
+		assert_eq!(squares.bounding_box(), Some([DVec2::new(-15., -10.), DVec2::new(15., 10.)]));
+
+		let hexagons = grid(GridType::Hexagon, 4, 3, false);
+		assert_eq!(hexagons.region_bezier_paths().count(), 12);
+		assert_eq!(hexagons.point_domain.positions().len(), 12 * 6);
+
+		let triangles = grid(GridType::Triangle, 5, 2, false);
+		assert_eq!(triangles.region_bezier_paths().count(), 10);
+		assert_eq!(triangles.point_domain.positions().len(), 10 * 3);
+
+		// A radial grid has a wedge for each slice of the middle ring and a four-sided slice for each of the others
+		let radial = grid(GridType::Radial, 6, 2, false);
+		assert_eq!(radial.region_bezier_paths().count(), 12);
+		let [min, max] = radial.bounding_box().unwrap();
+		assert!((min + DVec2::splat(20.)).length() < 1e-3 && (max - DVec2::splat(20.)).length() < 1e-3);
+	}
+
+	#[test]
+	fn grid_points() {
+		let centers = grid(GridType::Square, 2, 2, true);
+		assert_eq!(centers.point_domain.positions(), [DVec2::new(-5., -5.), DVec2::new(5., -5.), DVec2::new(-5., 5.), DVec2::new(5., 5.)]);
+		assert_eq!(centers.point_domain.attribute(ROW_ATTRIBUTE), Some(&AttributeValues::F64(vec![0., 0., 1., 1.])));
+		assert_eq!(centers.point_domain.attribute(COLUMN_ATTRIBUTE), Some(&AttributeValues::F64(vec![0., 1., 0., 1.])));
+		assert_eq!(centers.point_domain.attribute(RING_ATTRIBUTE), None);
+
+		// The points of a radial grid know their ring, and those in the middle of a whole ring sit at the origin
+		let rings = grid(GridType::Radial, 1, 3, true);
+		assert_eq!(rings.point_domain.attribute(RING_ATTRIBUTE), Some(&AttributeValues::F64(vec![0., 1., 2.])));
+		assert_eq!(rings.point_domain.positions()[0], DVec2::ZERO);
+	}
+}
@@ -0,0 +1,123 @@
+use super::{ConcatElement, VectorData};
+use crate::renderer::Quad;
+
+use dyn_any::{DynAny, StaticType};
+use glam::{DAffine2, DVec2};
+
+/// Copies of one shape, each placed by its own transform, which all share a single copy of the shape's geometry.
+///
+/// The renderer draws the shared geometry at each transform rather than expanding the copies into one big shape, so even huge numbers of copies only cost a transform each.
+/// Use [`VectorInstances::flatten`] (or the Flatten Instances node) where the copies are needed as real geometry, such as for editing or measuring their paths.
+#[derive(Clone, Debug, PartialEq, DynAny)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VectorInstances {
+	/// The shape that every instance is a copy of
+	pub geometry: VectorData,
+	/// Where each copy is placed, applied after the geometry's own transform
+	pub instances: Vec<DAffine2>,
+	/// The transform of all the copies together
+	pub transform: DAffine2,
+}
+
+impl core::hash::Hash for VectorInstances {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.geometry.hash(state);
+		self.instances.iter().flat_map(DAffine2::to_cols_array).for_each(|element| element.to_bits().hash(state));
+		self.transform.to_cols_array().iter().for_each(|element| element.to_bits().hash(state));
+	}
+}
+
+impl Default for VectorInstances {
+	fn default() -> Self {
+		Self::from_transforms(VectorData::empty(), Vec::new())
+	}
+}
+
+impl VectorInstances {
+	/// A single copy of the geometry, left where it is.
+	pub fn new(geometry: VectorData) -> Self {
+		Self::from_transforms(geometry, vec![DAffine2::IDENTITY])
+	}
+
+	/// A copy of the geometry at each of the transforms.
+	pub fn from_transforms(geometry: VectorData, instances: Vec<DAffine2>) -> Self {
+		Self {
+			geometry,
+			instances,
+			transform: DAffine2::IDENTITY,
+		}
+	}
+
+	pub fn len(&self) -> usize {
+		self.instances.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.instances.is_empty()
+	}
+
+	/// The bounds of all the copies, before the transform of the whole is applied.
+	pub fn bounding_box(&self) -> Option<[DVec2; 2]> {
+		self.instances
+			.iter()
+			.filter_map(|&instance| self.geometry.bounding_box_with_transform(instance * self.geometry.transform))
+			.reduce(Quad::combine_bounds)
+	}
+
+	/// Compute the pivot in local space with the current transform applied
+	pub fn local_pivot(&self, normalized_pivot: DVec2) -> DVec2 {
+		let [bounds_min, bounds_max] = self.bounding_box().unwrap_or_default();
+		self.transform.transform_point2(bounds_min + (bounds_max - bounds_min) * normalized_pivot)
+	}
+
+	/// Expands the copies into a single shape, with the geometry duplicated at each transform.
+	pub fn flatten(&self) -> VectorData {
+		let mut result = VectorData::empty();
+		for &instance in &self.instances {
+			result.concat(&self.geometry, self.transform * instance);
+		}
+		result
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::vector::style::Fill;
+	use crate::Color;
+
+	use bezier_rs::Subpath;
+
+	#[test]
+	fn concat_keeps_styles() {
+		let mut red = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		red.style.set_fill(Fill::Solid(Color::RED));
+		let mut blue = VectorData::from_subpath(Subpath::new_rect(DVec2::splat(2.), DVec2::splat(3.)));
+		blue.style.set_fill(Fill::Solid(Color::BLUE));
+
+		// The shape takes on the style of what's added to it, while its own subpaths keep theirs
+		let mut merged = red.clone();
+		merged.concat(&blue, DAffine2::IDENTITY);
+		assert_eq!(merged.style, blue.style);
+		assert_eq!(merged.subpath_style(0), &red.style);
+		assert_eq!(merged.subpath_style(1), &blue.style);
+
+		let mut same = blue.clone();
+		same.concat(&blue, DAffine2::IDENTITY);
+		assert!(same.subpath_styles.is_empty());
+	}
+
+	#[test]
+	fn flatten_matches_instances() {
+		let mut geometry = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		geometry.transform = DAffine2::from_scale(DVec2::splat(2.));
+		let mut instances = VectorInstances::from_transforms(geometry, vec![DAffine2::IDENTITY, DAffine2::from_translation(DVec2::new(10., 0.))]);
+		instances.transform = DAffine2::from_translation(DVec2::new(0., 5.));
+
+		assert_eq!(instances.bounding_box(), Some([DVec2::ZERO, DVec2::new(12., 2.)]));
+
+		let flattened = instances.flatten();
+		assert_eq!(flattened.point_domain.positions().len(), 8);
+		assert_eq!(flattened.bounding_box(), Some([DVec2::new(0., 5.), DVec2::new(12., 7.)]));
+	}
+}
@@ -7,7 +7,21 @@ pub use style::PathStyle;
 mod vector_data;
 pub use vector_data::*;
 
+mod instances;
+pub use instances::*;
+
+mod pattern_tile;
+pub use pattern_tile::*;
+
+mod sticker_outline;
+pub use sticker_outline::*;
+
+mod technical_drawing;
+pub use technical_drawing::*;
+
 mod vector_nodes;
+#[cfg(test)]
+pub(crate) use vector_nodes::test::FutureWrapperNode;
 pub use vector_nodes::*;
 
 pub use bezier_rs;
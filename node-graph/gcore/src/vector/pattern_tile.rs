@@ -0,0 +1,159 @@
+use super::{ConcatElement, VectorData};
+use crate::Node;
+
+use dyn_any::{DynAny, StaticType};
+use glam::{DAffine2, DVec2};
+
+/// The wallpaper group that [`PatternTileNode`] arranges copies of its motif by, named in the short international notation.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum WallpaperGroup {
+	/// The motif alone, repeated without turning or mirroring
+	#[default]
+	P1,
+	/// Half turns
+	P2,
+	/// Mirrored across a vertical line
+	Pm,
+	/// Mirrored across both a vertical and a horizontal line
+	Pmm,
+	/// Quarter turns on a square grid
+	P4,
+	/// Quarter turns and mirrors on a square grid
+	P4m,
+	/// Third turns on a hexagonal grid
+	P3,
+	/// Sixth turns on a hexagonal grid
+	P6,
+	/// Sixth turns and mirrors on a hexagonal grid
+	P6m,
+}
+
+impl WallpaperGroup {
+	fn hexagonal(self) -> bool {
+		matches!(self, Self::P3 | Self::P6 | Self::P6m)
+	}
+
+	/// The size of the rectangular tile, which is square for the groups with quarter turns and as tall as two rows of a hexagonal grid for those with third or sixth turns.
+	pub fn tile_size(self, size: DVec2) -> DVec2 {
+		match self {
+			Self::P4 | Self::P4m => DVec2::splat(size.x),
+			Self::P3 | Self::P6 | Self::P6m => DVec2::new(size.x, size.x * 3_f64.sqrt()),
+			_ => size,
+		}
+	}
+
+	/// Where each copy of the motif is placed within the tile, as turns and mirrors around the tile's center. A hexagonal grid has a second point of the grid at
+	/// the center of the rectangular tile besides its corners, so the copies are placed around both.
+	pub fn copy_transforms(self, tile_size: DVec2) -> Vec<DAffine2> {
+		let turns = match self {
+			Self::P1 | Self::Pm => 1,
+			Self::P2 | Self::Pmm => 2,
+			Self::P3 => 3,
+			Self::P4 | Self::P4m => 4,
+			Self::P6 | Self::P6m => 6,
+		};
+		let mirrors = match self {
+			Self::Pm => vec![DAffine2::IDENTITY, DAffine2::from_scale(DVec2::new(-1., 1.))],
+			Self::Pmm | Self::P4m | Self::P6m => vec![DAffine2::IDENTITY, DAffine2::from_scale(DVec2::new(1., -1.))],
+			_ => vec![DAffine2::IDENTITY],
+		};
+
+		let center = tile_size / 2.;
+		let around_center = |transform: DAffine2| DAffine2::from_translation(center) * transform * DAffine2::from_translation(-center);
+		let rotations = (0..turns).map(|turn| DAffine2::from_angle(core::f64::consts::TAU * turn as f64 / turns as f64));
+		let copies = rotations.flat_map(|rotation| mirrors.iter().map(move |&mirror| around_center(rotation * mirror)));
+		if self.hexagonal() {
+			copies.flat_map(|copy| [copy, DAffine2::from_translation(center) * copy]).collect()
+		} else {
+			copies.collect()
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PatternTileNode<Group, Size, Columns, Rows> {
+	group: Group,
+	size: Size,
+	columns: Columns,
+	rows: Rows,
+}
+
+/// Designs a seamlessly repeating tile from a motif, placed relative to the tile's top left corner, by arranging copies of it according to a wallpaper group.
+/// Copies that cross an edge of the tile appear again across the opposite edge, so the tile repeats without seams, as in a pattern fill.
+///
+/// The size sets the width and height of the tile, where the groups with quarter turns only use the width and those with third or sixth turns make the tile as tall as
+/// two rows of their hexagonal grid. Returns the tile and a preview of the tile repeated the number of columns and rows, with every copy of the motif drawn once.
+#[node_macro::node_fn(PatternTileNode)]
+fn pattern_tile(motif: VectorData, group: WallpaperGroup, size: DVec2, columns: u32, rows: u32) -> (VectorData, VectorData) {
+	let tile_size = group.tile_size(size);
+	if tile_size.x <= 0. || tile_size.y <= 0. {
+		return (VectorData::empty(), VectorData::empty());
+	}
+	let copies = group.copy_transforms(tile_size);
+
+	let mut tile = VectorData::empty();
+	for &copy in &copies {
+		// Each copy is placed in whichever of the tile and its neighbours it reaches into the tile from
+		for offset in (-1..=1).flat_map(|column| (-1..=1).map(move |row| DVec2::new(column as f64, row as f64) * tile_size)) {
+			let transform = DAffine2::from_translation(offset) * copy;
+			let reaches_tile = motif
+				.bounding_box_with_transform(transform * motif.transform)
+				.is_some_and(|[min, max]| min.x < tile_size.x && min.y < tile_size.y && max.x > 0. && max.y > 0.);
+			if reaches_tile {
+				tile.concat(&motif, transform);
+			}
+		}
+	}
+
+	let mut preview = VectorData::empty();
+	for row in 0..rows {
+		for column in 0..columns {
+			let offset = DVec2::new(column as f64, row as f64) * tile_size;
+			for &copy in &copies {
+				preview.concat(&motif, DAffine2::from_translation(offset) * copy);
+			}
+		}
+	}
+
+	(tile, preview)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+
+	use bezier_rs::Subpath;
+
+	#[test]
+	fn pattern_tile_wraps_edges() {
+		let tile_node = |motif: VectorData, group| {
+			PatternTileNode {
+				group: ClonedNode(group),
+				size: ClonedNode(DVec2::new(100., 100.)),
+				columns: ClonedNode(2),
+				rows: ClonedNode(3),
+			}
+			.eval(motif)
+		};
+
+		// A motif inside the tile is copied once for each turn and mirror of the group
+		let motif = VectorData::from_subpath(Subpath::new_rect(DVec2::new(10., 10.), DVec2::new(30., 20.)));
+		let (tile, preview) = tile_node(motif, WallpaperGroup::P4m);
+		assert_eq!(tile.stroke_bezier_paths().count(), 8);
+		assert_eq!(preview.stroke_bezier_paths().count(), 8 * 6);
+		let [min, max] = tile.bounding_box().unwrap();
+		assert!(min.abs_diff_eq(DVec2::splat(10.), 1e-9) && max.abs_diff_eq(DVec2::splat(90.), 1e-9));
+
+		// A motif crossing a corner of the tile appears in each of the four corners
+		let corner = VectorData::from_subpath(Subpath::new_rect(DVec2::splat(-5.), DVec2::splat(5.)));
+		let (tile, preview) = tile_node(corner, WallpaperGroup::P1);
+		assert_eq!(tile.stroke_bezier_paths().count(), 4);
+		assert_eq!(preview.stroke_bezier_paths().count(), 6);
+
+		// Hexagonal groups place copies around the center of the tile as well as its corners
+		assert_eq!(WallpaperGroup::P6.copy_transforms(WallpaperGroup::P6.tile_size(DVec2::splat(100.))).len(), 12);
+		assert!(WallpaperGroup::P3.tile_size(DVec2::new(100., 1.)).abs_diff_eq(DVec2::new(100., 100. * 3_f64.sqrt()), 1e-9));
+	}
+}
@@ -0,0 +1,136 @@
+use super::style::{Fill, LineCap, LineJoin, Stroke, StrokeAlignment};
+use super::VectorData;
+use crate::{Color, GraphicElement, GraphicGroup, Node};
+
+use bezier_rs::Subpath;
+use glam::{DAffine2, DVec2};
+
+#[derive(Debug, Clone, Copy)]
+pub struct StickerOutlineNode<Distance, BorderColor> {
+	distance: Distance,
+	color: BorderColor,
+}
+
+/// Gives the content the border of a die-cut sticker: its silhouette, with all of its shapes merged together, grown outward by the distance with rounded corners
+/// and placed behind it in the color. Holes narrower than twice the distance are closed up, like the letter counters of text.
+///
+/// The border is made of a copy of each shape, filled if it's filled and stroked with round joins as far out as the distance past its own stroke.
+/// Drawn in the same opaque color, the copies cover exactly the grown silhouette, so the color's alpha is given to the border as a whole.
+#[node_macro::node_fn(StickerOutlineNode)]
+fn sticker_outline(graphic_group: GraphicGroup, distance: f64, color: Color) -> GraphicGroup {
+	let mut border = GraphicGroup::EMPTY;
+	border.alpha_blending.opacity = color.a();
+	let paint = color.with_alpha(1.);
+	for element in graphic_group.iter() {
+		add_border_shapes(element, graphic_group.transform, distance.max(0.), paint, &mut border);
+	}
+
+	let mut sticker = GraphicGroup::EMPTY;
+	sticker.push(border.into());
+	sticker.push(graphic_group.into());
+	sticker
+}
+
+/// Adds a border shape for each shape within the element, placed by the transform. Images are bordered by their rectangle, while text labels and artboards aren't bordered.
+fn add_border_shapes(element: &GraphicElement, transform: DAffine2, distance: f64, paint: Color, border: &mut GraphicGroup) {
+	match element {
+		GraphicElement::GraphicGroup(graphic_group) => {
+			for element in graphic_group.iter() {
+				add_border_shapes(element, transform * graphic_group.transform, distance, paint, border);
+			}
+		}
+		GraphicElement::VectorData(vector_data) => border.push(border_shape(vector_data, transform, distance, paint).into()),
+		GraphicElement::VectorInstances(vector_instances) => {
+			for &instance in &vector_instances.instances {
+				border.push(border_shape(&vector_instances.geometry, transform * vector_instances.transform * instance, distance, paint).into());
+			}
+		}
+		GraphicElement::ImageFrame(image_frame) => {
+			let mut frame = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+			frame.transform = image_frame.transform;
+			frame.style.set_fill(Fill::Solid(paint));
+			border.push(border_shape(&frame, transform, distance, paint).into());
+		}
+		GraphicElement::Text(_) | GraphicElement::Artboard(_) => {}
+	}
+}
+
+/// A copy of the shape in document space, filled with the paint where the shape is filled and stroked with it as far out as the distance past the shape's stroke.
+fn border_shape(vector_data: &VectorData, transform: DAffine2, distance: f64, paint: Color) -> VectorData {
+	let transform = transform * vector_data.transform;
+	let subpaths = vector_data.stroke_bezier_paths().collect::<Vec<_>>();
+	let all_closed = !subpaths.is_empty() && subpaths.iter().all(Subpath::closed);
+
+	// How far the shape's own stroke reaches out from its path, scaled along with the shape
+	let stroke_reach = vector_data
+		.style
+		.stroke()
+		.filter(|stroke| stroke.weight > 0. && (stroke.paint != Fill::None || stroke.gradient.is_some()))
+		.map_or(0., |stroke| {
+			let weight = stroke.width_profile.iter().map(|&(_, width)| stroke.weight * width.max(0.)).reduce(f64::max).unwrap_or(stroke.weight);
+			let weight = weight * transform.matrix2.determinant().abs().sqrt();
+			match stroke.alignment {
+				StrokeAlignment::Inside if all_closed && stroke.width_profile.is_empty() => 0.,
+				StrokeAlignment::Outside if all_closed && stroke.width_profile.is_empty() => weight,
+				_ => weight / 2.,
+			}
+		});
+
+	let mut shape = VectorData::empty();
+	for mut subpath in subpaths {
+		subpath.apply_transform(transform);
+		shape.append_subpath(subpath);
+	}
+	if *vector_data.style.fill() != Fill::None {
+		shape.style.set_fill(Fill::Solid(paint));
+		shape.style.set_fill_rule(vector_data.style.fill_rule());
+	}
+	let weight = (distance + stroke_reach) * 2.;
+	if weight > 0. {
+		shape.style.set_stroke(Stroke::new(Some(paint), weight).with_line_join(LineJoin::Round).with_line_cap(LineCap::Round));
+	}
+	shape
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::renderer::GraphicElementRendered;
+	use crate::value::ClonedNode;
+
+	#[test]
+	fn sticker_outline_grows_silhouette() {
+		// Two overlapping squares, one with an outside stroke, and a hole in the first that's too small to survive the border
+		let mut first = VectorData::from_subpaths([Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)), Subpath::new_rect(DVec2::splat(2.), DVec2::splat(4.))]);
+		first.style.set_fill(Fill::Solid(Color::BLACK));
+		first.style.set_fill_rule(crate::vector::style::FillRule::EvenOdd);
+		let mut second = VectorData::from_subpath(Subpath::new_rect(DVec2::splat(8.), DVec2::splat(18.)));
+		second.style.set_stroke(Stroke::new(Some(Color::BLACK), 2.).with_alignment(StrokeAlignment::Outside));
+		let mut content = GraphicGroup::EMPTY;
+		content.extend([first.into(), second.into()]);
+		content.transform = DAffine2::from_translation(DVec2::new(100., 0.));
+
+		let sticker = StickerOutlineNode {
+			distance: ClonedNode(3.),
+			color: ClonedNode(Color::WHITE.with_alpha(0.5)),
+		}
+		.eval(content);
+
+		// The border is behind the content, with the alpha of its color
+		let [GraphicElement::GraphicGroup(border), GraphicElement::GraphicGroup(_)] = sticker.as_slice() else {
+			panic!("The border should be behind the content, got {sticker:?}");
+		};
+		assert_eq!(border.alpha_blending.opacity, 0.5);
+		assert_eq!(border.len(), 2);
+
+		// Grown by the distance past each shape and its stroke
+		assert!(border.contains_point(DVec2::new(102., 5.)));
+		assert!(border.contains_point(DVec2::new(97.5, 5.)));
+		assert!(!border.contains_point(DVec2::new(96.5, 5.)));
+		assert!(border.contains_point(DVec2::new(122.5, 12.)));
+		assert!(!border.contains_point(DVec2::new(123.5, 12.)));
+		// The small hole is closed up, and the corners are rounded
+		assert!(border.contains_point(DVec2::new(103., 3.)));
+		assert!(!border.contains_point(DVec2::new(97.6, -2.6)));
+	}
+}
@@ -1,7 +1,9 @@
 //! Contains stylistic options for SVG elements.
 
 use crate::consts::{LAYER_OUTLINE_STROKE_COLOR, LAYER_OUTLINE_STROKE_WEIGHT};
-use crate::Color;
+use crate::raster::{ColorSpace, ImageFrame};
+use crate::vector::VectorData;
+use crate::{AlphaBlending, Color};
 
 use dyn_any::{DynAny, StaticType};
 use glam::{DAffine2, DVec2};
@@ -12,6 +14,27 @@ use std::fmt::{self, Display, Write};
 /// A value of 3 would correspond to a precision of 10^-3.
 const OPACITY_PRECISION: usize = 3;
 
+/// Number of wedges that a conic gradient is drawn with, each filled with the color at its middle.
+const CONIC_WEDGES: usize = 180;
+
+/// Most tiles that a pattern is drawn with by renderers that repeat the tile themselves, beyond which the pattern is left out.
+const MAX_PATTERN_TILES: usize = 10_000;
+
+/// Length that each subpath of a stroke with a gradient along its path is split into pieces of, each stroked with the color at its middle.
+const STROKE_GRADIENT_PIECE_LENGTH: f64 = 2.;
+
+/// Most pieces that each subpath of a stroke with a gradient along its path is drawn with, beyond which the pieces get longer.
+const MAX_STROKE_GRADIENT_PIECES: usize = 1000;
+
+/// Number of line segments that each segment of a path is flattened into for the outline of a stroke with a width profile.
+const TAPERED_STROKE_STEPS: usize = 32;
+
+/// Least angle in degrees between the directions of the path on either side of an anchor for it to count as a corner that dashes are aligned to.
+const DASH_CORNER_ANGLE: f64 = 5.;
+
+/// Number of pieces that the span between each pair of neighboring stops is split into by extra stops, when a gradient blended outside of sRGB is drawn by renderers that blend in sRGB.
+const INTERPOLATED_STOP_PIECES: usize = 16;
+
 fn format_opacity(attribute: &str, opacity: f32) -> String {
 	if (opacity - 1.).abs() > 10_f32.powi(-(OPACITY_PRECISION as i32)) {
 		format!(r#" {attribute}="{opacity:.OPACITY_PRECISION$}""#)
@@ -20,16 +43,66 @@ fn format_opacity(attribute: &str, opacity: f32) -> String {
 	}
 }
 
+/// Formats a gamma-encoded color for an SVG paint attribute, as a hex string when it's within sRGB or otherwise as a CSS color in Display P3 or extended-range linear sRGB.
+/// The alpha is only included if `with_alpha` is set, since solid paints give it as a separate opacity attribute instead.
+fn format_color(color: Color, with_alpha: bool) -> String {
+	let linear = color.to_linear_srgb();
+	if linear.is_in_gamut(ColorSpace::Srgb) {
+		return if with_alpha { format!("#{}", color.rgba_hex()) } else { format!("#{}", color.rgb_hex()) };
+	}
+
+	let (space, [red, green, blue]) = if linear.is_in_gamut(ColorSpace::DisplayP3) {
+		("display-p3", linear.to_color_space(ColorSpace::DisplayP3))
+	} else {
+		("srgb-linear", linear.to_color_space(ColorSpace::LinearSrgb))
+	};
+	let alpha = if with_alpha { format!(" / {:.OPACITY_PRECISION$}", color.a()) } else { String::new() };
+	format!("color({space} {red:.4} {green:.4} {blue:.4}{alpha})")
+}
+
 #[derive(Default, PartialEq, Eq, Clone, Copy, Debug, Hash, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
 pub enum GradientType {
 	#[default]
 	Linear,
 	Radial,
+	/// Sweeps the colors around the center, starting at the angle pointing towards the end point, with stop positions from 0 to 1 going once around the full 360°.
+	Conic,
+}
+
+/// The color space that the colors of a [Gradient] are blended in between its stops.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum GradientInterpolation {
+	/// Blend the gamma-encoded channels, as SVG and CSS do, which passes through a muddy gray between very different colors
+	#[default]
+	Srgb,
+	/// Blend the channels as amounts of light, which keeps mixtures brighter than in sRGB
+	LinearSrgb,
+	/// Blend in the perceptual OKLab space, where the lightness changes evenly and mixtures stay clean
+	Oklab,
+	/// Blend the lightness, chroma, and hue of OKLab, turning the hue around the color wheel to keep mixtures saturated
+	Lch,
+}
+
+/// Which way around the color wheel the hue turns between two colors of a [Gradient] blended in [GradientInterpolation::Lch], in the same way as CSS.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum HueInterpolation {
+	/// Turn the short way, by at most 180°
+	#[default]
+	Shorter,
+	/// Turn the long way, by at least 180°
+	Longer,
+	/// Turn with increasing hue angles
+	Increasing,
+	/// Turn with decreasing hue angles
+	Decreasing,
 }
 
 /// A gradient fill.
 ///
 /// Contains the start and end points, along with the colors at varying points along the length.
+/// A radial gradient is centered on its start point and reaches its end point at its radius, with its colors optionally focused towards a focal point within the circle.
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
 pub struct Gradient {
@@ -38,6 +111,15 @@ pub struct Gradient {
 	pub transform: DAffine2,
 	pub positions: Vec<(f64, Color)>,
 	pub gradient_type: GradientType,
+	/// Where the first color of a radial gradient begins, which is the center unless set.
+	#[serde(default)]
+	pub focal_point: Option<DVec2>,
+	/// The color space that the colors are blended in between the stops.
+	#[serde(default)]
+	pub interpolation: GradientInterpolation,
+	/// Which way the hue turns between the stops when the colors are blended in LCH.
+	#[serde(default)]
+	pub hue_interpolation: HueInterpolation,
 }
 
 impl core::hash::Hash for Gradient {
@@ -51,6 +133,9 @@ impl core::hash::Hash for Gradient {
 			.for_each(|x| x.to_bits().hash(state));
 		self.positions.iter().for_each(|(_, color)| color.hash(state));
 		self.gradient_type.hash(state);
+		self.focal_point.map(|focal_point| focal_point.to_array().map(f64::to_bits)).hash(state);
+		self.interpolation.hash(state);
+		self.hue_interpolation.hash(state);
 	}
 }
 
@@ -63,9 +148,143 @@ impl Gradient {
 			positions: vec![(0., start_color), (1., end_color)],
 			transform,
 			gradient_type,
+			focal_point: None,
+			interpolation: GradientInterpolation::Srgb,
+			hue_interpolation: HueInterpolation::Shorter,
+		}
+	}
+
+	/// Constructs a new radial gradient around the center, with the colors at 0 and 1 specified and the first color focused at the focal point if given.
+	pub fn new_radial(center: DVec2, radius: f64, focal_point: Option<DVec2>, start_color: Color, end_color: Color, transform: DAffine2) -> Self {
+		Self {
+			focal_point,
+			..Self::new(center, start_color, center + DVec2::X * radius, end_color, transform, GradientType::Radial)
+		}
+	}
+
+	/// The center of a radial gradient.
+	pub fn center(&self) -> DVec2 {
+		self.start
+	}
+
+	/// The radius of a radial gradient, which is the distance from its center to its end point.
+	pub fn radius(&self) -> f64 {
+		self.start.distance(self.end)
+	}
+
+	/// Where the first color of a radial gradient begins.
+	pub fn focal_point(&self) -> DVec2 {
+		self.focal_point.unwrap_or(self.start)
+	}
+
+	/// Constructs a new conic gradient around the center, with the colors at 0 and 1 specified and the sweep beginning at the start angle in degrees.
+	pub fn new_conic(center: DVec2, start_angle: f64, start_color: Color, end_color: Color, transform: DAffine2) -> Self {
+		let end = center + DVec2::from_angle(start_angle.to_radians()) * 0.5;
+		Self::new(center, start_color, end, end_color, transform, GradientType::Conic)
+	}
+
+	/// The angle in degrees, from the positive x axis towards the positive y axis, where the sweep of a conic gradient begins.
+	pub fn start_angle(&self) -> f64 {
+		(self.end - self.start).to_angle().to_degrees()
+	}
+
+	/// The color at the position along the gradient, blended between the stops on either side of it and keeping the color of the nearest stop beyond the first or last.
+	pub fn evaluate(&self, position: f64) -> Color {
+		let Some(after) = self.positions.iter().position(|(stop, _)| *stop > position) else {
+			return self.positions.last().map_or(Color::TRANSPARENT, |(_, color)| *color);
+		};
+		let Some(before) = after.checked_sub(1) else { return self.positions[0].1 };
+
+		let [(start, start_color), (end, end_color)] = [self.positions[before], self.positions[after]];
+		self.mix(start_color, end_color, ((position - start) / (end - start)).clamp(0., 1.) as f32)
+	}
+
+	/// Blends between two colors in the gradient's interpolation space, by the factor from 0 at the first color to 1 at the second.
+	///
+	/// ```
+	/// # use graphene_core::vector::style::{Gradient, GradientInterpolation};
+	/// # use graphene_core::raster::color::Color;
+	/// let mut gradient = Gradient::default();
+	/// assert_eq!(gradient.mix(Color::BLACK, Color::WHITE, 0.5), Color::BLACK.lerp(&Color::WHITE, 0.5));
+	///
+	/// // Blending between complementary colors in OKLab keeps the midpoint brighter than in sRGB
+	/// gradient.interpolation = GradientInterpolation::Oklab;
+	/// let mixture = gradient.mix(Color::BLUE, Color::from_rgbf32_unchecked(1., 1., 0.), 0.5);
+	/// assert!(mixture.average_rgb_channels() > 0.5);
+	/// ```
+	pub fn mix(&self, start: Color, end: Color, factor: f32) -> Color {
+		let lerp = |a: f32, b: f32| a + (b - a) * factor;
+		let alpha = lerp(start.a(), end.a());
+
+		match self.interpolation {
+			GradientInterpolation::Srgb => start.lerp(&end, factor),
+			GradientInterpolation::LinearSrgb => start.to_linear_srgb().lerp(&end.to_linear_srgb(), factor).to_gamma_srgb(),
+			GradientInterpolation::Oklab => {
+				let ([start_l, start_a, start_b], [end_l, end_a, end_b]) = (to_oklab(start), to_oklab(end));
+				from_oklab([lerp(start_l, end_l), lerp(start_a, end_a), lerp(start_b, end_b)], alpha)
+			}
+			GradientInterpolation::Lch => {
+				let ([start_l, start_chroma, start_hue], [end_l, end_chroma, end_hue]) = (to_oklch(start), to_oklch(end));
+				// A gray has no hue of its own, so it takes that of the other color to keep the hue from swinging around the wheel
+				let start_hue = if start_chroma < ACHROMATIC_CHROMA { end_hue } else { start_hue };
+				let end_hue = if end_chroma < ACHROMATIC_CHROMA { start_hue } else { end_hue };
+
+				let tau = core::f32::consts::TAU;
+				let mut turn = (end_hue - start_hue).rem_euclid(tau);
+				turn = match self.hue_interpolation {
+					HueInterpolation::Shorter if turn > tau / 2. => turn - tau,
+					HueInterpolation::Longer if turn > 0. && turn < tau / 2. => turn - tau,
+					HueInterpolation::Longer if turn == 0. => tau,
+					HueInterpolation::Decreasing if turn > 0. => turn - tau,
+					_ => turn,
+				};
+
+				let (sin, cos) = (start_hue + turn * factor).sin_cos();
+				let chroma = lerp(start_chroma, end_chroma);
+				from_oklab([lerp(start_l, end_l), chroma * cos, chroma * sin], alpha)
+			}
 		}
 	}
 
+	/// The stops that renderers blending between stops in sRGB draw the gradient with, which have extra stops in between those of the gradient when it's blended in another space.
+	pub fn interpolated_stops(&self) -> Vec<(f64, Color)> {
+		if self.interpolation == GradientInterpolation::Srgb {
+			return self.positions.clone();
+		}
+
+		let mut stops = Vec::new();
+		for pair in self.positions.windows(2) {
+			let [(start, start_color), (end, end_color)] = [pair[0], pair[1]];
+			stops.extend((0..INTERPOLATED_STOP_PIECES).map(|piece| {
+				let factor = piece as f64 / INTERPOLATED_STOP_PIECES as f64;
+				(start + (end - start) * factor, self.mix(start_color, end_color, factor as f32))
+			}));
+		}
+		stops.extend(self.positions.last().copied());
+		stops
+	}
+
+	/// Splits a conic gradient into wedges around its center, each with the color at its middle, which together cover the unit square that the gradient's points are relative to.
+	/// The corners of each wedge are mapped by the transform.
+	pub fn conic_wedges(&self, transform: DAffine2) -> Vec<([DVec2; 3], Color)> {
+		let corners = [DVec2::ZERO, DVec2::X, DVec2::Y, DVec2::ONE];
+		// Reaching twice as far as the farthest corner leaves room for the straight outer edge of each wedge cutting inside the circle
+		let radius = corners.iter().map(|corner| corner.distance(self.start)).fold(0., f64::max) * 2.;
+		let step = core::f64::consts::TAU / CONIC_WEDGES as f64;
+		let start_angle = self.start_angle().to_radians();
+
+		(0..CONIC_WEDGES)
+			.map(|index| {
+				// Each wedge overlaps half of the next, which is drawn over it, to avoid hairline seams between them
+				let angle = start_angle + step * index as f64;
+				let overlap = if index + 1 < CONIC_WEDGES { 0.5 } else { 0. };
+				let [from, to] = [angle, angle + step * (1. + overlap)].map(|angle| self.start + DVec2::from_angle(angle) * radius);
+				let color = self.evaluate((index as f64 + 0.5) / CONIC_WEDGES as f64);
+				([self.start, from, to].map(|point| transform.transform_point2(point)), color)
+			})
+			.collect()
+	}
+
 	pub fn lerp(&self, other: &Self, time: f64) -> Self {
 		let start = self.start + (other.start - self.start) * time;
 		let end = self.end + (other.end - self.end) * time;
@@ -81,6 +300,12 @@ impl Gradient {
 			})
 			.collect::<Vec<_>>();
 		let gradient_type = if time < 0.5 { self.gradient_type } else { other.gradient_type };
+		let focal_point = (self.focal_point.is_some() || other.focal_point.is_some()).then(|| self.focal_point() + (other.focal_point() - self.focal_point()) * time);
+		let (interpolation, hue_interpolation) = if time < 0.5 {
+			(self.interpolation, self.hue_interpolation)
+		} else {
+			(other.interpolation, other.hue_interpolation)
+		};
 
 		Self {
 			start,
@@ -88,6 +313,9 @@ impl Gradient {
 			transform,
 			positions,
 			gradient_type,
+			focal_point,
+			interpolation,
+			hue_interpolation,
 		}
 	}
 
@@ -98,8 +326,8 @@ impl Gradient {
 		let updated_transform = multiplied_transform * bound_transform;
 
 		let mut positions = String::new();
-		for (position, color) in self.positions.iter() {
-			let _ = write!(positions, r##"<stop offset="{}" stop-color="#{}" />"##, position, color.with_alpha(color.a()).rgba_hex());
+		for (position, color) in self.interpolated_stops() {
+			let _ = write!(positions, r#"<stop offset="{}" stop-color="{}" />"#, position, format_color(color, true));
 		}
 
 		let mod_gradient = transformed_bound_transform.inverse();
@@ -107,6 +335,7 @@ impl Gradient {
 
 		let start = mod_points.transform_point2(self.start);
 		let end = mod_points.transform_point2(self.end);
+		let focal_point = self.focal_point.map(|focal_point| mod_points.transform_point2(focal_point));
 
 		let transform = mod_gradient
 			.to_cols_array()
@@ -126,11 +355,44 @@ impl Gradient {
 			}
 			GradientType::Radial => {
 				let radius = (f64::powi(start.x - end.x, 2) + f64::powi(start.y - end.y, 2)).sqrt();
+				let focal_point = focal_point.map(|focal_point| format!(r#" fx="{}" fy="{}""#, focal_point.x, focal_point.y)).unwrap_or_default();
+				let _ = write!(
+					svg_defs,
+					r#"<radialGradient id="{}" cx="{}" cy="{}" r="{}"{} gradientTransform="matrix({})">{}</radialGradient>"#,
+					gradient_id, start.x, start.y, radius, focal_point, transform, positions
+				);
+			}
+			// SVG has no conic gradients, so the wedges approximating it are drawn into a pattern instead
+			GradientType::Conic => {
+				let wedges = self.conic_wedges(mod_points);
+				let (min, max) = wedges
+					.iter()
+					.flat_map(|(corners, _)| corners)
+					.fold((DVec2::INFINITY, DVec2::NEG_INFINITY), |(min, max), &corner| (min.min(corner), max.max(corner)));
 				let _ = write!(
 					svg_defs,
-					r#"<radialGradient id="{}" cx="{}" cy="{}" r="{}" gradientTransform="matrix({})">{}</radialGradient>"#,
-					gradient_id, start.x, start.y, radius, transform, positions
+					r#"<pattern id="{}" patternUnits="userSpaceOnUse" x="{}" y="{}" width="{}" height="{}">"#,
+					gradient_id,
+					min.x,
+					min.y,
+					max.x - min.x,
+					max.y - min.y
 				);
+				for ([center, from, to], color) in wedges {
+					let _ = write!(
+						svg_defs,
+						r#"<path d="M{},{} L{},{} L{},{} Z" fill="{}"{} />"#,
+						center.x,
+						center.y,
+						from.x,
+						from.y,
+						to.x,
+						to.y,
+						format_color(color, false),
+						format_opacity("fill-opacity", color.a())
+					);
+				}
+				svg_defs.push_str("</pattern>");
 			}
 		}
 
@@ -152,9 +414,10 @@ impl Gradient {
 
 		// Compute the color of the inserted stop
 		let get_color = |index: usize, time: f64| match (self.positions[index].1, self.positions.get(index + 1).map(|(_, c)| *c)) {
-			// Lerp between the nearest colors if applicable
-			(a, Some(b)) => a.lerp(
-				&b,
+			// Blend between the nearest colors if applicable
+			(a, Some(b)) => self.mix(
+				a,
+				b,
 				((time - self.positions[index].0) / self.positions.get(index + 1).map(|end| end.0 - self.positions[index].0).unwrap_or_default()) as f32,
 			),
 			// Use the start or the end color if applicable
@@ -176,9 +439,46 @@ impl Gradient {
 	}
 }
 
+/// Chroma below which a color is treated as a gray without a hue when blending in [GradientInterpolation::Lch].
+const ACHROMATIC_CHROMA: f32 = 1e-4;
+
+/// Converts a color's gamma-encoded sRGB channels to the lightness and the green-red and blue-yellow axes of OKLab.
+/// See <https://bottosson.github.io/posts/oklab/>.
+fn to_oklab(color: Color) -> [f32; 3] {
+	let [r, g, b] = [color.r(), color.g(), color.b()].map(Color::srgb_to_linear);
+	let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+	let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+	let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+	[
+		0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+		1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+		0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+	]
+}
+
+/// Converts a color from OKLab back to gamma-encoded sRGB, keeping colors outside of the sRGB gamut as extended-range channels.
+fn from_oklab([lightness, a, b]: [f32; 3], alpha: f32) -> Color {
+	let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+	let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+	let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+	let [red, green, blue] = [
+		4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+		-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+		-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+	]
+	.map(Color::linear_to_srgb);
+	Color::from_rgbaf32_unchecked(red, green, blue, alpha)
+}
+
+/// Converts a color to the lightness, chroma, and hue angle in radians of OKLab in polar form.
+fn to_oklch(color: Color) -> [f32; 3] {
+	let [lightness, a, b] = to_oklab(color);
+	[lightness, a.hypot(b), b.atan2(a)]
+}
+
 /// Describes the fill of a layer.
 ///
-/// Can be None, a solid [Color], a linear [Gradient], a radial [Gradient] or potentially some sort of image or pattern in the future
+/// Can be None, a solid [Color], a linear [Gradient], a radial [Gradient], a repeated [Pattern] or an [ImageFill]
 #[repr(C)]
 #[derive(Default, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, DynAny, Hash, specta::Type)]
 pub enum Fill {
@@ -186,6 +486,8 @@ pub enum Fill {
 	None,
 	Solid(Color),
 	Gradient(Gradient),
+	Pattern(Box<Pattern>),
+	Image(Box<ImageFill>),
 }
 
 impl Fill {
@@ -195,20 +497,23 @@ impl Fill {
 	}
 
 	/// Construct a new [Fill::Solid] or [Fill::None] from an optional [Color].
-	pub fn solid_or_none(color: Option<Color>) -> Self {
+	pub const fn solid_or_none(color: Option<Color>) -> Self {
 		match color {
 			Some(color) => Self::Solid(color),
 			None => Self::None,
 		}
 	}
 
-	/// Evaluate the color at some point on the fill. Doesn't currently work for Gradient.
+	/// Evaluate the color at some point on the fill. Doesn't currently work for Gradient or Image, and gives the fill of the tile for Pattern.
 	pub fn color(&self) -> Color {
 		match self {
 			Self::None => Color::BLACK,
 			Self::Solid(color) => *color,
 			// TODO: Should correctly sample the gradient
 			Self::Gradient(Gradient { positions, .. }) => positions[0].1,
+			Self::Pattern(pattern) => pattern.tile.style.fill().color(),
+			// TODO: Should sample the image
+			Self::Image(_) => Color::BLACK,
 		}
 	}
 
@@ -232,19 +537,41 @@ impl Fill {
 				Self::Gradient(a.lerp(b, time))
 			}
 			(Self::Gradient(a), Self::Gradient(b)) => Self::Gradient(a.lerp(b, time)),
+			(Self::Pattern(a), Self::Pattern(b)) => Self::Pattern(Box::new(a.lerp(b, time))),
+			// Patterns and images can't be blended with anything else, so they switch halfway through
+			(Self::Pattern(_) | Self::Image(_), _) | (_, Self::Pattern(_) | Self::Image(_)) => {
+				if time < 0.5 {
+					self.clone()
+				} else {
+					other.clone()
+				}
+			}
 			_ => Self::None,
 		}
 	}
 
 	/// Renders the fill, adding necessary defs through mutating the first argument.
 	pub fn render(&self, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> String {
+		self.render_paint("fill", svg_defs, multiplied_transform, bounds, transformed_bounds)
+	}
+
+	/// Renders the fill as the value of the paint attribute, either `fill` or `stroke`, adding necessary defs through mutating the `svg_defs` argument.
+	fn render_paint(&self, attribute: &str, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> String {
 		match self {
-			Self::None => r#" fill="none""#.to_string(),
-			Self::Solid(color) => format!(r##" fill="#{}"{}"##, color.rgb_hex(), format_opacity("fill-opacity", color.a())),
+			Self::None => format!(r#" {attribute}="none""#),
+			Self::Solid(color) => format!(r#" {attribute}="{}"{}"#, format_color(*color, false), format_opacity(&format!("{attribute}-opacity"), color.a())),
 			Self::Gradient(gradient) => {
 				let gradient_id = gradient.render_defs(svg_defs, multiplied_transform, bounds, transformed_bounds);
-				format!(r##" fill="url('#{gradient_id}')""##)
+				format!(r##" {attribute}="url('#{gradient_id}')""##)
+			}
+			Self::Pattern(pattern) => {
+				let pattern_id = pattern.render_defs(svg_defs, multiplied_transform);
+				format!(r##" {attribute}="url('#{pattern_id}')""##)
 			}
+			Self::Image(image_fill) => match image_fill.render_defs(svg_defs, multiplied_transform, bounds) {
+				Some(pattern_id) => format!(r##" {attribute}="url('#{pattern_id}')""##),
+				None => format!(r#" {attribute}="none""#),
+			},
 		}
 	}
 
@@ -263,6 +590,204 @@ impl Fill {
 	}
 }
 
+/// A tile of vector artwork repeated in a grid across a [Fill].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
+pub struct Pattern {
+	/// The artwork of a single tile, drawn within the cell with its top left corner at the origin.
+	#[specta(skip)]
+	pub tile: VectorData,
+	/// The size of the cell that the tile is repeated in, which leaves gaps between tiles smaller than it.
+	pub spacing: DVec2,
+	/// Rotates and scales the grid of tiles within the space of the filled shape.
+	pub transform: DAffine2,
+}
+
+impl core::hash::Hash for Pattern {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.tile.hash(state);
+		[].iter()
+			.chain(self.spacing.to_array().iter())
+			.chain(self.transform.to_cols_array().iter())
+			.for_each(|x| x.to_bits().hash(state));
+	}
+}
+
+impl Pattern {
+	pub fn new(tile: VectorData, spacing: DVec2, transform: DAffine2) -> Self {
+		Self { tile, spacing, transform }
+	}
+
+	pub fn lerp(&self, other: &Self, time: f64) -> Self {
+		let tile = if time < 0.5 { self.tile.clone() } else { other.tile.clone() };
+		let spacing = self.spacing + (other.spacing - self.spacing) * time;
+		let transform = DAffine2::from_cols_array(&core::array::from_fn(|index| {
+			let (a, b) = (self.transform.to_cols_array()[index], other.transform.to_cols_array()[index]);
+			a + (b - a) * time
+		}));
+		Self { tile, spacing, transform }
+	}
+
+	/// The transforms, relative to the filled shape, placing a copy of the tile in each cell of the grid that overlaps the bounds of the shape.
+	/// Empty if the spacing isn't positive or more than [`MAX_PATTERN_TILES`] would be needed, for renderers that have to repeat the tile themselves.
+	pub fn tile_transforms(&self, bounds: [DVec2; 2]) -> Vec<DAffine2> {
+		if !(self.spacing.x > 0. && self.spacing.y > 0.) {
+			return Vec::new();
+		}
+		grid_cells(self.transform, self.spacing, bounds)
+	}
+
+	/// Adds an SVG `<pattern>` with the tile to the defs, returning its id.
+	fn render_defs(&self, svg_defs: &mut String, multiplied_transform: DAffine2) -> u64 {
+		use crate::renderer::{GraphicElementRendered, ImageRenderMode, RenderParams, RenderSvgSegmentList, SvgRender};
+
+		let mut render = SvgRender::new();
+		self.tile
+			.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, false));
+		// The defs used by the tile, like gradients, can't be nested inside the pattern
+		svg_defs.push_str(&render.svg_defs);
+
+		let transform = (multiplied_transform * self.transform)
+			.to_cols_array()
+			.iter()
+			.enumerate()
+			.map(|(i, entry)| entry.to_string() + if i == 5 { "" } else { "," })
+			.collect::<String>();
+		let pattern_id = crate::uuid::generate_uuid();
+		let _ = write!(
+			svg_defs,
+			r#"<pattern id="{}" patternUnits="userSpaceOnUse" width="{}" height="{}" patternTransform="matrix({})">{}</pattern>"#,
+			pattern_id,
+			self.spacing.x,
+			self.spacing.y,
+			transform,
+			render.svg.to_svg_string()
+		);
+		pattern_id
+	}
+}
+
+/// The transforms of the cells of a grid, each translated by a multiple of the spacing before the grid's transform, that overlap the bounds.
+/// Empty if the transform is singular or more than [`MAX_PATTERN_TILES`] cells would be needed.
+fn grid_cells(transform: DAffine2, spacing: DVec2, bounds: [DVec2; 2]) -> Vec<DAffine2> {
+	if transform.matrix2.determinant() == 0. {
+		return Vec::new();
+	}
+
+	let inverse = transform.inverse();
+	let corners = [bounds[0], DVec2::new(bounds[1].x, bounds[0].y), bounds[1], DVec2::new(bounds[0].x, bounds[1].y)].map(|corner| inverse.transform_point2(corner) / spacing);
+	let min = corners.iter().fold(DVec2::INFINITY, |min, &corner| min.min(corner)).floor();
+	let max = corners.iter().fold(DVec2::NEG_INFINITY, |max, &corner| max.max(corner)).ceil();
+	let cells = (max - min).max(DVec2::ZERO);
+	if cells.x * cells.y > MAX_PATTERN_TILES as f64 {
+		return Vec::new();
+	}
+
+	let (columns, rows) = (cells.x as i64, cells.y as i64);
+	(0..rows)
+		.flat_map(|row| (0..columns).map(move |column| min + DVec2::new(column as f64, row as f64)))
+		.map(|cell| transform * DAffine2::from_translation(cell * spacing))
+		.collect()
+}
+
+/// How the image of an [ImageFill] is sized to the bounding box of the filled shape.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum ImageFillMode {
+	/// Scales the image to fit entirely inside the bounding box, keeping its aspect ratio and centering it
+	Fit,
+	/// Scales the image to cover the whole bounding box, keeping its aspect ratio, centering it, and cropping the rest
+	#[default]
+	Fill,
+	/// Repeats the image at its own size in pixels, starting from the top left corner of the bounding box
+	Tile,
+}
+
+/// A raster image painted across a [Fill].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
+pub struct ImageFill {
+	/// The image, whose own transform is ignored in favor of the mode.
+	pub image: ImageFrame<Color>,
+	pub mode: ImageFillMode,
+	/// Moves, rotates, and scales the image within the space of the filled shape after it's sized by the mode.
+	pub transform: DAffine2,
+}
+
+impl core::hash::Hash for ImageFill {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.image.hash(state);
+		self.mode.hash(state);
+		self.transform.to_cols_array().iter().for_each(|x| x.to_bits().hash(state));
+	}
+}
+
+impl ImageFill {
+	pub fn new(image: ImageFrame<Color>, mode: ImageFillMode, transform: DAffine2) -> Self {
+		Self { image, mode, transform }
+	}
+
+	/// The transform from the unit square of the image to the space of the filled shape, for the first copy of it when tiled.
+	/// None if the image is empty.
+	pub fn placement(&self, bounds: [DVec2; 2]) -> Option<DAffine2> {
+		let size = DVec2::new(self.image.image.width as f64, self.image.image.height as f64);
+		if self.image.image.data.is_empty() || size.x == 0. || size.y == 0. {
+			return None;
+		}
+
+		let box_size = bounds[1] - bounds[0];
+		let (scaled, offset) = match self.mode {
+			ImageFillMode::Fit => (size * (box_size / size).min_element(), box_size / 2.),
+			ImageFillMode::Fill => (size * (box_size / size).max_element(), box_size / 2.),
+			ImageFillMode::Tile => (size, size / 2.),
+		};
+		Some(self.transform * DAffine2::from_scale_angle_translation(scaled, 0., bounds[0] + offset - scaled / 2.))
+	}
+
+	/// The transforms from the unit square of the image to the space of the filled shape for each copy of it that overlaps the bounds.
+	/// Empty if the image is empty, or if more than [`MAX_PATTERN_TILES`] copies would be needed, for renderers that have to repeat the image themselves.
+	pub fn image_transforms(&self, bounds: [DVec2; 2]) -> Vec<DAffine2> {
+		let Some(placement) = self.placement(bounds) else { return Vec::new() };
+		match self.mode {
+			ImageFillMode::Fit | ImageFillMode::Fill => vec![placement],
+			ImageFillMode::Tile => grid_cells(placement, DVec2::ONE, bounds),
+		}
+	}
+
+	/// Adds an SVG `<pattern>` with the image to the defs, returning its id, or None if there is nothing to draw.
+	fn render_defs(&self, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2]) -> Option<u64> {
+		let placement = self.placement(bounds).filter(|placement| placement.matrix2.determinant() != 0.)?;
+
+		// The pattern is repeated beyond a single image only when tiling, otherwise its cell spans the whole shape so the image appears once
+		let [min, max] = match self.mode {
+			ImageFillMode::Tile => [DVec2::ZERO, DVec2::ONE],
+			ImageFillMode::Fit | ImageFillMode::Fill => {
+				let inverse = placement.inverse();
+				let corners = [bounds[0], DVec2::new(bounds[1].x, bounds[0].y), bounds[1], DVec2::new(bounds[0].x, bounds[1].y)].map(|corner| inverse.transform_point2(corner));
+				corners.iter().fold([DVec2::ZERO, DVec2::ONE], |[min, max], &corner| [min.min(corner), max.max(corner)])
+			}
+		};
+
+		let transform = (multiplied_transform * placement)
+			.to_cols_array()
+			.iter()
+			.enumerate()
+			.map(|(i, entry)| entry.to_string() + if i == 5 { "" } else { "," })
+			.collect::<String>();
+		let pattern_id = crate::uuid::generate_uuid();
+		let _ = write!(
+			svg_defs,
+			r#"<pattern id="{}" patternUnits="userSpaceOnUse" x="{}" y="{}" width="{}" height="{}" patternTransform="matrix({})"><image width="1" height="1" preserveAspectRatio="none" href="{}" /></pattern>"#,
+			pattern_id,
+			min.x,
+			min.y,
+			max.x - min.x,
+			max.y - min.y,
+			transform,
+			crate::renderer::image_data_url(&self.image.image)
+		);
+		Some(pattern_id)
+	}
+}
+
 /// Enum describing the type of [Fill]
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize, DynAny, Hash, specta::Type)]
@@ -271,6 +796,26 @@ pub enum FillType {
 	Gradient,
 }
 
+/// The rule deciding which parts of a shape are inside, and so filled, where its subpaths overlap or are nested inside one another.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum FillRule {
+	/// Areas are filled unless the subpaths around them wind in opposite directions and cancel out
+	#[default]
+	NonZero,
+	/// Areas are filled when they're inside an odd number of subpaths, so each subpath nested inside another cuts a hole
+	EvenOdd,
+}
+
+impl Display for FillRule {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			FillRule::NonZero => write!(f, "nonzero"),
+			FillRule::EvenOdd => write!(f, "evenodd"),
+		}
+	}
+}
+
 /// The stroke (outline) style of an SVG element.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
@@ -308,11 +853,32 @@ impl Display for LineJoin {
 	}
 }
 
+/// Where the stroke is drawn relative to the path. Only closed paths have an inside and outside, so the stroke of a shape with any open subpaths is always centered.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum StrokeAlignment {
+	#[default]
+	Center,
+	Inside,
+	Outside,
+}
+
+impl Display for StrokeAlignment {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			StrokeAlignment::Center => write!(f, "center"),
+			StrokeAlignment::Inside => write!(f, "inside"),
+			StrokeAlignment::Outside => write!(f, "outside"),
+		}
+	}
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
 pub struct Stroke {
-	/// Stroke color
-	pub color: Option<Color>,
+	/// The paint of the stroke, which is either a solid color or a gradient spanning the bounds of the shape in the same way as a fill's.
+	#[serde(alias = "color", deserialize_with = "deserialize_stroke_paint")]
+	pub paint: Fill,
 	/// Line thickness
 	pub weight: f64,
 	pub dash_lengths: Vec<f64>,
@@ -320,11 +886,50 @@ pub struct Stroke {
 	pub line_cap: LineCap,
 	pub line_join: LineJoin,
 	pub line_join_miter_limit: f64,
+	/// Paints the stroke with the colors of the gradient's stops along the length of each subpath, from 0 at its start to 1 at its end, instead of its paint.
+	/// Only the stops of the gradient are used.
+	#[serde(default)]
+	pub gradient: Option<Gradient>,
+	/// Draws the whole weight of the stroke inside or outside of a closed path, instead of centered on it.
+	#[serde(default)]
+	pub alignment: StrokeAlignment,
+	/// Scales the weight along the length of each subpath by the factors at positions from 0 at its start to 1 at its end, in order and interpolated in between.
+	/// Unless the profile is empty, the stroke is drawn as the outline from [`Stroke::tapered_outline`] filled with its paint, which is centered on the path and has no dashes.
+	#[serde(default)]
+	pub width_profile: Vec<(f64, f64)>,
+	/// Draws the stroke at its weight in device pixels, whatever the transform or zoom, like the non-scaling strokes of SVG. Suits technical drawings and guides.
+	#[serde(default)]
+	pub hairline: bool,
+	/// The cap on both ends of every dash, in place of the line cap while the stroke is dashed.
+	#[serde(default)]
+	pub dash_cap: Option<LineCap>,
+	/// Treats the dash lengths and offset as multiples of the weight, so the pattern keeps its proportions as the stroke gets thicker.
+	#[serde(default)]
+	pub dashes_scale_with_weight: bool,
+	/// Stretches or squeezes the dash pattern along each stretch of the path between corners, so every corner lands in the middle of a dash and open paths begin and end with whole dashes.
+	/// Renderers draw such a stroke as the separate dashes from [`Stroke::corner_aligned_dashes`].
+	#[serde(default)]
+	pub dashes_align_to_corners: bool,
+}
+
+/// Reads the paint of a stroke, which was just an optional color before strokes could be painted with gradients.
+fn deserialize_stroke_paint<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Fill, D::Error> {
+	#[derive(serde::Deserialize)]
+	#[serde(untagged)]
+	enum StrokePaint {
+		Paint(Fill),
+		Color(Option<Color>),
+	}
+
+	Ok(match <StrokePaint as serde::Deserialize>::deserialize(deserializer)? {
+		StrokePaint::Paint(paint) => paint,
+		StrokePaint::Color(color) => Fill::solid_or_none(color),
+	})
 }
 
 impl core::hash::Hash for Stroke {
 	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-		self.color.hash(state);
+		self.paint.hash(state);
 		self.weight.to_bits().hash(state);
 		self.dash_lengths.len().hash(state);
 		self.dash_lengths.iter().for_each(|length| length.to_bits().hash(state));
@@ -332,37 +937,85 @@ impl core::hash::Hash for Stroke {
 		self.line_cap.hash(state);
 		self.line_join.hash(state);
 		self.line_join_miter_limit.to_bits().hash(state);
+		self.gradient.hash(state);
+		self.alignment.hash(state);
+		self.width_profile.len().hash(state);
+		self.width_profile.iter().for_each(|(position, width)| {
+			position.to_bits().hash(state);
+			width.to_bits().hash(state);
+		});
+		self.hairline.hash(state);
+		self.dash_cap.hash(state);
+		self.dashes_scale_with_weight.hash(state);
+		self.dashes_align_to_corners.hash(state);
 	}
 }
 
 impl Stroke {
 	pub const fn new(color: Option<Color>, weight: f64) -> Self {
 		Self {
-			color,
+			paint: Fill::solid_or_none(color),
 			weight,
 			dash_lengths: Vec::new(),
 			dash_offset: 0.,
 			line_cap: LineCap::Butt,
 			line_join: LineJoin::Miter,
 			line_join_miter_limit: 4.,
+			gradient: None,
+			alignment: StrokeAlignment::Center,
+			width_profile: Vec::new(),
+			hairline: false,
+			dash_cap: None,
+			dashes_scale_with_weight: false,
+			dashes_align_to_corners: false,
 		}
 	}
 
 	pub fn lerp(&self, other: &Self, time: f64) -> Self {
 		Self {
-			color: self.color.map(|color| color.lerp(&other.color.unwrap_or(color), time as f32)),
+			paint: self.paint.lerp(&other.paint, time),
 			weight: self.weight + (other.weight - self.weight) * time,
 			dash_lengths: self.dash_lengths.iter().zip(other.dash_lengths.iter()).map(|(a, b)| a + (b - a) * time).collect(),
 			dash_offset: self.dash_offset + (other.dash_offset - self.dash_offset) * time,
 			line_cap: if time < 0.5 { self.line_cap } else { other.line_cap },
 			line_join: if time < 0.5 { self.line_join } else { other.line_join },
 			line_join_miter_limit: self.line_join_miter_limit + (other.line_join_miter_limit - self.line_join_miter_limit) * time,
+			gradient: match (&self.gradient, &other.gradient) {
+				(Some(gradient), Some(other)) => Some(gradient.lerp(other, time)),
+				(gradient, other) => {
+					if time < 0.5 {
+						gradient.clone()
+					} else {
+						other.clone()
+					}
+				}
+			},
+			alignment: if time < 0.5 { self.alignment } else { other.alignment },
+			width_profile: if self.width_profile.len() == other.width_profile.len() {
+				self.width_profile
+					.iter()
+					.zip(&other.width_profile)
+					.map(|(&(a_position, a_width), &(b_position, b_width))| (a_position + (b_position - a_position) * time, a_width + (b_width - a_width) * time))
+					.collect()
+			} else if time < 0.5 {
+				self.width_profile.clone()
+			} else {
+				other.width_profile.clone()
+			},
+			hairline: if time < 0.5 { self.hairline } else { other.hairline },
+			dash_cap: if time < 0.5 { self.dash_cap } else { other.dash_cap },
+			dashes_scale_with_weight: if time < 0.5 { self.dashes_scale_with_weight } else { other.dashes_scale_with_weight },
+			dashes_align_to_corners: if time < 0.5 { self.dashes_align_to_corners } else { other.dashes_align_to_corners },
 		}
 	}
 
-	/// Get the current stroke color.
+	/// Get the current stroke color, which is the first color of a gradient, for places that can only draw the stroke in a solid color.
 	pub fn color(&self) -> Option<Color> {
-		self.color
+		match &self.paint {
+			Fill::None => None,
+			Fill::Gradient(gradient) => gradient.positions.first().map(|&(_, color)| color),
+			paint => Some(paint.color()),
+		}
 	}
 
 	/// Get the current stroke weight.
@@ -382,6 +1035,62 @@ impl Stroke {
 		self.dash_offset
 	}
 
+	/// Whether the stroke is drawn with dashes, which needs at least one dash or gap with a length. Like SVG, a pattern with a negative length is drawn solid.
+	pub fn is_dashed(&self) -> bool {
+		self.dash_lengths.iter().any(|&length| length > 0.) && self.dash_lengths.iter().all(|length| length.is_finite() && *length >= 0.)
+	}
+
+	/// The dash lengths as they're drawn, which are scaled by the weight if the dashes scale with it.
+	pub fn scaled_dash_lengths(&self) -> Vec<f64> {
+		let scale = if self.dashes_scale_with_weight { self.weight } else { 1. };
+		self.dash_lengths.iter().map(|length| length * scale).collect()
+	}
+
+	/// The dash offset as it's drawn, which is scaled by the weight if the dashes scale with it.
+	pub fn scaled_dash_offset(&self) -> f64 {
+		if self.dashes_scale_with_weight {
+			self.dash_offset * self.weight
+		} else {
+			self.dash_offset
+		}
+	}
+
+	/// The dash pattern that every renderer draws, which like SVG repeats an odd number of lengths to make an even number so dashes and gaps alternate. Empty for a solid stroke.
+	pub fn drawn_dash_lengths(&self) -> Vec<f64> {
+		if !self.is_dashed() {
+			return Vec::new();
+		}
+		let mut lengths = self.scaled_dash_lengths();
+		if lengths.len() % 2 == 1 {
+			lengths.extend_from_within(..);
+		}
+		lengths
+	}
+
+	/// The dash offset that every renderer draws, wrapped to within one repeat of the dash pattern since not every format can start the pattern from a negative offset.
+	pub fn drawn_dash_offset(&self) -> f64 {
+		let period = self.drawn_dash_lengths().iter().sum::<f64>();
+		let offset = self.scaled_dash_offset();
+		if period > 0. && offset.is_finite() {
+			offset.rem_euclid(period)
+		} else {
+			0.
+		}
+	}
+
+	/// The miter limit that every renderer draws, which like SVG is never below 1, where a miter would be shorter than the stroke is wide.
+	pub fn drawn_miter_limit(&self) -> f64 {
+		self.line_join_miter_limit.max(1.)
+	}
+
+	/// The cap drawn at the ends of the stroke, which is the dash cap for every dash of a dashed stroke that has one.
+	pub fn drawn_line_cap(&self) -> LineCap {
+		match self.dash_cap {
+			Some(dash_cap) if self.is_dashed() => dash_cap,
+			_ => self.line_cap,
+		}
+	}
+
 	pub fn line_cap_index(&self) -> u32 {
 		self.line_cap as u32
 	}
@@ -394,19 +1103,24 @@ impl Stroke {
 		self.line_join_miter_limit as f32
 	}
 
-	/// Provide the SVG attributes for the stroke.
-	pub fn render(&self) -> String {
-		if let Some(color) = self.color {
+	/// Provide the SVG attributes for the stroke, adding the defs of a gradient paint through mutating the first argument.
+	/// A stroke with a gradient along its path has none, since it's drawn separately in the pieces from [`Stroke::gradient_pieces`].
+	pub fn render(&self, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> String {
+		if self.paint.is_some() && self.gradient.is_none() {
 			format!(
-				r##" stroke="#{}"{} stroke-width="{}" stroke-dasharray="{}" stroke-dashoffset="{}" stroke-linecap="{}" stroke-linejoin="{}" stroke-miterlimit="{}" "##,
-				color.rgb_hex(),
-				format_opacity("stroke-opacity", color.a()),
+				r##"{} stroke-width="{}" stroke-dasharray="{}" stroke-dashoffset="{}" stroke-linecap="{}" stroke-linejoin="{}" stroke-miterlimit="{}"{} "##,
+				self.paint.render_paint("stroke", svg_defs, multiplied_transform, bounds, transformed_bounds),
 				self.weight,
-				self.dash_lengths(),
-				self.dash_offset,
-				self.line_cap,
+				if self.is_dashed() {
+					self.drawn_dash_lengths().iter().map(f64::to_string).collect::<Vec<_>>().join(", ")
+				} else {
+					"none".to_string()
+				},
+				self.drawn_dash_offset(),
+				self.drawn_line_cap(),
 				self.line_join,
-				self.line_join_miter_limit
+				self.drawn_miter_limit(),
+				if self.hairline { r#" vector-effect="non-scaling-stroke""# } else { "" }
 			)
 		} else {
 			String::new()
@@ -414,11 +1128,16 @@ impl Stroke {
 	}
 
 	pub fn with_color(mut self, color: &Option<Color>) -> Option<Self> {
-		self.color = *color;
+		self.paint = Fill::solid_or_none(*color);
 
 		Some(self)
 	}
 
+	pub fn with_paint(mut self, paint: Fill) -> Self {
+		self.paint = paint;
+		self
+	}
+
 	pub fn with_weight(mut self, weight: f64) -> Self {
 		self.weight = weight;
 		self
@@ -456,6 +1175,277 @@ impl Stroke {
 		self.line_join_miter_limit = limit;
 		self
 	}
+
+	pub fn with_gradient(mut self, gradient: Option<Gradient>) -> Self {
+		self.gradient = gradient;
+		self
+	}
+
+	pub fn with_alignment(mut self, alignment: StrokeAlignment) -> Self {
+		self.alignment = alignment;
+		self
+	}
+
+	pub fn with_width_profile(mut self, width_profile: Vec<(f64, f64)>) -> Self {
+		self.width_profile = width_profile;
+		self
+	}
+
+	pub fn with_hairline(mut self, hairline: bool) -> Self {
+		self.hairline = hairline;
+		self
+	}
+
+	pub fn with_dash_cap(mut self, dash_cap: Option<LineCap>) -> Self {
+		self.dash_cap = dash_cap;
+		self
+	}
+
+	pub fn with_dashes_scale_with_weight(mut self, dashes_scale_with_weight: bool) -> Self {
+		self.dashes_scale_with_weight = dashes_scale_with_weight;
+		self
+	}
+
+	pub fn with_dashes_align_to_corners(mut self, dashes_align_to_corners: bool) -> Self {
+		self.dashes_align_to_corners = dashes_align_to_corners;
+		self
+	}
+
+	/// The weight at the position along the length of a subpath, from 0 at its start to 1 at its end, scaled by the width profile.
+	pub fn weight_at(&self, position: f64) -> f64 {
+		let profile = &self.width_profile;
+		let factor = match profile.iter().position(|&(profile_position, _)| profile_position >= position) {
+			None => profile.last().map_or(1., |&(_, width)| width),
+			Some(0) => profile[0].1,
+			Some(index) => {
+				let ((start, start_width), (end, end_width)) = (profile[index - 1], profile[index]);
+				start_width + (end_width - start_width) * (position - start) / (end - start)
+			}
+		};
+		self.weight * factor.max(0.)
+	}
+
+	/// The outline of the stroke along the transformed subpath with its width varying by the width profile, as closed subpaths to be filled with the stroke's paint by the nonzero rule.
+	/// A closed subpath gives an outer and an inner ring winding in opposite directions, while the ends of an open subpath are shaped by the line cap.
+	pub fn tapered_outline<ManipulatorGroupId: bezier_rs::Identifier>(&self, subpath: &bezier_rs::Subpath<ManipulatorGroupId>, transform: DAffine2) -> Vec<bezier_rs::Subpath<ManipulatorGroupId>> {
+		// Number of points around each round cap
+		const CAP_STEPS: usize = 8;
+
+		let mut points = Vec::<DVec2>::new();
+		for bezier in subpath.iter() {
+			let bezier = bezier.apply_transformation(|point| transform.transform_point2(point));
+			for step in 0..=TAPERED_STROKE_STEPS {
+				let point = bezier.evaluate(bezier_rs::TValue::Parametric(step as f64 / TAPERED_STROKE_STEPS as f64));
+				if !points.last().is_some_and(|last| last.distance(point) <= 1e-9) {
+					points.push(point);
+				}
+			}
+		}
+		let closed = subpath.closed();
+		if closed && points.len() > 2 && points[0].distance(points[points.len() - 1]) <= 1e-9 {
+			points.pop();
+		}
+		if points.len() < 2 {
+			return Vec::new();
+		}
+
+		let mut distances = vec![0.];
+		for pair in points.windows(2) {
+			distances.push(distances[distances.len() - 1] + pair[0].distance(pair[1]));
+		}
+		let length = if closed {
+			distances[distances.len() - 1] + points[points.len() - 1].distance(points[0])
+		} else {
+			distances[distances.len() - 1]
+		};
+
+		let count = points.len();
+		let direction = |from: usize, to: usize| (points[to] - points[from]).normalize_or_zero();
+		let (mut left, mut right) = (Vec::with_capacity(count), Vec::with_capacity(count));
+		let mut normals = Vec::with_capacity(count);
+		for index in 0..count {
+			let incoming = if index > 0 {
+				direction(index - 1, index)
+			} else if closed {
+				direction(count - 1, 0)
+			} else {
+				DVec2::ZERO
+			};
+			let outgoing = if index + 1 < count {
+				direction(index, index + 1)
+			} else if closed {
+				direction(count - 1, 0)
+			} else {
+				DVec2::ZERO
+			};
+			let incoming = if incoming == DVec2::ZERO { outgoing } else { incoming };
+			let outgoing = if outgoing == DVec2::ZERO { incoming } else { outgoing };
+
+			// At a corner, the offset is lengthened to keep the width of the stroke on both sides of it, up to the miter limit
+			let normal = (incoming + outgoing).try_normalize().unwrap_or(outgoing).perp();
+			let miter = 1. / normal.dot(outgoing.perp()).max(1. / self.drawn_miter_limit());
+			let offset = normal * self.weight_at(distances[index] / length) / 2. * miter;
+			left.push(points[index] + offset);
+			right.push(points[index] - offset);
+			normals.push((normal, outgoing));
+		}
+
+		if closed {
+			right.reverse();
+			return [left, right].into_iter().map(|ring| bezier_rs::Subpath::from_anchors(ring, true)).collect();
+		}
+
+		// The cap at each end, going around from the left side of the stroke to the right
+		let cap = |index: usize, forward: DVec2, normal: DVec2| -> Vec<DVec2> {
+			let radius = self.weight_at(if index == 0 { 0. } else { 1. }) / 2.;
+			match self.line_cap {
+				LineCap::Butt => Vec::new(),
+				LineCap::Square => vec![points[index] + (normal + forward) * radius, points[index] + (forward - normal) * radius],
+				LineCap::Round => (1..CAP_STEPS)
+					.map(|step| {
+						let angle = core::f64::consts::PI * step as f64 / CAP_STEPS as f64;
+						points[index] + (normal * angle.cos() + forward * angle.sin()) * radius
+					})
+					.collect(),
+			}
+		};
+		let (end_normal, end_direction) = normals[count - 1];
+		let (start_normal, start_direction) = normals[0];
+
+		let mut outline = left;
+		outline.extend(cap(count - 1, end_direction, end_normal));
+		outline.extend(right.into_iter().rev());
+		outline.extend(cap(0, -start_direction, -start_normal));
+		vec![bezier_rs::Subpath::from_anchors(outline, true)]
+	}
+
+	/// The dashes of the transformed subpath as open subpaths of their own, with the dash pattern stretched or squeezed along each stretch between corners to fit it a whole number of times.
+	/// A dash is centered on every corner and bends around it, and an open subpath begins and ends with whole dashes. Gives the whole subpath if the stroke isn't dashed.
+	pub fn corner_aligned_dashes<ManipulatorGroupId: bezier_rs::Identifier>(
+		&self,
+		subpath: &bezier_rs::Subpath<ManipulatorGroupId>,
+		transform: DAffine2,
+	) -> Vec<bezier_rs::Subpath<ManipulatorGroupId>> {
+		let mut subpath = subpath.clone();
+		subpath.apply_transform(transform);
+
+		let pattern = self.drawn_dash_lengths();
+		let period = pattern.iter().sum::<f64>();
+		let length = subpath.length(None);
+		if period <= 0. || length <= 0. {
+			return vec![subpath];
+		}
+
+		// The distance along the subpath of each anchor between two segments that meet at a corner
+		let direction = |from: DVec2, to: DVec2| (to - from).normalize_or_zero();
+		let is_corner = |incoming: &bezier_rs::Bezier, outgoing: &bezier_rs::Bezier| {
+			let incoming = direction(incoming.evaluate(bezier_rs::TValue::Parametric(0.99)), incoming.end);
+			let outgoing = direction(outgoing.start, outgoing.evaluate(bezier_rs::TValue::Parametric(0.01)));
+			incoming.dot(outgoing) < DASH_CORNER_ANGLE.to_radians().cos()
+		};
+		let beziers = subpath.iter().collect::<Vec<_>>();
+		let mut corners = Vec::new();
+		let mut distance = 0.;
+		for (index, bezier) in beziers.iter().enumerate() {
+			let previous = if index > 0 { beziers.get(index - 1) } else { beziers.last().filter(|_| subpath.closed()) };
+			if previous.is_some_and(|previous| is_corner(previous, bezier)) {
+				corners.push(distance);
+			}
+			distance += bezier.length(None);
+		}
+		// Distances are measured per segment, so they're scaled to match the length of the whole subpath
+		corners.iter_mut().for_each(|corner| *corner *= length / distance);
+
+		// Each stretch is given by its start and end distances and whether each of those is a corner, which takes half a dash, rather than an end of the subpath
+		let stretches = if !subpath.closed() {
+			let bounds = core::iter::once(0.).chain(corners.iter().copied()).chain(core::iter::once(length)).collect::<Vec<_>>();
+			let count = bounds.len() - 1;
+			(0..count).map(|index| (bounds[index], bounds[index + 1], index > 0, index + 1 < count)).collect::<Vec<_>>()
+		} else if corners.is_empty() {
+			vec![(0., length, true, true)]
+		} else {
+			(0..corners.len())
+				.map(|index| {
+					let end = corners.get(index + 1).copied().unwrap_or(corners[0] + length);
+					(corners[index], end, true, true)
+				})
+				.collect()
+		};
+
+		let mut dashes = Vec::<(f64, f64)>::new();
+		for (start, end, start_corner, end_corner) in stretches {
+			let stretch = end - start;
+			let start_part = if start_corner { pattern[0] / 2. } else { pattern[0] };
+			let end_part = if end_corner { pattern[0] / 2. } else { pattern[0] };
+			// The stretch holds its starting part of a dash, then repeats of the pattern, and ends with its part of a dash
+			let unscaled = |repeats: f64| repeats * period - pattern[0] + start_part + end_part;
+			let repeats = ((stretch + pattern[0] - start_part - end_part) / period).round().max(1.);
+			let scale = if unscaled(repeats) > 0. { stretch / unscaled(repeats) } else { 1. };
+
+			let mut position = start;
+			let mut index = 0;
+			while position < end - 1e-9 {
+				let piece = if index == 0 { start_part } else { pattern[index % pattern.len()] } * scale;
+				let piece_end = (position + piece).min(end);
+				if index % pattern.len() % 2 == 0 {
+					match dashes.last_mut() {
+						// A dash that reaches a corner carries on into the next stretch
+						Some(last) if (last.1 - position).abs() < 1e-9 => last.1 = piece_end,
+						_ => dashes.push((position, piece_end)),
+					}
+				}
+				position = piece_end;
+				index += 1;
+			}
+		}
+
+		// On a closed subpath, the last dash joins the first where they meet at its start
+		if subpath.closed() && dashes.len() > 1 && (dashes[dashes.len() - 1].1 - length - dashes[0].0).abs() < 1e-9 {
+			let (start, _) = dashes.pop().unwrap();
+			dashes[0].0 = start - length;
+		}
+
+		// Dashes on a closed subpath can wrap around past its start, which trimming follows when the end comes before the start
+		let closed = subpath.closed();
+		let t = |distance: f64| bezier_rs::SubpathTValue::GlobalEuclidean(if closed { distance.rem_euclid(length) / length } else { (distance / length).clamp(0., 1.) });
+		dashes.into_iter().map(|(start, end)| subpath.trim(t(start), t(end))).collect()
+	}
+
+	/// Splits the transformed subpath into pieces of equal length for drawing the stroke's gradient along the path, each paired with the solid stroke it's drawn with.
+	/// There are as many pieces as fit the length of the path, up to a limit, and they meet end to end without overlapping so translucent colors don't double up.
+	/// The pieces continue the dash pattern from one to the next, and only the first and last keep the line cap. Empty unless the stroke has a gradient.
+	pub fn gradient_pieces<ManipulatorGroupId: bezier_rs::Identifier>(
+		&self,
+		subpath: &bezier_rs::Subpath<ManipulatorGroupId>,
+		transform: DAffine2,
+	) -> Vec<(bezier_rs::Subpath<ManipulatorGroupId>, Stroke)> {
+		let Some(gradient) = &self.gradient else { return Vec::new() };
+		let mut subpath = subpath.clone();
+		subpath.apply_transform(transform);
+		let length = subpath.length(None);
+		if subpath.len_segments() == 0 || length == 0. {
+			return Vec::new();
+		}
+
+		let count = ((length / STROKE_GRADIENT_PIECE_LENGTH).ceil() as usize).clamp(1, MAX_STROKE_GRADIENT_PIECES);
+		(0..count)
+			.map(|index| {
+				let start = index as f64 / count as f64;
+				let end = (index + 1) as f64 / count as f64;
+				let piece = subpath.trim(bezier_rs::SubpathTValue::GlobalEuclidean(start), bezier_rs::SubpathTValue::GlobalEuclidean(end));
+
+				let ends = index == 0 || index + 1 == count;
+				let stroke = Stroke {
+					paint: Fill::Solid(gradient.evaluate((index as f64 + 0.5) / count as f64)),
+					dash_offset: self.dash_offset + start * length,
+					line_cap: if ends { self.line_cap } else { LineCap::Butt },
+					gradient: None,
+					..self.clone()
+				};
+				(piece, stroke)
+			})
+			.collect()
+	}
 }
 
 // Having an alpha of 1 to start with leads to a better experience with the properties panel
@@ -463,38 +1453,137 @@ impl Default for Stroke {
 	fn default() -> Self {
 		Self {
 			weight: 0.,
-			color: Some(Color::from_rgba8_srgb(0, 0, 0, 255)),
+			paint: Fill::Solid(Color::from_rgba8_srgb(0, 0, 0, 255)),
 			dash_lengths: Vec::new(),
 			dash_offset: 0.,
 			line_cap: LineCap::Butt,
 			line_join: LineJoin::Miter,
 			line_join_miter_limit: 4.,
+			gradient: None,
+			alignment: StrokeAlignment::Center,
+			width_profile: Vec::new(),
+			hairline: false,
+			dash_cap: None,
+			dashes_scale_with_weight: false,
+			dashes_align_to_corners: false,
 		}
 	}
 }
 
+/// The paint of a [PaintLayer].
+#[derive(Debug, Clone, PartialEq, Hash, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
+pub enum Paint {
+	Fill(Fill),
+	Stroke(Stroke),
+}
+
+/// A fill or stroke painted over a shape's own, as part of a stack like a fill under an inner and an outer stroke, each with its own opacity and blend mode.
+#[derive(Debug, Clone, PartialEq, Hash, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
+pub struct PaintLayer {
+	pub paint: Paint,
+	pub alpha_blending: AlphaBlending,
+}
+
+impl PaintLayer {
+	pub fn new(paint: Paint, alpha_blending: AlphaBlending) -> Self {
+		Self { paint, alpha_blending }
+	}
+
+	/// The style of a shape painted with just this layer, filled by the fill rule.
+	pub fn style(&self, fill_rule: FillRule) -> PathStyle {
+		let mut style = match &self.paint {
+			Paint::Fill(fill) => PathStyle::new(None, fill.clone()),
+			Paint::Stroke(stroke) => PathStyle::new(Some(stroke.clone()), Fill::None),
+		};
+		style.set_fill_rule(fill_rule);
+		style
+	}
+
+	pub fn lerp(&self, other: &Self, time: f64) -> Self {
+		let paint = match (&self.paint, &other.paint) {
+			(Paint::Fill(a), Paint::Fill(b)) => Paint::Fill(a.lerp(b, time)),
+			(Paint::Stroke(a), Paint::Stroke(b)) => Paint::Stroke(a.lerp(b, time)),
+			_ => {
+				if time < 0.5 {
+					self.paint.clone()
+				} else {
+					other.paint.clone()
+				}
+			}
+		};
+		let alpha_blending = AlphaBlending {
+			opacity: self.alpha_blending.opacity + (other.alpha_blending.opacity - self.alpha_blending.opacity) * time as f32,
+			blend_mode: if time < 0.5 { self.alpha_blending.blend_mode } else { other.alpha_blending.blend_mode },
+		};
+		Self { paint, alpha_blending }
+	}
+}
+
 #[repr(C)]
-#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
 pub struct PathStyle {
 	stroke: Option<Stroke>,
 	fill: Fill,
+	#[serde(default)]
+	fill_rule: FillRule,
+	/// Painted in order over the fill and stroke.
+	#[serde(default)]
+	paint_layers: Vec<PaintLayer>,
+	/// Opacity of the fill alone, from 0 to 1, which is applied on top of the alpha of its colors so they can be swapped out without losing it.
+	#[serde(default = "full_opacity")]
+	fill_opacity: f64,
+	/// Opacity of the stroke alone, from 0 to 1, in the same way as the fill's.
+	#[serde(default = "full_opacity")]
+	stroke_opacity: f64,
+}
+
+/// The fill and stroke opacity of styles saved before they could be set.
+fn full_opacity() -> f64 {
+	1.
+}
+
+impl Default for PathStyle {
+	fn default() -> Self {
+		Self::new(None, Fill::None)
+	}
 }
 
 impl core::hash::Hash for PathStyle {
 	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
 		self.stroke.hash(state);
 		self.fill.hash(state);
+		self.fill_rule.hash(state);
+		self.paint_layers.hash(state);
+		self.fill_opacity.to_bits().hash(state);
+		self.stroke_opacity.to_bits().hash(state);
 	}
 }
 
 impl PathStyle {
 	pub const fn new(stroke: Option<Stroke>, fill: Fill) -> Self {
-		Self { stroke, fill }
+		Self {
+			stroke,
+			fill,
+			fill_rule: FillRule::NonZero,
+			paint_layers: Vec::new(),
+			fill_opacity: 1.,
+			stroke_opacity: 1.,
+		}
 	}
 
 	pub fn lerp(&self, other: &Self, time: f64) -> Self {
 		Self {
 			fill: self.fill.lerp(&other.fill, time),
+			fill_rule: if time < 0.5 { self.fill_rule } else { other.fill_rule },
+			fill_opacity: self.fill_opacity + (other.fill_opacity - self.fill_opacity) * time,
+			stroke_opacity: self.stroke_opacity + (other.stroke_opacity - self.stroke_opacity) * time,
+			paint_layers: if self.paint_layers.len() == other.paint_layers.len() {
+				self.paint_layers.iter().zip(&other.paint_layers).map(|(a, b)| a.lerp(b, time)).collect()
+			} else if time < 0.5 {
+				self.paint_layers.clone()
+			} else {
+				other.paint_layers.clone()
+			},
 			stroke: match (self.stroke.as_ref(), other.stroke.as_ref()) {
 				(Some(a), Some(b)) => Some(a.lerp(b, time)),
 				(Some(a), None) => {
@@ -546,6 +1635,37 @@ impl PathStyle {
 		self.stroke.clone()
 	}
 
+	/// Get the rule deciding which parts of the path are filled.
+	///
+	/// # Example
+	/// ```
+	/// # use graphene_core::vector::style::{FillRule, PathStyle};
+	/// let style = PathStyle::default();
+	///
+	/// assert_eq!(style.fill_rule(), FillRule::NonZero);
+	/// ```
+	pub fn fill_rule(&self) -> FillRule {
+		self.fill_rule
+	}
+
+	/// Get the opacity of the fill alone, from 0 to 1.
+	///
+	/// # Example
+	/// ```
+	/// # use graphene_core::vector::style::PathStyle;
+	/// let style = PathStyle::default();
+	///
+	/// assert_eq!(style.fill_opacity(), 1.);
+	/// ```
+	pub fn fill_opacity(&self) -> f64 {
+		self.fill_opacity
+	}
+
+	/// Get the opacity of the stroke alone, from 0 to 1.
+	pub fn stroke_opacity(&self) -> f64 {
+		self.stroke_opacity
+	}
+
 	/// Replace the path's [Fill] with a provided one.
 	///
 	/// # Example
@@ -565,6 +1685,43 @@ impl PathStyle {
 		self.fill = fill;
 	}
 
+	/// Replace the rule deciding which parts of the path are filled.
+	///
+	/// # Example
+	/// ```
+	/// # use graphene_core::vector::style::{FillRule, PathStyle};
+	/// let mut style = PathStyle::default();
+	///
+	/// style.set_fill_rule(FillRule::EvenOdd);
+	///
+	/// assert_eq!(style.fill_rule(), FillRule::EvenOdd);
+	/// ```
+	pub fn set_fill_rule(&mut self, fill_rule: FillRule) {
+		self.fill_rule = fill_rule;
+	}
+
+	/// Set the opacity of the fill alone, clamped between 0 and 1, without changing the colors it's painted with.
+	///
+	/// # Example
+	/// ```
+	/// # use graphene_core::vector::style::PathStyle;
+	/// let mut style = PathStyle::default();
+	///
+	/// style.set_fill_opacity(0.5);
+	/// style.set_stroke_opacity(2.);
+	///
+	/// assert_eq!(style.fill_opacity(), 0.5);
+	/// assert_eq!(style.stroke_opacity(), 1.);
+	/// ```
+	pub fn set_fill_opacity(&mut self, opacity: f64) {
+		self.fill_opacity = opacity.clamp(0., 1.);
+	}
+
+	/// Set the opacity of the stroke alone, clamped between 0 and 1, without changing the colors it's painted with.
+	pub fn set_stroke_opacity(&mut self, opacity: f64) {
+		self.stroke_opacity = opacity.clamp(0., 1.);
+	}
+
 	/// Replace the path's [Stroke] with a provided one.
 	///
 	/// # Example
@@ -620,23 +1777,156 @@ impl PathStyle {
 		self.stroke = None;
 	}
 
+	/// Get the stack of [PaintLayer]s painted over the path's fill and stroke, from the bottom to the top.
+	pub fn paint_layers(&self) -> &[PaintLayer] {
+		&self.paint_layers
+	}
+
+	/// Insert a [PaintLayer] into the stack at the index, counting from the bottom from 0 or from the top from -1.
+	/// Indices past either end of the stack insert the layer at that end.
+	///
+	/// # Example
+	/// ```
+	/// # use graphene_core::vector::style::{Fill, Paint, PaintLayer, PathStyle};
+	/// # use graphene_core::raster::color::Color;
+	/// # use graphene_core::AlphaBlending;
+	/// let mut style = PathStyle::default();
+	/// let layer = |color| PaintLayer::new(Paint::Fill(Fill::Solid(color)), AlphaBlending::default());
+	///
+	/// style.insert_paint_layer(-1, layer(Color::RED));
+	/// style.insert_paint_layer(-1, layer(Color::GREEN));
+	/// style.insert_paint_layer(0, layer(Color::BLUE));
+	///
+	/// assert_eq!(style.paint_layers(), [layer(Color::BLUE), layer(Color::RED), layer(Color::GREEN)]);
+	/// ```
+	pub fn insert_paint_layer(&mut self, index: i32, layer: PaintLayer) {
+		let len = self.paint_layers.len() as i64;
+		let index = if index < 0 { len + 1 + index as i64 } else { index as i64 };
+		self.paint_layers.insert(index.clamp(0, len) as usize, layer);
+	}
+
+	/// Remove the [PaintLayer] at the index, counting from the bottom from 0 or from the top from -1, returning it if there was one.
+	///
+	/// # Example
+	/// ```
+	/// # use graphene_core::vector::style::{Fill, Paint, PaintLayer, PathStyle};
+	/// # use graphene_core::raster::color::Color;
+	/// # use graphene_core::AlphaBlending;
+	/// let mut style = PathStyle::default();
+	/// let layer = PaintLayer::new(Paint::Fill(Fill::Solid(Color::RED)), AlphaBlending::default());
+	/// style.insert_paint_layer(-1, layer.clone());
+	///
+	/// assert_eq!(style.remove_paint_layer(1), None);
+	/// assert_eq!(style.remove_paint_layer(-1), Some(layer));
+	/// assert!(style.paint_layers().is_empty());
+	/// ```
+	pub fn remove_paint_layer(&mut self, index: i32) -> Option<PaintLayer> {
+		let len = self.paint_layers.len() as i64;
+		let index = if index < 0 { len + index as i64 } else { index as i64 };
+		(0..len).contains(&index).then(|| self.paint_layers.remove(index as usize))
+	}
+
+	/// Remove every [PaintLayer], leaving just the path's fill and stroke.
+	pub fn clear_paint_layers(&mut self) {
+		self.paint_layers.clear();
+	}
+
+	/// The style painting everything this one paints in white, the color of unprinted paper, so the shape knocks out what it covers in a print channel it isn't part of.
+	/// Solid colors keep their alpha, while gradients, patterns, and images are knocked out as if opaque.
+	pub fn knockout(&self) -> Self {
+		let white = |paint: &Fill| match paint {
+			Fill::None => Fill::None,
+			Fill::Solid(color) => Fill::Solid(Color::WHITE.with_alpha(color.a())),
+			_ => Fill::Solid(Color::WHITE),
+		};
+		let white_stroke = |stroke: &Stroke| Stroke {
+			paint: if stroke.gradient.is_some() { Fill::Solid(Color::WHITE) } else { white(&stroke.paint) },
+			gradient: None,
+			..stroke.clone()
+		};
+		let paint_layers = self
+			.paint_layers
+			.iter()
+			.map(|layer| PaintLayer {
+				paint: match &layer.paint {
+					Paint::Fill(fill) => Paint::Fill(white(fill)),
+					Paint::Stroke(stroke) => Paint::Stroke(white_stroke(stroke)),
+				},
+				alpha_blending: AlphaBlending {
+					blend_mode: Default::default(),
+					..layer.alpha_blending
+				},
+			})
+			.collect();
+
+		Self {
+			stroke: self.stroke.as_ref().map(white_stroke),
+			fill: white(&self.fill),
+			paint_layers,
+			..*self
+		}
+	}
+
 	/// Renders the shape's fill and stroke attributes as a string with them concatenated together.
 	pub fn render(&self, view_mode: ViewMode, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> String {
 		match view_mode {
 			ViewMode::Outline => {
 				let fill_attribute = Fill::None.render(svg_defs, multiplied_transform, bounds, transformed_bounds);
-				let stroke_attribute = Stroke::new(Some(LAYER_OUTLINE_STROKE_COLOR), LAYER_OUTLINE_STROKE_WEIGHT).render();
+				let stroke_attribute = Stroke::new(Some(LAYER_OUTLINE_STROKE_COLOR), LAYER_OUTLINE_STROKE_WEIGHT).render(svg_defs, multiplied_transform, bounds, transformed_bounds);
 				format!("{fill_attribute}{stroke_attribute}")
 			}
 			_ => {
 				let fill_attribute = self.fill.render(svg_defs, multiplied_transform, bounds, transformed_bounds);
-				let stroke_attribute = self.stroke.as_ref().map(|stroke| stroke.render()).unwrap_or_default();
-				format!("{fill_attribute}{stroke_attribute}")
+				// Nonzero is the default in SVG, so the attribute is only needed for the even-odd rule
+				let fill_rule_attribute = if self.fill.is_some() && self.fill_rule == FillRule::EvenOdd {
+					format!(r#" fill-rule="{}""#, self.fill_rule)
+				} else {
+					String::new()
+				};
+				let stroke_attribute = self
+					.stroke
+					.as_ref()
+					.map(|stroke| stroke.render(svg_defs, multiplied_transform, bounds, transformed_bounds))
+					.unwrap_or_default();
+				format!("{fill_attribute}{fill_rule_attribute}{stroke_attribute}")
 			}
 		}
 	}
 }
 
+/// Styles defined under names, so a single definition can restyle every shape it's applied to, and a whole look can be swapped for another library at once.
+#[derive(Debug, Clone, Default, PartialEq, Hash, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
+pub struct StyleLibrary {
+	pub styles: Vec<(String, PathStyle)>,
+}
+
+impl StyleLibrary {
+	/// The style defined under the name.
+	pub fn get(&self, name: &str) -> Option<&PathStyle> {
+		self.styles.iter().find(|(style_name, _)| style_name == name).map(|(_, style)| style)
+	}
+
+	/// Defines the style under the name, replacing any style already defined with that name.
+	///
+	/// ```
+	/// # use graphene_core::vector::style::{Fill, PathStyle, StyleLibrary};
+	/// # use graphene_core::raster::color::Color;
+	/// let mut library = StyleLibrary::default();
+	/// library.define("Accent".to_string(), PathStyle::new(None, Fill::Solid(Color::RED)));
+	/// library.define("Accent".to_string(), PathStyle::new(None, Fill::Solid(Color::BLUE)));
+	///
+	/// assert_eq!(library.styles.len(), 1);
+	/// assert_eq!(library.get("Accent").map(PathStyle::fill), Some(&Fill::Solid(Color::BLUE)));
+	/// assert_eq!(library.get("Muted"), None);
+	/// ```
+	pub fn define(&mut self, name: String, style: PathStyle) {
+		match self.styles.iter_mut().find(|(style_name, _)| *style_name == name) {
+			Some((_, defined)) => *defined = style,
+			None => self.styles.push((name, style)),
+		}
+	}
+}
+
 /// Represents different ways of rendering an object
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
 pub enum ViewMode {
@@ -0,0 +1,336 @@
+use super::style::{Fill, FillRule, Stroke};
+use super::VectorData;
+use crate::{Color, GraphicElement, GraphicGroup, Node};
+
+use bezier_rs::{Bezier, BezierHandles, Subpath, TValue};
+use glam::{DAffine2, DVec2};
+
+/// The weight of the shape's outline, which the other lines of the drawing are drawn at half of.
+const OUTLINE_WEIGHT: f64 = 2.;
+/// The length and width of the arrowheads at the ends of dimension lines.
+const ARROW_SIZE: DVec2 = DVec2::new(8., 5.);
+/// The approximate width of a character of the labels, for centering them on their dimension lines.
+const CHARACTER_WIDTH: f64 = 8.;
+/// How far the labels sit above their dimension lines.
+const LABEL_GAP: f64 = 4.;
+/// The size of the title block in the bottom right corner of the frame.
+const TITLE_BLOCK_SIZE: DVec2 = DVec2::new(200., 48.);
+/// The most hatch lines drawn across the shape, so a tiny spacing can't stall the evaluation.
+const MAX_HATCH_LINES: f64 = 2000.;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TechnicalDrawingNode<DimensionOffset, HatchSpacing, HatchAngle, Title, LineColor> {
+	dimension_offset: DimensionOffset,
+	hatch_spacing: HatchSpacing,
+	hatch_angle: HatchAngle,
+	title: Title,
+	color: LineColor,
+}
+
+/// Draws the shape in the style of an engineering drawing: outlined, with its closed regions hatched as a section, its salient edges dimensioned, and everything framed with a title block.
+///
+/// The salient edges are the shape's overall width and height, dimensioned above and to the right of it at twice the offset, and each of its straight edges at least
+/// a quarter as long as its larger side, dimensioned on its outer side at the offset. The title block shows the title and the overall size.
+#[node_macro::node_fn(TechnicalDrawingNode)]
+fn technical_drawing(vector_data: VectorData, dimension_offset: f64, hatch_spacing: f64, hatch_angle: f64, title: String, color: Color) -> GraphicGroup {
+	let subpaths = vector_data
+		.stroke_bezier_paths()
+		.map(|mut subpath| {
+			subpath.apply_transform(vector_data.transform);
+			subpath
+		})
+		.collect::<Vec<_>>();
+	let shape = Shape {
+		regions: subpaths.iter().filter(|subpath| subpath.closed()).cloned().collect(),
+		fill_rule: vector_data.style.fill_rule(),
+	};
+	let Some([min, max]) = subpaths
+		.iter()
+		.filter_map(Subpath::bounding_box)
+		.reduce(|[a_min, a_max], [b_min, b_max]| [a_min.min(b_min), a_max.max(b_max)])
+	else {
+		return GraphicGroup::EMPTY;
+	};
+	let offset = dimension_offset.max(0.);
+	let mut dimensions = Dimensions::new(color);
+
+	// The overall size, further out than the edges so the two don't overlap
+	let size = max - min;
+	dimensions.add(min, DVec2::new(max.x, min.y), -DVec2::Y, offset * 2.);
+	dimensions.add(DVec2::new(max.x, min.y), max, DVec2::X, offset * 2.);
+
+	// The long straight edges, except those already covered by the overall size
+	let minimum_length = size.max_element() / 4.;
+	let mut dimensioned = Vec::<[DVec2; 2]>::new();
+	for subpath in &subpaths {
+		for segment in subpath.iter().filter(|segment| matches!(segment.handles, BezierHandles::Linear)) {
+			let (start, end) = (segment.start, segment.end);
+			let length = start.distance(end);
+			let spans_width = (start.y - end.y).abs() < 1e-6 && (length - size.x).abs() < 1e-6;
+			let spans_height = (start.x - end.x).abs() < 1e-6 && (length - size.y).abs() < 1e-6;
+			let repeated = dimensioned
+				.iter()
+				.any(|&[a, b]| (a.abs_diff_eq(start, 1e-6) && b.abs_diff_eq(end, 1e-6)) || (a.abs_diff_eq(end, 1e-6) && b.abs_diff_eq(start, 1e-6)));
+			if length == 0. || length < minimum_length || spans_width || spans_height || repeated {
+				continue;
+			}
+			dimensioned.push([start, end]);
+
+			// Dimension closed shapes from outside, and open paths from their left
+			let direction = (end - start) / length;
+			let midpoint = (start + end) / 2.;
+			let normal = if subpath.closed() && shape.contains(midpoint - direction.perp()) {
+				direction.perp()
+			} else {
+				-direction.perp()
+			};
+			dimensions.add(start, end, normal, offset);
+		}
+	}
+
+	let mut hatching = VectorData::empty();
+	for [start, end] in shape.hatch_lines(hatch_spacing, hatch_angle.to_radians(), [min, max]) {
+		hatching.append_subpath(Subpath::new_line(start, end));
+	}
+	hatching.style.set_stroke(Stroke::new(Some(color), OUTLINE_WEIGHT / 2.));
+
+	let mut outline = VectorData::empty();
+	for subpath in subpaths {
+		outline.append_subpath(subpath);
+	}
+	outline.style.set_stroke(Stroke::new(Some(color), OUTLINE_WEIGHT));
+
+	// The frame leaves room for the labels of the overall size and places the title block below the drawing
+	let margin = DVec2::splat(offset * 3. + LABEL_GAP * 4.);
+	let frame_min = min - margin;
+	let frame_max = (max + margin + DVec2::new(0., TITLE_BLOCK_SIZE.y)).max(frame_min + TITLE_BLOCK_SIZE);
+	let title_block = title_block(&title, size, [frame_min, frame_max], color);
+
+	let mut drawing = GraphicGroup::EMPTY;
+	drawing.extend([hatching.into(), outline.into(), dimensions.into_group().into(), title_block.into()]);
+	drawing
+}
+
+/// The closed regions of the shape, which are hatched and tell which side of its edges is outside.
+struct Shape {
+	regions: Vec<Subpath<super::PointId>>,
+	fill_rule: FillRule,
+}
+
+impl Shape {
+	fn contains(&self, point: DVec2) -> bool {
+		let winding = self.regions.iter().map(|region| region.winding_order(point)).sum::<i32>();
+		match self.fill_rule {
+			FillRule::NonZero => winding != 0,
+			FillRule::EvenOdd => winding % 2 != 0,
+		}
+	}
+
+	/// Parallel lines at the angle, a spacing apart, across the parts of the bounds that are inside the shape.
+	fn hatch_lines(&self, spacing: f64, angle: f64, [min, max]: [DVec2; 2]) -> Vec<[DVec2; 2]> {
+		let corners = [min, DVec2::new(max.x, min.y), max, DVec2::new(min.x, max.y)];
+		let direction = DVec2::from_angle(angle);
+		let across = direction.perp();
+		let (across_min, across_max) = corners
+			.iter()
+			.map(|corner| corner.dot(across))
+			.fold((f64::MAX, f64::MIN), |(low, high), value| (low.min(value), high.max(value)));
+		let (along_min, along_max) = corners
+			.iter()
+			.map(|corner| corner.dot(direction))
+			.fold((f64::MAX, f64::MIN), |(low, high), value| (low.min(value), high.max(value)));
+		if self.regions.is_empty() || !spacing.is_finite() || spacing <= 0. || (across_max - across_min) / spacing > MAX_HATCH_LINES {
+			return Vec::new();
+		}
+
+		let mut lines = Vec::new();
+		let mut distance = (across_min / spacing).ceil() * spacing;
+		while distance <= across_max {
+			let start = across * distance + direction * (along_min - 1.);
+			let end = across * distance + direction * (along_max + 1.);
+			let line = Bezier::from_linear_dvec2(start, end);
+
+			// Where the line crosses the edges of the regions, in order along it
+			let mut crossings = self
+				.regions
+				.iter()
+				.flat_map(|region| region.iter())
+				.flat_map(|segment| {
+					segment
+						.intersections(&line, None, None)
+						.into_iter()
+						.map(move |t| segment.evaluate(TValue::Parametric(t)).dot(direction))
+				})
+				.collect::<Vec<_>>();
+			crossings.sort_by(f64::total_cmp);
+			crossings.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+
+			for pair in crossings.windows(2) {
+				let point = |along: f64| across * distance + direction * along;
+				if self.contains(point((pair[0] + pair[1]) / 2.)) {
+					lines.push([point(pair[0]), point(pair[1])]);
+				}
+			}
+			distance += spacing;
+		}
+		lines
+	}
+}
+
+/// The lines, arrowheads, and labels of the dimensions of a drawing.
+struct Dimensions {
+	lines: VectorData,
+	arrows: VectorData,
+	labels: Vec<GraphicElement>,
+}
+
+impl Dimensions {
+	fn new(color: Color) -> Self {
+		let mut lines = VectorData::empty();
+		lines.style.set_stroke(Stroke::new(Some(color), OUTLINE_WEIGHT / 2.));
+		let mut arrows = VectorData::empty();
+		arrows.style.set_fill(Fill::Solid(color));
+		Self { lines, arrows, labels: Vec::new() }
+	}
+
+	/// Dimensions the distance from the start to the end with a line the offset away in the direction of the normal, labeled with the distance.
+	fn add(&mut self, start: DVec2, end: DVec2, normal: DVec2, offset: f64) {
+		let length = start.distance(end);
+		if length == 0. {
+			return;
+		}
+		let direction = (end - start) / length;
+		let [line_start, line_end] = [start, end].map(|point| point + normal * offset);
+
+		// The extension lines reach from just off the edge to just past the dimension line
+		let gap = offset.min(LABEL_GAP);
+		for (point, line_point) in [(start, line_start), (end, line_end)] {
+			self.lines.append_subpath(Subpath::new_line(point + normal * gap, line_point + normal * LABEL_GAP));
+		}
+		self.lines.append_subpath(Subpath::new_line(line_start, line_end));
+		for (tip, pointing) in [(line_start, -direction), (line_end, direction)] {
+			let base = tip - pointing * ARROW_SIZE.x;
+			let side = pointing.perp() * ARROW_SIZE.y / 2.;
+			self.arrows.append_subpath(Subpath::from_anchors([tip, base + side, base - side], true));
+		}
+
+		// The label reads along the line, kept upright, centered above its middle
+		let label = format_length(length);
+		let reading = if direction.x < -1e-9 || (direction.x.abs() <= 1e-9 && direction.y > 0.) {
+			-direction
+		} else {
+			direction
+		};
+		let above = -reading.perp();
+		let center = (line_start + line_end) / 2. + above * LABEL_GAP;
+		let transform =
+			DAffine2::from_translation(center) * DAffine2::from_angle(reading.y.atan2(reading.x)) * DAffine2::from_translation(DVec2::new(-(label.len() as f64) * CHARACTER_WIDTH / 2., 0.));
+		self.labels.push(text_at(label, transform));
+	}
+
+	fn into_group(self) -> GraphicGroup {
+		let mut group = GraphicGroup::EMPTY;
+		group.extend([self.lines.into(), self.arrows.into()]);
+		group.extend(self.labels);
+		group
+	}
+}
+
+/// A border around the bounds with a title block in its bottom right corner, listing the title above the size of the drawing.
+fn title_block(title: &str, size: DVec2, [min, max]: [DVec2; 2], color: Color) -> GraphicGroup {
+	let block_min = max - TITLE_BLOCK_SIZE;
+	let divider = block_min.y + TITLE_BLOCK_SIZE.y / 2.;
+
+	let mut lines = VectorData::from_subpaths([
+		Subpath::new_rect(min, max),
+		Subpath::new_rect(block_min, max),
+		Subpath::new_line(DVec2::new(block_min.x, divider), DVec2::new(max.x, divider)),
+	]);
+	lines.style.set_stroke(Stroke::new(Some(color), OUTLINE_WEIGHT));
+
+	let padding = DVec2::new(LABEL_GAP * 2., TITLE_BLOCK_SIZE.y / 2. - LABEL_GAP * 2.);
+	let mut group = GraphicGroup::EMPTY;
+	group.extend([
+		lines.into(),
+		text_at(title.to_string(), DAffine2::from_translation(block_min + padding)),
+		text_at(
+			format!("Size: {} × {}", format_length(size.x), format_length(size.y)),
+			DAffine2::from_translation(DVec2::new(block_min.x, divider) + padding),
+		),
+	]);
+	group
+}
+
+/// Text with its baseline starting at the origin of the transform.
+fn text_at(text: String, transform: DAffine2) -> GraphicElement {
+	let mut group = GraphicGroup::EMPTY;
+	group.transform = transform;
+	group.push(GraphicElement::Text(text));
+	group.into()
+}
+
+/// The length to two decimal places, without trailing zeros.
+fn format_length(length: f64) -> String {
+	let formatted = format!("{length:.2}");
+	formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+
+	fn texts(group: &GraphicGroup) -> Vec<String> {
+		group
+			.iter()
+			.flat_map(|element| match element {
+				GraphicElement::Text(text) => vec![text.clone()],
+				GraphicElement::GraphicGroup(group) => texts(group),
+				_ => Vec::new(),
+			})
+			.collect()
+	}
+
+	#[test]
+	fn technical_drawing_of_an_l_shape() {
+		// An L shape, 100 wide and 60 tall, whose inner corner edges are too short to dimension
+		let shape = VectorData::from_subpath(Subpath::from_anchors(
+			[
+				DVec2::ZERO,
+				DVec2::new(100., 0.),
+				DVec2::new(100., 20.),
+				DVec2::new(30., 20.),
+				DVec2::new(30., 60.),
+				DVec2::new(0., 60.),
+			],
+			true,
+		));
+		let drawing = TechnicalDrawingNode {
+			dimension_offset: ClonedNode(20.),
+			hatch_spacing: ClonedNode(5.),
+			hatch_angle: ClonedNode(45.),
+			title: ClonedNode("Bracket".to_string()),
+			color: ClonedNode(Color::BLACK),
+		}
+		.eval(shape);
+
+		let [GraphicElement::VectorData(hatching), GraphicElement::VectorData(outline), GraphicElement::GraphicGroup(_), GraphicElement::GraphicGroup(_)] = drawing.as_slice() else {
+			panic!("The drawing should have hatching, an outline, dimensions, and a title block, got {drawing:?}");
+		};
+		assert_eq!(outline.stroke_bezier_paths().count(), 1);
+
+		// The hatching only covers the inside of the shape
+		assert!(hatching.stroke_bezier_paths().count() > 10);
+		for line in hatching.stroke_bezier_paths() {
+			let midpoint = line.evaluate(bezier_rs::SubpathTValue::GlobalParametric(0.5));
+			assert!(!(midpoint.x > 30. + 1e-6 && midpoint.y > 20. + 1e-6), "The hatch line through {midpoint} is outside of the shape");
+		}
+
+		// The overall size and the long edges are labeled, with the title in the title block
+		let labels = texts(&drawing);
+		for label in ["100", "60", "70", "40", "Bracket", "Size: 100 × 60"] {
+			assert!(labels.iter().any(|text| text == label), "There should be a {label} label in {labels:?}");
+		}
+		assert!(!labels.iter().any(|text| text == "20"), "The short edges shouldn't be dimensioned in {labels:?}");
+	}
+}
@@ -25,6 +25,9 @@ pub struct VectorData {
 	pub point_domain: PointDomain,
 	pub segment_domain: SegmentDomain,
 	pub region_domain: RegionDomain,
+	/// Styles that replace `style` for some of the subpaths, given by their index in the order of [`VectorData::stroke_bezier_paths`], sorted by that index.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub subpath_styles: Vec<(usize, PathStyle)>,
 }
 
 impl core::hash::Hash for VectorData {
@@ -36,6 +39,7 @@ impl core::hash::Hash for VectorData {
 		self.style.hash(state);
 		self.alpha_blending.hash(state);
 		self.colinear_manipulators.hash(state);
+		self.subpath_styles.hash(state);
 	}
 }
 
@@ -50,6 +54,7 @@ impl VectorData {
 			point_domain: PointDomain::new(),
 			segment_domain: SegmentDomain::new(),
 			region_domain: RegionDomain::new(),
+			subpath_styles: Vec::new(),
 		}
 	}
 
@@ -102,6 +107,18 @@ impl VectorData {
 		vector_data
 	}
 
+	/// A magenta box crossed from corner to corner, which is drawn in place of the output of a node that failed so the rest of the document is still shown.
+	pub fn failed_node_placeholder(bounds: [DVec2; 2]) -> Self {
+		let [min, max] = bounds;
+		let mut vector_data = Self::from_subpaths([
+			bezier_rs::Subpath::new_rect(min, max),
+			bezier_rs::Subpath::new_line(min, max),
+			bezier_rs::Subpath::new_line(DVec2::new(max.x, min.y), DVec2::new(min.x, max.y)),
+		]);
+		vector_data.style.set_stroke(Stroke::new(Some(Color::from_rgbf32_unchecked(1., 0., 1.)), 2.).with_hairline(true));
+		vector_data
+	}
+
 	/// Compute the bounding boxes of the subpaths without any transform
 	pub fn bounding_box(&self) -> Option<[DVec2; 2]> {
 		self.bounding_box_with_transform(DAffine2::IDENTITY)
@@ -142,6 +159,88 @@ impl VectorData {
 	pub fn local_pivot(&self, normalized_pivot: DVec2) -> DVec2 {
 		self.transform.transform_point2(self.layerspace_pivot(normalized_pivot))
 	}
+
+	/// The style the subpath with the index is drawn with, which is its own if it has one, otherwise the style of the whole shape.
+	pub fn subpath_style(&self, index: usize) -> &PathStyle {
+		match self.subpath_styles.binary_search_by_key(&index, |&(subpath, _)| subpath) {
+			Ok(found) => &self.subpath_styles[found].1,
+			Err(_) => &self.style,
+		}
+	}
+
+	/// Gives the subpath with the index its own style in place of the style of the whole shape.
+	pub fn set_subpath_style(&mut self, index: usize, style: PathStyle) {
+		match self.subpath_styles.binary_search_by_key(&index, |&(subpath, _)| subpath) {
+			Ok(found) => self.subpath_styles[found].1 = style,
+			Err(insert) => self.subpath_styles.insert(insert, (index, style)),
+		}
+	}
+
+	/// Whether the point was drawn or edited by hand rather than generated, as recorded by its [`MANUAL_ATTRIBUTE`]. A subpath counts as manual if all of its points are.
+	pub fn is_manual(&self, point: PointId) -> bool {
+		let Some(index) = self.point_domain.ids().iter().position(|&id| id == point) else {
+			return false;
+		};
+		self.point_domain.attribute(MANUAL_ATTRIBUTE).and_then(|values| values.scalar(index)).is_some_and(|value| value > 0.5)
+	}
+
+	/// Marks every point as drawn by hand, or as generated.
+	pub fn set_manual(&mut self, manual: bool) {
+		let value = if manual { 1. } else { 0. };
+		self.point_domain.set_attribute(MANUAL_ATTRIBUTE, AttributeValues::F64(vec![value; self.point_domain.ids().len()]));
+	}
+
+	/// Whether the handles either side of the point are locked at 180° from one another, as listed in `colinear_manipulators`.
+	pub fn colinear(&self, point: PointId) -> bool {
+		self.colinear_manipulators.iter().any(|&id| PointId::from(id) == point)
+	}
+
+	/// Finds the anchors with a cubic segment arriving at them and another leaving them, and so a handle on either side, giving the indices of the two segments.
+	pub fn handle_pairs(&self) -> Vec<(PointId, usize, usize)> {
+		let segments = &self.segment_domain;
+		let cubic = |index: usize| matches!(segments.handles()[index], bezier_rs::BezierHandles::Cubic { .. });
+
+		// Anchors where more than one segment arrives or leaves have no single pair of handles
+		let mut arriving = std::collections::HashMap::new();
+		let mut leaving = std::collections::HashMap::new();
+		for (index, (&start, &end)) in segments.start_point().iter().zip(segments.end_point()).enumerate() {
+			arriving.entry(end).and_modify(|segment| *segment = None).or_insert(Some(index));
+			leaving.entry(start).and_modify(|segment| *segment = None).or_insert(Some(index));
+		}
+
+		let pairs = self.point_domain.ids().iter().filter_map(|&point| {
+			let (arriving, leaving) = ((*arriving.get(&point)?)?, (*leaving.get(&point)?)?);
+			(arriving != leaving && cubic(arriving) && cubic(leaving)).then_some((point, arriving, leaving))
+		});
+		pairs.collect()
+	}
+
+	/// Turns the handles of each colinear anchor to point in exactly opposite directions, keeping their lengths, such as after a procedural change has bent them apart.
+	///
+	/// Both handles are turned by the same amount, so the new direction splits the difference between their old ones.
+	pub fn straighten_colinear_handles(&mut self) {
+		for (point, arriving, leaving) in self.handle_pairs() {
+			let Some(anchor) = self.point_domain.pos_from_id(point).filter(|_| self.colinear(point)) else {
+				continue;
+			};
+			let handles = self.segment_domain.handles_mut();
+			let (bezier_rs::BezierHandles::Cubic { handle_end: in_handle, .. }, bezier_rs::BezierHandles::Cubic { handle_start: out_handle, .. }) = (handles[arriving], handles[leaving]) else {
+				continue;
+			};
+
+			let (in_length, out_length) = (in_handle.distance(anchor), out_handle.distance(anchor));
+			let direction = ((out_handle - anchor).normalize_or_zero() - (in_handle - anchor).normalize_or_zero()).normalize_or_zero();
+			if direction == DVec2::ZERO {
+				continue;
+			}
+			if let bezier_rs::BezierHandles::Cubic { handle_end, .. } = &mut handles[arriving] {
+				*handle_end = anchor - direction * in_length;
+			}
+			if let bezier_rs::BezierHandles::Cubic { handle_start, .. } = &mut handles[leaving] {
+				*handle_start = anchor + direction * out_length;
+			}
+		}
+	}
 }
 
 impl Default for VectorData {
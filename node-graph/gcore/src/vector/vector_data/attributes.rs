@@ -27,18 +27,133 @@ macro_rules! create_ids {
 
 create_ids! { PointId, SegmentId, RegionId, StrokeId, FillId }
 
+/// The values of a named attribute, with one value stored for each point in a [`PointDomain`].
+#[derive(Clone, Debug, PartialEq, DynAny)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AttributeValues {
+	F64(Vec<f64>),
+	DVec2(Vec<DVec2>),
+	Color(Vec<crate::Color>),
+}
+
+impl core::hash::Hash for AttributeValues {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		core::mem::discriminant(self).hash(state);
+		match self {
+			Self::F64(values) => values.iter().for_each(|value| value.to_bits().hash(state)),
+			Self::DVec2(values) => values.iter().for_each(|value| value.to_array().map(|v| v.to_bits()).hash(state)),
+			Self::Color(values) => values.hash(state),
+		}
+	}
+}
+
+impl AttributeValues {
+	pub fn len(&self) -> usize {
+		match self {
+			Self::F64(values) => values.len(),
+			Self::DVec2(values) => values.len(),
+			Self::Color(values) => values.len(),
+		}
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Truncates or pads the values with the type's default so that there is exactly one value for each of the `len` points.
+	pub fn resize(&mut self, len: usize) {
+		match self {
+			Self::F64(values) => values.resize(len, 0.),
+			Self::DVec2(values) => values.resize(len, DVec2::ZERO),
+			Self::Color(values) => values.resize(len, crate::Color::TRANSPARENT),
+		}
+	}
+
+	/// Reads the value at the specified index as a single number: vectors give their length and colors their luminance.
+	pub fn scalar(&self, index: usize) -> Option<f64> {
+		match self {
+			Self::F64(values) => values.get(index).copied(),
+			Self::DVec2(values) => values.get(index).map(|value| value.length()),
+			Self::Color(values) => values.get(index).map(|color| color.luminance_srgb() as f64),
+		}
+	}
+
+	/// Builds a new attribute by picking the value at each of the specified indices in turn.
+	pub fn select(&self, indices: &[usize]) -> Self {
+		match self {
+			Self::F64(values) => Self::F64(indices.iter().map(|&index| values[index]).collect()),
+			Self::DVec2(values) => Self::DVec2(indices.iter().map(|&index| values[index]).collect()),
+			Self::Color(values) => Self::Color(indices.iter().map(|&index| values[index]).collect()),
+		}
+	}
+
+	/// Builds a new attribute by summing the values at the specified indices, each scaled by its weight, for each entry in turn.
+	/// Entries without any weights take their value from the fallback, or the type's default if the fallback is missing or of another type.
+	pub fn blend(&self, weights: &[Vec<(usize, f64)>], fallback: Option<&Self>) -> Self {
+		let mut result = match fallback {
+			Some(fallback) if core::mem::discriminant(fallback) == core::mem::discriminant(self) => fallback.clone(),
+			_ => self.select(&[]),
+		};
+		result.resize(weights.len());
+
+		let blended = weights.iter().enumerate().filter(|(_, weights)| !weights.is_empty());
+		match (self, &mut result) {
+			(Self::F64(values), Self::F64(result)) => {
+				for (index, weights) in blended {
+					result[index] = weights.iter().map(|&(source, weight)| values[source] * weight).sum();
+				}
+			}
+			(Self::DVec2(values), Self::DVec2(result)) => {
+				for (index, weights) in blended {
+					result[index] = weights.iter().map(|&(source, weight)| values[source] * weight).sum();
+				}
+			}
+			(Self::Color(values), Self::Color(result)) => {
+				for (index, weights) in blended {
+					let [red, green, blue, alpha] = weights.iter().fold([0.; 4], |sum, &(source, weight)| {
+						let (red, green, blue, alpha) = values[source].components();
+						let weight = weight as f32;
+						[sum[0] + red * weight, sum[1] + green * weight, sum[2] + blue * weight, sum[3] + alpha * weight]
+					});
+					result[index] = crate::Color::from_rgbaf32_unchecked(red, green, blue, alpha);
+				}
+			}
+			_ => {}
+		}
+		result
+	}
+
+	/// Appends the values of another attribute, converting them to default values if the types don't match.
+	fn extend(&mut self, other: &Self) {
+		match (self, other) {
+			(Self::F64(values), Self::F64(other)) => values.extend(other),
+			(Self::DVec2(values), Self::DVec2(other)) => values.extend(other),
+			(Self::Color(values), Self::Color(other)) => values.extend(other),
+			(values, other) => values.resize(values.len() + other.len()),
+		}
+	}
+}
+
+/// Name of the per-point attribute marking the points which were drawn or edited by hand with `1`, as opposed to those generated by nodes, which have `0` or no value.
+///
+/// Being an attribute, it's carried along with the points by the nodes that keep them.
+pub const MANUAL_ATTRIBUTE: &str = "manual";
+
 #[derive(Clone, Debug, Default, PartialEq, DynAny)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-/// Stores data which is per-point. Each point is merely a position and can be used in a point cloud or to for a bézier path. In future this will be extendable at runtime with custom attributes.
+/// Stores data which is per-point. Each point is merely a position and can be used in a point cloud or to for a bézier path. Any number of named [`AttributeValues`] can be attached to the points at runtime.
 pub struct PointDomain {
 	id: Vec<PointId>,
 	positions: Vec<DVec2>,
+	#[cfg_attr(feature = "serde", serde(default))]
+	attributes: Vec<(String, AttributeValues)>,
 }
 
 impl core::hash::Hash for PointDomain {
 	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
 		self.id.hash(state);
 		self.positions.iter().for_each(|pos| pos.to_array().map(|v| v.to_bits()).hash(state));
+		self.attributes.hash(state);
 	}
 }
 
@@ -47,23 +162,57 @@ impl PointDomain {
 		Self {
 			id: Vec::new(),
 			positions: Vec::new(),
+			attributes: Vec::new(),
 		}
 	}
 
 	pub fn clear(&mut self) {
 		self.id.clear();
 		self.positions.clear();
+		self.attributes.clear();
 	}
 
 	pub fn push(&mut self, id: PointId, position: DVec2) {
 		self.id.push(id);
 		self.positions.push(position);
+		let len = self.positions.len();
+		self.attributes.iter_mut().for_each(|(_, values)| values.resize(len));
 	}
 
 	pub fn positions(&self) -> &[DVec2] {
 		&self.positions
 	}
 
+	pub fn positions_mut(&mut self) -> &mut [DVec2] {
+		&mut self.positions
+	}
+
+	/// Looks up the values of the attribute with the specified name.
+	pub fn attribute(&self, name: &str) -> Option<&AttributeValues> {
+		self.attributes.iter().find(|(attribute, _)| attribute == name).map(|(_, values)| values)
+	}
+
+	/// Iterates over the names and values of all attributes.
+	pub fn attributes(&self) -> impl Iterator<Item = (&str, &AttributeValues)> {
+		self.attributes.iter().map(|(name, values)| (name.as_str(), values))
+	}
+
+	/// Adds or replaces the attribute with the specified name, resizing the values to match the number of points.
+	pub fn set_attribute(&mut self, name: impl Into<String>, mut values: AttributeValues) {
+		let name = name.into();
+		values.resize(self.positions.len());
+		match self.attributes.iter_mut().find(|(attribute, _)| *attribute == name) {
+			Some((_, existing)) => *existing = values,
+			None => self.attributes.push((name, values)),
+		}
+	}
+
+	/// Removes the attribute with the specified name, returning its values if it existed.
+	pub fn remove_attribute(&mut self, name: &str) -> Option<AttributeValues> {
+		let index = self.attributes.iter().position(|(attribute, _)| attribute == name)?;
+		Some(self.attributes.remove(index).1)
+	}
+
 	pub fn ids(&self) -> &[PointId] {
 		&self.id
 	}
@@ -81,6 +230,21 @@ impl PointDomain {
 	}
 
 	fn concat(&mut self, other: &Self, transform: DAffine2, id_map: &IdMap) {
+		let (len, other_len) = (self.positions.len(), other.positions.len());
+		for (name, other_values) in &other.attributes {
+			if self.attribute(name).is_none() {
+				let mut values = other_values.clone();
+				values.resize(0);
+				self.set_attribute(name.clone(), values);
+			}
+		}
+		for (name, values) in &mut self.attributes {
+			match other.attributes.iter().find(|(other_name, _)| other_name == name) {
+				Some((_, other_values)) => values.extend(other_values),
+				None => values.resize(len + other_len),
+			}
+		}
+
 		self.id.extend(other.id.iter().map(|id| *id_map.point_map.get(id).unwrap_or(id)));
 		self.positions.extend(other.positions.iter().map(|&pos| transform.transform_point2(pos)));
 	}
@@ -131,6 +295,22 @@ impl SegmentDomain {
 		self.stroke.push(stroke);
 	}
 
+	pub fn start_point(&self) -> &[PointId] {
+		&self.start_point
+	}
+
+	pub fn end_point(&self) -> &[PointId] {
+		&self.end_point
+	}
+
+	pub fn handles(&self) -> &[bezier_rs::BezierHandles] {
+		&self.handles
+	}
+
+	pub fn handles_mut(&mut self) -> &mut [bezier_rs::BezierHandles] {
+		&mut self.handles
+	}
+
 	fn resolve_id(&self, id: SegmentId) -> Option<usize> {
 		self.ids.iter().position(|&check_id| check_id == id)
 	}
@@ -266,8 +446,13 @@ impl super::VectorData {
 
 			last = Some((end, handle));
 		}
-		end_point(last, None, &mut groups)?;
+		// A closed subpath ends back at its first point, which takes the in handle of the closing segment rather than being repeated
 		let closed = groups.len() > 1 && last.map(|(point, _)| point) == first_point;
+		if closed {
+			groups[0].in_handle = last.and_then(|(_, handle)| handle.end());
+		} else {
+			end_point(last, None, &mut groups)?;
+		}
 		Some(bezier_rs::Subpath::new(groups, closed))
 	}
 
@@ -324,7 +509,10 @@ impl<'a> Iterator for StrokePathIter<'a> {
 			.take_while(|&(_, start, end)| {
 				let continuous = old_end.is_none() || old_end.is_some_and(|old_end| old_end == start);
 				old_end = Some(end);
-				count += 1;
+				// Only count the segments that belong to this subpath so the next one starts at the first discontinuous segment
+				if continuous {
+					count += 1;
+				}
 				continuous
 			});
 
@@ -359,12 +547,29 @@ impl crate::vector::ConcatElement for super::VectorData {
 		let new_ids = other.region_domain.ids.iter().filter(|id| self.region_domain.ids.contains(id)).map(|&old| (old, RegionId::generate()));
 		let region_map = new_ids.collect::<HashMap<_, _>>();
 		let id_map = IdMap { point_map, segment_map, region_map };
+
+		// The subpaths already here keep their style, as their own, when it differs from the one taken on from the other shape
+		let offset = self.stroke_bezier_paths().count();
+		if self.style != other.style {
+			for index in 0..offset {
+				if self.subpath_styles.binary_search_by_key(&index, |&(subpath, _)| subpath).is_err() {
+					self.set_subpath_style(index, self.style.clone());
+				}
+			}
+		}
+		self.subpath_styles.extend(other.subpath_styles.iter().map(|(index, style)| (index + offset, style.clone())));
+
 		self.point_domain.concat(&other.point_domain, transform * other.transform, &id_map);
 		self.segment_domain.concat(&other.segment_domain, transform * other.transform, &id_map);
 		self.region_domain.concat(&other.region_domain, transform * other.transform, &id_map);
 		// TODO: properly deal with fills such as gradients
 		self.style = other.style.clone();
-		self.colinear_manipulators.extend(other.colinear_manipulators.iter().copied());
+		if self.subpath_styles.iter().all(|(_, style)| *style == self.style) {
+			self.subpath_styles.clear();
+		}
+		// Points given new IDs to avoid clashing keep their colinear handles under those IDs
+		let colinear = other.colinear_manipulators.iter().map(|&id| id_map.point_map.get(&id.into()).map_or(id, |&new_id| new_id.into()));
+		self.colinear_manipulators.extend(colinear);
 		self.alpha_blending = other.alpha_blending;
 	}
 }
@@ -374,3 +579,37 @@ struct IdMap {
 	segment_map: HashMap<SegmentId, SegmentId>,
 	region_map: HashMap<RegionId, RegionId>,
 }
+
+#[cfg(test)]
+mod test {
+	use super::super::VectorData;
+	use glam::DVec2;
+
+	#[test]
+	fn closed_stroke_path_keeps_closing_handle() {
+		let ellipse = bezier_rs::Subpath::new_ellipse(DVec2::ZERO, DVec2::new(100., 50.));
+		let vector_data = VectorData::from_subpath(ellipse.clone());
+
+		let subpaths = vector_data.stroke_bezier_paths().collect::<Vec<_>>();
+		assert_eq!(subpaths.len(), 1);
+		assert!(subpaths[0].closed());
+		// The first point isn't repeated at the end, and takes the in handle of the closing segment
+		let groups = subpaths[0].manipulator_groups();
+		assert_eq!(groups.len(), ellipse.len());
+		for (group, expected) in groups.iter().zip(ellipse.manipulator_groups()) {
+			assert_eq!((group.anchor, group.in_handle, group.out_handle), (expected.anchor, expected.in_handle, expected.out_handle));
+		}
+	}
+
+	#[test]
+	fn stroke_paths_split_at_discontinuous_segments() {
+		let line = bezier_rs::Subpath::new_line(DVec2::ZERO, DVec2::new(10., 0.));
+		let rects = [20., 40.].map(|y| bezier_rs::Subpath::new_rect(DVec2::new(0., y), DVec2::new(10., y + 10.)));
+		let vector_data = VectorData::from_subpaths([line].into_iter().chain(rects));
+
+		// Each subpath starts at the segment that broke the previous one, rather than skipping it
+		let subpaths = vector_data.stroke_bezier_paths().collect::<Vec<_>>();
+		let shapes = subpaths.iter().map(|subpath| (subpath.len(), subpath.closed())).collect::<Vec<_>>();
+		assert_eq!(shapes, [(2, false), (4, true), (4, true)]);
+	}
+}
@@ -1,16 +1,24 @@
-use super::style::{Fill, FillType, Gradient, GradientType, Stroke};
-use super::{PointId, SegmentId, StrokeId, VectorData};
+use super::generator_nodes::{COLUMN_ATTRIBUTE, ROW_ATTRIBUTE};
+use super::style::{
+	Fill, FillRule, FillType, Gradient, GradientInterpolation, GradientType, HueInterpolation, ImageFill, ImageFillMode, Paint, PaintLayer, PathStyle, Pattern, Stroke, StrokeAlignment, StyleLibrary,
+};
+use super::{AttributeValues, FillId, PointId, RegionId, SegmentId, StrokeId, VectorData, VectorInstances};
+use crate::raster::curve::Curve;
+use crate::raster::{BlendMode, ColorSpace, ImageFrame};
 use crate::renderer::GraphicElementRendered;
 use crate::transform::{Footprint, Transform, TransformMut};
-use crate::{Color, GraphicGroup, Node};
+use crate::uuid::ManipulatorGroupId;
+use crate::{AlphaBlending, Color, GraphicElement, GraphicGroup, Node};
 use core::future::Future;
 
-use bezier_rs::{Cap, Join, Subpath, SubpathTValue, TValue};
+use bezier_rs::{Bezier, BezierHandles, Cap, Join, ManipulatorGroup, Subpath, SubpathTValue, TValue};
+use dyn_any::{DynAny, StaticType};
 use glam::{DAffine2, DVec2};
 use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Copy)]
-pub struct SetFillNode<FillType, SolidColor, GradientType, Start, End, Transform, Positions> {
+pub struct SetFillNode<FillType, SolidColor, GradientType, Start, End, Transform, Positions, FocalPoint, FillRule, BlendMode, Opacity> {
 	fill_type: FillType,
 	solid_color: SolidColor,
 	gradient_type: GradientType,
@@ -18,8 +26,15 @@ pub struct SetFillNode<FillType, SolidColor, GradientType, Start, End, Transform
 	end: End,
 	transform: Transform,
 	positions: Positions,
+	focal_point: FocalPoint,
+	fill_rule: FillRule,
+	blend_mode: BlendMode,
+	opacity: Opacity,
 }
 
+/// Fills the shape with either the solid color or a gradient.
+///
+/// Also sets the blend mode of the whole shape onto what's beneath it, and its opacity as a percentage.
 #[node_macro::node_fn(SetFillNode)]
 fn set_vector_data_fill(
 	mut vector_data: VectorData,
@@ -30,7 +45,13 @@ fn set_vector_data_fill(
 	end: DVec2,
 	transform: DAffine2,
 	positions: Vec<(f64, Color)>,
+	focal_point: Option<DVec2>,
+	fill_rule: FillRule,
+	blend_mode: BlendMode,
+	opacity: f64,
 ) -> VectorData {
+	set_blending(&mut vector_data.alpha_blending, blend_mode, opacity);
+	vector_data.style.set_fill_rule(fill_rule);
 	vector_data.style.set_fill(match fill_type {
 		FillType::Solid => solid_color.map_or(Fill::None, Fill::Solid),
 		FillType::Gradient => Fill::Gradient(Gradient {
@@ -39,13 +60,34 @@ fn set_vector_data_fill(
 			transform,
 			positions,
 			gradient_type,
+			focal_point,
+			..Default::default()
 		}),
 	});
 	vector_data
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct SetStrokeNode<Color, Weight, DashLengths, DashOffset, LineCap, LineJoin, MiterLimit> {
+pub struct SetStrokeNode<
+	Color,
+	Weight,
+	DashLengths,
+	DashOffset,
+	LineCap,
+	LineJoin,
+	MiterLimit,
+	Alignment,
+	PaintType,
+	GradientType,
+	Start,
+	End,
+	Transform,
+	Positions,
+	FocalPoint,
+	BlendMode,
+	Opacity,
+	Hairline,
+> {
 	color: Color,
 	weight: Weight,
 	dash_lengths: DashLengths,
@@ -53,8 +95,23 @@ pub struct SetStrokeNode<Color, Weight, DashLengths, DashOffset, LineCap, LineJo
 	line_cap: LineCap,
 	line_join: LineJoin,
 	miter_limit: MiterLimit,
+	alignment: Alignment,
+	paint_type: PaintType,
+	gradient_type: GradientType,
+	start: Start,
+	end: End,
+	transform: Transform,
+	positions: Positions,
+	focal_point: FocalPoint,
+	blend_mode: BlendMode,
+	opacity: Opacity,
+	hairline: Hairline,
 }
 
+/// Strokes the shape with either the solid color or a gradient, whose points are placed in the bounds of the shape like those of a gradient fill.
+///
+/// Also sets the blend mode and opacity of the whole shape, in the same way as the Fill node.
+/// A hairline stroke keeps its weight in pixels on screen at any zoom and however the shape is transformed.
 #[node_macro::node_fn(SetStrokeNode)]
 fn set_vector_data_stroke(
 	mut vector_data: VectorData,
@@ -65,73 +122,691 @@ fn set_vector_data_stroke(
 	line_cap: super::style::LineCap,
 	line_join: super::style::LineJoin,
 	miter_limit: f64,
+	alignment: super::style::StrokeAlignment,
+	paint_type: FillType,
+	gradient_type: GradientType,
+	start: DVec2,
+	end: DVec2,
+	transform: DAffine2,
+	positions: Vec<(f64, Color)>,
+	focal_point: Option<DVec2>,
+	blend_mode: BlendMode,
+	opacity: f64,
+	hairline: bool,
 ) -> VectorData {
+	set_blending(&mut vector_data.alpha_blending, blend_mode, opacity);
+	let paint = match paint_type {
+		FillType::Solid => color.map_or(Fill::None, Fill::Solid),
+		FillType::Gradient => Fill::Gradient(Gradient {
+			start,
+			end,
+			transform,
+			positions,
+			gradient_type,
+			focal_point,
+			..Default::default()
+		}),
+	};
 	vector_data.style.set_stroke(Stroke {
-		color,
+		paint,
 		weight,
 		dash_lengths,
 		dash_offset,
 		line_cap,
 		line_join,
 		line_join_miter_limit: miter_limit,
+		gradient: None,
+		alignment,
+		width_profile: Vec::new(),
+		hairline,
+		dash_cap: None,
+		dashes_scale_with_weight: false,
+		dashes_align_to_corners: false,
+	});
+	vector_data
+}
+
+/// Sets the blend mode and the opacity from the percentage, replacing any set by an earlier node.
+fn set_blending(alpha_blending: &mut AlphaBlending, blend_mode: BlendMode, opacity: f64) {
+	alpha_blending.blend_mode = blend_mode;
+	alpha_blending.opacity = opacity as f32 / 100.;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SetStrokeGradientNode<Positions> {
+	positions: Positions,
+}
+
+/// Paints the stroke with a gradient running along the length of each subpath, from the first stop at its start to the last stop at its end, which suits fading trails.
+/// The stroke keeps its other properties, and the gradient is removed when there are no stops.
+#[node_macro::node_fn(SetStrokeGradientNode)]
+fn set_stroke_gradient(mut vector_data: VectorData, positions: Vec<(f64, Color)>) -> VectorData {
+	let gradient = (!positions.is_empty()).then(|| Gradient { positions, ..Default::default() });
+	let stroke = vector_data.style.stroke().unwrap_or_default().with_gradient(gradient);
+	vector_data.style.set_stroke(stroke);
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StrokeWidthProfileNode<Widths> {
+	widths: Widths,
+}
+
+/// Varies the width of the stroke along the length of each subpath, scaling its weight by the widths spread evenly from its start to its end, for tapered and calligraphic strokes.
+/// For example, widths of 0, 1, and 0 taper the stroke to a point at both ends. The stroke keeps its other properties, and its width is made even again when there are no widths.
+#[node_macro::node_fn(StrokeWidthProfileNode)]
+fn stroke_width_profile(mut vector_data: VectorData, widths: Vec<f64>) -> VectorData {
+	let width_profile = match widths.len() {
+		1 => vec![(0., widths[0])],
+		count => widths.iter().enumerate().map(|(index, &width)| (index as f64 / (count - 1) as f64, width)).collect(),
+	};
+	let stroke = vector_data.style.stroke().unwrap_or_default().with_width_profile(width_profile);
+	vector_data.style.set_stroke(stroke);
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StrokeDashesNode<DashCap, ScaleWithWeight, AlignToCorners> {
+	dash_cap: DashCap,
+	scale_with_weight: ScaleWithWeight,
+	align_to_corners: AlignToCorners,
+}
+
+/// Sets how the stroke's dashes are drawn: the cap on the ends of each dash, whether the dash lengths and offset are multiples of the stroke's weight rather than
+/// fixed lengths, and whether the dashes are stretched so a dash lands centered on every corner. The stroke keeps its other properties, including its dash lengths.
+#[node_macro::node_fn(StrokeDashesNode)]
+fn stroke_dashes(mut vector_data: VectorData, dash_cap: LineCap, scale_with_weight: bool, align_to_corners: bool) -> VectorData {
+	let stroke = vector_data
+		.style
+		.stroke()
+		.unwrap_or_default()
+		.with_dash_cap(Some(dash_cap))
+		.with_dashes_scale_with_weight(scale_with_weight)
+		.with_dashes_align_to_corners(align_to_corners);
+	vector_data.style.set_stroke(stroke);
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FillStrokeOpacityNode<FillOpacity, StrokeOpacity> {
+	fill_opacity: FillOpacity,
+	stroke_opacity: StrokeOpacity,
+}
+
+/// Sets the opacity of the fill and of the stroke separately as percentages, apart from the opacity of the whole shape.
+/// Since the colors keep their own alpha, they can be swapped for those of another palette without losing the transparency.
+#[node_macro::node_fn(FillStrokeOpacityNode)]
+fn fill_stroke_opacity(mut vector_data: VectorData, fill_opacity: f64, stroke_opacity: f64) -> VectorData {
+	vector_data.style.set_fill_opacity(fill_opacity / 100.);
+	vector_data.style.set_stroke_opacity(stroke_opacity / 100.);
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GradientInterpolationNode<Interpolation, HueInterpolation> {
+	interpolation: Interpolation,
+	hue_interpolation: HueInterpolation,
+}
+
+/// Sets the color space that every gradient of the shape's fill, stroke, and paint layers blends its colors in between stops, and which way the hue turns in LCH.
+/// Blending in OKLab or LCH keeps the mixtures of very different colors from passing through a muddy gray.
+#[node_macro::node_fn(GradientInterpolationNode)]
+fn gradient_interpolation(mut vector_data: VectorData, interpolation: GradientInterpolation, hue_interpolation: HueInterpolation) -> VectorData {
+	let set_interpolation = |gradient: &mut Gradient| {
+		gradient.interpolation = interpolation;
+		gradient.hue_interpolation = hue_interpolation;
+	};
+	let set_paint = |fill: &mut Fill| {
+		if let Fill::Gradient(gradient) = fill {
+			set_interpolation(gradient);
+		}
+	};
+	let set_stroke = |stroke: &mut Stroke| {
+		set_paint(&mut stroke.paint);
+		if let Some(gradient) = &mut stroke.gradient {
+			set_interpolation(gradient);
+		}
+	};
+
+	let mut fill = vector_data.style.fill().clone();
+	set_paint(&mut fill);
+	vector_data.style.set_fill(fill);
+	if let Some(mut stroke) = vector_data.style.stroke() {
+		set_stroke(&mut stroke);
+		vector_data.style.set_stroke(stroke);
+	}
+
+	let layers = vector_data.style.paint_layers().to_vec();
+	vector_data.style.clear_paint_layers();
+	for mut layer in layers {
+		match &mut layer.paint {
+			Paint::Fill(fill) => set_paint(fill),
+			Paint::Stroke(stroke) => set_stroke(stroke),
+		}
+		vector_data.style.insert_paint_layer(-1, layer);
+	}
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ColorSpaceNode<Space> {
+	space: Space,
+}
+
+/// Reads the channels of every solid color and gradient stop of the shape's fill, stroke, and paint layers as given in the color space, which reaches
+/// the more saturated colors of wide-gamut displays beyond sRGB. The colors are kept as extended-range sRGB, which the SVG renderer draws in Display P3 where they fit.
+#[node_macro::node_fn(ColorSpaceNode)]
+fn color_space(mut vector_data: VectorData, space: ColorSpace) -> VectorData {
+	map_style_colors(&mut vector_data.style, &|color: &mut Color| {
+		let (red, green, blue, alpha) = color.components();
+		*color = Color::from_color_space(space, red, green, blue, alpha).to_gamma_srgb();
 	});
 	vector_data
 }
 
+/// Applies the function to every solid color and gradient stop of the style's fill, stroke, and paint layers.
+fn map_style_colors(style: &mut PathStyle, convert: &impl Fn(&mut Color)) {
+	let set_paint = |fill: &mut Fill| match fill {
+		Fill::Solid(color) => convert(color),
+		Fill::Gradient(gradient) => gradient.positions.iter_mut().for_each(|(_, color)| convert(color)),
+		_ => {}
+	};
+	let set_stroke = |stroke: &mut Stroke| {
+		set_paint(&mut stroke.paint);
+		if let Some(gradient) = &mut stroke.gradient {
+			gradient.positions.iter_mut().for_each(|(_, color)| convert(color));
+		}
+	};
+
+	let mut fill = style.fill().clone();
+	set_paint(&mut fill);
+	style.set_fill(fill);
+	if let Some(mut stroke) = style.stroke() {
+		set_stroke(&mut stroke);
+		style.set_stroke(stroke);
+	}
+
+	let layers = style.paint_layers().to_vec();
+	style.clear_paint_layers();
+	for mut layer in layers {
+		match &mut layer.paint {
+			Paint::Fill(fill) => set_paint(fill),
+			Paint::Stroke(stroke) => set_stroke(stroke),
+		}
+		style.insert_paint_layer(-1, layer);
+	}
+}
+
+/// Applies the function to the style colors of every shape in the group and its nested groups and artboards.
+fn map_group_colors(graphic_group: &mut GraphicGroup, convert: &impl Fn(&mut Color)) {
+	for element in graphic_group.iter_mut() {
+		match element {
+			GraphicElement::GraphicGroup(group) => map_group_colors(group, convert),
+			GraphicElement::VectorData(vector_data) => map_style_colors(&mut vector_data.style, convert),
+			GraphicElement::VectorInstances(instances) => map_style_colors(&mut instances.geometry.style, convert),
+			GraphicElement::Artboard(artboard) => map_group_colors(&mut artboard.graphic_group, convert),
+			_ => {}
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AdjustFillColorNode<Hue, Saturation, Lightness> {
+	hue_shift: Hue,
+	saturation_shift: Saturation,
+	lightness_shift: Lightness,
+}
+
+/// Shifts the hue by degrees and the saturation and lightness by percentage points, keeping them in range.
+fn shift_hsl(color: &mut Color, hue_shift: f64, saturation_shift: f64, lightness_shift: f64) {
+	let [hue, saturation, lightness, alpha] = color.to_hsla();
+	*color = Color::from_hsla(
+		(hue + hue_shift as f32 / 360.).rem_euclid(1.),
+		(saturation + saturation_shift as f32 / 100.).clamp(0., 1.),
+		(lightness + lightness_shift as f32 / 100.).clamp(0., 1.),
+		alpha,
+	);
+}
+
+/// Shifts the hue, saturation, and lightness of every fill, stroke, and gradient stop, so the colors of a whole procedural result can be changed downstream of the nodes that styled it.
+#[node_macro::node_fn(AdjustFillColorNode)]
+fn adjust_fill_color(mut vector_data: VectorData, hue_shift: f64, saturation_shift: f64, lightness_shift: f64) -> VectorData {
+	map_style_colors(&mut vector_data.style, &|color: &mut Color| shift_hsl(color, hue_shift, saturation_shift, lightness_shift));
+	vector_data
+}
+
+#[node_macro::node_impl(AdjustFillColorNode)]
+fn adjust_fill_color(mut graphic_group: GraphicGroup, hue_shift: f64, saturation_shift: f64, lightness_shift: f64) -> GraphicGroup {
+	map_group_colors(&mut graphic_group, &|color: &mut Color| shift_hsl(color, hue_shift, saturation_shift, lightness_shift));
+	graphic_group
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CopyStyleNode<Source> {
+	source: Source,
+}
+
+/// Replaces the style of the shape, with its fill, stroke, and paint layers, by that of the source, leaving its geometry and transform as they are.
+/// Gradients, patterns, and image fills are moved along so they sit on the shape's bounding box the way they sat on the source's.
+#[node_macro::node_fn(CopyStyleNode)]
+fn copy_style(mut vector_data: VectorData, source: VectorData) -> VectorData {
+	let mut style = source.style.clone();
+
+	if let (Some(from), Some(to)) = (source.bounding_box(), vector_data.bounding_box()) {
+		remap_style(&mut style, source.transform, from, vector_data.transform, to);
+	}
+
+	vector_data.style = style;
+	vector_data
+}
+
+/// Moves the placement of every fill of the style, including those of its stroke and paint layers, from one bounding box onto another with [`remap_fill`].
+fn remap_style(style: &mut PathStyle, from_transform: DAffine2, from: [DVec2; 2], to_transform: DAffine2, to: [DVec2; 2]) {
+	let remap = |fill: &mut Fill| remap_fill(fill, from_transform, from, to_transform, to);
+
+	let mut fill = style.fill().clone();
+	remap(&mut fill);
+	style.set_fill(fill);
+	if let Some(mut stroke) = style.stroke() {
+		remap(&mut stroke.paint);
+		style.set_stroke(stroke);
+	}
+
+	let layers = style.paint_layers().to_vec();
+	style.clear_paint_layers();
+	for mut layer in layers {
+		match &mut layer.paint {
+			Paint::Fill(fill) => remap(fill),
+			Paint::Stroke(stroke) => remap(&mut stroke.paint),
+		}
+		style.insert_paint_layer(-1, layer);
+	}
+}
+
+/// Moves the placement of a fill from the bounding box of one shape onto that of another, given in the local space of each shape's transform.
+/// The points of a gradient are already relative to the bounding box, so only the transform the editor shows its handles with is updated.
+fn remap_fill(fill: &mut Fill, from_transform: DAffine2, from: [DVec2; 2], to_transform: DAffine2, to: [DVec2; 2]) {
+	let shift = DAffine2::from_translation(to[0] - from[0]);
+	match fill {
+		Fill::Gradient(gradient) => {
+			let bounds_space = |transform: DAffine2, [min, max]: [DVec2; 2]| transform * DAffine2::from_scale_angle_translation(max - min, 0., min);
+			let (from_space, to_space) = (bounds_space(from_transform, from), bounds_space(to_transform, to));
+			if from_space.matrix2.determinant() != 0. {
+				gradient.transform = gradient.transform * from_space.inverse() * to_space;
+			}
+		}
+		// The grid of tiles is anchored to the origin, so it's shifted to keep the tiles lined up with the corner of the bounding box
+		Fill::Pattern(pattern) => pattern.transform = shift * pattern.transform,
+		// The image is already sized to the bounding box, so its transform is moved to act around the same corner
+		Fill::Image(image_fill) => image_fill.transform = shift * image_fill.transform * shift.inverse(),
+		Fill::None | Fill::Solid(_) => {}
+	}
+}
+
+/// The bounding box that the styles of a [`StyleLibrary`] are placed on, from which they're moved onto the shapes they're applied to.
+const STYLE_LIBRARY_BOUNDS: [DVec2; 2] = [DVec2::ZERO, DVec2::ONE];
+
+#[derive(Debug, Clone, Copy)]
+pub struct DefineStyleNode<Name, Source> {
+	name: Name,
+	source: Source,
+}
+
+/// Adds the style of the source shape to the library under the name, replacing any style already defined with that name, for the Apply Style node to restyle other shapes with.
+/// Chaining these nodes builds up a library that can be swapped for another one to change the look of everything styled from it.
+#[node_macro::node_fn(DefineStyleNode)]
+fn define_style(mut library: StyleLibrary, name: String, source: VectorData) -> StyleLibrary {
+	let mut style = source.style.clone();
+	if let Some(bounds) = source.bounding_box() {
+		remap_style(&mut style, source.transform, bounds, DAffine2::IDENTITY, STYLE_LIBRARY_BOUNDS);
+	}
+
+	library.define(name, style);
+	library
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ApplyStyleNode<Library, Name> {
+	library: Library,
+	name: Name,
+}
+
+/// Replaces the style of the shape by the one defined under the name in the library, in the same way as the Copy Style node.
+/// The shape is left as it is when the library has no style with that name.
+#[node_macro::node_fn(ApplyStyleNode)]
+fn apply_style(mut vector_data: VectorData, library: StyleLibrary, name: String) -> VectorData {
+	let Some(style) = library.get(&name) else {
+		return vector_data;
+	};
+
+	let mut style = style.clone();
+	if let Some(bounds) = vector_data.bounding_box() {
+		remap_style(&mut style, DAffine2::IDENTITY, STYLE_LIBRARY_BOUNDS, vector_data.transform, bounds);
+	}
+
+	vector_data.style = style;
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AddFillLayerNode<Color, BlendMode, Opacity, Index> {
+	color: Color,
+	blend_mode: BlendMode,
+	opacity: Opacity,
+	index: Index,
+}
+
+/// Adds a fill to the shape's stack of paint, which is painted over its own fill and stroke.
+/// The index counts from the bottom of the stack from 0 or from the top from -1, so the default of -1 paints the fill over everything else.
+#[node_macro::node_fn(AddFillLayerNode)]
+fn add_fill_layer(mut vector_data: VectorData, color: Option<Color>, blend_mode: BlendMode, opacity: f64, index: f64) -> VectorData {
+	let fill = color.map_or(Fill::None, Fill::Solid);
+	let alpha_blending = AlphaBlending {
+		opacity: (opacity / 100.).clamp(0., 1.) as f32,
+		blend_mode,
+	};
+	vector_data.style.insert_paint_layer(index.round() as i32, PaintLayer::new(Paint::Fill(fill), alpha_blending));
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AddStrokeLayerNode<Color, Weight, BlendMode, Opacity, Index> {
+	color: Color,
+	weight: Weight,
+	blend_mode: BlendMode,
+	opacity: Opacity,
+	index: Index,
+}
+
+/// Adds a stroke to the shape's stack of paint, which is painted over its own fill and stroke.
+/// The index counts from the bottom of the stack from 0 or from the top from -1, so the default of -1 paints the stroke over everything else.
+#[node_macro::node_fn(AddStrokeLayerNode)]
+fn add_stroke_layer(mut vector_data: VectorData, color: Option<Color>, weight: f64, blend_mode: BlendMode, opacity: f64, index: f64) -> VectorData {
+	let stroke = Stroke::new(color, weight);
+	let alpha_blending = AlphaBlending {
+		opacity: (opacity / 100.).clamp(0., 1.) as f32,
+		blend_mode,
+	};
+	vector_data.style.insert_paint_layer(index.round() as i32, PaintLayer::new(Paint::Stroke(stroke), alpha_blending));
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RemovePaintLayerNode<Index> {
+	index: Index,
+}
+
+/// Removes a fill or stroke from the shape's stack of paint, counting from the bottom of the stack from 0 or from the top from -1.
+/// The shape's own fill and stroke aren't part of the stack.
+#[node_macro::node_fn(RemovePaintLayerNode)]
+fn remove_paint_layer(mut vector_data: VectorData, index: f64) -> VectorData {
+	vector_data.style.remove_paint_layer(index.round() as i32);
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SetPatternFillNode<Shape, Tile, Spacing, Rotation, Scale> {
+	shape: Shape,
+	tile: Tile,
+	spacing: Spacing,
+	rotation: Rotation,
+	scale: Scale,
+}
+
+/// Fills the shape with copies of the tile repeated in a grid of cells of the given size, like wallpaper or fabric.
+/// The grid is rotated (in degrees) and scaled within the space of the shape, and the tile is drawn with its origin at the top left corner of each cell.
+#[node_macro::node_fn(SetPatternFillNode)]
+async fn set_pattern_fill<ShapeFuture: Future<Output = VectorData>, TileFuture: Future<Output = VectorData>>(
+	footprint: Footprint,
+	shape: impl Node<Footprint, Output = ShapeFuture>,
+	tile: impl Node<Footprint, Output = TileFuture>,
+	spacing: DVec2,
+	rotation: f64,
+	scale: DVec2,
+) -> VectorData {
+	let mut shape = self.shape.eval(footprint).await;
+	let tile = self.tile.eval(footprint).await;
+
+	let transform = DAffine2::from_scale_angle_translation(scale, rotation.to_radians(), DVec2::ZERO);
+	shape.style.set_fill(Fill::Pattern(Box::new(Pattern::new(tile, spacing, transform))));
+	shape
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SetImageFillNode<Shape, Image, Mode, Offset, Rotation, Scale> {
+	shape: Shape,
+	image: Image,
+	mode: Mode,
+	offset: Offset,
+	rotation: Rotation,
+	scale: Scale,
+}
+
+/// Fills the shape with the image, sized to its bounding box by the mode, like a photo in a frame.
+/// The image is then moved, rotated (in degrees), and scaled within the space of the shape.
+#[node_macro::node_fn(SetImageFillNode)]
+async fn set_image_fill<ShapeFuture: Future<Output = VectorData>, ImageFuture: Future<Output = ImageFrame<Color>>>(
+	footprint: Footprint,
+	shape: impl Node<Footprint, Output = ShapeFuture>,
+	image: impl Node<Footprint, Output = ImageFuture>,
+	mode: ImageFillMode,
+	offset: DVec2,
+	rotation: f64,
+	scale: DVec2,
+) -> VectorData {
+	let mut shape = self.shape.eval(footprint).await;
+	let image = self.image.eval(footprint).await;
+
+	let transform = DAffine2::from_scale_angle_translation(scale, rotation.to_radians(), offset);
+	shape.style.set_fill(Fill::Image(Box::new(ImageFill::new(image, mode, transform))));
+	shape
+}
+
 #[derive(Debug, Clone, Copy)]
-pub struct RepeatNode<Direction, Count> {
+pub struct RepeatNode<Direction, Count, Rotation, Scale> {
 	direction: Direction,
 	count: Count,
+	rotation: Rotation,
+	scale: Scale,
 }
 
+/// Repeats the vector data, with each copy moved by the direction from the one before it, as well as rotated by the rotation (in degrees) and scaled by the scale around its center.
+///
+/// Since every step is applied on top of the last, a rotation turns later steps too, so the copies curve around into a spiral.
+/// Repeating [`VectorInstances`] only adds more instances, leaving the geometry shared rather than copied.
 #[node_macro::node_fn(RepeatNode)]
-fn repeat_vector_data(vector_data: VectorData, direction: DVec2, count: u32) -> VectorData {
+fn repeat_vector_data<Data: RepeatElement>(vector_data: Data, direction: DVec2, count: u32, rotation: f64, scale: f64) -> Data {
+	let center = vector_data.local_bounding_box().map(|[min, max]| (min + max) / 2.).unwrap_or_default();
+	let step = DAffine2::from_translation(direction + center) * DAffine2::from_scale_angle_translation(DVec2::splat(scale), rotation.to_radians(), DVec2::ZERO) * DAffine2::from_translation(-center);
+
 	// Repeat the vector data
-	let mut result = VectorData::empty();
-	for i in 0..count {
-		let transform = DAffine2::from_translation(direction * i as f64);
+	let mut result = Data::default();
+	let mut transform = DAffine2::IDENTITY;
+	for _ in 0..count {
 		result.concat(&vector_data, transform);
+		transform = step * transform;
 	}
 
 	result
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct CircularRepeatNode<AngleOffset, Radius, Count> {
+pub struct CircularRepeatNode<AngleOffset, Radius, Count, Pivot, KeepUpright> {
 	angle_offset: AngleOffset,
 	radius: Radius,
 	count: Count,
+	pivot: Pivot,
+	keep_upright: KeepUpright,
 }
 
+/// Repeats the vector data around a circle of the given radius, centered on the pivot if one is given, otherwise on the center of the vector data's bounding box.
+///
+/// Each copy is rotated along with its angle around the circle, unless kept upright, in which case it's only moved there (as with the numbers on a clock face).
 #[node_macro::node_fn(CircularRepeatNode)]
-fn circular_repeat_vector_data(vector_data: VectorData, angle_offset: f64, radius: f64, count: u32) -> VectorData {
-	let mut result = VectorData::empty();
+fn circular_repeat_vector_data<Data: RepeatElement>(vector_data: Data, angle_offset: f64, radius: f64, count: u32, pivot: Option<DVec2>, keep_upright: bool) -> Data {
+	let mut result = Data::default();
 
-	let Some(bounding_box) = vector_data.bounding_box() else { return vector_data };
+	let Some(bounding_box) = vector_data.local_bounding_box() else { return vector_data };
 	let center = (bounding_box[0] + bounding_box[1]) / 2.;
+	let pivot = pivot.unwrap_or(center);
 
 	let base_transform = DVec2::new(0., radius) - center;
 
 	for i in 0..count {
 		let angle = (2. * std::f64::consts::PI / count as f64) * i as f64 + angle_offset.to_radians();
 		let rotation = DAffine2::from_angle(angle);
-		let transform = DAffine2::from_translation(center) * rotation * DAffine2::from_translation(base_transform);
+		let transform = if keep_upright {
+			DAffine2::from_translation(pivot + rotation.transform_vector2(DVec2::new(0., radius)) - center)
+		} else {
+			DAffine2::from_translation(pivot) * rotation * DAffine2::from_translation(base_transform)
+		};
 		result.concat(&vector_data, transform);
 	}
 
 	result
 }
 
+/// Artwork that [`RepeatNode`] and [`CircularRepeatNode`] can make copies of.
+pub trait RepeatElement: ConcatElement + Default {
+	/// The bounds of the artwork before its own transform is applied, which the copies are arranged around.
+	fn local_bounding_box(&self) -> Option<[DVec2; 2]>;
+}
+
+impl RepeatElement for VectorData {
+	fn local_bounding_box(&self) -> Option<[DVec2; 2]> {
+		self.bounding_box()
+	}
+}
+
+impl RepeatElement for VectorInstances {
+	fn local_bounding_box(&self) -> Option<[DVec2; 2]> {
+		self.bounding_box()
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ToInstancesNode;
+
+/// Turns the vector data into a single instance of itself, so the repeat and copy nodes after this place more instances that share its geometry instead of duplicating it.
+#[node_macro::node_fn(ToInstancesNode)]
+fn to_instances(vector_data: VectorData) -> VectorInstances {
+	VectorInstances::new(vector_data)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FlattenInstancesNode;
+
+/// Expands the instances into vector data holding its own copy of the geometry for each one, for use with nodes that need the actual geometry.
+#[node_macro::node_fn(FlattenInstancesNode)]
+fn flatten_instances(vector_instances: VectorInstances) -> VectorData {
+	vector_instances.flatten()
+}
+
 #[derive(Debug, Clone, Copy)]
-pub struct BoundingBoxNode;
+pub struct BoundingBoxNode<Oriented, PerSubpath, Padding> {
+	oriented: Oriented,
+	per_subpath: PerSubpath,
+	padding: Padding,
+}
 
+/// Outlines the shape with a rectangle in document space, grown outwards on every side by the padding (or shrunk by a negative padding).
+///
+/// The oriented box is turned to whichever angle gives it the smallest area, rather than lining up with the document's axes.
+/// Each subpath can instead get a box of its own, such as for backgrounds behind the separate words of a label.
 #[node_macro::node_fn(BoundingBoxNode)]
-fn generate_bounding_box(vector_data: VectorData) -> VectorData {
-	let bounding_box = vector_data.bounding_box().unwrap();
-	VectorData::from_subpath(Subpath::new_rect(
-		vector_data.transform.transform_point2(bounding_box[0]),
-		vector_data.transform.transform_point2(bounding_box[1]),
-	))
+fn generate_bounding_box(vector_data: VectorData, oriented: bool, per_subpath: bool, padding: f64) -> VectorData {
+	let transform = vector_data.transform;
+	let subpaths = vector_data.stroke_bezier_paths().map(|mut subpath| {
+		subpath.apply_transform(transform);
+		subpath
+	});
+	let groups = if per_subpath {
+		subpaths.map(|subpath| vec![subpath]).collect()
+	} else {
+		vec![subpaths.collect::<Vec<_>>()]
+	};
+
+	let mut result = VectorData::empty();
+	for subpaths in groups {
+		let rotation = if oriented { smallest_box_rotation(&subpaths) } else { 0. };
+		let to_box = DAffine2::from_angle(-rotation);
+		let Some([min, max]) = subpaths
+			.iter()
+			.filter_map(|subpath| subpath.bounding_box_with_transform(to_box))
+			.reduce(|[a_min, a_max], [b_min, b_max]| [a_min.min(b_min), a_max.max(b_max)])
+		else {
+			continue;
+		};
+
+		let center = (min + max) / 2.;
+		let half_size = ((max - min) / 2. + padding).max(DVec2::ZERO);
+		let mut rectangle = Subpath::<PointId>::new_rect(center - half_size, center + half_size);
+		rectangle.apply_transform(DAffine2::from_angle(rotation));
+		result.append_subpath(rectangle);
+	}
+	result
+}
+
+/// Finds the angle at which an upright box around the subpaths, turned by that angle, has the least area.
+///
+/// The smallest box always has a side along an edge of the convex hull, so only the angles of the hull's edges need trying.
+fn smallest_box_rotation(subpaths: &[Subpath<PointId>]) -> f64 {
+	let points = subpaths
+		.iter()
+		.flat_map(|subpath| flatten_subpath(subpath, DAffine2::IDENTITY, 16).into_iter().chain(subpath.anchors()))
+		.collect::<Vec<_>>();
+	let hull = convex_hull(points);
+
+	let area = |rotation: f64| {
+		let to_box = DAffine2::from_angle(-rotation);
+		let bounds = subpaths.iter().filter_map(|subpath| subpath.bounding_box_with_transform(to_box));
+		bounds
+			.reduce(|[a_min, a_max], [b_min, b_max]| [a_min.min(b_min), a_max.max(b_max)])
+			.map_or(0., |[min, max]| (max - min).x * (max - min).y)
+	};
+	let edge_angles = hull
+		.iter()
+		.zip(hull.iter().cycle().skip(1))
+		.map(|(&start, &end)| (end - start).y.atan2((end - start).x).rem_euclid(core::f64::consts::FRAC_PI_2));
+	edge_angles
+		.fold((0., area(0.)), |smallest, angle| {
+			let angle_area = area(angle);
+			if angle_area < smallest.1 - 1e-9 {
+				(angle, angle_area)
+			} else {
+				smallest
+			}
+		})
+		.0
+}
+
+/// Finds the corners of the smallest convex polygon containing all the points, in counterclockwise order, using Andrew's monotone chain algorithm.
+fn convex_hull(mut points: Vec<DVec2>) -> Vec<DVec2> {
+	points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+	points.dedup();
+	if points.len() < 3 {
+		return points;
+	}
+
+	// The lower half of the hull is found from left to right, then the upper half from right to left
+	let mut hull: Vec<DVec2> = Vec::with_capacity(points.len() + 1);
+	let turns_left = |hull: &[DVec2], point: DVec2| (hull[hull.len() - 1] - hull[hull.len() - 2]).perp_dot(point - hull[hull.len() - 2]) > 0.;
+	for &point in &points {
+		while hull.len() >= 2 && !turns_left(&hull, point) {
+			hull.pop();
+		}
+		hull.push(point);
+	}
+	let lower_length = hull.len() + 1;
+	for &point in points.iter().rev().skip(1) {
+		while hull.len() >= lower_length && !turns_left(&hull, point) {
+			hull.pop();
+		}
+		hull.push(point);
+	}
+	// The upper half ends back at the first point
+	hull.pop();
+	hull
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -141,18 +816,32 @@ pub struct SolidifyStrokeNode;
 fn solidify_stroke(vector_data: VectorData) -> VectorData {
 	// Grab what we need from original data.
 	let VectorData { transform, style, .. } = &vector_data;
-	let subpaths = vector_data.stroke_bezier_paths();
+	let subpaths = vector_data.stroke_bezier_paths().collect::<Vec<_>>();
 	let mut result = VectorData::empty();
 
+	// Like when rendering, the stroke is only aligned inside or outside of shapes made entirely of closed subpaths, and never when tapered
+	let alignment = match style.stroke() {
+		Some(stroke) if stroke.width_profile.is_empty() && !subpaths.is_empty() && subpaths.iter().all(|subpath| subpath.closed()) => stroke.alignment,
+		_ => StrokeAlignment::Center,
+	};
+
 	// Perform operation on all subpaths in this shape.
 	for mut subpath in subpaths {
 		let stroke = style.stroke().unwrap();
 		let transform = transform.clone();
 		subpath.apply_transform(transform);
 
+		if !stroke.width_profile.is_empty() {
+			for contour in stroke.tapered_outline(&subpath, DAffine2::IDENTITY) {
+				result.append_subpath(contour);
+			}
+			continue;
+		}
+
 		// Taking the existing stroke data and passing it to Bezier-rs to generate new paths.
 		let subpath_out = subpath.outline(
-			stroke.weight / 2., // Diameter to radius.
+			// Diameter to radius, or the whole weight for a stroke that's entirely on one side of the path.
+			if alignment == StrokeAlignment::Center { stroke.weight / 2. } else { stroke.weight },
 			match stroke.line_join {
 				crate::vector::style::LineJoin::Miter => Join::Miter(Some(stroke.line_join_miter_limit)),
 				crate::vector::style::LineJoin::Bevel => Join::Bevel,
@@ -166,7 +855,14 @@ fn solidify_stroke(vector_data: VectorData) -> VectorData {
 		);
 
 		// This is where we determine whether we have a closed or open path. Ex: Oval vs line segment.
-		if subpath_out.1.is_some() {
+		if let (Some(other), true) = (&subpath_out.1, alignment != StrokeAlignment::Center) {
+			// An aligned stroke spans from the path itself to whichever of the two offset contours is on its side, telling them apart by the size of their bounds
+			let area = |subpath: &Subpath<PointId>| subpath.bounding_box().map_or(0., |[min, max]| (max - min).x * (max - min).y);
+			let (outer, inner) = if area(&subpath_out.0) >= area(other) { (&subpath_out.0, other) } else { (other, &subpath_out.0) };
+			let contour = if alignment == StrokeAlignment::Inside { inner } else { outer };
+			result.append_subpath(contour.clone());
+			result.append_subpath(subpath);
+		} else if subpath_out.1.is_some() {
 			// Two closed subpaths, closed shape. Add both subpaths.
 			result.append_subpath(subpath_out.0);
 			result.append_subpath(subpath_out.1.unwrap());
@@ -178,9 +874,13 @@ fn solidify_stroke(vector_data: VectorData) -> VectorData {
 
 	// We set our fill to our stroke's color, then clear our stroke.
 	if let Some(stroke) = vector_data.style.stroke() {
-		result.style.set_fill(Fill::solid_or_none(stroke.color));
+		result.style.set_fill(stroke.paint);
 		result.style.set_stroke(Stroke::default());
 	}
+	// The path and its offset contour of an aligned stroke don't necessarily wind in opposite directions, so the area between them is filled by the even-odd rule
+	if alignment != StrokeAlignment::Center {
+		result.style.set_fill_rule(FillRule::EvenOdd);
+	}
 
 	result
 }
@@ -200,53 +900,179 @@ impl ConcatElement for GraphicGroup {
 	}
 }
 
+impl ConcatElement for VectorInstances {
+	fn concat(&mut self, other: &Self, transform: DAffine2) {
+		let instances = other.instances.iter().map(|&instance| transform * other.transform * instance);
+		if self.instances.is_empty() {
+			self.geometry.clone_from(&other.geometry);
+			self.instances.extend(instances);
+		} else if self.geometry == other.geometry {
+			self.instances.extend(instances);
+		} else {
+			// Instances of different geometry can't share it, so everything is merged into a single shape instead
+			let mut geometry = self.flatten();
+			geometry.concat(&other.flatten(), transform);
+			*self = VectorInstances::new(geometry);
+		}
+	}
+}
+
+/// Artwork made up of alternative variants that [`CopyToPoints`] can choose between for each point.
+pub trait InstanceVariants: Sized {
+	/// Splits the artwork into its variants, in order, each keeping its placement within the whole.
+	fn variants(&self) -> Vec<Self>;
+}
+
+impl InstanceVariants for VectorData {
+	fn variants(&self) -> Vec<Self> {
+		vec![self.clone()]
+	}
+}
+
+impl InstanceVariants for VectorInstances {
+	fn variants(&self) -> Vec<Self> {
+		vec![self.clone()]
+	}
+}
+
+impl InstanceVariants for GraphicGroup {
+	fn variants(&self) -> Vec<Self> {
+		self.iter()
+			.map(|element| {
+				let mut variant = GraphicGroup::EMPTY;
+				variant.transform = self.transform;
+				variant.alpha_blending = self.alpha_blending;
+				variant.channel.clone_from(&self.channel);
+				variant.push(element.clone());
+				variant
+			})
+			.collect()
+	}
+}
+
+/// How [`CopyToPoints`] picks what to place at each point when the instance is made up of several variants, such as the children of a group.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum VariantSelection {
+	/// Place the whole instance at every point
+	#[default]
+	Whole,
+	/// Cycle through the variants in order, one per point
+	RoundRobin,
+	/// Pick a random variant for each point, based on the random seed
+	Random,
+}
+
+/// Name of the per-point attribute that [`CopyToPoints`] multiplies the scale of each instance by.
+pub const SCALE_ATTRIBUTE: &str = "scale";
+/// Name of the per-point attribute that [`CopyToPoints`] adds to the rotation of each instance, in degrees.
+pub const ROTATION_ATTRIBUTE: &str = "rotation";
+/// Name of the per-point attribute that [`CopyToPoints`] adds to the position of each instance.
+pub const OFFSET_ATTRIBUTE: &str = "offset";
+/// Name of the per-point attribute, written by [`SamplePoints`], holding the unit direction of the path at each point.
+pub const TANGENT_ATTRIBUTE: &str = "tangent";
+
 #[derive(Debug, Clone, Copy)]
-pub struct CopyToPoints<Points, Instance, RandomScaleMin, RandomScaleMax, RandomScaleBias, RandomRotation> {
+pub struct CopyToPoints<Points, Instance, RandomScaleMin, RandomScaleMax, RandomScaleBias, RandomRotationMin, RandomRotationMax, RandomSeed, AlignToPath, VariantSelection> {
 	points: Points,
 	instance: Instance,
 	random_scale_min: RandomScaleMin,
 	random_scale_max: RandomScaleMax,
 	random_scale_bias: RandomScaleBias,
-	random_rotation: RandomRotation,
+	random_rotation_min: RandomRotationMin,
+	random_rotation_max: RandomRotationMax,
+	random_seed: RandomSeed,
+	align_to_path: AlignToPath,
+	variant_selection: VariantSelection,
 }
 
+/// Places a copy of the instance at each point.
+///
+/// Each copy is given a random scale and rotation (in degrees) between the respective minimum and maximum. The randomness is deterministic for a given seed, so the copies only change when the seed does.
+///
+/// If the instance is made up of variants (such as a group of differently shaped leaves), the variant selection can place just one of them at each point. Every copy is centered on its point.
+///
+/// Points carrying the [`SCALE_ATTRIBUTE`], [`ROTATION_ATTRIBUTE`], or [`OFFSET_ATTRIBUTE`] per-point attributes have their copy scaled, rotated, or moved accordingly, on top of any randomization.
+///
+/// When aligning to the path, each copy is turned so that its x-axis follows the direction of the path at its point. That direction comes from the [`TANGENT_ATTRIBUTE`] if the points have one (such as those from [`SamplePoints`]),
+/// otherwise from the segments meeting at each point. A rotation attribute of 90° then lines the copies up with the normal instead.
 #[node_macro::node_fn(CopyToPoints)]
-async fn copy_to_points<I: GraphicElementRendered + Default + ConcatElement + TransformMut, FP: Future<Output = VectorData>, FI: Future<Output = I>>(
+async fn copy_to_points<I: GraphicElementRendered + Default + ConcatElement + InstanceVariants + TransformMut, FP: Future<Output = VectorData>, FI: Future<Output = I>>(
 	footprint: Footprint,
 	points: impl Node<Footprint, Output = FP>,
 	instance: impl Node<Footprint, Output = FI>,
 	random_scale_min: f64,
 	random_scale_max: f64,
 	random_scale_bias: f64,
-	random_rotation: f64,
+	random_rotation_min: f64,
+	random_rotation_max: f64,
+	random_seed: u32,
+	align_to_path: bool,
+	variant_selection: VariantSelection,
 ) -> I {
 	let points = self.points.eval(footprint).await;
 	let instance = self.instance.eval(footprint).await;
 	let random_scale_difference = random_scale_max - random_scale_min;
+	let random_rotation_difference = random_rotation_max - random_rotation_min;
 
 	let points_list = points.point_domain.positions();
 
-	let instance_bounding_box = instance.bounding_box(DAffine2::IDENTITY).unwrap_or_default();
-	let instance_center = -0.5 * (instance_bounding_box[0] + instance_bounding_box[1]);
+	let variants = match variant_selection {
+		VariantSelection::Whole => Vec::new(),
+		VariantSelection::RoundRobin | VariantSelection::Random => instance.variants(),
+	};
+	let variants = if variants.is_empty() { vec![instance] } else { variants };
+	let variant_centers = variants
+		.iter()
+		.map(|variant| {
+			let bounding_box = variant.bounding_box(DAffine2::IDENTITY).unwrap_or_default();
+			-0.5 * (bounding_box[0] + bounding_box[1])
+		})
+		.collect::<Vec<_>>();
 
-	let mut scale_rng = rand::rngs::StdRng::seed_from_u64(0);
-	let mut rotation_rng = rand::rngs::StdRng::seed_from_u64(0);
+	// Separate generators mean that changing one range doesn't reshuffle the other, and seeding them differently keeps the largest copies from always being the most rotated
+	let mut scale_rng = rand::rngs::StdRng::seed_from_u64(random_seed as u64);
+	let mut rotation_rng = rand::rngs::StdRng::seed_from_u64(random_seed as u64 + (1 << 32));
+	let mut variant_rng = rand::rngs::StdRng::seed_from_u64(random_seed as u64 + (2 << 32));
 
 	let do_scale = random_scale_difference.abs() > 1e-6;
-	let do_rotation = random_rotation.abs() > 1e-6;
+	let do_rotation = random_rotation_difference.abs() > 1e-6;
+
+	let scale_attribute = match points.point_domain.attribute(SCALE_ATTRIBUTE) {
+		Some(AttributeValues::F64(values)) => values.as_slice(),
+		_ => &[],
+	};
+	let rotation_attribute = match points.point_domain.attribute(ROTATION_ATTRIBUTE) {
+		Some(AttributeValues::F64(values)) => values.as_slice(),
+		_ => &[],
+	};
+	let offset_attribute = match points.point_domain.attribute(OFFSET_ATTRIBUTE) {
+		Some(AttributeValues::DVec2(values)) => values.as_slice(),
+		_ => &[],
+	};
+	let tangents = match points.point_domain.attribute(TANGENT_ATTRIBUTE) {
+		_ if !align_to_path => Vec::new(),
+		Some(AttributeValues::DVec2(values)) => values.clone(),
+		_ => path_tangents(&points),
+	};
 
 	let mut result = I::default();
-	for &point in points_list {
-		let center_transform = DAffine2::from_translation(instance_center);
+	for (index, &point) in points_list.iter().enumerate() {
+		let variant_index = match variant_selection {
+			VariantSelection::Random => variant_rng.gen_range(0..variants.len()),
+			_ => index % variants.len(),
+		};
+		let center_transform = DAffine2::from_translation(variant_centers[variant_index]);
 
-		let translation = points.transform.transform_point2(point);
+		let offset = offset_attribute.get(index).copied().unwrap_or_default();
+		let translation = points.transform.transform_point2(point + offset);
 
-		let rotation = if do_rotation {
-			let degrees = (rotation_rng.gen::<f64>() - 0.5) * random_rotation;
-			degrees / 360. * std::f64::consts::TAU
+		let degrees = if do_rotation {
+			random_rotation_min + rotation_rng.gen::<f64>() * random_rotation_difference
 		} else {
-			0.
+			random_rotation_min
 		};
+		let rotation = degrees.to_radians();
 
 		let scale = if do_scale {
 			if random_scale_bias.abs() < 1e-6 {
@@ -261,8 +1087,17 @@ async fn copy_to_points<I: GraphicElementRendered + Default + ConcatElement + Tr
 		} else {
 			random_scale_min
 		};
+		let scale = scale * scale_attribute.get(index).copied().unwrap_or(1.);
+		let rotation = rotation + rotation_attribute.get(index).copied().unwrap_or_default().to_radians();
+		let rotation = match tangents.get(index).map(|&tangent| points.transform.transform_vector2(tangent)) {
+			Some(tangent) if tangent != DVec2::ZERO => rotation + tangent.y.atan2(tangent.x),
+			_ => rotation,
+		};
 
-		result.concat(&instance, DAffine2::from_scale_angle_translation(DVec2::splat(scale), rotation, translation) * center_transform);
+		result.concat(
+			&variants[variant_index],
+			DAffine2::from_scale_angle_translation(DVec2::splat(scale), rotation, translation) * center_transform,
+		);
 	}
 
 	result
@@ -295,6 +1130,8 @@ async fn sample_points<FV: Future<Output = VectorData>, FL: Future<Output = Vec<
 
 	let mut result = VectorData::empty();
 	result.transform = vector_data.transform;
+	let inverse_transform = vector_data.transform.inverse();
+	let mut tangents = Vec::new();
 
 	while let Some((index, (segment, _, _, mut last_end))) = bezier.next() {
 		let mut lengths = vec![(segment, lengths_of_segments_of_subpaths.get(index).copied().unwrap_or_default())];
@@ -345,13 +1182,41 @@ async fn sample_points<FV: Future<Output = VectorData>, FL: Future<Output = Vec<
 
 			let parametric_t = segment.euclidean_to_parametric_with_total_length((total_distance - total_length_before) / length, 0.001, length);
 			let point = segment.evaluate(TValue::Parametric(parametric_t));
-			result.point_domain.push(PointId::generate(), vector_data.transform.inverse().transform_point2(point));
+			result.point_domain.push(PointId::generate(), inverse_transform.transform_point2(point));
+			tangents.push(inverse_transform.transform_vector2(bezier_tangent(&segment, parametric_t)).normalize_or_zero());
 		}
 	}
 
+	result.point_domain.set_attribute(TANGENT_ATTRIBUTE, AttributeValues::DVec2(tangents));
 	result
 }
 
+/// The unit direction of the curve at the parametric `t`-value.
+fn bezier_tangent(bezier: &Bezier, t: f64) -> DVec2 {
+	let tangent = bezier.tangent(TValue::Parametric(t));
+	if tangent != DVec2::ZERO {
+		return tangent;
+	}
+	// Handles that coincide with their anchors have no derivative at the anchor, so use the direction towards the nearby points instead
+	let [before, after] = [t - 1e-3, t + 1e-3].map(|t| bezier.evaluate(TValue::Parametric(t.clamp(0., 1.))));
+	(after - before).normalize_or_zero()
+}
+
+/// Finds the unit direction of the path through each point by averaging the segments that start or end there. Points that aren't on any segment get a zero vector.
+fn path_tangents(vector_data: &VectorData) -> Vec<DVec2> {
+	let indices = vector_data.point_domain.ids().iter().enumerate().map(|(index, &id)| (id, index)).collect::<HashMap<_, _>>();
+	let mut tangents = vec![DVec2::ZERO; indices.len()];
+	for (_, bezier, start, end) in vector_data.segment_bezier_iter() {
+		for (id, t) in [(start, 0.), (end, 1.)] {
+			if let Some(&index) = indices.get(&id) {
+				tangents[index] += bezier_tangent(&bezier, t);
+			}
+		}
+	}
+	tangents.iter_mut().for_each(|tangent| *tangent = tangent.normalize_or_zero());
+	tangents
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct PoissonDiskPoints<SeparationDiskDiameter> {
 	separation_disk_diameter: SeparationDiskDiameter,
@@ -377,12 +1242,74 @@ fn poisson_disk_points(vector_data: VectorData, separation_disk_diameter: f64) -
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct LengthsOfSegmentsOfSubpaths;
+pub struct PointsInBoundsNode<Spacing, Inset, Stagger, InsideShape> {
+	spacing: Spacing,
+	inset: Inset,
+	stagger: Stagger,
+	inside_shape: InsideShape,
+}
 
-#[node_macro::node_fn(LengthsOfSegmentsOfSubpaths)]
-fn lengths_of_segments_of_subpaths(vector_data: VectorData) -> Vec<f64> {
-	vector_data
-		.segment_bezier_iter()
+/// Fills the bounding box of the shape with a regular lattice of points the spacing apart, ready to place copies onto without drawing a rectangle by hand.
+///
+/// The bounding box is first shrunk by the inset on every side, and the lattice is centered within it. Staggering shifts every other row over by half of
+/// the spacing, giving a triangular lattice. When kept inside the shape, only the points within its closed subpaths remain, following its fill rule.
+///
+/// Every point is given the [`ROW_ATTRIBUTE`] and [`COLUMN_ATTRIBUTE`] of its place in the lattice.
+#[node_macro::node_fn(PointsInBoundsNode)]
+fn points_in_bounds(vector_data: VectorData, spacing: DVec2, inset: f64, stagger: bool, inside_shape: bool) -> VectorData {
+	// Number of line segments each curved segment is flattened into when testing which points are inside the shape
+	const FLATTENING_STEPS: usize = 16;
+	// Most points in the lattice, which keeps a tiny spacing from generating an endless number of them
+	const MAX_POINTS: f64 = 250_000.;
+
+	let mut result = VectorData::empty();
+	result.transform = vector_data.transform;
+	let Some([min, max]) = vector_data.bounding_box_with_transform(vector_data.transform) else {
+		return result;
+	};
+	let (min, max) = (min + inset, max - inset);
+	if spacing.min_element() <= 0. || min.cmpgt(max).any() {
+		return result;
+	}
+
+	let size = max - min;
+	let spacing = spacing.max(DVec2::splat((size.x * size.y / MAX_POINTS).sqrt())).max(size / MAX_POINTS);
+	let [columns, rows] = [size.x / spacing.x, size.y / spacing.y].map(|count| count.floor() as usize + 1);
+	let start = (min + max) / 2. - DVec2::new((columns - 1) as f64, (rows - 1) as f64) * spacing / 2.;
+
+	let polygons = inside_shape.then(|| flattened_regions(&vector_data, FLATTENING_STEPS));
+	let fill_rule = vector_data.style.fill_rule();
+	let to_local = vector_data.transform.inverse();
+	let (mut point_rows, mut point_columns) = (Vec::new(), Vec::new());
+	for row in 0..rows {
+		let shift = if stagger && row % 2 == 1 { spacing.x / 2. } else { 0. };
+		for column in 0..columns {
+			let point = start + DVec2::new(column as f64 * spacing.x + shift, row as f64 * spacing.y);
+			// Shifted rows would run past the edge of the box by half of the spacing
+			if point.x > max.x + 1e-9 {
+				continue;
+			}
+			if polygons.as_ref().is_some_and(|polygons| !covers_point(polygons, fill_rule, point)) {
+				continue;
+			}
+			result.point_domain.push(PointId::generate(), to_local.transform_point2(point));
+			point_rows.push(row as f64);
+			point_columns.push(column as f64);
+		}
+	}
+
+	result.point_domain.set_attribute(ROW_ATTRIBUTE, AttributeValues::F64(point_rows));
+	result.point_domain.set_attribute(COLUMN_ATTRIBUTE, AttributeValues::F64(point_columns));
+	result
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LengthsOfSegmentsOfSubpaths;
+
+#[node_macro::node_fn(LengthsOfSegmentsOfSubpaths)]
+fn lengths_of_segments_of_subpaths(vector_data: VectorData) -> Vec<f64> {
+	vector_data
+		.segment_bezier_iter()
 		.map(|(_id, bezier, _, _)| bezier.apply_transformation(|point| vector_data.transform.transform_point2(point)).length(None))
 		.collect()
 }
@@ -408,9 +1335,421 @@ fn splines_from_points(mut vector_data: VectorData) -> VectorData {
 			.push(SegmentId::generate(), points.ids()[start_index], points.ids()[end_index], handles, StrokeId::generate())
 	}
 
+	// The spline passes smoothly through every point between its ends
+	let interior = points.ids().iter().skip(1).take(points.ids().len().saturating_sub(2));
+	vector_data.colinear_manipulators = interior.map(|&id| id.into()).collect();
+
+	vector_data
+}
+
+pub struct SetHandleMirroringNode<Colinear, AngleTolerance> {
+	colinear: Colinear,
+	angle_tolerance: AngleTolerance,
+}
+
+/// Locks the handles of smooth anchors at 180° from one another, or frees the handles of every anchor, so editing the path by hand afterwards keeps (or no longer keeps) its curves smooth.
+///
+/// An anchor counts as smooth if its handles are within the angle tolerance, in degrees, of pointing in opposite directions. Their directions are then evened out to be exactly opposite.
+#[node_macro::node_fn(SetHandleMirroringNode)]
+fn set_handle_mirroring(mut vector_data: VectorData, colinear: bool, angle_tolerance: f64) -> VectorData {
+	vector_data.colinear_manipulators.clear();
+	if !colinear {
+		return vector_data;
+	}
+
+	let handles = vector_data.segment_domain.handles();
+	let smooth = vector_data.handle_pairs().into_iter().filter(|&(point, arriving, leaving)| {
+		let (Some(anchor), Some(in_handle), Some(out_handle)) = (vector_data.point_domain.pos_from_id(point), handles[arriving].end(), handles[leaving].start()) else {
+			return false;
+		};
+		let (in_direction, out_direction) = (in_handle - anchor, out_handle - anchor);
+		in_direction != DVec2::ZERO && out_direction != DVec2::ZERO && core::f64::consts::PI - in_direction.angle_between(out_direction).abs() <= angle_tolerance.to_radians()
+	});
+	vector_data.colinear_manipulators = smooth.map(|(point, _, _)| point.into()).collect();
+	vector_data.straighten_colinear_handles();
+
 	vector_data
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct FreezeNode;
+
+/// Bakes the shape as it is into manual geometry, marking every point with the [`MANUAL_ATTRIBUTE`] as if it had been drawn by hand, so later steps can tell it apart from geometry that's still generated.
+#[node_macro::node_fn(FreezeNode)]
+fn freeze(mut vector_data: VectorData) -> VectorData {
+	vector_data.set_manual(true);
+	vector_data
+}
+
+pub struct FitCurveNode<Tolerance, Closed> {
+	tolerance: Tolerance,
+	closed: Closed,
+}
+
+/// Fits as few cubic segments as it can through the points, in order, so that none of them is further than the tolerance from the curve, such as for turning a traced scan into clean outlines.
+///
+/// Unlike [`SplinesFromPointsNode`], the curve doesn't pass through every point: the anchors are picked from among the points using Schneider's algorithm from Graphics Gems.
+/// The tolerance is measured in document space. The anchors keep the IDs and attributes of the points they were picked from.
+#[node_macro::node_fn(FitCurveNode)]
+fn fit_curve(vector_data: VectorData, tolerance: f64, closed: bool) -> VectorData {
+	let transform = vector_data.transform;
+	let to_document = if transform.matrix2.determinant() == 0. { DAffine2::IDENTITY } else { transform };
+	let positions = vector_data.point_domain.positions();
+
+	// Points sitting on top of the one before them would give no direction for the curve to follow
+	let mut indices: Vec<usize> = Vec::new();
+	let mut points: Vec<DVec2> = Vec::new();
+	for (index, &position) in positions.iter().enumerate() {
+		let point = to_document.transform_point2(position);
+		if !points.last().is_some_and(|last| last.distance(point) <= 1e-9) {
+			indices.push(index);
+			points.push(point);
+		}
+	}
+	if closed && points.len() > 2 && points[0].distance(points[points.len() - 1]) <= 1e-9 {
+		indices.pop();
+		points.pop();
+	}
+
+	let mut result = VectorData::empty();
+	result.transform = transform;
+	result.style = vector_data.style.clone();
+	result.alpha_blending = vector_data.alpha_blending;
+	if points.len() < 2 {
+		return result;
+	}
+
+	let curves = fit_path(&points, closed, tolerance);
+
+	let ids = vector_data.point_domain.ids();
+	let from_document = to_document.inverse();
+	let anchors = core::iter::once(0).chain(curves.iter().map(|&(end, _)| end)).map(|index| indices[index]).collect::<Vec<_>>();
+	// A closed curve ends back at its first anchor, which isn't added again, even when a single cubic loops all the way around from it
+	let anchors = if anchors.len() > 1 && anchors[0] == anchors[anchors.len() - 1] {
+		&anchors[..anchors.len() - 1]
+	} else {
+		&anchors[..]
+	};
+	for &index in anchors {
+		result.point_domain.push(ids[index], positions[index]);
+	}
+	for (name, values) in vector_data.point_domain.attributes() {
+		result.point_domain.set_attribute(name, values.select(anchors));
+	}
+
+	// The cubics either side of each anchor between them share a tangent, as does the first anchor of a closed curve
+	let smooth = if closed { anchors } else { &anchors[1..anchors.len() - 1] };
+	result.colinear_manipulators = smooth.iter().map(|&index| ids[index].into()).collect();
+
+	let mut start = indices[0];
+	let mut segment_ids = Vec::new();
+	for (end, [_, handle_start, handle_end, _]) in curves {
+		let end = indices[end];
+		let handle_start = from_document.transform_point2(handle_start);
+		let handle_end = from_document.transform_point2(handle_end);
+		let id = SegmentId::generate();
+		result
+			.segment_domain
+			.push(id, ids[start], ids[end], BezierHandles::Cubic { handle_start, handle_end }, StrokeId::generate());
+		segment_ids.push(id);
+		start = end;
+	}
+	if closed && anchors.len() > 1 {
+		if let (Some(&first), Some(&last)) = (segment_ids.first(), segment_ids.last()) {
+			result.region_domain.push(RegionId::generate(), first..=last, FillId::generate());
+		}
+	}
+
+	result
+}
+
+/// Fits cubics through the points in order, coming back around to the first point if closed, along with the index of the point each cubic ends at.
+fn fit_path(points: &[DVec2], closed: bool, tolerance: f64) -> Vec<(usize, [DVec2; 4])> {
+	let mut curves = Vec::new();
+	if points.len() < 2 {
+		return curves;
+	}
+
+	// A closed curve comes back around to the first point, where it passes straight through in the direction between its neighbours
+	if closed && points.len() > 2 {
+		let before = points.iter().rev().copied().chain(core::iter::once(points[0])).collect::<Vec<_>>();
+		let tangent = (estimated_tangent(points) - estimated_tangent(&before)).normalize_or_zero();
+		let around = points.iter().copied().chain(core::iter::once(points[0])).collect::<Vec<_>>();
+		fit_cubics(&around, 0, tangent, -tangent, tolerance.max(1e-6), &mut curves);
+		for (end, _) in &mut curves {
+			*end %= points.len();
+		}
+	} else {
+		let reversed = points.iter().rev().copied().collect::<Vec<_>>();
+		fit_cubics(points, 0, estimated_tangent(points), estimated_tangent(&reversed), tolerance.max(1e-6), &mut curves);
+	}
+	curves
+}
+
+/// Fits cubics through the points from the first to the last, leaving the first along the start tangent and arriving at the last against the end tangent.
+/// Each fitted cubic is added along with the index (offset by `first_index`) of the point it ends at.
+fn fit_cubics(points: &[DVec2], first_index: usize, start_tangent: DVec2, end_tangent: DVec2, tolerance: f64, curves: &mut Vec<(usize, [DVec2; 4])>) {
+	let last = points.len() - 1;
+	let (start, end) = (points[0], points[last]);
+
+	// Two points are joined by a curve which follows the tangents for a third of the way in from each end
+	if points.len() == 2 {
+		let length = start.distance(end) / 3.;
+		curves.push((first_index + last, [start, start + start_tangent * length, end + end_tangent * length, end]));
+		return;
+	}
+
+	// The points are first placed along the curve by the distance travelled between them, which Newton's method then improves while the fit is close
+	let mut parameters = chord_length_parameters(points);
+	let mut cubic = least_squares_cubic(points, &parameters, start_tangent, end_tangent);
+	let (mut error, mut split) = largest_error(points, &parameters, &cubic);
+	if error <= tolerance {
+		curves.push((first_index + last, cubic));
+		return;
+	}
+	if error <= tolerance * 4. {
+		for _ in 0..4 {
+			for (parameter, &point) in parameters.iter_mut().zip(points) {
+				*parameter = newton_raphson_root(&cubic, point, *parameter);
+			}
+			cubic = least_squares_cubic(points, &parameters, start_tangent, end_tangent);
+			(error, split) = largest_error(points, &parameters, &cubic);
+			if error <= tolerance {
+				curves.push((first_index + last, cubic));
+				return;
+			}
+		}
+	}
+
+	// Otherwise the points are split where the fit was worst, with both halves sharing a tangent there so the curve stays smooth
+	let split = split.clamp(1, last - 1);
+	let before = points[..=split].iter().rev().copied().collect::<Vec<_>>();
+	let mut center_tangent = (estimated_tangent(&before) - estimated_tangent(&points[split..])).normalize_or_zero();
+	if center_tangent == DVec2::ZERO {
+		center_tangent = (points[split - 1] - points[split]).perp().normalize_or_zero();
+	}
+	fit_cubics(&points[..=split], first_index, start_tangent, center_tangent, tolerance, curves);
+	fit_cubics(&points[split..], first_index + split, -center_tangent, end_tangent, tolerance, curves);
+}
+
+/// Finds the direction leaving the first point, from the few points after it so that noise in the next one doesn't throw it off.
+fn estimated_tangent(points: &[DVec2]) -> DVec2 {
+	let direction = points.iter().skip(1).take(3).map(|&point| (point - points[0]).normalize_or_zero()).sum::<DVec2>();
+	direction.normalize_or_zero()
+}
+
+fn chord_length_parameters(points: &[DVec2]) -> Vec<f64> {
+	let mut distance = 0.;
+	let mut parameters = core::iter::once(0.)
+		.chain(points.windows(2).map(|pair| {
+			distance += pair[0].distance(pair[1]);
+			distance
+		}))
+		.collect::<Vec<_>>();
+	for parameter in &mut parameters {
+		*parameter /= distance;
+	}
+	parameters
+}
+
+/// Finds the lengths of the handles, along the given tangents, which put the curve closest to the points at their parameters.
+fn least_squares_cubic(points: &[DVec2], parameters: &[f64], start_tangent: DVec2, end_tangent: DVec2) -> [DVec2; 4] {
+	let (start, end) = (points[0], points[points.len() - 1]);
+	let bernstein = |t: f64| [(1. - t).powi(3), 3. * t * (1. - t).powi(2), 3. * t * t * (1. - t), t.powi(3)];
+
+	let mut c = [[0.; 2]; 2];
+	let mut x = [0.; 2];
+	for (&point, &t) in points.iter().zip(parameters) {
+		let [b0, b1, b2, b3] = bernstein(t);
+		let a = [start_tangent * b1, end_tangent * b2];
+		c[0][0] += a[0].dot(a[0]);
+		c[0][1] += a[0].dot(a[1]);
+		c[1][1] += a[1].dot(a[1]);
+		let remainder = point - (start * (b0 + b1) + end * (b2 + b3));
+		x[0] += a[0].dot(remainder);
+		x[1] += a[1].dot(remainder);
+	}
+	c[1][0] = c[0][1];
+
+	let determinant = c[0][0] * c[1][1] - c[0][1] * c[1][0];
+	let (start_length, end_length) = if determinant.abs() > 1e-12 {
+		((x[0] * c[1][1] - x[1] * c[0][1]) / determinant, (c[0][0] * x[1] - c[1][0] * x[0]) / determinant)
+	} else {
+		(0., 0.)
+	};
+
+	// Handles that would point backwards or vanish fall back to a third of the way between the ends
+	let chord = start.distance(end);
+	let epsilon = 1e-6 * chord;
+	if start_length < epsilon || end_length < epsilon {
+		return [start, start + start_tangent * chord / 3., end + end_tangent * chord / 3., end];
+	}
+	[start, start + start_tangent * start_length, end + end_tangent * end_length, end]
+}
+
+fn cubic_point(cubic: &[DVec2; 4], t: f64) -> DVec2 {
+	let s = 1. - t;
+	cubic[0] * s * s * s + cubic[1] * 3. * s * s * t + cubic[2] * 3. * s * t * t + cubic[3] * t * t * t
+}
+
+/// Moves the parameter along the curve towards the point on it closest to the given point.
+fn newton_raphson_root(cubic: &[DVec2; 4], point: DVec2, t: f64) -> f64 {
+	let first = [(cubic[1] - cubic[0]) * 3., (cubic[2] - cubic[1]) * 3., (cubic[3] - cubic[2]) * 3.];
+	let second = [(first[1] - first[0]) * 2., (first[2] - first[1]) * 2.];
+	let s = 1. - t;
+
+	let difference = cubic_point(cubic, t) - point;
+	let derivative = first[0] * s * s + first[1] * 2. * s * t + first[2] * t * t;
+	let second_derivative = second[0] * s + second[1] * t;
+
+	let denominator = derivative.dot(derivative) + difference.dot(second_derivative);
+	if denominator.abs() < 1e-12 {
+		return t;
+	}
+	t - difference.dot(derivative) / denominator
+}
+
+/// Finds the furthest any of the points is from the curve at its parameter, and which point that is.
+fn largest_error(points: &[DVec2], parameters: &[f64], cubic: &[DVec2; 4]) -> (f64, usize) {
+	points
+		.iter()
+		.zip(parameters)
+		.enumerate()
+		.map(|(index, (&point, &t))| (cubic_point(cubic, t).distance(point), index))
+		.fold((0., points.len() / 2), |largest, current| if current.0 > largest.0 { current } else { largest })
+}
+
+/// How many samples are taken along each segment of a path to find the sides of its [`VariableOffsetNode`] outline.
+const VARIABLE_OFFSET_STEPS: usize = 32;
+/// How far, in document space, the fitted sides of a [`VariableOffsetNode`] outline may stray from the sampled offset points.
+const VARIABLE_OFFSET_TOLERANCE: f64 = 0.05;
+
+#[derive(Debug, Clone, Copy)]
+pub struct VariableOffsetNode<Distance, Attribute, Taper, Envelope> {
+	distance: Distance,
+	attribute: Attribute,
+	taper: Taper,
+	envelope: Envelope,
+}
+
+/// Outlines each subpath with a filled shape whose sides are offset from it by a distance that varies along its length, giving brush-like strokes.
+///
+/// The distance is multiplied by the named per-point attribute, blended between the anchors, when the path has it. When tapering, it's also multiplied by
+/// the envelope curve, which is read from 0 at the ends of an open subpath to 1 halfway along it, so the default curve narrows to a point at both ends.
+/// Closed subpaths give an outer and an inner loop and aren't tapered. The outline is filled with the color of the path's stroke, or its fill if it has no stroke.
+#[node_macro::node_fn(VariableOffsetNode)]
+fn variable_offset(vector_data: VectorData, distance: f64, attribute: String, taper: bool, envelope: Curve) -> VectorData {
+	let transform = vector_data.transform;
+	let to_document = if transform.matrix2.determinant() == 0. { DAffine2::IDENTITY } else { transform };
+	let indices = vector_data.point_domain.ids().iter().enumerate().map(|(index, &id)| (id, index)).collect::<HashMap<_, _>>();
+	let values = vector_data.point_domain.attribute(&attribute);
+	let scale_at = |id: PointId| values.zip(indices.get(&id)).and_then(|(values, &index)| values.scalar(index)).unwrap_or(1.);
+
+	let mut result = VectorData::empty();
+	result.transform = transform;
+	result.alpha_blending = vector_data.alpha_blending;
+	let color = vector_data.style.stroke().and_then(|stroke| stroke.color()).unwrap_or_else(|| vector_data.style.fill().color());
+	result.style.set_fill(Fill::Solid(color));
+
+	let from_document = to_document.inverse();
+	for mut subpath in vector_data.stroke_bezier_paths() {
+		subpath.apply_transform(to_document);
+		let closed = subpath.closed();
+		let groups = subpath.manipulator_groups();
+
+		// The position, unit normal, and attribute scale along each segment, including both its ends so that corners between segments are bevelled
+		let mut samples = Vec::new();
+		for (index, bezier) in subpath.iter().enumerate() {
+			let [start_scale, end_scale] = [index, (index + 1) % groups.len()].map(|index| scale_at(groups[index].id));
+			let steps = (0..=VARIABLE_OFFSET_STEPS).map(|step| step as f64 / VARIABLE_OFFSET_STEPS as f64);
+			let positions = steps.clone().map(|t| bezier.evaluate(TValue::Parametric(t))).collect::<Vec<_>>();
+			let length = positions.windows(2).map(|pair| pair[0].distance(pair[1])).sum::<f64>();
+
+			// The scale blends by the distance along the segment rather than its parameter, which can bunch up towards either end
+			let mut along = 0.;
+			for (step, (t, &position)) in steps.zip(&positions).enumerate() {
+				along += step.checked_sub(1).map_or(0., |previous| positions[previous].distance(position));
+				let fraction = if length > 0. { along / length } else { t };
+				let normal = bezier_tangent(&bezier, t).perp().normalize_or_zero();
+				samples.push((position, normal, start_scale + (end_scale - start_scale) * fraction));
+			}
+		}
+		if samples.len() < 2 {
+			continue;
+		}
+
+		let mut travelled = 0.;
+		let lengths = samples
+			.iter()
+			.enumerate()
+			.map(|(index, &(position, _, _))| {
+				travelled += index.checked_sub(1).map_or(0., |previous| samples[previous].0.distance(position));
+				travelled
+			})
+			.collect::<Vec<_>>();
+		let offsets = samples
+			.iter()
+			.zip(&lengths)
+			.map(|(&(_, normal, scale), &length)| {
+				let along = if travelled > 0. { length / travelled } else { 0. };
+				let envelope = if taper && !closed { envelope.evaluate(along.min(1. - along) * 2.) } else { 1. };
+				normal * distance * (scale * envelope).max(0.)
+			})
+			.collect::<Vec<_>>();
+		let [left, right] = [1., -1.].map(|side| {
+			let mut points: Vec<DVec2> = Vec::new();
+			for (&(position, _, _), &offset) in samples.iter().zip(&offsets) {
+				let point = position + offset * side;
+				if !points.last().is_some_and(|last| last.distance(point) <= 1e-9) {
+					points.push(point);
+				}
+			}
+			if closed && points.len() > 2 && points[0].distance(points[points.len() - 1]) <= 1e-9 {
+				points.pop();
+			}
+			points
+		});
+
+		let cubics = |points: &[DVec2]| {
+			fit_path(points, closed, VARIABLE_OFFSET_TOLERANCE)
+				.into_iter()
+				.map(|(_, [p1, p2, p3, p4])| Bezier::from_cubic_dvec2(p1, p2, p3, p4))
+		};
+		let reversed = |beziers: Vec<Bezier>| beziers.into_iter().rev().map(|bezier| bezier.reverse()).collect::<Vec<_>>();
+		let outlines = if closed {
+			// The inner loop runs the other way around so that it cuts a hole in the outer one
+			vec![cubics(&left).collect(), reversed(cubics(&right).collect())]
+		} else {
+			// The sides are joined across the ends, unless they already meet at a tapered point
+			let mut outline = cubics(&left).collect::<Vec<_>>();
+			let right = reversed(cubics(&right).collect());
+			let (Some(left_start), Some(left_end), Some(right_start), Some(right_end)) = (
+				outline.first().map(Bezier::start),
+				outline.last().map(Bezier::end),
+				right.first().map(Bezier::start),
+				right.last().map(Bezier::end),
+			) else {
+				continue;
+			};
+			if left_end.distance(right_start) > 1e-9 {
+				outline.push(Bezier::from_linear_dvec2(left_end, right_start));
+			}
+			outline.extend(right);
+			if right_end.distance(left_start) > 1e-9 {
+				outline.push(Bezier::from_linear_dvec2(right_end, left_start));
+			}
+			vec![outline]
+		};
+		for outline in outlines.into_iter().filter(|outline: &Vec<Bezier>| outline.len() > 1) {
+			let mut outline = Subpath::<ManipulatorGroupId>::from_beziers(&outline, true);
+			outline.apply_transform(from_document);
+			result.append_subpath(outline);
+		}
+	}
+
+	result
+}
+
 pub struct MorphNode<Source, Target, StartIndex, Time> {
 	source: Source,
 	target: Target,
@@ -507,81 +1846,3132 @@ async fn morph<SourceFuture: Future<Output = VectorData>, TargetFuture: Future<O
 	result
 }
 
-#[cfg(test)]
-mod test {
-	use super::*;
-	use crate::transform::CullNode;
-	use crate::value::ClonedNode;
+pub struct LatticeDeformNode<VectorData, Lattice, Columns, Rows, Subdivisions> {
+	vector_data: VectorData,
+	lattice: Lattice,
+	columns: Columns,
+	rows: Rows,
+	subdivisions: Subdivisions,
+}
 
-	use bezier_rs::Bezier;
+/// Free-form deformation of the vector data by a grid of `columns` × `rows` control points, read in row-major order from the points of the lattice.
+///
+/// The undeformed lattice spans the bounding box of the vector data, so a lattice that forms an evenly spaced grid over those bounds leaves the geometry unchanged.
+#[node_macro::node_fn(LatticeDeformNode)]
+async fn lattice_deform<VectorDataFuture: Future<Output = VectorData>, LatticeFuture: Future<Output = VectorData>>(
+	footprint: Footprint,
+	vector_data: impl Node<Footprint, Output = VectorDataFuture>,
+	lattice: impl Node<Footprint, Output = LatticeFuture>,
+	columns: u32,
+	rows: u32,
+	subdivisions: u32,
+) -> VectorData {
+	let vector_data = self.vector_data.eval(footprint).await;
+	let lattice = self.lattice.eval(footprint).await;
 
-	use std::pin::Pin;
+	let (columns, rows) = (columns as usize, rows as usize);
+	let control_points = lattice.point_domain.positions().iter().map(|&point| lattice.transform.transform_point2(point)).collect::<Vec<_>>();
+	if columns < 2 || rows < 2 || control_points.len() < columns * rows {
+		return vector_data;
+	}
+	let Some([min, max]) = vector_data.bounding_box_with_transform(vector_data.transform) else {
+		return vector_data;
+	};
+	let size = (max - min).max(DVec2::splat(1e-10));
+	let deform = |point: DVec2| lattice_point(&control_points[..columns * rows], columns, (vector_data.transform.transform_point2(point) - min) / size);
 
-	#[derive(Clone)]
-	pub struct FutureWrapperNode<Node: Clone>(Node);
+	let mut result = VectorData::empty();
+	result.style = vector_data.style.clone();
+	result.alpha_blending = vector_data.alpha_blending;
 
-	impl<'i, T: 'i, N: Node<'i, T> + Clone> Node<'i, T> for FutureWrapperNode<N>
-	where
-		N: Node<'i, T>,
-	{
-		type Output = Pin<Box<dyn core::future::Future<Output = N::Output> + 'i>>;
-		fn eval(&'i self, input: T) -> Self::Output {
-			Box::pin(async move { self.0.eval(input) })
+	// Curves are split up before deforming their control points, since the deformation of a bézier is of a higher degree than the bézier itself
+	let subdivisions = subdivisions.max(1);
+	for subpath in vector_data.stroke_bezier_paths() {
+		let beziers = subpath
+			.iter()
+			.flat_map(|bezier| {
+				(0..subdivisions).map(move |index| {
+					let [start, end] = [index, index + 1].map(|index| TValue::Parametric(index as f64 / subdivisions as f64));
+					let segment = bezier.trim(start, end);
+					match segment.handles {
+						bezier_rs::BezierHandles::Linear => Bezier::from_cubic_dvec2(segment.start, segment.start.lerp(segment.end, 1. / 3.), segment.start.lerp(segment.end, 2. / 3.), segment.end),
+						_ => segment.to_cubic(),
+					}
+				})
+			})
+			.map(|bezier| bezier.apply_transformation(deform))
+			.collect::<Vec<_>>();
+		result.append_subpath(Subpath::<PointId>::from_beziers(&beziers, subpath.closed() && beziers.len() > 1));
+	}
+
+	result
+}
+
+/// Evaluates the tensor product bézier surface defined by the row-major control points at the normalized coordinates `uv`.
+fn lattice_point(control_points: &[DVec2], columns: usize, uv: DVec2) -> DVec2 {
+	let de_casteljau = |mut points: Vec<DVec2>, t: f64| {
+		for length in (1..points.len()).rev() {
+			for index in 0..length {
+				points[index] = points[index].lerp(points[index + 1], t);
+			}
 		}
+		points[0]
+	};
+	let rows = control_points.chunks_exact(columns).map(|row| de_casteljau(row.to_vec(), uv.x)).collect();
+	de_casteljau(rows, uv.y)
+}
+
+pub struct ProjectPointsOntoPathNode<Points, Path, TValueAttribute> {
+	points: Points,
+	path: Path,
+	t_value_attribute: TValueAttribute,
+}
+
+/// Snaps every point to the closest location on the path.
+///
+/// If an attribute name is given, the parametric `t`-value of each projected point along its subpath (from 0 at the start to 1 at the end) is stored in that per-point attribute.
+#[node_macro::node_fn(ProjectPointsOntoPathNode)]
+async fn project_points_onto_path<PointsFuture: Future<Output = VectorData>, PathFuture: Future<Output = VectorData>>(
+	footprint: Footprint,
+	points: impl Node<Footprint, Output = PointsFuture>,
+	path: impl Node<Footprint, Output = PathFuture>,
+	t_value_attribute: String,
+) -> VectorData {
+	let mut points = self.points.eval(footprint).await;
+	let path = self.path.eval(footprint).await;
+
+	let subpaths = path
+		.stroke_bezier_paths()
+		.map(|mut subpath| {
+			subpath.apply_transform(path.transform);
+			subpath
+		})
+		.collect::<Vec<_>>();
+	if subpaths.iter().all(|subpath| subpath.len_segments() == 0) {
+		return points;
 	}
 
-	#[test]
-	fn repeat() {
-		let direction = DVec2::X * 1.5;
-		let repeated = RepeatNode {
-			direction: ClonedNode::new(direction),
-			count: ClonedNode::new(3),
+	let inverse_transform = points.transform.inverse();
+	let mut t_values = Vec::with_capacity(points.point_domain.positions().len());
+	for position in points.point_domain.positions_mut() {
+		let target = points.transform.transform_point2(*position);
+		if let Some((projected, t)) = closest_point_on_subpaths(&subpaths, target) {
+			*position = inverse_transform.transform_point2(projected);
+			t_values.push(t);
+		} else {
+			t_values.push(0.);
 		}
-		.eval(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)));
-		assert_eq!(repeated.region_bezier_paths().count(), 3);
-		for (index, (_, subpath)) in repeated.region_bezier_paths().enumerate() {
-			assert_eq!(subpath.manipulator_groups()[0].anchor, direction * index as f64);
+	}
+
+	if !t_value_attribute.is_empty() {
+		points.point_domain.set_attribute(t_value_attribute, AttributeValues::F64(t_values));
+	}
+
+	points
+}
+
+/// Finds the location on any of the subpaths that is nearest to the target, along with its parametric `t`-value along that subpath (from 0 at the start to 1 at the end).
+fn closest_point_on_subpaths(subpaths: &[Subpath<PointId>], target: DVec2) -> Option<(DVec2, f64)> {
+	subpaths
+		.iter()
+		.filter_map(|subpath| {
+			let (segment_index, t) = subpath.project(target)?;
+			let projected = subpath.evaluate(SubpathTValue::Parametric { segment_index, t });
+			let global_t = (segment_index as f64 + t) / subpath.len_segments() as f64;
+			Some((projected, global_t))
+		})
+		.min_by(|(a, _), (b, _)| a.distance_squared(target).total_cmp(&b.distance_squared(target)))
+}
+
+pub struct ClosestPointNode<Position> {
+	position: Position,
+}
+
+/// Finds the location on the path nearest to the given position, returning that point, its parametric `t`-value along its subpath, and its distance from the position.
+///
+/// If the path has no segments, the position itself is returned with an infinite distance.
+#[node_macro::node_fn(ClosestPointNode)]
+fn closest_point(vector_data: VectorData, position: DVec2) -> (DVec2, f64, f64) {
+	let subpaths = vector_data
+		.stroke_bezier_paths()
+		.map(|mut subpath| {
+			subpath.apply_transform(vector_data.transform);
+			subpath
+		})
+		.collect::<Vec<_>>();
+
+	match closest_point_on_subpaths(&subpaths, position) {
+		Some((point, t)) => (point, t, point.distance(position)),
+		None => (position, 0., f64::INFINITY),
+	}
+}
+
+/// The kind of symmetry that [`DetectSymmetryNode`] looks for.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum Symmetry {
+	/// Mirrored across a line
+	#[default]
+	Reflection,
+	/// Unchanged when turned around a center by a fraction of a full turn
+	Rotation,
+}
+
+pub struct DetectSymmetryNode<Symmetry, MaxOrder> {
+	symmetry: Symmetry,
+	max_order: MaxOrder,
+}
+
+/// Finds the mirror line or rotational symmetry that the shape comes closest to having, then evens out its points so it has that symmetry exactly, for cleaning up hand-drawn shapes meant to be symmetric.
+///
+/// Returns the symmetrized shape, the center of symmetry, and either the angle of the mirror line or the angle between the rotated copies, in degrees.
+/// Rotations are tried with up to the maximum order of copies, picking the most copies that fit about as well as the best fit.
+#[node_macro::node_fn(DetectSymmetryNode)]
+fn detect_symmetry(mut vector_data: VectorData, symmetry: Symmetry, max_order: u32) -> (VectorData, DVec2, f64) {
+	// Number of points spread along the outline to compare against its mirrored or rotated copy
+	const SAMPLES: usize = 256;
+	// Number of mirror line angles tried before the best is refined
+	const ANGLE_STEPS: usize = 180;
+	const REFINEMENT_ITERATIONS: usize = 24;
+	// Limit on the number of rotated copies tried, so a huge maximum can't stall the graph
+	const MAX_ORDER: u32 = 64;
+	// How much worse than the best fit, as a fraction of the shape's radius, a rotation with more copies may fit and still be chosen
+	const ORDER_TOLERANCE: f64 = 0.01;
+
+	let transform = vector_data.transform;
+	let samples = outline_samples(&vector_data, SAMPLES);
+	if samples.is_empty() || transform.matrix2.determinant() == 0. {
+		return (vector_data, transform.translation, 0.);
+	}
+	let center = samples.iter().sum::<DVec2>() / samples.len() as f64;
+
+	// The average distance from each sample, once moved, to the nearest sample where it should land
+	let misfit = |movement: DAffine2| {
+		let total = samples
+			.iter()
+			.map(|&sample| {
+				let moved = movement.transform_point2(sample);
+				samples.iter().map(|other| other.distance_squared(moved)).fold(f64::INFINITY, f64::min).sqrt()
+			})
+			.sum::<f64>();
+		total / samples.len() as f64
+	};
+	let around_center = |movement: DAffine2| DAffine2::from_translation(center) * movement * DAffine2::from_translation(-center);
+
+	let (group, angle) = match symmetry {
+		Symmetry::Reflection => {
+			let reflection = |angle: f64| {
+				let (sin, cos) = (2. * angle).sin_cos();
+				around_center(DAffine2::from_mat2(glam::DMat2::from_cols(DVec2::new(cos, sin), DVec2::new(sin, -cos))))
+			};
+			let step = core::f64::consts::PI / ANGLE_STEPS as f64;
+			let coarse = (0..ANGLE_STEPS)
+				.map(|index| (index as f64 * step, misfit(reflection(index as f64 * step))))
+				.min_by(|(_, a), (_, b)| a.total_cmp(b))
+				.map_or(0., |(angle, _)| angle);
+
+			// Narrow down the best angle between its neighbouring steps
+			let (mut low, mut high) = (coarse - step, coarse + step);
+			for _ in 0..REFINEMENT_ITERATIONS {
+				let (first, second) = (low + (high - low) / 3., high - (high - low) / 3.);
+				if misfit(reflection(first)) < misfit(reflection(second)) {
+					high = second;
+				} else {
+					low = first;
+				}
+			}
+			let angle = (low + high) / 2.;
+			(vec![DAffine2::IDENTITY, reflection(angle)], angle.to_degrees().rem_euclid(180.))
+		}
+		Symmetry::Rotation => {
+			let rotation = |order: u32, turns: u32| around_center(DAffine2::from_angle(core::f64::consts::TAU * turns as f64 / order as f64));
+			let misfits = (2..=max_order.clamp(2, MAX_ORDER)).map(|order| (order, misfit(rotation(order, 1)))).collect::<Vec<_>>();
+			let best = misfits.iter().map(|&(_, misfit)| misfit).fold(f64::INFINITY, f64::min);
+			let radius = (samples.iter().map(|sample| sample.distance_squared(center)).sum::<f64>() / samples.len() as f64).sqrt();
+
+			// A shape with some number of copies also fits the rotations of its factors, so the most copies that fit well are taken
+			let order = misfits
+				.iter()
+				.filter(|&&(_, misfit)| misfit <= best + radius * ORDER_TOLERANCE)
+				.map(|&(order, _)| order)
+				.max()
+				.unwrap_or(2);
+			((0..order).map(|turns| rotation(order, turns)).collect::<Vec<_>>(), 360. / order as f64)
 		}
+	};
+
+	// Work on the points in the local space of the vector data
+	let inverse_transform = transform.inverse();
+	let group = group.into_iter().map(|element| inverse_transform * element * transform).collect::<Vec<_>>();
+	symmetrize(&mut vector_data, &group);
+
+	(vector_data, center, angle)
+}
+
+/// Spreads the number of points evenly along the whole outline of the shape in document space, following its segments in order, or gives its points if it has no segments.
+fn outline_samples(vector_data: &VectorData, count: usize) -> Vec<DVec2> {
+	let beziers = vector_data
+		.segment_bezier_iter()
+		.map(|(_, bezier, _, _)| bezier.apply_transformation(|point| vector_data.transform.transform_point2(point)))
+		.collect::<Vec<_>>();
+	let lengths = beziers.iter().map(|bezier| bezier.length(None)).collect::<Vec<_>>();
+	let total_length = lengths.iter().sum::<f64>();
+	if total_length <= 0. {
+		return vector_data.point_domain.positions().iter().map(|&point| vector_data.transform.transform_point2(point)).collect();
 	}
-	#[test]
-	fn repeat_transform_position() {
-		let direction = DVec2::new(12., 10.);
-		let repeated = RepeatNode {
-			direction: ClonedNode::new(direction),
-			count: ClonedNode::new(8),
+
+	let mut samples = Vec::with_capacity(count);
+	let (mut bezier_index, mut bezier_start) = (0, 0.);
+	for index in 0..count {
+		let distance = index as f64 * total_length / count as f64;
+		while bezier_index + 1 < beziers.len() && bezier_start + lengths[bezier_index] <= distance {
+			bezier_start += lengths[bezier_index];
+			bezier_index += 1;
 		}
-		.eval(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)));
-		assert_eq!(repeated.region_bezier_paths().count(), 8);
-		for (index, (_, subpath)) in repeated.region_bezier_paths().enumerate() {
-			assert_eq!(subpath.manipulator_groups()[0].anchor, direction * index as f64);
+		let t = if lengths[bezier_index] > 0. {
+			((distance - bezier_start) / lengths[bezier_index]).clamp(0., 1.)
+		} else {
+			0.
+		};
+		samples.push(beziers[bezier_index].evaluate(TValue::Euclidean(t)));
+	}
+	samples
+}
+
+/// Makes the shape exactly symmetric under the group of transforms by moving each point and handle to the average of where its counterparts say it should be.
+///
+/// Each transform pairs a point with the point nearest to where it lands, and the point is moved to the average of its partners carried back by the inverse transforms.
+/// Handles are paired the same way through the segments joining the partners, and are left alone where a segment has no counterpart of the same kind.
+fn symmetrize(vector_data: &mut VectorData, group: &[DAffine2]) {
+	let positions = vector_data.point_domain.positions().to_vec();
+	if positions.is_empty() {
+		return;
+	}
+
+	let nearest = |target: DVec2| {
+		let distances = positions.iter().map(|position| position.distance_squared(target));
+		distances.enumerate().min_by(|(_, a), (_, b)| a.total_cmp(b)).map_or(0, |(index, _)| index)
+	};
+	let partners = group
+		.iter()
+		.map(|element| positions.iter().map(|&position| nearest(element.transform_point2(position))).collect::<Vec<_>>())
+		.collect::<Vec<_>>();
+	let inverses = group.iter().map(DAffine2::inverse).collect::<Vec<_>>();
+
+	for (index, position) in vector_data.point_domain.positions_mut().iter_mut().enumerate() {
+		let total = inverses
+			.iter()
+			.zip(&partners)
+			.map(|(inverse, partners)| inverse.transform_point2(positions[partners[index]]))
+			.sum::<DVec2>();
+		*position = total / group.len() as f64;
+	}
+
+	let point_indices = vector_data.point_domain.ids().iter().enumerate().map(|(index, &id)| (id, index)).collect::<HashMap<_, _>>();
+	let segment_domain = &vector_data.segment_domain;
+	let endpoints = segment_domain
+		.start_point()
+		.iter()
+		.zip(segment_domain.end_point())
+		.map(|(start, end)| point_indices.get(start).zip(point_indices.get(end)).map(|(&start, &end)| (start, end)))
+		.collect::<Vec<_>>();
+	let segment_indices = endpoints.iter().enumerate().filter_map(|(index, &endpoints)| Some((endpoints?, index))).collect::<HashMap<_, _>>();
+	let handles = segment_domain.handles().to_vec();
+
+	for (index, segment_handles) in vector_data.segment_domain.handles_mut().iter_mut().enumerate() {
+		let Some((start, end)) = endpoints[index] else { continue };
+		let counterparts = inverses.iter().zip(&partners).map(|(inverse, partners)| {
+			let (start, end) = (partners[start], partners[end]);
+			let (partner, reversed) = match segment_indices.get(&(start, end)) {
+				Some(&partner) => (partner, false),
+				None => (*segment_indices.get(&(end, start))?, true),
+			};
+			let counterpart = handles[partner].apply_transformation(|point| inverse.transform_point2(point));
+			Some(if reversed { reversed_handles(counterpart) } else { counterpart })
+		});
+		let Some(counterparts) = counterparts.collect::<Option<Vec<_>>>() else { continue };
+		if let Some(average) = average_handles(&counterparts) {
+			*segment_handles = average;
 		}
 	}
-	#[test]
-	fn circle_repeat() {
-		let repeated = CircularRepeatNode {
-			angle_offset: ClonedNode::new(45.),
-			radius: ClonedNode::new(4.),
-			count: ClonedNode::new(8),
+
+	// Averaging can bend apart handles which were locked opposite each other
+	vector_data.straighten_colinear_handles();
+}
+
+/// Averages the positions of handles which are all of the same kind, or gives `None` if they're of different kinds.
+fn average_handles(handles: &[BezierHandles]) -> Option<BezierHandles> {
+	let count = handles.len() as f64;
+	match handles.first()? {
+		BezierHandles::Linear => handles.iter().all(|handles| *handles == BezierHandles::Linear).then_some(BezierHandles::Linear),
+		BezierHandles::Quadratic { .. } => {
+			let mut total = DVec2::ZERO;
+			for handles in handles {
+				let BezierHandles::Quadratic { handle } = handles else { return None };
+				total += *handle;
+			}
+			Some(BezierHandles::Quadratic { handle: total / count })
 		}
-		.eval(VectorData::from_subpath(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE)));
-		assert_eq!(repeated.region_bezier_paths().count(), 8);
-		for (index, (_, subpath)) in repeated.region_bezier_paths().enumerate() {
-			let expected_angle = (index as f64 + 1.) * 45.;
-			let centre = (subpath.manipulator_groups()[0].anchor + subpath.manipulator_groups()[2].anchor) / 2.;
-			let actual_angle = DVec2::Y.angle_between(centre).to_degrees();
-			assert!((actual_angle - expected_angle).abs() % 360. < 1e-5);
+		BezierHandles::Cubic { .. } => {
+			let (mut total_start, mut total_end) = (DVec2::ZERO, DVec2::ZERO);
+			for handles in handles {
+				let BezierHandles::Cubic { handle_start, handle_end } = handles else { return None };
+				total_start += *handle_start;
+				total_end += *handle_end;
+			}
+			Some(BezierHandles::Cubic {
+				handle_start: total_start / count,
+				handle_end: total_end / count,
+			})
 		}
 	}
-	#[test]
-	fn bounding_box() {
-		let bouding_box = BoundingBoxNode.eval(VectorData::from_subpath(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE)));
-		assert_eq!(bouding_box.region_bezier_paths().count(), 1);
-		let subpath = bouding_box.region_bezier_paths().next().unwrap().1;
-		assert_eq!(&subpath.anchors()[..4], &[DVec2::NEG_ONE, DVec2::new(1., -1.), DVec2::ONE, DVec2::new(-1., 1.),]);
+}
+
+/// The handles of a segment once it's drawn from its end to its start.
+fn reversed_handles(handles: BezierHandles) -> BezierHandles {
+	match handles {
+		BezierHandles::Cubic { handle_start, handle_end } => BezierHandles::Cubic {
+			handle_start: handle_end,
+			handle_end: handle_start,
+		},
+		handles => handles,
 	}
-	#[tokio::test]
-	async fn copy_to_points() {
-		let points = VectorData::from_subpath(Subpath::new_rect(DVec2::NEG_ONE * 10., DVec2::ONE * 10.));
+}
+
+/// The handles of a segment once its start and end anchors have been moved by the offsets, so the curve keeps its shape.
+fn offset_handles(handles: BezierHandles, start_offset: DVec2, end_offset: DVec2) -> BezierHandles {
+	match handles {
+		BezierHandles::Linear => BezierHandles::Linear,
+		BezierHandles::Quadratic { handle } => BezierHandles::Quadratic {
+			handle: handle + (start_offset + end_offset) / 2.,
+		},
+		BezierHandles::Cubic { handle_start, handle_end } => BezierHandles::Cubic {
+			handle_start: handle_start + start_offset,
+			handle_end: handle_end + end_offset,
+		},
+	}
+}
+
+pub struct WeldPointsNode<Distance> {
+	distance: Distance,
+}
+
+/// Merges points that are within the distance of each other into a single point at their average position, then joins open subpaths whose ends now meet into longer or closed subpaths.
+///
+/// The distance is measured in document space. Segments between merged points that are no longer than the distance are removed.
+#[node_macro::node_fn(WeldPointsNode)]
+fn weld_points(vector_data: VectorData, distance: f64) -> VectorData {
+	let positions = vector_data.point_domain.positions();
+	let document_positions = positions.iter().map(|&position| vector_data.transform.transform_point2(position)).collect::<Vec<_>>();
+	let groups = weld_groups(&document_positions, distance.max(0.));
+
+	// Each group is merged into its first point, placed at the average position of the group
+	let mut totals = vec![(DVec2::ZERO, 0); positions.len()];
+	for (index, &group) in groups.iter().enumerate() {
+		totals[group].0 += positions[index];
+		totals[group].1 += 1;
+	}
+	let welded_positions = groups.iter().map(|&group| totals[group].0 / totals[group].1 as f64).collect::<Vec<_>>();
+
+	// Handles move along with their anchors
+	let point_indices = vector_data.point_domain.ids().iter().enumerate().map(|(index, &id)| (id, index)).collect::<HashMap<_, _>>();
+	let mut segments = Vec::new();
+	for (id, bezier, start, end) in vector_data.segment_bezier_iter() {
+		let (Some(&start), Some(&end)) = (point_indices.get(&start), point_indices.get(&end)) else {
+			continue;
+		};
+		if groups[start] == groups[end] && bezier.apply_transformation(|point| vector_data.transform.transform_point2(point)).length(None) <= distance {
+			continue;
+		}
+
+		let (start_offset, end_offset) = (welded_positions[start] - positions[start], welded_positions[end] - positions[end]);
+		segments.push((id, groups[start], groups[end], offset_handles(bezier.handles, start_offset, end_offset)));
+	}
+
+	// Runs of segments that follow on from one another make up the subpaths, which stop once they're closed
+	let closed = |chain: &[(SegmentId, usize, usize, BezierHandles)]| chain.first().map(|&(_, start, _, _)| start) == chain.last().map(|&(_, _, end, _)| end);
+	let mut chains: Vec<Vec<_>> = Vec::new();
+	for segment in segments {
+		match chains.last_mut() {
+			Some(chain) if !closed(chain) && chain.last().is_some_and(|&(_, _, end, _)| end == segment.1) => chain.push(segment),
+			_ => chains.push(vec![segment]),
+		}
+	}
+
+	// Open subpaths are joined end to end, turning them around where they meet head to head or tail to tail
+	let mut open_ends: HashMap<usize, Vec<usize>> = HashMap::new();
+	for (index, chain) in chains.iter().enumerate().filter(|(_, chain)| !closed(chain)) {
+		open_ends.entry(chain[0].1).or_default().push(index);
+		open_ends.entry(chain[chain.len() - 1].2).or_default().push(index);
+	}
+	let reversed = |chain: Vec<(SegmentId, usize, usize, BezierHandles)>| chain.into_iter().rev().map(|(id, start, end, handles)| (id, end, start, reversed_handles(handles))).collect::<Vec<_>>();
+	let mut used = vec![false; chains.len()];
+	let mut joined = Vec::new();
+	for index in 0..chains.len() {
+		if used[index] {
+			continue;
+		}
+		used[index] = true;
+		let mut chain = core::mem::take(&mut chains[index]);
+
+		// Extend the end, then turn the chain around to extend its start the same way and turn it back
+		for _ in 0..2 {
+			while !closed(&chain) {
+				let end = chain[chain.len() - 1].2;
+				let Some(&next) = open_ends.get(&end).and_then(|candidates| candidates.iter().find(|&&candidate| !used[candidate])) else {
+					break;
+				};
+				used[next] = true;
+				let next_chain = core::mem::take(&mut chains[next]);
+				chain.extend(if next_chain[0].1 == end { next_chain } else { reversed(next_chain) });
+			}
+			chain = reversed(chain);
+		}
+		joined.push(chain);
+	}
+
+	let mut result = VectorData::empty();
+	result.transform = vector_data.transform;
+	result.style = vector_data.style.clone();
+	result.alpha_blending = vector_data.alpha_blending;
+
+	let ids = vector_data.point_domain.ids();
+	let kept = (0..positions.len()).filter(|&index| groups[index] == index).collect::<Vec<_>>();
+	result.colinear_manipulators = kept.iter().map(|&index| ids[index]).filter(|&id| vector_data.colinear(id)).map(Into::into).collect();
+	for &index in &kept {
+		result.point_domain.push(ids[index], welded_positions[index]);
+	}
+	for (name, values) in vector_data.point_domain.attributes() {
+		result.point_domain.set_attribute(name, values.select(&kept));
+	}
+
+	for chain in joined {
+		let is_closed = closed(&chain);
+		for &(id, start, end, handles) in &chain {
+			result.segment_domain.push(id, ids[start], ids[end], handles, StrokeId::generate());
+		}
+		if is_closed {
+			if let (Some(&(first, ..)), Some(&(last, ..))) = (chain.first(), chain.last()) {
+				result.region_domain.push(RegionId::generate(), first..=last, FillId::generate());
+			}
+		}
+	}
+
+	result
+}
+
+/// Finds the groups of points that are within the distance of one another, directly or through other points in between, giving the first point of its group for each point.
+fn weld_groups(points: &[DVec2], distance: f64) -> Vec<usize> {
+	// Points are sorted into grid cells the size of the distance, so each only needs comparing with the points in its neighbouring cells
+	let cell_size = distance.max(1e-6);
+	let cell = |point: DVec2| ((point.x / cell_size).floor() as i64, (point.y / cell_size).floor() as i64);
+	let mut grid: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+	for (index, &point) in points.iter().enumerate() {
+		grid.entry(cell(point)).or_default().push(index);
+	}
+
+	// Each group is kept as a tree whose root is its first point
+	let mut parents = (0..points.len()).collect::<Vec<_>>();
+	let root = |parents: &mut Vec<usize>, mut index: usize| {
+		while parents[index] != index {
+			parents[index] = parents[parents[index]];
+			index = parents[index];
+		}
+		index
+	};
+	for (index, &point) in points.iter().enumerate() {
+		let (x, y) = cell(point);
+		let neighbours = (x - 1..=x + 1).flat_map(|x| (y - 1..=y + 1).map(move |y| (x, y)));
+		for other in neighbours.filter_map(|neighbour| grid.get(&neighbour)).flatten().copied() {
+			if other < index && points[other].distance(point) <= distance {
+				let (first, second) = (root(&mut parents, index), root(&mut parents, other));
+				parents[first.max(second)] = first.min(second);
+			}
+		}
+	}
+	(0..points.len()).map(|index| root(&mut parents, index)).collect()
+}
+
+pub struct SnapToGridNode<Spacing, Origin, Isometric, SnapHandles> {
+	spacing: Spacing,
+	origin: Origin,
+	isometric: Isometric,
+	snap_handles: SnapHandles,
+}
+
+/// Moves every anchor to the nearest point of a grid in document space, such as for keeping pixel art and icons on whole pixels.
+///
+/// The isometric grid has vertical lines crossed by lines at 30° either side of horizontal, like the editor's isometric grid, with the spacing's Y giving the distance between crossings along a vertical line.
+/// Handles are snapped to the grid too if chosen, or otherwise move along with their anchors so the curves keep their shape.
+#[node_macro::node_fn(SnapToGridNode)]
+fn snap_to_grid(mut vector_data: VectorData, spacing: DVec2, origin: DVec2, isometric: bool, snap_handles: bool) -> VectorData {
+	let transform = vector_data.transform;
+	if spacing.x <= 0. || spacing.y <= 0. || transform.matrix2.determinant() == 0. {
+		return vector_data;
+	}
+	let inverse_transform = transform.inverse();
+
+	// The grid points are whole steps along two directions from the origin
+	let (across, down) = if isometric {
+		let tan = 30_f64.to_radians().tan();
+		let width = spacing.y / (2. * tan);
+		(DVec2::new(width, -width * tan), DVec2::new(0., spacing.y))
+	} else {
+		(DVec2::new(spacing.x, 0.), DVec2::new(0., spacing.y))
+	};
+	let snap = |point: DVec2| {
+		let document_point = transform.transform_point2(point) - origin;
+		let column = (document_point.x / across.x).round();
+		// The neighbouring columns are checked too, since a slanted grid's nearest point isn't always in the nearest column
+		let nearest = [column - 1., column, column + 1.]
+			.into_iter()
+			.map(|column| {
+				let row = ((document_point - across * column).y / down.y).round();
+				across * column + down * row
+			})
+			.min_by(|a, b| a.distance_squared(document_point).total_cmp(&b.distance_squared(document_point)))
+			.unwrap_or(document_point);
+		inverse_transform.transform_point2(nearest + origin)
+	};
+
+	snap_anchors(&mut vector_data, snap, snap_handles);
+	vector_data
+}
+
+/// Moves every anchor to where the snap function puts it, with the handles either snapped in the same way or moved along with their anchors so the curves keep their shape.
+fn snap_anchors(vector_data: &mut VectorData, snap: impl Fn(DVec2) -> DVec2, snap_handles: bool) {
+	let point_indices = vector_data.point_domain.ids().iter().enumerate().map(|(index, &id)| (id, index)).collect::<HashMap<_, _>>();
+	let positions = vector_data.point_domain.positions().to_vec();
+	let snapped = positions.iter().map(|&position| snap(position)).collect::<Vec<_>>();
+	vector_data.point_domain.positions_mut().copy_from_slice(&snapped);
+
+	let offset = |id: &PointId| point_indices.get(id).map_or(DVec2::ZERO, |&index| snapped[index] - positions[index]);
+	let segment_domain = &vector_data.segment_domain;
+	let offsets = segment_domain.start_point().iter().zip(segment_domain.end_point()).map(|(start, end)| (offset(start), offset(end)));
+	let offsets = offsets.collect::<Vec<_>>();
+	for (handles, (start_offset, end_offset)) in vector_data.segment_domain.handles_mut().iter_mut().zip(offsets) {
+		*handles = if snap_handles {
+			handles.apply_transformation(&snap)
+		} else {
+			offset_handles(*handles, start_offset, end_offset)
+		};
+	}
+}
+
+/// A shape from the keylines of a standard icon grid, like that of Material Design, which artwork is sized to fit so icons of different shapes look equally large side by side.
+/// The sizes are given for a grid 24 units across, and scale along with it.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum IconKeyline {
+	/// Pick the keyline from the proportions and roundness of the artwork
+	#[default]
+	Auto,
+	/// A square 18 units across
+	Square,
+	/// A circle reaching past the square by the overshoot on every side, 20 units across by default, since round artwork looks smaller than square artwork of the same size
+	Circle,
+	/// An upright rectangle 16 units wide and 20 units tall
+	Portrait,
+	/// A lying rectangle 20 units wide and 16 units tall
+	Landscape,
+}
+
+impl IconKeyline {
+	/// The width and height of the keyline on a grid 24 units across, with round artwork overshooting the square by the given amount on each side.
+	pub fn size(self, overshoot: f64) -> DVec2 {
+		match self {
+			Self::Auto | Self::Square => DVec2::splat(18.),
+			Self::Circle => DVec2::splat(18. + overshoot * 2.),
+			Self::Portrait => DVec2::new(16., 20.),
+			Self::Landscape => DVec2::new(20., 16.),
+		}
+	}
+
+	/// Picks the keyline that suits artwork with the bounds, from how far it is from being as wide as it is tall, and from how much of its bounds it covers.
+	fn detect(bounds: DVec2, area: f64) -> Self {
+		// Proportions further from square than this are fitted to the rectangular keylines
+		const RECTANGULAR_ASPECT_RATIO: f64 = 1.15;
+		// Artwork covering less of its bounds than this is taken to be round, halfway between a circle covering π/4 and a square covering all of it
+		const ROUND_COVERAGE: f64 = 0.89;
+
+		let aspect_ratio = bounds.x / bounds.y;
+		if aspect_ratio > RECTANGULAR_ASPECT_RATIO {
+			Self::Landscape
+		} else if aspect_ratio < 1. / RECTANGULAR_ASPECT_RATIO {
+			Self::Portrait
+		} else if area < bounds.x * bounds.y * ROUND_COVERAGE {
+			Self::Circle
+		} else {
+			Self::Square
+		}
+	}
+}
+
+pub struct IconKeylineNode<Keyline, GridSize, Overshoot, SnapToPixels> {
+	keyline: Keyline,
+	grid_size: GridSize,
+	overshoot: Overshoot,
+	snap_to_pixels: SnapToPixels,
+}
+
+/// Scales the artwork to fit the keyline shape of an icon grid and centers it in the grid, which spans from the origin to the grid size, so a set of icons made procedurally
+/// all look consistently sized. The keyline is picked from the artwork's proportions and roundness unless one is chosen.
+///
+/// The transform of the artwork is applied to its points, and its anchors may be snapped to the pixels of the grid, each a 24th of its size, to keep edges crisp at the size it's drawn.
+/// Strokes keep their weight, so the icons of a set keep the same line thickness.
+#[node_macro::node_fn(IconKeylineNode)]
+fn icon_keyline(mut vector_data: VectorData, keyline: IconKeyline, grid_size: f64, overshoot: f64, snap_to_pixels: bool) -> VectorData {
+	// Number of line segments each curved segment is flattened into when finding the area that decides whether the artwork is round
+	const FLATTENING_STEPS: usize = 16;
+	// Number of pixels across the grid that the keyline sizes are given for
+	const GRID_PIXELS: f64 = 24.;
+
+	let Some([min, max]) = vector_data.bounding_box_with_transform(vector_data.transform) else {
+		return vector_data;
+	};
+	let bounds = max - min;
+	if grid_size <= 0. || bounds.max_element() <= 0. {
+		return vector_data;
+	}
+
+	let keyline = if keyline == IconKeyline::Auto {
+		let area = vector_data
+			.stroke_bezier_paths()
+			.map(|subpath| signed_area(&flatten_subpath(&subpath, vector_data.transform, FLATTENING_STEPS)).abs() / 2.)
+			.sum();
+		IconKeyline::detect(bounds, area)
+	} else {
+		keyline
+	};
+
+	// Artwork that's flat along one axis is fitted by its other one
+	let pixel = grid_size / GRID_PIXELS;
+	let size = keyline.size(overshoot.max(0.)) * pixel;
+	let scale = [size.x / bounds.x, size.y / bounds.y].into_iter().filter(|scale| scale.is_finite()).fold(f64::INFINITY, f64::min);
+	let fit = DAffine2::from_translation(DVec2::splat(grid_size / 2.)) * DAffine2::from_scale(DVec2::splat(scale)) * DAffine2::from_translation(-(min + max) / 2.);
+
+	vector_data.transform(fit * vector_data.transform);
+	vector_data.transform = DAffine2::IDENTITY;
+	if snap_to_pixels {
+		snap_anchors(&mut vector_data, |point| (point / pixel).round() * pixel, false);
+	}
+	vector_data
+}
+
+pub struct IntersectionPointsNode<First, Second> {
+	first: First,
+	second: Second,
+}
+
+/// Places a point at every location where the paths of the two inputs cross or touch.
+#[node_macro::node_fn(IntersectionPointsNode)]
+async fn intersection_points<FirstFuture: Future<Output = VectorData>, SecondFuture: Future<Output = VectorData>>(
+	footprint: Footprint,
+	first: impl Node<Footprint, Output = FirstFuture>,
+	second: impl Node<Footprint, Output = SecondFuture>,
+) -> VectorData {
+	// Size of the region that curved segments are subdivided down to when searching for an intersection
+	const ERROR: f64 = 1e-4;
+	// Intersections closer together than this are considered the same crossing, such as where two segments of one path meet at an endpoint
+	const MINIMUM_SEPARATION: f64 = 1e-3;
+
+	let first = self.first.eval(footprint).await;
+	let second = self.second.eval(footprint).await;
+
+	let beziers = |vector_data: &VectorData| {
+		vector_data
+			.segment_bezier_iter()
+			.map(|(_, bezier, _, _)| bezier.apply_transformation(|point| vector_data.transform.transform_point2(point)))
+			.collect::<Vec<_>>()
+	};
+	let (first_beziers, second_beziers) = (beziers(&first), beziers(&second));
+
+	let mut result = VectorData::empty();
+	for first_bezier in &first_beziers {
+		for second_bezier in &second_beziers {
+			for t in first_bezier.intersections(second_bezier, Some(ERROR), None) {
+				let point = first_bezier.evaluate(TValue::Parametric(t));
+				if result
+					.point_domain
+					.positions()
+					.iter()
+					.all(|existing| existing.distance_squared(point) > MINIMUM_SEPARATION * MINIMUM_SEPARATION)
+				{
+					result.point_domain.push(PointId::generate(), point);
+				}
+			}
+		}
+	}
+
+	result
+}
+
+/// Name of the per-point attribute, written by [`ShapeDeviationNode`], holding the distance from each point to the reference outline.
+pub const DEVIATION_ATTRIBUTE: &str = "deviation";
+
+pub struct ShapeDeviationNode<Compared, Reference, Spacing, Tolerance, Weight> {
+	compared: Compared,
+	reference: Reference,
+	spacing: Spacing,
+	tolerance: Tolerance,
+	weight: Weight,
+}
+
+/// Colors the outline of the first input by how far it strays from the outline of the reference, from green where they match to red where they're the tolerance or more apart, for checking traced or simplified artwork against the original.
+///
+/// The outline is split into pieces of about the spacing in length, whose points store their distance from the reference in the `deviation` attribute.
+/// Pieces of the same shade are gathered into one path, so the output group holds a path for each shade.
+#[node_macro::node_fn(ShapeDeviationNode)]
+async fn shape_deviation<ComparedFuture: Future<Output = VectorData>, ReferenceFuture: Future<Output = VectorData>>(
+	footprint: Footprint,
+	compared: impl Node<Footprint, Output = ComparedFuture>,
+	reference: impl Node<Footprint, Output = ReferenceFuture>,
+	spacing: f64,
+	tolerance: f64,
+	weight: f64,
+) -> GraphicGroup {
+	// Number of shades from green to red that the pieces are sorted into
+	const SHADES: usize = 8;
+	// Limit on how finely each segment is split, so a tiny spacing can't stall the graph
+	const MAX_PIECES_PER_SEGMENT: f64 = 1000.;
+	// Number of line segments each curved segment of the reference is flattened into when measuring distances to it
+	const FLATTENING_STEPS: usize = 64;
+
+	let compared = self.compared.eval(footprint).await;
+	let reference = self.reference.eval(footprint).await;
+
+	// The reference is flattened finely, since the distance to a polyline is found reliably even where a curve's handles sit on its anchors
+	let reference_polylines = reference
+		.stroke_bezier_paths()
+		.map(|subpath| {
+			let mut polyline = flatten_subpath(&subpath, reference.transform, FLATTENING_STEPS);
+			let last = if subpath.closed() {
+				polyline.first().copied()
+			} else {
+				subpath.iter().last().map(|bezier| reference.transform.transform_point2(bezier.end))
+			};
+			polyline.extend(last);
+			polyline
+		})
+		.collect::<Vec<_>>();
+	let deviation = |point: DVec2| {
+		reference_polylines
+			.iter()
+			.flat_map(|polyline| polyline.windows(2))
+			.map(|line| distance_to_segment(point, line[0], line[1]))
+			.fold(f64::INFINITY, f64::min)
+	};
+
+	let mut shades = vec![(VectorData::empty(), Vec::new()); SHADES];
+	for subpath in compared.stroke_bezier_paths() {
+		for bezier in subpath.iter() {
+			let bezier = bezier.apply_transformation(|point| compared.transform.transform_point2(point));
+			let pieces = if spacing > 0. {
+				(bezier.length(None) / spacing).ceil().clamp(1., MAX_PIECES_PER_SEGMENT) as usize
+			} else {
+				1
+			};
+
+			for index in 0..pieces {
+				let piece = bezier.trim(TValue::Euclidean(index as f64 / pieces as f64), TValue::Euclidean((index + 1) as f64 / pieces as f64));
+				let [start, end] = [piece.start, piece.end].map(deviation);
+				let worst = start.max(end).max(deviation(piece.evaluate(TValue::Parametric(0.5))));
+
+				let shade = if tolerance > 0. {
+					(worst / tolerance * (SHADES - 1) as f64).round()
+				} else if worst > 0. {
+					f64::INFINITY
+				} else {
+					0.
+				};
+				let (vector_data, deviations) = &mut shades[(shade as usize).min(SHADES - 1)];
+				vector_data.append_subpath(Subpath::<PointId>::from_bezier(&piece));
+				deviations.extend([start, end]);
+			}
+		}
+	}
+
+	let yellow = Color::from_rgbf32_unchecked(1., 1., 0.);
+	let mut result = GraphicGroup::EMPTY;
+	for (index, (mut vector_data, deviations)) in shades.into_iter().enumerate() {
+		if deviations.is_empty() {
+			continue;
+		}
+
+		let t = index as f32 / (SHADES - 1) as f32;
+		let color = if t < 0.5 { Color::GREEN.lerp(&yellow, t * 2.) } else { yellow.lerp(&Color::RED, t * 2. - 1.) };
+		vector_data.point_domain.set_attribute(DEVIATION_ATTRIBUTE, AttributeValues::F64(deviations));
+		vector_data.style.set_fill(Fill::None);
+		vector_data.style.set_stroke(Stroke::new(Some(color), weight));
+		result.push(vector_data.into());
+	}
+	result
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClipToViewportNode<VectorData, Margin> {
+	vector_data: VectorData,
+	margin: Margin,
+}
+
+/// Cuts away the parts of the shape beyond the area being rendered, which is the artboard or bounds being exported, or the viewport while editing, leaving a margin in pixels around it.
+/// Procedural graphs often make geometry reaching far past the canvas, which this keeps out of exported files.
+///
+/// Where a subpath leaves the area, the part outside is replaced by straight lines along the edge of the margin, so its fill inside the area stays the same.
+/// The stroke along these lines stays out of view as long as the margin is wider than it, and subpaths lying entirely outside the area are removed.
+#[node_macro::node_fn(ClipToViewportNode)]
+async fn clip_to_viewport<Fut: Future<Output = VectorData>>(footprint: Footprint, vector_data: impl Node<Footprint, Output = Fut>, margin: f64) -> VectorData {
+	let vector_data = self.vector_data.eval(footprint).await;
+
+	// The shape is clipped in the pixel space of the render, where the area is an upright rectangle however the shape is transformed
+	let to_pixels = footprint.transform * vector_data.transform;
+	if to_pixels.matrix2.determinant() == 0. {
+		return vector_data;
+	}
+	let margin = DVec2::splat(margin.max(0.));
+	let [min, max] = [-margin, footprint.resolution.as_dvec2() + margin];
+
+	let subpaths = vector_data.stroke_bezier_paths().collect::<Vec<_>>();
+	let subpath_bounds = subpaths.iter().map(|subpath| subpath.bounding_box_with_transform(to_pixels)).collect::<Vec<_>>();
+	let inside = |bounds: &Option<[DVec2; 2]>| bounds.iter().all(|[start, end]| start.cmpge(min).all() && end.cmple(max).all());
+	if subpath_bounds.iter().all(inside) {
+		return vector_data;
+	}
+
+	let mut result = VectorData::empty();
+	result.transform = vector_data.transform;
+	result.style = vector_data.style.clone();
+	result.alpha_blending = vector_data.alpha_blending;
+	result.colinear_manipulators.clone_from(&vector_data.colinear_manipulators);
+
+	let to_local = to_pixels.inverse();
+	let mut kept = 0;
+	for (index, (subpath, bounds)) in subpaths.into_iter().zip(subpath_bounds).enumerate() {
+		let Some([start, end]) = bounds else { continue };
+		if start.cmpgt(max).any() || end.cmplt(min).any() {
+			continue;
+		}
+
+		let subpath = if inside(&bounds) {
+			subpath
+		} else {
+			let beziers = subpath.iter().map(|bezier| bezier.apply_transformation(|point| to_pixels.transform_point2(point))).collect::<Vec<_>>();
+			let clipped = clip_to_rectangle(beziers, [min, max]);
+			let clipped = clipped
+				.into_iter()
+				.map(|bezier| bezier.apply_transformation(|point| to_local.transform_point2(point)))
+				.collect::<Vec<_>>();
+			Subpath::from_beziers(&clipped, subpath.closed() && clipped.len() > 1)
+		};
+
+		result.append_subpath(subpath);
+		if vector_data.subpath_styles.iter().any(|&(subpath, _)| subpath == index) {
+			result.set_subpath_style(kept, vector_data.subpath_style(index).clone());
+		}
+		kept += 1;
+	}
+
+	result
+}
+
+/// Clips a path to the rectangle with [`clip_to_half_plane`] on each of its sides in turn.
+fn clip_to_rectangle(mut beziers: Vec<Bezier>, [min, max]: [DVec2; 2]) -> Vec<Bezier> {
+	// Each side is turned to face along the x axis, so the rectangle is clipped to by limiting x on each
+	let sides = [
+		(DAffine2::IDENTITY, max.x),
+		(DAffine2::from_scale(DVec2::new(-1., 1.)), -min.x),
+		(DAffine2::from_cols_array(&[0., 1., 1., 0., 0., 0.]), max.y),
+		(DAffine2::from_cols_array(&[0., 1., -1., 0., 0., 0.]), -min.y),
+	];
+	for (turn, limit) in sides {
+		let turned = beziers.iter().map(|bezier| bezier.apply_transformation(|point| turn.transform_point2(point))).collect::<Vec<_>>();
+		let back = turn.inverse();
+		beziers = clip_to_half_plane(&turned, limit)
+			.into_iter()
+			.map(|bezier| bezier.apply_transformation(|point| back.transform_point2(point)))
+			.collect();
+	}
+	beziers
+}
+
+/// Clips a path to the half of the plane where x is at most the limit, replacing each run of the path beyond it by a straight line between where the run starts and ends on the limit.
+/// The path winds around every point on the kept side as many times as before, so its fill on that side is unchanged.
+fn clip_to_half_plane(beziers: &[Bezier], limit: f64) -> Vec<Bezier> {
+	let onto_limit = |point: DVec2| DVec2::new(point.x.min(limit), point.y);
+	let mut clipped = Vec::new();
+	let mut outside: Option<[DVec2; 2]> = None;
+	let end_outside = |outside: &mut Option<[DVec2; 2]>, clipped: &mut Vec<Bezier>| {
+		if let Some([start, end]) = outside.take().filter(|[start, end]| start != end) {
+			clipped.push(Bezier::from_linear_dvec2(start, end));
+		}
+	};
+
+	for bezier in beziers {
+		let mut splits = bezier.find_tvalues_for_x(limit).filter(|&t| t > 0. && t < 1.).collect::<Vec<_>>();
+		splits.sort_by(f64::total_cmp);
+		splits.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+
+		let bounds = core::iter::once(0.).chain(splits).chain(core::iter::once(1.)).collect::<Vec<_>>();
+		for pair in bounds.windows(2) {
+			let piece = bezier.trim(TValue::Parametric(pair[0]), TValue::Parametric(pair[1]));
+			if piece.evaluate(TValue::Parametric(0.5)).x <= limit {
+				end_outside(&mut outside, &mut clipped);
+				clipped.push(piece);
+			} else {
+				let start = outside.map_or(onto_limit(piece.start), |[start, _]| start);
+				outside = Some([start, onto_limit(piece.end)]);
+			}
+		}
+	}
+	end_outside(&mut outside, &mut clipped);
+
+	clipped
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ScalePreservingCornersNode<Scale> {
+	scale: Scale,
+}
+
+/// Scales the shape about the center of its bounds while keeping its rounded corners the same size, so a rounded rectangle can be stretched into a wider button
+/// without its corners turning into ellipses. The points are moved rather than the transform, so the stroke weight stays the same as well.
+///
+/// A rounded corner is a curve between two straight lines that it meets smoothly. After scaling, it's rounded again the same distance away from where the lines meet,
+/// or less where the lines became too short, with its handles reaching the same fraction of the way towards that point. Other curves are scaled with the rest of the shape.
+#[node_macro::node_fn(ScalePreservingCornersNode)]
+fn scale_preserving_corners(vector_data: VectorData, scale: DVec2) -> VectorData {
+	let Some([min, max]) = vector_data.bounding_box() else {
+		return vector_data;
+	};
+	let center = (min + max) / 2.;
+	let transform = DAffine2::from_translation(center) * DAffine2::from_scale(scale) * DAffine2::from_translation(-center);
+
+	let mut result = VectorData::empty();
+	result.transform = vector_data.transform;
+	result.style = vector_data.style.clone();
+	result.alpha_blending = vector_data.alpha_blending;
+	result.subpath_styles.clone_from(&vector_data.subpath_styles);
+	for mut subpath in vector_data.stroke_bezier_paths() {
+		let beziers = subpath.iter().collect::<Vec<_>>();
+		if beziers.is_empty() {
+			subpath.apply_transform(transform);
+			result.append_subpath(subpath);
+			continue;
+		}
+
+		let scaled = scale_rounded_corners(&beziers, subpath.closed(), transform);
+		result.append_subpath(Subpath::<PointId>::from_beziers(&scaled, subpath.closed() && scaled.len() > 1));
+	}
+	result
+}
+
+/// A curve found by [`rounded_corner`] which rounds off the corner between the straight lines before and after it.
+struct RoundedCorner {
+	/// Where the two lines would meet if the corner were sharp.
+	point: DVec2,
+	/// How far away from the point the curve starts and ends along the lines.
+	distances: [f64; 2],
+	/// How far the handles of the curve reach towards the point, as fractions of those distances.
+	handle_fractions: [f64; 2],
+}
+
+/// Finds whether the curve rounds off a corner, by leaving the straight line before it and joining the straight line after it in the same directions as those lines.
+fn rounded_corner(previous: &Bezier, curve: &Bezier, next: &Bezier) -> Option<RoundedCorner> {
+	// Largest sine of the angle between the end of the curve and the line it meets for it to count as meeting the line smoothly
+	const SMOOTHNESS_TOLERANCE: f64 = 1e-3;
+
+	if !is_straight(previous) || !is_straight(next) || is_straight(curve) {
+		return None;
+	}
+	// A quadratic traces the same curve as a cubic with its handles two thirds of the way to its control point
+	let [handle_start, handle_end] = match curve.handles {
+		BezierHandles::Linear => return None,
+		BezierHandles::Quadratic { handle } => [curve.start + (handle - curve.start) * 2. / 3., curve.end + (handle - curve.end) * 2. / 3.],
+		BezierHandles::Cubic { handle_start, handle_end } => [handle_start, handle_end],
+	};
+
+	let incoming = (previous.end - previous.start).try_normalize()?;
+	let outgoing = (next.end - next.start).try_normalize()?;
+	let smooth = |tangent: DVec2, direction: DVec2| {
+		tangent
+			.try_normalize()
+			.is_some_and(|tangent| tangent.perp_dot(direction).abs() < SMOOTHNESS_TOLERANCE && tangent.dot(direction) > 0.)
+	};
+	if !smooth(handle_start - curve.start, incoming) || !smooth(curve.end - handle_end, outgoing) {
+		return None;
+	}
+
+	// Parallel lines never meet, so the curve between them is a U-turn rather than a corner
+	let denominator = incoming.perp_dot(outgoing);
+	if denominator.abs() < SMOOTHNESS_TOLERANCE {
+		return None;
+	}
+	let point = curve.start + incoming * ((curve.end - curve.start).perp_dot(outgoing) / denominator);
+	let distances = [(point - curve.start).dot(incoming), (curve.end - point).dot(outgoing)];
+	if distances.iter().any(|&distance| distance <= 0.) {
+		return None;
+	}
+
+	Some(RoundedCorner {
+		point,
+		distances,
+		handle_fractions: [handle_start.distance(curve.start) / distances[0], handle_end.distance(curve.end) / distances[1]],
+	})
+}
+
+/// Moves the beziers of a subpath by the transform, then rounds each of its rounded corners off again the same distance away from the moved corner point.
+fn scale_rounded_corners(beziers: &[Bezier], closed: bool, transform: DAffine2) -> Vec<Bezier> {
+	let count = beziers.len();
+	let previous = |index: usize| if index > 0 { Some(index - 1) } else { closed.then_some(count - 1) };
+	let next = |index: usize| if index + 1 < count { Some(index + 1) } else { closed.then_some(0) };
+
+	let corners = (0..count)
+		.map(|index| rounded_corner(&beziers[previous(index)?], &beziers[index], &beziers[next(index)?]))
+		.collect::<Vec<_>>();
+	let corner_point = |index: Option<usize>| index.and_then(|index| corners[index].as_ref()).map(|corner| transform.transform_point2(corner.point));
+
+	let rounded = (0..count)
+		.map(|index| {
+			let corner = corners[index].as_ref()?;
+			let (line_before, line_after) = (previous(index)?, next(index)?);
+			let point = transform.transform_point2(corner.point);
+
+			// The far end of each line is shared with any rounded corner there, so each of the two corners may take up to half the line
+			let far_ends = [
+				corner_point(previous(line_before)).map_or((transform.transform_point2(beziers[line_before].start), 1.), |far_point| (far_point, 0.5)),
+				corner_point(next(line_after)).map_or((transform.transform_point2(beziers[line_after].end), 1.), |far_point| (far_point, 0.5)),
+			];
+			let [start, end] = [0, 1].map(|side| {
+				let (far_end, share) = far_ends[side];
+				let distance = corner.distances[side].min(point.distance(far_end) * share);
+				point + (far_end - point).normalize_or_zero() * distance
+			});
+
+			let [start_fraction, end_fraction] = corner.handle_fractions;
+			Some(Bezier::from_cubic_dvec2(start, start + (point - start) * start_fraction, end + (point - end) * end_fraction, end))
+		})
+		.collect::<Vec<_>>();
+
+	(0..count)
+		.map(|index| {
+			if let Some(curve) = rounded[index] {
+				return curve;
+			}
+			let bezier = beziers[index].apply_transformation(|point| transform.transform_point2(point));
+			let start = previous(index).and_then(|previous| rounded[previous]).map_or(bezier.start, |curve| curve.end);
+			let end = next(index).and_then(|next| rounded[next]).map_or(bezier.end, |curve| curve.start);
+			// Only the straight lines leading into rounded corners are moved, which are left without handles that would be off their new anchors
+			if start == bezier.start && end == bezier.end {
+				bezier
+			} else {
+				Bezier::from_linear_dvec2(start, end)
+			}
+		})
+		.collect()
+}
+
+pub struct AlignToReferenceNode<VectorData, Reference, Scale> {
+	vector_data: VectorData,
+	reference: Reference,
+	scale: Scale,
+}
+
+/// Moves, turns, and optionally resizes the shape to fit over the reference as closely as possible, for lining up traced scans with their templates.
+///
+/// Both outlines are resampled into evenly spaced points, and the fit minimizes the squared distances between matching points.
+/// Every starting point and both directions around the outline are tried, so shapes drawn from a different place or the other way around still line up.
+#[node_macro::node_fn(AlignToReferenceNode)]
+async fn align_to_reference<VectorDataFuture: Future<Output = VectorData>, ReferenceFuture: Future<Output = VectorData>>(
+	footprint: Footprint,
+	vector_data: impl Node<Footprint, Output = VectorDataFuture>,
+	reference: impl Node<Footprint, Output = ReferenceFuture>,
+	scale: bool,
+) -> VectorData {
+	// Number of points each outline is resampled into
+	const SAMPLES: usize = 128;
+
+	let mut vector_data = self.vector_data.eval(footprint).await;
+	let reference = self.reference.eval(footprint).await;
+
+	let source = outline_samples(&vector_data, SAMPLES);
+	let target = outline_samples(&reference, SAMPLES);
+	if source.is_empty() || source.len() != target.len() {
+		return vector_data;
+	}
+
+	let centroid = |points: &[DVec2]| points.iter().sum::<DVec2>() / points.len() as f64;
+	let (source_center, target_center) = (centroid(&source), centroid(&target));
+	let source = source.into_iter().map(|point| point - source_center).collect::<Vec<_>>();
+	let target = target.into_iter().map(|point| point - target_center).collect::<Vec<_>>();
+	let source_size = source.iter().map(|point| point.length_squared()).sum::<f64>();
+
+	// Treating the points as complex numbers, the best rotation (and scale) is given by the sum of each source point's conjugate times its matching target point.
+	// The pairing with the largest sum leaves the smallest squared distances, with or without scaling.
+	let count = source.len();
+	let mut best = DVec2::ZERO;
+	for reversed in [false, true] {
+		for shift in 0..count {
+			let sum = source
+				.iter()
+				.enumerate()
+				.map(|(index, &source)| {
+					let target = target[if reversed { (shift + count - index) % count } else { (shift + index) % count }];
+					DVec2::new(source.dot(target), source.perp_dot(target))
+				})
+				.sum::<DVec2>();
+			if sum.length_squared() > best.length_squared() {
+				best = sum;
+			}
+		}
+	}
+
+	let rotation = best.try_normalize().unwrap_or(DVec2::X);
+	let factor = if scale && source_size > 0. { best.length() / source_size } else { 1. };
+	let matrix = glam::DMat2::from_cols(rotation, rotation.perp()) * factor;
+	let alignment = DAffine2::from_mat2_translation(matrix, target_center - matrix * source_center);
+	vector_data.transform = alignment * vector_data.transform;
+	vector_data
+}
+
+pub struct HatchFillNode<Angle, Spacing, CrossHatch> {
+	angle: Angle,
+	spacing: Spacing,
+	cross_hatch: CrossHatch,
+}
+
+/// Fills the closed subpaths with parallel hatch lines, clipped to the interior of the shape by the even-odd rule, so pen plotters can draw the fill as strokes.
+///
+/// The spacing is measured in document space, and is widened where needed to keep to a thousand lines across the shape. The lines keep the stroke of the input, or a thin black stroke if it has none.
+#[node_macro::node_fn(HatchFillNode)]
+fn hatch_fill(vector_data: VectorData, angle: f64, spacing: f64, cross_hatch: bool) -> VectorData {
+	// Number of line segments each curved segment is flattened into when finding where the hatch lines cross it
+	const FLATTENING_STEPS: usize = 64;
+	// Most lines across the shape in each direction, which keeps a tiny spacing from generating an endless number of lines
+	const MAX_LINES: f64 = 1000.;
+
+	let polygons = flattened_regions(&vector_data, FLATTENING_STEPS);
+	let (min, max) = polygons
+		.iter()
+		.flatten()
+		.fold((DVec2::INFINITY, DVec2::NEG_INFINITY), |(min, max), &point| (min.min(point), max.max(point)));
+	let diagonal = (max - min).length();
+
+	let mut lines = Vec::new();
+	if spacing > 0. && diagonal > 0. {
+		let spacing = spacing.max(diagonal / MAX_LINES);
+		let angles = if cross_hatch { vec![angle, angle + 90.] } else { vec![angle] };
+		for angle in angles {
+			lines.extend(hatch_lines(&polygons, angle.to_radians(), spacing).into_iter().map(|(_, line)| line));
+		}
+	}
+
+	let inverse_transform = vector_data.transform.inverse();
+	let mut result = VectorData::from_subpaths(
+		lines
+			.into_iter()
+			.map(|[start, end]| Subpath::new_line(inverse_transform.transform_point2(start), inverse_transform.transform_point2(end))),
+	);
+	result.transform = vector_data.transform;
+	result.alpha_blending = vector_data.alpha_blending;
+	result.style.set_fill(Fill::None);
+	result.style.set_stroke(vector_data.style.stroke().unwrap_or_else(|| Stroke::new(Some(Color::BLACK), 1.)));
+	result
+}
+
+pub struct HatchShadingNode<LightDirection, Angle, MinSpacing, MaxSpacing, CrossHatch, Jitter, Seed> {
+	light_direction: LightDirection,
+	angle: Angle,
+	min_spacing: MinSpacing,
+	max_spacing: MaxSpacing,
+	cross_hatch: CrossHatch,
+	jitter: Jitter,
+	seed: Seed,
+}
+
+/// Shades the closed subpaths with hatch lines drawn closer together where the shape turns away from the light, like an engraving, so pen plotters can draw shading as strokes.
+///
+/// The shape is shaded as a rounded surface rising from its outline to its middle, whose slope at each point faces away from the nearest point of the outline.
+/// The light shines from the light direction in degrees (where 0 is from the right), halfway up from the horizon. The lines are the max spacing apart where
+/// the shading is lightest, narrowing down to the min spacing where it's darkest, with the highlights left blank. Where the shading is darker than the cross hatch
+/// amount from 0 to 1, a second set of lines crosses the first.
+///
+/// The seed gives each node its own random style, turning each set of lines by up to the jitter in degrees. The lines keep the stroke of the input, or a thin black stroke if it has none.
+#[node_macro::node_fn(HatchShadingNode)]
+fn hatch_shading(vector_data: VectorData, light_direction: f64, angle: f64, min_spacing: f64, max_spacing: f64, cross_hatch: f64, jitter: f64, seed: u32) -> VectorData {
+	// Number of line segments each curved segment is flattened into when finding where the hatch lines cross it
+	const FLATTENING_STEPS: usize = 64;
+	// Most lines across the shape at the min spacing, which keeps a tiny spacing from generating an endless number of lines
+	const MAX_LINES: f64 = 1000.;
+	// Most times the max spacing is halved to reach the min spacing, each halving adding lines between those of the lighter shades
+	const MAX_LEVELS: u32 = 8;
+
+	let polygons = flattened_regions(&vector_data, FLATTENING_STEPS);
+	let edges = polygons
+		.iter()
+		.flat_map(|polygon| polygon.iter().zip(polygon.iter().cycle().skip(1)).map(|(&start, &end)| [start, end]))
+		.collect::<Vec<_>>();
+	let (min, max) = polygons
+		.iter()
+		.flatten()
+		.fold((DVec2::INFINITY, DVec2::NEG_INFINITY), |(min, max), &point| (min.min(point), max.max(point)));
+	let diagonal = (max - min).length();
+
+	let mut lines = Vec::new();
+	if max_spacing > 0. && diagonal > 0. {
+		let max_spacing = max_spacing.max(diagonal / MAX_LINES);
+		let levels = (max_spacing / min_spacing.max(diagonal / MAX_LINES)).log2().round().clamp(0., MAX_LEVELS as f64) as u32;
+		let spacing = max_spacing / 2_f64.powi(levels as i32);
+
+		let light = DVec2::from_angle(light_direction.to_radians()) * core::f64::consts::FRAC_1_SQRT_2;
+		let light_height = core::f64::consts::FRAC_1_SQRT_2;
+
+		let mut rng = rand::rngs::StdRng::seed_from_u64(seed as u64);
+		let mut jittered = |angle: f64| angle + jitter * rng.gen_range(-1_f64..=1.);
+		let families = [(jittered(angle), 0.), (jittered(angle + 90.), cross_hatch.clamp(0., 1.))];
+
+		// Each line is sampled along its length, with the distance to the nearest point of the outline and the direction away from it
+		let mut sampled_lines = Vec::new();
+		for (family, &(angle, _)) in families.iter().enumerate() {
+			for (index, [start, end]) in hatch_lines(&polygons, angle.to_radians(), spacing) {
+				let count = (start.distance(end) / (spacing / 2.)).ceil().max(1.) as usize;
+				let samples = (0..=count)
+					.map(|sample| {
+						let point = start.lerp(end, sample as f64 / count as f64);
+						let nearest = edges
+							.iter()
+							.map(|&[start, end]| closest_point_on_segment(point, start, end))
+							.min_by(|a, b| a.distance_squared(point).total_cmp(&b.distance_squared(point)))
+							.unwrap_or(point);
+						(point, point.distance(nearest), (point - nearest).normalize_or_zero())
+					})
+					.collect::<Vec<_>>();
+				sampled_lines.push((family, index, samples));
+			}
+		}
+		let depth = sampled_lines.iter().flat_map(|(_, _, samples)| samples).map(|&(_, distance, _)| distance).fold(0., f64::max).max(1e-9);
+
+		for (family, index, samples) in sampled_lines {
+			// Lines at every power of two steps apart appear one shade darker than those twice as far apart, so the spacing halves with each shade
+			let level = levels - (index as u32).trailing_zeros().min(levels);
+			let start_darkness = families[family].1;
+			let threshold = start_darkness + (1. - start_darkness) * (level + 1) as f64 / (levels + 2) as f64;
+
+			let darkness = |&(_, distance, inward): &(DVec2, f64, DVec2)| {
+				// The point's position from the middle (0) to the outline (1), which is how far the surface normal leans outward
+				let lean = (1. - distance / depth).clamp(0., 1.);
+				let brightness = -inward.dot(light) * lean + light_height * (1. - lean * lean).sqrt();
+				1. - brightness.max(0.)
+			};
+
+			let mut run_start = None;
+			for (sample_index, sample) in samples.iter().enumerate() {
+				let shaded = darkness(sample) > threshold;
+				match (run_start, shaded) {
+					(None, true) => run_start = Some(sample.0),
+					(Some(start), false) => {
+						lines.push([start, samples[sample_index - 1].0]);
+						run_start = None;
+					}
+					_ => {}
+				}
+			}
+			if let (Some(start), Some(last)) = (run_start, samples.last()) {
+				lines.push([start, last.0]);
+			}
+		}
+	}
+
+	let inverse_transform = vector_data.transform.inverse();
+	let mut result = VectorData::from_subpaths(
+		lines
+			.into_iter()
+			.filter(|[start, end]| start.distance(end) > 1e-9)
+			.map(|[start, end]| Subpath::new_line(inverse_transform.transform_point2(start), inverse_transform.transform_point2(end))),
+	);
+	result.transform = vector_data.transform;
+	result.alpha_blending = vector_data.alpha_blending;
+	result.style.set_fill(Fill::None);
+	result.style.set_stroke(vector_data.style.stroke().unwrap_or_else(|| Stroke::new(Some(Color::BLACK), 1.)));
+	result
+}
+
+pub struct TangentNormalLinesNode<Spacing, Length, Normal> {
+	spacing: Spacing,
+	length: Length,
+	normal: Normal,
+}
+
+/// Samples each subpath at evenly spaced distances and outputs a short line at every sample, starting at the path and pointing along its tangent (or its normal).
+///
+/// A negative length flips the lines to the other side. The lines keep the stroke of the input, or a thin black stroke if it has none.
+#[node_macro::node_fn(TangentNormalLinesNode)]
+fn tangent_normal_lines(vector_data: VectorData, spacing: f64, length: f64, normal: bool) -> VectorData {
+	let inverse_transform = vector_data.transform.inverse();
+	let mut lines = Vec::new();
+
+	if spacing > 0. {
+		for subpath in vector_data.stroke_bezier_paths() {
+			let segments = subpath
+				.iter()
+				.map(|bezier| bezier.apply_transformation(|point| vector_data.transform.transform_point2(point)))
+				.map(|bezier| (bezier, bezier.length(None)))
+				.filter(|&(_, length)| length > 0.)
+				.collect::<Vec<_>>();
+			let total_length: f64 = segments.iter().map(|(_, length)| length).sum();
+			if total_length <= 0. {
+				continue;
+			}
+
+			// Closed subpaths end where they start, so the sample at the very end would be a duplicate of the first
+			let count = (total_length / spacing + 1e-9).floor() as usize;
+			let count = if subpath.closed() && count as f64 * spacing >= total_length - 1e-9 { count } else { count + 1 };
+
+			let mut segment_index = 0;
+			let mut length_before = 0.;
+			for index in 0..count {
+				let distance = (index as f64 * spacing).min(total_length);
+				while segment_index + 1 < segments.len() && length_before + segments[segment_index].1 < distance {
+					length_before += segments[segment_index].1;
+					segment_index += 1;
+				}
+
+				let (bezier, segment_length) = segments[segment_index];
+				let t = bezier.euclidean_to_parametric_with_total_length(((distance - length_before) / segment_length).clamp(0., 1.), 0.001, segment_length);
+				let point = bezier.evaluate(TValue::Parametric(t));
+				let tangent = bezier_tangent(&bezier, t);
+
+				let direction = if normal { tangent.perp() } else { tangent };
+				if direction == DVec2::ZERO {
+					continue;
+				}
+				lines.push([point, point + direction * length].map(|point| inverse_transform.transform_point2(point)));
+			}
+		}
+	}
+
+	let mut result = VectorData::from_subpaths(lines.into_iter().map(|[start, end]| Subpath::new_line(start, end)));
+	result.transform = vector_data.transform;
+	result.alpha_blending = vector_data.alpha_blending;
+	result.style.set_fill(Fill::None);
+	result.style.set_stroke(vector_data.style.stroke().unwrap_or_else(|| Stroke::new(Some(Color::BLACK), 1.)));
+	result
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExtrudeNode<Direction, Shading, SideColor> {
+	direction: Direction,
+	shading: Shading,
+	side_color: SideColor,
+}
+
+/// Sweeps the shape along the direction, for a simple isometric extrusion or long shadow. The result holds a group of the side faces swept out by each piece of
+/// the outline, followed by a copy of the shape moved to the end of the sweep as its cap.
+///
+/// The side faces are filled with the side color, or the shape's own color when there's none, and darkened by up to the shading amount the further they face
+/// away from the light shining from the top left. The faces facing away from the direction of the sweep come first, so those facing towards it are drawn over them.
+#[node_macro::node_fn(ExtrudeNode)]
+fn extrude(vector_data: VectorData, direction: DVec2, shading: f64, side_color: Option<Color>) -> GraphicGroup {
+	// Number of pieces each curved segment is split into, each given its own face so that its shade follows the curve
+	const PIECES_PER_CURVE: usize = 16;
+	let light = DVec2::new(-1., -1.).normalize();
+
+	let base_color = side_color.unwrap_or_else(|| match vector_data.style.fill() {
+		Fill::None => vector_data.style.stroke().and_then(|stroke| stroke.color()).unwrap_or(Color::BLACK),
+		fill => fill.color(),
+	});
+
+	let mut faces = Vec::new();
+	for mut subpath in vector_data.stroke_bezier_paths() {
+		subpath.apply_transform(vector_data.transform);
+		// The outward side of each piece depends on which way around the subpath winds
+		let winding = if signed_area(&flatten_subpath(&subpath, DAffine2::IDENTITY, 8)) < 0. { -1. } else { 1. };
+
+		for bezier in subpath.iter() {
+			let straight = match bezier.handles {
+				BezierHandles::Linear => true,
+				BezierHandles::Quadratic { handle } => distance_to_segment(handle, bezier.start, bezier.end) <= 1e-9,
+				BezierHandles::Cubic { handle_start, handle_end } => [handle_start, handle_end].iter().all(|&handle| distance_to_segment(handle, bezier.start, bezier.end) <= 1e-9),
+			};
+			let pieces = if straight { 1 } else { PIECES_PER_CURVE };
+			for piece in 0..pieces {
+				let [start, end] = [piece, piece + 1].map(|t| TValue::Parametric(t as f64 / pieces as f64));
+				let piece = bezier.trim(start, end);
+				if straight && piece.start.distance(piece.end) <= 1e-9 {
+					continue;
+				}
+
+				let outward = -bezier_tangent(&piece, 0.5).perp() * winding;
+				let darkness = shading.clamp(0., 1.) * (1. - outward.dot(light)) / 2.;
+				let mut face = VectorData::from_subpath(Subpath::from_beziers(
+					&[
+						piece,
+						Bezier::from_linear_dvec2(piece.end, piece.end + direction),
+						piece.translate(direction).reverse(),
+						Bezier::from_linear_dvec2(piece.start + direction, piece.start),
+					],
+					true,
+				));
+				face.style.set_fill(Fill::Solid(base_color.map_rgb(|channel| channel * (1. - darkness as f32))));
+				face.alpha_blending = vector_data.alpha_blending;
+				faces.push((outward.dot(direction), face));
+			}
+		}
+	}
+	faces.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+
+	let mut sides = GraphicGroup::EMPTY;
+	sides.extend(faces.into_iter().map(|(_, face)| face.into()));
+	let mut cap = vector_data;
+	cap.transform = DAffine2::from_translation(direction) * cap.transform;
+
+	let mut extrusion = GraphicGroup::EMPTY;
+	extrusion.push(sides.into());
+	extrusion.push(cap.into());
+	extrusion
+}
+
+/// The shape of the cells that [`MosaicNode`] divides the artwork into.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum MosaicCell {
+	/// Squares in rows and columns, like the stitches of a cross-stitch pattern
+	#[default]
+	Square,
+	/// Hexagons in rows offset by half a cell, which fit together without gaps
+	Hexagon,
+	/// Circles in rows and columns, like beads or dots
+	Circle,
+}
+
+/// Something drawn in the artwork that a cell of a [`MosaicNode`] can take its color from.
+enum MosaicSource<'a> {
+	/// Filled polygons in document space, which cover a point according to the fill rule
+	Region { polygons: Vec<Vec<DVec2>>, fill_rule: FillRule, color: Color },
+	/// An image, along with the transform from document space to its unit square
+	Image { frame: &'a ImageFrame<Color>, inverse_transform: DAffine2 },
+}
+
+impl MosaicSource<'_> {
+	/// The color drawn at the point, if this covers it with anything that isn't fully transparent.
+	fn color_at(&self, point: DVec2) -> Option<Color> {
+		let color = match self {
+			Self::Region { polygons, fill_rule, color } => covers_point(polygons, *fill_rule, point).then_some(*color)?,
+			Self::Image { frame, inverse_transform } => {
+				let uv = inverse_transform.transform_point2(point);
+				if !(0. ..1.).contains(&uv.x) || !(0. ..1.).contains(&uv.y) {
+					return None;
+				}
+				frame.sample(uv * DVec2::new(frame.image.width as f64, frame.image.height as f64))
+			}
+		};
+		(color.a() > 0.).then_some(color)
+	}
+}
+
+/// Whether the point is filled by the polygons of a shape with the fill rule.
+fn covers_point(polygons: &[Vec<DVec2>], fill_rule: FillRule, point: DVec2) -> bool {
+	let winding = polygons.iter().map(|polygon| winding_number(polygon, point));
+	match fill_rule {
+		FillRule::NonZero => winding.sum::<i32>() != 0,
+		FillRule::EvenOdd => winding.filter(|&winding| winding != 0).count() % 2 == 1,
+	}
+}
+
+/// How many times the polygon winds counterclockwise around the point, counting clockwise turns as negative.
+fn winding_number(polygon: &[DVec2], point: DVec2) -> i32 {
+	let mut winding = 0;
+	for (index, &start) in polygon.iter().enumerate() {
+		let end = polygon[(index + 1) % polygon.len()];
+		let side = (end - start).perp_dot(point - start);
+		if start.y <= point.y && end.y > point.y && side > 0. {
+			winding += 1;
+		} else if start.y > point.y && end.y <= point.y && side < 0. {
+			winding -= 1;
+		}
+	}
+	winding
+}
+
+/// Collects the filled shapes and images of the element in the order they're drawn, with the transform placing the element in document space.
+/// Strokes and text are left out, as are the backgrounds of artboards, so only the artwork itself is sampled.
+fn mosaic_sources<'a>(element: &'a GraphicElement, transform: DAffine2, sources: &mut Vec<MosaicSource<'a>>) {
+	// Number of line segments each curved segment is flattened into when testing which cells it covers
+	const FLATTENING_STEPS: usize = 16;
+
+	let mut add_region = |vector_data: &VectorData, transform: DAffine2| {
+		if vector_data.style.fill() == &Fill::None {
+			return;
+		}
+		let polygons = flattened_regions(vector_data, FLATTENING_STEPS)
+			.into_iter()
+			.map(|polygon| polygon.into_iter().map(|point| transform.transform_point2(point)).collect())
+			.collect();
+		sources.push(MosaicSource::Region {
+			polygons,
+			fill_rule: vector_data.style.fill_rule(),
+			color: vector_data.style.fill().color(),
+		});
+	};
+
+	match element {
+		GraphicElement::GraphicGroup(group) => group.iter().for_each(|element| mosaic_sources(element, transform * group.transform, sources)),
+		GraphicElement::VectorData(vector_data) => add_region(vector_data, transform),
+		GraphicElement::VectorInstances(instances) => {
+			for &instance in &instances.instances {
+				add_region(&instances.geometry, transform * instances.transform * instance);
+			}
+		}
+		GraphicElement::ImageFrame(frame) => {
+			let image_transform = transform * frame.transform;
+			if image_transform.matrix2.determinant() != 0. {
+				sources.push(MosaicSource::Image {
+					frame,
+					inverse_transform: image_transform.inverse(),
+				});
+			}
+		}
+		GraphicElement::Text(_) => {}
+		GraphicElement::Artboard(artboard) => {
+			let transform = transform * DAffine2::from_translation(artboard.location.as_dvec2()) * artboard.graphic_group.transform;
+			artboard.graphic_group.iter().for_each(|element| mosaic_sources(element, transform, sources));
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MosaicNode<CellShape, CellSize, Gap, Levels> {
+	cell_shape: CellShape,
+	cell_size: CellSize,
+	gap: Gap,
+	levels: Levels,
+}
+
+/// Rebuilds the artwork out of a grid of cells, each a single color taken from whatever is drawn at its center, for cross-stitch patterns and mosaic effects.
+///
+/// The cell size is the distance between the centers of neighboring cells, and each cell is shrunk by the gap to leave grout lines between them. Cells over
+/// empty parts of the artwork are left out. With more than one level, the colors are posterized to that many levels of each channel, which limits the
+/// palette to fewer colors of thread or tile.
+///
+/// The cells of each color are copies of one shared cell, so the result is a group holding a set of instances for each color in the palette.
+#[node_macro::node_fn(MosaicNode)]
+fn mosaic(graphic_group: GraphicGroup, cell_shape: MosaicCell, cell_size: f64, gap: f64, levels: u32) -> GraphicGroup {
+	// Most cells the artwork is divided into, which keeps a tiny cell size from generating an endless number of them
+	const MAX_CELLS: f64 = 250_000.;
+
+	let mut result = GraphicGroup::EMPTY;
+	let Some([min, max]) = graphic_group.bounding_box(DAffine2::IDENTITY) else { return result };
+	let area = (max - min).x * (max - min).y;
+	let cell_size = cell_size.max((area / MAX_CELLS).sqrt());
+	if !cell_size.is_finite() || cell_size <= 0. {
+		return result;
+	}
+
+	let mut sources = Vec::new();
+	for element in graphic_group.iter() {
+		mosaic_sources(element, graphic_group.transform, &mut sources);
+	}
+
+	// Hexagons are packed into rows closer than their width, with every other row shifted over by half a cell
+	let row_spacing = match cell_shape {
+		MosaicCell::Hexagon => cell_size * 3_f64.sqrt() / 2.,
+		MosaicCell::Square | MosaicCell::Circle => cell_size,
+	};
+	let rows = ((max.y - min.y) / row_spacing).ceil().max(1.) as usize;
+	let columns = ((max.x - min.x) / cell_size).ceil().max(1.) as usize;
+
+	let quantize = |color: Color| {
+		if levels < 2 {
+			return color;
+		}
+		let steps = (levels - 1) as f32;
+		color.map_rgba(|channel| (channel.clamp(0., 1.) * steps).round() / steps)
+	};
+
+	let mut palette: Vec<(Color, Vec<DAffine2>)> = Vec::new();
+	let mut palette_indices = HashMap::new();
+	for row in 0..rows {
+		let offset = if cell_shape == MosaicCell::Hexagon && row % 2 == 1 { cell_size / 2. } else { 0. };
+		let columns = if offset > 0. { columns + 1 } else { columns };
+		for column in 0..columns {
+			let center = min + DVec2::new((column as f64 + 0.5) * cell_size - offset, (row as f64 + 0.5) * row_spacing);
+			let Some(color) = sources.iter().rev().find_map(|source| source.color_at(center)) else { continue };
+			let color = quantize(color);
+
+			let palette_index = *palette_indices.entry(color.to_rgba8_srgb()).or_insert_with(|| {
+				palette.push((color, Vec::new()));
+				palette.len() - 1
+			});
+			palette[palette_index].1.push(DAffine2::from_translation(center));
+		}
+	}
+
+	let radius = (cell_size - gap).max(0.) / 2.;
+	let cell = match cell_shape {
+		MosaicCell::Square => Subpath::new_rect(DVec2::splat(-radius), DVec2::splat(radius)),
+		MosaicCell::Circle => Subpath::new_ellipse(DVec2::splat(-radius), DVec2::splat(radius)),
+		MosaicCell::Hexagon => {
+			// The corners of a hexagon are further out than the middle of its sides, which meet those of its neighbors
+			let corner_radius = radius * 2. / 3_f64.sqrt();
+			Subpath::from_anchors((0..6).map(|corner| DVec2::from_angle((corner as f64 * 60. - 90.).to_radians()) * corner_radius), true)
+		}
+	};
+
+	for (color, instances) in palette {
+		let mut geometry = VectorData::from_subpath(cell.clone());
+		geometry.style.set_fill(Fill::Solid(color));
+		result.push(VectorInstances::from_transforms(geometry, instances).into());
+	}
+	result
+}
+
+/// Intersects a set of parallel lines at the given angle with the polygons, returning the segments of the lines that lie inside an odd number of polygons
+/// along with the index of the line each segment is part of.
+fn hatch_lines(polygons: &[Vec<DVec2>], angle: f64, spacing: f64) -> Vec<(usize, [DVec2; 2])> {
+	// Work in a rotated frame where the hatch lines are horizontal
+	let to_hatch_space = DAffine2::from_angle(-angle);
+	let from_hatch_space = DAffine2::from_angle(angle);
+	let polygons = polygons
+		.iter()
+		.map(|polygon| polygon.iter().map(|&point| to_hatch_space.transform_point2(point)).collect::<Vec<_>>())
+		.collect::<Vec<_>>();
+
+	let (min_y, max_y) = polygons
+		.iter()
+		.flatten()
+		.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), point| (min.min(point.y), max.max(point.y)));
+	let mut lines = Vec::new();
+	let mut crossings = Vec::new();
+
+	// Offset the lines by half of the spacing so they are centered within the shape
+	let mut y = min_y + spacing / 2.;
+	let mut index = 0;
+	while y < max_y {
+		crossings.clear();
+		for polygon in &polygons {
+			for (index, &start) in polygon.iter().enumerate() {
+				let end = polygon[(index + 1) % polygon.len()];
+				// Half-open test, so a line passing through a vertex only crosses one of its two edges
+				if (start.y <= y) != (end.y <= y) {
+					crossings.push(start.x + (y - start.y) / (end.y - start.y) * (end.x - start.x));
+				}
+			}
+		}
+		crossings.sort_by(f64::total_cmp);
+
+		for pair in crossings.chunks_exact(2) {
+			lines.push((index, [DVec2::new(pair[0], y), DVec2::new(pair[1], y)].map(|point| from_hatch_space.transform_point2(point))));
+		}
+		y += spacing;
+		index += 1;
+	}
+
+	lines
+}
+
+/// Flattens the closed subpaths into polygons in document space, splitting each curved segment into the given number of line segments.
+fn flattened_regions(vector_data: &VectorData, steps: usize) -> Vec<Vec<DVec2>> {
+	vector_data
+		.region_bezier_paths()
+		.map(|(_, subpath)| flatten_subpath(&subpath, vector_data.transform, steps))
+		.filter(|polygon| polygon.len() >= 3)
+		.collect()
+}
+
+/// Flattens the transformed subpath into the points of a polygon, splitting each curved segment into the given number of line segments.
+fn flatten_subpath(subpath: &Subpath<PointId>, transform: DAffine2, steps: usize) -> Vec<DVec2> {
+	let mut polygon = Vec::new();
+	for bezier in subpath.iter() {
+		let bezier = bezier.apply_transformation(|point| transform.transform_point2(point));
+		let steps = if bezier.handles == BezierHandles::Linear { 1 } else { steps };
+		polygon.extend((0..steps).map(|step| bezier.evaluate(TValue::Parametric(step as f64 / steps as f64))));
+	}
+	polygon
+}
+
+/// Name of the per-point attribute, written by [`MedialAxisNode`], holding the distance from each point of the skeleton to the nearest part of the outline.
+pub const RADIUS_ATTRIBUTE: &str = "radius";
+
+pub struct MedialAxisNode<Spacing, Pruning> {
+	spacing: Spacing,
+	pruning: Pruning,
+}
+
+/// Finds the centerline skeleton of the filled regions, the lines running midway between their opposite sides, such as for turning filled letterforms into single strokes.
+///
+/// The outlines are sampled at the spacing, in document space, and the skeleton is made of the Voronoi edges between those samples that lie inside the shape.
+/// Branches running out towards the outline are pruned unless the two samples they're between are further apart along the outline than the pruning ratio times the distance straight between them, so higher values keep only the main lines.
+/// Each point of the skeleton gets a [`RADIUS_ATTRIBUTE`] giving the local half thickness of the shape. The skeleton is made of many short lines, which the Fit Curve node can smooth out.
+#[node_macro::node_fn(MedialAxisNode)]
+fn medial_axis(vector_data: VectorData, spacing: f64, pruning: f64) -> VectorData {
+	// Number of line segments each curved segment is flattened into before being sampled
+	const FLATTENING_STEPS: usize = 32;
+	// Largest number of samples taken around all the outlines, beyond which the spacing is widened
+	const MAX_SAMPLES: f64 = 4000.;
+
+	let polygons = flattened_regions(&vector_data, FLATTENING_STEPS);
+	let perimeter = polygons.iter().map(|polygon| polygon_length(polygon)).sum::<f64>();
+	let spacing = spacing.max(perimeter / MAX_SAMPLES).max(1e-3);
+
+	// Each sample is kept with its outline and how far around the outline it is, to tell how far apart two samples are along the outline
+	let mut samples = Vec::new();
+	let mut placements = Vec::new();
+	for (outline, polygon) in polygons.iter().enumerate() {
+		let length = polygon_length(polygon);
+		let count = ((length / spacing).ceil() as usize).max(3);
+		for (point, distance) in resample_polygon(polygon, count) {
+			samples.push(point);
+			placements.push((outline, distance, length));
+		}
+	}
+	let separation = |a: usize, b: usize| {
+		let ((a_outline, a_distance, length), (b_outline, b_distance, _)) = (placements[a], placements[b]);
+		if a_outline != b_outline {
+			return f64::INFINITY;
+		}
+		let along = (a_distance - b_distance).abs();
+		along.min(length - along)
+	};
+	let inside = |point: DVec2| polygons.iter().filter(|polygon| inside_polygon(polygon, point)).count() % 2 == 1;
+
+	// Neighbouring triangles of the Delaunay triangulation have the ends of a Voronoi edge as their circumcenters
+	let triangles = delaunay_triangles(&samples);
+	let mut edge_triangles: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+	for (index, triangle) in triangles.iter().enumerate() {
+		for side in 0..3 {
+			let (a, b) = (triangle.vertices[side], triangle.vertices[(side + 1) % 3]);
+			edge_triangles.entry((a.min(b), a.max(b))).or_default().push(index);
+		}
+	}
+	let centers = triangles.iter().map(|triangle| triangle.center).collect::<Vec<_>>();
+	let center_inside = centers.iter().map(|&center| inside(center)).collect::<Vec<_>>();
+
+	let mut edges = Vec::new();
+	for (&(a, b), neighbours) in &edge_triangles {
+		let &[first, second] = neighbours.as_slice() else { continue };
+		if center_inside[first] && center_inside[second] && separation(a, b) > pruning.max(1.) * samples[a].distance(samples[b]) {
+			edges.push((first, second));
+		}
+	}
+	// The order of a hash map isn't stable, so the edges are sorted to give the same result every time
+	edges.sort_unstable();
+
+	// Circumcenters which coincide, like those of triangles around a circle, become a single point of the skeleton
+	let used = edges
+		.iter()
+		.flat_map(|&(first, second)| [first, second])
+		.collect::<std::collections::BTreeSet<_>>()
+		.into_iter()
+		.collect::<Vec<_>>();
+	let groups = weld_groups(&used.iter().map(|&triangle| centers[triangle]).collect::<Vec<_>>(), spacing * 1e-3);
+	let vertex_of = used.iter().enumerate().map(|(index, &triangle)| (triangle, groups[index])).collect::<HashMap<_, _>>();
+	let mut links = edges
+		.into_iter()
+		.map(|(first, second)| (vertex_of[&first], vertex_of[&second]))
+		.filter(|(first, second)| first != second)
+		.map(|(first, second)| (first.min(second), first.max(second)))
+		.collect::<Vec<_>>();
+	links.sort_unstable();
+	links.dedup();
+
+	let to_local = if vector_data.transform.matrix2.determinant() == 0. {
+		DAffine2::IDENTITY
+	} else {
+		vector_data.transform.inverse()
+	};
+	let mut result = VectorData::empty();
+	result.transform = vector_data.transform;
+	result.alpha_blending = vector_data.alpha_blending;
+	result.style.set_fill(Fill::None);
+	result.style.set_stroke(vector_data.style.stroke().unwrap_or_else(|| Stroke::new(Some(Color::BLACK), 1.)));
+
+	let mut point_ids = HashMap::new();
+	let mut radii = Vec::new();
+	for chain in skeleton_chains(&links) {
+		let mut previous = None;
+		for vertex in chain {
+			let id = *point_ids.entry(vertex).or_insert_with(|| {
+				let triangle = &triangles[used[vertex]];
+				let id = PointId::generate();
+				result.point_domain.push(id, to_local.transform_point2(triangle.center));
+				radii.push(triangle.radius_squared.sqrt());
+				id
+			});
+			if let Some(previous) = previous {
+				result.segment_domain.push(SegmentId::generate(), previous, id, BezierHandles::Linear, StrokeId::generate());
+			}
+			previous = Some(id);
+		}
+	}
+	result.point_domain.set_attribute(RADIUS_ATTRIBUTE, AttributeValues::F64(radii));
+
+	result
+}
+
+fn polygon_length(polygon: &[DVec2]) -> f64 {
+	polygon.iter().zip(polygon.iter().cycle().skip(1)).map(|(start, end)| start.distance(*end)).sum()
+}
+
+/// Places the given number of points evenly around the closed polygon, along with how far around it each one is.
+fn resample_polygon(polygon: &[DVec2], count: usize) -> Vec<(DVec2, f64)> {
+	let step = polygon_length(polygon) / count as f64;
+	let mut samples = Vec::with_capacity(count);
+	let mut travelled = 0.;
+	for (&start, &end) in polygon.iter().zip(polygon.iter().cycle().skip(1)) {
+		let length = start.distance(end);
+		while samples.len() < count && (samples.len() as f64 * step) < travelled + length {
+			let distance = samples.len() as f64 * step;
+			samples.push((start.lerp(end, (distance - travelled) / length), distance));
+		}
+		travelled += length;
+	}
+	samples
+}
+
+struct DelaunayTriangle {
+	vertices: [usize; 3],
+	center: DVec2,
+	radius_squared: f64,
+}
+
+impl DelaunayTriangle {
+	fn new(vertices: [usize; 3], points: &[DVec2]) -> Self {
+		let [a, b, c] = vertices.map(|vertex| points[vertex]);
+		let (ab, ac) = (b - a, c - a);
+		let denominator = 2. * ab.perp_dot(ac);
+		// A sliver with no area has its circumcircle at infinity, containing every point
+		if denominator.abs() < 1e-12 {
+			return Self {
+				vertices,
+				center: (a + b + c) / 3.,
+				radius_squared: f64::INFINITY,
+			};
+		}
+		let offset = DVec2::new(ac.y * ab.length_squared() - ab.y * ac.length_squared(), ab.x * ac.length_squared() - ac.x * ab.length_squared()) / denominator;
+		Self {
+			vertices,
+			center: a + offset,
+			radius_squared: offset.length_squared(),
+		}
+	}
+}
+
+/// Triangulates the points so that no point lies inside the circumcircle of any triangle, using the Bowyer-Watson algorithm.
+fn delaunay_triangles(points: &[DVec2]) -> Vec<DelaunayTriangle> {
+	let Some([min, max]) = points.iter().map(|&point| [point, point]).reduce(|[a_min, a_max], [b_min, b_max]| [a_min.min(b_min), a_max.max(b_max)]) else {
+		return Vec::new();
+	};
+
+	// A triangle far larger than the points contains them all to begin with, and is removed at the end
+	let (center, size) = ((min + max) / 2., (max - min).max_element().max(1.) * 20.);
+	let mut all_points = points.to_vec();
+	all_points.extend([center + DVec2::new(-size, -size), center + DVec2::new(size, -size), center + DVec2::new(0., size)]);
+	let outer = points.len();
+	let mut triangles = vec![DelaunayTriangle::new([outer, outer + 1, outer + 2], &all_points)];
+
+	for (index, &point) in points.iter().enumerate() {
+		// The triangles whose circumcircles contain the new point are replaced by a fan around the point, covering the hole they leave
+		let (broken, kept): (Vec<_>, Vec<_>) = triangles.into_iter().partition(|triangle| triangle.center.distance_squared(point) < triangle.radius_squared);
+		let mut sides: HashMap<(usize, usize), (usize, usize, u32)> = HashMap::new();
+		for triangle in &broken {
+			for side in 0..3 {
+				let (a, b) = (triangle.vertices[side], triangle.vertices[(side + 1) % 3]);
+				sides.entry((a.min(b), a.max(b))).or_insert((a, b, 0)).2 += 1;
+			}
+		}
+		triangles = kept;
+		let mut hole_sides = sides.into_values().filter(|&(_, _, count)| count == 1).collect::<Vec<_>>();
+		hole_sides.sort_unstable();
+		triangles.extend(hole_sides.into_iter().map(|(a, b, _)| DelaunayTriangle::new([a, b, index], &all_points)));
+	}
+
+	triangles.retain(|triangle| triangle.vertices.iter().all(|&vertex| vertex < outer));
+	triangles
+}
+
+/// Splits the graph of links between vertices into runs that only branch or end at their first and last vertices, with loops ending where they began.
+fn skeleton_chains(links: &[(usize, usize)]) -> Vec<Vec<usize>> {
+	let mut neighbours: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+	for (index, &(a, b)) in links.iter().enumerate() {
+		neighbours.entry(a).or_default().push((b, index));
+		neighbours.entry(b).or_default().push((a, index));
+	}
+
+	let mut used = vec![false; links.len()];
+	let mut chains = Vec::new();
+	let mut follow = |start: usize, used: &mut Vec<bool>| {
+		for &(next, link) in &neighbours[&start] {
+			if used[link] {
+				continue;
+			}
+			used[link] = true;
+			let mut chain = vec![start, next];
+			let mut current = next;
+			while neighbours[&current].len() == 2 {
+				let Some(&(next, link)) = neighbours[&current].iter().find(|&&(_, link)| !used[link]) else {
+					break;
+				};
+				used[link] = true;
+				chain.push(next);
+				current = next;
+			}
+			chains.push(chain);
+		}
+	};
+
+	// Runs start at the ends and branching points, then whatever is left over is made of loops
+	let mut vertices = neighbours.keys().copied().collect::<Vec<_>>();
+	vertices.sort_unstable();
+	for &vertex in vertices.iter().filter(|vertex| neighbours[vertex].len() != 2) {
+		follow(vertex, &mut used);
+	}
+	for &vertex in &vertices {
+		follow(vertex, &mut used);
+	}
+	chains
+}
+
+pub struct PathfindNode<Start, End, Clearance, CornerRadius> {
+	start: Start,
+	end: End,
+	clearance: Clearance,
+	corner_radius: CornerRadius,
+}
+
+/// Finds the shortest route from the start point to the end point which keeps the clearance away from the closed subpaths of the obstacles, with its corners rounded off, for map routes and for diagram connectors which weave between shapes.
+///
+/// The points are in document space. Obstacles that cover the start or end point are ignored, and if there's no way through the result is a straight line.
+#[node_macro::node_fn(PathfindNode)]
+fn pathfind(obstacles: VectorData, start: DVec2, end: DVec2, clearance: f64, corner_radius: f64) -> VectorData {
+	// Curves are flattened more coarsely than for hatching since every corner of an obstacle is a place the route might turn
+	const FLATTENING_STEPS: usize = 16;
+
+	let obstacles = flattened_regions(&obstacles, FLATTENING_STEPS)
+		.iter()
+		.map(|polygon| inflate_polygon(polygon, clearance.max(0.)))
+		.filter(|polygon| polygon.len() >= 3 && !inside_polygon(polygon, start) && !inside_polygon(polygon, end))
+		.collect::<Vec<_>>();
+	let route = shortest_route(&obstacles, start, end).unwrap_or_else(|| vec![start, end]);
+
+	let mut result = VectorData::from_subpath(rounded_polyline(&route, corner_radius));
+	result.style.set_fill(Fill::None);
+	result.style.set_stroke(Stroke::new(Some(Color::BLACK), 1.));
+	result
+}
+
+// Distance below which points are considered to lie on a line, in document space
+const ROUTE_EPSILON: f64 = 1e-6;
+
+/// Twice the area of the polygon, positive if it winds counterclockwise (with the y axis pointing up).
+fn signed_area(polygon: &[DVec2]) -> f64 {
+	polygon.iter().enumerate().map(|(index, point)| point.perp_dot(polygon[(index + 1) % polygon.len()])).sum()
+}
+
+/// Grows the polygon outwards by the distance, keeping each edge parallel to the original. Sharp corners are squared off rather than extended into long spikes.
+fn inflate_polygon(polygon: &[DVec2], distance: f64) -> Vec<DVec2> {
+	// How many times the distance a corner may extend before it's squared off
+	const MITER_LIMIT: f64 = 4.;
+
+	let mut points = polygon.to_vec();
+	points.dedup_by(|a, b| a.distance(*b) < ROUTE_EPSILON);
+	while points.len() > 1 && points[0].distance(points[points.len() - 1]) < ROUTE_EPSILON {
+		points.pop();
+	}
+	if points.len() < 3 || distance <= 0. {
+		return points;
+	}
+
+	// Which side of each edge is outside depends on the direction the polygon winds
+	let area = signed_area(&points);
+	let outward = |direction: DVec2| if area > 0. { -direction.perp() } else { direction.perp() };
+
+	let mut inflated = Vec::with_capacity(points.len());
+	for (index, &point) in points.iter().enumerate() {
+		let previous = points[(index + points.len() - 1) % points.len()];
+		let next = points[(index + 1) % points.len()];
+		let (incoming, outgoing) = ((point - previous).normalize(), (next - point).normalize());
+		let (incoming_normal, outgoing_normal) = (outward(incoming), outward(outgoing));
+
+		let miter = (incoming_normal + outgoing_normal).normalize_or_zero();
+		let cosine = miter.dot(incoming_normal);
+		let convex = incoming.perp_dot(outgoing) * area >= 0.;
+		if !convex || cosine > 1. / MITER_LIMIT {
+			inflated.push(point + miter * distance / cosine.max(1. / MITER_LIMIT));
+		} else {
+			inflated.push(point + (incoming_normal + incoming) * distance);
+			inflated.push(point + (outgoing_normal - outgoing) * distance);
+		}
+	}
+	inflated
+}
+
+fn distance_to_segment(point: DVec2, start: DVec2, end: DVec2) -> f64 {
+	point.distance(closest_point_on_segment(point, start, end))
+}
+
+fn closest_point_on_segment(point: DVec2, start: DVec2, end: DVec2) -> DVec2 {
+	let direction = end - start;
+	let t = if direction == DVec2::ZERO {
+		0.
+	} else {
+		((point - start).dot(direction) / direction.length_squared()).clamp(0., 1.)
+	};
+	start + direction * t
+}
+
+/// Whether the point is inside the polygon by the even-odd rule, not counting points on its boundary.
+fn inside_polygon(polygon: &[DVec2], point: DVec2) -> bool {
+	let mut inside = false;
+	for (index, &start) in polygon.iter().enumerate() {
+		let end = polygon[(index + 1) % polygon.len()];
+		if distance_to_segment(point, start, end) < ROUTE_EPSILON {
+			return false;
+		}
+		if (start.y <= point.y) != (end.y <= point.y) && point.x < start.x + (point.y - start.y) / (end.y - start.y) * (end.x - start.x) {
+			inside = !inside;
+		}
+	}
+	inside
+}
+
+/// Whether the two segments cross each other, rather than just touching or running alongside one another.
+fn segments_cross(a: DVec2, b: DVec2, c: DVec2, d: DVec2) -> bool {
+	let side = |start: DVec2, end: DVec2, point: DVec2| (end - start).perp_dot(point - start) / start.distance(end).max(ROUTE_EPSILON);
+	let opposite = |first: f64, second: f64| (first > ROUTE_EPSILON && second < -ROUTE_EPSILON) || (first < -ROUTE_EPSILON && second > ROUTE_EPSILON);
+	opposite(side(a, b, c), side(a, b, d)) && opposite(side(c, d, a), side(c, d, b))
+}
+
+/// Whether the line between the points stays out of the insides of all the polygons. Touching their boundaries is allowed, so routes can run along edges and turn at corners.
+fn visible(polygons: &[(Vec<DVec2>, [DVec2; 2])], start: DVec2, end: DVec2) -> bool {
+	let direction = end - start;
+	let (min, max) = (start.min(end), start.max(end));
+
+	polygons
+		.iter()
+		.filter(|(_, [polygon_min, polygon_max])| min.cmple(*polygon_max + ROUTE_EPSILON).all() && max.cmpge(*polygon_min - ROUTE_EPSILON).all())
+		.all(|(polygon, _)| {
+			let mut pieces = vec![0., 1.];
+			for (index, &corner) in polygon.iter().enumerate() {
+				if segments_cross(start, end, corner, polygon[(index + 1) % polygon.len()]) {
+					return false;
+				}
+				// Corners lying on the line split it into pieces which are each either entirely inside or entirely outside the polygon
+				if direction != DVec2::ZERO && distance_to_segment(corner, start, end) < ROUTE_EPSILON {
+					pieces.push((corner - start).dot(direction) / direction.length_squared());
+				}
+			}
+			pieces.sort_by(f64::total_cmp);
+			pieces.windows(2).all(|piece| !inside_polygon(polygon, start + direction * (piece[0] + piece[1]) / 2.))
+		})
+}
+
+/// Finds the shortest route between the points which avoids the insides of the polygons, by A* search over the polygon corners that can see one another.
+fn shortest_route(polygons: &[Vec<DVec2>], start: DVec2, end: DVec2) -> Option<Vec<DVec2>> {
+	let bounded_polygons = polygons
+		.iter()
+		.map(|polygon| {
+			let bounds = polygon.iter().fold([DVec2::INFINITY, DVec2::NEG_INFINITY], |[min, max], &point| [min.min(point), max.max(point)]);
+			(polygon.clone(), bounds)
+		})
+		.collect::<Vec<_>>();
+
+	// A shortest route only ever turns at the convex corners of the obstacles, so the other corners are left out of the search
+	let mut nodes = vec![start, end];
+	for polygon in polygons {
+		let area = signed_area(polygon);
+		for (index, &corner) in polygon.iter().enumerate() {
+			let previous = polygon[(index + polygon.len() - 1) % polygon.len()];
+			let next = polygon[(index + 1) % polygon.len()];
+			let convex = (corner - previous).perp_dot(next - corner) * area > 0.;
+			if convex && !polygons.iter().any(|other| inside_polygon(other, corner)) {
+				nodes.push(corner);
+			}
+		}
+	}
+
+	let mut distances = vec![f64::INFINITY; nodes.len()];
+	let mut previous = vec![None; nodes.len()];
+	let mut visited = vec![false; nodes.len()];
+	let mut open = vec![0];
+	distances[0] = 0.;
+
+	while let Some(position) = (0..open.len()).min_by(|&a, &b| {
+		let estimate = |node: usize| distances[node] + nodes[node].distance(end);
+		estimate(open[a]).total_cmp(&estimate(open[b]))
+	}) {
+		let current = open.swap_remove(position);
+		if current == 1 {
+			let mut route = vec![end];
+			let mut node = current;
+			while let Some(before) = previous[node] {
+				route.push(nodes[before]);
+				node = before;
+			}
+			route.reverse();
+			return Some(route);
+		}
+		visited[current] = true;
+
+		for neighbor in 1..nodes.len() {
+			let distance = distances[current] + nodes[current].distance(nodes[neighbor]);
+			if visited[neighbor] || distance >= distances[neighbor] || !visible(&bounded_polygons, nodes[current], nodes[neighbor]) {
+				continue;
+			}
+			if distances[neighbor] == f64::INFINITY {
+				open.push(neighbor);
+			}
+			distances[neighbor] = distance;
+			previous[neighbor] = Some(current);
+		}
+	}
+
+	None
+}
+
+/// Joins the points with straight lines, rounding off each corner with a curve that starts and ends the radius away from it, or less where the lines are too short.
+fn rounded_polyline(points: &[DVec2], radius: f64) -> Subpath<ManipulatorGroupId> {
+	let mut manipulator_groups = Vec::new();
+	for (index, &point) in points.iter().enumerate() {
+		let (Some(&previous), Some(&next)) = (index.checked_sub(1).and_then(|index| points.get(index)), points.get(index + 1)) else {
+			manipulator_groups.push(ManipulatorGroup::new(point, None, None));
+			continue;
+		};
+
+		let radius = radius.min(point.distance(previous) / 2.).min(point.distance(next) / 2.);
+		if radius <= 0. {
+			manipulator_groups.push(ManipulatorGroup::new(point, None, None));
+			continue;
+		}
+
+		// A cubic with its handles two thirds of the way to the corner traces the same curve as a quadratic with the corner as its control point
+		let (entry, exit) = (point + (previous - point).normalize() * radius, point + (next - point).normalize() * radius);
+		manipulator_groups.push(ManipulatorGroup::new(entry, None, Some(entry + (point - entry) * 2. / 3.)));
+		manipulator_groups.push(ManipulatorGroup::new(exit, Some(exit + (point - exit) * 2. / 3.), None));
+	}
+	Subpath::new(manipulator_groups, false)
+}
+
+/// The property of each subpath that [`FilterSubpathsNode`] compares and sorts by.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum SubpathMeasure {
+	/// The area enclosed by the subpath, where open subpaths are measured as if they were closed
+	#[default]
+	Area,
+	/// The distance along the subpath
+	Length,
+	/// The number of anchor points
+	PointCount,
+	/// The width or height of the bounding box of the subpath, whichever is larger
+	BoundingBoxSize,
+}
+
+impl SubpathMeasure {
+	/// Measures the subpath once the transform is applied to it.
+	pub fn measure(self, subpath: &Subpath<PointId>, transform: DAffine2) -> f64 {
+		// Number of line segments each curved segment is flattened into when finding the area
+		const FLATTENING_STEPS: usize = 64;
+
+		match self {
+			Self::Area => {
+				let polygon = flatten_subpath(subpath, transform, FLATTENING_STEPS);
+				if polygon.len() < 3 {
+					return 0.;
+				}
+				signed_area(&polygon).abs() / 2.
+			}
+			Self::Length => subpath.iter().map(|bezier| bezier.apply_transformation(|point| transform.transform_point2(point)).length(None)).sum(),
+			Self::PointCount => subpath.len() as f64,
+			Self::BoundingBoxSize => subpath.bounding_box_with_transform(transform).map_or(0., |[min, max]| (max - min).max_element()),
+		}
+	}
+}
+
+/// The order that [`FilterSubpathsNode`] outputs the subpaths it keeps in, which also decides which of them are drawn on top.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum SubpathOrder {
+	/// Keep the subpaths in the order they were in
+	#[default]
+	Original,
+	/// Smallest measure first
+	Ascending,
+	/// Largest measure first
+	Descending,
+}
+
+pub struct FilterSubpathsNode<Measure, Minimum, Maximum, Invert, Order> {
+	measure: Measure,
+	minimum: Minimum,
+	maximum: Maximum,
+	invert: Invert,
+	order: Order,
+}
+
+/// Keeps only the subpaths whose measure lies between the minimum and maximum, or only those outside that range when inverted, and can sort them by the measure. Useful for clearing specks and stray marks out of traced artwork.
+///
+/// Measures are taken in document space. A maximum of zero means there's no upper limit.
+#[node_macro::node_fn(FilterSubpathsNode)]
+fn filter_subpaths(vector_data: VectorData, measure: SubpathMeasure, minimum: f64, maximum: f64, invert: bool, order: SubpathOrder) -> VectorData {
+	let maximum = if maximum > 0. { maximum } else { f64::INFINITY };
+	let mut subpaths = vector_data
+		.stroke_bezier_paths()
+		.enumerate()
+		.map(|(index, subpath)| (measure.measure(&subpath, vector_data.transform), index, subpath))
+		.filter(|(value, _, _)| (minimum..=maximum).contains(value) != invert)
+		.collect::<Vec<_>>();
+	match order {
+		SubpathOrder::Original => {}
+		SubpathOrder::Ascending => subpaths.sort_by(|(a, _, _), (b, _, _)| a.total_cmp(b)),
+		SubpathOrder::Descending => subpaths.sort_by(|(a, _, _), (b, _, _)| b.total_cmp(a)),
+	}
+
+	kept_subpaths(&vector_data, subpaths.into_iter().map(|(_, index, subpath)| (index, subpath)))
+}
+
+/// Builds a shape out of some of the subpaths of another, each given with its index, in the given order, which keeps its style, the styles of the subpaths that have their own,
+/// and the per-point attributes of the points that remain. Each subpath must appear at most once, since its points keep their ids.
+fn kept_subpaths(vector_data: &VectorData, subpaths: impl IntoIterator<Item = (usize, Subpath<PointId>)>) -> VectorData {
+	let mut result = VectorData::empty();
+	result.transform = vector_data.transform;
+	result.style = vector_data.style.clone();
+	result.alpha_blending = vector_data.alpha_blending;
+	result.colinear_manipulators.clone_from(&vector_data.colinear_manipulators);
+	for (kept, (index, subpath)) in subpaths.into_iter().enumerate() {
+		result.append_subpath(subpath);
+		if vector_data.subpath_styles.iter().any(|&(subpath, _)| subpath == index) {
+			result.set_subpath_style(kept, vector_data.subpath_style(index).clone());
+		}
+	}
+
+	// Carry over the per-point attributes of the points that were kept
+	let indices = vector_data.point_domain.ids().iter().enumerate().map(|(index, &id)| (id, index)).collect::<HashMap<_, _>>();
+	let kept = result.point_domain.ids().iter().filter_map(|id| indices.get(id).copied()).collect::<Vec<_>>();
+	for (name, values) in vector_data.point_domain.attributes() {
+		result.point_domain.set_attribute(name, values.select(&kept));
+	}
+
+	result
+}
+
+/// What [`GetSubpathNode`] does with the part of its range of subpaths that falls outside of the shape's subpaths.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum IndexOutOfRange {
+	/// Leave out the subpaths past either end, which gives nothing when the whole range is outside
+	#[default]
+	Skip,
+	/// Move the start of the range to the nearest subpath, so there's always at least one
+	Clamp,
+	/// Count on from the first subpath again after the last
+	Wrap,
+}
+
+pub struct GetSubpathNode<Index, Count, OutOfRange> {
+	index: Index,
+	count: Count,
+	out_of_range: OutOfRange,
+}
+
+/// Picks out the subpath at the index, along with the ones after it up to the count, so the pieces of a shape made of several parts can be sent down different branches.
+/// A negative index counts back from the end, with -1 being the last subpath. The subpaths keep their style and point attributes.
+#[node_macro::node_fn(GetSubpathNode)]
+fn get_subpath(vector_data: VectorData, index: f64, count: u32, out_of_range: IndexOutOfRange) -> VectorData {
+	let subpaths = vector_data.stroke_bezier_paths().collect::<Vec<_>>();
+	let indices = subpath_indices(subpaths.len(), index, count, out_of_range);
+	kept_subpaths(&vector_data, indices.into_iter().map(|index| (index, subpaths[index].clone())))
+}
+
+/// The indices of the subpaths in the range from the index up to the count, with a negative index counting back from the end, each at most once.
+fn subpath_indices(length: usize, index: f64, count: u32, out_of_range: IndexOutOfRange) -> Vec<usize> {
+	let length = length as i64;
+	if length == 0 {
+		return Vec::new();
+	}
+
+	let start = index.round() as i64;
+	let start = if start < 0 { start + length } else { start };
+	let count = (count.max(1) as i64).min(length);
+	let indices = match out_of_range {
+		IndexOutOfRange::Skip => (start..start + count).filter(|index| (0..length).contains(index)).collect::<Vec<_>>(),
+		IndexOutOfRange::Clamp => {
+			let start = start.clamp(0, length - 1);
+			(start..(start + count).min(length)).collect()
+		}
+		IndexOutOfRange::Wrap => (start..start + count).map(|index| index.rem_euclid(length)).collect(),
+	};
+	indices.into_iter().map(|index| index as usize).collect()
+}
+
+pub struct StyleSubpathsNode<Source, Index, Count> {
+	source: Source,
+	index: Index,
+	count: Count,
+}
+
+/// Gives the subpath at the index, along with the ones after it up to the count, the style of the source in place of the style of the whole shape,
+/// so a compound path can be drawn in several colors without splitting it into layers. A negative index counts back from the end, with -1 being the last subpath.
+///
+/// Setting the fill or stroke of the shape afterwards only changes the subpaths without a style of their own.
+#[node_macro::node_fn(StyleSubpathsNode)]
+fn style_subpaths(mut vector_data: VectorData, source: VectorData, index: f64, count: u32) -> VectorData {
+	let length = vector_data.stroke_bezier_paths().count();
+	for subpath in subpath_indices(length, index, count, IndexOutOfRange::Skip) {
+		vector_data.set_subpath_style(subpath, source.style.clone());
+	}
+	vector_data
+}
+
+pub struct StyleSubpathsByMeasureNode<Source, Measure, Minimum, Maximum> {
+	source: Source,
+	measure: Measure,
+	minimum: Minimum,
+	maximum: Maximum,
+}
+
+/// Gives the subpaths whose measure, taken in document space, lies between the minimum and maximum the style of the source in place of the style of the whole shape.
+/// A maximum of zero means there's no upper limit.
+#[node_macro::node_fn(StyleSubpathsByMeasureNode)]
+fn style_subpaths_by_measure(mut vector_data: VectorData, source: VectorData, measure: SubpathMeasure, minimum: f64, maximum: f64) -> VectorData {
+	let maximum = if maximum > 0. { maximum } else { f64::INFINITY };
+	let matching = vector_data
+		.stroke_bezier_paths()
+		.enumerate()
+		.filter(|(_, subpath)| (minimum..=maximum).contains(&measure.measure(subpath, vector_data.transform)))
+		.map(|(index, _)| index)
+		.collect::<Vec<_>>();
+	for subpath in matching {
+		vector_data.set_subpath_style(subpath, source.style.clone());
+	}
+	vector_data
+}
+
+pub struct SetPointAttributeNode<Name, Start, End> {
+	name: Name,
+	start: Start,
+	end: End,
+}
+
+/// Stores a number in the named per-point attribute, blending from the start value at the first point to the end value at the last point.
+#[node_macro::node_fn(SetPointAttributeNode)]
+fn set_point_attribute(mut vector_data: VectorData, name: String, start: f64, end: f64) -> VectorData {
+	if !name.is_empty() {
+		let values = point_ramp(vector_data.point_domain.positions().len()).map(|t| start + (end - start) * t).collect();
+		vector_data.point_domain.set_attribute(name, AttributeValues::F64(values));
+	}
+	vector_data
+}
+
+pub struct SetPointVectorAttributeNode<Name, Start, End> {
+	name: Name,
+	start: Start,
+	end: End,
+}
+
+/// Stores a vector in the named per-point attribute, blending from the start value at the first point to the end value at the last point.
+#[node_macro::node_fn(SetPointVectorAttributeNode)]
+fn set_point_vector_attribute(mut vector_data: VectorData, name: String, start: DVec2, end: DVec2) -> VectorData {
+	if !name.is_empty() {
+		let values = point_ramp(vector_data.point_domain.positions().len()).map(|t| start.lerp(end, t)).collect();
+		vector_data.point_domain.set_attribute(name, AttributeValues::DVec2(values));
+	}
+	vector_data
+}
+
+pub struct SetPointColorAttributeNode<Name, Start, End> {
+	name: Name,
+	start: Start,
+	end: End,
+}
+
+/// Stores a color in the named per-point attribute, blending from the start color at the first point to the end color at the last point.
+#[node_macro::node_fn(SetPointColorAttributeNode)]
+fn set_point_color_attribute(mut vector_data: VectorData, name: String, start: Color, end: Color) -> VectorData {
+	if !name.is_empty() {
+		let values = point_ramp(vector_data.point_domain.positions().len()).map(|t| start.lerp(&end, t as f32)).collect();
+		vector_data.point_domain.set_attribute(name, AttributeValues::Color(values));
+	}
+	vector_data
+}
+
+/// Yields the fraction of the way through the points that each of the `count` points lies, from 0 at the first to 1 at the last.
+fn point_ramp(count: usize) -> impl Iterator<Item = f64> {
+	let last = count.saturating_sub(1).max(1) as f64;
+	(0..count).map(move |index| index as f64 / last)
+}
+
+pub struct ReadPointAttributeNode<Name, Index> {
+	name: Name,
+	index: Index,
+}
+
+/// Reads the named per-point attribute at the point with the given index as a number. Vector attributes give their length and color attributes their luminance.
+///
+/// Missing attributes and out of range indices read as 0.
+#[node_macro::node_fn(ReadPointAttributeNode)]
+fn read_point_attribute(vector_data: VectorData, name: String, index: u32) -> f64 {
+	vector_data.point_domain.attribute(&name).and_then(|values| values.scalar(index as usize)).unwrap_or_default()
+}
+
+/// How [`TransferPointAttributesNode`] finds the values for each point from the points of the source.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum TransferMethod {
+	/// Copy the values of the nearest source point
+	#[default]
+	Nearest,
+	/// Blend the values at the corners of the triangle of source points around the point, by its barycentric coordinates
+	Barycentric,
+}
+
+pub struct TransferPointAttributesNode<Points, Source, Name, Method, Radius> {
+	points: Points,
+	source: Source,
+	name: Name,
+	method: Method,
+	radius: Radius,
+}
+
+/// Copies per-point attributes onto each point from the nearest point of the source, or blends them from the triangle of source points around it.
+///
+/// If a name is given, only the attribute with that name is transferred, otherwise all of the source's attributes are.
+/// Points farther than the radius from every source point keep the values they had, or get the default of 0, unless the radius is 0 to have no limit.
+/// Points outside of the triangles that the source points make up take the values of the nearest one when blending.
+#[node_macro::node_fn(TransferPointAttributesNode)]
+async fn transfer_point_attributes<PointsFuture: Future<Output = VectorData>, SourceFuture: Future<Output = VectorData>>(
+	footprint: Footprint,
+	points: impl Node<Footprint, Output = PointsFuture>,
+	source: impl Node<Footprint, Output = SourceFuture>,
+	name: String,
+	method: TransferMethod,
+	radius: f64,
+) -> VectorData {
+	let mut points = self.points.eval(footprint).await;
+	let source = self.source.eval(footprint).await;
+
+	let source_positions = source.point_domain.positions().iter().map(|&position| source.transform.transform_point2(position)).collect::<Vec<_>>();
+	if source_positions.is_empty() {
+		return points;
+	}
+
+	let triangles = match method {
+		TransferMethod::Nearest => Vec::new(),
+		TransferMethod::Barycentric => delaunay_triangles(&source_positions),
+	};
+	let weights = points
+		.point_domain
+		.positions()
+		.iter()
+		.map(|&position| {
+			let position = points.transform.transform_point2(position);
+			let nearest = (0..source_positions.len())
+				.min_by(|&a, &b| source_positions[a].distance_squared(position).total_cmp(&source_positions[b].distance_squared(position)))
+				.unwrap_or_default();
+			if radius > 0. && source_positions[nearest].distance(position) > radius {
+				return Vec::new();
+			}
+			triangles
+				.iter()
+				.find_map(|triangle| barycentric_weights(triangle.vertices, &source_positions, position))
+				.unwrap_or_else(|| vec![(nearest, 1.)])
+		})
+		.collect::<Vec<_>>();
+
+	for (attribute, values) in source.point_domain.attributes() {
+		if name.is_empty() || attribute == name {
+			let blended = values.blend(&weights, points.point_domain.attribute(attribute));
+			points.point_domain.set_attribute(attribute, blended);
+		}
+	}
+
+	points
+}
+
+/// The weights of the corners of the triangle that blend into the position, if it lies within the triangle.
+fn barycentric_weights(vertices: [usize; 3], positions: &[DVec2], position: DVec2) -> Option<Vec<(usize, f64)>> {
+	let [a, b, c] = vertices.map(|vertex| positions[vertex]);
+	let area = (b - a).perp_dot(c - a);
+	if area.abs() < 1e-12 {
+		return None;
+	}
+	let weight_b = (position - a).perp_dot(c - a) / area;
+	let weight_c = (b - a).perp_dot(position - a) / area;
+	let weight_a = 1. - weight_b - weight_c;
+	let weights = [weight_a, weight_b, weight_c];
+	weights.iter().all(|&weight| weight >= -1e-9).then(|| vertices.into_iter().zip(weights).collect())
+}
+
+/// The CSS shape function that [`ToCssClipPathNode`] writes.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum ClipPathFormat {
+	/// `path()`, which keeps the curves exactly but is always measured in pixels
+	#[default]
+	Path,
+	/// `polygon()`, which flattens the curves into straight edges but can be given in percentages so it scales with the element
+	Polygon,
+}
+
+pub struct ToCssClipPathNode<Format, Precision, Normalize> {
+	format: Format,
+	precision: Precision,
+	normalize: Normalize,
+}
+
+/// Writes the closed subpaths of the shape, in document space, as the value of a CSS `clip-path` property such as `path("M 0 0 L 10 0 L 10 10 Z")` or `polygon(0% 0%, 100% 0%, 100% 100%)`.
+/// Numbers are rounded to the given number of decimal places.
+///
+/// Normalizing moves a path so the top left corner of its bounding box is at the origin of the element, and gives a polygon in percentages of its bounding box so it fills the element.
+/// A polygon can only have one outline, so several subpaths are joined by doubling back along an edge to the first point of each.
+#[node_macro::node_fn(ToCssClipPathNode)]
+fn to_css_clip_path(vector_data: VectorData, format: ClipPathFormat, precision: u32, normalize: bool) -> String {
+	// Number of line segments each curved segment is flattened into for a polygon
+	const FLATTENING_STEPS: usize = 16;
+
+	let subpaths = vector_data.region_bezier_paths().map(|(_, subpath)| subpath).collect::<Vec<_>>();
+	let bounds = subpaths
+		.iter()
+		.filter_map(|subpath| subpath.bounding_box_with_transform(vector_data.transform))
+		.reduce(|[a_min, a_max], [b_min, b_max]| [a_min.min(b_min), a_max.max(b_max)]);
+	let Some([min, max]) = bounds else { return String::new() };
+
+	let fill_rule = match vector_data.style.fill_rule() {
+		FillRule::EvenOdd => "evenodd, ",
+		FillRule::NonZero => "",
+	};
+	let number = |value: f64| css_number(value, precision);
+
+	match format {
+		ClipPathFormat::Path => {
+			let offset = if normalize { -min } else { DVec2::ZERO };
+			let transform = DAffine2::from_translation(offset) * vector_data.transform;
+			let point = |point: DVec2| {
+				let point = transform.transform_point2(point);
+				format!("{} {}", number(point.x), number(point.y))
+			};
+
+			let mut commands = Vec::new();
+			for subpath in &subpaths {
+				let Some(first) = subpath.manipulator_groups().first() else { continue };
+				commands.push(format!("M {}", point(first.anchor)));
+				// The line back to the first point is drawn by `Z` instead
+				let closing_line = subpath.closed() && subpath.iter().last().is_some_and(|bezier| is_straight(&bezier));
+				let segments = subpath.len_segments() - usize::from(closing_line);
+				for bezier in subpath.iter().take(segments) {
+					commands.push(match bezier.handles {
+						BezierHandles::Quadratic { handle } if !is_straight(&bezier) => format!("Q {} {}", point(handle), point(bezier.end)),
+						BezierHandles::Cubic { handle_start, handle_end } if !is_straight(&bezier) => format!("C {} {} {}", point(handle_start), point(handle_end), point(bezier.end)),
+						_ => format!("L {}", point(bezier.end)),
+					});
+				}
+				if subpath.closed() {
+					commands.push("Z".to_string());
+				}
+			}
+			format!("path({fill_rule}\"{}\")", commands.join(" "))
+		}
+		ClipPathFormat::Polygon => {
+			let polygons = subpaths
+				.iter()
+				.map(|subpath| {
+					let mut polygon = Vec::new();
+					for bezier in subpath.iter() {
+						let bezier = bezier.apply_transformation(|point| vector_data.transform.transform_point2(point));
+						let steps = if is_straight(&bezier) { 1 } else { FLATTENING_STEPS };
+						polygon.extend((0..steps).map(|step| bezier.evaluate(TValue::Parametric(step as f64 / steps as f64))));
+					}
+					polygon
+				})
+				.filter(|polygon| polygon.len() >= 3)
+				.collect::<Vec<_>>();
+			let Some(start) = polygons.first().and_then(|polygon| polygon.first()).copied() else {
+				return String::new();
+			};
+
+			let mut points = Vec::new();
+			for polygon in &polygons {
+				points.extend(polygon.iter().copied());
+				if polygons.len() > 1 {
+					points.push(polygon[0]);
+				}
+			}
+			if polygons.len() > 1 {
+				points.push(start);
+			}
+
+			let size = max - min;
+			let point = |point: DVec2| {
+				if normalize {
+					let percent = DVec2::select(size.cmpgt(DVec2::ZERO), (point - min) / size * 100., DVec2::ZERO);
+					format!("{}% {}%", number(percent.x), number(percent.y))
+				} else {
+					format!("{}px {}px", number(point.x), number(point.y))
+				}
+			};
+			format!("polygon({fill_rule}{})", points.into_iter().map(point).collect::<Vec<_>>().join(", "))
+		}
+	}
+}
+
+/// Whether the segment is a straight line, including curves with their handles on top of their anchors.
+fn is_straight(bezier: &Bezier) -> bool {
+	match bezier.handles {
+		BezierHandles::Linear => true,
+		BezierHandles::Quadratic { handle } => handle == bezier.start || handle == bezier.end,
+		BezierHandles::Cubic { handle_start, handle_end } => handle_start == bezier.start && handle_end == bezier.end,
+	}
+}
+
+/// Rounds the number to the given number of decimal places and leaves out the trailing zeros, as CSS and code are usually written.
+fn css_number(value: f64, precision: u32) -> String {
+	let rounded = format!("{value:.0$}", precision as usize);
+	let trimmed = if rounded.contains('.') { rounded.trim_end_matches('0').trim_end_matches('.') } else { &rounded };
+	if trimmed == "-0" {
+		"0".to_string()
+	} else {
+		trimmed.to_string()
+	}
+}
+
+/// The language that [`GeneratePathCodeNode`] writes its code in.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum PathCodeLanguage {
+	/// JavaScript building a `Path2D` for an HTML canvas
+	#[default]
+	Canvas,
+	/// Swift building a SwiftUI `Path`
+	SwiftUI,
+	/// Dart building a Flutter `Path`
+	Flutter,
+}
+
+pub struct GeneratePathCodeNode<Language, Precision> {
+	language: Language,
+	precision: Precision,
+}
+
+/// Writes source code that builds the subpaths of the shape, in document space, as a path in the given language, ready to be copied into an app.
+/// Numbers are rounded to the given number of decimal places.
+#[node_macro::node_fn(GeneratePathCodeNode)]
+fn generate_path_code(vector_data: VectorData, language: PathCodeLanguage, precision: u32) -> String {
+	let number = |value: f64| css_number(value, precision);
+	let point = |point: DVec2| {
+		let point = vector_data.transform.transform_point2(point);
+		match language {
+			PathCodeLanguage::SwiftUI => format!("CGPoint(x: {}, y: {})", number(point.x), number(point.y)),
+			PathCodeLanguage::Canvas | PathCodeLanguage::Flutter => format!("{}, {}", number(point.x), number(point.y)),
+		}
+	};
+
+	let mut calls = Vec::new();
+	for subpath in vector_data.stroke_bezier_paths() {
+		let Some(first) = subpath.manipulator_groups().first() else { continue };
+		calls.push(match language {
+			PathCodeLanguage::Canvas => format!("moveTo({})", point(first.anchor)),
+			PathCodeLanguage::SwiftUI => format!("move(to: {})", point(first.anchor)),
+			PathCodeLanguage::Flutter => format!("moveTo({})", point(first.anchor)),
+		});
+		// The line back to the first point is drawn by closing the subpath instead
+		let closing_line = subpath.closed() && subpath.iter().last().is_some_and(|bezier| is_straight(&bezier));
+		let segments = subpath.len_segments() - usize::from(closing_line);
+		for bezier in subpath.iter().take(segments) {
+			let end = point(bezier.end);
+			calls.push(match (bezier.handles, language) {
+				(BezierHandles::Quadratic { handle }, PathCodeLanguage::Canvas) if !is_straight(&bezier) => format!("quadraticCurveTo({}, {end})", point(handle)),
+				(BezierHandles::Quadratic { handle }, PathCodeLanguage::SwiftUI) if !is_straight(&bezier) => format!("addQuadCurve(to: {end}, control: {})", point(handle)),
+				(BezierHandles::Quadratic { handle }, PathCodeLanguage::Flutter) if !is_straight(&bezier) => format!("quadraticBezierTo({}, {end})", point(handle)),
+				(BezierHandles::Cubic { handle_start, handle_end }, PathCodeLanguage::Canvas) if !is_straight(&bezier) => {
+					format!("bezierCurveTo({}, {}, {end})", point(handle_start), point(handle_end))
+				}
+				(BezierHandles::Cubic { handle_start, handle_end }, PathCodeLanguage::SwiftUI) if !is_straight(&bezier) => {
+					format!("addCurve(to: {end}, control1: {}, control2: {})", point(handle_start), point(handle_end))
+				}
+				(BezierHandles::Cubic { handle_start, handle_end }, PathCodeLanguage::Flutter) if !is_straight(&bezier) => format!("cubicTo({}, {}, {end})", point(handle_start), point(handle_end)),
+				(_, PathCodeLanguage::Canvas | PathCodeLanguage::Flutter) => format!("lineTo({end})"),
+				(_, PathCodeLanguage::SwiftUI) => format!("addLine(to: {end})"),
+			});
+		}
+		if subpath.closed() {
+			calls.push(
+				match language {
+					PathCodeLanguage::Canvas => "closePath()",
+					PathCodeLanguage::SwiftUI => "closeSubpath()",
+					PathCodeLanguage::Flutter => "close()",
+				}
+				.to_string(),
+			);
+		}
+	}
+
+	match language {
+		PathCodeLanguage::Canvas => {
+			let calls = calls.iter().map(|call| format!("path.{call};\n")).collect::<String>();
+			format!("const path = new Path2D();\n{calls}")
+		}
+		PathCodeLanguage::SwiftUI => {
+			let calls = calls.iter().map(|call| format!("    path.{call}\n")).collect::<String>();
+			format!("Path {{ path in\n{calls}}}\n")
+		}
+		PathCodeLanguage::Flutter => {
+			let calls = calls.iter().map(|call| format!("\n  ..{call}")).collect::<String>();
+			format!("final path = Path(){calls};\n")
+		}
+	}
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+	use super::*;
+	use crate::transform::CullNode;
+	use crate::value::ClonedNode;
+	use crate::GraphicElement;
+
+	use std::pin::Pin;
+
+	/// Wraps the output of a node in a future, for the inputs of nodes that await them.
+	#[derive(Clone)]
+	pub struct FutureWrapperNode<Node: Clone>(pub Node);
+
+	impl<'i, T: 'i, N: Node<'i, T> + Clone> Node<'i, T> for FutureWrapperNode<N>
+	where
+		N: Node<'i, T>,
+	{
+		type Output = Pin<Box<dyn core::future::Future<Output = N::Output> + 'i>>;
+		fn eval(&'i self, input: T) -> Self::Output {
+			Box::pin(async move { self.0.eval(input) })
+		}
+	}
+
+	#[test]
+	fn style_blending() {
+		let fill = SetFillNode {
+			fill_type: ClonedNode(FillType::Solid),
+			solid_color: ClonedNode(Some(Color::RED)),
+			gradient_type: ClonedNode(GradientType::Linear),
+			start: ClonedNode(DVec2::ZERO),
+			end: ClonedNode(DVec2::X),
+			transform: ClonedNode(DAffine2::IDENTITY),
+			positions: ClonedNode(Vec::new()),
+			focal_point: ClonedNode(None),
+			fill_rule: ClonedNode(FillRule::NonZero),
+			blend_mode: ClonedNode(BlendMode::Multiply),
+			opacity: ClonedNode(50.),
+		};
+		let stroke = SetStrokeNode {
+			color: ClonedNode(Some(Color::BLACK)),
+			weight: ClonedNode(2.),
+			dash_lengths: ClonedNode(Vec::new()),
+			dash_offset: ClonedNode(0.),
+			line_cap: ClonedNode(crate::vector::style::LineCap::Butt),
+			line_join: ClonedNode(crate::vector::style::LineJoin::Miter),
+			miter_limit: ClonedNode(4.),
+			alignment: ClonedNode(StrokeAlignment::Center),
+			paint_type: ClonedNode(FillType::Solid),
+			gradient_type: ClonedNode(GradientType::Linear),
+			start: ClonedNode(DVec2::ZERO),
+			end: ClonedNode(DVec2::X),
+			transform: ClonedNode(DAffine2::IDENTITY),
+			positions: ClonedNode(Vec::new()),
+			focal_point: ClonedNode(None),
+			blend_mode: ClonedNode(BlendMode::Normal),
+			opacity: ClonedNode(50.),
+			hairline: ClonedNode(false),
+		};
+
+		let filled = fill.eval(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)));
+		assert_eq!(filled.alpha_blending.blend_mode, BlendMode::Multiply);
+		assert_eq!(filled.alpha_blending.opacity, 0.5);
+
+		// The stroke's blending replaces the fill's, including the normal blend mode, rather than compounding with it
+		let styled = stroke.eval(filled);
+		assert_eq!(styled.alpha_blending.blend_mode, BlendMode::Normal);
+		assert_eq!(styled.alpha_blending.opacity, 0.5);
+		assert_eq!(styled.style.fill(), &Fill::Solid(Color::RED));
+	}
+
+	#[test]
+	fn copy_style() {
+		let mut source = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		let pattern = Pattern {
+			tile: VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)),
+			spacing: DVec2::splat(2.),
+			transform: DAffine2::from_translation(DVec2::splat(1.)),
+		};
+		source.style.set_fill(Fill::Pattern(pattern));
+		source.style.set_fill_rule(FillRule::EvenOdd);
+		let gradient = Gradient {
+			start: DVec2::ZERO,
+			end: DVec2::X,
+			..Default::default()
+		};
+		source.style.set_stroke(Stroke {
+			paint: Fill::Gradient(gradient.clone()),
+			..Stroke::new(None, 3.)
+		});
+
+		let target = VectorData::from_subpath(Subpath::new_rect(DVec2::splat(100.), DVec2::splat(120.)));
+		let styled = CopyStyleNode { source: ClonedNode(source) }.eval(target.clone());
+		assert_eq!(styled.point_domain, target.point_domain);
+		assert_eq!(styled.style.fill_rule(), FillRule::EvenOdd);
+
+		// The tiles stay lined up with the corner of the shape, while the gradient's points relative to its bounds are left alone
+		let Fill::Pattern(pattern) = styled.style.fill() else { panic!("The fill should be a pattern") };
+		assert_eq!(pattern.transform.translation, DVec2::splat(101.));
+		let stroke = styled.style.stroke().unwrap();
+		assert_eq!(stroke.weight, 3.);
+		let Fill::Gradient(copied) = stroke.paint else { panic!("The stroke should be a gradient") };
+		assert_eq!((copied.start, copied.end), (gradient.start, gradient.end));
+		assert_eq!(copied.transform.matrix2.x_axis, DVec2::new(2., 0.));
+	}
+
+	#[test]
+	fn style_library() {
+		let mut accent = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		accent.style.set_fill(Fill::Pattern(Pattern {
+			tile: VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)),
+			spacing: DVec2::splat(2.),
+			transform: DAffine2::from_translation(DVec2::splat(1.)),
+		}));
+		let mut muted = accent.clone();
+		muted.style = PathStyle::new(Some(Stroke::new(Some(Color::BLACK), 2.)), Fill::None);
+
+		let library = DefineStyleNode {
+			name: ClonedNode("Accent".to_string()),
+			source: ClonedNode(accent.clone()),
+		}
+		.eval(StyleLibrary::default());
+		let library = DefineStyleNode {
+			name: ClonedNode("Muted".to_string()),
+			source: ClonedNode(muted),
+		}
+		.eval(library);
+		assert_eq!(library.styles.len(), 2);
+
+		// A style from the library is placed on the shape the same way as when it's copied straight from the shape it was defined with
+		let target = VectorData::from_subpath(Subpath::new_rect(DVec2::splat(100.), DVec2::splat(120.)));
+		let apply = |name: &str| {
+			ApplyStyleNode {
+				library: ClonedNode(library.clone()),
+				name: ClonedNode(name.to_string()),
+			}
+			.eval(target.clone())
+		};
+		assert_eq!(apply("Accent").style, CopyStyleNode { source: ClonedNode(accent) }.eval(target.clone()).style);
+		assert_eq!(apply("Muted").style.stroke().map(|stroke| stroke.weight), Some(2.));
+		assert_eq!(apply("Missing").style, target.style);
+	}
+
+	#[test]
+	fn repeat() {
+		let direction = DVec2::X * 1.5;
+		let repeated = RepeatNode {
+			direction: ClonedNode::new(direction),
+			count: ClonedNode::new(3),
+			rotation: ClonedNode::new(0.),
+			scale: ClonedNode::new(1.),
+		}
+		.eval(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)));
+		assert_eq!(repeated.region_bezier_paths().count(), 3);
+		for (index, (_, subpath)) in repeated.region_bezier_paths().enumerate() {
+			assert_eq!(subpath.manipulator_groups()[0].anchor, direction * index as f64);
+		}
+	}
+	#[test]
+	fn repeat_transform_position() {
+		let direction = DVec2::new(12., 10.);
+		let repeated = RepeatNode {
+			direction: ClonedNode::new(direction),
+			count: ClonedNode::new(8),
+			rotation: ClonedNode::new(0.),
+			scale: ClonedNode::new(1.),
+		}
+		.eval(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)));
+		assert_eq!(repeated.region_bezier_paths().count(), 8);
+		for (index, (_, subpath)) in repeated.region_bezier_paths().enumerate() {
+			assert_eq!(subpath.manipulator_groups()[0].anchor, direction * index as f64);
+		}
+	}
+	#[test]
+	fn repeat_rotated_and_scaled() {
+		let repeated = RepeatNode {
+			direction: ClonedNode::new(DVec2::new(10., 0.)),
+			count: ClonedNode::new(4),
+			rotation: ClonedNode::new(90.),
+			scale: ClonedNode::new(0.5),
+		}
+		.eval(VectorData::from_subpath(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE)));
+		assert_eq!(repeated.region_bezier_paths().count(), 4);
+
+		let centers = [DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(10., 5.), DVec2::new(7.5, 5.)];
+		for (index, (_, subpath)) in repeated.region_bezier_paths().enumerate() {
+			let [min, max] = subpath.bounding_box().unwrap();
+			assert!(((min + max) / 2.).abs_diff_eq(centers[index], 1e-6), "Copy {index} is centered at {}", (min + max) / 2.);
+			assert!((max - min).abs_diff_eq(DVec2::splat(2. * 0.5_f64.powi(index as i32)), 1e-6));
+			// Each copy is turned a further quarter turn, which carries its first corner around the square
+			let corner = (subpath.manipulator_groups()[0].anchor - (min + max) / 2.).normalize();
+			assert!(corner.abs_diff_eq(DAffine2::from_angle(index as f64 * std::f64::consts::FRAC_PI_2).transform_vector2(DVec2::NEG_ONE.normalize()), 1e-6));
+		}
+	}
+	#[test]
+	fn circle_repeat() {
+		let repeated = CircularRepeatNode {
+			angle_offset: ClonedNode::new(45.),
+			radius: ClonedNode::new(4.),
+			count: ClonedNode::new(8),
+			pivot: ClonedNode::new(None),
+			keep_upright: ClonedNode::new(false),
+		}
+		.eval(VectorData::from_subpath(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE)));
+		assert_eq!(repeated.region_bezier_paths().count(), 8);
+		for (index, (_, subpath)) in repeated.region_bezier_paths().enumerate() {
+			let expected_angle = (index as f64 + 1.) * 45.;
+			let centre = (subpath.manipulator_groups()[0].anchor + subpath.manipulator_groups()[2].anchor) / 2.;
+			let actual_angle = DVec2::Y.angle_between(centre).to_degrees();
+			assert!((actual_angle - expected_angle).abs() % 360. < 1e-5);
+		}
+	}
+	#[test]
+	fn circle_repeat_around_pivot_upright() {
+		let pivot = DVec2::new(10., 20.);
+		let repeated = CircularRepeatNode {
+			angle_offset: ClonedNode::new(0.),
+			radius: ClonedNode::new(4.),
+			count: ClonedNode::new(4),
+			pivot: ClonedNode::new(Some(pivot)),
+			keep_upright: ClonedNode::new(true),
+		}
+		.eval(VectorData::from_subpath(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE)));
+		assert_eq!(repeated.region_bezier_paths().count(), 4);
+		for (index, (_, subpath)) in repeated.region_bezier_paths().enumerate() {
+			let centre = (subpath.manipulator_groups()[0].anchor + subpath.manipulator_groups()[2].anchor) / 2.;
+			let expected_centre = pivot + DVec2::from_angle(index as f64 * std::f64::consts::FRAC_PI_2).rotate(DVec2::new(0., 4.));
+			assert!(centre.abs_diff_eq(expected_centre, 1e-6), "Found {centre}, expected {expected_centre}");
+			// Upright copies keep their first corner at the top left
+			assert!((subpath.manipulator_groups()[0].anchor - centre).abs_diff_eq(DVec2::NEG_ONE, 1e-6));
+		}
+	}
+	#[test]
+	fn repeat_instances_share_geometry() {
+		let shape = VectorData::from_subpath(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE));
+		let repeat = RepeatNode {
+			direction: ClonedNode::new(DVec2::new(10., 0.)),
+			count: ClonedNode::new(3),
+			rotation: ClonedNode::new(30.),
+			scale: ClonedNode::new(0.5),
+		};
+		let circular_repeat = CircularRepeatNode {
+			angle_offset: ClonedNode::new(0.),
+			radius: ClonedNode::new(40.),
+			count: ClonedNode::new(5),
+			pivot: ClonedNode::new(None),
+			keep_upright: ClonedNode::new(false),
+		};
+
+		let instances = circular_repeat.eval(repeat.eval(ToInstancesNode.eval(shape.clone())));
+		assert_eq!(instances.len(), 15);
+		assert_eq!(instances.geometry, shape);
+
+		let flattened = FlattenInstancesNode.eval(instances);
+		let expected = circular_repeat.eval(repeat.eval(shape));
+		for ((_, subpath), (_, expected)) in flattened.region_bezier_paths().zip(expected.region_bezier_paths()) {
+			for (anchor, expected) in subpath.anchors().into_iter().zip(expected.anchors()) {
+				assert!(anchor.abs_diff_eq(expected, 1e-6), "Found {anchor}, expected {expected}");
+			}
+		}
+		assert_eq!(flattened.region_bezier_paths().count(), 15);
+	}
+	#[test]
+	fn bounding_box() {
+		let bounding_box = |vector_data, oriented, per_subpath, padding| {
+			BoundingBoxNode {
+				oriented: ClonedNode(oriented),
+				per_subpath: ClonedNode(per_subpath),
+				padding: ClonedNode(padding),
+			}
+			.eval(vector_data)
+		};
+		let bouding_box = bounding_box(VectorData::from_subpath(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE)), false, false, 0.);
+		assert_eq!(bouding_box.region_bezier_paths().count(), 1);
+		let subpath = bouding_box.region_bezier_paths().next().unwrap().1;
+		assert_eq!(&subpath.anchors()[..4], &[DVec2::NEG_ONE, DVec2::new(1., -1.), DVec2::ONE, DVec2::new(-1., 1.),]);
+
+		// A long thin rectangle turned by 30° has a much larger upright box, but the oriented box fits it exactly
+		let mut turned = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::new(100., 10.)));
+		turned.transform = DAffine2::from_angle(30_f64.to_radians());
+		let upright = bounding_box(turned.clone(), false, false, 0.).bounding_box().unwrap();
+		assert!((upright[1] - upright[0]).x * (upright[1] - upright[0]).y > 2000.);
+		let oriented = bounding_box(turned.clone(), true, false, 1.);
+		let corners = oriented.stroke_bezier_paths().next().unwrap().anchors();
+		assert!((corners[0].distance(corners[1]) - 102.).abs() < 1e-6);
+		assert!((corners[1].distance(corners[2]) - 12.).abs() < 1e-6);
+
+		// Each subpath gets its own box
+		let mut two_squares = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		two_squares.append_subpath(Subpath::<PointId>::new_rect(DVec2::splat(20.), DVec2::splat(30.)));
+		assert_eq!(bounding_box(two_squares.clone(), false, true, 0.).region_bezier_paths().count(), 2);
+		assert_eq!(bounding_box(two_squares, false, false, 0.).bounding_box(), Some([DVec2::ZERO, DVec2::splat(30.)]));
+	}
+	#[test]
+	fn oriented_bounding_box() {
+		let bounding_box = BoundingBoxNode {
+			oriented: ClonedNode(true),
+			per_subpath: ClonedNode(false),
+			padding: ClonedNode(0.),
+		};
+
+		// The oriented box of a rotated rectangle is the rectangle itself, corner for corner
+		let rectangle = [DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(100., 10.), DVec2::new(0., 10.)];
+		for angle in [30_f64, -75., 135.] {
+			let mut rotated = VectorData::from_subpath(Subpath::from_anchors(rectangle, true));
+			rotated.transform = DAffine2::from_angle_translation(angle.to_radians(), DVec2::new(20., -40.));
+			let oriented = bounding_box.eval(rotated.clone());
+			assert_eq!(oriented.stroke_bezier_paths().count(), 1);
+
+			let corners = oriented.stroke_bezier_paths().next().unwrap().anchors();
+			assert_eq!(corners.len(), 4);
+			for corner in rectangle.map(|corner| rotated.transform.transform_point2(corner)) {
+				assert!(corners.iter().any(|other| other.distance(corner) < 1e-6), "{corner} isn't a corner of the oriented box {corners:?}");
+			}
+		}
+
+		// An upright rectangle keeps its upright box
+		let upright = bounding_box.eval(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::new(40., 20.))));
+		assert_eq!(upright.bounding_box(), Some([DVec2::ZERO, DVec2::new(40., 20.)]));
+	}
+
+	#[test]
+	fn per_subpath_bounding_boxes() {
+		let bounding_box = |padding| BoundingBoxNode {
+			oriented: ClonedNode(false),
+			per_subpath: ClonedNode(true),
+			padding: ClonedNode(padding),
+		};
+
+		let mut shapes = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		shapes.append_subpath(Subpath::<PointId>::new_rect(DVec2::new(20., 5.), DVec2::new(50., 10.)));
+		shapes.append_subpath(Subpath::<PointId>::from_anchors([DVec2::new(0., 20.), DVec2::new(10., 40.), DVec2::new(-10., 30.)], true));
+		shapes.transform = DAffine2::from_scale_angle_translation(DVec2::splat(2.), 0., DVec2::new(5., 0.));
+
+		// One box for each subpath, around its extents in document space and grown by the padding on every side
+		let extents = |vector_data: VectorData| vector_data.stroke_bezier_paths().map(|subpath| subpath.bounding_box().unwrap()).collect::<Vec<_>>();
+		let expected = [
+			[DVec2::new(5., 0.), DVec2::new(25., 20.)],
+			[DVec2::new(45., 10.), DVec2::new(105., 20.)],
+			[DVec2::new(-15., 40.), DVec2::new(25., 80.)],
+		];
+		assert_eq!(extents(bounding_box(0.).eval(shapes.clone())), expected);
+		let padded = expected.map(|[min, max]| [min - 1., max + 1.]);
+		assert_eq!(extents(bounding_box(1.).eval(shapes.clone())), padded);
+
+		// A negative padding shrinks each box, flattening it along any side it's shrunk past
+		let shrunk = extents(bounding_box(-6.).eval(shapes));
+		assert_eq!(shrunk[0], [DVec2::new(11., 6.), DVec2::new(19., 14.)]);
+		assert_eq!(shrunk[1], [DVec2::new(51., 15.), DVec2::new(99., 15.)]);
+	}
+
+	#[tokio::test]
+	async fn copy_to_points() {
+		let points = VectorData::from_subpath(Subpath::new_rect(DVec2::NEG_ONE * 10., DVec2::ONE * 10.));
 		let expected_points = points.point_domain.positions().to_vec();
 		let bouding_box = CopyToPoints {
 			points: CullNode::new(FutureWrapperNode(ClonedNode(points))),
@@ -589,7 +4979,11 @@ mod test {
 			random_scale_min: FutureWrapperNode(ClonedNode(1.)),
 			random_scale_max: FutureWrapperNode(ClonedNode(1.)),
 			random_scale_bias: FutureWrapperNode(ClonedNode(0.)),
-			random_rotation: FutureWrapperNode(ClonedNode(0.)),
+			random_rotation_min: FutureWrapperNode(ClonedNode(0.)),
+			random_rotation_max: FutureWrapperNode(ClonedNode(0.)),
+			random_seed: FutureWrapperNode(ClonedNode(0)),
+			align_to_path: FutureWrapperNode(ClonedNode(false)),
+			variant_selection: FutureWrapperNode(ClonedNode(VariantSelection::Whole)),
 		}
 		.eval(Footprint::default())
 		.await;
@@ -603,84 +4997,1372 @@ mod test {
 		}
 	}
 	#[tokio::test]
-	async fn sample_points() {
-		let path = VectorData::from_subpath(Subpath::from_bezier(&Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::ZERO, DVec2::X * 100., DVec2::X * 100.)));
-		let sample_points = SamplePoints {
-			vector_data: CullNode::new(FutureWrapperNode(ClonedNode(path))),
-			spacing: FutureWrapperNode(ClonedNode(30.)),
-			start_offset: FutureWrapperNode(ClonedNode(0.)),
-			stop_offset: FutureWrapperNode(ClonedNode(0.)),
-			adaptive_spacing: FutureWrapperNode(ClonedNode(false)),
-			lengths_of_segments_of_subpaths: CullNode::new(FutureWrapperNode(ClonedNode(vec![100.]))),
+	async fn clip_to_viewport() {
+		let mut shape = VectorData::from_subpaths([
+			Subpath::new_rect(DVec2::splat(-100.), DVec2::splat(100.)),
+			Subpath::new_ellipse(DVec2::splat(1000.), DVec2::splat(1010.)),
+			Subpath::new_rect(DVec2::splat(10.), DVec2::splat(20.)),
+		]);
+		shape.set_subpath_style(2, PathStyle::new(None, Fill::Solid(Color::RED)));
+		let footprint = Footprint {
+			resolution: glam::UVec2::new(50, 50),
+			..Default::default()
+		};
+		let clip = |vector_data: &VectorData, margin: f64| {
+			let node = ClipToViewportNode {
+				vector_data: CullNode::new(FutureWrapperNode(ClonedNode(vector_data.clone()))),
+				margin: FutureWrapperNode(ClonedNode(margin)),
+			};
+			async move { node.eval(footprint).await }
+		};
+
+		// The square reaching past the area is cut down to it along with the margin, the far away circle is removed, and the square inside is kept with its style
+		let clipped = clip(&shape, 5.).await;
+		let subpaths = clipped.stroke_bezier_paths().collect::<Vec<_>>();
+		assert_eq!(subpaths.len(), 2);
+		let [min, max] = subpaths[0].bounding_box().unwrap();
+		assert!(min.abs_diff_eq(DVec2::splat(-5.), 1e-9) && max.abs_diff_eq(DVec2::splat(55.), 1e-9));
+		assert!(subpaths[0].closed());
+		assert_eq!(subpaths[1].bounding_box(), Some([DVec2::splat(10.), DVec2::splat(20.)]));
+		assert_eq!(clipped.subpath_style(1).fill(), &Fill::Solid(Color::RED));
+
+		// Curves crossing the edge are split where they cross it
+		let circle = VectorData::from_subpath(Subpath::new_ellipse(DVec2::new(-20., 0.), DVec2::new(20., 40.)));
+		let clipped = clip(&circle, 0.).await;
+		let [min, max] = clipped.bounding_box().unwrap();
+		assert!(min.abs_diff_eq(DVec2::ZERO, 1e-6) && max.abs_diff_eq(DVec2::new(20., 40.), 1e-6));
+
+		// Nothing changes for a shape already within the area
+		let inside = VectorData::from_subpath(Subpath::new_rect(DVec2::splat(10.), DVec2::splat(20.)));
+		assert_eq!(clip(&inside, 0.).await.point_domain, inside.point_domain);
+	}
+
+	#[tokio::test]
+	async fn sample_points() {
+		let path = VectorData::from_subpath(Subpath::from_bezier(&Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::ZERO, DVec2::X * 100., DVec2::X * 100.)));
+		let sample_points = SamplePoints {
+			vector_data: CullNode::new(FutureWrapperNode(ClonedNode(path))),
+			spacing: FutureWrapperNode(ClonedNode(30.)),
+			start_offset: FutureWrapperNode(ClonedNode(0.)),
+			stop_offset: FutureWrapperNode(ClonedNode(0.)),
+			adaptive_spacing: FutureWrapperNode(ClonedNode(false)),
+			lengths_of_segments_of_subpaths: CullNode::new(FutureWrapperNode(ClonedNode(vec![100.]))),
+		}
+		.eval(Footprint::default())
+		.await;
+		assert_eq!(sample_points.point_domain.positions().len(), 4);
+		for (pos, expected) in sample_points.point_domain.positions().iter().zip([DVec2::X * 0., DVec2::X * 30., DVec2::X * 60., DVec2::X * 90.]) {
+			assert!(pos.distance(expected) < 1e-3, "Expected {expected} found {pos}");
+		}
+		let Some(AttributeValues::DVec2(tangents)) = sample_points.point_domain.attribute(TANGENT_ATTRIBUTE) else {
+			panic!("Expected a tangent attribute");
+		};
+		assert!(tangents.iter().all(|tangent| tangent.abs_diff_eq(DVec2::X, 1e-6)), "Found {tangents:?}");
+	}
+	#[tokio::test]
+	async fn adaptive_spacing() {
+		let path = VectorData::from_subpath(Subpath::from_bezier(&Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::ZERO, DVec2::X * 100., DVec2::X * 100.)));
+		let sample_points = SamplePoints {
+			vector_data: CullNode::new(FutureWrapperNode(ClonedNode(path))),
+			spacing: FutureWrapperNode(ClonedNode(18.)),
+			start_offset: FutureWrapperNode(ClonedNode(45.)),
+			stop_offset: FutureWrapperNode(ClonedNode(10.)),
+			adaptive_spacing: FutureWrapperNode(ClonedNode(true)),
+			lengths_of_segments_of_subpaths: CullNode::new(FutureWrapperNode(ClonedNode(vec![100.]))),
+		}
+		.eval(Footprint::default())
+		.await;
+		assert_eq!(sample_points.point_domain.positions().len(), 4);
+		for (pos, expected) in sample_points.point_domain.positions().iter().zip([DVec2::X * 45., DVec2::X * 60., DVec2::X * 75., DVec2::X * 90.]) {
+			assert!(pos.distance(expected) < 1e-3, "Expected {expected} found {pos}");
+		}
+	}
+	#[test]
+	fn poisson() {
+		let sample_points = PoissonDiskPoints {
+			separation_disk_diameter: ClonedNode(10. * std::f64::consts::SQRT_2),
+		}
+		.eval(VectorData::from_subpath(Subpath::new_ellipse(DVec2::NEG_ONE * 50., DVec2::ONE * 50.)));
+		assert!(
+			(20..=40).contains(&sample_points.point_domain.positions().len()),
+			"actual len {}",
+			sample_points.point_domain.positions().len()
+		);
+		for point in sample_points.point_domain.positions() {
+			assert!(point.length() < 50. + 1., "Expected point in circle {point}")
+		}
+	}
+	#[test]
+	fn lengths() {
+		let subpath = VectorData::from_subpath(Subpath::from_bezier(&Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::ZERO, DVec2::X * 100., DVec2::X * 100.)));
+		let lengths = LengthsOfSegmentsOfSubpaths.eval(subpath);
+		assert_eq!(lengths, vec![100.]);
+	}
+	#[test]
+	fn spline() {
+		let subpath = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE * 100.));
+		let spline = SplinesFromPointsNode.eval(subpath);
+		assert_eq!(spline.stroke_bezier_paths().count(), 1);
+		assert_eq!(spline.point_domain.positions(), &[DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(100., 100.), DVec2::new(0., 100.)]);
+		assert!(spline.colinear(spline.point_domain.ids()[1]) && spline.colinear(spline.point_domain.ids()[2]));
+		assert!(!spline.colinear(spline.point_domain.ids()[0]));
+	}
+	#[test]
+	fn set_handle_mirroring() {
+		// The middle anchor's handles are 10° short of opposite, and the last anchor joins two curves at a sharp corner
+		let bent = DVec2::from_angle(10_f64.to_radians()) * 20.;
+		let mut path = VectorData::empty();
+		let anchors = [DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(200., 0.), DVec2::new(100., 100.)];
+		for anchor in anchors {
+			path.point_domain.push(PointId::generate(), anchor);
+		}
+		let ids = path.point_domain.ids().to_vec();
+		for (index, (handle_start, handle_end)) in [
+			(DVec2::new(20., 20.), anchors[1] - DVec2::X * 20.),
+			(anchors[1] + bent, DVec2::new(180., 20.)),
+			(DVec2::new(220., 20.), DVec2::new(120., 100.)),
+		]
+		.into_iter()
+		.enumerate()
+		{
+			path.segment_domain.push(
+				SegmentId::generate(),
+				ids[index],
+				ids[index + 1],
+				BezierHandles::Cubic { handle_start, handle_end },
+				StrokeId::generate(),
+			);
+		}
+		let mirror = |colinear, angle_tolerance| {
+			SetHandleMirroringNode {
+				colinear: ClonedNode(colinear),
+				angle_tolerance: ClonedNode(angle_tolerance),
+			}
+			.eval(path.clone())
+		};
+
+		let smooth = mirror(true, 15.);
+		assert_eq!(smooth.colinear_manipulators, vec![ids[1].into()]);
+		let handles = smooth.segment_domain.handles();
+		let (in_handle, out_handle) = (handles[0].end().unwrap(), handles[1].start().unwrap());
+		assert!((in_handle - anchors[1]).normalize().dot((out_handle - anchors[1]).normalize()) < -1. + 1e-9);
+		assert!((in_handle.distance(anchors[1]) - 20.).abs() < 1e-9 && (out_handle.distance(anchors[1]) - 20.).abs() < 1e-9);
+
+		assert!(mirror(true, 5.).colinear_manipulators.is_empty());
+		assert_eq!(mirror(true, 180.).colinear_manipulators.len(), 2);
+		assert!(mirror(false, 180.).colinear_manipulators.is_empty());
+	}
+	#[test]
+	fn freeze() {
+		let generated = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		let first = generated.point_domain.ids()[0];
+		assert!(!generated.is_manual(first));
+
+		let mut frozen = FreezeNode.eval(generated);
+		assert!(frozen.point_domain.ids().iter().all(|&id| frozen.is_manual(id)));
+
+		// Points added afterwards by other nodes count as generated, while the frozen ones stay manual
+		frozen.append_subpath(Subpath::<PointId>::new_line(DVec2::ZERO, DVec2::ONE));
+		let added = frozen.point_domain.ids()[4];
+		assert!(frozen.is_manual(first) && !frozen.is_manual(added));
+	}
+	#[tokio::test]
+	async fn morph() {
+		let source = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE * 100.));
+		let target = VectorData::from_subpath(Subpath::new_ellipse(DVec2::NEG_ONE * 100., DVec2::ZERO));
+		let sample_points = MorphNode {
+			source: CullNode::new(FutureWrapperNode(ClonedNode(source))),
+			target: CullNode::new(FutureWrapperNode(ClonedNode(target))),
+			time: FutureWrapperNode(ClonedNode(0.5)),
+			start_index: FutureWrapperNode(ClonedNode(0)),
+		}
+		.eval(Footprint::default())
+		.await;
+		assert_eq!(
+			&sample_points.point_domain.positions()[..4],
+			vec![DVec2::new(-25., -50.), DVec2::new(50., -25.), DVec2::new(25., 50.), DVec2::new(-50., 25.)]
+		);
+	}
+	#[tokio::test]
+	async fn lattice_deform() {
+		let lattice = |offset: DVec2| {
+			let mut lattice = VectorData::empty();
+			for y in 0..3 {
+				for x in 0..3 {
+					let offset = if (x, y) == (1, 0) { offset } else { DVec2::ZERO };
+					lattice.point_domain.push(PointId::generate(), DVec2::new(x as f64, y as f64) * 50. + offset);
+				}
+			}
+			lattice
+		};
+		let deform = |lattice| async move {
+			LatticeDeformNode {
+				vector_data: CullNode::new(FutureWrapperNode(ClonedNode(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE * 100.))))),
+				lattice: CullNode::new(FutureWrapperNode(ClonedNode(lattice))),
+				columns: FutureWrapperNode(ClonedNode(3)),
+				rows: FutureWrapperNode(ClonedNode(3)),
+				subdivisions: FutureWrapperNode(ClonedNode(4)),
+			}
+			.eval(Footprint::default())
+			.await
+		};
+
+		// An undisturbed lattice leaves the geometry in place
+		let identity = deform(lattice(DVec2::ZERO)).await;
+		assert_eq!(identity.region_bezier_paths().count(), 1);
+		let [min, max] = identity.bounding_box().unwrap();
+		assert!(
+			min.abs_diff_eq(DVec2::ZERO, 1e-6) && max.abs_diff_eq(DVec2::ONE * 100., 1e-6),
+			"Expected unchanged bounds, found {min} {max}"
+		);
+
+		// Pulling the top middle control point upwards bows the top edge outwards
+		let deformed = deform(lattice(DVec2::new(0., -40.))).await;
+		let top_middle = deformed.point_domain.positions()[2];
+		assert!(top_middle.abs_diff_eq(DVec2::new(50., -20.), 1e-6), "Expected {} found {top_middle}", DVec2::new(50., -20.));
+	}
+	#[tokio::test]
+	async fn project_points_onto_path() {
+		let mut points = VectorData::empty();
+		for position in [DVec2::new(-10., -5.), DVec2::new(50., 20.), DVec2::new(150., 3.)] {
+			points.point_domain.push(PointId::generate(), position);
+		}
+		let path = VectorData::from_subpath(Subpath::new_line(DVec2::ZERO, DVec2::X * 100.));
+		let projected = ProjectPointsOntoPathNode {
+			points: CullNode::new(FutureWrapperNode(ClonedNode(points))),
+			path: CullNode::new(FutureWrapperNode(ClonedNode(path))),
+			t_value_attribute: FutureWrapperNode(ClonedNode("t".to_string())),
+		}
+		.eval(Footprint::default())
+		.await;
+		let Some(AttributeValues::F64(t_values)) = projected.point_domain.attribute("t") else {
+			panic!("Expected a t-value attribute");
+		};
+		for ((position, t), (expected_position, expected_t)) in projected
+			.point_domain
+			.positions()
+			.iter()
+			.zip(t_values)
+			.zip([(DVec2::ZERO, 0.), (DVec2::new(50., 0.), 0.5), (DVec2::X * 100., 1.)])
+		{
+			assert!(position.abs_diff_eq(expected_position, 1e-6), "Expected {expected_position} found {position}");
+			assert!((t - expected_t).abs() < 1e-6, "Expected {expected_t} found {t}");
+		}
+	}
+
+	#[test]
+	fn closest_point() {
+		let mut path = VectorData::from_subpath(Subpath::new_line(DVec2::ZERO, DVec2::new(100., 0.)));
+		path.transform = DAffine2::from_translation(DVec2::new(0., 10.));
+		let (point, t, distance) = ClosestPointNode {
+			position: ClonedNode(DVec2::new(25., 40.)),
+		}
+		.eval(path);
+		assert!(point.abs_diff_eq(DVec2::new(25., 10.), 1e-6), "{point}");
+		// The line is stored as a cubic with its handles on the endpoints, so x(t) = 3t² - 2t³ along it
+		assert!((3. * t * t - 2. * t * t * t - 0.25).abs() < 1e-6, "{t}");
+		assert!((distance - 30.).abs() < 1e-6, "{distance}");
+
+		let (point, _, distance) = ClosestPointNode { position: ClonedNode(DVec2::ONE) }.eval(VectorData::empty());
+		assert_eq!(point, DVec2::ONE);
+		assert_eq!(distance, f64::INFINITY);
+	}
+
+	#[test]
+	fn detect_symmetry() {
+		// Whether moving every anchor and segment midpoint lands on another one
+		let symmetric_under = |vector_data: &VectorData, movement: DAffine2| {
+			let anchors = vector_data.point_domain.positions().iter().map(|&point| vector_data.transform.transform_point2(point));
+			let midpoints = vector_data
+				.segment_bezier_iter()
+				.map(|(_, bezier, _, _)| vector_data.transform.transform_point2(bezier.evaluate(TValue::Parametric(0.5))));
+			let points = anchors.chain(midpoints).collect::<Vec<_>>();
+			points.iter().all(|&point| points.iter().any(|other| other.abs_diff_eq(movement.transform_point2(point), 1e-6)))
+		};
+		let detect = |vector_data: VectorData, symmetry: Symmetry| {
+			DetectSymmetryNode {
+				symmetry: ClonedNode(symmetry),
+				max_order: ClonedNode(8),
+			}
+			.eval(vector_data)
+		};
+
+		// A triangle whose tip is pulled a little to one side of the middle
+		let mut triangle = VectorData::from_subpath(Subpath::from_anchors([DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(53., 80.)], true));
+		triangle.transform = DAffine2::from_translation(DVec2::new(10., 20.));
+		let (mirrored, center, angle) = detect(triangle, Symmetry::Reflection);
+		assert!((angle - 90.).abs() < 3., "{angle}");
+		let axis = DVec2::from_angle(angle.to_radians());
+		let reflection =
+			DAffine2::from_translation(center) * DAffine2::from_mat2(glam::DMat2::from_cols(2. * axis.x * axis - DVec2::X, 2. * axis.y * axis - DVec2::Y)) * DAffine2::from_translation(-center);
+		assert!(symmetric_under(&mirrored, reflection));
+
+		// A square with one corner out of place
+		let square = VectorData::from_subpath(Subpath::from_anchors([DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(104., 97.), DVec2::new(0., 100.)], true));
+		let (rotated, center, angle) = detect(square, Symmetry::Rotation);
+		assert_eq!(angle, 90.);
+		assert!(center.abs_diff_eq(DVec2::splat(50.), 3.), "{center}");
+		let rotation = DAffine2::from_translation(center) * DAffine2::from_angle(angle.to_radians()) * DAffine2::from_translation(-center);
+		assert!(symmetric_under(&rotated, rotation));
+	}
+
+	#[test]
+	fn icon_keyline() {
+		let fit = |subpath: Subpath<ManipulatorGroupId>, keyline, snap_to_pixels| {
+			IconKeylineNode {
+				keyline: ClonedNode(keyline),
+				grid_size: ClonedNode(24.),
+				overshoot: ClonedNode(1.),
+				snap_to_pixels: ClonedNode(snap_to_pixels),
+			}
+			.eval(VectorData::from_subpath(subpath))
+			.bounding_box()
+			.unwrap()
+		};
+
+		// Wide artwork fits the landscape keyline, and round artwork overshoots the square keyline
+		assert_eq!(
+			fit(Subpath::new_rect(DVec2::ZERO, DVec2::new(100., 50.)), IconKeyline::Auto, false),
+			[DVec2::new(2., 7.), DVec2::new(22., 17.)]
+		);
+		assert_eq!(fit(Subpath::new_rect(DVec2::ZERO, DVec2::splat(5.)), IconKeyline::Auto, false), [DVec2::splat(3.), DVec2::splat(21.)]);
+		let [min, max] = fit(Subpath::new_ellipse(DVec2::ZERO, DVec2::splat(10.)), IconKeyline::Auto, false);
+		assert!(min.abs_diff_eq(DVec2::splat(2.), 1e-9) && max.abs_diff_eq(DVec2::splat(22.), 1e-9));
+		assert_eq!(
+			fit(Subpath::new_rect(DVec2::ZERO, DVec2::splat(5.)), IconKeyline::Portrait, false),
+			[DVec2::new(4., 4.), DVec2::new(20., 20.)]
+		);
+
+		// Nearly square artwork lands between pixels unless it's snapped
+		let [min, max] = fit(Subpath::new_rect(DVec2::ZERO, DVec2::new(30., 29.)), IconKeyline::Auto, false);
+		assert!((min.y - 3.3).abs() < 1e-9 && (max.y - 20.7).abs() < 1e-9);
+		assert_eq!(
+			fit(Subpath::new_rect(DVec2::ZERO, DVec2::new(30., 29.)), IconKeyline::Auto, true),
+			[DVec2::splat(3.), DVec2::splat(21.)]
+		);
+	}
+
+	#[test]
+	fn adjust_fill_color() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		vector_data.style.set_fill(Fill::Solid(Color::from_hsla(0., 1., 0.5, 1.)));
+		vector_data.style.set_stroke(Stroke::new(Some(Color::from_hsla(0.5, 0.8, 0.5, 1.)), 2.));
+		let mut nested = GraphicGroup::EMPTY;
+		nested.push(GraphicElement::VectorData(Box::new(vector_data)));
+		let mut graphic_group = GraphicGroup::EMPTY;
+		graphic_group.push(GraphicElement::GraphicGroup(nested));
+
+		let adjusted = AdjustFillColorNode {
+			hue_shift: ClonedNode(120.),
+			saturation_shift: ClonedNode(-50.),
+			lightness_shift: ClonedNode(0.),
+		}
+		.eval(graphic_group);
+
+		let GraphicElement::GraphicGroup(nested) = &adjusted[0] else {
+			panic!("Expected the nested group")
+		};
+		let GraphicElement::VectorData(vector_data) = &nested[0] else { panic!("Expected the shape") };
+		let [hue, saturation, ..] = vector_data.style.fill().color().to_hsla();
+		assert!((hue - 1. / 3.).abs() < 1e-3 && (saturation - 0.5).abs() < 1e-3);
+		let [hue, saturation, ..] = vector_data.style.stroke().unwrap().color().unwrap().to_hsla();
+		assert!((hue - 5. / 6.).abs() < 1e-3 && (saturation - 0.3).abs() < 1e-3);
+	}
+
+	#[test]
+	fn snap_to_grid() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::new(0.4, 0.6), DVec2::new(9.5, 10.2)));
+		vector_data.transform = DAffine2::from_translation(DVec2::new(0.3, 0.));
+		let snap = |isometric| {
+			SnapToGridNode {
+				spacing: ClonedNode(DVec2::splat(2.)),
+				origin: ClonedNode(DVec2::new(1., 0.)),
+				isometric: ClonedNode(isometric),
+				snap_handles: ClonedNode(false),
+			}
+			.eval(vector_data.clone())
+		};
+		let document_positions = |vector_data: &VectorData| {
+			vector_data
+				.point_domain
+				.positions()
+				.iter()
+				.map(|&point| vector_data.transform.transform_point2(point))
+				.collect::<Vec<_>>()
+		};
+
+		let snapped = snap(false);
+		assert_eq!(document_positions(&snapped), [DVec2::new(1., 0.), DVec2::new(9., 0.), DVec2::new(9., 10.), DVec2::new(1., 10.)]);
+		// The handles sat on their anchors, so they move along with them
+		for (_, bezier, _, _) in snapped.segment_bezier_iter() {
+			assert_eq!(
+				bezier.handles,
+				BezierHandles::Cubic {
+					handle_start: bezier.start,
+					handle_end: bezier.end
+				}
+			);
+		}
+
+		// Isometric grid points are whole steps along the slanted and vertical lines
+		let width = 1. / 30_f64.to_radians().tan();
+		for (point, original) in document_positions(&snap(true)).into_iter().zip(document_positions(&vector_data)) {
+			let column = (point.x - 1.) / width;
+			let row = (point.y + column) / 2.;
+			assert!((column - column.round()).abs() < 1e-9 && (row - row.round()).abs() < 1e-9, "{point}");
+			assert!(point.distance(original) <= 2., "{point} {original}");
+		}
+	}
+
+	#[test]
+	fn weld_points() {
+		// Three sides of a triangle whose ends almost meet, with the last one drawn the other way around
+		let mut sides = VectorData::from_subpath(Subpath::new_line(DVec2::ZERO, DVec2::new(100., 0.)));
+		sides.append_subpath(Subpath::<PointId>::new_line(DVec2::new(100.2, 0.), DVec2::new(50., 80.)));
+		sides.append_subpath(Subpath::<PointId>::new_line(DVec2::new(0., 0.4), DVec2::new(50., 80.2)));
+		sides.point_domain.set_attribute("weight", AttributeValues::F64((0..6).map(|index| index as f64).collect()));
+		let weld = |distance| WeldPointsNode { distance: ClonedNode(distance) }.eval(sides.clone());
+
+		let welded = weld(1.);
+		let subpaths = welded.stroke_bezier_paths().collect::<Vec<_>>();
+		assert_eq!(subpaths.len(), 1);
+		assert!(subpaths[0].closed());
+		let anchors = subpaths[0].anchors();
+		assert_eq!(anchors.len(), 3);
+		for (anchor, expected) in anchors.iter().zip([DVec2::new(0., 0.2), DVec2::new(100.1, 0.), DVec2::new(50., 80.1)]) {
+			assert!(anchor.abs_diff_eq(expected, 1e-9), "{anchor} != {expected}");
+		}
+		assert_eq!(welded.region_bezier_paths().count(), 1);
+		assert_eq!(welded.point_domain.attribute("weight"), Some(&AttributeValues::F64(vec![0., 1., 3.])));
+
+		// Nothing is close enough at a smaller distance
+		let unchanged = weld(0.1);
+		assert_eq!(unchanged.stroke_bezier_paths().count(), 3);
+		assert_eq!(unchanged.point_domain.positions(), sides.point_domain.positions());
+	}
+
+	#[test]
+	fn fit_curve() {
+		let noisy_points = |count: usize, position: &dyn Fn(f64) -> DVec2| {
+			let mut points = VectorData::empty();
+			for index in 0..count {
+				let noise = if index % 2 == 0 { 0.3 } else { -0.3 };
+				points.point_domain.push(PointId::generate(), position(index as f64 / (count - 1) as f64) + DVec2::splat(noise));
+			}
+			points
+		};
+		let fit = |points: VectorData, closed| {
+			FitCurveNode {
+				tolerance: ClonedNode(1.),
+				closed: ClonedNode(closed),
+			}
+			.eval(points)
+		};
+		let largest_distance = |points: &VectorData, fitted: &VectorData| {
+			let samples = fitted
+				.segment_bezier_iter()
+				.flat_map(|(_, bezier, _, _)| (0..=200).map(move |step| bezier.evaluate(TValue::Parametric(step as f64 / 200.))));
+			let samples = samples.collect::<Vec<_>>();
+			let distances = points
+				.point_domain
+				.positions()
+				.iter()
+				.map(|point| samples.iter().map(|sample| sample.distance(*point)).fold(f64::INFINITY, f64::min));
+			distances.fold(0., f64::max)
+		};
+
+		// A straight line only needs the one segment between its ends
+		let line = noisy_points(20, &|t| DVec2::new(t * 100., 50.));
+		let fitted = fit(line.clone(), false);
+		assert_eq!(fitted.segment_bezier_iter().count(), 1);
+		assert_eq!(fitted.point_domain.ids(), &[line.point_domain.ids()[0], line.point_domain.ids()[19]]);
+
+		let arc = noisy_points(60, &|t| DVec2::from_angle(t * core::f64::consts::PI) * 100.);
+		let fitted = fit(arc.clone(), false);
+		assert!((2..=4).contains(&fitted.segment_bezier_iter().count()));
+		assert!(largest_distance(&arc, &fitted) <= 1.01);
+
+		// A closed curve comes back to its first point and is filled
+		let circle = noisy_points(80, &|t| DVec2::from_angle(t * core::f64::consts::TAU * 79. / 80.) * 100.);
+		let fitted = fit(circle.clone(), true);
+		let subpaths = fitted.stroke_bezier_paths().collect::<Vec<_>>();
+		assert_eq!(subpaths.len(), 1);
+		assert!(subpaths[0].closed());
+		assert_eq!(fitted.region_bezier_paths().count(), 1);
+		assert!(largest_distance(&circle, &fitted) <= 1.01);
+
+		// Loosely enough that one cubic loops all the way around, the first point isn't repeated as the last anchor
+		let triangle = noisy_points(3, &|t| DVec2::from_angle(t * core::f64::consts::TAU * 2. / 3.) * 100.);
+		let fitted = FitCurveNode {
+			tolerance: ClonedNode(1e6),
+			closed: ClonedNode(true),
+		}
+		.eval(triangle.clone());
+		assert_eq!(fitted.point_domain.ids(), &[triangle.point_domain.ids()[0]]);
+		assert_eq!(fitted.segment_bezier_iter().count(), 1);
+	}
+
+	#[tokio::test]
+	async fn intersection_points() {
+		let square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(100.)));
+		let line = VectorData::from_subpath(Subpath::new_line(DVec2::new(-50., 50.), DVec2::new(150., 50.)));
+		let corner = VectorData::from_subpath(Subpath::new_line(DVec2::new(-50., -50.), DVec2::new(50., 50.)));
+		let intersections = |first: &VectorData, second: &VectorData| IntersectionPointsNode {
+			first: CullNode::new(FutureWrapperNode(ClonedNode(first.clone()))),
+			second: CullNode::new(FutureWrapperNode(ClonedNode(second.clone()))),
+		};
+
+		let mut points = intersections(&square, &line).eval(Footprint::default()).await.point_domain.positions().to_vec();
+		points.sort_by(|a, b| a.x.total_cmp(&b.x));
+		assert_eq!(points.len(), 2);
+		assert!(points[0].abs_diff_eq(DVec2::new(0., 50.), 1e-3), "{points:?}");
+		assert!(points[1].abs_diff_eq(DVec2::new(100., 50.), 1e-3), "{points:?}");
+
+		// Crossing through a corner shared by two segments gives a single point
+		let points = intersections(&square, &corner).eval(Footprint::default()).await;
+		assert_eq!(points.point_domain.positions().len(), 1);
+		assert!(points.point_domain.positions()[0].abs_diff_eq(DVec2::ZERO, 1e-3));
+	}
+
+	#[tokio::test]
+	async fn shape_deviation() {
+		let line = VectorData::from_subpath(Subpath::new_line(DVec2::ZERO, DVec2::new(100., 0.)));
+		let slanted = VectorData::from_subpath(Subpath::new_line(DVec2::ZERO, DVec2::new(100., 10.)));
+		let deviation = |compared: &VectorData, reference: &VectorData| ShapeDeviationNode {
+			compared: CullNode::new(FutureWrapperNode(ClonedNode(compared.clone()))),
+			reference: CullNode::new(FutureWrapperNode(ClonedNode(reference.clone()))),
+			spacing: FutureWrapperNode(ClonedNode(10.)),
+			tolerance: FutureWrapperNode(ClonedNode(10.)),
+			weight: FutureWrapperNode(ClonedNode(2.)),
+		};
+
+		// Matching outlines are all in the first shade
+		let matching = deviation(&line, &line).eval(Footprint::default()).await;
+		assert_eq!(matching.len(), 1);
+		let GraphicElement::VectorData(vector_data) = &matching[0] else { panic!("Expected vector data") };
+		assert_eq!(vector_data.segment_bezier_iter().count(), 10);
+		assert_eq!(vector_data.style.stroke().unwrap().color(), Some(Color::GREEN));
+
+		// Pieces further along the line are further from the slanted reference, so they get redder shades
+		let diverging = deviation(&line, &slanted).eval(Footprint::default()).await;
+		let pieces = diverging.iter().map(|element| {
+			let GraphicElement::VectorData(vector_data) = element else { panic!("Expected vector data") };
+			let Some(AttributeValues::F64(deviations)) = vector_data.point_domain.attribute(DEVIATION_ATTRIBUTE) else {
+				panic!("Expected deviations")
+			};
+			(vector_data.segment_bezier_iter().count(), deviations.iter().copied().fold(0., f64::max))
+		});
+		let pieces = pieces.collect::<Vec<_>>();
+		assert!(diverging.len() > 4);
+		assert_eq!(pieces.iter().map(|(count, _)| count).sum::<usize>(), 10);
+		assert!(pieces.windows(2).all(|pair| pair[0].1 < pair[1].1));
+		let GraphicElement::VectorData(last) = diverging.last().unwrap() else {
+			panic!("Expected vector data")
+		};
+		assert_eq!(last.style.stroke().unwrap().color(), Some(Color::RED));
+	}
+
+	#[test]
+	fn scale_preserving_corners() {
+		// A square with its corners rounded off to a radius of 2, by curves with their handles the usual fraction of the way to each corner for a circular arc
+		let (radius, fraction) = (2., 0.5523);
+		let corners = [DVec2::ZERO, DVec2::new(10., 0.), DVec2::splat(10.), DVec2::new(0., 10.)];
+		let mut beziers = Vec::new();
+		for (index, &corner) in corners.iter().enumerate() {
+			let (previous, next) = (corners[(index + 3) % 4], corners[(index + 1) % 4]);
+			let (start, end) = (corner + (previous - corner).normalize() * radius, corner + (next - corner).normalize() * radius);
+			beziers.push(Bezier::from_cubic_dvec2(start, start + (corner - start) * fraction, end + (corner - end) * fraction, end));
+			beziers.push(Bezier::from_linear_dvec2(end, next + (corner - next).normalize() * radius));
 		}
-		.eval(Footprint::default())
-		.await;
-		assert_eq!(sample_points.point_domain.positions().len(), 4);
-		for (pos, expected) in sample_points.point_domain.positions().iter().zip([DVec2::X * 0., DVec2::X * 30., DVec2::X * 60., DVec2::X * 90.]) {
-			assert!(pos.distance(expected) < 1e-3, "Expected {expected} found {pos}");
+		let scale = |vector_data: VectorData, scale: DVec2| ScalePreservingCornersNode { scale: ClonedNode(scale) }.eval(vector_data);
+
+		let rounded = scale(VectorData::from_subpath(Subpath::from_beziers(&beziers, true)), DVec2::new(3., 0.5));
+		let [min, max] = rounded.bounding_box().unwrap();
+		assert!(min.abs_diff_eq(DVec2::new(-10., 2.5), 1e-9) && max.abs_diff_eq(DVec2::new(20., 7.5), 1e-9));
+		// The corners keep their radius rather than being stretched into ellipses
+		let curves = rounded.segment_bezier_iter().filter(|(_, bezier, _, _)| !is_straight(bezier)).collect::<Vec<_>>();
+		assert_eq!(curves.len(), 4);
+		for (_, curve, _, _) in curves {
+			assert!((curve.start.distance(curve.end) - radius * 2_f64.sqrt()).abs() < 1e-9);
+		}
+
+		// Sharp corners are scaled as usual, as are the corners of lines made of curves with their handles on their anchors
+		let square = scale(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.))), DVec2::new(2., 1.));
+		assert_eq!(square.bounding_box(), Some([DVec2::new(-5., 0.), DVec2::new(15., 10.)]));
+		let mut straight_cubics = beziers.clone();
+		for bezier in straight_cubics.iter_mut().filter(|bezier| bezier.handles == BezierHandles::Linear) {
+			*bezier = Bezier::from_cubic_dvec2(bezier.start, bezier.start, bezier.end, bezier.end);
 		}
+		let rounded = scale(VectorData::from_subpath(Subpath::from_beziers(&straight_cubics, true)), DVec2::new(3., 0.5));
+		assert_eq!(rounded.segment_bezier_iter().filter(|(_, bezier, _, _)| !is_straight(bezier)).count(), 4);
 	}
+
 	#[tokio::test]
-	async fn adaptive_spacing() {
-		let path = VectorData::from_subpath(Subpath::from_bezier(&Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::ZERO, DVec2::X * 100., DVec2::X * 100.)));
-		let sample_points = SamplePoints {
-			vector_data: CullNode::new(FutureWrapperNode(ClonedNode(path))),
-			spacing: FutureWrapperNode(ClonedNode(18.)),
-			start_offset: FutureWrapperNode(ClonedNode(45.)),
-			stop_offset: FutureWrapperNode(ClonedNode(10.)),
-			adaptive_spacing: FutureWrapperNode(ClonedNode(true)),
-			lengths_of_segments_of_subpaths: CullNode::new(FutureWrapperNode(ClonedNode(vec![100.]))),
+	async fn align_to_reference() {
+		let reference = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		// The same square, drawn the other way around from a different corner, then turned, enlarged, and moved
+		let corners = [DVec2::new(0., 10.), DVec2::ZERO, DVec2::new(10., 0.), DVec2::splat(10.)];
+		let mut traced = VectorData::from_subpath(Subpath::from_anchors(corners.into_iter().rev(), true));
+		traced.transform = DAffine2::from_scale_angle_translation(DVec2::splat(2.), 0.5, DVec2::new(30., -20.));
+		let align = |scale| AlignToReferenceNode {
+			vector_data: CullNode::new(FutureWrapperNode(ClonedNode(traced.clone()))),
+			reference: CullNode::new(FutureWrapperNode(ClonedNode(reference.clone()))),
+			scale: FutureWrapperNode(ClonedNode(scale)),
+		};
+
+		let aligned = align(true).eval(Footprint::default()).await;
+		for position in aligned.point_domain.positions() {
+			let position = aligned.transform.transform_point2(*position);
+			assert!(corners.iter().any(|corner| corner.abs_diff_eq(position, 1e-3)), "{position}");
 		}
-		.eval(Footprint::default())
-		.await;
-		assert_eq!(sample_points.point_domain.positions().len(), 4);
-		for (pos, expected) in sample_points.point_domain.positions().iter().zip([DVec2::X * 45., DVec2::X * 60., DVec2::X * 75., DVec2::X * 90.]) {
-			assert!(pos.distance(expected) < 1e-3, "Expected {expected} found {pos}");
+
+		// Without scaling, the square keeps its size but is centered and turned to match
+		let unscaled = align(false).eval(Footprint::default()).await;
+		let [min, max] = unscaled.bounding_box_with_transform(unscaled.transform).unwrap();
+		assert!(min.abs_diff_eq(DVec2::splat(-5.), 1e-3) && max.abs_diff_eq(DVec2::splat(15.), 1e-3), "{min} {max}");
+	}
+
+	#[test]
+	fn variable_offset() {
+		let offset = |vector_data, attribute: &str, taper| {
+			VariableOffsetNode {
+				distance: ClonedNode(10.),
+				attribute: ClonedNode(attribute.to_string()),
+				taper: ClonedNode(taper),
+				envelope: ClonedNode(Curve::default()),
+			}
+			.eval(vector_data)
+		};
+		let inside = |outline: &VectorData, point| flattened_regions(outline, 16).iter().any(|polygon| inside_polygon(polygon, point));
+		let mut line = VectorData::from_subpath(Subpath::new_line(DVec2::ZERO, DVec2::new(100., 0.)));
+
+		let outline = offset(line.clone(), "", false);
+		assert_eq!(outline.region_bezier_paths().count(), 1);
+		let [min, max] = outline.bounding_box().unwrap();
+		assert!(min.abs_diff_eq(DVec2::new(0., -10.), 1e-6) && max.abs_diff_eq(DVec2::new(100., 10.), 1e-6));
+
+		// The default envelope narrows the outline evenly to points at both ends
+		let tapered = offset(line.clone(), "", true);
+		assert!(inside(&tapered, DVec2::new(50., 9.)) && inside(&tapered, DVec2::new(25., 4.)));
+		assert!(!inside(&tapered, DVec2::new(25., 6.)) && !inside(&tapered, DVec2::new(90., -3.)));
+
+		line.point_domain.set_attribute("width", AttributeValues::F64(vec![0., 1.]));
+		let widening = offset(line, "width", false);
+		assert!(inside(&widening, DVec2::new(25., 2.)) && inside(&widening, DVec2::new(90., -8.)));
+		assert!(!inside(&widening, DVec2::new(25., 3.)));
+
+		// A closed path gives a ring, with the inner loop leaving a hole
+		let ring = offset(VectorData::from_subpath(Subpath::new_ellipse(DVec2::ZERO, DVec2::splat(100.))), "", true);
+		assert_eq!(ring.region_bezier_paths().count(), 2);
+		let [min, max] = ring.bounding_box().unwrap();
+		assert!(min.abs_diff_eq(DVec2::splat(-10.), 0.1) && max.abs_diff_eq(DVec2::splat(110.), 0.1));
+	}
+	#[test]
+	fn extrude() {
+		let mut square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		square.style.set_fill(Fill::Solid(Color::WHITE));
+		let extrusion = ExtrudeNode {
+			direction: ClonedNode(DVec2::new(5., -5.)),
+			shading: ClonedNode(0.5),
+			side_color: ClonedNode(None),
 		}
+		.eval(square);
+
+		let (GraphicElement::GraphicGroup(sides), GraphicElement::VectorData(cap)) = (&extrusion[0], &extrusion[1]) else {
+			panic!("The extrusion should be the side faces followed by the cap")
+		};
+		assert_eq!(cap.transform, DAffine2::from_translation(DVec2::new(5., -5.)));
+		let faces = sides
+			.iter()
+			.map(|face| {
+				let GraphicElement::VectorData(face) = face else { panic!("Each face should be a shape") };
+				(face.bounding_box().unwrap(), face.style.fill().color())
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(faces.len(), 4);
+
+		// The top and right faces are hidden behind the others, and the bottom face is darker than the left one as it faces further from the light
+		let top = [DVec2::new(0., -5.), DVec2::new(15., 0.)];
+		assert!(faces[..2].iter().any(|(bounds, _)| *bounds == top));
+		let shade = |bounds: [DVec2; 2]| faces.iter().find(|(face, _)| *face == bounds).unwrap().1.r();
+		let (left, bottom) = (shade([DVec2::new(0., -5.), DVec2::new(5., 10.)]), shade([DVec2::new(0., 5.), DVec2::new(15., 10.)]));
+		assert!(bottom < left && left < 1. && bottom >= 0.5);
 	}
 	#[test]
-	fn poisson() {
-		let sample_points = PoissonDiskPoints {
-			separation_disk_diameter: ClonedNode(10. * std::f64::consts::SQRT_2),
+	fn hatch_fill() {
+		let mut square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(100.)));
+		square.append_subpath(Subpath::<PointId>::new_rect(DVec2::splat(40.), DVec2::splat(60.)));
+		let hatch = |cross_hatch| HatchFillNode {
+			angle: ClonedNode(0.),
+			spacing: ClonedNode(10.),
+			cross_hatch: ClonedNode(cross_hatch),
+		};
+
+		let result = hatch(false).eval(square.clone());
+		let lines = result.segment_bezier_iter().map(|(_, bezier, _, _)| [bezier.start, bezier.end]).collect::<Vec<_>>();
+		// Lines at y = 5, 15, ..., 95, where the two lines at y = 45 and y = 55 are split by the hole
+		assert_eq!(lines.len(), 12);
+		assert!(lines.iter().all(|[start, end]| start.y == end.y && (start.x - end.x).abs() > 1.));
+		assert!(lines.contains(&[DVec2::new(0., 45.), DVec2::new(40., 45.)]));
+		assert!(lines.contains(&[DVec2::new(60., 45.), DVec2::new(100., 45.)]));
+		assert!(result.style.stroke().is_some());
+
+		assert_eq!(hatch(true).eval(square.clone()).segment_bezier_iter().count(), 24);
+
+		// A tiny spacing is widened to keep the number of lines bounded
+		let dense = HatchFillNode {
+			angle: ClonedNode(0.),
+			spacing: ClonedNode(1e-9),
+			cross_hatch: ClonedNode(false),
 		}
-		.eval(VectorData::from_subpath(Subpath::new_ellipse(DVec2::NEG_ONE * 50., DVec2::ONE * 50.)));
-		assert!(
-			(20..=40).contains(&sample_points.point_domain.positions().len()),
-			"actual len {}",
-			sample_points.point_domain.positions().len()
-		);
-		for point in sample_points.point_domain.positions() {
-			assert!(point.length() < 50. + 1., "Expected point in circle {point}")
+		.eval(square);
+		let count = dense.segment_bezier_iter().count();
+		assert!(count > 12 && count <= 2000, "{count}");
+	}
+
+	#[test]
+	fn hatch_shading() {
+		let circle = VectorData::from_subpath(Subpath::new_ellipse(DVec2::ZERO, DVec2::splat(100.)));
+		let shade = |cross_hatch| {
+			HatchShadingNode {
+				light_direction: ClonedNode(180.),
+				angle: ClonedNode(90.),
+				min_spacing: ClonedNode(1.),
+				max_spacing: ClonedNode(8.),
+				cross_hatch: ClonedNode(cross_hatch),
+				jitter: ClonedNode(0.),
+				seed: ClonedNode(0),
+			}
+			.eval(circle.clone())
+		};
+
+		// With the light from the left, the lines are packed more tightly on the right side
+		let lines = shade(1.).segment_bezier_iter().map(|(_, bezier, _, _)| [bezier.start, bezier.end]).collect::<Vec<_>>();
+		let count = |side: fn(f64) -> bool| lines.iter().filter(|[start, _]| side(start.x - 50.)).count();
+		assert!(count(|x| x > 20.) > count(|x| x < -20.));
+		assert!(lines.iter().all(|[start, end]| (start.x - end.x).abs() < 1e-6));
+
+		// Cross hatching adds horizontal lines in the darkest parts
+		let crossed = shade(0.5);
+		assert!(crossed
+			.segment_bezier_iter()
+			.any(|(_, bezier, _, _)| (bezier.start.y - bezier.end.y).abs() < 1e-6 && bezier.start.x > 50.));
+	}
+
+	#[test]
+	fn points_in_bounds() {
+		let points = |vector_data: &VectorData, inset: f64, stagger: bool, inside_shape: bool| {
+			PointsInBoundsNode {
+				spacing: ClonedNode(DVec2::splat(10.)),
+				inset: ClonedNode(inset),
+				stagger: ClonedNode(stagger),
+				inside_shape: ClonedNode(inside_shape),
+			}
+			.eval(vector_data.clone())
+		};
+
+		let square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(100.)));
+		let lattice = points(&square, 0., false, false);
+		assert_eq!(lattice.point_domain.positions().len(), 121);
+		assert_eq!(lattice.point_domain.positions()[12], DVec2::new(10., 10.));
+		assert_eq!(lattice.point_domain.attribute(ROW_ATTRIBUTE).and_then(|rows| rows.scalar(12)), Some(1.));
+
+		// The inset shrinks the box and the lattice is centered within it, while staggered rows leave off the point that would pass its edge
+		let inset = points(&square, 5., false, false);
+		assert_eq!(inset.point_domain.positions().len(), 100);
+		assert_eq!(inset.point_domain.positions()[0], DVec2::splat(5.));
+		assert_eq!(points(&square, 5., true, false).point_domain.positions().len(), 95);
+
+		// Points can be kept to those inside the shape itself
+		let circle = VectorData::from_subpath(Subpath::new_ellipse(DVec2::ZERO, DVec2::splat(100.)));
+		let inside = points(&circle, 0., false, true);
+		let count = inside.point_domain.positions().len();
+		assert!(count > 60 && count < 121, "{count}");
+		assert!(inside.point_domain.positions().iter().all(|point| point.distance(DVec2::splat(50.)) < 50.));
+	}
+
+	#[test]
+	fn mosaic() {
+		let square = |min: f64, max: f64, color: Color| {
+			let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::splat(min), DVec2::splat(max)));
+			vector_data.style.set_fill(Fill::Solid(color));
+			GraphicElement::from(vector_data)
+		};
+		let mut artwork = GraphicGroup::EMPTY;
+		artwork.push(square(0., 40., Color::RED));
+		artwork.push(square(20., 40., Color::BLUE));
+		let mosaic = |artwork: &GraphicGroup, cell_shape: MosaicCell, levels: u32| {
+			MosaicNode {
+				cell_shape: ClonedNode(cell_shape),
+				cell_size: ClonedNode(10.),
+				gap: ClonedNode(2.),
+				levels: ClonedNode(levels),
+			}
+			.eval(artwork.clone())
+		};
+		let palette = |mosaic: &GraphicGroup| {
+			mosaic
+				.iter()
+				.map(|element| {
+					let GraphicElement::VectorInstances(instances) = element else { panic!("Expected instances") };
+					(
+						instances.geometry.style.fill().color(),
+						instances.instances.iter().map(|instance| instance.translation).collect::<Vec<_>>(),
+					)
+				})
+				.collect::<Vec<_>>()
+		};
+
+		// Each cell takes the color of the topmost shape at its center, with the cells of each color sharing one shrunken square
+		let squares = mosaic(&artwork, MosaicCell::Square, 0);
+		let colors = palette(&squares);
+		assert_eq!(colors.len(), 2);
+		assert_eq!((colors[0].0, colors[0].1.len()), (Color::RED, 12));
+		assert_eq!((colors[1].0, colors[1].1.len()), (Color::BLUE, 4));
+		assert!(colors[1].1.iter().all(|center| center.x > 20. && center.y > 20.));
+		let GraphicElement::VectorInstances(instances) = &squares[0] else { panic!("Expected instances") };
+		assert_eq!(instances.geometry.bounding_box(), Some([DVec2::splat(-4.), DVec2::splat(4.)]));
+
+		// Offset rows of hexagons cover the same artwork, without any cells over the empty space around it
+		let hexagons = palette(&mosaic(&artwork, MosaicCell::Hexagon, 0));
+		assert!(hexagons
+			.iter()
+			.flat_map(|(_, centers)| centers)
+			.all(|center| (0.0..=40.).contains(&center.x) && (0.0..=40.).contains(&center.y)));
+		assert!(hexagons.iter().flat_map(|(_, centers)| centers).any(|center| (center.x - 10.).abs() < 1e-9));
+
+		// Posterizing merges similar colors into one
+		let mut shaded = GraphicGroup::EMPTY;
+		shaded.push(square(0., 20., Color::from_rgbf32_unchecked(0.9, 0., 0.)));
+		shaded.push(square(20., 40., Color::RED));
+		assert_eq!(palette(&mosaic(&shaded, MosaicCell::Circle, 2)).len(), 1);
+		assert_eq!(palette(&mosaic(&shaded, MosaicCell::Circle, 0)).len(), 2);
+	}
+
+	#[test]
+	fn medial_axis() {
+		let medial_axis = |vector_data| {
+			MedialAxisNode {
+				spacing: ClonedNode(1.),
+				pruning: ClonedNode(2.),
+			}
+			.eval(vector_data)
+		};
+
+		// A bar's skeleton runs along its middle, with the branches out to its corners pruned away
+		let bar = medial_axis(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::new(100., 20.))));
+		assert_eq!(bar.stroke_bezier_paths().count(), 1);
+		let positions = bar.point_domain.positions();
+		assert!(positions.len() > 10);
+		assert!(positions.iter().all(|position| (position.y - 10.).abs() < 0.1 && (5.0..=95.).contains(&position.x)), "{positions:?}");
+		let Some(AttributeValues::F64(radii)) = bar.point_domain.attribute(RADIUS_ATTRIBUTE) else {
+			panic!("The skeleton should have radii");
+		};
+		assert!(radii.iter().all(|radius| (radius - 10.).abs() < 0.1));
+
+		// A frame's skeleton is a loop around the hole
+		let mut frame = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(100.)));
+		frame.append_subpath(Subpath::<PointId>::new_rect(DVec2::splat(20.), DVec2::splat(80.)));
+		let frame = medial_axis(frame);
+		let loops = frame.stroke_bezier_paths().filter(|subpath| subpath.closed()).collect::<Vec<_>>();
+		assert_eq!(loops.len(), 1);
+		// Along the sides it's midway between the outline and the hole, and it cuts across the corners a little
+		let from_center = |anchor: &DVec2| (*anchor - DVec2::splat(50.)).abs().max_element();
+		assert!(loops[0].anchors().iter().all(|anchor| (37.5..=40.5).contains(&from_center(anchor))));
+	}
+
+	#[test]
+	fn pathfind() {
+		let obstacles = VectorData::from_subpath(Subpath::new_rect(DVec2::splat(40.), DVec2::splat(60.)));
+		let route = |start, corner_radius| {
+			PathfindNode {
+				start: ClonedNode(start),
+				end: ClonedNode(DVec2::new(100., 50.)),
+				clearance: ClonedNode(5.),
+				corner_radius: ClonedNode(corner_radius),
+			}
+			.eval(obstacles.clone())
+		};
+
+		// Around the square grown by the clearance, turning at two of its corners
+		let result = route(DVec2::new(0., 50.), 0.);
+		let points = result.point_domain.positions();
+		assert_eq!(points.len(), 4);
+		assert!(points[1..3].iter().all(|point| (point.x == 35. || point.x == 65.) && (point.y == 35. || point.y == 65.)));
+		let length: f64 = result.segment_bezier_iter().map(|(_, bezier, _, _)| bezier.length(None)).sum();
+		assert!((length - (2. * 35_f64.hypot(15.) + 30.)).abs() < 1e-6);
+
+		// Rounding replaces each corner with a curve between two points
+		let rounded = route(DVec2::new(0., 50.), 10.);
+		assert_eq!(rounded.point_domain.positions().len(), 6);
+		assert_eq!(rounded.segment_bezier_iter().filter(|(_, bezier, _, _)| bezier.handles != BezierHandles::Linear).count(), 2);
+
+		// An obstacle covering the start is ignored
+		assert_eq!(route(DVec2::new(50., 50.), 0.).point_domain.positions(), &[DVec2::new(50., 50.), DVec2::new(100., 50.)]);
+	}
+
+	#[test]
+	fn filter_subpaths() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		vector_data.append_subpath(Subpath::<PointId>::new_rect(DVec2::splat(20.), DVec2::splat(21.)));
+		vector_data.append_subpath(Subpath::<PointId>::new_ellipse(DVec2::splat(30.), DVec2::splat(34.)));
+		vector_data.point_domain.set_attribute("weight", AttributeValues::F64((0..12).map(|index| index as f64).collect()));
+		let filter = |minimum, invert, order| {
+			FilterSubpathsNode {
+				measure: ClonedNode(SubpathMeasure::Area),
+				minimum: ClonedNode(minimum),
+				maximum: ClonedNode(0.),
+				invert: ClonedNode(invert),
+				order: ClonedNode(order),
+			}
+			.eval(vector_data.clone())
+		};
+		let areas = |vector_data: &VectorData| {
+			vector_data
+				.stroke_bezier_paths()
+				.map(|subpath| SubpathMeasure::Area.measure(&subpath, vector_data.transform).round())
+				.collect::<Vec<_>>()
+		};
+
+		// The speck is removed, along with its attribute values
+		let cleaned = filter(5., false, SubpathOrder::Original);
+		assert_eq!(areas(&cleaned), [100., 13.]);
+		assert_eq!(cleaned.region_bezier_paths().count(), 2);
+		assert_eq!(cleaned.point_domain.attribute("weight"), Some(&AttributeValues::F64(vec![0., 1., 2., 3., 8., 9., 10., 11.])));
+
+		assert_eq!(areas(&filter(5., true, SubpathOrder::Original)), [1.]);
+		assert_eq!(areas(&filter(0., false, SubpathOrder::Ascending)), [1., 13., 100.]);
+		assert_eq!(areas(&filter(0., false, SubpathOrder::Descending)), [100., 13., 1.]);
+
+		let lengths = Subpath::<PointId>::new_rect(DVec2::ZERO, DVec2::new(2., 3.));
+		assert_eq!(SubpathMeasure::Length.measure(&lengths, DAffine2::from_scale(DVec2::splat(2.))), 20.);
+		assert_eq!(SubpathMeasure::PointCount.measure(&lengths, DAffine2::IDENTITY), 4.);
+		assert_eq!(SubpathMeasure::BoundingBoxSize.measure(&lengths, DAffine2::IDENTITY), 3.);
+	}
+
+	#[test]
+	fn get_subpath() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(1.)));
+		vector_data.append_subpath(Subpath::<PointId>::new_rect(DVec2::splat(10.), DVec2::splat(11.)));
+		vector_data.append_subpath(Subpath::<PointId>::new_rect(DVec2::splat(20.), DVec2::splat(21.)));
+		vector_data.point_domain.set_attribute("weight", AttributeValues::F64((0..12).map(|index| index as f64).collect()));
+		let get = |index, count, out_of_range| {
+			let picked = GetSubpathNode {
+				index: ClonedNode(index),
+				count: ClonedNode(count),
+				out_of_range: ClonedNode(out_of_range),
+			}
+			.eval(vector_data.clone());
+			picked.stroke_bezier_paths().map(|subpath| subpath.manipulator_groups()[0].anchor.x).collect::<Vec<_>>()
+		};
+
+		assert_eq!(get(1., 1, IndexOutOfRange::Skip), [10.]);
+		assert_eq!(get(-1., 1, IndexOutOfRange::Skip), [20.]);
+		assert_eq!(get(1., 5, IndexOutOfRange::Skip), [10., 20.]);
+		assert!(get(3., 1, IndexOutOfRange::Skip).is_empty());
+		assert_eq!(get(3., 1, IndexOutOfRange::Clamp), [20.]);
+		assert_eq!(get(-5., 2, IndexOutOfRange::Clamp), [0., 10.]);
+		assert_eq!(get(2., 2, IndexOutOfRange::Wrap), [20., 0.]);
+
+		// Attribute values follow the points of the subpath that's picked
+		let middle = GetSubpathNode {
+			index: ClonedNode(1.),
+			count: ClonedNode(1),
+			out_of_range: ClonedNode(IndexOutOfRange::Skip),
 		}
+		.eval(vector_data.clone());
+		assert_eq!(middle.point_domain.attribute("weight"), Some(&AttributeValues::F64(vec![4., 5., 6., 7.])));
+		assert_eq!(middle.region_bezier_paths().count(), 1);
 	}
+
 	#[test]
-	fn lengths() {
-		let subpath = VectorData::from_subpath(Subpath::from_bezier(&Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::ZERO, DVec2::X * 100., DVec2::X * 100.)));
-		let lengths = LengthsOfSegmentsOfSubpaths.eval(subpath);
-		assert_eq!(lengths, vec![100.]);
+	fn style_subpaths() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(1.)));
+		vector_data.append_subpath(Subpath::<PointId>::new_rect(DVec2::splat(10.), DVec2::splat(20.)));
+		vector_data.append_subpath(Subpath::<PointId>::new_rect(DVec2::splat(30.), DVec2::splat(31.)));
+		let mut source = VectorData::empty();
+		source.style.set_fill(Fill::Solid(Color::RED));
+
+		let styled = StyleSubpathsNode {
+			source: ClonedNode(source.clone()),
+			index: ClonedNode(-1.),
+			count: ClonedNode(1),
+		}
+		.eval(vector_data.clone());
+		assert_eq!(styled.subpath_styles.iter().map(|(index, _)| *index).collect::<Vec<_>>(), [2]);
+		assert_eq!(styled.subpath_style(2).fill(), &Fill::Solid(Color::RED));
+		assert_eq!(styled.subpath_style(0), &vector_data.style);
+
+		let large = StyleSubpathsByMeasureNode {
+			source: ClonedNode(source),
+			measure: ClonedNode(SubpathMeasure::Area),
+			minimum: ClonedNode(10.),
+			maximum: ClonedNode(0.),
+		}
+		.eval(vector_data);
+		assert_eq!(large.subpath_styles.iter().map(|(index, _)| *index).collect::<Vec<_>>(), [1]);
+
+		// Picking out a subpath keeps its style, now at its new index
+		let picked = GetSubpathNode {
+			index: ClonedNode(1.),
+			count: ClonedNode(2),
+			out_of_range: ClonedNode(IndexOutOfRange::Skip),
+		}
+		.eval(styled);
+		assert_eq!(picked.subpath_styles.iter().map(|(index, _)| *index).collect::<Vec<_>>(), [1]);
 	}
+
 	#[test]
-	fn spline() {
-		let subpath = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE * 100.));
-		let spline = SplinesFromPointsNode.eval(subpath);
-		assert_eq!(spline.stroke_bezier_paths().count(), 1);
-		assert_eq!(spline.point_domain.positions(), &[DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(100., 100.), DVec2::new(0., 100.)]);
+	fn tangent_normal_lines() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_line(DVec2::ZERO, DVec2::new(100., 0.)));
+		vector_data.append_subpath(Subpath::<PointId>::new_rect(DVec2::new(0., 50.), DVec2::new(20., 70.)));
+		let lines = |normal| {
+			TangentNormalLinesNode {
+				spacing: ClonedNode(25.),
+				length: ClonedNode(5.),
+				normal: ClonedNode(normal),
+			}
+			.eval(vector_data.clone())
+			.segment_bezier_iter()
+			.map(|(_, bezier, _, _)| [bezier.start, bezier.end])
+			.collect::<Vec<_>>()
+		};
+
+		// Five samples along the open line including both ends, and four around the closed square without repeating its start
+		let tangents = lines(false);
+		assert_eq!(tangents.len(), 9);
+		assert_eq!(tangents[0], [DVec2::ZERO, DVec2::new(5., 0.)]);
+		assert!(tangents[4][0].abs_diff_eq(DVec2::new(100., 0.), 1e-6));
+		assert!(tangents.iter().all(|[start, end]| (start.distance(*end) - 5.).abs() < 1e-6));
+
+		let normals = lines(true);
+		assert_eq!(normals.len(), 9);
+		assert!(normals[2][0].abs_diff_eq(DVec2::new(50., 0.), 1e-6));
+		assert!(normals[2][1].abs_diff_eq(DVec2::new(50., 5.), 1e-6));
 	}
 	#[tokio::test]
-	async fn morph() {
-		let source = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE * 100.));
-		let target = VectorData::from_subpath(Subpath::new_ellipse(DVec2::NEG_ONE * 100., DVec2::ZERO));
-		let sample_points = MorphNode {
+	async fn copy_to_points_random_seed() {
+		let copy = |random_rotation_min: f64, random_rotation_max: f64, random_seed: u32| async move {
+			let points = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(100.)));
+			let copies = CopyToPoints {
+				points: CullNode::new(FutureWrapperNode(ClonedNode(points))),
+				instance: CullNode::new(FutureWrapperNode(ClonedNode(VectorData::from_subpath(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE))))),
+				random_scale_min: FutureWrapperNode(ClonedNode(1.)),
+				random_scale_max: FutureWrapperNode(ClonedNode(2.)),
+				random_scale_bias: FutureWrapperNode(ClonedNode(0.)),
+				random_rotation_min: FutureWrapperNode(ClonedNode(random_rotation_min)),
+				random_rotation_max: FutureWrapperNode(ClonedNode(random_rotation_max)),
+				random_seed: FutureWrapperNode(ClonedNode(random_seed)),
+				align_to_path: FutureWrapperNode(ClonedNode(false)),
+				variant_selection: FutureWrapperNode(ClonedNode(VariantSelection::Whole)),
+			}
+			.eval(Footprint::default())
+			.await;
+			copies.region_bezier_paths().map(|(_, subpath)| subpath.manipulator_groups()[0].anchor).collect::<Vec<_>>()
+		};
+
+		let first = copy(-45., 45., 0).await;
+		assert_eq!(first, copy(-45., 45., 0).await);
+		assert_ne!(first, copy(-45., 45., 1).await);
+
+		// With no range of rotations, every copy is turned by the same angle, leaving the first corner of each one on the same side of its point
+		let fixed = copy(90., 90., 3).await;
+		for (anchor, point) in fixed.iter().zip([DVec2::ZERO, DVec2::new(100., 0.), DVec2::splat(100.), DVec2::new(0., 100.)]) {
+			let corner = (*anchor - point) / (*anchor - point).length();
+			assert!(corner.abs_diff_eq(DVec2::new(1., -1.).normalize(), 1e-6), "Found {corner}");
+			assert!((1. ..=2.).contains(&(anchor.distance(point) / std::f64::consts::SQRT_2)));
+		}
+	}
+	#[tokio::test]
+	async fn copy_to_points_aligned_to_path() {
+		let copy = |points: VectorData| async move {
+			let copies = CopyToPoints {
+				points: CullNode::new(FutureWrapperNode(ClonedNode(points))),
+				instance: CullNode::new(FutureWrapperNode(ClonedNode(VectorData::from_subpath(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE))))),
+				random_scale_min: FutureWrapperNode(ClonedNode(1.)),
+				random_scale_max: FutureWrapperNode(ClonedNode(1.)),
+				random_scale_bias: FutureWrapperNode(ClonedNode(0.)),
+				random_rotation_min: FutureWrapperNode(ClonedNode(0.)),
+				random_rotation_max: FutureWrapperNode(ClonedNode(0.)),
+				random_seed: FutureWrapperNode(ClonedNode(0)),
+				align_to_path: FutureWrapperNode(ClonedNode(true)),
+				variant_selection: FutureWrapperNode(ClonedNode(VariantSelection::Whole)),
+			}
+			.eval(Footprint::default())
+			.await;
+			copies.region_bezier_paths().map(|(_, subpath)| subpath.manipulator_groups()[0].anchor).collect::<Vec<_>>()
+		};
+
+		// Without a tangent attribute, the direction comes from the segment running downwards through both points
+		let anchors = copy(VectorData::from_subpath(Subpath::new_line(DVec2::ZERO, DVec2::new(0., 10.)))).await;
+		assert!(anchors[0].abs_diff_eq(DVec2::new(1., -1.), 1e-6), "Found {}", anchors[0]);
+		assert!(anchors[1].abs_diff_eq(DVec2::new(1., 9.), 1e-6), "Found {}", anchors[1]);
+
+		let mut points = VectorData::empty();
+		points.point_domain.push(PointId::generate(), DVec2::ZERO);
+		points.point_domain.set_attribute(TANGENT_ATTRIBUTE, AttributeValues::DVec2(vec![DVec2::NEG_X]));
+		let anchors = copy(points).await;
+		assert!(anchors[0].abs_diff_eq(DVec2::ONE, 1e-6), "Found {}", anchors[0]);
+	}
+	#[tokio::test]
+	async fn copy_to_points_instance_variants() {
+		let mut instance = GraphicGroup::EMPTY;
+		for size in [1., 2., 3.] {
+			instance.push(VectorData::from_subpath(Subpath::new_rect(DVec2::splat(-size), DVec2::splat(size))).into());
+		}
+		let copy = |variant_selection: VariantSelection, random_seed: u32| {
+			let instance = instance.clone();
+			async move {
+				let copies = CopyToPoints {
+					points: CullNode::new(FutureWrapperNode(ClonedNode(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(100.)))))),
+					instance: CullNode::new(FutureWrapperNode(ClonedNode(instance))),
+					random_scale_min: FutureWrapperNode(ClonedNode(1.)),
+					random_scale_max: FutureWrapperNode(ClonedNode(1.)),
+					random_scale_bias: FutureWrapperNode(ClonedNode(0.)),
+					random_rotation_min: FutureWrapperNode(ClonedNode(0.)),
+					random_rotation_max: FutureWrapperNode(ClonedNode(0.)),
+					random_seed: FutureWrapperNode(ClonedNode(random_seed)),
+					align_to_path: FutureWrapperNode(ClonedNode(false)),
+					variant_selection: FutureWrapperNode(ClonedNode(variant_selection)),
+				}
+				.eval(Footprint::default())
+				.await;
+				// Each copy is identified by the half-width of the variant placed there
+				copies
+					.iter()
+					.map(|element| {
+						let [min, max] = element.bounding_box(DAffine2::IDENTITY).unwrap();
+						assert!((0.5 * (min + max)).abs_diff_eq((0.5 * (min + max)).round(), 1e-6), "Copy isn't centered on a point");
+						(0.5 * (max.x - min.x)).round()
+					})
+					.collect::<Vec<_>>()
+			}
+		};
+
+		assert_eq!(copy(VariantSelection::Whole, 0).await.len(), 12);
+		assert_eq!(copy(VariantSelection::RoundRobin, 0).await, vec![1., 2., 3., 1.]);
+
+		let random = copy(VariantSelection::Random, 0).await;
+		assert_eq!(random.len(), 4);
+		assert!(random.iter().all(|size| [1., 2., 3.].contains(size)));
+		assert_eq!(random, copy(VariantSelection::Random, 0).await);
+		let mut reseeded = Vec::new();
+		for random_seed in 1..10 {
+			reseeded.push(copy(VariantSelection::Random, random_seed).await);
+		}
+		assert!(reseeded.iter().any(|sizes| *sizes != random));
+	}
+	#[test]
+	fn set_and_read_point_attribute() {
+		let points = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		let points = SetPointAttributeNode {
+			name: ClonedNode("weight".to_string()),
+			start: ClonedNode(1.),
+			end: ClonedNode(4.),
+		}
+		.eval(points);
+		assert_eq!(points.point_domain.attribute("weight"), Some(&AttributeValues::F64(vec![1., 2., 3., 4.])));
+
+		let read = |name: &str, index| {
+			ReadPointAttributeNode {
+				name: ClonedNode(name.to_string()),
+				index: ClonedNode(index),
+			}
+			.eval(points.clone())
+		};
+		assert_eq!(read("weight", 2), 3.);
+		assert_eq!(read("weight", 10), 0.);
+		assert_eq!(read("missing", 0), 0.);
+	}
+	#[tokio::test]
+	async fn transfer_point_attributes_blended() {
+		let mut source = VectorData::empty();
+		for position in [DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(0., 100.)] {
+			source.point_domain.push(PointId::generate(), position);
+		}
+		source.point_domain.set_attribute("weight", AttributeValues::F64(vec![0., 10., 20.]));
+		source.point_domain.set_attribute("tint", AttributeValues::Color(vec![Color::BLACK, Color::WHITE, Color::WHITE]));
+
+		let mut points = VectorData::empty();
+		for position in [DVec2::new(50., 0.), DVec2::new(25., 25.), DVec2::new(100., 100.), DVec2::new(300., 0.)] {
+			points.point_domain.push(PointId::generate(), position);
+		}
+		points.point_domain.set_attribute("weight", AttributeValues::F64(vec![-1.; 4]));
+		let transfer = |method, radius| {
+			TransferPointAttributesNode {
+				points: CullNode::new(FutureWrapperNode(ClonedNode(points.clone()))),
+				source: CullNode::new(FutureWrapperNode(ClonedNode(source.clone()))),
+				name: FutureWrapperNode(ClonedNode(String::new())),
+				method: FutureWrapperNode(ClonedNode(method)),
+				radius: FutureWrapperNode(ClonedNode(radius)),
+			}
+			.eval(Footprint::default())
+		};
+
+		// Inside the triangle the values are blended, while outside of it the nearest point's are used, and past the radius the point keeps its own
+		let blended = transfer(TransferMethod::Barycentric, 150.).await;
+		assert_eq!(blended.point_domain.attribute("weight"), Some(&AttributeValues::F64(vec![5., 7.5, 10., -1.])));
+		let Some(AttributeValues::Color(tints)) = blended.point_domain.attribute("tint") else {
+			panic!("The colors should be transferred")
+		};
+		assert!((tints[0].r() - 0.5).abs() < 1e-6);
+		assert_eq!(tints[3], Color::TRANSPARENT);
+
+		let nearest = transfer(TransferMethod::Nearest, 0.).await;
+		assert_eq!(nearest.point_domain.attribute("weight"), Some(&AttributeValues::F64(vec![0., 0., 10., 10.])));
+	}
+
+	#[tokio::test]
+	async fn transfer_point_attributes_and_copy_to_points() {
+		let mut source = VectorData::empty();
+		source.point_domain.push(PointId::generate(), DVec2::ZERO);
+		source.point_domain.push(PointId::generate(), DVec2::new(100., 0.));
+		source.point_domain.set_attribute(SCALE_ATTRIBUTE, AttributeValues::F64(vec![1., 3.]));
+		source.point_domain.set_attribute(OFFSET_ATTRIBUTE, AttributeValues::DVec2(vec![DVec2::ZERO, DVec2::new(0., 10.)]));
+
+		let mut points = VectorData::empty();
+		for position in [DVec2::new(10., 5.), DVec2::new(90., -5.)] {
+			points.point_domain.push(PointId::generate(), position);
+		}
+		let points = TransferPointAttributesNode {
+			points: CullNode::new(FutureWrapperNode(ClonedNode(points))),
 			source: CullNode::new(FutureWrapperNode(ClonedNode(source))),
-			target: CullNode::new(FutureWrapperNode(ClonedNode(target))),
-			time: FutureWrapperNode(ClonedNode(0.5)),
-			start_index: FutureWrapperNode(ClonedNode(0)),
+			name: FutureWrapperNode(ClonedNode(String::new())),
+			method: FutureWrapperNode(ClonedNode(TransferMethod::Nearest)),
+			radius: FutureWrapperNode(ClonedNode(0.)),
+		}
+		.eval(Footprint::default())
+		.await;
+		assert_eq!(points.point_domain.attribute(SCALE_ATTRIBUTE), Some(&AttributeValues::F64(vec![1., 3.])));
+
+		let copies = CopyToPoints {
+			points: CullNode::new(FutureWrapperNode(ClonedNode(points))),
+			instance: CullNode::new(FutureWrapperNode(ClonedNode(VectorData::from_subpath(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE))))),
+			random_scale_min: FutureWrapperNode(ClonedNode(1.)),
+			random_scale_max: FutureWrapperNode(ClonedNode(1.)),
+			random_scale_bias: FutureWrapperNode(ClonedNode(0.)),
+			random_rotation_min: FutureWrapperNode(ClonedNode(0.)),
+			random_rotation_max: FutureWrapperNode(ClonedNode(0.)),
+			random_seed: FutureWrapperNode(ClonedNode(0)),
+			align_to_path: FutureWrapperNode(ClonedNode(false)),
+			variant_selection: FutureWrapperNode(ClonedNode(VariantSelection::Whole)),
 		}
 		.eval(Footprint::default())
 		.await;
+		let anchors = copies.region_bezier_paths().map(|(_, subpath)| subpath.manipulator_groups()[0].anchor).collect::<Vec<_>>();
+		assert_eq!(anchors, vec![DVec2::new(9., 4.), DVec2::new(87., 2.)]);
+	}
+
+	#[test]
+	fn css_clip_path() {
+		let mut square = VectorData::from_subpath(Subpath::new_rect(DVec2::new(10., 20.), DVec2::new(30., 40.)));
+		square.transform = DAffine2::from_translation(DVec2::new(0.75, 0.));
+		let clip_path = |vector_data: &VectorData, format: ClipPathFormat, precision: u32, normalize: bool| {
+			ToCssClipPathNode {
+				format: ClonedNode(format),
+				precision: ClonedNode(precision),
+				normalize: ClonedNode(normalize),
+			}
+			.eval(vector_data.clone())
+		};
+
+		assert_eq!(clip_path(&square, ClipPathFormat::Path, 2, false), r#"path("M 10.75 20 L 30.75 20 L 30.75 40 L 10.75 40 Z")"#);
+		assert_eq!(clip_path(&square, ClipPathFormat::Path, 0, true), r#"path("M 0 0 L 20 0 L 20 20 L 0 20 Z")"#);
+		assert_eq!(clip_path(&square, ClipPathFormat::Polygon, 0, false), "polygon(11px 20px, 31px 20px, 31px 40px, 11px 40px)");
+		assert_eq!(clip_path(&square, ClipPathFormat::Polygon, 1, true), "polygon(0% 0%, 100% 0%, 100% 100%, 0% 100%)");
+
+		// A square with a hole in it is joined into one outline, and the fill rule carried over
+		let mut frame = VectorData::from_subpaths([Subpath::new_rect(DVec2::ZERO, DVec2::splat(4.)), Subpath::new_rect(DVec2::ONE, DVec2::splat(3.))]);
+		frame.style.set_fill_rule(FillRule::EvenOdd);
 		assert_eq!(
-			&sample_points.point_domain.positions()[..4],
-			vec![DVec2::new(-25., -50.), DVec2::new(50., -25.), DVec2::new(25., 50.), DVec2::new(-50., 25.)]
+			clip_path(&frame, ClipPathFormat::Polygon, 0, true),
+			"polygon(evenodd, 0% 0%, 100% 0%, 100% 100%, 0% 100%, 0% 0%, 25% 25%, 75% 25%, 75% 75%, 25% 75%, 25% 25%, 0% 0%)"
+		);
+		assert!(clip_path(&VectorData::empty(), ClipPathFormat::Path, 2, false).is_empty());
+	}
+
+	#[test]
+	fn path_code() {
+		let mut shape = VectorData::from_subpath(Subpath::from_anchors([DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(10., 10.)], true));
+		shape.transform = DAffine2::from_translation(DVec2::new(0.5, 0.));
+		let code = |language: PathCodeLanguage| {
+			GeneratePathCodeNode {
+				language: ClonedNode(language),
+				precision: ClonedNode(1),
+			}
+			.eval(shape.clone())
+		};
+
+		assert_eq!(
+			code(PathCodeLanguage::Canvas),
+			"const path = new Path2D();\npath.moveTo(0.5, 0);\npath.lineTo(10.5, 0);\npath.lineTo(10.5, 10);\npath.closePath();\n"
+		);
+		assert_eq!(
+			code(PathCodeLanguage::SwiftUI),
+			"Path { path in\n    path.move(to: CGPoint(x: 0.5, y: 0))\n    path.addLine(to: CGPoint(x: 10.5, y: 0))\n    path.addLine(to: CGPoint(x: 10.5, y: 10))\n    path.closeSubpath()\n}\n"
+		);
+		assert_eq!(
+			code(PathCodeLanguage::Flutter),
+			"final path = Path()\n  ..moveTo(0.5, 0)\n  ..lineTo(10.5, 0)\n  ..lineTo(10.5, 10)\n  ..close();\n"
 		);
+
+		// Curves keep their handles
+		shape = VectorData::from_subpath(Subpath::from_bezier(&Bezier::from_cubic_dvec2(
+			DVec2::ZERO,
+			DVec2::new(0., 5.),
+			DVec2::new(10., 5.),
+			DVec2::new(10., 0.),
+		)));
+		assert_eq!(code(PathCodeLanguage::Flutter), "final path = Path()\n  ..moveTo(0, 0)\n  ..cubicTo(0, 5, 10, 5, 10, 0);\n");
+		assert!(code(PathCodeLanguage::SwiftUI).contains("path.addCurve(to: CGPoint(x: 10, y: 0), control1: CGPoint(x: 0, y: 5), control2: CGPoint(x: 10, y: 5))"));
+	}
+
+	#[tokio::test]
+	async fn pattern_fill() {
+		let tile = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(5.)));
+		let filled = SetPatternFillNode {
+			shape: CullNode::new(FutureWrapperNode(ClonedNode(VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::new(40., 15.)))))),
+			tile: CullNode::new(FutureWrapperNode(ClonedNode(tile.clone()))),
+			spacing: FutureWrapperNode(ClonedNode(DVec2::splat(10.))),
+			rotation: FutureWrapperNode(ClonedNode(0.)),
+			scale: FutureWrapperNode(ClonedNode(DVec2::ONE)),
+		}
+		.eval(Footprint::default())
+		.await;
+
+		let Fill::Pattern(pattern) = filled.style.fill() else {
+			panic!("Expected a pattern fill, found {:?}", filled.style.fill())
+		};
+		assert_eq!(pattern.tile, tile);
+		// Four columns and two rows of cells cover the shape
+		let offsets = pattern
+			.tile_transforms(filled.bounding_box().unwrap())
+			.iter()
+			.map(|transform| transform.translation)
+			.collect::<Vec<_>>();
+		assert_eq!(offsets.len(), 8);
+		assert_eq!(offsets[0], DVec2::ZERO);
+		assert_eq!(offsets[7], DVec2::new(30., 10.));
+
+		// Cells that would be too small to draw one by one are left out
+		assert!(Pattern::new(tile, DVec2::splat(0.01), DAffine2::IDENTITY).tile_transforms([DVec2::ZERO, DVec2::splat(100.)]).is_empty());
+	}
+
+	#[test]
+	fn image_fill_placement() {
+		let image = ImageFrame {
+			image: crate::raster::Image::new(4, 2, Color::RED),
+			..Default::default()
+		};
+		let bounds = [DVec2::ZERO, DVec2::new(10., 10.)];
+		let placement = |mode: ImageFillMode| ImageFill::new(image.clone(), mode, DAffine2::IDENTITY).placement(bounds).unwrap();
+
+		// Fitting centers the whole image, filling covers the box and crops the sides, and tiling keeps the size in pixels
+		assert_eq!(placement(ImageFillMode::Fit), DAffine2::from_scale_angle_translation(DVec2::new(10., 5.), 0., DVec2::new(0., 2.5)));
+		assert_eq!(placement(ImageFillMode::Fill), DAffine2::from_scale_angle_translation(DVec2::new(20., 10.), 0., DVec2::new(-5., 0.)));
+		assert_eq!(placement(ImageFillMode::Tile), DAffine2::from_scale(DVec2::new(4., 2.)));
+		assert_eq!(ImageFill::new(image, ImageFillMode::Tile, DAffine2::IDENTITY).image_transforms(bounds).len(), 3 * 5);
+
+		assert!(ImageFill::new(ImageFrame::empty(), ImageFillMode::Fill, DAffine2::IDENTITY).placement(bounds).is_none());
 	}
 }
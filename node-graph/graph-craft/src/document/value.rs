@@ -40,6 +40,7 @@ pub enum TaggedValue {
 	ImaginateMaskStartingFill(ImaginateMaskStartingFill),
 	ImaginateController(ImaginateController),
 	VectorData(graphene_core::vector::VectorData),
+	VectorInstances(graphene_core::vector::VectorInstances),
 	Fill(graphene_core::vector::style::Fill),
 	Stroke(graphene_core::vector::style::Stroke),
 	VecF64(Vec<f64>),
@@ -52,8 +53,10 @@ pub enum TaggedValue {
 	DomainWarpType(graphene_core::raster::DomainWarpType),
 	RelativeAbsolute(graphene_core::raster::RelativeAbsolute),
 	SelectiveColorChoice(graphene_core::raster::SelectiveColorChoice),
+	FillRule(graphene_core::vector::style::FillRule),
 	LineCap(graphene_core::vector::style::LineCap),
 	LineJoin(graphene_core::vector::style::LineJoin),
+	StrokeAlignment(graphene_core::vector::style::StrokeAlignment),
 	FillType(graphene_core::vector::style::FillType),
 	GradientType(graphene_core::vector::style::GradientType),
 	GradientPositions(Vec<(f64, graphene_core::Color)>),
@@ -61,6 +64,31 @@ pub enum TaggedValue {
 	OptionalColor(Option<graphene_core::raster::color::Color>),
 	ManipulatorGroupIds(Vec<graphene_core::uuid::ManipulatorGroupId>),
 	Font(graphene_core::text::Font),
+	MissingGlyph(graphene_core::text::MissingGlyph),
+	TextAlign(graphene_core::text::TextAlign),
+	Hyphenation(graphene_core::text::Hyphenation),
+	VariantSelection(graphene_core::vector::VariantSelection),
+	SubpathMeasure(graphene_core::vector::SubpathMeasure),
+	SubpathOrder(graphene_core::vector::SubpathOrder),
+	IndexOutOfRange(graphene_core::vector::IndexOutOfRange),
+	TransferMethod(graphene_core::vector::TransferMethod),
+	StyleLibrary(graphene_core::vector::style::StyleLibrary),
+	GradientInterpolation(graphene_core::vector::style::GradientInterpolation),
+	HueInterpolation(graphene_core::vector::style::HueInterpolation),
+	ColorSpace(graphene_core::raster::color::ColorSpace),
+	ColorHarmony(graphene_core::raster::palette::ColorHarmony),
+	ClipPathFormat(graphene_core::vector::ClipPathFormat),
+	PathCodeLanguage(graphene_core::vector::PathCodeLanguage),
+	MosaicCell(graphene_core::vector::MosaicCell),
+	IconKeyline(graphene_core::vector::IconKeyline),
+	GridType(graphene_core::vector::generator_nodes::GridType),
+	ImageFillMode(graphene_core::vector::style::ImageFillMode),
+	Symmetry(graphene_core::vector::Symmetry),
+	WallpaperGroup(graphene_core::vector::WallpaperGroup),
+	SvgViewBox(graphene_core::renderer::SvgViewBox),
+	HorizontalAlignment(graphene_core::layout::HorizontalAlignment),
+	VerticalAlignment(graphene_core::layout::VerticalAlignment),
+	Distribution(graphene_core::layout::Distribution),
 	BrushStrokes(Vec<graphene_core::vector::brush_stroke::BrushStroke>),
 	BrushCache(BrushCache),
 	Segments(Vec<graphene_core::raster::ImageFrame<Color>>),
@@ -107,6 +135,7 @@ impl Hash for TaggedValue {
 			Self::ImaginateController(x) => x.hash(state),
 			Self::ImageFrame(x) => x.hash(state),
 			Self::VectorData(x) => x.hash(state),
+			Self::VectorInstances(x) => x.hash(state),
 			Self::Fill(x) => x.hash(state),
 			Self::Stroke(x) => x.hash(state),
 			Self::VecF64(x) => x.iter().for_each(|val| val.to_bits().hash(state)),
@@ -119,8 +148,10 @@ impl Hash for TaggedValue {
 			Self::DomainWarpType(x) => x.hash(state),
 			Self::RelativeAbsolute(x) => x.hash(state),
 			Self::SelectiveColorChoice(x) => x.hash(state),
+			Self::FillRule(x) => x.hash(state),
 			Self::LineCap(x) => x.hash(state),
 			Self::LineJoin(x) => x.hash(state),
+			Self::StrokeAlignment(x) => x.hash(state),
 			Self::FillType(x) => x.hash(state),
 			Self::GradientType(x) => x.hash(state),
 			Self::GradientPositions(x) => {
@@ -134,6 +165,31 @@ impl Hash for TaggedValue {
 			Self::OptionalColor(x) => x.hash(state),
 			Self::ManipulatorGroupIds(x) => x.hash(state),
 			Self::Font(x) => x.hash(state),
+			Self::MissingGlyph(x) => x.hash(state),
+			Self::TextAlign(x) => x.hash(state),
+			Self::Hyphenation(x) => x.hash(state),
+			Self::VariantSelection(x) => x.hash(state),
+			Self::SubpathMeasure(x) => x.hash(state),
+			Self::SubpathOrder(x) => x.hash(state),
+			Self::IndexOutOfRange(x) => x.hash(state),
+			Self::TransferMethod(x) => x.hash(state),
+			Self::StyleLibrary(x) => x.hash(state),
+			Self::GradientInterpolation(x) => x.hash(state),
+			Self::HueInterpolation(x) => x.hash(state),
+			Self::ColorSpace(x) => x.hash(state),
+			Self::ColorHarmony(x) => x.hash(state),
+			Self::ClipPathFormat(x) => x.hash(state),
+			Self::PathCodeLanguage(x) => x.hash(state),
+			Self::MosaicCell(x) => x.hash(state),
+			Self::IconKeyline(x) => x.hash(state),
+			Self::GridType(x) => x.hash(state),
+			Self::ImageFillMode(x) => x.hash(state),
+			Self::Symmetry(x) => x.hash(state),
+			Self::WallpaperGroup(x) => x.hash(state),
+			Self::SvgViewBox(x) => x.hash(state),
+			Self::HorizontalAlignment(x) => x.hash(state),
+			Self::VerticalAlignment(x) => x.hash(state),
+			Self::Distribution(x) => x.hash(state),
 			Self::BrushStrokes(x) => x.hash(state),
 			Self::BrushCache(x) => x.hash(state),
 			Self::Segments(x) => {
@@ -181,6 +237,7 @@ impl<'a> TaggedValue {
 			TaggedValue::ImaginateMaskStartingFill(x) => Box::new(x),
 			TaggedValue::ImaginateController(x) => Box::new(x),
 			TaggedValue::VectorData(x) => Box::new(x),
+			TaggedValue::VectorInstances(x) => Box::new(x),
 			TaggedValue::Fill(x) => Box::new(x),
 			TaggedValue::Stroke(x) => Box::new(x),
 			TaggedValue::VecF64(x) => Box::new(x),
@@ -193,8 +250,10 @@ impl<'a> TaggedValue {
 			TaggedValue::DomainWarpType(x) => Box::new(x),
 			TaggedValue::RelativeAbsolute(x) => Box::new(x),
 			TaggedValue::SelectiveColorChoice(x) => Box::new(x),
+			TaggedValue::FillRule(x) => Box::new(x),
 			TaggedValue::LineCap(x) => Box::new(x),
 			TaggedValue::LineJoin(x) => Box::new(x),
+			TaggedValue::StrokeAlignment(x) => Box::new(x),
 			TaggedValue::FillType(x) => Box::new(x),
 			TaggedValue::GradientType(x) => Box::new(x),
 			TaggedValue::GradientPositions(x) => Box::new(x),
@@ -202,6 +261,31 @@ impl<'a> TaggedValue {
 			TaggedValue::OptionalColor(x) => Box::new(x),
 			TaggedValue::ManipulatorGroupIds(x) => Box::new(x),
 			TaggedValue::Font(x) => Box::new(x),
+			TaggedValue::MissingGlyph(x) => Box::new(x),
+			TaggedValue::TextAlign(x) => Box::new(x),
+			TaggedValue::Hyphenation(x) => Box::new(x),
+			TaggedValue::VariantSelection(x) => Box::new(x),
+			TaggedValue::SubpathMeasure(x) => Box::new(x),
+			TaggedValue::SubpathOrder(x) => Box::new(x),
+			TaggedValue::IndexOutOfRange(x) => Box::new(x),
+			TaggedValue::TransferMethod(x) => Box::new(x),
+			TaggedValue::StyleLibrary(x) => Box::new(x),
+			TaggedValue::GradientInterpolation(x) => Box::new(x),
+			TaggedValue::HueInterpolation(x) => Box::new(x),
+			TaggedValue::ColorSpace(x) => Box::new(x),
+			TaggedValue::ColorHarmony(x) => Box::new(x),
+			TaggedValue::ClipPathFormat(x) => Box::new(x),
+			TaggedValue::PathCodeLanguage(x) => Box::new(x),
+			TaggedValue::MosaicCell(x) => Box::new(x),
+			TaggedValue::IconKeyline(x) => Box::new(x),
+			TaggedValue::GridType(x) => Box::new(x),
+			TaggedValue::ImageFillMode(x) => Box::new(x),
+			TaggedValue::Symmetry(x) => Box::new(x),
+			TaggedValue::WallpaperGroup(x) => Box::new(x),
+			TaggedValue::SvgViewBox(x) => Box::new(x),
+			TaggedValue::HorizontalAlignment(x) => Box::new(x),
+			TaggedValue::VerticalAlignment(x) => Box::new(x),
+			TaggedValue::Distribution(x) => Box::new(x),
 			TaggedValue::BrushStrokes(x) => Box::new(x),
 			TaggedValue::BrushCache(x) => Box::new(x),
 			TaggedValue::Segments(x) => Box::new(x),
@@ -257,6 +341,7 @@ impl<'a> TaggedValue {
 			TaggedValue::DAffine2(_) => concrete!(DAffine2),
 			TaggedValue::LuminanceCalculation(_) => concrete!(LuminanceCalculation),
 			TaggedValue::VectorData(_) => concrete!(graphene_core::vector::VectorData),
+			TaggedValue::VectorInstances(_) => concrete!(graphene_core::vector::VectorInstances),
 			TaggedValue::Fill(_) => concrete!(graphene_core::vector::style::Fill),
 			TaggedValue::Stroke(_) => concrete!(graphene_core::vector::style::Stroke),
 			TaggedValue::VecF64(_) => concrete!(Vec<f64>),
@@ -269,8 +354,10 @@ impl<'a> TaggedValue {
 			TaggedValue::DomainWarpType(_) => concrete!(graphene_core::raster::DomainWarpType),
 			TaggedValue::RelativeAbsolute(_) => concrete!(graphene_core::raster::RelativeAbsolute),
 			TaggedValue::SelectiveColorChoice(_) => concrete!(graphene_core::raster::SelectiveColorChoice),
+			TaggedValue::FillRule(_) => concrete!(graphene_core::vector::style::FillRule),
 			TaggedValue::LineCap(_) => concrete!(graphene_core::vector::style::LineCap),
 			TaggedValue::LineJoin(_) => concrete!(graphene_core::vector::style::LineJoin),
+			TaggedValue::StrokeAlignment(_) => concrete!(graphene_core::vector::style::StrokeAlignment),
 			TaggedValue::FillType(_) => concrete!(graphene_core::vector::style::FillType),
 			TaggedValue::GradientType(_) => concrete!(graphene_core::vector::style::GradientType),
 			TaggedValue::GradientPositions(_) => concrete!(Vec<(f64, graphene_core::Color)>),
@@ -278,6 +365,31 @@ impl<'a> TaggedValue {
 			TaggedValue::OptionalColor(_) => concrete!(Option<graphene_core::Color>),
 			TaggedValue::ManipulatorGroupIds(_) => concrete!(Vec<graphene_core::uuid::ManipulatorGroupId>),
 			TaggedValue::Font(_) => concrete!(graphene_core::text::Font),
+			TaggedValue::MissingGlyph(_) => concrete!(graphene_core::text::MissingGlyph),
+			TaggedValue::TextAlign(_) => concrete!(graphene_core::text::TextAlign),
+			TaggedValue::Hyphenation(_) => concrete!(graphene_core::text::Hyphenation),
+			TaggedValue::VariantSelection(_) => concrete!(graphene_core::vector::VariantSelection),
+			TaggedValue::SubpathMeasure(_) => concrete!(graphene_core::vector::SubpathMeasure),
+			TaggedValue::SubpathOrder(_) => concrete!(graphene_core::vector::SubpathOrder),
+			TaggedValue::IndexOutOfRange(_) => concrete!(graphene_core::vector::IndexOutOfRange),
+			TaggedValue::TransferMethod(_) => concrete!(graphene_core::vector::TransferMethod),
+			TaggedValue::StyleLibrary(_) => concrete!(graphene_core::vector::style::StyleLibrary),
+			TaggedValue::GradientInterpolation(_) => concrete!(graphene_core::vector::style::GradientInterpolation),
+			TaggedValue::HueInterpolation(_) => concrete!(graphene_core::vector::style::HueInterpolation),
+			TaggedValue::ColorSpace(_) => concrete!(graphene_core::raster::color::ColorSpace),
+			TaggedValue::ColorHarmony(_) => concrete!(graphene_core::raster::palette::ColorHarmony),
+			TaggedValue::ClipPathFormat(_) => concrete!(graphene_core::vector::ClipPathFormat),
+			TaggedValue::PathCodeLanguage(_) => concrete!(graphene_core::vector::PathCodeLanguage),
+			TaggedValue::MosaicCell(_) => concrete!(graphene_core::vector::MosaicCell),
+			TaggedValue::IconKeyline(_) => concrete!(graphene_core::vector::IconKeyline),
+			TaggedValue::GridType(_) => concrete!(graphene_core::vector::generator_nodes::GridType),
+			TaggedValue::ImageFillMode(_) => concrete!(graphene_core::vector::style::ImageFillMode),
+			TaggedValue::Symmetry(_) => concrete!(graphene_core::vector::Symmetry),
+			TaggedValue::WallpaperGroup(_) => concrete!(graphene_core::vector::WallpaperGroup),
+			TaggedValue::SvgViewBox(_) => concrete!(graphene_core::renderer::SvgViewBox),
+			TaggedValue::HorizontalAlignment(_) => concrete!(graphene_core::layout::HorizontalAlignment),
+			TaggedValue::VerticalAlignment(_) => concrete!(graphene_core::layout::VerticalAlignment),
+			TaggedValue::Distribution(_) => concrete!(graphene_core::layout::Distribution),
 			TaggedValue::BrushStrokes(_) => concrete!(Vec<graphene_core::vector::brush_stroke::BrushStroke>),
 			TaggedValue::BrushCache(_) => concrete!(BrushCache),
 			TaggedValue::Segments(_) => concrete!(graphene_core::raster::IndexNode<Vec<graphene_core::raster::ImageFrame<Color>>>),
@@ -321,6 +433,7 @@ impl<'a> TaggedValue {
 			x if x == TypeId::of::<DAffine2>() => Ok(TaggedValue::DAffine2(*downcast(input).unwrap())),
 			x if x == TypeId::of::<LuminanceCalculation>() => Ok(TaggedValue::LuminanceCalculation(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::VectorData>() => Ok(TaggedValue::VectorData(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::VectorInstances>() => Ok(TaggedValue::VectorInstances(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::Fill>() => Ok(TaggedValue::Fill(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::Stroke>() => Ok(TaggedValue::Stroke(*downcast(input).unwrap())),
 			x if x == TypeId::of::<Vec<f64>>() => Ok(TaggedValue::VecF64(*downcast(input).unwrap())),
@@ -333,8 +446,10 @@ impl<'a> TaggedValue {
 			x if x == TypeId::of::<graphene_core::raster::DomainWarpType>() => Ok(TaggedValue::DomainWarpType(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::raster::RelativeAbsolute>() => Ok(TaggedValue::RelativeAbsolute(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::raster::SelectiveColorChoice>() => Ok(TaggedValue::SelectiveColorChoice(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::style::FillRule>() => Ok(TaggedValue::FillRule(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::LineCap>() => Ok(TaggedValue::LineCap(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::LineJoin>() => Ok(TaggedValue::LineJoin(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::style::StrokeAlignment>() => Ok(TaggedValue::StrokeAlignment(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::FillType>() => Ok(TaggedValue::FillType(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::GradientType>() => Ok(TaggedValue::GradientType(*downcast(input).unwrap())),
 			x if x == TypeId::of::<Vec<(f64, graphene_core::Color)>>() => Ok(TaggedValue::GradientPositions(*downcast(input).unwrap())),
@@ -342,6 +457,31 @@ impl<'a> TaggedValue {
 			x if x == TypeId::of::<Option<graphene_core::Color>>() => Ok(TaggedValue::OptionalColor(*downcast(input).unwrap())),
 			x if x == TypeId::of::<Vec<graphene_core::uuid::ManipulatorGroupId>>() => Ok(TaggedValue::ManipulatorGroupIds(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::text::Font>() => Ok(TaggedValue::Font(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::text::MissingGlyph>() => Ok(TaggedValue::MissingGlyph(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::text::TextAlign>() => Ok(TaggedValue::TextAlign(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::text::Hyphenation>() => Ok(TaggedValue::Hyphenation(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::VariantSelection>() => Ok(TaggedValue::VariantSelection(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::SubpathMeasure>() => Ok(TaggedValue::SubpathMeasure(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::SubpathOrder>() => Ok(TaggedValue::SubpathOrder(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::IndexOutOfRange>() => Ok(TaggedValue::IndexOutOfRange(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::TransferMethod>() => Ok(TaggedValue::TransferMethod(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::style::StyleLibrary>() => Ok(TaggedValue::StyleLibrary(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::style::GradientInterpolation>() => Ok(TaggedValue::GradientInterpolation(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::style::HueInterpolation>() => Ok(TaggedValue::HueInterpolation(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::color::ColorSpace>() => Ok(TaggedValue::ColorSpace(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::palette::ColorHarmony>() => Ok(TaggedValue::ColorHarmony(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::ClipPathFormat>() => Ok(TaggedValue::ClipPathFormat(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::PathCodeLanguage>() => Ok(TaggedValue::PathCodeLanguage(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::MosaicCell>() => Ok(TaggedValue::MosaicCell(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::IconKeyline>() => Ok(TaggedValue::IconKeyline(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::generator_nodes::GridType>() => Ok(TaggedValue::GridType(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::style::ImageFillMode>() => Ok(TaggedValue::ImageFillMode(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::Symmetry>() => Ok(TaggedValue::Symmetry(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::WallpaperGroup>() => Ok(TaggedValue::WallpaperGroup(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::renderer::SvgViewBox>() => Ok(TaggedValue::SvgViewBox(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::layout::HorizontalAlignment>() => Ok(TaggedValue::HorizontalAlignment(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::layout::VerticalAlignment>() => Ok(TaggedValue::VerticalAlignment(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::layout::Distribution>() => Ok(TaggedValue::Distribution(*downcast(input).unwrap())),
 			x if x == TypeId::of::<Vec<graphene_core::vector::brush_stroke::BrushStroke>>() => Ok(TaggedValue::BrushStrokes(*downcast(input).unwrap())),
 			x if x == TypeId::of::<BrushCache>() => Ok(TaggedValue::BrushCache(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::raster::IndexNode<Vec<graphene_core::raster::ImageFrame<Color>>>>() => Ok(TaggedValue::Segments(*downcast(input).unwrap())),
@@ -383,5 +523,21 @@ impl UpcastNode {
 pub enum RenderOutput {
 	CanvasFrame(graphene_core::SurfaceFrame),
 	Svg(String),
+	/// The layers of the artwork, ordered from bottom to top, for export to layered file formats.
+	SvgLayers(Vec<SvgLayer>),
+	Eps(String),
+	Emf(Vec<u8>),
+	Pdf(Vec<u8>),
+	/// A [`graphene_core::renderer::Scene`] serialized as JSON.
+	Json(String),
 	Image(Vec<u8>),
 }
+
+/// A standalone SVG document of a single layer of the artwork, along with the opacity and blend mode it is composited with.
+#[derive(Debug, Clone, PartialEq, dyn_any::DynAny, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SvgLayer {
+	pub name: String,
+	pub svg: String,
+	pub alpha_blending: graphene_core::AlphaBlending,
+}
@@ -1,7 +1,7 @@
 use crate::document::{value, InlineRust};
 use crate::document::{NodeId, OriginalLocation};
 
-use dyn_any::DynAny;
+use dyn_any::{DynAny, StaticType};
 use graphene_core::*;
 
 #[cfg(feature = "serde")]
@@ -25,6 +25,24 @@ pub type TypeErasedPinned<'n> = Pin<Box<TypeErasedNode<'n>>>;
 
 pub type SharedNodeContainer = std::rc::Rc<NodeContainer>;
 
+/// The output of a node that failed, which is passed along in place of its value so the failure can be reported without panicking, even on targets where panics can't be caught.
+#[derive(Clone, Debug, PartialEq, DynAny)]
+pub struct NodeError(pub String);
+
+impl core::fmt::Display for NodeError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+/// Reads the type erased output of a node as its value, or as its error if the node failed.
+pub fn node_result(output: Any<'_>) -> Result<Any<'_>, NodeError> {
+	if DynAny::type_id(output.as_ref()) == std::any::TypeId::of::<NodeError>() {
+		return Err(*dyn_any::downcast::<NodeError>(output).expect("The output should be a node error"));
+	}
+	Ok(output)
+}
+
 pub type NodeConstructor = fn(Vec<SharedNodeContainer>) -> DynFuture<'static, TypeErasedBox<'static>>;
 
 #[derive(Clone)]
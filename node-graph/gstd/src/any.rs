@@ -1,6 +1,6 @@
 use dyn_any::StaticType;
+use graph_craft::proto::{node_result, DynFuture, FutureAny, NodeError, SharedNodeContainer};
 pub use graph_craft::proto::{Any, NodeContainer, TypeErasedBox, TypeErasedNode};
-use graph_craft::proto::{DynFuture, FutureAny, SharedNodeContainer};
 use graphene_core::NodeIO;
 pub use graphene_core::{generic, ops, Node};
 use std::marker::PhantomData;
@@ -19,7 +19,12 @@ where
 	#[inline]
 	fn eval(&'input self, input: Any<'input>) -> Self::Output {
 		let node_name = core::any::type_name::<N>();
-		let input: Box<_I> = dyn_any::downcast(input).unwrap_or_else(|e| panic!("DynAnyNode Input, {0} in:\n{1}", e, node_name));
+		// A failure upstream is passed along, and an input of the wrong type is reported as this node failing
+		let input = node_result(input).and_then(|input| dyn_any::downcast::<_I>(input).map_err(|e| NodeError(format!("DynAnyNode Input, {e} in:\n{node_name}"))));
+		let input = match input {
+			Ok(input) => input,
+			Err(error) => return Box::pin(async move { Box::new(error) as Any<'input> }),
+		};
 		let output = async move {
 			let result = self.node.eval(*input).await;
 			Box::new(result) as Any<'input>
@@ -0,0 +1,187 @@
+//! Packing of the elements of a group into a sprite atlas, a single texture holding every sprite along with metadata naming where each one is, for game engines and other asset pipelines.
+
+use crate::rasterize::rasterize_svg;
+use graphene_core::raster::{Image, ImageFrame};
+use graphene_core::renderer::{element_kind, GraphicElementRendered, ImageRenderMode, RenderParams, RenderSvgSegmentList, SvgRender};
+use graphene_core::vector::style::ViewMode;
+use graphene_core::{Color, GraphicElement, GraphicGroup, Node};
+
+use glam::{DAffine2, DVec2, UVec2};
+
+/// Where a sprite was placed in the atlas.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AtlasSprite {
+	pub name: String,
+	/// The top left corner of the sprite in pixels.
+	pub position: UVec2,
+	/// The size of the sprite in pixels.
+	pub size: UVec2,
+}
+
+impl AtlasSprite {
+	/// The rectangle covered by the sprite as `[left, top, right, bottom]`, from 0 to 1 across the atlas of the given size.
+	pub fn uv_rect(&self, atlas_size: UVec2) -> [f64; 4] {
+		let [min, max] = [self.position, self.position + self.size].map(|corner| corner.as_dvec2() / atlas_size.max(UVec2::ONE).as_dvec2());
+		[min.x, min.y, max.x, max.y]
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AtlasPackNode<Names, Scale, Padding, MaxWidth, PowerOfTwo> {
+	names: Names,
+	scale: Scale,
+	padding: Padding,
+	max_width: MaxWidth,
+	power_of_two: PowerOfTwo,
+}
+
+/// Rasterizes each element of the group and packs them into one atlas image, returned along with JSON metadata giving the name, pixel rectangle, and UV rectangle of every sprite.
+///
+/// Sprites are named in order from the comma separated list of names, with the rest named by their kind and number like "Vector 2".
+/// Images keep their own pixels, while other elements are drawn at their size in the document multiplied by the scale, which needs the `resvg` feature.
+#[node_macro::node_fn(AtlasPackNode)]
+fn atlas_pack_node(graphic_group: GraphicGroup, names: String, scale: f64, padding: u32, max_width: u32, power_of_two: bool) -> (ImageFrame<Color>, String) {
+	let mut names = names.split(',').map(str::trim);
+	let sprites = graphic_group
+		.iter()
+		.enumerate()
+		.filter_map(|(index, element)| {
+			let name = names
+				.next()
+				.filter(|name| !name.is_empty())
+				.map_or_else(|| format!("{} {}", element_kind(element), index + 1), ToString::to_string);
+			let Some(image) = rasterize(element, scale) else {
+				warn!("The sprite \"{name}\" couldn't be rasterized so it's left out of the atlas");
+				return None;
+			};
+			Some((name, image))
+		})
+		.collect::<Vec<_>>();
+
+	let (atlas, sprites) = atlas_pack(sprites, padding, max_width, power_of_two);
+	let metadata = atlas_metadata(&sprites, UVec2::new(atlas.image.width, atlas.image.height));
+	(atlas, metadata)
+}
+
+/// Packs the named images into rows, from the tallest to the shortest, starting a new row whenever the next image would reach past the maximum width.
+/// Each image is kept apart from its neighbors and the edges of the atlas by the padding, so sampling one sprite doesn't bleed into another.
+pub fn atlas_pack(sprites: Vec<(String, Image<Color>)>, padding: u32, max_width: u32, power_of_two: bool) -> (ImageFrame<Color>, Vec<AtlasSprite>) {
+	let mut order = (0..sprites.len()).collect::<Vec<_>>();
+	order.sort_by_key(|&index| core::cmp::Reverse(sprites[index].1.height));
+
+	let mut placed = vec![UVec2::ZERO; sprites.len()];
+	let mut cursor = UVec2::splat(padding);
+	let (mut row_height, mut size) = (0, UVec2::ZERO);
+	for index in order {
+		let image = &sprites[index].1;
+		if cursor.x > padding && cursor.x + image.width + padding > max_width {
+			cursor = UVec2::new(padding, cursor.y + row_height + padding);
+			row_height = 0;
+		}
+		placed[index] = cursor;
+		row_height = row_height.max(image.height);
+		cursor.x += image.width + padding;
+		size = size.max(UVec2::new(cursor.x, cursor.y + row_height + padding));
+	}
+	if power_of_two {
+		size = UVec2::new(size.x.next_power_of_two(), size.y.next_power_of_two());
+	}
+
+	let mut atlas = Image::new(size.x, size.y, Color::TRANSPARENT);
+	let sprites = sprites
+		.into_iter()
+		.zip(placed)
+		.map(|((name, image), position)| {
+			for y in 0..image.height {
+				let row = ((position.y + y) * size.x + position.x) as usize;
+				let source = (y * image.width) as usize;
+				atlas.data[row..row + image.width as usize].copy_from_slice(&image.data[source..source + image.width as usize]);
+			}
+			let size = UVec2::new(image.width, image.height);
+			AtlasSprite { name, position, size }
+		})
+		.collect();
+
+	let atlas = ImageFrame {
+		image: atlas,
+		transform: DAffine2::from_scale(size.as_dvec2()),
+		..Default::default()
+	};
+	(atlas, sprites)
+}
+
+/// Describes the sprites of an atlas of the given size as JSON.
+pub fn atlas_metadata(sprites: &[AtlasSprite], atlas_size: UVec2) -> String {
+	let sprites = sprites
+		.iter()
+		.map(|sprite| {
+			serde_json::json!({
+				"name": sprite.name,
+				"x": sprite.position.x,
+				"y": sprite.position.y,
+				"width": sprite.size.x,
+				"height": sprite.size.y,
+				"uv": sprite.uv_rect(atlas_size),
+			})
+		})
+		.collect::<Vec<_>>();
+	serde_json::json!({ "width": atlas_size.x, "height": atlas_size.y, "sprites": sprites }).to_string()
+}
+
+/// Draws the element into an image snugly framing its bounds at the scale, or takes the pixels of an image as they are.
+fn rasterize(element: &GraphicElement, scale: f64) -> Option<Image<Color>> {
+	if let GraphicElement::ImageFrame(image_frame) = element {
+		return Some(image_frame.image.clone());
+	}
+
+	let [min, max] = element.bounding_box(DAffine2::from_scale(DVec2::splat(scale)))?;
+	let size = (max - min).ceil().max(DVec2::ONE).as_uvec2();
+	let mut render = SvgRender::new();
+	element.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, true, true));
+	render.wrap_with_transform(DAffine2::from_translation(-min) * DAffine2::from_scale(DVec2::splat(scale)), Some(size.as_dvec2()));
+	rasterize_svg(&render.svg.to_svg_string(), size)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use graphene_core::value::ClonedNode;
+
+	fn image(width: u32, height: u32, color: Color) -> ImageFrame<Color> {
+		ImageFrame {
+			image: Image::new(width, height, color),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn atlas_pack_images() {
+		let mut group = GraphicGroup::EMPTY;
+		group.extend([image(4, 2, Color::RED).into(), image(3, 5, Color::GREEN).into(), image(6, 3, Color::BLUE).into()]);
+		let node = AtlasPackNode {
+			names: ClonedNode("small, tall".to_string()),
+			scale: ClonedNode(1.),
+			padding: ClonedNode(1),
+			max_width: ClonedNode(12),
+			power_of_two: ClonedNode(false),
+		};
+		let (atlas, metadata) = node.eval(group);
+
+		// The tall image and the wide one share the first row, and the small one is pushed onto the second
+		assert_eq!((atlas.image.width, atlas.image.height), (12, 10));
+		let pixel = |x: usize, y: usize| atlas.image.data[y * 12 + x];
+		assert_eq!(pixel(1, 1), Color::GREEN);
+		assert_eq!(pixel(5, 1), Color::BLUE);
+		assert_eq!(pixel(1, 7), Color::RED);
+		assert_eq!(pixel(0, 0), Color::TRANSPARENT);
+
+		let metadata: serde_json::Value = serde_json::from_str(&metadata).unwrap();
+		let sprites = metadata["sprites"].as_array().unwrap();
+		assert_eq!(sprites.iter().map(|sprite| sprite["name"].as_str().unwrap()).collect::<Vec<_>>(), ["small", "tall", "Image 3"]);
+		assert_eq!((sprites[0]["x"].as_u64(), sprites[0]["y"].as_u64()), (Some(1), Some(7)));
+		assert_eq!(sprites[1]["uv"], serde_json::json!([1. / 12., 0.1, 4. / 12., 0.6]));
+
+		let (atlas, _) = atlas_pack(vec![("sprite".to_string(), Image::new(5, 3, Color::BLACK))], 0, 100, true);
+		assert_eq!((atlas.image.width, atlas.image.height), (8, 4));
+	}
+}
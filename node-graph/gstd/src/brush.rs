@@ -9,10 +9,14 @@ use graphene_core::transform::{Transform, TransformMut};
 use graphene_core::value::{ClonedNode, CopiedNode, OnceCellNode, ValueNode};
 use graphene_core::vector::brush_stroke::{BrushStroke, BrushStyle};
 use graphene_core::vector::VectorData;
-use graphene_core::Node;
+use graphene_core::{AlphaBlending, GraphicGroup, Node};
 use node_macro::node_fn;
 
+use bezier_rs::TValue;
 use glam::{DAffine2, DVec2};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -393,6 +397,139 @@ async fn brush(image: ImageFrame<Color>, bounds: ImageFrame<Color>, strokes: Vec
 	actual_image
 }
 
+pub struct BrushAlongPathNode<Tip, Size, Spacing, Scatter, RotationJitter, Seed, PressureAttribute, Rasterize> {
+	tip: Tip,
+	size: Size,
+	spacing: Spacing,
+	scatter: Scatter,
+	rotation_jitter: RotationJitter,
+	seed: Seed,
+	pressure_attribute: PressureAttribute,
+	rasterize: Rasterize,
+}
+
+/// Paints the path by stamping the brush tip image along it, bridging vector paths with painterly raster strokes. Without a tip, a soft round one
+/// is used in the color of the path's stroke.
+///
+/// The tip is scaled so its longer side is the size, and turned to follow the direction of the path. Stamps are placed the spacing apart, as a
+/// percentage of their size, and are pushed off to either side of the path by up to the scatter (also a percentage of their size) and turned by up
+/// to the rotation jitter in degrees, picked randomly by the seed. The named per-point attribute, when the path has it, is read as the pen pressure
+/// blended between the anchors, which scales the stamps.
+///
+/// The result is a group holding either the stamps composited into a single image, when rasterizing, or each stamp as its own image.
+#[node_fn(BrushAlongPathNode)]
+fn brush_along_path(
+	vector_data: VectorData,
+	tip: ImageFrame<Color>,
+	size: f64,
+	spacing: f64,
+	scatter: f64,
+	rotation_jitter: f64,
+	seed: u32,
+	pressure_attribute: String,
+	rasterize: bool,
+) -> GraphicGroup {
+	// Number of line segments each curved segment is flattened into when measuring the distance along it
+	const FLATTENING_STEPS: usize = 32;
+	// Most stamps placed along the path, which keeps a tiny spacing from generating an endless number of them
+	const MAX_STAMPS: usize = 100_000;
+
+	let mut result = GraphicGroup::EMPTY;
+	if size <= 0. {
+		return result;
+	}
+
+	let tip = if tip.image.width == 0 || tip.image.height == 0 {
+		let color = vector_data.style.stroke().and_then(|stroke| stroke.color()).unwrap_or(Color::BLACK);
+		create_brush_texture(&BrushStyle {
+			color,
+			diameter: size,
+			..Default::default()
+		})
+	} else {
+		tip.image
+	};
+	let tip_size = DVec2::new(tip.width as f64, tip.height as f64);
+	let tip_size = tip_size / tip_size.max_element() * size;
+
+	let indices = vector_data.point_domain.ids().iter().enumerate().map(|(index, &id)| (id, index)).collect::<HashMap<_, _>>();
+	let pressures = vector_data.point_domain.attribute(&pressure_attribute);
+	let pressure_at = |id| pressures.zip(indices.get(&id)).and_then(|(values, &index)| values.scalar(index)).unwrap_or(1.).max(0.);
+
+	let mut rng = ChaCha8Rng::seed_from_u64(seed as u64);
+	let mut stamps = Vec::new();
+	'subpaths: for subpath in vector_data.stroke_bezier_paths() {
+		let groups = subpath.manipulator_groups();
+		// Distance along the subpath, and where the next stamp goes, which starts with a stamp at its beginning
+		let mut along = 0.;
+		let mut next_stamp = 0.;
+
+		for (index, bezier) in subpath.iter().enumerate() {
+			let bezier = bezier.apply_transformation(|point| vector_data.transform.transform_point2(point));
+			let [start_pressure, end_pressure] = [index, (index + 1) % groups.len()].map(|index| pressure_at(groups[index].id));
+			let points = (0..=FLATTENING_STEPS)
+				.map(|step| bezier.evaluate(TValue::Parametric(step as f64 / FLATTENING_STEPS as f64)))
+				.collect::<Vec<_>>();
+			let length = points.windows(2).map(|pair| pair[0].distance(pair[1])).sum::<f64>();
+			let segment_start = along;
+
+			for pair in points.windows(2) {
+				let [start, end] = [pair[0], pair[1]];
+				let piece_length = start.distance(end);
+				if piece_length <= 0. {
+					continue;
+				}
+				while next_stamp <= along + piece_length {
+					if stamps.len() >= MAX_STAMPS {
+						break 'subpaths;
+					}
+					let position = start.lerp(end, (next_stamp - along) / piece_length);
+					let fraction = if length > 0. { (next_stamp - segment_start) / length } else { 0. };
+					let pressure = start_pressure + (end_pressure - start_pressure) * fraction;
+					let direction = (end - start) / piece_length;
+
+					let offset = direction.perp() * scatter / 100. * size * pressure * rng.gen_range(-1_f64..=1.);
+					let angle = direction.to_angle() + (rotation_jitter * rng.gen_range(-1_f64..=1.)).to_radians();
+					let scale = tip_size * pressure;
+					if scale.min_element() > 0. {
+						stamps.push(DAffine2::from_translation(position + offset) * DAffine2::from_angle(angle) * DAffine2::from_scale(scale) * DAffine2::from_translation(DVec2::splat(-0.5)));
+					}
+					next_stamp += (spacing / 100. * size * pressure).max(size / 100.);
+				}
+				along += piece_length;
+			}
+		}
+	}
+
+	let stamp = |transform| ImageFrame {
+		image: tip.clone(),
+		transform,
+		alpha_blending: AlphaBlending::default(),
+	};
+	if !rasterize {
+		result.extend(stamps.into_iter().map(|transform| stamp(transform).into()));
+		return result;
+	}
+
+	let Some(mut bounds) = stamps
+		.iter()
+		.map(|&transform| Bbox::unit().affine_transform(transform).to_axis_aligned_bbox())
+		.reduce(|a, b| a.union(&b))
+	else {
+		return result;
+	};
+	bounds.start = bounds.start.floor();
+	bounds.end = bounds.end.ceil();
+
+	let mut image = EmptyImageNode::new(CopiedNode::new(Color::TRANSPARENT)).eval(bounds.to_transform());
+	for transform in stamps {
+		image = blend_image_closure(stamp(transform), image, |foreground, background| blend_colors(foreground, background, BlendMode::Normal, 1.));
+	}
+	image.alpha_blending = vector_data.alpha_blending;
+	result.push(image.into());
+	result
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -455,4 +592,50 @@ mod test {
 		assert_eq!(final_image.image.width, 30);
 		drop(final_image);
 	}
+
+	#[test]
+	fn brush_along_path() {
+		let mut line = VectorData::from_subpath(bezier_rs::Subpath::new_line(DVec2::ZERO, DVec2::new(100., 0.)));
+		let brush = |line: &VectorData, rasterize: bool| {
+			BrushAlongPathNode::new(
+				ClonedNode::new(ImageFrame::empty()),
+				ClonedNode::new(10.),
+				ClonedNode::new(50.),
+				ClonedNode::new(0.),
+				ClonedNode::new(0.),
+				ClonedNode::new(0_u32),
+				ClonedNode::new("pressure".to_string()),
+				ClonedNode::new(rasterize),
+			)
+			.eval(line.clone())
+		};
+		let stamp_sizes = |group: &GraphicGroup| {
+			group
+				.iter()
+				.map(|element| {
+					let graphene_core::GraphicElement::ImageFrame(frame) = element else { panic!("Expected an image") };
+					frame.transform.transform_vector2(DVec2::X).length()
+				})
+				.collect::<Vec<_>>()
+		};
+
+		// Stamps of the default round tip are placed half their size apart, from one end of the path to the other
+		let stamps = brush(&line, false);
+		assert_eq!(stamps.len(), 21);
+		assert!(stamp_sizes(&stamps).iter().all(|&size| (size - 10.).abs() < 1e-9));
+
+		// Rasterizing composites them into a single image covering all of the stamps
+		let rasterized = brush(&line, true);
+		let graphene_core::GraphicElement::ImageFrame(image) = &rasterized[0] else {
+			panic!("Expected an image")
+		};
+		assert_eq!((rasterized.len(), image.image.width, image.image.height), (1, 110, 10));
+		assert!(image.image.data.iter().any(|pixel| pixel.a() > 0.5));
+
+		// The pressure attribute shrinks the stamps, which are then placed closer together
+		line.point_domain.set_attribute("pressure", graphene_core::vector::AttributeValues::F64(vec![1., 0.5]));
+		let sizes = stamp_sizes(&brush(&line, false));
+		assert!(sizes.len() > 21);
+		assert!((sizes[0] - 10.).abs() < 1e-9 && sizes.last().unwrap() < &6.);
+	}
 }
@@ -0,0 +1,159 @@
+//! Dual-format clipboard payloads for copied artwork: a plain SVG that other applications can paste, alongside a native binary which restores the artwork losslessly, per-point attributes and all.
+
+use graphene_core::renderer::{GraphicElementRendered, ImageRenderMode, RenderParams, RenderSvgSegmentList, SvgRender};
+use graphene_core::vector::style::ViewMode;
+use graphene_core::{GraphicElement, GraphicGroup};
+
+use glam::{DAffine2, DVec2};
+
+/// The MIME type that the SVG half of a [`ClipboardPayload`] should be offered to the system clipboard as.
+pub const SVG_MIME_TYPE: &str = "image/svg+xml";
+/// The MIME type that the native half of a [`ClipboardPayload`] should be offered to the system clipboard as.
+pub const NATIVE_MIME_TYPE: &str = "application/x-graphite-artwork";
+
+/// Identifies the start of a native payload.
+const NATIVE_MAGIC: &[u8; 8] = b"GRPHCLIP";
+/// Bumped whenever the layout of the native payload changes incompatibly.
+const NATIVE_VERSION: u16 = 1;
+/// Size of the magic bytes, the version, and the length of the body that precede the body of a native payload.
+const NATIVE_HEADER_LENGTH: usize = NATIVE_MAGIC.len() + 2 + 4;
+
+/// Copied artwork in both of the formats placed on the clipboard.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClipboardPayload {
+	/// A standalone SVG document framing the artwork, for pasting into other applications.
+	pub svg: String,
+	/// The full-fidelity binary form of the artwork, which [`decode_native`] turns back into the original graphic group.
+	pub native: Vec<u8>,
+}
+
+impl ClipboardPayload {
+	/// Bundles the selected elements into a single group and encodes it in both formats.
+	pub fn from_selection(elements: impl IntoIterator<Item = GraphicElement>) -> Self {
+		let mut group = GraphicGroup::EMPTY;
+		for element in elements {
+			group.push(element);
+		}
+		Self::from_group(&group)
+	}
+
+	/// Encodes the group in both formats.
+	pub fn from_group(group: &GraphicGroup) -> Self {
+		Self {
+			svg: render_svg(group),
+			native: encode_native(group),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub enum ClipboardError {
+	/// The data doesn't start with the magic bytes of a native payload, so it most likely came from another application.
+	NotNative,
+	/// The payload was written by a newer version of the format.
+	UnsupportedVersion(u16),
+	/// The payload is shorter than its header claims.
+	Truncated,
+	/// The body of the payload couldn't be deserialized.
+	Deserialize(serde_json::Error),
+}
+
+impl core::fmt::Display for ClipboardError {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::NotNative => write!(f, "the clipboard doesn't contain native artwork"),
+			Self::UnsupportedVersion(version) => write!(f, "the clipboard artwork uses unsupported format version {version}"),
+			Self::Truncated => write!(f, "the clipboard artwork is truncated"),
+			Self::Deserialize(err) => write!(f, "the clipboard artwork is malformed ({err})"),
+		}
+	}
+}
+
+impl std::error::Error for ClipboardError {}
+
+/// Serializes the group into the native payload: the magic bytes, the little endian format version and body length, then the body itself.
+pub fn encode_native(group: &GraphicGroup) -> Vec<u8> {
+	let body = serde_json::to_vec(group).expect("Failed to serialize graphic group");
+
+	let mut data = Vec::with_capacity(NATIVE_HEADER_LENGTH + body.len());
+	data.extend_from_slice(NATIVE_MAGIC);
+	data.extend_from_slice(&NATIVE_VERSION.to_le_bytes());
+	data.extend_from_slice(&(body.len() as u32).to_le_bytes());
+	data.extend_from_slice(&body);
+	data
+}
+
+/// Restores the graphic group from a native payload produced by [`encode_native`].
+/// Floats come back with exactly the values they were copied with because this crate enables the `float_roundtrip` feature of `serde_json`.
+pub fn decode_native(data: &[u8]) -> Result<GraphicGroup, ClipboardError> {
+	if !data.starts_with(NATIVE_MAGIC) {
+		return Err(ClipboardError::NotNative);
+	}
+	let header = data.get(..NATIVE_HEADER_LENGTH).ok_or(ClipboardError::Truncated)?;
+
+	let version = u16::from_le_bytes([header[8], header[9]]);
+	if version > NATIVE_VERSION {
+		return Err(ClipboardError::UnsupportedVersion(version));
+	}
+
+	let length = u32::from_le_bytes([header[10], header[11], header[12], header[13]]) as usize;
+	let body = data[NATIVE_HEADER_LENGTH..].get(..length).ok_or(ClipboardError::Truncated)?;
+	serde_json::from_slice(body).map_err(ClipboardError::Deserialize)
+}
+
+/// Renders the group into an SVG document whose view box fits snugly around the artwork.
+fn render_svg(group: &GraphicGroup) -> String {
+	let render_params = RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, true, true);
+	let mut render = SvgRender::new();
+	group.render_svg(&mut render, &render_params);
+
+	let [min, max] = group.bounding_box(DAffine2::IDENTITY).unwrap_or([DVec2::ZERO; 2]);
+	render.format_svg(min, max);
+	render.svg.to_svg_string()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use graphene_core::vector::{AttributeValues, VectorData};
+
+	use bezier_rs::Subpath;
+
+	fn artwork() -> GraphicGroup {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::new(0.1, 0.2), DVec2::new(10. / 3., 20.)));
+		vector_data.point_domain.set_attribute("weight", AttributeValues::F64(vec![0.1, 0.2, 0.3, std::f64::consts::PI]));
+		vector_data.transform = DAffine2::from_angle(1.);
+
+		let mut group = GraphicGroup::EMPTY;
+		group.push(vector_data.into());
+		group.channel = Some("foil".to_string());
+		group
+	}
+
+	#[test]
+	fn native_round_trip() {
+		let group = artwork();
+		let payload = ClipboardPayload::from_group(&group);
+		assert_eq!(decode_native(&payload.native).unwrap(), group);
+	}
+
+	#[test]
+	fn svg_is_standalone() {
+		let payload = ClipboardPayload::from_selection(artwork().iter().cloned());
+		assert!(payload.svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox="));
+		assert!(payload.svg.contains("<path"));
+		assert!(payload.svg.ends_with("</svg>"));
+	}
+
+	#[test]
+	fn rejects_foreign_and_damaged_data() {
+		assert!(matches!(decode_native(b"<svg></svg>"), Err(ClipboardError::NotNative)));
+
+		let native = encode_native(&artwork());
+		assert!(matches!(decode_native(&native[..native.len() - 1]), Err(ClipboardError::Truncated)));
+
+		let mut newer = native.clone();
+		newer[8..10].copy_from_slice(&(NATIVE_VERSION + 1).to_le_bytes());
+		assert!(matches!(decode_native(&newer), Err(ClipboardError::UnsupportedVersion(_))));
+	}
+}
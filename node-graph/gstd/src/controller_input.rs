@@ -0,0 +1,325 @@
+//! Live input from OSC and MIDI controllers, for driving parameters of the graph from hardware during a performance.
+//!
+//! Each controller message carries a value for an address: OSC messages have their own address, like `/1/fader1`, while MIDI messages are given one naming
+//! the kind of message, its channel from 1 to 16, and its controller or note number, like `midi/cc/1/7`, `midi/note/10/36`, or `midi/pitch/1`.
+//! The last value received for each address is kept, and the node runtime calls [`poll_changes`] between graph evaluations to evaluate the graph again
+//! whenever a value changes, rebuilding only the Controller Parameter nodes and those downstream of them by marking them with the [`generation`].
+
+use graphene_core::transform::Footprint;
+use graphene_core::Node;
+
+use std::net::UdpSocket;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// The prefix of the addresses given to MIDI messages.
+const MIDI_PREFIX: &str = "midi/";
+
+struct ControllerState {
+	/// The last value received for each address, scaled to the range from 0 to 1.
+	values: Vec<(String, f64)>,
+	/// The UDP ports that are being listened to for OSC messages.
+	osc_ports: Vec<u16>,
+	midi_connected: bool,
+}
+
+static CONTROLLER_STATE: Mutex<ControllerState> = Mutex::new(ControllerState {
+	values: Vec::new(),
+	osc_ports: Vec::new(),
+	midi_connected: false,
+});
+
+/// The longest wait before trying again to receive from an OSC socket that keeps failing.
+const MAX_RECEIVE_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Set when a value has changed since the last call to [`poll_changes`].
+static CHANGED: AtomicBool = AtomicBool::new(false);
+
+/// Counts the changes to any value, so the graph can tell whether what the controller nodes read is still the same.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Returns whether any controller values have changed since this was last called.
+pub fn poll_changes() -> bool {
+	CHANGED.swap(false, Ordering::Relaxed)
+}
+
+/// A number that is different after every change to a controller value.
+pub fn generation() -> u64 {
+	GENERATION.load(Ordering::Relaxed)
+}
+
+/// The last value received for the address, from 0 to 1.
+pub fn value(address: &str) -> Option<f64> {
+	let state = CONTROLLER_STATE.lock().ok()?;
+	state.values.iter().find(|(received, _)| received == address.trim()).map(|&(_, value)| value)
+}
+
+fn receive(messages: impl IntoIterator<Item = (String, f64)>) {
+	let Ok(mut state) = CONTROLLER_STATE.lock() else { return };
+
+	for (address, value) in messages {
+		match state.values.iter_mut().find(|(received, _)| *received == address) {
+			// Controllers often repeat the value they last sent, which doesn't need the graph to be evaluated again
+			Some((_, current)) if *current == value => continue,
+			Some((_, current)) => *current = value,
+			None => state.values.push((address, value)),
+		}
+		GENERATION.fetch_add(1, Ordering::Relaxed);
+		CHANGED.store(true, Ordering::Relaxed);
+	}
+}
+
+/// Starts receiving the messages for the address, which are OSC messages sent to the port unless it's a MIDI address. Does nothing if they're already being received.
+fn listen(address: &str, port: u16) {
+	let Ok(mut state) = CONTROLLER_STATE.lock() else { return };
+
+	if address.trim().starts_with(MIDI_PREFIX) {
+		if !state.midi_connected {
+			state.midi_connected = true;
+			connect_midi();
+		}
+	} else if !state.osc_ports.contains(&port) {
+		// The port is remembered even if it can't be bound, so a port in use by another program isn't tried again on every evaluation
+		state.osc_ports.push(port);
+		listen_osc(port);
+	}
+}
+
+fn listen_osc(port: u16) {
+	let socket = match UdpSocket::bind(("0.0.0.0", port)) {
+		Ok(socket) => socket,
+		Err(error) => {
+			warn!("Failed to listen for OSC messages on port {port}: {error}");
+			return;
+		}
+	};
+
+	std::thread::spawn(move || {
+		let mut buffer = vec![0; u16::MAX as usize];
+		let mut backoff = Duration::ZERO;
+		loop {
+			let length = match socket.recv(&mut buffer) {
+				Ok(length) => length,
+				Err(error) => {
+					// Wait longer after each failure in a row, so a socket that keeps failing doesn't spin
+					backoff = (backoff * 2).clamp(Duration::from_millis(10), MAX_RECEIVE_BACKOFF);
+					warn!("Failed to receive OSC messages on port {port}, trying again in {backoff:?}: {error}");
+					std::thread::sleep(backoff);
+					continue;
+				}
+			};
+			backoff = Duration::ZERO;
+			let mut messages = Vec::new();
+			parse_osc(&buffer[..length], &mut messages);
+			receive(messages);
+		}
+	});
+}
+
+/// Connects to every MIDI input available, which stay connected for as long as the application runs. Devices plugged in later aren't seen until it's restarted.
+#[cfg(feature = "midi")]
+fn connect_midi() {
+	let ports = match midir::MidiInput::new("Graphite") {
+		Ok(input) => input.ports(),
+		Err(error) => {
+			warn!("Failed to access MIDI inputs: {error}");
+			return;
+		}
+	};
+
+	for port in ports {
+		let Ok(input) = midir::MidiInput::new("Graphite") else { continue };
+		let name = input.port_name(&port).unwrap_or_default();
+		match input.connect(&port, "graphite-controller-input", |_, message, _| receive(parse_midi(message)), ()) {
+			Ok(connection) => std::mem::forget(connection),
+			Err(error) => warn!("Failed to connect to the MIDI input {name}: {error}"),
+		}
+	}
+}
+
+#[cfg(not(feature = "midi"))]
+fn connect_midi() {
+	warn!("MIDI input is not available in this build");
+}
+
+/// Reads the messages in an OSC packet, which may be a bundle of other packets, taking the first numeric argument of each message as its value.
+/// Booleans are given the values 0 and 1, and messages without a numeric argument are skipped.
+fn parse_osc(packet: &[u8], messages: &mut Vec<(String, f64)>) {
+	if let Some(mut elements) = packet.strip_prefix(b"#bundle\0") {
+		// Skip the time tag, since messages are used as soon as they arrive
+		elements = elements.get(8..).unwrap_or_default();
+		while let Some(size) = bytes::<4>(elements) {
+			let size = u32::from_be_bytes(size) as usize;
+			let Some(element) = elements.get(4..4 + size) else { return };
+			parse_osc(element, messages);
+			elements = &elements[4 + size..];
+		}
+		return;
+	}
+
+	let Some((address, rest)) = osc_string(packet) else { return };
+	let Some((type_tags, mut arguments)) = osc_string(rest) else { return };
+	let Some(type_tags) = type_tags.strip_prefix(',') else { return };
+
+	for type_tag in type_tags.chars() {
+		let (value, size) = match type_tag {
+			'f' => (bytes::<4>(arguments).map(|bytes| f32::from_be_bytes(bytes) as f64), 4),
+			'i' => (bytes::<4>(arguments).map(|bytes| i32::from_be_bytes(bytes) as f64), 4),
+			'd' => (bytes::<8>(arguments).map(|bytes| f64::from_be_bytes(bytes)), 8),
+			'h' => (bytes::<8>(arguments).map(|bytes| i64::from_be_bytes(bytes) as f64), 8),
+			'T' => (Some(1.), 0),
+			'F' => (Some(0.), 0),
+			's' | 'S' => match osc_string(arguments) {
+				Some((_, rest)) => (None, arguments.len() - rest.len()),
+				None => return,
+			},
+			'b' => match bytes::<4>(arguments) {
+				Some(size) => (None, 4 + padded(u32::from_be_bytes(size) as usize)),
+				None => return,
+			},
+			'c' | 'r' | 'm' => (None, 4),
+			't' => (None, 8),
+			_ => (None, 0),
+		};
+		if let Some(value) = value.filter(|value| value.is_finite()) {
+			messages.push((address.to_string(), value));
+			return;
+		}
+		let Some(rest) = arguments.get(size..) else { return };
+		arguments = rest;
+	}
+}
+
+/// Reads a null-terminated string padded to a multiple of four bytes from the start of OSC data, returning it along with the data after it.
+fn osc_string(data: &[u8]) -> Option<(&str, &[u8])> {
+	let length = data.iter().position(|&byte| byte == 0)?;
+	let string = std::str::from_utf8(&data[..length]).ok()?;
+	let rest = data.get(padded(length + 1)..).unwrap_or_default();
+	Some((string, rest))
+}
+
+/// Rounds the length of OSC data up to the multiple of four bytes that it's padded to.
+fn padded(length: usize) -> usize {
+	(length + 3) & !3
+}
+
+/// The first bytes of the data, for reading a number from them.
+fn bytes<const N: usize>(data: &[u8]) -> Option<[u8; N]> {
+	data.get(..N)?.try_into().ok()
+}
+
+/// Reads the address and value of a MIDI control change, note, or pitch bend message, with the value scaled to the range from 0 to 1.
+/// A note's value is its velocity while held down and 0 once released.
+fn parse_midi(message: &[u8]) -> Option<(String, f64)> {
+	let (&status, data) = message.split_first()?;
+	let channel = (status & 0x0F) + 1;
+
+	let (kind, number, value) = match (status & 0xF0, data) {
+		(0xB0, &[controller, value, ..]) => ("cc", Some(controller), value as f64 / 127.),
+		(0x90, &[note, velocity, ..]) => ("note", Some(note), velocity as f64 / 127.),
+		(0x80, &[note, ..]) => ("note", Some(note), 0.),
+		(0xE0, &[least_significant, most_significant, ..]) => ("pitch", None, ((most_significant as u16) << 7 | least_significant as u16) as f64 / 16383.),
+		_ => return None,
+	};
+
+	let address = match number {
+		Some(number) => format!("{MIDI_PREFIX}{kind}/{channel}/{number}"),
+		None => format!("{MIDI_PREFIX}{kind}/{channel}"),
+	};
+	Some((address, value))
+}
+
+pub struct ControllerParameterNode<Name, Default, Address, Port, Min, Max> {
+	name: Name,
+	default: Default,
+	address: Address,
+	port: Port,
+	min: Min,
+	max: Max,
+}
+
+/// A named number like that of a [`graphene_core::variable_data::ParameterNode`], which is instead set by a hardware controller when it sends a value for the address.
+/// OSC messages are received on the UDP port, and MIDI addresses start with `midi/`, as described in the [module documentation](self).
+///
+/// Controllers send values from 0 to 1 (with MIDI's 0 to 127 scaled down), which are mapped onto the range from the min to the max.
+/// The default is used until a value is received, and a Parameter Sweep of the parameter's name still takes priority over the controller.
+#[node_macro::node_fn(ControllerParameterNode)]
+fn controller_parameter(footprint: Footprint, name: String, default: f64, address: String, port: u32, min: f64, max: f64) -> f64 {
+	if let Some(value) = footprint.parameter.and_then(|binding| binding.value_of(&name)) {
+		return value;
+	}
+
+	listen(&address, port.min(u16::MAX as u32) as u16);
+	value(&address).map_or(default, |value| min + (max - min) * value)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn osc_string_bytes(string: &str) -> Vec<u8> {
+		let mut bytes = string.as_bytes().to_vec();
+		bytes.resize(padded(string.len() + 1), 0);
+		bytes
+	}
+
+	#[test]
+	fn parse_osc_messages() {
+		let mut message = osc_string_bytes("/1/fader1");
+		message.extend(osc_string_bytes(",sf"));
+		message.extend(osc_string_bytes("label"));
+		message.extend(0.25_f32.to_be_bytes());
+
+		let mut bundle = osc_string_bytes("#bundle");
+		bundle.extend([0, 0, 0, 0, 0, 0, 0, 1]);
+		bundle.extend((message.len() as u32).to_be_bytes());
+		bundle.extend(&message);
+		let mut toggle = osc_string_bytes("/toggle");
+		toggle.extend(osc_string_bytes(",T"));
+		bundle.extend((toggle.len() as u32).to_be_bytes());
+		bundle.extend(&toggle);
+
+		let mut messages = Vec::new();
+		parse_osc(&bundle, &mut messages);
+		assert_eq!(messages, [("/1/fader1".to_string(), 0.25), ("/toggle".to_string(), 1.)]);
+
+		// Cut off data is skipped rather than read past its end
+		messages.clear();
+		parse_osc(&message[..message.len() - 2], &mut messages);
+		assert!(messages.is_empty());
+	}
+
+	#[test]
+	fn parse_midi_messages() {
+		assert_eq!(parse_midi(&[0xB0, 7, 127]), Some(("midi/cc/1/7".to_string(), 1.)));
+		assert_eq!(parse_midi(&[0x99, 36, 0]), Some(("midi/note/10/36".to_string(), 0.)));
+		assert_eq!(parse_midi(&[0x80, 36, 64]), Some(("midi/note/1/36".to_string(), 0.)));
+		assert_eq!(parse_midi(&[0xE0, 0x7F, 0x7F]), Some(("midi/pitch/1".to_string(), 1.)));
+		assert_eq!(parse_midi(&[0xF8]), None);
+	}
+
+	#[test]
+	fn map_received_value() {
+		let node = ControllerParameterNode::new(
+			graphene_core::value::ClonedNode::new("size".to_string()),
+			graphene_core::value::ClonedNode::new(10.),
+			graphene_core::value::ClonedNode::new("/test/map_received_value".to_string()),
+			graphene_core::value::ClonedNode::new(0_u32),
+			graphene_core::value::ClonedNode::new(100.),
+			graphene_core::value::ClonedNode::new(200.),
+		);
+		assert_eq!(node.eval(Footprint::default()), 10.);
+
+		let before = generation();
+		receive([("/test/map_received_value".to_string(), 0.5)]);
+		assert!(poll_changes());
+		assert_ne!(generation(), before);
+		assert_eq!(node.eval(Footprint::default()), 150.);
+
+		// Receiving the same value again isn't a change
+		let before = generation();
+		receive([("/test/map_received_value".to_string(), 0.5)]);
+		assert_eq!(generation(), before);
+	}
+}
@@ -0,0 +1,753 @@
+use graphene_core::raster::BlendMode;
+use graphene_core::vector::style::{Fill, Gradient, GradientType, LineCap, LineJoin, Stroke, StrokeAlignment};
+use graphene_core::vector::{PointId, VectorData};
+use graphene_core::{AlphaBlending, Color, GraphicGroup, Node};
+
+use bezier_rs::{ManipulatorGroup, Subpath};
+use glam::{DAffine2, DVec2};
+use serde_json::Value;
+
+pub struct DesignImportNode<Json> {
+	json: Json,
+}
+
+/// Converts the JSON of a Figma file or node, as returned by its REST API, or of a page or layer from a Sketch file into a graphic group.
+///
+/// Figma geometry is only included in the API response when requesting it with the `geometry=paths` parameter. Without it, only rectangles, ellipses, and lines can be recreated from their bounds.
+/// Text, images, effects, and clipping are not imported.
+#[node_macro::node_fn(DesignImportNode)]
+fn design_import(_input: (), json: String) -> GraphicGroup {
+	let mut graphic_group = GraphicGroup::EMPTY;
+	let value = match serde_json::from_str::<Value>(&json) {
+		Ok(value) => value,
+		Err(error) => {
+			warn!("Failed to parse the design file: {error}");
+			return graphic_group;
+		}
+	};
+
+	if value.get("_class").is_some() {
+		sketch_layer(&value, DAffine2::IDENTITY, &mut graphic_group);
+	} else if let Some(document) = value.get("document") {
+		figma_node(document, DAffine2::IDENTITY, &mut graphic_group);
+	} else if let Some(nodes) = value.get("nodes").and_then(Value::as_object) {
+		// The response of the endpoint for requesting specific nodes of a file
+		for node in nodes.values() {
+			figma_node(&node["document"], DAffine2::IDENTITY, &mut graphic_group);
+		}
+	} else {
+		figma_node(&value, DAffine2::IDENTITY, &mut graphic_group);
+	}
+
+	graphic_group
+}
+
+/// Converts a Figma node and its children, where `container_transform` is the transform of the nearest frame-like ancestor that the node is positioned relative to.
+fn figma_node(node: &Value, container_transform: DAffine2, graphic_group: &mut GraphicGroup) {
+	if node["visible"] == Value::Bool(false) {
+		return;
+	}
+
+	let bounding_box = &node["absoluteBoundingBox"];
+	let transform = match node["relativeTransform"].as_array() {
+		Some(rows) if rows.len() == 2 => {
+			let [a, c, e] = row(&rows[0]);
+			let [b, d, f] = row(&rows[1]);
+			container_transform * DAffine2::from_cols_array(&[a, b, c, d, e, f])
+		}
+		// Without the transform, fall back to the absolute position, which loses any rotation
+		_ => DAffine2::from_translation(DVec2::new(number(&bounding_box["x"], 0.), number(&bounding_box["y"], 0.))),
+	};
+	let size = match node.get("size") {
+		Some(size) => DVec2::new(number(&size["x"], 0.), number(&size["y"], 0.)),
+		None => DVec2::new(number(&bounding_box["width"], 0.), number(&bounding_box["height"], 0.)),
+	};
+	let children = node["children"].as_array().map(Vec::as_slice).unwrap_or_default();
+
+	match node["type"].as_str().unwrap_or_default() {
+		// Only the first page of a file is imported, since the pages would otherwise overlap
+		"DOCUMENT" => {
+			if let Some(canvas) = children.iter().find(|child| child["type"] == "CANVAS") {
+				figma_node(canvas, DAffine2::IDENTITY, graphic_group);
+			}
+		}
+		"CANVAS" => {
+			for child in children {
+				figma_node(child, DAffine2::IDENTITY, graphic_group);
+			}
+		}
+		// Groups don't have a coordinate space of their own, so their children are positioned relative to the same container as the group
+		"GROUP" => {
+			let mut group = GraphicGroup::EMPTY;
+			group.alpha_blending = figma_alpha_blending(node);
+			for child in children {
+				figma_node(child, container_transform, &mut group);
+			}
+			graphic_group.push(group.into());
+		}
+		"FRAME" | "COMPONENT" | "COMPONENT_SET" | "INSTANCE" | "SECTION" => {
+			let mut group = GraphicGroup::EMPTY;
+			group.alpha_blending = figma_alpha_blending(node);
+			if let Some(mut background) = figma_shape(node, transform, size) {
+				// The opacity of the frame applies to the whole group rather than just its background
+				background.alpha_blending = AlphaBlending::default();
+				group.push(background.into());
+			}
+			for child in children {
+				figma_node(child, transform, &mut group);
+			}
+			graphic_group.push(group.into());
+		}
+		// Other nodes, including boolean operations whose geometry is the result of the operation, are shapes
+		_ => {
+			if let Some(vector_data) = figma_shape(node, transform, size) {
+				graphic_group.push(vector_data.into());
+			}
+		}
+	}
+}
+
+fn figma_shape(node: &Value, transform: DAffine2, size: DVec2) -> Option<VectorData> {
+	let geometry = |key: &str| {
+		node[key]
+			.as_array()
+			.into_iter()
+			.flatten()
+			.filter_map(|geometry| geometry["path"].as_str())
+			.flat_map(parse_path_data)
+			.collect::<Vec<_>>()
+	};
+	let mut fill = figma_fill(&node["fills"]);
+	let mut stroke = figma_stroke(node);
+
+	let mut subpaths = geometry("fillGeometry");
+	if subpaths.is_empty() {
+		subpaths = match node["type"].as_str().unwrap_or_default() {
+			"RECTANGLE" | "FRAME" | "COMPONENT" | "COMPONENT_SET" | "INSTANCE" | "SECTION" => vec![Subpath::new_rect(DVec2::ZERO, size)],
+			"ELLIPSE" => vec![Subpath::new_ellipse(DVec2::ZERO, size)],
+			"LINE" => vec![Subpath::new_line(DVec2::ZERO, DVec2::new(size.x, 0.))],
+			_ => {
+				// Open paths only have the outline of their stroke, so fill the outline with the stroke's paint instead
+				let outline = geometry("strokeGeometry");
+				if let Some(stroke) = stroke.take() {
+					fill = stroke.paint;
+				}
+				outline
+			}
+		};
+	}
+	if subpaths.is_empty() || (fill == Fill::None && stroke.is_none()) {
+		return None;
+	}
+
+	let mut vector_data = VectorData::empty();
+	for subpath in subpaths {
+		vector_data.append_subpath(subpath);
+	}
+	vector_data.transform = transform;
+	vector_data.alpha_blending = figma_alpha_blending(node);
+	vector_data.style.set_fill(fill);
+	match stroke {
+		Some(stroke) => vector_data.style.set_stroke(stroke),
+		None => vector_data.style.clear_stroke(),
+	}
+	Some(vector_data)
+}
+
+/// Figma stacks its paints from bottom to top, so the topmost visible paint is used.
+fn figma_fill(paints: &Value) -> Fill {
+	let visible_paints = paints.as_array().into_iter().flatten().filter(|paint| paint["visible"] != Value::Bool(false));
+
+	visible_paints
+		.rev()
+		.find_map(|paint| {
+			let opacity = number(&paint["opacity"], 1.);
+			let gradient_type = match paint["type"].as_str()? {
+				"SOLID" => return Some(Fill::Solid(figma_color(&paint["color"], opacity))),
+				"GRADIENT_LINEAR" => GradientType::Linear,
+				"GRADIENT_RADIAL" => GradientType::Radial,
+				"GRADIENT_ANGULAR" => GradientType::Conic,
+				_ => return None,
+			};
+
+			// The handles are positioned relative to the bounds of the node, like the start and end of Graphite's gradients
+			let handles = paint["gradientHandlePositions"].as_array()?;
+			let [start, end] = [handles.first()?, handles.get(1)?].map(|handle| DVec2::new(number(&handle["x"], 0.), number(&handle["y"], 0.)));
+			let mut gradient = Gradient::new(start, Color::BLACK, end, Color::WHITE, DAffine2::IDENTITY, gradient_type);
+			gradient.positions = paint["gradientStops"]
+				.as_array()?
+				.iter()
+				.map(|stop| (number(&stop["position"], 0.), figma_color(&stop["color"], opacity)))
+				.collect();
+			Some(Fill::Gradient(gradient))
+		})
+		.unwrap_or(Fill::None)
+}
+
+fn figma_stroke(node: &Value) -> Option<Stroke> {
+	let paint = match figma_fill(&node["strokes"]) {
+		Fill::None | Fill::Pattern(_) | Fill::Image(_) => return None,
+		paint => paint,
+	};
+
+	let mut stroke = Stroke::new(None, number(&node["strokeWeight"], 1.)).with_paint(paint);
+	stroke.line_cap = match node["strokeCap"].as_str() {
+		Some("ROUND") => LineCap::Round,
+		Some("SQUARE") => LineCap::Square,
+		_ => LineCap::Butt,
+	};
+	stroke.line_join = match node["strokeJoin"].as_str() {
+		Some("ROUND") => LineJoin::Round,
+		Some("BEVEL") => LineJoin::Bevel,
+		_ => LineJoin::Miter,
+	};
+	// Figma specifies the smallest angle between two segments that is still mitered, rather than the ratio of the miter length to the stroke weight
+	let miter_angle = number(&node["strokeMiterAngle"], 28.96).to_radians();
+	stroke.line_join_miter_limit = 1. / (miter_angle / 2.).sin().max(f64::EPSILON);
+	stroke.dash_lengths = node["strokeDashes"].as_array().into_iter().flatten().filter_map(Value::as_f64).collect();
+	stroke.alignment = match node["strokeAlign"].as_str() {
+		Some("INSIDE") => StrokeAlignment::Inside,
+		Some("OUTSIDE") => StrokeAlignment::Outside,
+		_ => StrokeAlignment::Center,
+	};
+	Some(stroke)
+}
+
+fn figma_color(color: &Value, opacity: f64) -> Color {
+	let [red, green, blue] = ["r", "g", "b"].map(|channel| number(&color[channel], 0.) as f32);
+	Color::from_rgbaf32_unchecked(red, green, blue, (number(&color["a"], 1.) * opacity) as f32)
+}
+
+fn figma_alpha_blending(node: &Value) -> AlphaBlending {
+	let blend_mode = match node["blendMode"].as_str().unwrap_or_default() {
+		"DARKEN" => BlendMode::Darken,
+		"MULTIPLY" => BlendMode::Multiply,
+		"LINEAR_BURN" => BlendMode::LinearBurn,
+		"COLOR_BURN" => BlendMode::ColorBurn,
+		"LIGHTEN" => BlendMode::Lighten,
+		"SCREEN" => BlendMode::Screen,
+		"LINEAR_DODGE" => BlendMode::LinearDodge,
+		"COLOR_DODGE" => BlendMode::ColorDodge,
+		"OVERLAY" => BlendMode::Overlay,
+		"SOFT_LIGHT" => BlendMode::SoftLight,
+		"HARD_LIGHT" => BlendMode::HardLight,
+		"DIFFERENCE" => BlendMode::Difference,
+		"EXCLUSION" => BlendMode::Exclusion,
+		"HUE" => BlendMode::Hue,
+		"SATURATION" => BlendMode::Saturation,
+		"COLOR" => BlendMode::Color,
+		"LUMINOSITY" => BlendMode::Luminosity,
+		_ => BlendMode::Normal,
+	};
+	AlphaBlending {
+		opacity: number(&node["opacity"], 1.) as f32,
+		blend_mode,
+	}
+}
+
+/// Converts a Sketch layer and its children, where `parent_transform` is the transform of the layer that it is positioned relative to.
+fn sketch_layer(layer: &Value, parent_transform: DAffine2, graphic_group: &mut GraphicGroup) {
+	if layer["isVisible"] == Value::Bool(false) {
+		return;
+	}
+
+	let frame = &layer["frame"];
+	let size = DVec2::new(number(&frame["width"], 0.), number(&frame["height"], 0.));
+	let center = size / 2.;
+	let flip = DVec2::new(
+		if layer["isFlippedHorizontal"] == Value::Bool(true) { -1. } else { 1. },
+		if layer["isFlippedVertical"] == Value::Bool(true) { -1. } else { 1. },
+	);
+	// Sketch rotates counterclockwise around the center of the layer
+	let rotation = DAffine2::from_translation(center) * DAffine2::from_angle(-number(&layer["rotation"], 0.).to_radians()) * DAffine2::from_scale(flip) * DAffine2::from_translation(-center);
+	let transform = parent_transform * DAffine2::from_translation(DVec2::new(number(&frame["x"], 0.), number(&frame["y"], 0.))) * rotation;
+	let children = layer["layers"].as_array().map(Vec::as_slice).unwrap_or_default();
+
+	match layer["_class"].as_str().unwrap_or_default() {
+		// The frame of a page is not used to position its layers
+		"page" => {
+			for child in children {
+				sketch_layer(child, parent_transform, graphic_group);
+			}
+		}
+		"artboard" | "symbolMaster" | "group" => {
+			let mut group = GraphicGroup::EMPTY;
+			group.alpha_blending = sketch_alpha_blending(layer);
+			if layer["hasBackgroundColor"] == Value::Bool(true) {
+				let mut background = VectorData::empty();
+				background.append_subpath(Subpath::<PointId>::new_rect(DVec2::ZERO, size));
+				background.transform = transform;
+				background.style.set_fill(Fill::Solid(sketch_color(&layer["backgroundColor"])));
+				background.style.clear_stroke();
+				group.push(background.into());
+			}
+			for child in children {
+				sketch_layer(child, transform, &mut group);
+			}
+			graphic_group.push(group.into());
+		}
+		// Older files combine the paths of their children into a single shape with the style of the group
+		"shapeGroup" => {
+			let mut vector_data = VectorData::empty();
+			for child in children.iter().filter(|child| child["isVisible"] != Value::Bool(false)) {
+				let child_transform = DAffine2::from_translation(DVec2::new(number(&child["frame"]["x"], 0.), number(&child["frame"]["y"], 0.)));
+				for mut subpath in sketch_subpaths(child) {
+					subpath.apply_transform(child_transform);
+					vector_data.append_subpath(subpath);
+				}
+			}
+			if let Some(vector_data) = sketch_styled(layer, vector_data, transform) {
+				graphic_group.push(vector_data.into());
+			}
+		}
+		"rectangle" | "oval" | "shapePath" | "triangle" | "star" | "polygon" => {
+			let mut vector_data = VectorData::empty();
+			for subpath in sketch_subpaths(layer) {
+				vector_data.append_subpath(subpath);
+			}
+			if let Some(vector_data) = sketch_styled(layer, vector_data, transform) {
+				graphic_group.push(vector_data.into());
+			}
+		}
+		_ => {}
+	}
+}
+
+/// Builds the path of a Sketch shape layer from its points, which are normalized to the size of its frame.
+fn sketch_subpaths(layer: &Value) -> Vec<Subpath<PointId>> {
+	let frame = &layer["frame"];
+	let size = DVec2::new(number(&frame["width"], 0.), number(&frame["height"], 0.));
+	let points = layer["points"].as_array().map(Vec::as_slice).unwrap_or_default();
+
+	if points.is_empty() {
+		return match layer["_class"].as_str() {
+			Some("rectangle") => vec![Subpath::new_rect(DVec2::ZERO, size)],
+			Some("oval") => vec![Subpath::new_ellipse(DVec2::ZERO, size)],
+			_ => Vec::new(),
+		};
+	}
+
+	let manipulator_groups = points
+		.iter()
+		.filter_map(|point| {
+			let anchor = sketch_point(&point["point"])? * size;
+			// A handle is only present if the curve leaves or arrives at the point with a curve, in which case it is stored in the same normalized space
+			let handle = |key: &str, flag: &str| {
+				let handle = sketch_point(&point[key]).map(|handle| handle * size)?;
+				match point[flag].as_bool() {
+					Some(has_curve) => has_curve.then_some(handle),
+					None => (handle != anchor).then_some(handle),
+				}
+			};
+			Some(ManipulatorGroup::new(anchor, handle("curveTo", "hasCurveTo"), handle("curveFrom", "hasCurveFrom")))
+		})
+		.collect::<Vec<_>>();
+
+	vec![Subpath::new(manipulator_groups, layer["isClosed"].as_bool().unwrap_or(true))]
+}
+
+fn sketch_styled(layer: &Value, mut vector_data: VectorData, transform: DAffine2) -> Option<VectorData> {
+	let style = &layer["style"];
+	let enabled = |key: &str| style[key].as_array().into_iter().flatten().filter(|item| item["isEnabled"] != Value::Bool(false)).collect::<Vec<_>>();
+
+	// Sketch stacks its fills and borders from bottom to top, so the topmost one is used
+	let fill = enabled("fills")
+		.into_iter()
+		.rev()
+		.find_map(|fill| match fill["fillType"].as_u64().unwrap_or_default() {
+			0 => Some(Fill::Solid(sketch_color(&fill["color"]))),
+			1 => {
+				let gradient = &fill["gradient"];
+				let [start, end] = [&gradient["from"], &gradient["to"]].map(|point| sketch_point(point).unwrap_or_default());
+				let gradient_type = match gradient["gradientType"].as_u64() {
+					Some(1) => GradientType::Radial,
+					Some(2) => GradientType::Conic,
+					_ => GradientType::Linear,
+				};
+				let mut graphite_gradient = Gradient::new(start, Color::BLACK, end, Color::WHITE, DAffine2::IDENTITY, gradient_type);
+				graphite_gradient.positions = gradient["stops"]
+					.as_array()
+					.into_iter()
+					.flatten()
+					.map(|stop| (number(&stop["position"], 0.), sketch_color(&stop["color"])))
+					.collect();
+				Some(Fill::Gradient(graphite_gradient))
+			}
+			_ => None,
+		})
+		.unwrap_or(Fill::None);
+
+	let stroke = enabled("borders").last().map(|border| {
+		let options = &style["borderOptions"];
+		let mut stroke = Stroke::new(Some(sketch_color(&border["color"])), number(&border["thickness"], 1.));
+		stroke.line_cap = match options["lineCapStyle"].as_u64() {
+			Some(1) => LineCap::Round,
+			Some(2) => LineCap::Square,
+			_ => LineCap::Butt,
+		};
+		stroke.line_join = match options["lineJoinStyle"].as_u64() {
+			Some(1) => LineJoin::Round,
+			Some(2) => LineJoin::Bevel,
+			_ => LineJoin::Miter,
+		};
+		stroke.dash_lengths = options["dashPattern"].as_array().into_iter().flatten().filter_map(Value::as_f64).collect();
+		stroke.alignment = match border["position"].as_u64() {
+			Some(1) => StrokeAlignment::Inside,
+			Some(2) => StrokeAlignment::Outside,
+			_ => StrokeAlignment::Center,
+		};
+		stroke
+	});
+
+	if vector_data.segment_bezier_iter().next().is_none() || (fill == Fill::None && stroke.is_none()) {
+		return None;
+	}
+
+	vector_data.transform = transform;
+	vector_data.alpha_blending = sketch_alpha_blending(layer);
+	vector_data.style.set_fill(fill);
+	match stroke {
+		Some(stroke) => vector_data.style.set_stroke(stroke),
+		None => vector_data.style.clear_stroke(),
+	}
+	Some(vector_data)
+}
+
+fn sketch_color(color: &Value) -> Color {
+	let [red, green, blue, alpha] = [("red", 0.), ("green", 0.), ("blue", 0.), ("alpha", 1.)].map(|(channel, default)| number(&color[channel], default) as f32);
+	Color::from_rgbaf32_unchecked(red, green, blue, alpha)
+}
+
+/// Parses a point stored as a string like `{0.5, 1}`.
+fn sketch_point(point: &Value) -> Option<DVec2> {
+	let (x, y) = point.as_str()?.trim().trim_start_matches('{').trim_end_matches('}').split_once(',')?;
+	Some(DVec2::new(x.trim().parse().ok()?, y.trim().parse().ok()?))
+}
+
+fn sketch_alpha_blending(layer: &Value) -> AlphaBlending {
+	let context = &layer["style"]["contextSettings"];
+	let blend_mode = match context["blendMode"].as_u64().unwrap_or_default() {
+		1 => BlendMode::Darken,
+		2 => BlendMode::Multiply,
+		3 => BlendMode::ColorBurn,
+		4 => BlendMode::Lighten,
+		5 => BlendMode::Screen,
+		6 => BlendMode::ColorDodge,
+		7 => BlendMode::Overlay,
+		8 => BlendMode::SoftLight,
+		9 => BlendMode::HardLight,
+		10 => BlendMode::Difference,
+		11 => BlendMode::Exclusion,
+		12 => BlendMode::Hue,
+		13 => BlendMode::Saturation,
+		14 => BlendMode::Color,
+		15 => BlendMode::Luminosity,
+		_ => BlendMode::Normal,
+	};
+	AlphaBlending {
+		opacity: number(&context["opacity"], 1.) as f32,
+		blend_mode,
+	}
+}
+
+fn number(value: &Value, default: f64) -> f64 {
+	value.as_f64().unwrap_or(default)
+}
+
+fn row(row: &Value) -> [f64; 3] {
+	let row = row.as_array().map(Vec::as_slice).unwrap_or_default();
+	[0, 1, 2].map(|index| row.get(index).and_then(Value::as_f64).unwrap_or_default())
+}
+
+/// Parses SVG path data, which is how Figma stores the geometry of its nodes, into subpaths. Elliptical arcs are not supported and end the parsing.
+fn parse_path_data(data: &str) -> Vec<Subpath<PointId>> {
+	let mut subpaths = Vec::new();
+	let mut groups: Vec<ManipulatorGroup<PointId>> = Vec::new();
+	let finish = |groups: &mut Vec<ManipulatorGroup<PointId>>, subpaths: &mut Vec<_>, closed: bool| {
+		if groups.is_empty() {
+			return;
+		}
+		// Closing a path which ends on its starting point would otherwise duplicate the point
+		if closed && groups.len() > 1 && groups[0].anchor.abs_diff_eq(groups[groups.len() - 1].anchor, 1e-6) {
+			let last = groups.pop().unwrap();
+			groups[0].in_handle = last.in_handle;
+		}
+		subpaths.push(Subpath::new(core::mem::take(groups), closed));
+	};
+
+	let tokens = tokenize_path_data(data);
+	let mut tokens = tokens.iter().peekable();
+	let (mut current, mut start) = (DVec2::ZERO, DVec2::ZERO);
+	// The second control point of the previous curve, which smooth curves reflect
+	let mut previous_control: Option<DVec2> = None;
+	let mut command = ' ';
+
+	loop {
+		match tokens.peek() {
+			None => break,
+			Some(PathToken::Command(next)) => {
+				command = *next;
+				tokens.next();
+			}
+			// Numbers without a command repeat the previous command, except that a move turns into a line
+			Some(PathToken::Number(_)) if command == 'M' => command = 'L',
+			Some(PathToken::Number(_)) if command == 'm' => command = 'l',
+			Some(PathToken::Number(_)) => {}
+		}
+
+		let relative = command.is_ascii_lowercase();
+		let mut numbers = |count: usize| -> Option<Vec<f64>> {
+			(0..count)
+				.map(|_| match tokens.next_if(|token| matches!(token, PathToken::Number(_))) {
+					Some(PathToken::Number(number)) => Some(*number),
+					_ => None,
+				})
+				.collect()
+		};
+		let offset = if relative { current } else { DVec2::ZERO };
+		let point = |numbers: &[f64]| DVec2::new(numbers[0], numbers[1]) + offset;
+
+		let reflected = |previous_control: Option<DVec2>, current: DVec2| previous_control.map_or(current, |control| 2. * current - control);
+		let mut control = None;
+		match command.to_ascii_uppercase() {
+			'M' => {
+				let Some(values) = numbers(2) else { break };
+				finish(&mut groups, &mut subpaths, false);
+				current = point(&values);
+				start = current;
+				groups.push(ManipulatorGroup::new(current, None, None));
+			}
+			'L' | 'H' | 'V' => {
+				let end = match command.to_ascii_uppercase() {
+					'L' => {
+						let Some(values) = numbers(2) else { break };
+						point(&values)
+					}
+					'H' => {
+						let Some(values) = numbers(1) else { break };
+						DVec2::new(values[0] + offset.x, current.y)
+					}
+					_ => {
+						let Some(values) = numbers(1) else { break };
+						DVec2::new(current.x, values[0] + offset.y)
+					}
+				};
+				current = end;
+				groups.push(ManipulatorGroup::new(current, None, None));
+			}
+			'C' | 'S' => {
+				let (first, values) = if command.eq_ignore_ascii_case(&'C') {
+					let Some(values) = numbers(6) else { break };
+					(point(&values[0..2]), values[2..].to_vec())
+				} else {
+					let Some(values) = numbers(4) else { break };
+					(reflected(previous_control, current), values)
+				};
+				let (second, end) = (point(&values[0..2]), point(&values[2..4]));
+				if let Some(last) = groups.last_mut() {
+					last.out_handle = Some(first);
+				}
+				current = end;
+				groups.push(ManipulatorGroup::new(current, Some(second), None));
+				control = Some(second);
+			}
+			'Q' | 'T' => {
+				let (quadratic, end) = if command.eq_ignore_ascii_case(&'Q') {
+					let Some(values) = numbers(4) else { break };
+					(point(&values[0..2]), point(&values[2..4]))
+				} else {
+					let Some(values) = numbers(2) else { break };
+					(reflected(previous_control, current), point(&values))
+				};
+				// Elevate the quadratic curve to a cubic one
+				if let Some(last) = groups.last_mut() {
+					last.out_handle = Some(current + (quadratic - current) * 2. / 3.);
+				}
+				groups.push(ManipulatorGroup::new(end, Some(end + (quadratic - end) * 2. / 3.), None));
+				current = end;
+				control = Some(quadratic);
+			}
+			'Z' => {
+				finish(&mut groups, &mut subpaths, true);
+				current = start;
+			}
+			_ => {
+				warn!("Unsupported path command `{command}` in path data");
+				break;
+			}
+		}
+		previous_control = control;
+
+		// A command which takes no numbers, like closing the path, must be followed by another command
+		if command.eq_ignore_ascii_case(&'Z') && matches!(tokens.peek(), Some(PathToken::Number(_))) {
+			break;
+		}
+	}
+	finish(&mut groups, &mut subpaths, false);
+
+	subpaths
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PathToken {
+	Command(char),
+	Number(f64),
+}
+
+fn tokenize_path_data(data: &str) -> Vec<PathToken> {
+	let mut tokens = Vec::new();
+	let mut chars = data.char_indices().peekable();
+
+	while let Some(&(start, char)) = chars.peek() {
+		if char.is_ascii_alphabetic() && !matches!(char, 'e' | 'E') {
+			tokens.push(PathToken::Command(char));
+			chars.next();
+		} else if char.is_ascii_digit() || matches!(char, '-' | '+' | '.') {
+			chars.next();
+			let mut end = start + 1;
+			let mut seen_point = char == '.';
+			let mut previous = char;
+			while let Some(&(index, next)) = chars.peek() {
+				// A sign only continues the number directly after an exponent, and a second decimal point starts a new number
+				let continues = next.is_ascii_digit() || matches!(next, 'e' | 'E') || (matches!(next, '-' | '+') && matches!(previous, 'e' | 'E')) || (next == '.' && !seen_point);
+				if !continues {
+					break;
+				}
+				seen_point |= next == '.';
+				previous = next;
+				end = index + 1;
+				chars.next();
+			}
+			if let Ok(number) = data[start..end].parse() {
+				tokens.push(PathToken::Number(number));
+			}
+		} else {
+			chars.next();
+		}
+	}
+
+	tokens
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn import(json: &str) -> GraphicGroup {
+		DesignImportNode {
+			json: graphene_core::value::ClonedNode(json.to_string()),
+		}
+		.eval(())
+	}
+
+	#[test]
+	fn path_data() {
+		let subpaths = parse_path_data("M0 0L10 0 10 10Z m20 0 h5 v5 Q 30 10 25 15 C20 15,20 10-1e1 5");
+		assert_eq!(subpaths.len(), 2);
+		assert!(subpaths[0].closed());
+		assert_eq!(subpaths[0].len(), 3);
+		assert_eq!(subpaths[0].manipulator_groups()[2].anchor, DVec2::new(10., 10.));
+
+		// The relative move starts from the end of the closed path, which is its starting point
+		let second = &subpaths[1];
+		assert!(!second.closed());
+		let anchors = second.manipulator_groups().iter().map(|group| group.anchor).collect::<Vec<_>>();
+		assert_eq!(anchors, [DVec2::new(20., 0.), DVec2::new(25., 0.), DVec2::new(25., 5.), DVec2::new(25., 15.), DVec2::new(-10., 5.)]);
+		assert!(second.manipulator_groups()[2].out_handle.is_some());
+		assert_eq!(second.manipulator_groups()[4].in_handle, Some(DVec2::new(20., 10.)));
+	}
+
+	#[test]
+	fn figma_file() {
+		let graphic_group = import(
+			r#"{
+				"document": {
+					"type": "DOCUMENT",
+					"children": [{
+						"type": "CANVAS",
+						"children": [{
+							"type": "FRAME",
+							"relativeTransform": [[1, 0, 100], [0, 1, 50]],
+							"size": { "x": 200, "y": 100 },
+							"fills": [{ "type": "SOLID", "color": { "r": 1, "g": 1, "b": 1, "a": 1 } }],
+							"children": [
+								{
+									"type": "VECTOR",
+									"relativeTransform": [[1, 0, 10], [0, 1, 20]],
+									"fills": [{ "type": "SOLID", "color": { "r": 1, "g": 0, "b": 0, "a": 1 }, "opacity": 0.5 }],
+									"strokes": [{ "type": "SOLID", "color": { "r": 0, "g": 0, "b": 0, "a": 1 } }],
+									"strokeWeight": 2,
+									"fillGeometry": [{ "path": "M0 0L10 0L10 10Z", "windingRule": "NONZERO" }]
+								},
+								{ "type": "RECTANGLE", "visible": false, "size": { "x": 10, "y": 10 } }
+							]
+						}]
+					}]
+				}
+			}"#,
+		);
+
+		assert_eq!(graphic_group.len(), 1);
+		let graphene_core::GraphicElement::GraphicGroup(frame) = &graphic_group[0] else {
+			panic!("Expected the frame to be a group")
+		};
+		// The background of the frame and the vector, since the hidden rectangle is skipped
+		assert_eq!(frame.len(), 2);
+
+		let graphene_core::GraphicElement::VectorData(vector_data) = &frame[1] else {
+			panic!("Expected the vector to be vector data")
+		};
+		assert_eq!(vector_data.transform, DAffine2::from_translation(DVec2::new(110., 70.)));
+		assert_eq!(vector_data.style.fill(), &Fill::Solid(Color::from_rgbaf32_unchecked(1., 0., 0., 0.5)));
+		assert_eq!(vector_data.style.stroke().map(|stroke| stroke.weight), Some(2.));
+		assert_eq!(vector_data.region_bezier_paths().count(), 1);
+	}
+
+	#[test]
+	fn sketch_page() {
+		let graphic_group = import(
+			r#"{
+				"_class": "page",
+				"frame": { "x": 0, "y": 0, "width": 0, "height": 0 },
+				"layers": [{
+					"_class": "group",
+					"frame": { "x": 10, "y": 10, "width": 100, "height": 100 },
+					"layers": [{
+						"_class": "shapePath",
+						"frame": { "x": 5, "y": 5, "width": 20, "height": 10 },
+						"isClosed": true,
+						"points": [
+							{ "point": "{0, 0}", "curveFrom": "{0, 0}", "curveTo": "{0, 0}", "hasCurveFrom": false, "hasCurveTo": false },
+							{ "point": "{1, 0}", "curveFrom": "{1, 0.5}", "curveTo": "{1, 0}", "hasCurveFrom": true, "hasCurveTo": false },
+							{ "point": "{1, 1}", "curveFrom": "{1, 1}", "curveTo": "{1, 1}", "hasCurveFrom": false, "hasCurveTo": false }
+						],
+						"style": {
+							"fills": [{ "isEnabled": true, "fillType": 0, "color": { "red": 0, "green": 0, "blue": 1, "alpha": 1 } }],
+							"borders": [{ "isEnabled": false, "color": { "red": 0, "green": 0, "blue": 0, "alpha": 1 }, "thickness": 1 }]
+						}
+					}]
+				}]
+			}"#,
+		);
+
+		let graphene_core::GraphicElement::GraphicGroup(group) = &graphic_group[0] else {
+			panic!("Expected a group")
+		};
+		let graphene_core::GraphicElement::VectorData(vector_data) = &group[0] else {
+			panic!("Expected a shape")
+		};
+		assert_eq!(vector_data.transform, DAffine2::from_translation(DVec2::new(15., 15.)));
+		assert_eq!(vector_data.style.fill(), &Fill::Solid(Color::from_rgbaf32_unchecked(0., 0., 1., 1.)));
+		// The disabled border is ignored
+		assert!(vector_data.style.stroke().is_none());
+
+		let (_, subpath) = vector_data.region_bezier_paths().next().unwrap();
+		assert_eq!(subpath.manipulator_groups()[1].anchor, DVec2::new(20., 0.));
+		assert_eq!(subpath.manipulator_groups()[1].out_handle, Some(DVec2::new(20., 5.)));
+	}
+
+	#[test]
+	fn invalid_json() {
+		assert_eq!(import("not json").len(), 0);
+	}
+}
@@ -0,0 +1,133 @@
+//! Encoding of images into PNG, JPEG, and WebP files, so what the graph rasterizes can be written out as a finished export without leaving it.
+
+use graphene_core::raster::{Image, ImageFrame};
+use graphene_core::{Color, Node};
+
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+use image::codecs::webp::WebPEncoder;
+use image::{ColorType, ImageEncoder, ImageResult};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy)]
+pub struct EncodePngNode<BestCompression> {
+	best_compression: BestCompression,
+}
+
+/// Encodes the image as a PNG file, keeping its transparency. Compressing it as much as possible makes a smaller file but takes longer.
+#[node_macro::node_fn(EncodePngNode)]
+fn encode_png_node(image_frame: ImageFrame<Color>, best_compression: bool) -> Arc<[u8]> {
+	encoded("PNG", encode_png(&image_frame.image, best_compression))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EncodeJpegNode<Quality, Background> {
+	quality: Quality,
+	background: Background,
+}
+
+/// Encodes the image as a JPEG file at a quality from 1 to 100, where lower qualities make smaller files with more visible compression artifacts.
+/// JPEG has no transparency, so the image is placed over the background color, taken as opaque.
+#[node_macro::node_fn(EncodeJpegNode)]
+fn encode_jpeg_node(image_frame: ImageFrame<Color>, quality: u32, background: Color) -> Arc<[u8]> {
+	encoded("JPEG", encode_jpeg(&image_frame.image, quality, background))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EncodeWebPNode;
+
+/// Encodes the image as a lossless WebP file, keeping its transparency. It's usually smaller than the same image as a PNG.
+///
+/// There's no quality setting because lossy WebP encoding isn't available: the `image` crate's own encoder is lossless only, and its lossy one needs the native libwebp library,
+/// which can't be built for the web. Use the Encode JPEG node where a smaller lossy file matters more than transparency.
+#[node_macro::node_fn(EncodeWebPNode)]
+fn encode_webp_node(image_frame: ImageFrame<Color>) -> Arc<[u8]> {
+	encoded("WebP", encode_webp(&image_frame.image))
+}
+
+/// The bytes of the encoded file, or none if the image couldn't be encoded, such as when it has no pixels.
+fn encoded(format: &str, result: ImageResult<Vec<u8>>) -> Arc<[u8]> {
+	let data = result.unwrap_or_else(|error| {
+		warn!("The image couldn't be encoded as a {format} file: {error}");
+		Vec::new()
+	});
+	data.into()
+}
+
+/// Encodes the image as a PNG file, as quickly as possible or into as small a file as possible.
+pub fn encode_png(image: &Image<Color>, best_compression: bool) -> ImageResult<Vec<u8>> {
+	let compression = if best_compression { CompressionType::Best } else { CompressionType::Fast };
+	let (data, width, height) = image.to_flat_u8();
+	let mut png = Vec::new();
+	PngEncoder::new_with_quality(&mut png, compression, FilterType::Adaptive).write_image(&data, width, height, ColorType::Rgba8)?;
+	Ok(png)
+}
+
+/// Encodes the image as a JPEG file at the quality, clamped between 1 and 100, over the background made opaque.
+pub fn encode_jpeg(image: &Image<Color>, quality: u32, background: Color) -> ImageResult<Vec<u8>> {
+	let background = background.with_alpha(1.);
+	let flattened = Image {
+		width: image.width,
+		height: image.height,
+		data: image.data.iter().map(|&color| background.alpha_blend(color)).collect(),
+		base64_string: None,
+	};
+	let (data, width, height) = flattened.to_flat_u8();
+	let rgb = data.chunks_exact(4).flat_map(|pixel| [pixel[0], pixel[1], pixel[2]]).collect::<Vec<_>>();
+
+	let mut jpeg = Vec::new();
+	JpegEncoder::new_with_quality(&mut jpeg, quality.clamp(1, 100) as u8).write_image(&rgb, width, height, ColorType::Rgb8)?;
+	Ok(jpeg)
+}
+
+/// Encodes the image as a lossless WebP file, which is the only kind that can be encoded (see [`EncodeWebPNode`]).
+pub fn encode_webp(image: &Image<Color>) -> ImageResult<Vec<u8>> {
+	let (data, width, height) = image.to_flat_u8();
+	let mut webp = Vec::new();
+	WebPEncoder::new_lossless(&mut webp).write_image(&data, width, height, ColorType::Rgba8)?;
+	Ok(webp)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use graphene_core::value::ClonedNode;
+
+	#[test]
+	fn encode_and_decode() {
+		// Left half opaque red, right half transparent
+		let mut image = Image::new(16, 8, Color::TRANSPARENT);
+		for y in 0..8 {
+			for x in 0..8 {
+				image.data[y * 16 + x] = Color::RED;
+			}
+		}
+		let image_frame = ImageFrame { image, ..Default::default() };
+		let decode = |data: &[u8]| ::image::load_from_memory(data).unwrap().to_rgba8();
+
+		let png = decode(&EncodePngNode { best_compression: ClonedNode(true) }.eval(image_frame.clone()));
+		assert_eq!((png.width(), png.height()), (16, 8));
+		assert_eq!(png.get_pixel(2, 2).0, [255, 0, 0, 255]);
+		assert_eq!(png.get_pixel(12, 2).0[3], 0);
+
+		let webp = decode(&EncodeWebPNode.eval(image_frame.clone()));
+		assert_eq!(webp.get_pixel(2, 2).0, [255, 0, 0, 255]);
+		assert_eq!(webp.get_pixel(12, 2).0[3], 0);
+
+		// The transparent half shows the background, and lower qualities make smaller files
+		let jpeg = |quality: u32| {
+			EncodeJpegNode {
+				quality: ClonedNode(quality),
+				background: ClonedNode(Color::WHITE),
+			}
+			.eval(image_frame.clone())
+		};
+		let decoded = decode(&jpeg(90));
+		assert!(decoded.get_pixel(2, 2).0.iter().zip([255, 0, 0, 255]).all(|(&channel, expected)| channel.abs_diff(expected) <= 8));
+		assert!(decoded.get_pixel(12, 2).0.iter().all(|&channel| channel >= 247));
+		assert!(jpeg(10).len() < jpeg(100).len());
+
+		// Nothing is encoded for an image without any pixels
+		assert!(EncodePngNode { best_compression: ClonedNode(false) }.eval(ImageFrame::empty()).is_empty());
+	}
+}
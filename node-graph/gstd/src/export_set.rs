@@ -0,0 +1,181 @@
+//! Headless exporting of the export sets declared by Export Set nodes, writing every target of each set in one pass, such as the 1x, 2x, and 3x PNGs and the SVG of an icon.
+
+use crate::encode::encode_png;
+use crate::nine_patch::nine_patch_png;
+use crate::rasterize::rasterize_svg;
+use graphene_core::export::{ExportFileFormat, ExportSet};
+use graphene_core::renderer::{EmfRender, EpsRender, GraphicElementRendered, ImageRenderMode, PdfRender, RenderParams, RenderSvgSegmentList, Scene, SvgRender};
+use graphene_core::vector::style::ViewMode;
+use graphene_core::{GraphicElement, GraphicGroup};
+
+use glam::{DAffine2, DVec2};
+
+/// A file written for one of the targets of an export set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportedFile {
+	/// The name of the export set followed by the target's suffix and the format's extension, such as "icon@2x.png".
+	pub name: String,
+	pub format: ExportFileFormat,
+	pub data: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub enum ExportSetError {
+	/// The export set with this name has no content with bounds to frame.
+	Empty(String),
+	/// PNG targets need the `resvg` feature to rasterize their artwork.
+	RasterizationUnavailable(String),
+	/// The rasterized artwork of the target with this name couldn't be encoded into a PNG file.
+	Encoding(String),
+}
+
+impl core::fmt::Display for ExportSetError {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::Empty(name) => write!(f, "the export set \"{name}\" has no content to export"),
+			Self::RasterizationUnavailable(name) => write!(f, "can't write \"{name}\" because rasterization isn't available in this build"),
+			Self::Encoding(name) => write!(f, "can't encode \"{name}\" as a PNG file"),
+		}
+	}
+}
+
+impl std::error::Error for ExportSetError {}
+
+/// Writes the targets of every export set in the group, including those nested inside other groups and artboards, in the order they're found.
+pub fn export_all(graphic_group: &GraphicGroup) -> Result<Vec<ExportedFile>, ExportSetError> {
+	let mut files = Vec::new();
+	for (transform, group, export_set) in export_sets(graphic_group) {
+		let mut placed = GraphicGroup::EMPTY;
+		placed.transform = transform;
+		placed.push(GraphicElement::GraphicGroup(group.clone()));
+		files.extend(export(&placed, export_set)?);
+	}
+	Ok(files)
+}
+
+/// Writes each target of the export set for the content, framed snugly around its bounds.
+/// PNG targets of an export set with a nine-patch region get the border of a nine-patch, with the region scaled along with the artwork.
+pub fn export(content: &GraphicGroup, export_set: &ExportSet) -> Result<Vec<ExportedFile>, ExportSetError> {
+	let [min, max] = content.bounding_box(DAffine2::IDENTITY).ok_or_else(|| ExportSetError::Empty(export_set.name.clone()))?;
+	let render_params = RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, true, true);
+
+	export_set
+		.targets
+		.iter()
+		.map(|target| {
+			let name = export_set.file_name(target);
+			let size = (max - min) * target.scale;
+			let transform = DAffine2::from_scale(DVec2::splat(target.scale)) * DAffine2::from_translation(-min);
+			let data = match target.format {
+				ExportFileFormat::Svg => render_svg(content, &render_params, transform, size).into_bytes(),
+				ExportFileFormat::Png => {
+					let svg = render_svg(content, &render_params, transform, size);
+					let image = rasterize_svg(&svg, size.ceil().max(DVec2::ONE).as_uvec2()).ok_or_else(|| ExportSetError::RasterizationUnavailable(name.clone()))?;
+					let png = match export_set.nine_patch {
+						Some(insets) => nine_patch_png(&image, insets.map(|inset| (inset as f64 * target.scale).round() as u32)),
+						None => encode_png(&image, false),
+					};
+					png.map_err(|_| ExportSetError::Encoding(name.clone()))?
+				}
+				ExportFileFormat::Eps => {
+					let mut render = EpsRender::new(transform);
+					content.render_eps(&mut render, &render_params);
+					render.to_eps_document(size).into_bytes()
+				}
+				ExportFileFormat::Emf => {
+					let mut render = EmfRender::new(transform);
+					content.render_emf(&mut render, &render_params);
+					render.to_emf_document(size)
+				}
+				ExportFileFormat::Pdf => {
+					let mut render = PdfRender::new(transform);
+					content.render_pdf(&mut render, &render_params);
+					render.to_pdf_document(size)
+				}
+				ExportFileFormat::Json => serde_json::to_vec_pretty(&Scene::new(content, &render_params, transform, size)).expect("Failed to serialize the scene"),
+			};
+			Ok(ExportedFile { name, format: target.format, data })
+		})
+		.collect()
+}
+
+/// Finds the groups with export sets that have targets, along with the transform that places each in the space of the outermost group.
+fn export_sets(graphic_group: &GraphicGroup) -> Vec<(DAffine2, &GraphicGroup, &ExportSet)> {
+	fn collect<'a>(graphic_group: &'a GraphicGroup, transform: DAffine2, found: &mut Vec<(DAffine2, &'a GraphicGroup, &'a ExportSet)>) {
+		if let Some(export_set) = graphic_group.export_set.as_ref().filter(|export_set| !export_set.targets.is_empty()) {
+			found.push((transform, graphic_group, export_set));
+		}
+		let transform = transform * graphic_group.transform;
+		for element in graphic_group.iter() {
+			match element {
+				GraphicElement::GraphicGroup(graphic_group) => collect(graphic_group, transform, found),
+				GraphicElement::Artboard(artboard) => collect(&artboard.graphic_group, transform * DAffine2::from_translation(artboard.location.as_dvec2()), found),
+				_ => {}
+			}
+		}
+	}
+
+	let mut found = Vec::new();
+	collect(graphic_group, DAffine2::IDENTITY, &mut found);
+	found
+}
+
+/// Renders the content into a standalone SVG document of the given size, placed on it by the transform.
+fn render_svg(content: &GraphicGroup, render_params: &RenderParams, transform: DAffine2, size: DVec2) -> String {
+	let mut render = SvgRender::new();
+	content.render_svg(&mut render, render_params);
+	render.wrap_with_transform(transform, Some(size));
+	render.svg.to_svg_string()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use graphene_core::export::ExportTarget;
+	use graphene_core::vector::VectorData;
+
+	use bezier_rs::Subpath;
+
+	#[test]
+	fn export_every_target() {
+		let mut icon = GraphicGroup::from(VectorData::from_subpath(Subpath::new_rect(DVec2::new(10., 10.), DVec2::new(26., 26.))));
+		icon.export_set = Some(ExportSet {
+			name: "icon".to_string(),
+			targets: ExportTarget::parse_list("1x svg, 2x svg @2x, 1x eps").unwrap(),
+			..Default::default()
+		});
+		let mut document = GraphicGroup::EMPTY;
+		document.transform = DAffine2::from_translation(DVec2::new(100., 0.));
+		document.push(icon.into());
+
+		let files = export_all(&document).unwrap();
+		assert_eq!(files.iter().map(|file| file.name.as_str()).collect::<Vec<_>>(), ["icon.svg", "icon@2x.svg", "icon.eps"]);
+		let svg = String::from_utf8(files[1].data.clone()).unwrap();
+		assert!(svg.contains(r#"width="32" height="32""#));
+		let eps = String::from_utf8(files[2].data.clone()).unwrap();
+		assert!(eps.contains("%%BoundingBox: 0 0 16 16"));
+
+		assert!(export_all(&GraphicGroup::EMPTY).unwrap().is_empty());
+	}
+
+	#[cfg(feature = "resvg")]
+	#[test]
+	fn export_nine_patch() {
+		let mut button = GraphicGroup::from(VectorData::from_subpath(Subpath::new_rect(DVec2::new(10., 10.), DVec2::new(26., 26.))));
+		button.export_set = Some(ExportSet {
+			name: "button".to_string(),
+			targets: ExportTarget::parse_list("2x png @2x, 1x svg").unwrap(),
+			nine_patch: Some([4, 4, 4, 4]),
+		});
+
+		let files = export_all(&button).unwrap();
+		assert_eq!(files.iter().map(|file| file.name.as_str()).collect::<Vec<_>>(), ["button@2x.9.png", "button.svg"]);
+		let png = ::image::load_from_memory(&files[0].data).unwrap().to_rgba8();
+		assert_eq!(png.dimensions(), (34, 34));
+		// The region is scaled along with the artwork
+		let top = (0..34).filter(|&x| png.get_pixel(x, 0).0 == [0, 0, 0, 255]).collect::<Vec<_>>();
+		assert_eq!(top, (9..25).collect::<Vec<_>>());
+		assert_eq!(png.get_pixel(0, 8).0[3], 0);
+		assert_eq!(png.get_pixel(0, 9).0, [0, 0, 0, 255]);
+	}
+}
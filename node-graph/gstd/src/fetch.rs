@@ -0,0 +1,154 @@
+//! Nodes that download data from the web into the graph, for artwork driven by live data like images, JSON, or CSV documents.
+
+use graphene_core::Node;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Most bytes of downloaded data kept by [`FetchUrlNode`]s at once, beyond which the least recently used downloads are dropped.
+const MAX_FETCHED_BYTES: usize = 256 * 1024 * 1024;
+
+/// The data downloaded by [`FetchUrlNode`]s, kept by URL so that evaluating the graph again doesn't download it again.
+static FETCHED_URLS: Mutex<Option<FetchCache>> = Mutex::new(None);
+
+struct Fetched {
+	data: Arc<[u8]>,
+	downloaded: Instant,
+	used: Instant,
+}
+
+/// Downloads kept by URL, holding no more than the capacity in bytes by dropping the least recently used ones.
+struct FetchCache {
+	fetched: HashMap<String, Fetched>,
+	size: usize,
+	capacity: usize,
+}
+
+impl FetchCache {
+	fn new(capacity: usize) -> Self {
+		Self {
+			fetched: HashMap::new(),
+			size: 0,
+			capacity,
+		}
+	}
+
+	/// The data kept for the URL, unless it was downloaded longer than the max age ago, in which case it's dropped to be downloaded again.
+	fn get(&mut self, url: &str, max_age: Option<Duration>, now: Instant) -> Option<Arc<[u8]>> {
+		let fetched = self.fetched.get_mut(url)?;
+		if max_age.is_some_and(|max_age| now.saturating_duration_since(fetched.downloaded) >= max_age) {
+			self.remove(url);
+			return None;
+		}
+		fetched.used = now;
+		Some(fetched.data.clone())
+	}
+
+	/// Keeps the data for the URL, replacing what was kept for it before, unless it's larger than the whole capacity.
+	fn insert(&mut self, url: String, data: Arc<[u8]>, now: Instant) {
+		self.remove(&url);
+		if data.len() > self.capacity {
+			return;
+		}
+		while self.size + data.len() > self.capacity {
+			let Some(least_recent) = self.fetched.iter().min_by_key(|(_, fetched)| fetched.used).map(|(url, _)| url.clone()) else {
+				break;
+			};
+			self.remove(&least_recent);
+		}
+		self.size += data.len();
+		self.fetched.insert(url, Fetched { data, downloaded: now, used: now });
+	}
+
+	fn remove(&mut self, url: &str) {
+		if let Some(fetched) = self.fetched.remove(url) {
+			self.size -= fetched.data.len();
+		}
+	}
+}
+
+pub struct FetchUrlNode<Url, Refresh> {
+	url: Url,
+	refresh: Refresh,
+}
+
+/// Downloads the contents of the URL, along with a message describing why the download failed, if it did.
+/// Successful downloads are cached for the URL, and downloaded again once they're older than the refresh interval in seconds, if it's above 0.
+/// Failed ones give no data and are tried again the next time the graph is evaluated.
+#[node_macro::node_fn(FetchUrlNode)]
+async fn fetch_url(_input: (), url: String, refresh: f64) -> (Arc<[u8]>, String) {
+	let max_age = Duration::try_from_secs_f64(refresh).ok().filter(|max_age| !max_age.is_zero());
+	let cached = FETCHED_URLS
+		.lock()
+		.ok()
+		.and_then(|mut fetched| fetched.get_or_insert_with(|| FetchCache::new(MAX_FETCHED_BYTES)).get(&url, max_age, Instant::now()));
+	if let Some(data) = cached {
+		return (data, String::new());
+	}
+
+	match fetch(&url).await {
+		Ok(data) => {
+			if let Ok(mut fetched) = FETCHED_URLS.lock() {
+				fetched.get_or_insert_with(|| FetchCache::new(MAX_FETCHED_BYTES)).insert(url, data.clone(), Instant::now());
+			}
+			(data, String::new())
+		}
+		Err(error) => {
+			warn!("Failed to fetch {url}: {error}");
+			(Arc::from(Vec::new()), error)
+		}
+	}
+}
+
+async fn fetch(url: &str) -> Result<Arc<[u8]>, String> {
+	let url = reqwest::Url::parse(url).map_err(|error| format!("Invalid URL: {error}"))?;
+	let response = reqwest::get(url).await.map_err(|error| format!("Request failed: {error}"))?;
+
+	let status = response.status();
+	if !status.is_success() {
+		return Err(format!("The server responded with {status}"));
+	}
+
+	let data = response.bytes().await.map_err(|error| format!("Failed to download the response: {error}"))?;
+	Ok(Arc::from(data.as_ref()))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn fetch_invalid_url() {
+		let node = FetchUrlNode::new(graphene_core::value::ClonedNode::new("not a url".to_string()), graphene_core::value::ClonedNode::new(0.));
+		let (data, error) = futures::executor::block_on(node.eval(()));
+		assert!(data.is_empty());
+		assert!(error.starts_with("Invalid URL"));
+	}
+
+	#[test]
+	fn fetch_cache() {
+		let data = |length: usize| Arc::from(vec![0; length]);
+		let start = Instant::now();
+		let later = |seconds: u64| start + Duration::from_secs(seconds);
+		let mut cache = FetchCache::new(10);
+		cache.insert("a".to_string(), data(4), start);
+		cache.insert("b".to_string(), data(4), later(1));
+		assert!(cache.get("a", None, later(2)).is_some());
+
+		// Going over the capacity drops the least recently used download
+		cache.insert("c".to_string(), data(4), later(3));
+		assert!(cache.get("b", None, later(4)).is_none());
+		assert_eq!((cache.fetched.len(), cache.size), (2, 8));
+
+		// Downloads older than the max age are dropped to be downloaded again
+		assert!(cache.get("a", Some(Duration::from_secs(5)), later(4)).is_some());
+		assert!(cache.get("a", Some(Duration::from_secs(5)), later(5)).is_none());
+		assert_eq!((cache.fetched.len(), cache.size), (1, 4));
+
+		// Downloads larger than the capacity aren't kept
+		cache.insert("d".to_string(), data(11), later(6));
+		assert!(cache.get("d", None, later(6)).is_none());
+		assert!(cache.get("c", None, later(6)).is_some());
+	}
+}
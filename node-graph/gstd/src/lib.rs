@@ -17,12 +17,35 @@ pub mod gpu_nodes;
 #[cfg(feature = "quantization")]
 pub mod quantization;
 
+#[cfg(feature = "native")]
+pub mod watch_file;
+
+#[cfg(feature = "fetch")]
+pub mod fetch;
+
+#[cfg(feature = "native")]
+pub mod controller_input;
+
 pub use graphene_core::*;
 
 pub mod image_segmentation;
 
 pub mod image_color_palette;
 
+pub mod design_import;
+
+pub mod clipboard;
+
+pub mod export_set;
+
+pub mod atlas;
+
+pub mod rasterize;
+
+pub mod nine_patch;
+
+pub mod encode;
+
 pub mod brush;
 
 #[cfg(feature = "wasm")]
@@ -0,0 +1,111 @@
+//! Android nine-patch images, which mark the parts of a UI asset that stretch with a one pixel border of black lines so the asset can be resized without distorting its corners.
+
+use crate::encode::encode_png;
+use graphene_core::raster::Image;
+use graphene_core::{Color, GraphicGroup, Node};
+
+use image::ImageResult;
+
+#[derive(Debug, Clone, Copy)]
+pub struct NinePatchNode<Left, Top, Right, Bottom> {
+	left: Left,
+	top: Top,
+	right: Right,
+	bottom: Bottom,
+}
+
+/// Marks the region of the content that stretches, between the insets from each edge in pixels of the 1x artwork, so the PNG targets of its export set are written as nine-patches.
+/// Each of those files gets the border of a nine-patch and a name ending in ".9.png", which Android reads to resize the asset without distorting its corners.
+#[node_macro::node_fn(NinePatchNode)]
+fn nine_patch_node<Data: Into<GraphicGroup>>(data: Data, left: u32, top: u32, right: u32, bottom: u32) -> GraphicGroup {
+	let mut graphic_group = data.into();
+	graphic_group.export_set.get_or_insert_with(Default::default).nine_patch = Some([left, top, right, bottom]);
+	graphic_group
+}
+
+/// Adds the nine-patch border to the image, with the stretching region found between the `[left, top, right, bottom]` insets.
+/// At least one pixel in each direction is kept stretchable when the insets overlap.
+///
+/// Only the stretch lines along the top and left are drawn, so Android also places content within the stretching region.
+pub fn nine_patch(image: &Image<Color>, [left, top, right, bottom]: [u32; 4]) -> Image<Color> {
+	let (width, height) = (image.width + 2, image.height + 2);
+	let mut patch = Image::new(width, height, Color::TRANSPARENT);
+	if image.width == 0 || image.height == 0 {
+		return patch;
+	}
+
+	for y in 0..image.height {
+		let row = ((y + 1) * width + 1) as usize;
+		let source = (y * image.width) as usize;
+		patch.data[row..row + image.width as usize].copy_from_slice(&image.data[source..source + image.width as usize]);
+	}
+
+	let stretch = |length: u32, start: u32, end: u32| {
+		let start = start.min(length - 1);
+		start..length.saturating_sub(end).max(start + 1)
+	};
+	for x in stretch(image.width, left, right) {
+		patch.data[x as usize + 1] = Color::BLACK;
+	}
+	for y in stretch(image.height, top, bottom) {
+		patch.data[((y + 1) * width) as usize] = Color::BLACK;
+	}
+	patch
+}
+
+/// Encodes the image with its nine-patch border as a PNG, writing the pixels exactly so the border stays crisp, which it wouldn't if rendered through the document.
+pub fn nine_patch_png(image: &Image<Color>, insets: [u32; 4]) -> ImageResult<Vec<u8>> {
+	encode_png(&nine_patch(image, insets), false)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use graphene_core::export::ExportSetNode;
+	use graphene_core::raster::ImageFrame;
+	use graphene_core::value::ClonedNode;
+
+	#[test]
+	fn nine_patch_border() {
+		let image = Image::new(4, 3, Color::RED);
+		let patch = nine_patch(&image, [1, 1, 1, 1]);
+		assert_eq!((patch.width, patch.height), (6, 5));
+
+		let pixel = |x: usize, y: usize| patch.data[y * 6 + x];
+		let top = (0..6).map(|x| pixel(x, 0) == Color::BLACK).collect::<Vec<_>>();
+		assert_eq!(top, [false, false, true, true, false, false]);
+		let left = (0..5).map(|y| pixel(0, y) == Color::BLACK).collect::<Vec<_>>();
+		assert_eq!(left, [false, false, true, false, false]);
+		assert_eq!((pixel(1, 1), pixel(4, 3), pixel(5, 4)), (Color::RED, Color::RED, Color::TRANSPARENT));
+
+		// Insets reaching past each other still leave a pixel to stretch
+		let patch = nine_patch(&image, [3, 0, 3, 0]);
+		assert_eq!((0..6).filter(|&x| patch.data[x] == Color::BLACK).collect::<Vec<_>>(), [4]);
+
+		let png = ::image::load_from_memory(&nine_patch_png(&image, [1, 1, 1, 1]).unwrap()).unwrap().to_rgba8();
+		assert_eq!(png.get_pixel(2, 0).0, [0, 0, 0, 255]);
+		assert_eq!(png.get_pixel(1, 0).0[3], 0);
+	}
+
+	#[test]
+	fn nine_patch_export_set() {
+		let insets = |left, top, right, bottom| NinePatchNode {
+			left: ClonedNode(left),
+			top: ClonedNode(top),
+			right: ClonedNode(right),
+			bottom: ClonedNode(bottom),
+		};
+		let export_set = ExportSetNode {
+			name: ClonedNode("button".to_string()),
+			targets: ClonedNode("1x png, 2x png @2x".to_string()),
+		};
+
+		// The region is kept whether it's marked before or after the export set is declared
+		let marked = export_set.eval(insets(1, 2, 3, 4).eval(ImageFrame::<Color>::empty()));
+		assert_eq!(marked.export_set.as_ref().unwrap().nine_patch, Some([1, 2, 3, 4]));
+		let marked = insets(4, 3, 2, 1).eval(export_set.eval(ImageFrame::<Color>::empty()));
+		let export_set = marked.export_set.unwrap();
+		assert_eq!(export_set.nine_patch, Some([4, 3, 2, 1]));
+		assert_eq!(export_set.targets.len(), 2);
+	}
+}
@@ -0,0 +1,200 @@
+//! Rasterization of graphics on the CPU, drawing their SVG rendering into a pixel buffer with `resvg`, so vector output can feed nodes that work on images.
+
+use graphene_core::raster::{Image, ImageFrame};
+use graphene_core::renderer::{GraphicElementRendered, ImageRenderMode, RenderParams, SvgRender};
+use graphene_core::transform::Footprint;
+use graphene_core::vector::style::ViewMode;
+use graphene_core::{Color, Node};
+
+use core::future::Future;
+use glam::{DAffine2, UVec2};
+
+#[derive(Debug, Clone, Copy)]
+pub struct RasterizeNode<Data> {
+	data: Data,
+}
+
+/// Draws the graphics into an image covering the footprint at its resolution, so the area of the document that's visible becomes pixels that raster filters can work on.
+/// The image is placed over the same area of the document, so it lines up with the graphics it was drawn from. Needs the `resvg` feature, without which the image is empty.
+#[node_macro::node_fn(RasterizeNode)]
+async fn rasterize_node<Data: GraphicElementRendered, Fut: Future<Output = Data>>(footprint: Footprint, data: impl Node<Footprint, Output = Fut>) -> ImageFrame<Color> {
+	let data = self.data.eval(footprint).await;
+	rasterize(&data, footprint).unwrap_or_else(|| {
+		warn!("The graphics couldn't be rasterized so the image is left empty");
+		ImageFrame::empty()
+	})
+}
+
+/// Draws the graphics into an image of the footprint's resolution, viewing the document through the footprint's transform, and places the image over the area it covers.
+pub fn rasterize(data: &impl GraphicElementRendered, footprint: Footprint) -> Option<ImageFrame<Color>> {
+	if footprint.resolution.x == 0 || footprint.resolution.y == 0 || footprint.transform.matrix2.determinant() == 0. {
+		return None;
+	}
+
+	let mut render = SvgRender::new();
+	data.render_svg(&mut render, &RenderParams::new(ViewMode::Normal, ImageRenderMode::Base64, None, false, false, true));
+	render.wrap_with_transform(footprint.transform, Some(footprint.resolution.as_dvec2()));
+	let image = rasterize_svg(&render.svg.to_svg_string(), footprint.resolution)?;
+
+	Some(ImageFrame {
+		image,
+		transform: footprint.transform.inverse() * DAffine2::from_scale(footprint.resolution.as_dvec2()),
+		..Default::default()
+	})
+}
+
+/// Draws the SVG document into an image of the given size in pixels, with its colors no longer premultiplied by their alpha.
+#[cfg(feature = "resvg")]
+pub fn rasterize_svg(svg: &str, size: UVec2) -> Option<Image<Color>> {
+	let tree = resvg::usvg::Tree::from_str(svg, &resvg::usvg::Options::default()).ok()?;
+	rasterize_tree(&tree, size)
+}
+
+#[cfg(not(feature = "resvg"))]
+pub fn rasterize_svg(_svg: &str, _size: UVec2) -> Option<Image<Color>> {
+	None
+}
+
+/// Draws the SVG file into an image at the size the file gives itself, or none if it isn't an SVG file.
+#[cfg(feature = "resvg")]
+pub fn rasterize_svg_file(data: &[u8]) -> Option<Image<Color>> {
+	let tree = resvg::usvg::Tree::from_data(data, &resvg::usvg::Options::default()).ok()?;
+	let size = tree.size.to_int_size();
+	rasterize_tree(&tree, UVec2::new(size.width(), size.height()))
+}
+
+#[cfg(not(feature = "resvg"))]
+pub fn rasterize_svg_file(_data: &[u8]) -> Option<Image<Color>> {
+	None
+}
+
+/// Draws the parsed SVG document into an image of the given size in pixels, with its colors no longer premultiplied by their alpha.
+#[cfg(feature = "resvg")]
+pub fn rasterize_tree(tree: &resvg::usvg::Tree, size: UVec2) -> Option<Image<Color>> {
+	let mut pixmap = resvg::tiny_skia::Pixmap::new(size.x, size.y)?;
+	resvg::render(tree, resvg::tiny_skia::Transform::default(), &mut pixmap.as_mut());
+
+	let data = pixmap.pixels().iter().flat_map(|pixel| {
+		let color = pixel.demultiply();
+		[color.red(), color.green(), color.blue(), color.alpha()]
+	});
+	Some(Image::from_image_data(&data.collect::<Vec<_>>(), size.x, size.y))
+}
+
+#[cfg(all(test, feature = "resvg"))]
+mod test {
+	use super::*;
+	use graphene_core::vector::style::{Fill, FillRule, LineCap, LineJoin, Stroke};
+	use graphene_core::vector::VectorData;
+
+	use bezier_rs::Subpath;
+	use glam::DVec2;
+
+	#[test]
+	fn rasterize_at_footprint_resolution() {
+		let mut square = VectorData::from_subpath(Subpath::new_rect(DVec2::new(10., 10.), DVec2::new(20., 20.)));
+		square.style.set_fill(Fill::Solid(Color::RED));
+		let footprint = Footprint {
+			transform: DAffine2::from_scale(DVec2::splat(2.)),
+			resolution: UVec2::new(50, 40),
+			..Default::default()
+		};
+
+		let image_frame = rasterize(&square, footprint).unwrap();
+		assert_eq!((image_frame.image.width, image_frame.image.height), (50, 40));
+		let pixel = |x: usize, y: usize| image_frame.image.data[y * 50 + x];
+		assert_eq!(pixel(30, 30), Color::RED);
+		assert_eq!(pixel(10, 10).a(), 0.);
+		assert_eq!(pixel(45, 30).a(), 0.);
+
+		// The image covers the area of the document that the footprint views
+		assert!(image_frame.transform.transform_point2(DVec2::ONE).abs_diff_eq(DVec2::new(25., 20.), 1e-9));
+	}
+
+	#[test]
+	fn rasterize_fill_rule() {
+		let mut ring = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		ring.append_subpath(Subpath::<graphene_core::vector::PointId>::new_rect(DVec2::splat(2.), DVec2::splat(8.)));
+		ring.style.set_fill(Fill::Solid(Color::BLACK));
+		let footprint = Footprint {
+			resolution: UVec2::splat(10),
+			..Default::default()
+		};
+		let alpha = |ring: &VectorData, x: usize, y: usize| rasterize(ring, footprint).unwrap().image.data[y * 10 + x].a();
+
+		// Both subpaths wind the same way, so only the even-odd rule leaves a hole where they overlap
+		assert_eq!((alpha(&ring, 1, 1), alpha(&ring, 5, 5)), (1., 1.));
+		ring.style.set_fill_rule(FillRule::EvenOdd);
+		assert_eq!((alpha(&ring, 1, 1), alpha(&ring, 5, 5)), (1., 0.));
+	}
+
+	#[test]
+	fn rasterize_file_at_its_size() {
+		let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="2"><rect x="2" width="2" height="2" fill="red" /></svg>"#;
+		let image = rasterize_svg_file(svg.as_bytes()).unwrap();
+		assert_eq!((image.width, image.height), (4, 2));
+		assert_eq!((image.data[0].a(), image.data[3]), (0., Color::RED));
+
+		assert!(rasterize_svg_file(b"a,b").is_none());
+	}
+
+	/// Draws the graphics over the area from the origin to the size, at one pixel per unit, both from their SVG rendering and from their `usvg` tree, giving the alpha of every pixel from each.
+	fn coverage(data: &impl GraphicElementRendered, size: UVec2) -> [Vec<f32>; 2] {
+		let footprint = Footprint {
+			resolution: size,
+			..Default::default()
+		};
+		let from_svg = rasterize(data, footprint).unwrap().image.data.iter().map(|color| color.a()).collect();
+
+		let tree = data.to_usvg_tree(size, [DVec2::ZERO, size.as_dvec2()]);
+		let from_tree = rasterize_tree(&tree, size).unwrap().data.iter().map(|color| color.a()).collect();
+
+		[from_svg, from_tree]
+	}
+
+	#[test]
+	fn rasterize_stroke_properties() {
+		let stroked = |anchors: &[DVec2], stroke: Stroke| {
+			let mut vector_data = VectorData::from_subpath(Subpath::from_anchors(anchors.iter().copied(), false));
+			vector_data.style.set_stroke(stroke);
+			vector_data
+		};
+		let cap = |line_cap| stroked(&[DVec2::new(10., 10.), DVec2::new(40., 10.)], Stroke::new(Some(Color::BLACK), 8.).with_line_cap(line_cap));
+		let corner = |line_join, miter_limit| {
+			let stroke = Stroke::new(Some(Color::BLACK), 10.).with_line_join(line_join).with_line_join_miter_limit(miter_limit);
+			stroked(&[DVec2::new(70., 10.), DVec2::new(100., 10.), DVec2::new(100., 40.)], stroke)
+		};
+		let dashes = stroked(
+			&[DVec2::new(10., 70.), DVec2::new(90., 70.)],
+			Stroke::new(Some(Color::BLACK), 4.).with_dash_lengths("10").unwrap().with_dash_offset(-5.),
+		);
+
+		// The expected alpha of pixels, by their top left corner, that are either entirely covered or entirely missed
+		let golden: [(VectorData, &[((u32, u32), f32)]); 9] = [
+			// Caps reach past the end of the line, except for a butt cap, but only a square cap fills out the corners
+			(cap(LineCap::Butt), &[((42, 9), 0.), ((43, 13), 0.)]),
+			(cap(LineCap::Round), &[((42, 9), 1.), ((43, 13), 0.)]),
+			(cap(LineCap::Square), &[((42, 9), 1.), ((43, 13), 1.)]),
+			// A single length makes equal dashes and gaps, and the negative offset begins the line 5 units into a gap
+			(dashes, &[((12, 69), 0.), ((19, 69), 1.), ((29, 69), 0.), ((39, 69), 1.)]),
+			// Only a miter within its limit reaches the corner of a right angle, whose miter is √2 times the weight, and no limit is below 1
+			(corner(LineJoin::Miter, 4.), &[((104, 5), 1.), ((85, 7), 1.)]),
+			(corner(LineJoin::Miter, 1.2), &[((104, 5), 0.), ((85, 7), 1.)]),
+			(corner(LineJoin::Miter, 0.5), &[((104, 5), 0.), ((85, 7), 1.)]),
+			(corner(LineJoin::Bevel, 4.), &[((104, 5), 0.), ((85, 7), 1.)]),
+			(corner(LineJoin::Round, 4.), &[((104, 5), 0.), ((85, 7), 1.)]),
+		];
+		let size = UVec2::splat(120);
+		for (index, (vector_data, pixels)) in golden.iter().enumerate() {
+			for (renderer, alphas) in ["SVG", "usvg tree"].into_iter().zip(coverage(vector_data, size)) {
+				for &((x, y), expected) in pixels.iter() {
+					let alpha = alphas[(y * size.x + x) as usize];
+					assert!(
+						(alpha - expected).abs() < 0.01,
+						"case {index} drawn from the {renderer} has alpha {alpha} at ({x}, {y}) instead of {expected}"
+					);
+				}
+			}
+		}
+	}
+}
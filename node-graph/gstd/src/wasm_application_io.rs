@@ -2,7 +2,10 @@ use dyn_any::StaticType;
 use graphene_core::application_io::{ApplicationError, ApplicationIo, ExportFormat, RenderConfig, ResourceFuture, SurfaceHandle, SurfaceHandleFrame, SurfaceId};
 use graphene_core::raster::Image;
 use graphene_core::raster::{color::SRGBA8, ImageFrame};
-use graphene_core::renderer::{format_transform_matrix, GraphicElementRendered, ImageRenderMode, RenderParams, RenderSvgSegmentList, SvgRender};
+#[cfg(all(feature = "vello", target_arch = "wasm32"))]
+use graphene_core::renderer::VelloRender;
+use graphene_core::renderer::{format_transform_matrix, GraphicElementRendered, ImageRenderMode, RenderParams, RenderSvgSegmentList, RenderedLayer, SvgRender};
+use graphene_core::renderer::{min_scale, EmfRender, EpsRender, PdfRender, Quad, Scene};
 use graphene_core::transform::Footprint;
 use graphene_core::Color;
 use graphene_core::Node;
@@ -10,6 +13,7 @@ use graphene_core::Node;
 use wgpu_executor::WgpuExecutor;
 
 use core::future::Future;
+use glam::DVec2;
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Object, Reflect};
 use std::cell::RefCell;
@@ -283,7 +287,7 @@ fn decode_image_node<'a: 'input>(data: Arc<[u8]>) -> ImageFrame<Color> {
 	};
 	image
 }
-pub use graph_craft::document::value::RenderOutput;
+pub use graph_craft::document::value::{RenderOutput, SvgLayer};
 pub struct RenderNode<Data, Surface, Parameter> {
 	data: Data,
 	#[cfg(any(feature = "resvg", feature = "vello"))]
@@ -293,6 +297,14 @@ pub struct RenderNode<Data, Surface, Parameter> {
 	parameter: PhantomData<Parameter>,
 }
 
+/// The area of the document visible in the viewport, outside of which elements are skipped while rendering. Exports are rendered in full.
+fn viewport_culling_bounds(footprint: Footprint, for_export: bool) -> Option<[DVec2; 2]> {
+	if for_export || footprint.transform.matrix2.determinant() == 0. {
+		return None;
+	}
+	Some((footprint.transform.inverse() * Quad::from_box([DVec2::ZERO, footprint.resolution.as_dvec2()])).bounding_box())
+}
+
 fn render_svg(data: impl GraphicElementRendered, mut render: SvgRender, render_params: RenderParams, footprint: Footprint) -> RenderOutput {
 	if !data.contains_artboard() && !render_params.hide_artboards {
 		render.leaf_tag("rect", |attributes| {
@@ -311,8 +323,78 @@ fn render_svg(data: impl GraphicElementRendered, mut render: SvgRender, render_p
 	RenderOutput::Svg(render.svg.to_svg_string())
 }
 
+fn render_eps(data: impl GraphicElementRendered, render_params: RenderParams, footprint: Footprint) -> RenderOutput {
+	let mut render = EpsRender::new(footprint.transform);
+	if !data.contains_artboard() && !render_params.hide_artboards {
+		render.fill_page(footprint.resolution.as_dvec2(), Color::WHITE);
+	}
+	data.render_eps(&mut render, &render_params);
+
+	RenderOutput::Eps(render.to_eps_document(footprint.resolution.as_dvec2()))
+}
+
+fn render_emf(data: impl GraphicElementRendered, render_params: RenderParams, footprint: Footprint) -> RenderOutput {
+	let mut render = EmfRender::new(footprint.transform);
+	if !data.contains_artboard() && !render_params.hide_artboards {
+		render.fill_page(footprint.resolution.as_dvec2(), Color::WHITE);
+	}
+	data.render_emf(&mut render, &render_params);
+
+	RenderOutput::Emf(render.to_emf_document(footprint.resolution.as_dvec2()))
+}
+
+fn render_pdf(data: impl GraphicElementRendered, render_params: RenderParams, footprint: Footprint) -> RenderOutput {
+	let mut render = PdfRender::new(footprint.transform);
+	if !data.contains_artboard() && !render_params.hide_artboards {
+		render.fill_page(footprint.resolution.as_dvec2(), Color::WHITE);
+	}
+	data.render_pdf(&mut render, &render_params);
+
+	RenderOutput::Pdf(render.to_pdf_document(footprint.resolution.as_dvec2()))
+}
+
+fn render_json(data: impl GraphicElementRendered, render_params: RenderParams, footprint: Footprint) -> RenderOutput {
+	let scene = Scene::new(&data, &render_params, footprint.transform, footprint.resolution.as_dvec2());
+
+	RenderOutput::Json(serde_json::to_string_pretty(&scene).expect("Failed to serialize the scene"))
+}
+
+fn render_svg_layers(data: impl GraphicElementRendered, render_params: RenderParams, footprint: Footprint) -> RenderOutput {
+	let mut layers = Vec::new();
+	if !data.contains_artboard() && !render_params.hide_artboards {
+		let mut render = SvgRender::new();
+		render.leaf_tag("rect", |attributes| {
+			attributes.push("x", "0");
+			attributes.push("y", "0");
+			attributes.push("width", footprint.resolution.x.to_string());
+			attributes.push("height", footprint.resolution.y.to_string());
+			attributes.push("transform", format_transform_matrix(footprint.transform.inverse()));
+			attributes.push("fill", "white");
+		});
+		layers.push(RenderedLayer {
+			name: "Background".to_string(),
+			render,
+			alpha_blending: Default::default(),
+		});
+	}
+	layers.extend(data.render_svg_layers(&render_params));
+
+	let layers = layers
+		.into_iter()
+		.map(|RenderedLayer { name, mut render, alpha_blending }| {
+			render.wrap_with_transform(footprint.transform, Some(footprint.resolution.as_dvec2()));
+			SvgLayer {
+				name,
+				svg: render.svg.to_svg_string(),
+				alpha_blending,
+			}
+		})
+		.collect();
+	RenderOutput::SvgLayers(layers)
+}
+
 #[cfg(any(feature = "resvg", feature = "vello"))]
-fn render_canvas(
+async fn render_canvas(
 	data: impl GraphicElementRendered,
 	mut render: SvgRender,
 	render_params: RenderParams,
@@ -321,6 +403,17 @@ fn render_canvas(
 	surface_handle: Arc<SurfaceHandle<HtmlCanvasElement>>,
 ) -> RenderOutput {
 	let resolution = footprint.resolution;
+	let canvas = &surface_handle.surface;
+	canvas.set_width(resolution.x);
+	canvas.set_height(resolution.y);
+	if render_canvas_on_gpu(&data, &render_params, footprint, &editor, canvas).await {
+		let frame = SurfaceHandleFrame {
+			surface_handle,
+			transform: glam::DAffine2::IDENTITY,
+		};
+		return RenderOutput::CanvasFrame(frame.into());
+	}
+
 	data.render_svg(&mut render, &render_params);
 	// TODO: reenable once we switch to full node graph
 	let min = footprint.transform.inverse().transform_point2((0., 0.).into());
@@ -328,22 +421,15 @@ fn render_canvas(
 	render.format_svg(min, max);
 	let string = render.svg.to_svg_string();
 	let array = string.as_bytes();
-	let canvas = &surface_handle.surface;
-	canvas.set_width(resolution.x);
-	canvas.set_height(resolution.y);
 	let usvg_tree = data.to_usvg_tree(resolution, [min, max]);
 
-	if let Some(exec) = editor.application_io.gpu_executor() {
-		todo!()
-	} else {
-		let pixmap_size = usvg_tree.size.to_int_size();
-		let mut pixmap = resvg::tiny_skia::Pixmap::new(pixmap_size.width(), pixmap_size.height()).unwrap();
-		resvg::render(&usvg_tree, resvg::tiny_skia::Transform::default(), &mut pixmap.as_mut());
-		let array: Clamped<&[u8]> = Clamped(pixmap.data());
-		let context = canvas.get_context("2d").unwrap().unwrap().dyn_into::<CanvasRenderingContext2d>().unwrap();
-		let image_data = web_sys::ImageData::new_with_u8_clamped_array_and_sh(array, pixmap_size.width(), pixmap_size.height()).expect("Failed to construct ImageData");
-		context.put_image_data(&image_data, 0.0, 0.0).unwrap();
-	}
+	let pixmap_size = usvg_tree.size.to_int_size();
+	let mut pixmap = resvg::tiny_skia::Pixmap::new(pixmap_size.width(), pixmap_size.height()).unwrap();
+	resvg::render(&usvg_tree, resvg::tiny_skia::Transform::default(), &mut pixmap.as_mut());
+	let array: Clamped<&[u8]> = Clamped(pixmap.data());
+	let context = canvas.get_context("2d").unwrap().unwrap().dyn_into::<CanvasRenderingContext2d>().unwrap();
+	let image_data = web_sys::ImageData::new_with_u8_clamped_array_and_sh(array, pixmap_size.width(), pixmap_size.height()).expect("Failed to construct ImageData");
+	context.put_image_data(&image_data, 0.0, 0.0).unwrap();
 	/*
 	let preamble = "data:image/svg+xml;base64,";
 	let mut base64_string = String::with_capacity(preamble.len() + array.len() * 4);
@@ -362,6 +448,30 @@ fn render_canvas(
 	RenderOutput::CanvasFrame(frame.into())
 }
 
+/// Encodes the data into a vello scene and renders it onto the canvas on the GPU, which is much faster than rasterizing it on the CPU for documents with many shapes.
+/// Returns false when there's no GPU to render with, leaving the canvas to be drawn on the CPU. Failures are logged and leave the canvas blank, since a canvas
+/// that's been given to the GPU can't be drawn into by the CPU.
+#[cfg(all(feature = "vello", target_arch = "wasm32"))]
+async fn render_canvas_on_gpu(data: &impl GraphicElementRendered, render_params: &RenderParams, footprint: Footprint, editor: &WasmEditorApi<'_>, canvas: &HtmlCanvasElement) -> bool {
+	let Some(executor) = editor.application_io.gpu_executor() else { return false };
+
+	let mut render = VelloRender::new(footprint.transform, footprint.resolution.as_dvec2());
+	if !data.contains_artboard() && !render_params.hide_artboards {
+		render.fill_page(Color::WHITE);
+	}
+	data.render_vello(&mut render, render_params);
+
+	if let Err(error) = executor.render_vello_scene(render.scene(), canvas, footprint.resolution.x, footprint.resolution.y).await {
+		error!("{error}");
+	}
+	true
+}
+
+#[cfg(all(any(feature = "resvg", feature = "vello"), not(all(feature = "vello", target_arch = "wasm32"))))]
+async fn render_canvas_on_gpu(_data: &impl GraphicElementRendered, _render_params: &RenderParams, _footprint: Footprint, _editor: &WasmEditorApi<'_>, _canvas: &HtmlCanvasElement) -> bool {
+	false
+}
+
 // Render with the data node taking in Footprint.
 impl<'input, 'a: 'input, T: 'input + GraphicElementRendered, F: 'input + Future<Output = T>, Data: 'input, Surface: 'input, SurfaceFuture: 'input> Node<'input, WasmEditorApi<'a>>
 	for RenderNode<Data, Surface, Footprint>
@@ -378,13 +488,21 @@ where
 			let footprint = editor.render_config.viewport;
 
 			let RenderConfig { hide_artboards, for_export, .. } = editor.render_config;
-			let render_params = RenderParams::new(editor.render_config.view_mode, ImageRenderMode::Base64, None, false, hide_artboards, for_export);
+			let culling_bounds = viewport_culling_bounds(footprint, for_export);
+			let mut render_params = RenderParams::new(editor.render_config.view_mode, ImageRenderMode::Base64, culling_bounds, false, hide_artboards, for_export);
+			render_params.channel = editor.render_config.channel.clone();
+			render_params.culling_pixel_size = 1. / min_scale(footprint.transform);
 
 			let output_format = editor.render_config.export_format;
 			match output_format {
 				ExportFormat::Svg => render_svg(self.data.eval(footprint).await, SvgRender::new(), render_params, footprint),
+				ExportFormat::SvgLayers => render_svg_layers(self.data.eval(footprint).await, render_params, footprint),
+				ExportFormat::Eps => render_eps(self.data.eval(footprint).await, render_params, footprint),
+				ExportFormat::Emf => render_emf(self.data.eval(footprint).await, render_params, footprint),
+				ExportFormat::Pdf => render_pdf(self.data.eval(footprint).await, render_params, footprint),
+				ExportFormat::Json => render_json(self.data.eval(footprint).await, render_params, footprint),
 				#[cfg(any(feature = "resvg", feature = "vello"))]
-				ExportFormat::Canvas => render_canvas(self.data.eval(footprint).await, SvgRender::new(), render_params, footprint, editor, self.surface_handle.eval(()).await),
+				ExportFormat::Canvas => render_canvas(self.data.eval(footprint).await, SvgRender::new(), render_params, footprint, editor, self.surface_handle.eval(()).await).await,
 				_ => todo!("Non-SVG render output for {output_format:?}"),
 			}
 		})
@@ -406,13 +524,21 @@ where
 			let footprint = editor.render_config.viewport;
 
 			let RenderConfig { hide_artboards, for_export, .. } = editor.render_config;
-			let render_params = RenderParams::new(editor.render_config.view_mode, ImageRenderMode::Base64, None, false, hide_artboards, for_export);
+			let culling_bounds = viewport_culling_bounds(footprint, for_export);
+			let mut render_params = RenderParams::new(editor.render_config.view_mode, ImageRenderMode::Base64, culling_bounds, false, hide_artboards, for_export);
+			render_params.channel = editor.render_config.channel.clone();
+			render_params.culling_pixel_size = 1. / min_scale(footprint.transform);
 
 			let output_format = editor.render_config.export_format;
 			match output_format {
 				ExportFormat::Svg => render_svg(self.data.eval(()).await, SvgRender::new(), render_params, footprint),
+				ExportFormat::SvgLayers => render_svg_layers(self.data.eval(()).await, render_params, footprint),
+				ExportFormat::Eps => render_eps(self.data.eval(()).await, render_params, footprint),
+				ExportFormat::Emf => render_emf(self.data.eval(()).await, render_params, footprint),
+				ExportFormat::Pdf => render_pdf(self.data.eval(()).await, render_params, footprint),
+				ExportFormat::Json => render_json(self.data.eval(()).await, render_params, footprint),
 				#[cfg(any(feature = "resvg", feature = "vello"))]
-				ExportFormat::Canvas => render_canvas(self.data.eval(()).await, SvgRender::new(), render_params, footprint, editor, self.surface_handle.eval(()).await),
+				ExportFormat::Canvas => render_canvas(self.data.eval(()).await, SvgRender::new(), render_params, footprint, editor, self.surface_handle.eval(()).await).await,
 				_ => todo!("Non-SVG render output for {output_format:?}"),
 			}
 		})
@@ -0,0 +1,127 @@
+//! Nodes that read files from disk and read them again whenever they're changed by another program, for live round-trips with external tools.
+//!
+//! Each watched file is kept in memory along with its modification time. The node runtime calls [`poll_changes`] between graph evaluations,
+//! and when it reports a change, the runtime rebuilds the graph so that any cached results downstream of the file are evaluated again.
+
+use crate::rasterize::rasterize_svg_file;
+use graphene_core::raster::{Image, ImageFrame};
+use graphene_core::{Color, Node};
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+struct WatchedFile {
+	path: PathBuf,
+	modified: Option<SystemTime>,
+	data: Arc<[u8]>,
+}
+
+/// The files read by [`WatchFileNode`]s, which are checked for changes by [`poll_changes`].
+static WATCHED_FILES: Mutex<Vec<WatchedFile>> = Mutex::new(Vec::new());
+
+fn modified(path: &Path) -> Option<SystemTime> {
+	std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+fn read(path: &Path) -> Arc<[u8]> {
+	match std::fs::read(path) {
+		Ok(data) => Arc::from(data),
+		Err(error) => {
+			warn!("Failed to read the watched file {}: {error}", path.display());
+			Arc::from(Vec::new())
+		}
+	}
+}
+
+/// Reads the watched files that have been modified since they were last read, returning whether any were.
+pub fn poll_changes() -> bool {
+	let Ok(mut watched_files) = WATCHED_FILES.lock() else { return false };
+
+	let mut changed = false;
+	for file in watched_files.iter_mut() {
+		let modified = modified(&file.path);
+		if modified != file.modified {
+			file.modified = modified;
+			file.data = read(&file.path);
+			changed = true;
+		}
+	}
+	changed
+}
+
+pub struct WatchFileNode<Path> {
+	path: Path,
+}
+
+/// Reads the contents of the file at the path, which is then watched so the graph is evaluated again with the new contents whenever the file is saved.
+/// A missing or unreadable file gives no data.
+#[node_macro::node_fn(WatchFileNode)]
+fn watch_file(_input: (), path: String) -> Arc<[u8]> {
+	let path = PathBuf::from(path);
+	let Ok(mut watched_files) = WATCHED_FILES.lock() else { return read(&path) };
+
+	if let Some(file) = watched_files.iter().find(|file| file.path == path) {
+		return file.data.clone();
+	}
+
+	let file = WatchedFile {
+		modified: modified(&path),
+		data: read(&path),
+		path,
+	};
+	let data = file.data.clone();
+	watched_files.push(file);
+	data
+}
+
+pub struct DecodeFileImageNode;
+
+/// Decodes the contents of an image file, including SVGs at their own size when SVG rendering is available.
+/// Unlike the regular image decoding, data that isn't a whole image gives an empty image, since an external tool may still be part way through writing the file.
+#[node_macro::node_fn(DecodeFileImageNode)]
+fn decode_file_image(data: Arc<[u8]>) -> ImageFrame<Color> {
+	let image = match image::load_from_memory(data.as_ref()) {
+		Ok(image) => {
+			let image = image.to_rgba8();
+			Image::from_image_data(image.as_raw(), image.width(), image.height())
+		}
+		Err(_) => rasterize_svg_file(data.as_ref()).unwrap_or_default(),
+	};
+
+	ImageFrame { image, ..Default::default() }
+}
+
+pub struct DecodeFileTextNode;
+
+/// Decodes the contents of a text file, such as a CSV table or an SVG document, replacing any invalid UTF-8 with the replacement character.
+#[node_macro::node_fn(DecodeFileTextNode)]
+fn decode_file_text(data: Arc<[u8]>) -> String {
+	String::from_utf8_lossy(data.as_ref()).into_owned()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn reload_changed_file() {
+		let path = std::env::temp_dir().join(format!("graphite-watch-file-{}.csv", std::process::id()));
+		std::fs::write(&path, "a,b").unwrap();
+
+		let node = WatchFileNode::new(graphene_core::value::ClonedNode::new(path.to_string_lossy().into_owned()));
+		assert_eq!(DecodeFileTextNode::new().eval(node.eval(())), "a,b");
+
+		// Move the modification time forward so the change is seen even on file systems with coarse timestamps
+		std::fs::write(&path, "c,d").unwrap();
+		let file = std::fs::File::options().write(true).open(&path).unwrap();
+		file.set_modified(SystemTime::now() + std::time::Duration::from_secs(10)).unwrap();
+		drop(file);
+
+		assert!(poll_changes());
+		assert!(!poll_changes());
+		assert_eq!(DecodeFileTextNode::new().eval(node.eval(())), "c,d");
+
+		std::fs::remove_file(&path).unwrap();
+	}
+}
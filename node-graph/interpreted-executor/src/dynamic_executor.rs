@@ -1,11 +1,12 @@
 use crate::node_registry;
+use crate::placeholder::{PlaceholderOnFailureNode, PlaceholderOutput};
 
 use dyn_any::StaticType;
 use graph_craft::document::value::{TaggedValue, UpcastNode};
 use graph_craft::document::{NodeId, Source};
 use graph_craft::graphene_compiler::Executor;
+use graph_craft::proto::{node_result, GraphErrorType, GraphErrors};
 use graph_craft::proto::{ConstructionArgs, GraphError, LocalFuture, NodeContainer, ProtoNetwork, ProtoNode, SharedNodeContainer, TypeErasedBox, TypingContext};
-use graph_craft::proto::{GraphErrorType, GraphErrors};
 use graph_craft::Type;
 
 use std::collections::{HashMap, HashSet};
@@ -174,7 +175,12 @@ impl BorrowTree {
 	pub async fn eval_tagged_value<'i, I: StaticType + 'i>(&'i self, id: NodeId, input: I) -> Result<TaggedValue, String> {
 		let node = self.nodes.get(&id).cloned().ok_or("Output node not found in executor")?;
 		let output = node.eval(Box::new(input));
-		TaggedValue::try_from_any(output.await)
+		let result = node_result(output.await).map_err(|error| error.to_string()).and_then(TaggedValue::try_from_any);
+		#[cfg(feature = "tracing")]
+		if let Err(error) = &result {
+			tracing::error!(id = id.0, "Failed to read the output of the graph: {error}");
+		}
+		result
 	}
 
 	pub fn free_node(&mut self, id: NodeId) {
@@ -200,6 +206,8 @@ impl BorrowTree {
 			ConstructionArgs::Value(value) => {
 				let upcasted = UpcastNode::new(value.to_owned());
 				let node = Box::new(upcasted) as TypeErasedBox<'_>;
+				#[cfg(feature = "tracing")]
+				let node = Box::new(crate::trace::TracedNode::new(node, id, &proto_node)) as TypeErasedBox<'_>;
 				let node = NodeContainer::new(node);
 				self.store_node(node, id);
 			}
@@ -207,8 +215,18 @@ impl BorrowTree {
 			ConstructionArgs::Nodes(ids) => {
 				let ids: Vec<_> = ids.iter().map(|(id, _)| *id).collect();
 				let construction_nodes = self.node_deps(&ids);
-				let constructor = typing_context.constructor(id).ok_or_else(|| vec![GraphError::new(&proto_node, GraphErrorType::NoConstructor)])?;
+				let constructor = typing_context.constructor(id).ok_or_else(|| {
+					#[cfg(feature = "tracing")]
+					tracing::error!(node = %proto_node.identifier.name, id = id.0, "No constructor found for the node");
+					vec![GraphError::new(&proto_node, GraphErrorType::NoConstructor)]
+				})?;
 				let node = constructor(construction_nodes).await;
+				let node = match typing_context.type_of(id).and_then(|types| PlaceholderOutput::for_output(&types.output)) {
+					Some(output) => Box::new(PlaceholderOnFailureNode::new(node, output, proto_node.identifier.name.to_string())) as TypeErasedBox<'_>,
+					None => node,
+				};
+				#[cfg(feature = "tracing")]
+				let node = Box::new(crate::trace::TracedNode::new(node, id, &proto_node)) as TypeErasedBox<'_>;
 				let node = NodeContainer::new(node);
 				self.store_node(node, id);
 			}
@@ -242,4 +260,45 @@ mod test {
 		let result = futures::executor::block_on(tree.eval(NodeId(0), ()));
 		assert_eq!(result, Some(2u32));
 	}
+
+	#[test]
+	fn failed_node_outputs_placeholder() {
+		use graph_craft::proto::{Any, NodeError};
+		use graphene_core::vector::VectorData;
+		use graphene_core::{concrete, GraphicGroup};
+		use graphene_std::any::{DynAnyNode, FutureWrapperNode};
+
+		assert_eq!(PlaceholderOutput::for_output(&Type::Future(Box::new(concrete!(GraphicGroup)))), Some(PlaceholderOutput::GraphicGroup));
+		assert_eq!(PlaceholderOutput::for_output(&concrete!(u32)), None);
+
+		let empty = || DynAnyNode::<(), VectorData, _>::new(FutureWrapperNode::new(graphene_core::generic::FnNode::new(|_: ()| VectorData::empty())));
+		fn eval<'i>(node: &'i TypeErasedBox<'static>, input: Any<'i>) -> Any<'i> {
+			futures::executor::block_on(node.eval(input))
+		}
+
+		// An input of the wrong type, or a node upstream failing, is reported as the node failing rather than panicking
+		let node = Box::new(empty()) as TypeErasedBox<'static>;
+		assert!(node_result(eval(&node, Box::new(()))).is_ok());
+		let error = node_result(eval(&node, Box::new(1_u32))).err().unwrap();
+		assert!(error.0.starts_with("DynAnyNode Input"));
+		assert_eq!(node_result(eval(&node, Box::new(NodeError("Upstream".to_string())))).err(), Some(NodeError("Upstream".to_string())));
+
+		// Which is drawn as a placeholder for a graphical node
+		let node = Box::new(PlaceholderOnFailureNode::new(Box::new(empty()), PlaceholderOutput::VectorData, "Empty".to_string())) as TypeErasedBox<'static>;
+		assert_eq!(dyn_any::downcast::<VectorData>(eval(&node, Box::new(()))).unwrap().stroke_bezier_paths().count(), 0);
+		let placeholder = dyn_any::downcast::<VectorData>(eval(&node, Box::new(1_u32))).unwrap();
+		// A box crossed by its two diagonals
+		assert_eq!(placeholder.stroke_bezier_paths().count(), 3);
+		assert_eq!(placeholder.bounding_box(), Some([glam::DVec2::ZERO, glam::DVec2::splat(100.)]));
+	}
+
+	#[cfg(feature = "tracing")]
+	#[test]
+	fn traced_node_passes_output_through() {
+		let mut tree = BorrowTree::default();
+		let value = ProtoNode::value(ConstructionArgs::Value(TaggedValue::String("traced".to_string())), vec![]);
+		futures::executor::block_on(tree.push_node(NodeId(0), value, &TypingContext::default())).unwrap();
+		let result = futures::executor::block_on(tree.eval_tagged_value(NodeId(0), ()));
+		assert_eq!(result, Ok(TaggedValue::String("traced".to_string())));
+	}
 }
@@ -1,5 +1,8 @@
 pub mod dynamic_executor;
 pub mod node_registry;
+pub mod placeholder;
+#[cfg(feature = "tracing")]
+pub mod trace;
 
 #[cfg(test)]
 mod tests {
@@ -9,7 +9,7 @@ use graphene_core::structural::Then;
 use graphene_core::transform::Footprint;
 use graphene_core::value::{ClonedNode, CopiedNode, ValueNode};
 use graphene_core::vector::brush_stroke::BrushStroke;
-use graphene_core::vector::VectorData;
+use graphene_core::vector::{VectorData, VectorInstances};
 use graphene_core::{application_io::SurfaceHandle, SurfaceFrame, WasmSurfaceHandleFrame};
 use graphene_core::{concrete, generic, Artboard, GraphicGroup};
 use graphene_core::{fn_type, raster::*};
@@ -287,6 +287,7 @@ fn node_registry() -> HashMap<ProtoNodeIdentifier, HashMap<NodeIOTypes, NodeCons
 		async_node!(graphene_core::ops::IntoNode<_, ImageFrame<Color>>, input: ImageFrame<SRGBA8>, output: ImageFrame<Color>, params: []),
 		async_node!(graphene_core::ops::IntoNode<_, GraphicGroup>, input: ImageFrame<Color>, output: GraphicGroup, params: []),
 		async_node!(graphene_core::ops::IntoNode<_, GraphicGroup>, input: VectorData, output: GraphicGroup, params: []),
+		async_node!(graphene_core::ops::IntoNode<_, GraphicGroup>, input: VectorInstances, output: GraphicGroup, params: []),
 		async_node!(graphene_core::ops::IntoNode<_, GraphicGroup>, input: GraphicGroup, output: GraphicGroup, params: []),
 		async_node!(graphene_core::ops::IntoNode<_, GraphicGroup>, input: Artboard, output: GraphicGroup, params: []),
 		#[cfg(feature = "gpu")]
@@ -449,6 +450,7 @@ fn node_registry() -> HashMap<ProtoNodeIdentifier, HashMap<NodeIOTypes, NodeCons
 		)],
 		register_node!(graphene_std::brush::IntoIterNode<_>, input: &Vec<BrushStroke>, params: []),
 		async_node!(graphene_std::brush::BrushNode<_, _, _>, input: ImageFrame<Color>, output: ImageFrame<Color>, params: [ImageFrame<Color>, Vec<BrushStroke>, BrushCache]),
+		register_node!(graphene_std::brush::BrushAlongPathNode<_, _, _, _, _, _, _, _>, input: VectorData, params: [ImageFrame<Color>, f64, f64, f64, f64, u32, String, bool]),
 		// Filters
 		raster_node!(graphene_core::raster::LuminanceNode<_>, params: [LuminanceCalculation]),
 		raster_node!(graphene_core::raster::ExtractChannelNode<_>, params: [RedGreenBlue]),
@@ -457,10 +459,25 @@ fn node_registry() -> HashMap<ProtoNodeIdentifier, HashMap<NodeIOTypes, NodeCons
 		raster_node!(graphene_core::raster::LevelsNode<_, _, _, _, _>, params: [f64, f64, f64, f64, f64]),
 		register_node!(graphene_std::image_segmentation::ImageSegmentationNode<_>, input: ImageFrame<Color>, params: [ImageFrame<Color>]),
 		register_node!(graphene_std::image_color_palette::ImageColorPaletteNode<_>, input: ImageFrame<Color>, params: [u32]),
+		register_node!(graphene_std::design_import::DesignImportNode<_>, input: (), params: [String]),
+		#[cfg(feature = "native")]
+		register_node!(graphene_std::watch_file::WatchFileNode<_>, input: (), params: [String]),
+		#[cfg(feature = "native")]
+		register_node!(graphene_std::watch_file::DecodeFileImageNode, input: Arc<[u8]>, params: []),
+		#[cfg(feature = "native")]
+		register_node!(graphene_std::watch_file::DecodeFileTextNode, input: Arc<[u8]>, params: []),
+		#[cfg(feature = "fetch")]
+		async_node!(graphene_std::fetch::FetchUrlNode<_, _>, input: (), output: (Arc<[u8]>, String), params: [String, f64]),
+		#[cfg(feature = "fetch")]
+		register_node!(graphene_core::ops::FirstOfPairNode, input: (Arc<[u8]>, String), params: []),
+		#[cfg(feature = "fetch")]
+		register_node!(graphene_core::ops::SecondOfPairNode, input: (Arc<[u8]>, String), params: []),
 		register_node!(graphene_core::raster::IndexNode<_>, input: Vec<ImageFrame<Color>>, params: [u32]),
 		register_node!(graphene_core::raster::adjustments::ColorFillNode<_>, input: ImageFrame<Color>, params: [Color]),
 		register_node!(graphene_core::raster::adjustments::ColorOverlayNode<_, _, _>, input: ImageFrame<Color>, params: [Color, BlendMode, f64]),
 		register_node!(graphene_core::raster::IndexNode<_>, input: Vec<Color>, params: [u32]),
+		register_node!(graphene_core::raster::palette::ColorHarmonyNode<_, _, _>, input: Color, params: [graphene_core::raster::palette::ColorHarmony, u32, f64]),
+		register_node!(graphene_core::raster::palette::PaletteColorNode<_>, input: Vec<Color>, params: [f64]),
 		vec![(
 			ProtoNodeIdentifier::new("graphene_core::raster::BlendNode<_, _, _, _>"),
 			|args| {
@@ -677,6 +694,7 @@ fn node_registry() -> HashMap<ProtoNodeIdentifier, HashMap<NodeIOTypes, NodeCons
 		register_node!(graphene_core::ops::CloneNode<_>, input: &QuantizationChannels, params: []),
 		async_node!(graphene_std::wasm_application_io::RenderNode<_, _, _>, input: WasmEditorApi, output: RenderOutput, fn_params: [Footprint => ImageFrame<Color>, () => Arc<WasmSurfaceHandle>]),
 		async_node!(graphene_std::wasm_application_io::RenderNode<_, _, _>, input: WasmEditorApi, output: RenderOutput, fn_params: [Footprint => VectorData, () => Arc<WasmSurfaceHandle>]),
+		async_node!(graphene_std::wasm_application_io::RenderNode<_, _, _>, input: WasmEditorApi, output: RenderOutput, fn_params: [Footprint => VectorInstances, () => Arc<WasmSurfaceHandle>]),
 		async_node!(graphene_std::wasm_application_io::RenderNode<_, _, _>, input: WasmEditorApi, output: RenderOutput, fn_params: [Footprint => GraphicGroup, () => Arc<WasmSurfaceHandle>]),
 		async_node!(graphene_std::wasm_application_io::RenderNode<_, _, _>, input: WasmEditorApi, output: RenderOutput, fn_params: [Footprint => Artboard, () => Arc<WasmSurfaceHandle>]),
 		async_node!(graphene_std::wasm_application_io::RenderNode<_, _, _>, input: WasmEditorApi, output: RenderOutput, fn_params: [() => ImageFrame<Color>, () => Arc<WasmSurfaceHandle>]),
@@ -692,6 +710,7 @@ fn node_registry() -> HashMap<ProtoNodeIdentifier, HashMap<NodeIOTypes, NodeCons
 		async_node!(graphene_std::wasm_application_io::RenderNode<_, _, _>, input: WasmEditorApi, output: RenderOutput, fn_params: [() => Vec<Color>, () => Arc<WasmSurfaceHandle>]),
 		async_node!(graphene_std::wasm_application_io::RenderNode<_, _, _>, input: WasmEditorApi, output: RenderOutput, fn_params: [Footprint => Vec<Color>, () => Arc<WasmSurfaceHandle>]),
 		async_node!(graphene_core::transform::TransformNode<_, _, _, _, _, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, () => DVec2, () => f64, () => DVec2, () => DVec2, () => DVec2]),
+		async_node!(graphene_core::transform::TransformNode<_, _, _, _, _, _>, input: Footprint, output: VectorInstances, fn_params: [Footprint => VectorInstances, () => DVec2, () => f64, () => DVec2, () => DVec2, () => DVec2]),
 		async_node!(graphene_core::transform::TransformNode<_, _, _, _, _, _>, input: Footprint, output: WasmSurfaceHandleFrame, fn_params: [Footprint => WasmSurfaceHandleFrame, () => DVec2, () => f64, () => DVec2, () => DVec2, () => DVec2]),
 		async_node!(graphene_core::transform::TransformNode<_, _, _, _, _, _>, input: Footprint, output: WasmSurfaceHandleFrame, fn_params: [Footprint => WasmSurfaceHandleFrame, () => DVec2, () => f64, () => DVec2, () => DVec2, () => DVec2]),
 		async_node!(graphene_core::transform::TransformNode<_, _, _, _, _, _>, input: Footprint, output: ImageFrame<Color>, fn_params: [Footprint => ImageFrame<Color>, () => DVec2, () => f64, () => DVec2, () => DVec2, () => DVec2]),
@@ -700,12 +719,37 @@ fn node_registry() -> HashMap<ProtoNodeIdentifier, HashMap<NodeIOTypes, NodeCons
 		register_node!(graphene_core::transform::SetTransformNode<_>, input: ImageFrame<Color>, params: [ImageFrame<Color>]),
 		register_node!(graphene_core::transform::SetTransformNode<_>, input: VectorData, params: [DAffine2]),
 		register_node!(graphene_core::transform::SetTransformNode<_>, input: ImageFrame<Color>, params: [DAffine2]),
-		register_node!(graphene_core::vector::SetFillNode<_, _, _, _, _, _, _>, input: VectorData, params: [graphene_core::vector::style::FillType, Option<graphene_core::Color>, graphene_core::vector::style::GradientType, DVec2, DVec2, DAffine2, Vec<(f64, graphene_core::Color)>]),
-		register_node!(graphene_core::vector::SetStrokeNode<_, _, _, _, _, _, _>, input: VectorData, params: [Option<graphene_core::Color>, f64, Vec<f64>, f64, graphene_core::vector::style::LineCap, graphene_core::vector::style::LineJoin, f64]),
-		register_node!(graphene_core::vector::RepeatNode<_, _>, input: VectorData, params: [DVec2, u32]),
-		register_node!(graphene_core::vector::BoundingBoxNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::SetFillNode<_, _, _, _, _, _, _, _, _, _, _>, input: VectorData, params: [graphene_core::vector::style::FillType, Option<graphene_core::Color>, graphene_core::vector::style::GradientType, DVec2, DVec2, DAffine2, Vec<(f64, graphene_core::Color)>, Option<DVec2>, graphene_core::vector::style::FillRule, BlendMode, f64]),
+		register_node!(graphene_core::vector::SetStrokeNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>, input: VectorData, params: [Option<graphene_core::Color>, f64, Vec<f64>, f64, graphene_core::vector::style::LineCap, graphene_core::vector::style::LineJoin, f64, graphene_core::vector::style::StrokeAlignment, graphene_core::vector::style::FillType, graphene_core::vector::style::GradientType, DVec2, DVec2, DAffine2, Vec<(f64, graphene_core::Color)>, Option<DVec2>, BlendMode, f64, bool]),
+		register_node!(graphene_core::vector::SetStrokeGradientNode<_>, input: VectorData, params: [Vec<(f64, graphene_core::Color)>]),
+		register_node!(graphene_core::vector::StrokeWidthProfileNode<_>, input: VectorData, params: [Vec<f64>]),
+		register_node!(graphene_core::vector::StrokeDashesNode<_, _, _>, input: VectorData, params: [graphene_core::vector::style::LineCap, bool, bool]),
+		register_node!(graphene_core::vector::FillStrokeOpacityNode<_, _>, input: VectorData, params: [f64, f64]),
+		register_node!(graphene_core::vector::CopyStyleNode<_>, input: VectorData, params: [VectorData]),
+		register_node!(graphene_core::vector::GradientInterpolationNode<_, _>, input: VectorData, params: [graphene_core::vector::style::GradientInterpolation, graphene_core::vector::style::HueInterpolation]),
+		register_node!(graphene_core::vector::ColorSpaceNode<_>, input: VectorData, params: [graphene_core::raster::color::ColorSpace]),
+		register_node!(graphene_core::vector::AdjustFillColorNode<_, _, _>, input: VectorData, params: [f64, f64, f64]),
+		register_node!(graphene_core::vector::AdjustFillColorNode<_, _, _>, input: GraphicGroup, params: [f64, f64, f64]),
+		register_node!(graphene_core::vector::DefineStyleNode<_, _>, input: graphene_core::vector::style::StyleLibrary, params: [String, VectorData]),
+		register_node!(graphene_core::vector::ApplyStyleNode<_, _>, input: VectorData, params: [graphene_core::vector::style::StyleLibrary, String]),
+		register_node!(graphene_core::vector::AddFillLayerNode<_, _, _, _>, input: VectorData, params: [Option<graphene_core::Color>, BlendMode, f64, f64]),
+		register_node!(graphene_core::vector::AddStrokeLayerNode<_, _, _, _, _>, input: VectorData, params: [Option<graphene_core::Color>, f64, BlendMode, f64, f64]),
+		register_node!(graphene_core::vector::RemovePaintLayerNode<_>, input: VectorData, params: [f64]),
+		async_node!(graphene_core::vector::SetPatternFillNode<_, _, _, _, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, Footprint => VectorData, () => DVec2, () => f64, () => DVec2]),
+		async_node!(graphene_core::vector::SetImageFillNode<_, _, _, _, _, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, Footprint => ImageFrame<Color>, () => graphene_core::vector::style::ImageFillMode, () => DVec2, () => f64, () => DVec2]),
+		register_node!(graphene_core::vector::RepeatNode<_, _, _, _>, input: VectorData, params: [DVec2, u32, f64, f64]),
+		register_node!(graphene_core::vector::RepeatNode<_, _, _, _>, input: VectorInstances, params: [DVec2, u32, f64, f64]),
+		register_node!(graphene_core::vector::BoundingBoxNode<_, _, _>, input: VectorData, params: [bool, bool, f64]),
 		register_node!(graphene_core::vector::SolidifyStrokeNode, input: VectorData, params: []),
-		register_node!(graphene_core::vector::CircularRepeatNode<_, _, _>, input: VectorData, params: [f64, f64, u32]),
+		register_node!(graphene_core::vector::CircularRepeatNode<_, _, _, _, _>, input: VectorData, params: [f64, f64, u32, Option<DVec2>, bool]),
+		register_node!(graphene_core::vector::CircularRepeatNode<_, _, _, _, _>, input: VectorInstances, params: [f64, f64, u32, Option<DVec2>, bool]),
+		register_node!(graphene_core::vector::PatternTileNode<_, _, _, _>, input: VectorData, params: [graphene_core::vector::WallpaperGroup, DVec2, u32, u32]),
+		register_node!(graphene_core::vector::StickerOutlineNode<_, _>, input: GraphicGroup, params: [f64, Color]),
+		register_node!(graphene_core::vector::TechnicalDrawingNode<_, _, _, _, _>, input: VectorData, params: [f64, f64, f64, String, Color]),
+		register_node!(graphene_core::ops::FirstOfPairNode, input: (VectorData, VectorData), params: []),
+		register_node!(graphene_core::ops::SecondOfPairNode, input: (VectorData, VectorData), params: []),
+		register_node!(graphene_core::vector::ToInstancesNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::FlattenInstancesNode, input: VectorInstances, params: []),
 		vec![(
 			ProtoNodeIdentifier::new("graphene_core::transform::CullNode<_>"),
 			|args| {
@@ -748,13 +792,55 @@ fn node_registry() -> HashMap<ProtoNodeIdentifier, HashMap<NodeIOTypes, NodeCons
 		)],
 		register_node!(graphene_std::raster::SampleNode<_>, input: Footprint, params: [ImageFrame<Color>]),
 		register_node!(graphene_std::raster::MandelbrotNode, input: Footprint, params: []),
-		async_node!(graphene_core::vector::CopyToPoints<_, _, _, _, _, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, Footprint => VectorData, () => f64, () => f64, () => f64, () => f64]),
-		async_node!(graphene_core::vector::CopyToPoints<_, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => VectorData, Footprint => GraphicGroup, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_core::vector::CopyToPoints<_, _, _, _, _, _, _, _, _, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, Footprint => VectorData, () => f64, () => f64, () => f64, () => f64, () => f64, () => u32, () => bool, () => graphene_core::vector::VariantSelection]),
+		async_node!(graphene_core::vector::CopyToPoints<_, _, _, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => VectorData, Footprint => GraphicGroup, () => f64, () => f64, () => f64, () => f64, () => f64, () => u32, () => bool, () => graphene_core::vector::VariantSelection]),
+		async_node!(graphene_core::vector::CopyToPoints<_, _, _, _, _, _, _, _, _, _>, input: Footprint, output: VectorInstances, fn_params: [Footprint => VectorData, Footprint => VectorInstances, () => f64, () => f64, () => f64, () => f64, () => f64, () => u32, () => bool, () => graphene_core::vector::VariantSelection]),
 		async_node!(graphene_core::vector::SamplePoints<_, _, _, _, _, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, () => f64, () => f64, () => f64, () => bool, Footprint => Vec<f64>]),
 		register_node!(graphene_core::vector::PoissonDiskPoints<_>, input: VectorData, params: [f64]),
+		register_node!(graphene_core::vector::PointsInBoundsNode<_, _, _, _>, input: VectorData, params: [DVec2, f64, bool, bool]),
+		register_node!(graphene_core::vector::HatchFillNode<_, _, _>, input: VectorData, params: [f64, f64, bool]),
+		register_node!(graphene_core::vector::HatchShadingNode<_, _, _, _, _, _, _>, input: VectorData, params: [f64, f64, f64, f64, f64, f64, u32]),
+		register_node!(graphene_core::vector::ExtrudeNode<_, _, _>, input: VectorData, params: [DVec2, f64, Option<Color>]),
+		register_node!(graphene_core::vector::MosaicNode<_, _, _, _>, input: GraphicGroup, params: [graphene_core::vector::MosaicCell, f64, f64, u32]),
+		register_node!(graphene_core::vector::MedialAxisNode<_, _>, input: VectorData, params: [f64, f64]),
+		register_node!(graphene_core::vector::TangentNormalLinesNode<_, _, _>, input: VectorData, params: [f64, f64, bool]),
+		register_node!(graphene_core::vector::PathfindNode<_, _, _, _>, input: VectorData, params: [DVec2, DVec2, f64, f64]),
+		register_node!(graphene_core::vector::FilterSubpathsNode<_, _, _, _, _>, input: VectorData, params: [graphene_core::vector::SubpathMeasure, f64, f64, bool, graphene_core::vector::SubpathOrder]),
+		register_node!(graphene_core::vector::GetSubpathNode<_, _, _>, input: VectorData, params: [f64, u32, graphene_core::vector::IndexOutOfRange]),
+		register_node!(graphene_core::vector::StyleSubpathsNode<_, _, _>, input: VectorData, params: [VectorData, f64, u32]),
+		register_node!(graphene_core::vector::StyleSubpathsByMeasureNode<_, _, _, _>, input: VectorData, params: [VectorData, graphene_core::vector::SubpathMeasure, f64, f64]),
+		register_node!(graphene_core::vector::ToCssClipPathNode<_, _, _>, input: VectorData, params: [graphene_core::vector::ClipPathFormat, u32, bool]),
+		register_node!(graphene_core::vector::GeneratePathCodeNode<_, _>, input: VectorData, params: [graphene_core::vector::PathCodeLanguage, u32]),
+		register_node!(graphene_core::vector::WeldPointsNode<_>, input: VectorData, params: [f64]),
+		register_node!(graphene_core::vector::SnapToGridNode<_, _, _, _>, input: VectorData, params: [DVec2, DVec2, bool, bool]),
+		register_node!(graphene_core::vector::IconKeylineNode<_, _, _, _>, input: VectorData, params: [graphene_core::vector::IconKeyline, f64, f64, bool]),
 		register_node!(graphene_core::vector::LengthsOfSegmentsOfSubpaths, input: VectorData, params: []),
 		register_node!(graphene_core::vector::SplinesFromPointsNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::FreezeNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::SetHandleMirroringNode<_, _>, input: VectorData, params: [bool, f64]),
+		register_node!(graphene_core::vector::FitCurveNode<_, _>, input: VectorData, params: [f64, bool]),
+		register_node!(graphene_core::vector::VariableOffsetNode<_, _, _, _>, input: VectorData, params: [f64, String, bool, graphene_core::raster::curve::Curve]),
+		register_node!(graphene_core::vector::ClosestPointNode<_>, input: VectorData, params: [DVec2]),
+		register_node!(graphene_core::vector::DetectSymmetryNode<_, _>, input: VectorData, params: [graphene_core::vector::Symmetry, u32]),
+		async_node!(graphene_core::vector::IntersectionPointsNode<_, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, Footprint => VectorData]),
+		async_node!(graphene_core::vector::ShapeDeviationNode<_, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => VectorData, Footprint => VectorData, () => f64, () => f64, () => f64]),
+		async_node!(graphene_core::vector::ClipToViewportNode<_, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, () => f64]),
+		register_node!(graphene_core::vector::ScalePreservingCornersNode<_>, input: VectorData, params: [DVec2]),
+		async_node!(graphene_core::vector::AlignToReferenceNode<_, _, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, Footprint => VectorData, () => bool]),
+		register_node!(graphene_core::ops::FirstOfTripleNode, input: (DVec2, f64, f64), params: []),
+		register_node!(graphene_core::ops::SecondOfTripleNode, input: (DVec2, f64, f64), params: []),
+		register_node!(graphene_core::ops::ThirdOfTripleNode, input: (DVec2, f64, f64), params: []),
+		register_node!(graphene_core::ops::FirstOfTripleNode, input: (VectorData, DVec2, f64), params: []),
+		register_node!(graphene_core::ops::SecondOfTripleNode, input: (VectorData, DVec2, f64), params: []),
+		register_node!(graphene_core::ops::ThirdOfTripleNode, input: (VectorData, DVec2, f64), params: []),
 		async_node!(graphene_core::vector::MorphNode<_, _, _, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, Footprint => VectorData, () => u32, () => f64]),
+		async_node!(graphene_core::vector::LatticeDeformNode<_, _, _, _, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, Footprint => VectorData, () => u32, () => u32, () => u32]),
+		async_node!(graphene_core::vector::ProjectPointsOntoPathNode<_, _, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, Footprint => VectorData, () => String]),
+		register_node!(graphene_core::vector::SetPointAttributeNode<_, _, _>, input: VectorData, params: [String, f64, f64]),
+		register_node!(graphene_core::vector::SetPointVectorAttributeNode<_, _, _>, input: VectorData, params: [String, DVec2, DVec2]),
+		register_node!(graphene_core::vector::SetPointColorAttributeNode<_, _, _>, input: VectorData, params: [String, Color, Color]),
+		register_node!(graphene_core::vector::ReadPointAttributeNode<_, _>, input: VectorData, params: [String, u32]),
+		async_node!(graphene_core::vector::TransferPointAttributesNode<_, _, _, _, _>, input: Footprint, output: VectorData, fn_params: [Footprint => VectorData, Footprint => VectorData, () => String, () => graphene_core::vector::TransferMethod, () => f64]),
 		register_node!(graphene_core::vector::generator_nodes::CircleGenerator<_>, input: (), params: [f64]),
 		register_node!(graphene_core::vector::generator_nodes::EllipseGenerator<_, _>, input: (), params: [f64, f64]),
 		register_node!(graphene_core::vector::generator_nodes::RectangleGenerator<_, _>, input: (), params: [f64, f64]),
@@ -762,20 +848,61 @@ fn node_registry() -> HashMap<ProtoNodeIdentifier, HashMap<NodeIOTypes, NodeCons
 		register_node!(graphene_core::vector::generator_nodes::StarGenerator<_, _, _>, input: (), params: [u32, f64, f64]),
 		register_node!(graphene_core::vector::generator_nodes::LineGenerator<_, _>, input: (), params: [DVec2, DVec2]),
 		register_node!(graphene_core::vector::generator_nodes::SplineGenerator<_>, input: (), params: [Vec<DVec2>]),
+		register_node!(graphene_core::vector::generator_nodes::GridNode<_, _, _, _, _>, input: (), params: [graphene_core::vector::generator_nodes::GridType, f64, u32, u32, bool]),
 		register_node!(
 			graphene_core::vector::generator_nodes::PathGenerator<_>,
 			input: Vec<graphene_core::vector::bezier_rs::Subpath<graphene_core::uuid::ManipulatorGroupId>>,
 			params: [Vec<graphene_core::uuid::ManipulatorGroupId>]
 		),
-		register_node!(graphene_core::text::TextGeneratorNode<_, _, _>, input: WasmEditorApi, params: [String, graphene_core::text::Font, f64]),
+		register_node!(graphene_core::text::TextGeneratorNode<_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _>, input: WasmEditorApi, params: [String, graphene_core::text::Font, f64, graphene_core::text::Font, graphene_core::text::Font, graphene_core::text::MissingGlyph, bool, bool, bool, u32, f64, graphene_core::text::TextAlign, graphene_core::text::Hyphenation, f64, f64, VectorData, VectorData, f64, f64, u32]),
 		register_node!(graphene_std::brush::VectorPointsNode, input: VectorData, params: []),
 		register_node!(graphene_core::ExtractImageFrame, input: WasmEditorApi, params: []),
 		async_node!(graphene_core::ConstructLayerNode<_, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => graphene_core::GraphicElement, Footprint => GraphicGroup]),
 		register_node!(graphene_core::ToGraphicElementNode, input: graphene_core::vector::VectorData, params: []),
+		register_node!(graphene_core::ToGraphicElementNode, input: VectorInstances, params: []),
 		register_node!(graphene_core::ToGraphicElementNode, input: ImageFrame<Color>, params: []),
 		register_node!(graphene_core::ToGraphicElementNode, input: GraphicGroup, params: []),
 		register_node!(graphene_core::ToGraphicElementNode, input: Artboard, params: []),
+		register_node!(graphene_core::SetChannelNode<_>, input: graphene_core::vector::VectorData, params: [String]),
+		register_node!(graphene_core::SetChannelNode<_>, input: ImageFrame<Color>, params: [String]),
+		register_node!(graphene_core::SetChannelNode<_>, input: GraphicGroup, params: [String]),
+		register_node!(graphene_core::ReferenceImageNode<_, _, _>, input: GraphicGroup, params: [ImageFrame<Color>, f64, bool]),
+		register_node!(graphene_core::export::ExportSetNode<_, _>, input: graphene_core::vector::VectorData, params: [String, String]),
+		register_node!(graphene_core::export::ExportSetNode<_, _>, input: ImageFrame<Color>, params: [String, String]),
+		register_node!(graphene_core::export::ExportSetNode<_, _>, input: GraphicGroup, params: [String, String]),
+		register_node!(graphene_core::mask::ClipNode<_>, input: graphene_core::vector::VectorData, params: [graphene_core::vector::VectorData]),
+		register_node!(graphene_core::mask::ClipNode<_>, input: graphene_core::vector::VectorData, params: [ImageFrame<Color>]),
+		register_node!(graphene_core::mask::ClipNode<_>, input: ImageFrame<Color>, params: [graphene_core::vector::VectorData]),
+		register_node!(graphene_core::mask::ClipNode<_>, input: ImageFrame<Color>, params: [ImageFrame<Color>]),
+		register_node!(graphene_core::mask::ClipNode<_>, input: GraphicGroup, params: [graphene_core::vector::VectorData]),
+		register_node!(graphene_core::mask::ClipNode<_>, input: GraphicGroup, params: [ImageFrame<Color>]),
+		register_node!(graphene_core::renderer::ExportSvgNode<_, _, _, _>, input: GraphicGroup, params: [u32, graphene_core::renderer::SvgViewBox, bool, bool]),
+		register_node!(graphene_core::renderer::ExportSvgNode<_, _, _, _>, input: graphene_core::vector::VectorData, params: [u32, graphene_core::renderer::SvgViewBox, bool, bool]),
+		register_node!(graphene_std::atlas::AtlasPackNode<_, _, _, _, _>, input: GraphicGroup, params: [String, f64, u32, u32, bool]),
+		async_node!(graphene_std::rasterize::RasterizeNode<_>, input: Footprint, output: ImageFrame<Color>, fn_params: [Footprint => GraphicGroup]),
+		async_node!(graphene_std::rasterize::RasterizeNode<_>, input: Footprint, output: ImageFrame<Color>, fn_params: [Footprint => VectorData]),
+		async_node!(graphene_std::rasterize::RasterizeNode<_>, input: Footprint, output: ImageFrame<Color>, fn_params: [Footprint => ImageFrame<Color>]),
+		async_node!(graphene_std::rasterize::RasterizeNode<_>, input: Footprint, output: ImageFrame<Color>, fn_params: [Footprint => Artboard]),
+		register_node!(graphene_std::nine_patch::NinePatchNode<_, _, _, _>, input: graphene_core::vector::VectorData, params: [u32, u32, u32, u32]),
+		register_node!(graphene_std::nine_patch::NinePatchNode<_, _, _, _>, input: ImageFrame<Color>, params: [u32, u32, u32, u32]),
+		register_node!(graphene_std::nine_patch::NinePatchNode<_, _, _, _>, input: GraphicGroup, params: [u32, u32, u32, u32]),
+		register_node!(graphene_std::encode::EncodePngNode<_>, input: ImageFrame<Color>, params: [bool]),
+		register_node!(graphene_std::encode::EncodeJpegNode<_, _>, input: ImageFrame<Color>, params: [u32, Color]),
+		register_node!(graphene_std::encode::EncodeWebPNode, input: ImageFrame<Color>, params: []),
+		register_node!(graphene_core::ops::FirstOfPairNode, input: (ImageFrame<Color>, String), params: []),
+		register_node!(graphene_core::ops::SecondOfPairNode, input: (ImageFrame<Color>, String), params: []),
+		register_node!(graphene_core::layout::AlignDistributeNode<_, _, _>, input: GraphicGroup, params: [graphene_core::layout::HorizontalAlignment, graphene_core::layout::VerticalAlignment, graphene_core::layout::Distribution]),
+		register_node!(graphene_core::diff::VisualDiffNode<_>, input: GraphicGroup, params: [GraphicGroup]),
 		async_node!(graphene_core::ConstructArtboardNode<_, _, _, _, _>, input: Footprint, output: Artboard, fn_params: [Footprint => GraphicGroup, () => glam::IVec2, () => glam::IVec2, () => Color, () => bool]),
+		register_node!(graphene_core::imposition::ImposeNode<_, _, _, _, _, _>, input: Artboard, params: [glam::IVec2, DVec2, f64, bool, bool, f64]),
+		async_node!(graphene_core::variable_data::ForEachRowNode<_, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => GraphicGroup, () => String, () => u32, () => DVec2, () => bool]),
+		register_node!(graphene_core::variable_data::RowFieldNode<_, _>, input: Footprint, params: [String, String]),
+		async_node!(graphene_core::variable_data::ParameterSweepNode<_, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => GraphicGroup, () => String, () => String, () => u32, () => DVec2, () => bool]),
+		register_node!(graphene_core::variable_data::ParameterNode<_, _>, input: Footprint, params: [String, f64]),
+		#[cfg(feature = "native")]
+		register_node!(graphene_std::controller_input::ControllerParameterNode<_, _, _, _, _, _>, input: Footprint, params: [String, f64, String, u32, f64, f64]),
+		register_node!(graphene_core::variable_data::SequenceNumberNode<_, _, _, _, _>, input: Footprint, params: [u32, u32, u32, String, String]),
+		register_node!(graphene_core::variable_data::UuidNode<_>, input: Footprint, params: [u32]),
 	];
 	let mut map: HashMap<ProtoNodeIdentifier, HashMap<NodeIOTypes, NodeConstructor>> = HashMap::new();
 	for (id, c, types) in node_types.into_iter().flatten() {
@@ -0,0 +1,96 @@
+//! Placeholders drawn in place of the output of nodes that fail, so one failing node doesn't blank the whole document.
+
+use graph_craft::proto::{node_result, Any, FutureAny, TypeErasedBox};
+use graphene_core::transform::Footprint;
+use graphene_core::vector::VectorData;
+use graphene_core::{concrete, GraphicElement, GraphicGroup, Node, Type};
+
+use glam::DVec2;
+use std::sync::Arc;
+
+/// The size of the placeholder for a node that isn't given a footprint to fill.
+const PLACEHOLDER_SIZE: f64 = 100.;
+
+/// The graphical types that a failed node's output can be replaced by a placeholder of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceholderOutput {
+	VectorData,
+	GraphicElement,
+	GraphicGroup,
+}
+
+impl PlaceholderOutput {
+	/// The kind of placeholder for a node with the given output type, whether or not it's the output of a future, or none if it isn't graphical.
+	pub fn for_output(output: &Type) -> Option<Self> {
+		let output = match output {
+			Type::Future(output) => output.as_ref(),
+			output => output,
+		};
+		[
+			(concrete!(VectorData), Self::VectorData),
+			(concrete!(GraphicElement), Self::GraphicElement),
+			(concrete!(GraphicGroup), Self::GraphicGroup),
+		]
+		.into_iter()
+		.find_map(|(ty, placeholder)| (*output == ty).then_some(placeholder))
+	}
+
+	fn placeholder<'i>(self, bounds: [DVec2; 2]) -> Any<'i> {
+		let vector_data = VectorData::failed_node_placeholder(bounds);
+		match self {
+			Self::VectorData => Box::new(vector_data),
+			Self::GraphicElement => Box::new(GraphicElement::from(vector_data)),
+			Self::GraphicGroup => {
+				let mut graphic_group = GraphicGroup::EMPTY;
+				graphic_group.push(vector_data.into());
+				Box::new(graphic_group)
+			}
+		}
+	}
+}
+
+/// Wraps a node with a graphical output so that when it fails, outputting a [`graph_craft::proto::NodeError`] in place of its value, a magenta crossed box filling
+/// the footprint it was evaluated with is output instead and the nodes downstream of it carry on evaluating.
+pub struct PlaceholderOnFailureNode {
+	node: TypeErasedBox<'static>,
+	output: PlaceholderOutput,
+	name: String,
+}
+
+impl PlaceholderOnFailureNode {
+	pub fn new(node: TypeErasedBox<'static>, output: PlaceholderOutput, name: String) -> Self {
+		Self { node, output, name }
+	}
+}
+
+impl<'i> Node<'i, Any<'i>> for PlaceholderOnFailureNode {
+	type Output = FutureAny<'i>;
+
+	fn eval(&'i self, input: Any<'i>) -> Self::Output {
+		// The placeholder covers the area that's visible when the node is given a footprint
+		let (input, bounds) = if dyn_any::DynAny::type_id(input.as_ref()) == core::any::TypeId::of::<Footprint>() {
+			let footprint = dyn_any::downcast::<Footprint>(input).expect("The input should be a footprint");
+			let viewport = footprint.viewport_bounds_in_local_space();
+			let bounds = [viewport.start.min(viewport.end), viewport.start.max(viewport.end)];
+			(footprint as Any<'i>, bounds)
+		} else {
+			(input, [DVec2::ZERO, DVec2::splat(PLACEHOLDER_SIZE)])
+		};
+
+		let output = self.node.eval(input);
+		Box::pin(async move {
+			node_result(output.await).unwrap_or_else(|error| {
+				log::error!("The {} node failed, so a placeholder is drawn in place of its output: {error}", self.name);
+				self.output.placeholder(bounds)
+			})
+		})
+	}
+
+	fn reset(&self) {
+		self.node.reset();
+	}
+
+	fn serialize(&self) -> Option<Arc<dyn core::any::Any>> {
+		self.node.serialize()
+	}
+}
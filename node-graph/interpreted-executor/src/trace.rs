@@ -0,0 +1,81 @@
+//! Tracing of node evaluations, enabled by the `tracing` feature, so embedders evaluating graphs headlessly can diagnose slow or failing graphs with any `tracing` subscriber.
+
+use graph_craft::document::NodeId;
+use graph_craft::proto::{Any, ConstructionArgs, FutureAny, ProtoNode, TypeErasedBox};
+use graphene_core::Node;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use tracing::Instrument;
+
+/// Wraps a node of the [`crate::dynamic_executor::BorrowTree`] so each of its evaluations runs inside a `node_evaluation` span at the debug level.
+///
+/// The span records the node's name, its ID, the path of the document node it was compiled from, the IDs of the nodes it takes its inputs from (or the hash of its value for a value node),
+/// and once its output is ready, how many microseconds the evaluation took. The inputs are identified by where they come from rather than by their values, which aren't hashable once type erased.
+/// Evaluations of upstream nodes are nested inside the span, so their durations are included in it.
+pub struct TracedNode {
+	node: TypeErasedBox<'static>,
+	name: String,
+	id: NodeId,
+	path: Vec<NodeId>,
+	/// The IDs of the nodes this node takes its inputs from, which are stable hashes of those nodes rather than of what they output.
+	input_ids: Vec<u64>,
+	/// The hash of the value of a value node, which has no input nodes.
+	value_hash: Option<u64>,
+}
+
+impl TracedNode {
+	pub fn new(node: TypeErasedBox<'static>, id: NodeId, proto_node: &ProtoNode) -> Self {
+		let (input_ids, value_hash) = match &proto_node.construction_args {
+			ConstructionArgs::Value(value) => {
+				let mut hasher = DefaultHasher::new();
+				value.hash(&mut hasher);
+				(Vec::new(), Some(hasher.finish()))
+			}
+			ConstructionArgs::Nodes(nodes) => (nodes.iter().map(|(id, _)| id.0).collect(), None),
+			ConstructionArgs::Inline(_) => (Vec::new(), None),
+		};
+
+		Self {
+			node,
+			name: proto_node.identifier.name.to_string(),
+			id,
+			path: proto_node.original_location.path.clone().unwrap_or_default(),
+			input_ids,
+			value_hash,
+		}
+	}
+}
+
+impl<'i> Node<'i, Any<'i>> for TracedNode {
+	type Output = FutureAny<'i>;
+
+	fn eval(&'i self, input: Any<'i>) -> Self::Output {
+		let span = tracing::debug_span!(
+			"node_evaluation",
+			node = %self.name,
+			id = self.id.0,
+			path = ?self.path,
+			input_ids = ?self.input_ids,
+			value_hash = self.value_hash,
+			duration_us = tracing::field::Empty,
+		);
+		let start = std::time::Instant::now();
+		let output = span.in_scope(|| self.node.eval(input));
+
+		Box::pin(async move {
+			let output = output.instrument(span.clone()).await;
+			span.record("duration_us", start.elapsed().as_micros() as u64);
+			output
+		})
+	}
+
+	fn reset(&self) {
+		self.node.reset();
+	}
+
+	fn serialize(&self) -> Option<Arc<dyn core::any::Any>> {
+		self.node.serialize()
+	}
+}
@@ -26,6 +26,9 @@ pub struct WgpuExecutor {
 	pub context: Context,
 	render_configuration: RenderConfiguration,
 	surface_config: Cell<Option<SurfaceConfiguration>>,
+	/// Renders vello scenes, reused between frames since creating its pipelines is slow.
+	#[cfg(feature = "vello")]
+	vello_renderer: futures::lock::Mutex<vello::Renderer>,
 }
 
 impl std::fmt::Debug for WgpuExecutor {
@@ -591,12 +594,61 @@ impl WgpuExecutor {
 			sampler,
 		};
 
+		#[cfg(feature = "vello")]
+		let vello_renderer = vello::Renderer::new(
+			&context.device,
+			vello::RendererOptions {
+				surface_format: Some(wgpu::TextureFormat::Rgba8Unorm),
+				use_cpu: false,
+				antialiasing_support: vello::AaSupport::area_only(),
+				num_init_threads: std::num::NonZeroUsize::new(1),
+			},
+		)
+		.map_err(|error| log::error!("Failed to create the vello renderer: {error}"))
+		.ok()?;
+
 		Some(Self {
 			context,
 			render_configuration,
 			surface_config: Cell::new(None),
+			#[cfg(feature = "vello")]
+			vello_renderer: futures::lock::Mutex::new(vello_renderer),
 		})
 	}
+
+	/// Renders the vello scene onto the canvas, resized to the given size in pixels, over a transparent background.
+	#[cfg(all(feature = "vello", target_arch = "wasm32"))]
+	pub async fn render_vello_scene(&self, scene: &vello::Scene, canvas: &HtmlCanvasElement, width: u32, height: u32) -> Result<()> {
+		let surface = self.context.instance.create_surface(wgpu::SurfaceTarget::Canvas(canvas.clone()))?;
+		let surface_caps = surface.get_capabilities(&self.context.adapter);
+		let config = wgpu::SurfaceConfiguration {
+			usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+			format: wgpu::TextureFormat::Rgba8Unorm,
+			width,
+			height,
+			present_mode: surface_caps.present_modes[0],
+			alpha_mode: wgpu::CompositeAlphaMode::PreMultiplied,
+			view_formats: vec![],
+			desired_maximum_frame_latency: 2,
+		};
+		surface.configure(&self.context.device, &config);
+		let surface_texture = surface.get_current_texture()?;
+
+		let render_params = vello::RenderParams {
+			base_color: vello::peniko::Color::TRANSPARENT,
+			width,
+			height,
+			antialiasing_method: vello::AaConfig::Area,
+		};
+		self.vello_renderer
+			.lock()
+			.await
+			.render_to_surface_async(&self.context.device, &self.context.queue, scene, &surface_texture, &render_params)
+			.await
+			.map_err(|error| anyhow::anyhow!("Failed to render the vello scene: {error}"))?;
+		surface_texture.present();
+		Ok(())
+	}
 }
 
 #[derive(Debug)]